@@ -0,0 +1,323 @@
+use crate::checksum::sha256_hex;
+use crate::config::{Config, ConfigPaths};
+use crate::session::{SessionError, StoredSession, list_sessions};
+use clap::{Args, Subcommand};
+use koe_core::types::NoteKind;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+
+#[derive(Args, Debug, Clone)]
+pub struct ActionsArgs {
+    #[command(subcommand)]
+    pub action: ActionsAction,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ActionsAction {
+    /// Action items from every session, open ones by due date
+    List {
+        /// Include items already done
+        #[arg(long)]
+        all: bool,
+        /// Only items assigned to this owner
+        #[arg(long, value_name = "name")]
+        owner: Option<String>,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Mark an action item done by id (or a unique id prefix)
+    Done { id: String },
+}
+
+#[derive(Debug, Error)]
+pub enum ActionsError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("time error: {0}")]
+    Time(#[from] time::error::Format),
+    #[error("{0}")]
+    Message(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionStatus {
+    Open,
+    Done,
+}
+
+/// An action item note followed past its meeting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrackedAction {
+    /// Short id stable across refreshes, derived from session and note ids.
+    pub id: String,
+    pub session_id: String,
+    pub note_id: String,
+    /// `YYYY-MM-DD title` of the meeting the item came from.
+    pub meeting: String,
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    pub status: ActionStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub done_at: Option<String>,
+}
+
+/// Action items across sessions, kept in `~/.koe/actions.json`. Sessions'
+/// notes are the source of text, owner, and due date; status lives here, so
+/// an item closed after its meeting stays closed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActionStore {
+    pub actions: Vec<TrackedAction>,
+}
+
+impl ActionStore {
+    /// Pick up action items from every session in `sessions_dirs`; items
+    /// ticked off in the notes are closed, and known items keep their status.
+    pub fn refresh(&mut self, sessions_dirs: &[PathBuf]) -> Result<(), ActionsError> {
+        for dir in sessions_dirs {
+            for session in list_sessions(dir)? {
+                self.refresh_session(&session)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn refresh_session(&mut self, session: &StoredSession) -> Result<(), ActionsError> {
+        let notes = session.notes()?;
+        for bullet in notes
+            .bullets
+            .iter()
+            .filter(|bullet| bullet.kind == NoteKind::Action)
+        {
+            let position = self.actions.iter().position(|action| {
+                action.session_id == session.metadata.id && action.note_id == bullet.id
+            });
+            let action = match position {
+                Some(position) => &mut self.actions[position],
+                None => {
+                    self.actions.push(TrackedAction {
+                        id: action_id(&session.metadata.id, &bullet.id),
+                        session_id: session.metadata.id.clone(),
+                        note_id: bullet.id.clone(),
+                        meeting: String::new(),
+                        text: String::new(),
+                        owner: None,
+                        due: None,
+                        status: ActionStatus::Open,
+                        done_at: None,
+                    });
+                    self.actions.last_mut().expect("just pushed")
+                }
+            };
+            action.meeting = session.metadata.display_name();
+            action.text = bullet.text.trim().to_string();
+            action.owner = bullet.owner.clone();
+            action.due = bullet.due.clone();
+            if bullet.done && action.status == ActionStatus::Open {
+                action.status = ActionStatus::Done;
+                action.done_at = Some(session.metadata.last_update.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Open items first, each group by due date (undated last), then meeting.
+    pub fn sorted(&self) -> Vec<&TrackedAction> {
+        let mut actions: Vec<&TrackedAction> = self.actions.iter().collect();
+        actions.sort_by(|a, b| {
+            (a.status == ActionStatus::Done)
+                .cmp(&(b.status == ActionStatus::Done))
+                .then_with(|| a.due.is_none().cmp(&b.due.is_none()))
+                .then_with(|| a.due.cmp(&b.due))
+                .then_with(|| a.meeting.cmp(&b.meeting))
+        });
+        actions
+    }
+
+    /// Mark the item whose id starts with `id` done; ambiguous or unknown ids fail.
+    pub fn mark_done(&mut self, id: &str) -> Result<&TrackedAction, ActionsError> {
+        let matches: Vec<usize> = self
+            .actions
+            .iter()
+            .enumerate()
+            .filter(|(_, action)| action.id.starts_with(id))
+            .map(|(index, _)| index)
+            .collect();
+        let index = match matches.as_slice() {
+            [index] => *index,
+            [] => return Err(ActionsError::Message(format!("no action item {id}"))),
+            _ => {
+                return Err(ActionsError::Message(format!(
+                    "action id {id} is ambiguous"
+                )));
+            }
+        };
+        let action = &mut self.actions[index];
+        if action.status == ActionStatus::Open {
+            action.status = ActionStatus::Done;
+            action.done_at = Some(OffsetDateTime::now_utc().format(&Rfc3339)?);
+        }
+        Ok(action)
+    }
+}
+
+pub fn run(args: &ActionsArgs, paths: &ConfigPaths, config: &Config) -> Result<(), ActionsError> {
+    let mut store = load(&paths.actions_path)?;
+    store.refresh(&sessions_dirs(paths, config))?;
+    match &args.action {
+        ActionsAction::List { all, owner, json } => {
+            let actions: Vec<&TrackedAction> = store
+                .sorted()
+                .into_iter()
+                .filter(|action| *all || action.status == ActionStatus::Open)
+                .filter(|action| {
+                    owner.as_deref().is_none_or(|owner| {
+                        action
+                            .owner
+                            .as_deref()
+                            .is_some_and(|name| name.eq_ignore_ascii_case(owner))
+                    })
+                })
+                .collect();
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&actions)?);
+            } else {
+                for action in &actions {
+                    println!("{}", action_line(action));
+                }
+            }
+        }
+        ActionsAction::Done { id } => {
+            let action = store.mark_done(id)?;
+            println!("{}", action_line(action));
+        }
+    }
+    save(&paths.actions_path, &store)?;
+    Ok(())
+}
+
+/// `id  [ ] text (owner, due date)  meeting` for listings.
+pub fn action_line(action: &TrackedAction) -> String {
+    let mut line = format!(
+        "{}  [{}] {}",
+        action.id,
+        if action.status == ActionStatus::Done {
+            "x"
+        } else {
+            " "
+        },
+        action.text
+    );
+    match (action.owner.as_deref(), action.due.as_deref()) {
+        (Some(owner), Some(due)) => line.push_str(&format!(" ({owner}, due {due})")),
+        (Some(owner), None) => line.push_str(&format!(" ({owner})")),
+        (None, Some(due)) => line.push_str(&format!(" (due {due})")),
+        (None, None) => {}
+    }
+    line.push_str(&format!("  {}", action.meeting));
+    line
+}
+
+/// The default sessions dir plus every workspace's, so the tracker sees all meetings.
+pub fn sessions_dirs(paths: &ConfigPaths, config: &Config) -> Vec<PathBuf> {
+    let mut dirs = vec![paths.sessions_dir.clone()];
+    for (name, workspace) in &config.workspaces {
+        let dir = paths.for_workspace(name, workspace).sessions_dir;
+        if !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+    }
+    dirs
+}
+
+/// The store from `path`; a missing file is empty.
+pub fn load(path: &Path) -> io::Result<ActionStore> {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents).map_err(io::Error::other),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(ActionStore::default()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Replace the store file atomically.
+pub fn save(path: &Path, store: &ActionStore) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let payload = serde_json::to_string_pretty(store).map_err(io::Error::other)?;
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, payload)?;
+    fs::rename(&tmp, path)
+}
+
+fn action_id(session_id: &str, note_id: &str) -> String {
+    sha256_hex(format!("{session_id}/{note_id}").as_bytes())[..8].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActionStatus, ActionStore, load, save};
+    use crate::config::ConfigPaths;
+    use crate::session::SessionHandle;
+    use crate::test_support::{bullet, session_metadata};
+    use koe_core::types::{MeetingNotes, NoteBullet, NoteKind};
+    use tempfile::tempdir;
+
+    fn action(id: &str, text: &str, done: bool) -> NoteBullet {
+        NoteBullet {
+            done,
+            owner: Some("Dana".to_string()),
+            ..bullet(id, text, NoteKind::Action)
+        }
+    }
+
+    #[test]
+    fn actions_aggregate_across_sessions_and_stay_done() {
+        let temp = tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().join("koe"));
+        let metadata = session_metadata();
+        let mut session = SessionHandle::start(&paths, metadata, None).unwrap();
+        let mut notes = MeetingNotes::default();
+        notes.bullets.push(action("n1", "Send checklist", false));
+        notes.bullets.push(action("n2", "Book venue", true));
+        session.write_notes(&notes).unwrap();
+
+        let dirs = vec![paths.sessions_dir.clone()];
+        let mut store = ActionStore::default();
+        store.refresh(&dirs).unwrap();
+        assert_eq!(store.actions.len(), 2);
+        let sorted = store.sorted();
+        assert_eq!(
+            (sorted[0].text.as_str(), sorted[0].status),
+            ("Send checklist", ActionStatus::Open)
+        );
+        assert_eq!(sorted[1].status, ActionStatus::Done);
+
+        let id = sorted[0].id.clone();
+        store.mark_done(&id[..4]).unwrap();
+        store.refresh(&dirs).unwrap();
+        assert_eq!(store.actions.len(), 2);
+        assert!(
+            store
+                .actions
+                .iter()
+                .all(|action| action.status == ActionStatus::Done)
+        );
+
+        save(&paths.actions_path, &store).unwrap();
+        assert_eq!(load(&paths.actions_path).unwrap(), store);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::config::{Config, ConfigPaths};
+use crate::http;
+use crate::tui;
+use clap::Args;
+use koe_core::types::{MeetingNotes, TranscriptSegment};
+use serde::Deserialize;
+use std::io::{self, BufRead, BufReader};
+use std::sync::mpsc::{Sender, channel};
+use std::thread;
+use std::time::Duration;
+use thiserror::Error;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+#[derive(Args, Debug, Clone)]
+pub struct AttachArgs {
+    /// Mirror transcript and notes without any control
+    #[arg(long)]
+    pub read_only: bool,
+
+    /// Local HTTP API port of the running instance (defaults to `http.port`)
+    #[arg(long)]
+    pub port: Option<u16>,
+}
+
+#[derive(Debug, Error)]
+pub enum AttachError {
+    #[error("attach io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Message(String),
+}
+
+/// Updates from the controlling instance, in arrival order.
+#[derive(Debug, Clone)]
+pub enum ObserverEvent {
+    Snapshot {
+        active: bool,
+        segments: Vec<TranscriptSegment>,
+        notes: MeetingNotes,
+    },
+    MeetingStart,
+    Segments(Vec<TranscriptSegment>),
+    Notes(MeetingNotes),
+    MeetingEnd,
+    /// The stream dropped; the client keeps retrying.
+    Disconnected(String),
+}
+
+pub fn run(args: &AttachArgs, paths: &ConfigPaths, config: &Config) -> Result<(), AttachError> {
+    if !args.read_only {
+        return Err(AttachError::Message(
+            "only read-only attach is supported; pass --read-only".into(),
+        ));
+    }
+    let port = args.port.unwrap_or(config.http.port);
+    let base = format!("http://127.0.0.1:{port}");
+    let token = http::read_token(&paths.http_token_path).map_err(|e| {
+        AttachError::Message(format!(
+            "no http token at {} ({e}); is koe running with http.enabled?",
+            paths.http_token_path.display()
+        ))
+    })?;
+    let (tx, rx) = channel();
+    let endpoint = base.clone();
+    thread::Builder::new()
+        .name("koe-attach".into())
+        .spawn(move || follow(&endpoint, &token, &tx))?;
+    tui::run_observer(rx, &base, &config.ui).map_err(|e| AttachError::Message(e.to_string()))
+}
+
+#[derive(Deserialize)]
+struct TranscriptResponse {
+    active: bool,
+    segments: Vec<TranscriptSegment>,
+}
+
+#[derive(Deserialize)]
+struct NotesResponse {
+    notes: MeetingNotes,
+}
+
+/// Stream from the local HTTP API until the observer UI goes away, reconnecting on failure.
+fn follow(base: &str, token: &str, tx: &Sender<ObserverEvent>) {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_connect(Some(CONNECT_TIMEOUT))
+        .build()
+        .into();
+    let authorization = format!("Bearer {token}");
+    loop {
+        let message = match stream_once(&agent, base, &authorization, tx) {
+            Ok(()) => "stream closed".to_string(),
+            Err(e) => e.to_string(),
+        };
+        if tx.send(ObserverEvent::Disconnected(message)).is_err() {
+            return;
+        }
+        thread::sleep(RECONNECT_DELAY);
+    }
+}
+
+fn stream_once(
+    agent: &ureq::Agent,
+    base: &str,
+    authorization: &str,
+    tx: &Sender<ObserverEvent>,
+) -> Result<(), ureq::Error> {
+    // Subscribe before taking the snapshot so nothing falls between the two;
+    // the ledger absorbs any segment seen twice.
+    let events = agent
+        .get(format!("{base}/events"))
+        .header("Authorization", authorization)
+        .call()?;
+    let transcript: TranscriptResponse = agent
+        .get(format!("{base}/transcript"))
+        .header("Authorization", authorization)
+        .call()?
+        .into_body()
+        .read_json()?;
+    let notes: NotesResponse = agent
+        .get(format!("{base}/notes"))
+        .header("Authorization", authorization)
+        .call()?
+        .into_body()
+        .read_json()?;
+    let snapshot = ObserverEvent::Snapshot {
+        active: transcript.active,
+        segments: transcript.segments,
+        notes: notes.notes,
+    };
+    if tx.send(snapshot).is_err() {
+        return Ok(());
+    }
+
+    let mut frame = SseFrame::default();
+    for line in BufReader::new(events.into_body().into_reader()).lines() {
+        if let Some(event) = frame.push_line(&line?)
+            && tx.send(event).is_err()
+        {
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
+/// Accumulates `event:`/`data:` lines until the blank line ending an SSE frame.
+#[derive(Debug, Default)]
+struct SseFrame {
+    event: String,
+    data: String,
+}
+
+impl SseFrame {
+    fn push_line(&mut self, line: &str) -> Option<ObserverEvent> {
+        if line.is_empty() {
+            let event = std::mem::take(&mut self.event);
+            let data = std::mem::take(&mut self.data);
+            return decode_event(&event, &data);
+        }
+        if let Some(value) = line.strip_prefix("event:") {
+            self.event = value.trim().to_string();
+        } else if let Some(value) = line.strip_prefix("data:") {
+            if !self.data.is_empty() {
+                self.data.push('\n');
+            }
+            self.data.push_str(value.trim_start());
+        }
+        None
+    }
+}
+
+fn decode_event(event: &str, data: &str) -> Option<ObserverEvent> {
+    match event {
+        "meeting_start" => Some(ObserverEvent::MeetingStart),
+        "meeting_end" => Some(ObserverEvent::MeetingEnd),
+        "segments" => serde_json::from_str(data).ok().map(ObserverEvent::Segments),
+        "notes" => serde_json::from_str(data).ok().map(ObserverEvent::Notes),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ObserverEvent, SseFrame};
+
+    #[test]
+    fn sse_frames_decode_into_observer_events() {
+        let stream = concat!(
+            ": keepalive\n",
+            "\n",
+            "event: meeting_start\n",
+            "data: {\"session_id\":\"abc\"}\n",
+            "\n",
+            "event: segments\n",
+            "data: [{\"id\":1,\"start_ms\":0,\"end_ms\":900,\"speaker\":\"Me\",\"text\":\"hi\",\"finalized\":true}]\n",
+            "\n",
+            "event: notes\n",
+            "data: {\"bullets\":[]}\n",
+            "\n",
+        );
+        let mut frame = SseFrame::default();
+        let events: Vec<ObserverEvent> = stream
+            .lines()
+            .filter_map(|line| frame.push_line(line))
+            .collect();
+
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], ObserverEvent::MeetingStart));
+        assert!(
+            matches!(&events[1], ObserverEvent::Segments(segments) if segments[0].text == "hi")
+        );
+        assert!(matches!(&events[2], ObserverEvent::Notes(notes) if notes.bullets.is_empty()));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::config::{Config, ConfigPaths};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+
+/// One JSON line of `~/.koe/audit.log`.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditEntry<'a> {
+    pub time: String,
+    pub user: String,
+    pub action: &'a str,
+    pub detail: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<&'a str>,
+}
+
+/// Append-only record of config changes and where meeting data was sent.
+#[derive(Debug, Clone)]
+pub struct AuditLog {
+    path: PathBuf,
+}
+
+impl AuditLog {
+    pub fn new(paths: &ConfigPaths) -> Self {
+        Self {
+            path: paths.base_dir.join("audit.log"),
+        }
+    }
+
+    pub fn record(&self, action: &str, detail: &str, session_id: Option<&str>) -> io::Result<()> {
+        let entry = AuditEntry {
+            time: OffsetDateTime::now_utc()
+                .format(&Rfc3339)
+                .map_err(io::Error::other)?,
+            user: std::env::var("USER").unwrap_or_else(|_| "unknown".to_string()),
+            action,
+            detail,
+            session_id,
+        };
+        let mut line = serde_json::to_string(&entry)?;
+        line.push('\n');
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(line.as_bytes())
+    }
+
+    /// Record the keys that differ between two configs; secrets are never logged.
+    pub fn record_config_change(
+        &self,
+        action: &str,
+        before: &Config,
+        after: &Config,
+    ) -> io::Result<()> {
+        let changed = changed_keys(before, after);
+        if changed.is_empty() {
+            return Ok(());
+        }
+        let detail = changed
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.record(action, &detail, None)
+    }
+}
+
+/// Dotted keys whose redacted value changed, with the new value.
+fn changed_keys(before: &Config, after: &Config) -> Vec<(String, String)> {
+    let before = flatten(&before.redacted());
+    let after = flatten(&after.redacted());
+    let mut changed: Vec<(String, String)> = after
+        .iter()
+        .filter(|(key, value)| before.get(*key) != Some(*value))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|key| !after.contains_key(*key))
+            .map(|key| (key.clone(), "<removed>".to_string())),
+    );
+    changed
+}
+
+fn flatten(config: &Config) -> BTreeMap<String, String> {
+    let mut out = BTreeMap::new();
+    if let Ok(value) = toml::Value::try_from(config) {
+        flatten_value("", &value, &mut out);
+    }
+    out
+}
+
+fn flatten_value(prefix: &str, value: &toml::Value, out: &mut BTreeMap<String, String>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_value(&key, value, out);
+            }
+        }
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuditLog;
+    use crate::config::{Config, ConfigPaths};
+    use std::fs;
+
+    #[test]
+    fn config_changes_append_redacted_lines() {
+        let temp = tempfile::tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().to_path_buf());
+        let audit = AuditLog::new(&paths);
+
+        let before = Config::default();
+        let mut after = before.clone();
+        after.summarize.cloud.api_key = "secret".to_string();
+        after.summarize.active = "cloud".to_string();
+        audit
+            .record_config_change("config_set", &before, &after)
+            .unwrap();
+        audit
+            .record_config_change("config_set", &after, &after)
+            .unwrap();
+        audit
+            .record("provider_change", "transcribe groq -> whisper", Some("abc"))
+            .unwrap();
+
+        let log = fs::read_to_string(temp.path().join("audit.log")).unwrap();
+        let lines: Vec<serde_json::Value> = log
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        let detail = lines[0]["detail"].as_str().unwrap();
+        assert!(detail.contains("summarize.active=\"cloud\""));
+        assert!(detail.contains("summarize.cloud.api_key=\"<redacted>\""));
+        assert!(!log.contains("secret"));
+        assert_eq!(lines[1]["session_id"], "abc");
+    }
+}
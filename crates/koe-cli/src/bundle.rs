@@ -0,0 +1,166 @@
+use crate::config::{Config, ConfigError, ConfigPaths};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use uuid::Uuid;
+
+const BUNDLE_CONFIG_FILE: &str = "config.toml";
+/// Shared resources under `~/.koe` copied into bundles when present.
+const BUNDLE_RESOURCES: [&str; 3] = ["prompts", "templates", "glossary.txt"];
+
+/// Package config (without API keys) and shared resources into a `.tar.gz` at `dest`.
+pub fn export_bundle(
+    paths: &ConfigPaths,
+    config: &Config,
+    dest: &Path,
+) -> Result<Vec<String>, ConfigError> {
+    let staging = staging_dir("export")?;
+    let result = stage_bundle(paths, config, &staging).and_then(|entries| {
+        run_tar(
+            Command::new("tar")
+                .arg("-czf")
+                .arg(dest)
+                .arg("-C")
+                .arg(&staging)
+                .args(&entries),
+        )?;
+        Ok(entries)
+    });
+    let _ = fs::remove_dir_all(&staging);
+    result
+}
+
+/// Unpack a bundle over the local setup, keeping this machine's API keys.
+///
+/// The previous config is kept as `config.toml.bak`.
+pub fn import_bundle(paths: &ConfigPaths, src: &Path) -> Result<Vec<String>, ConfigError> {
+    let staging = staging_dir("import")?;
+    let result = run_tar(
+        Command::new("tar")
+            .arg("-xzf")
+            .arg(src)
+            .arg("-C")
+            .arg(&staging),
+    )
+    .and_then(|()| apply_staged(paths, &staging));
+    let _ = fs::remove_dir_all(&staging);
+    result
+}
+
+fn stage_bundle(
+    paths: &ConfigPaths,
+    config: &Config,
+    staging: &Path,
+) -> Result<Vec<String>, ConfigError> {
+    let content = toml::to_string_pretty(&config.without_secrets())?;
+    fs::write(staging.join(BUNDLE_CONFIG_FILE), content)?;
+    let mut entries = vec![BUNDLE_CONFIG_FILE.to_string()];
+    for name in BUNDLE_RESOURCES {
+        let source = paths.base_dir.join(name);
+        if source.exists() {
+            copy_recursive(&source, &staging.join(name))?;
+            entries.push(name.to_string());
+        }
+    }
+    Ok(entries)
+}
+
+fn apply_staged(paths: &ConfigPaths, staging: &Path) -> Result<Vec<String>, ConfigError> {
+    let content = fs::read_to_string(staging.join(BUNDLE_CONFIG_FILE))
+        .map_err(|_| ConfigError::Bundle(format!("bundle is missing {BUNDLE_CONFIG_FILE}")))?;
+    let mut imported: Config = toml::from_str(&content)?;
+    imported.validate()?;
+
+    let mut entries = vec![BUNDLE_CONFIG_FILE.to_string()];
+    if paths.config_path.exists() {
+        let current = Config::load(paths)?;
+        imported.restore_secrets(&current);
+        fs::copy(&paths.config_path, backup_path(&paths.config_path))?;
+    }
+    Config::write(paths, &imported)?;
+
+    for name in BUNDLE_RESOURCES {
+        let source = staging.join(name);
+        if source.exists() {
+            copy_recursive(&source, &paths.base_dir.join(name))?;
+            entries.push(name.to_string());
+        }
+    }
+    Ok(entries)
+}
+
+fn copy_recursive(source: &Path, dest: &Path) -> Result<(), ConfigError> {
+    if source.is_dir() {
+        fs::create_dir_all(dest)?;
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &dest.join(entry.file_name()))?;
+        }
+    } else {
+        fs::copy(source, dest)?;
+    }
+    Ok(())
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
+
+fn staging_dir(label: &str) -> Result<PathBuf, ConfigError> {
+    let dir = std::env::temp_dir().join(format!("koe-bundle-{label}-{}", Uuid::now_v7()));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn run_tar(command: &mut Command) -> Result<(), ConfigError> {
+    let output = command
+        .output()
+        .map_err(|e| ConfigError::Bundle(format!("failed to run tar: {e}")))?;
+    if !output.status.success() {
+        return Err(ConfigError::Bundle(format!(
+            "tar failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_staged, stage_bundle};
+    use crate::config::{Config, ConfigPaths};
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn staged_bundle_round_trips_without_secrets() {
+        let temp = tempdir().unwrap();
+        let source = ConfigPaths::from_base(temp.path().join("source"));
+        let mut config = Config::load_or_create(&source).unwrap();
+        config.session.context = "weekly sync".to_string();
+        config.summarize.cloud.api_key = "shared-secret".to_string();
+        fs::create_dir_all(source.base_dir.join("prompts")).unwrap();
+        fs::write(source.base_dir.join("prompts/standup.txt"), "summarize").unwrap();
+
+        let staging = temp.path().join("staging");
+        fs::create_dir_all(&staging).unwrap();
+        let entries = stage_bundle(&source, &config, &staging).unwrap();
+        assert_eq!(entries, vec!["config.toml", "prompts"]);
+        let staged = fs::read_to_string(staging.join("config.toml")).unwrap();
+        assert!(!staged.contains("shared-secret"));
+
+        let target = ConfigPaths::from_base(temp.path().join("target"));
+        let mut local = Config::load_or_create(&target).unwrap();
+        local.summarize.cloud.api_key = "local-secret".to_string();
+        Config::write(&target, &local).unwrap();
+
+        apply_staged(&target, &staging).unwrap();
+        let imported = Config::load(&target).unwrap();
+        assert_eq!(imported.session.context, "weekly sync");
+        assert_eq!(imported.summarize.cloud.api_key, "local-secret");
+        assert!(target.base_dir.join("prompts/standup.txt").exists());
+        assert!(target.base_dir.join("config.toml.bak").exists());
+    }
+}
@@ -0,0 +1,555 @@
+use crate::config::CalendarConfig;
+use std::collections::HashSet;
+use std::fs;
+use std::process::Command;
+use std::time::Duration;
+use thiserror::Error;
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+/// Longest event description copied into the meeting context.
+const MAX_DESCRIPTION_CHARS: usize = 500;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+#[derive(Debug, Error)]
+pub enum CalendarError {
+    #[error("calendar read error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("calendar fetch error: {0}")]
+    Fetch(String),
+}
+
+/// One occurrence of a calendar event, times in Unix seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    pub title: String,
+    pub description: String,
+    pub participants: Vec<String>,
+    pub start: i64,
+    pub end: i64,
+}
+
+impl CalendarEvent {
+    /// Meeting context: the title, then the start of the description.
+    pub fn context(&self) -> String {
+        let description = self.description.trim();
+        if description.is_empty() {
+            return self.title.clone();
+        }
+        let description: String = description.chars().take(MAX_DESCRIPTION_CHARS).collect();
+        format!("{}\n\n{description}", self.title)
+    }
+}
+
+/// The event running now, or starting within `calendar.early_minutes`, in the
+/// configured ICS calendar. `None` when no calendar is configured.
+pub fn current_event(config: &CalendarConfig) -> Result<Option<CalendarEvent>, CalendarError> {
+    let source = config.ics.trim();
+    if source.is_empty() {
+        return Ok(None);
+    }
+    let ics = read_source(source)?;
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let events = parse_ics(&ics, local_offset());
+    Ok(event_at(&events, now, i64::from(config.early_minutes) * 60))
+}
+
+fn read_source(source: &str) -> Result<String, CalendarError> {
+    let url = match source.strip_prefix("webcal://") {
+        Some(rest) => format!("https://{rest}"),
+        None => source.to_string(),
+    };
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Ok(fs::read_to_string(source)?);
+    }
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(FETCH_TIMEOUT))
+        .build()
+        .into();
+    agent
+        .get(&url)
+        .call()
+        .map_err(|e| CalendarError::Fetch(e.to_string()))?
+        .into_body()
+        .read_to_string()
+        .map_err(|e| CalendarError::Fetch(e.to_string()))
+}
+
+/// Floating and `TZID` times are read in the machine's current zone; the
+/// `time` crate cannot look it up safely from a multi-threaded process.
+pub(crate) fn local_offset() -> UtcOffset {
+    Command::new("date")
+        .arg("+%z")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .and_then(|value| parse_utc_offset(value.trim()))
+        .unwrap_or(UtcOffset::UTC)
+}
+
+fn parse_utc_offset(value: &str) -> Option<UtcOffset> {
+    let sign = match value.get(..1)? {
+        "+" => 1,
+        "-" => -1,
+        _ => return None,
+    };
+    let hours: i32 = value.get(1..3)?.parse().ok()?;
+    let minutes: i32 = value.get(3..5)?.parse().ok()?;
+    UtcOffset::from_whole_seconds(sign * (hours * 3600 + minutes * 60)).ok()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frequency {
+    Daily,
+    Weekly,
+}
+
+/// The subset of `RRULE` that meeting series use. Other frequencies leave the
+/// event as a single occurrence.
+#[derive(Debug, Clone)]
+struct Recurrence {
+    frequency: Frequency,
+    interval: i64,
+    until: Option<i64>,
+    count: Option<usize>,
+    by_day: Vec<Weekday>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ParsedEvent {
+    uid: String,
+    title: String,
+    description: String,
+    participants: Vec<String>,
+    start: Option<OffsetDateTime>,
+    end: Option<i64>,
+    duration: Option<i64>,
+    all_day: bool,
+    cancelled: bool,
+    recurrence: Option<Recurrence>,
+    excluded: Vec<i64>,
+    /// Set on an edited instance of a series; replaces that occurrence.
+    recurrence_id: Option<i64>,
+}
+
+fn parse_ics(ics: &str, local: UtcOffset) -> Vec<ParsedEvent> {
+    let mut events = Vec::new();
+    let mut current: Option<ParsedEvent> = None;
+    for line in unfold(ics) {
+        let Some((name, params, value)) = split_property(&line) else {
+            continue;
+        };
+        match (name.as_str(), current.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VEVENT") => {
+                current = Some(ParsedEvent::default());
+            }
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                events.extend(current.take());
+            }
+            (_, Some(event)) => apply_property(event, &name, &params, value, local),
+            _ => {}
+        }
+    }
+    events
+}
+
+fn apply_property(
+    event: &mut ParsedEvent,
+    name: &str,
+    params: &[(String, String)],
+    value: &str,
+    local: UtcOffset,
+) {
+    match name {
+        "UID" => event.uid = value.to_string(),
+        "SUMMARY" => event.title = unescape(value),
+        "DESCRIPTION" => event.description = unescape(value),
+        "STATUS" => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+        "DTSTART" => match parse_stamp(value, local) {
+            Some(Stamp::At(start)) => event.start = Some(start),
+            Some(Stamp::AllDay) => event.all_day = true,
+            None => {}
+        },
+        "DTEND" => {
+            if let Some(Stamp::At(end)) = parse_stamp(value, local) {
+                event.end = Some(end.unix_timestamp());
+            }
+        }
+        "DURATION" => event.duration = parse_duration(value),
+        "RRULE" => event.recurrence = parse_recurrence(value, local),
+        "EXDATE" => {
+            event.excluded.extend(value.split(',').filter_map(|value| {
+                match parse_stamp(value, local)? {
+                    Stamp::At(at) => Some(at.unix_timestamp()),
+                    Stamp::AllDay => None,
+                }
+            }))
+        }
+        "RECURRENCE-ID" => {
+            if let Some(Stamp::At(at)) = parse_stamp(value, local) {
+                event.recurrence_id = Some(at.unix_timestamp());
+            }
+        }
+        "ATTENDEE" | "ORGANIZER" => {
+            if let Some(participant) = participant_name(params, value)
+                && !event.participants.contains(&participant)
+            {
+                event.participants.push(participant);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Pick the current occurrence; when several overlap, the latest to start wins.
+fn event_at(events: &[ParsedEvent], now: i64, early: i64) -> Option<CalendarEvent> {
+    let replaced: HashSet<(&str, i64)> = events
+        .iter()
+        .filter_map(|event| Some((event.uid.as_str(), event.recurrence_id?)))
+        .collect();
+    let mut best: Option<CalendarEvent> = None;
+    for event in events {
+        if event.cancelled || event.all_day {
+            continue;
+        }
+        let Some(start) = event.start else {
+            continue;
+        };
+        let length = event
+            .end
+            .map(|end| end - start.unix_timestamp())
+            .or(event.duration)
+            .unwrap_or(0)
+            .max(0);
+        let starts = match (&event.recurrence, event.recurrence_id) {
+            (Some(recurrence), None) => occurrences(start, recurrence, now + early),
+            _ => vec![start.unix_timestamp()],
+        };
+        for occurrence in starts {
+            let skipped = event.excluded.contains(&occurrence)
+                || (event.recurrence_id.is_none()
+                    && replaced.contains(&(event.uid.as_str(), occurrence)));
+            let current = occurrence - early <= now && now < occurrence + length;
+            if skipped || !current || best.as_ref().is_some_and(|best| best.start >= occurrence) {
+                continue;
+            }
+            best = Some(CalendarEvent {
+                title: event.title.trim().to_string(),
+                description: event.description.clone(),
+                participants: event.participants.clone(),
+                start: occurrence,
+                end: occurrence + length,
+            });
+        }
+    }
+    best
+}
+
+/// Occurrence starts of a series up to `horizon`, stepping one day at a time.
+fn occurrences(start: OffsetDateTime, recurrence: &Recurrence, horizon: i64) -> Vec<i64> {
+    let first_monday =
+        start.date() - time::Duration::days(start.weekday().number_days_from_monday().into());
+    let by_day = if recurrence.by_day.is_empty() {
+        vec![start.weekday()]
+    } else {
+        recurrence.by_day.clone()
+    };
+    let mut starts = Vec::new();
+    let mut produced = 0;
+    for day in 0.. {
+        let at = start + time::Duration::days(day);
+        let timestamp = at.unix_timestamp();
+        if timestamp > horizon || recurrence.until.is_some_and(|until| timestamp > until) {
+            break;
+        }
+        let included = match recurrence.frequency {
+            Frequency::Daily => day % recurrence.interval == 0,
+            Frequency::Weekly => {
+                let week = (at.date() - first_monday).whole_days() / 7;
+                week % recurrence.interval == 0 && by_day.contains(&at.weekday())
+            }
+        };
+        if !included {
+            continue;
+        }
+        if recurrence.count.is_some_and(|count| produced >= count) {
+            break;
+        }
+        produced += 1;
+        starts.push(timestamp);
+    }
+    starts
+}
+
+fn parse_recurrence(value: &str, local: UtcOffset) -> Option<Recurrence> {
+    let mut recurrence = Recurrence {
+        frequency: Frequency::Daily,
+        interval: 1,
+        until: None,
+        count: None,
+        by_day: Vec::new(),
+    };
+    let mut supported = false;
+    for part in value.split(';') {
+        let Some((key, value)) = part.split_once('=') else {
+            continue;
+        };
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                (recurrence.frequency, supported) = match value.to_ascii_uppercase().as_str() {
+                    "DAILY" => (Frequency::Daily, true),
+                    "WEEKLY" => (Frequency::Weekly, true),
+                    _ => return None,
+                };
+            }
+            "INTERVAL" => recurrence.interval = value.parse().ok().filter(|n| *n > 0)?,
+            "COUNT" => recurrence.count = value.parse().ok(),
+            "UNTIL" => {
+                recurrence.until = match parse_stamp(value, local)? {
+                    Stamp::At(at) => Some(at.unix_timestamp()),
+                    // A date-only bound includes that whole day.
+                    Stamp::AllDay => parse_date(value).map(|date| {
+                        date.midnight().assume_offset(local).unix_timestamp() + SECONDS_PER_DAY
+                    }),
+                };
+            }
+            "BYDAY" => {
+                recurrence.by_day = value
+                    .split(',')
+                    .filter_map(|day| weekday(day.get(day.len().saturating_sub(2)..)?))
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+    supported.then_some(recurrence)
+}
+
+fn weekday(code: &str) -> Option<Weekday> {
+    Some(match code.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Monday,
+        "TU" => Weekday::Tuesday,
+        "WE" => Weekday::Wednesday,
+        "TH" => Weekday::Thursday,
+        "FR" => Weekday::Friday,
+        "SA" => Weekday::Saturday,
+        "SU" => Weekday::Sunday,
+        _ => return None,
+    })
+}
+
+enum Stamp {
+    At(OffsetDateTime),
+    AllDay,
+}
+
+/// `20261017T140000Z` is UTC, `20261017T140000` local, `20261017` all-day.
+fn parse_stamp(value: &str, local: UtcOffset) -> Option<Stamp> {
+    let value = value.trim();
+    let date = parse_date(value)?;
+    let Some(clock) = value.get(8..).and_then(|rest| rest.strip_prefix('T')) else {
+        return Some(Stamp::AllDay);
+    };
+    let (clock, offset) = match clock.strip_suffix('Z') {
+        Some(clock) => (clock, UtcOffset::UTC),
+        None => (clock, local),
+    };
+    let number = |range: std::ops::Range<usize>| clock.get(range)?.parse::<u8>().ok();
+    let time = Time::from_hms(number(0..2)?, number(2..4)?, number(4..6)?).ok()?;
+    Some(Stamp::At(
+        PrimitiveDateTime::new(date, time).assume_offset(offset),
+    ))
+}
+
+fn parse_date(value: &str) -> Option<Date> {
+    let year: i32 = value.get(0..4)?.parse().ok()?;
+    let month: u8 = value.get(4..6)?.parse().ok()?;
+    let day: u8 = value.get(6..8)?.parse().ok()?;
+    Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()
+}
+
+/// ISO 8601 duration such as `PT1H30M` or `P1D`, in seconds.
+fn parse_duration(value: &str) -> Option<i64> {
+    let rest = value
+        .trim()
+        .trim_start_matches(['+', '-'])
+        .strip_prefix('P')?;
+    let mut seconds = 0;
+    let mut number = String::new();
+    for ch in rest.chars() {
+        match ch {
+            '0'..='9' => number.push(ch),
+            'T' => {}
+            unit => {
+                let value: i64 = std::mem::take(&mut number).parse().ok()?;
+                seconds += value
+                    * match unit {
+                        'W' => 7 * SECONDS_PER_DAY,
+                        'D' => SECONDS_PER_DAY,
+                        'H' => 3600,
+                        'M' => 60,
+                        'S' => 1,
+                        _ => return None,
+                    };
+            }
+        }
+    }
+    Some(seconds)
+}
+
+/// People only: rooms, resources, declined and non-participant entries are skipped.
+fn participant_name(params: &[(String, String)], value: &str) -> Option<String> {
+    let param = |name: &str| {
+        params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.to_ascii_uppercase())
+    };
+    if matches!(param("CUTYPE").as_deref(), Some("ROOM" | "RESOURCE"))
+        || param("PARTSTAT").as_deref() == Some("DECLINED")
+        || param("ROLE").as_deref() == Some("NON-PARTICIPANT")
+    {
+        return None;
+    }
+    let name = params
+        .iter()
+        .find(|(key, _)| key == "CN")
+        .map(|(_, name)| name.trim().to_string())
+        .filter(|name| !name.is_empty() && !name.contains('@'))
+        .or_else(|| {
+            let address = value
+                .get(..7)
+                .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+                .map_or(value, |_| &value[7..]);
+            address.split('@').next().map(str::to_string)
+        })?;
+    (!name.is_empty()).then_some(name)
+}
+
+/// Join folded continuation lines (leading space or tab).
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Property name, `KEY=value` parameters, and value.
+type Property<'a> = (String, Vec<(String, String)>, &'a str);
+
+/// `NAME;KEY=value;...:VALUE`, with the name and parameter keys upper-cased.
+fn split_property(line: &str) -> Option<Property<'_>> {
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(index, ch)| match ch {
+        '"' => {
+            quoted = !quoted;
+            None
+        }
+        ':' if !quoted => Some(index),
+        _ => None,
+    })?;
+    let mut head = line[..colon].split(';');
+    let name = head.next()?.trim().to_ascii_uppercase();
+    let params = head
+        .filter_map(|param| param.split_once('='))
+        .map(|(key, value)| {
+            (
+                key.trim().to_ascii_uppercase(),
+                value.trim().trim_matches('"').to_string(),
+            )
+        })
+        .collect();
+    Some((name, params, &line[colon + 1..]))
+}
+
+fn unescape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            output.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => output.push('\n'),
+            Some(other) => output.push(other),
+            None => {}
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{event_at, parse_ics};
+    use time::UtcOffset;
+
+    const ICS: &str = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+SUMMARY:Daily standup\r\n\
+DTSTART:20261012T090000\r\n\
+DURATION:PT15M\r\n\
+RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR\r\n\
+EXDATE:20261016T090000\r\n\
+ATTENDEE;CN=Alice Smith;PARTSTAT=ACCEPTED:mailto:alice@example.com\r\n\
+ATTENDEE;CN=Room 4;CUTYPE=ROOM:mailto:room4@example.com\r\n\
+ATTENDEE;PARTSTAT=DECLINED;CN=Carol:mailto:carol@example.com\r\n\
+ORGANIZER:mailto:bob@example.com\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+RECURRENCE-ID:20261014T090000\r\n\
+SUMMARY:Daily standup (moved)\r\n\
+DTSTART:20261014T100000\r\n\
+DTEND:20261014T101500\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:review\r\n\
+SUMMARY:Design review\\, Q4\r\n\
+DESCRIPTION:Agenda:\\n- latency\r\n \\n- pricing\r\n\
+DTSTART:20261013T143000Z\r\n\
+DTEND:20261013T153000Z\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    /// 2026-10-12 00:00 at UTC+02:00, in Unix seconds.
+    const MONDAY: i64 = 1_791_756_000;
+
+    fn at(day: i64, hour: i64, minute: i64) -> i64 {
+        MONDAY + day * 86_400 + hour * 3600 + minute * 60
+    }
+
+    #[test]
+    fn finds_current_occurrence_of_series_and_single_events() {
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        let events = parse_ics(ICS, offset);
+        let early = 10 * 60;
+
+        let standup = event_at(&events, at(0, 9, 5), early).expect("monday standup");
+        assert_eq!(standup.title, "Daily standup");
+        assert_eq!(standup.participants, vec!["Alice Smith", "bob"]);
+        assert_eq!(standup.end - standup.start, 15 * 60);
+        // Starting soon counts; Tuesday is not in BYDAY.
+        assert!(event_at(&events, at(0, 8, 55), early).is_some());
+        assert!(event_at(&events, at(1, 9, 5), early).is_none());
+        // Wednesday's instance was moved an hour later; Friday's was cancelled.
+        assert!(event_at(&events, at(2, 9, 5), early).is_none());
+        let moved = event_at(&events, at(2, 10, 5), early).expect("moved standup");
+        assert_eq!(moved.title, "Daily standup (moved)");
+        assert!(event_at(&events, at(4, 9, 5), early).is_none());
+        assert!(event_at(&events, at(7, 9, 5), early).is_some());
+
+        // 14:30 UTC is 16:30 at UTC+02:00.
+        let review = event_at(&events, at(1, 17, 0), early).expect("review");
+        assert_eq!(review.title, "Design review, Q4");
+        assert_eq!(
+            review.context(),
+            "Design review, Q4\n\nAgenda:\n- latency\n- pricing"
+        );
+    }
+}
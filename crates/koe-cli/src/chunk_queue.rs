@@ -0,0 +1,196 @@
+use koe_core::transcribe::{decode_wav, encode_wav};
+use koe_core::types::{AudioChunk, AudioSource};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// First wait before retrying queued chunks after a failure; doubles up to the cap.
+const RETRY_BASE: Duration = Duration::from_secs(5);
+const RETRY_MAX: Duration = Duration::from_secs(120);
+
+/// A queued chunk and the WAV it was loaded from.
+pub struct QueuedChunk {
+    pub path: PathBuf,
+    pub chunk: AudioChunk,
+}
+
+/// Bounded on-disk queue of chunks that failed to transcribe while offline,
+/// stored as `<start_pts_ns>-<source>.wav` so the oldest is retried first.
+pub struct ChunkQueue {
+    dir: PathBuf,
+    capacity: usize,
+    failures: u32,
+    retry_at: Instant,
+}
+
+impl ChunkQueue {
+    pub fn new(dir: PathBuf, capacity: usize) -> Self {
+        Self {
+            dir,
+            capacity,
+            failures: 0,
+            retry_at: Instant::now(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.paths().map(|paths| paths.len()).unwrap_or(0)
+    }
+
+    /// Store `chunk`; fails without writing when the queue is full.
+    pub fn push(&mut self, chunk: &AudioChunk, now: Instant) -> io::Result<()> {
+        if self.len() >= self.capacity {
+            return Err(io::Error::other(format!(
+                "offline queue full ({} chunks)",
+                self.capacity
+            )));
+        }
+        fs::create_dir_all(&self.dir)?;
+        let name = format!(
+            "{:020}-{}.wav",
+            chunk.start_pts_ns.max(0),
+            source_label(chunk.source)
+        );
+        fs::write(
+            self.dir.join(name),
+            encode_wav(&chunk.pcm_mono_f32, chunk.sample_rate_hz),
+        )?;
+        self.record_failure(now);
+        Ok(())
+    }
+
+    /// Oldest queued chunk when a retry is due.
+    pub fn next_due(&self, now: Instant) -> io::Result<Option<QueuedChunk>> {
+        if now < self.retry_at {
+            return Ok(None);
+        }
+        let Some(path) = self.paths()?.into_iter().next() else {
+            return Ok(None);
+        };
+        let chunk = read_chunk(&path)?;
+        Ok(Some(QueuedChunk { path, chunk }))
+    }
+
+    /// The provider answered, so queued chunks are retried right away.
+    pub fn record_success(&mut self, now: Instant) {
+        self.failures = 0;
+        self.retry_at = now;
+    }
+
+    /// Drop a chunk once it is transcribed, or when it can never be.
+    pub fn remove(&self, path: &Path) -> io::Result<()> {
+        fs::remove_file(path)
+    }
+
+    /// Back off exponentially before the next retry.
+    pub fn record_failure(&mut self, now: Instant) {
+        let delay = RETRY_BASE
+            .saturating_mul(1 << self.failures.min(5))
+            .min(RETRY_MAX);
+        self.failures = self.failures.saturating_add(1);
+        self.retry_at = now + delay;
+    }
+
+    fn paths(&self) -> io::Result<Vec<PathBuf>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "wav"))
+            .collect();
+        paths.sort();
+        Ok(paths)
+    }
+}
+
+fn read_chunk(path: &Path) -> io::Result<AudioChunk> {
+    let invalid = || io::Error::other(format!("unreadable queued chunk {}", path.display()));
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(invalid)?;
+    let (pts, source) = stem.split_once('-').ok_or_else(invalid)?;
+    let start_pts_ns = pts.parse().map_err(|_| invalid())?;
+    let source = parse_source(source).ok_or_else(invalid)?;
+    let (pcm_mono_f32, sample_rate_hz) = decode_wav(&fs::read(path)?).ok_or_else(invalid)?;
+    Ok(AudioChunk {
+        source,
+        start_pts_ns,
+        sample_rate_hz,
+        pcm_mono_f32,
+    })
+}
+
+fn source_label(source: AudioSource) -> &'static str {
+    match source {
+        AudioSource::System => "system",
+        AudioSource::Microphone => "microphone",
+        AudioSource::Mixed => "mixed",
+    }
+}
+
+fn parse_source(label: &str) -> Option<AudioSource> {
+    match label {
+        "system" => Some(AudioSource::System),
+        "microphone" => Some(AudioSource::Microphone),
+        "mixed" => Some(AudioSource::Mixed),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChunkQueue;
+    use koe_core::types::{AudioChunk, AudioSource};
+    use std::fs;
+    use std::time::{Duration, Instant};
+
+    fn chunk(start_pts_ns: i128, source: AudioSource) -> AudioChunk {
+        AudioChunk {
+            source,
+            start_pts_ns,
+            sample_rate_hz: 16_000,
+            pcm_mono_f32: vec![0.25, -0.5],
+        }
+    }
+
+    #[test]
+    fn queue_is_bounded_ordered_and_backs_off() {
+        let dir = std::env::temp_dir().join(format!("koe-chunk-queue-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let mut queue = ChunkQueue::new(dir.clone(), 2);
+        let now = Instant::now();
+
+        queue
+            .push(&chunk(9_000_000_000, AudioSource::System), now)
+            .unwrap();
+        queue
+            .push(&chunk(2_000_000_000, AudioSource::Microphone), now)
+            .unwrap();
+        assert!(
+            queue
+                .push(&chunk(12_000_000_000, AudioSource::System), now)
+                .is_err()
+        );
+        assert!(queue.next_due(now).unwrap().is_none());
+
+        let later = now + Duration::from_secs(600);
+        let oldest = queue.next_due(later).unwrap().unwrap();
+        assert_eq!(oldest.chunk.start_pts_ns, 2_000_000_000);
+        assert_eq!(oldest.chunk.source, AudioSource::Microphone);
+        assert_eq!(oldest.chunk.pcm_mono_f32, vec![0.25, -0.5]);
+
+        queue.remove(&oldest.path).unwrap();
+        queue.record_success(now);
+        assert_eq!(queue.len(), 1);
+        let next = queue.next_due(now).unwrap().unwrap();
+        assert_eq!(next.chunk.start_pts_ns, 9_000_000_000);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,83 @@
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+/// Clipboard tools tried in order; the first one installed is used.
+#[cfg(target_os = "macos")]
+const CLIPBOARD_COMMANDS: &[&[&str]] = &[&["pbcopy"]];
+#[cfg(target_os = "windows")]
+const CLIPBOARD_COMMANDS: &[&[&str]] = &[&["clip"]];
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const CLIPBOARD_COMMANDS: &[&[&str]] = &[
+    &["wl-copy"],
+    &["xclip", "-selection", "clipboard"],
+    &["xsel", "--clipboard", "--input"],
+];
+
+/// Put `text` on the system clipboard.
+pub fn copy(text: &str) -> io::Result<()> {
+    copy_with(CLIPBOARD_COMMANDS, text)
+}
+
+fn copy_with(commands: &[&[&str]], text: &str) -> io::Result<()> {
+    for command in commands {
+        match pipe_to(command, text) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            result => return result,
+        }
+    }
+    let tools: Vec<&str> = commands
+        .iter()
+        .filter_map(|command| command.first().copied())
+        .collect();
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("no clipboard tool found (tried {})", tools.join(", ")),
+    ))
+}
+
+fn pipe_to(command: &[&str], text: &str) -> io::Result<()> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "empty clipboard command"))?;
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("{program} exited with {status}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::copy_with;
+
+    #[test]
+    fn falls_through_to_the_first_installed_tool() {
+        let temp = tempfile::tempdir().unwrap();
+        let out = temp.path().join("clipboard.txt");
+        let script = format!("cat > '{}'", out.display());
+
+        copy_with(
+            &[&["koe-missing-clipboard-tool"], &["sh", "-c", &script]],
+            "# Notes\n- ship Friday\n",
+        )
+        .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&out).unwrap(),
+            "# Notes\n- ship Friday\n"
+        );
+
+        let err = copy_with(&[&["koe-missing-clipboard-tool"]], "x").unwrap_err();
+        assert!(err.to_string().contains("koe-missing-clipboard-tool"));
+        assert!(copy_with(&[&["sh", "-c", "exit 3"]], "x").is_err());
+    }
+}
@@ -1,10 +1,15 @@
+use koe_core::summarize::summarize_provider_names;
+use koe_core::transcribe::transcribe_provider_names;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 const CONFIG_VERSION: u32 = 5;
+/// Longest chunk whisper transcribes in one window.
+const MAX_CHUNK_MS: u32 = 30_000;
 
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
@@ -21,6 +26,8 @@ pub enum ConfigError {
     Serialize(#[from] toml::ser::Error),
     #[error("config validation error: {0}")]
     Validation(String),
+    #[error("config bundle error: {0}")]
+    Bundle(String),
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +36,18 @@ pub struct ConfigPaths {
     pub config_path: PathBuf,
     pub models_dir: PathBuf,
     pub sessions_dir: PathBuf,
+    /// Lifecycle hook executables (`on-meeting-start`, `on-finalize`, `on-note-added`).
+    pub hooks_dir: PathBuf,
+    /// Custom live-notes prompt templates, `<name>.txt` selected by `summarize.prompt_profile`.
+    pub prompts_dir: PathBuf,
+    /// Transcript corrections learned from user edits.
+    pub vocabulary_path: PathBuf,
+    /// Per-meeting usage counts for `koe stats --usage`; never leaves the machine unless shared.
+    pub metrics_path: PathBuf,
+    /// Action items tracked across sessions by `koe actions`.
+    pub actions_path: PathBuf,
+    /// Bearer token for the local HTTP API, rewritten each launch.
+    pub http_token_path: PathBuf,
 }
 
 impl ConfigPaths {
@@ -41,11 +60,35 @@ impl ConfigPaths {
         let config_path = base_dir.join("config.toml");
         let models_dir = base_dir.join("models");
         let sessions_dir = base_dir.join("sessions");
+        let hooks_dir = base_dir.join("hooks");
+        let prompts_dir = base_dir.join("prompts");
+        let vocabulary_path = base_dir.join("vocabulary.json");
+        let metrics_path = base_dir.join("metrics.jsonl");
+        let actions_path = base_dir.join("actions.json");
+        let http_token_path = base_dir.join("http.token");
         Self {
             base_dir,
             config_path,
             models_dir,
             sessions_dir,
+            hooks_dir,
+            prompts_dir,
+            vocabulary_path,
+            metrics_path,
+            actions_path,
+            http_token_path,
+        }
+    }
+
+    /// Paths with sessions stored in the workspace's own directory.
+    pub fn for_workspace(&self, name: &str, workspace: &WorkspaceConfig) -> Self {
+        let sessions_dir = match workspace.sessions_dir.trim() {
+            "" => self.base_dir.join("workspaces").join(name).join("sessions"),
+            value => self.base_dir.join(value),
+        };
+        Self {
+            sessions_dir,
+            ..self.clone()
         }
     }
 }
@@ -55,10 +98,27 @@ impl ConfigPaths {
 pub struct Config {
     pub version: u32,
     pub audio: AudioConfig,
+    pub process: ProcessConfig,
     pub transcribe: TranscribeConfig,
     pub summarize: SummarizeConfig,
     pub session: SessionConfig,
     pub ui: UiConfig,
+    pub limits: LimitsConfig,
+    pub webhooks: WebhooksConfig,
+    pub review: ReviewConfig,
+    pub http: HttpConfig,
+    pub outputs: OutputsConfig,
+    pub translate: TranslateConfig,
+    pub calendar: CalendarConfig,
+    pub detect: DetectConfig,
+    pub metrics: MetricsConfig,
+    pub redact: RedactConfig,
+    pub integrations: IntegrationsConfig,
+    pub sync: SyncConfig,
+    /// Named per-client/project overrides selected with `--workspace`.
+    pub workspaces: BTreeMap<String, WorkspaceConfig>,
+    /// Named meeting-type presets selected with `--profile` or from the palette.
+    pub profiles: BTreeMap<String, MeetingProfileConfig>,
 }
 
 impl Default for Config {
@@ -66,10 +126,25 @@ impl Default for Config {
         Self {
             version: CONFIG_VERSION,
             audio: AudioConfig::default(),
+            process: ProcessConfig::default(),
             transcribe: TranscribeConfig::default(),
             summarize: SummarizeConfig::default(),
             session: SessionConfig::default(),
             ui: UiConfig::default(),
+            limits: LimitsConfig::default(),
+            webhooks: WebhooksConfig::default(),
+            review: ReviewConfig::default(),
+            http: HttpConfig::default(),
+            outputs: OutputsConfig::default(),
+            translate: TranslateConfig::default(),
+            calendar: CalendarConfig::default(),
+            detect: DetectConfig::default(),
+            metrics: MetricsConfig::default(),
+            redact: RedactConfig::default(),
+            integrations: IntegrationsConfig::default(),
+            sync: SyncConfig::default(),
+            workspaces: BTreeMap::new(),
+            profiles: BTreeMap::new(),
         }
     }
 }
@@ -81,7 +156,11 @@ pub struct AudioConfig {
     pub channels: u16,
     pub sources: Vec<String>,
     pub microphone_device_id: String,
+    pub multitrack: bool,
+    pub archive_format: String,
     pub mixdown: MixdownConfig,
+    /// Check capture levels and the transcriber for 2s before a meeting starts.
+    pub health_check: bool,
 }
 
 impl Default for AudioConfig {
@@ -91,7 +170,10 @@ impl Default for AudioConfig {
             channels: 1,
             sources: vec!["system".to_string(), "microphone".to_string()],
             microphone_device_id: String::new(),
+            multitrack: false,
+            archive_format: "wav".to_string(),
             mixdown: MixdownConfig::default(),
+            health_check: true,
         }
     }
 }
@@ -168,12 +250,77 @@ impl Default for HighPassConfig {
     }
 }
 
+/// Speech detection and chunking; see `koe_core::process::ProcessConfig`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProcessConfig {
+    /// Speech detector: `silero` (neural, default) or `energy` (level over the noise floor).
+    pub vad: String,
+    /// Speech probability (0..1) a frame needs; raise for noisy rooms.
+    pub vad_threshold: f32,
+    pub vad_min_speech_ms: u32,
+    /// Pause allowed inside speech; lower for rapid back-and-forth.
+    pub vad_hangover_ms: u32,
+    pub chunk_min_ms: u32,
+    pub chunk_target_ms: u32,
+    /// Chunks are force-emitted (with overlap) at this length during continuous
+    /// speech, bounding transcript latency for long monologues.
+    pub chunk_max_ms: u32,
+    pub chunk_overlap_ms: u32,
+    /// Split system-audio chunks at pauses where the voice changes, so each
+    /// transcription holds one remote speaker.
+    pub split_turns: bool,
+    /// Measure the mic noise floor for ~3 s at meeting start and retune the VAD
+    /// threshold and mixdown denoise gate to it.
+    pub calibrate: bool,
+}
+
+impl Default for ProcessConfig {
+    fn default() -> Self {
+        let core = koe_core::process::ProcessConfig::default();
+        Self {
+            vad: "silero".to_string(),
+            vad_threshold: core.vad_threshold,
+            vad_min_speech_ms: core.vad_min_speech_ms,
+            vad_hangover_ms: core.vad_hangover_ms,
+            chunk_min_ms: core.chunk_min_ms,
+            chunk_target_ms: core.chunk_target_ms,
+            chunk_max_ms: core.chunk_max_ms,
+            chunk_overlap_ms: core.chunk_overlap_ms,
+            split_turns: core.split_turns,
+            calibrate: false,
+        }
+    }
+}
+
+impl ProcessConfig {
+    pub fn to_core(&self) -> koe_core::process::ProcessConfig {
+        koe_core::process::ProcessConfig {
+            vad: koe_core::process::vad::VadKind::parse(&self.vad).unwrap_or_default(),
+            vad_threshold: self.vad_threshold,
+            vad_min_speech_ms: self.vad_min_speech_ms,
+            vad_hangover_ms: self.vad_hangover_ms,
+            chunk_min_ms: self.chunk_min_ms,
+            chunk_target_ms: self.chunk_target_ms,
+            chunk_max_ms: self.chunk_max_ms,
+            chunk_overlap_ms: self.chunk_overlap_ms,
+            split_turns: self.split_turns,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct TranscribeConfig {
     pub active: String,
     pub local: ProviderConfig,
     pub cloud: ProviderConfig,
+    /// Show live partial hypotheses while speech is in progress (whisper only).
+    pub streaming: bool,
+    /// Fall back to the other transcribe profile when the active one keeps failing.
+    pub failover: bool,
+    /// Chunks kept on disk while the cloud provider is unreachable; 0 drops them.
+    pub offline_queue_chunks: u32,
 }
 
 impl Default for TranscribeConfig {
@@ -190,6 +337,9 @@ impl Default for TranscribeConfig {
                 model: "whisper-large-v3-turbo".to_string(),
                 api_key: String::new(),
             },
+            streaming: false,
+            failover: false,
+            offline_queue_chunks: 60,
         }
     }
 }
@@ -201,6 +351,28 @@ pub struct SummarizeConfig {
     pub local: ProviderConfig,
     pub cloud: ProviderConfig,
     pub prompt_profile: String,
+    /// Write an Anki-compatible flashcards CSV when finalizing a lecture.
+    pub flashcards: bool,
+    /// Detect topic boundaries and write a chaptered outline of the transcript.
+    pub chapters: bool,
+    /// Name untitled meetings from the transcript when they end.
+    pub auto_title: bool,
+    /// When live notes update: `adaptive` (after `trigger_segments` new finalized
+    /// segments or a pause in speech) or `interval` (every `interval_secs`).
+    pub schedule: String,
+    /// Seconds between live notes updates, or the shortest gap when adaptive;
+    /// tripled while resource limits are exceeded.
+    pub interval_secs: u32,
+    /// New finalized segments that trigger an adaptive update.
+    pub trigger_segments: u32,
+    /// Milliseconds without new transcript, i.e. without speech, that count as a pause.
+    pub pause_ms: u32,
+    /// Most transcript segments sent with one live notes update; the rest wait for the next.
+    pub window_segments: u32,
+    /// Most notes one live update may add or change.
+    pub max_ops: u32,
+    /// Seconds between passes that merge and prune the live notes; 0 disables.
+    pub consolidate_secs: u32,
 }
 
 impl Default for SummarizeConfig {
@@ -218,6 +390,16 @@ impl Default for SummarizeConfig {
                 api_key: String::new(),
             },
             prompt_profile: "minimal".to_string(),
+            flashcards: false,
+            chapters: false,
+            auto_title: true,
+            schedule: "adaptive".to_string(),
+            interval_secs: 4,
+            trigger_segments: 6,
+            pause_ms: 1_500,
+            window_segments: 120,
+            max_ops: 3,
+            consolidate_secs: 300,
         }
     }
 }
@@ -230,29 +412,608 @@ pub struct ProviderConfig {
     pub api_key: String,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct SessionConfig {
     pub context: String,
     pub participants: Vec<String>,
     pub export_dir: String,
+    /// Segments below this confidence render dimmed in the transcript pane and get a `(?)`
+    /// marker there and in markdown exports; 0 disables.
+    pub low_confidence_threshold: f32,
+    /// When the live transcript is fsynced: `never`, `interval` (every few seconds), or `always`.
+    pub fsync: String,
+    /// Begin a meeting as soon as the TUI opens, like `koe --start`.
+    pub auto_start: bool,
+    /// Refresh `transcript.md` and `notes.md` this often during a meeting; 0 only exports at the end.
+    pub autosave_export_minutes: u32,
+    /// Export folders under `export_dir`: `session` (`<date> <title>/`) or `day` (`<date>/<title>/`).
+    pub export_layout: String,
+    /// Put every meeting's exports in one folder, file names prefixed with the meeting name.
+    pub export_flatten: bool,
+    /// Ask for tags when a meeting ends in the TUI.
+    pub tag_prompt: bool,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            context: String::new(),
+            participants: Vec::new(),
+            export_dir: String::new(),
+            low_confidence_threshold: 0.0,
+            fsync: "interval".to_string(),
+            auto_start: false,
+            autosave_export_minutes: 0,
+            export_layout: "session".to_string(),
+            export_flatten: false,
+            tag_prompt: true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct UiConfig {
+    /// `minimal`, `dark`, `light`, `high-contrast`, or a name from `themes`.
     pub color_theme: String,
+    /// Check GitHub releases on launch and show available updates in the title bar.
+    pub check_updates: bool,
+    /// Toggles capture pause during a meeting, e.g. `space` or `ctrl+space`; empty disables.
+    pub pause_key: String,
+    /// Pauses capture only while held; empty disables.
+    pub hold_to_pause_key: String,
+    /// Main view panes: `split`, `notes`, or `transcript`; `v` cycles them in the TUI.
+    pub layout: String,
+    /// Notes pane width in the split view, in percent; `<`/`>` adjust it in the TUI.
+    pub split_percent: u16,
+    /// Custom palettes, e.g. `[ui.themes.solarized]`, selected with `color_theme`.
+    pub themes: BTreeMap<String, ThemePalette>,
+}
+
+/// Custom TUI palette; empty colors keep those of `base`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemePalette {
+    /// Built-in theme to start from; empty means `minimal`.
+    pub base: String,
+    /// `#rrggbb` colors.
+    pub accent: String,
+    pub me: String,
+    pub them: String,
+    pub heading: String,
+    pub muted: String,
+    pub neutral: String,
+    pub error: String,
 }
 
 impl Default for UiConfig {
     fn default() -> Self {
         Self {
             color_theme: "minimal".to_string(),
+            check_updates: false,
+            pause_key: "space".to_string(),
+            hold_to_pause_key: String::new(),
+            layout: "split".to_string(),
+            split_percent: 55,
+            themes: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LimitsConfig {
+    /// Resident memory ceiling in MB before degrading; 0 disables the limit.
+    pub max_rss_mb: u32,
+    /// Whisper model to switch to once `max_rss_mb` is exceeded.
+    pub degraded_model: String,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_rss_mb: 0,
+            degraded_model: "tiny.en".to_string(),
+        }
+    }
+}
+
+/// URLs that receive a JSON `POST` for each session event.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhooksConfig {
+    pub meeting_start: Vec<String>,
+    pub notes_patch: Vec<String>,
+    pub meeting_end: Vec<String>,
+}
+
+impl WebhooksConfig {
+    pub fn is_empty(&self) -> bool {
+        self.meeting_start.is_empty() && self.notes_patch.is_empty() && self.meeting_end.is_empty()
+    }
+
+    fn redact(&mut self) {
+        for url in [
+            &mut self.meeting_start,
+            &mut self.notes_patch,
+            &mut self.meeting_end,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *url = redact_url(url);
+        }
+    }
+
+    fn restore_secrets(&mut self, local: &Self) {
+        if self.is_empty() {
+            *self = local.clone();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReviewConfig {
+    /// Show the review screen after ending a meeting before it is finalized.
+    pub enabled: bool,
+    /// Obsidian vault directory offered as an export target; empty hides it.
+    /// Slack is offered when `integrations.slack.webhook` is set.
+    pub obsidian_vault: String,
+}
+
+impl Default for ReviewConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            obsidian_vault: String::new(),
+        }
+    }
+}
+
+/// Meeting phase and new-note signals for Stream Deck / Companion setups.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OutputsConfig {
+    /// Named pipe receiving one JSON line per signal; created if missing.
+    pub pipe: String,
+    /// `host:port` receiving OSC messages over UDP.
+    pub osc: String,
+}
+
+impl OutputsConfig {
+    pub fn is_empty(&self) -> bool {
+        self.pipe.trim().is_empty() && self.osc.trim().is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HttpConfig {
+    /// Serve live transcript/notes on 127.0.0.1 while the TUI runs.
+    pub enabled: bool,
+    pub port: u16,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 7373,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TranslateConfig {
+    /// Translate finalized segments with the summarize provider and show them
+    /// under each transcript line.
+    pub enabled: bool,
+    pub target_language: String,
+}
+
+impl Default for TranslateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_language: "English".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    /// Record per-meeting counts (minutes, providers, error rates) to `~/.koe/metrics.jsonl`.
+    pub enabled: bool,
+    /// Opt in to `koe stats --usage --share` sending the aggregate to `share_url`.
+    pub share: bool,
+    pub share_url: String,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            share: false,
+            share_url: String::new(),
+        }
+    }
+}
+
+/// Masking applied to transcript text before it reaches the ledger.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedactConfig {
+    pub enabled: bool,
+    pub emails: bool,
+    pub phones: bool,
+    pub cards: bool,
+    /// Words or phrases (client names, project codenames) masked case-insensitively.
+    pub keywords: Vec<String>,
+}
+
+impl Default for RedactConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            emails: true,
+            phones: true,
+            cards: true,
+            keywords: Vec::new(),
+        }
+    }
+}
+
+impl RedactConfig {
+    /// The configured redactor, or `None` while redaction is off.
+    pub fn redactor(&self) -> Option<koe_core::redact::Redactor> {
+        self.enabled.then(|| koe_core::redact::Redactor {
+            emails: self.emails,
+            phones: self.phones,
+            cards: self.cards,
+            keywords: self.keywords.clone(),
+        })
+    }
+}
+
+/// Third-party destinations that receive each meeting when it ends.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IntegrationsConfig {
+    pub notion: NotionConfig,
+    pub slack: SlackConfig,
+    pub email: EmailConfig,
+}
+
+impl IntegrationsConfig {
+    fn redact(&mut self) {
+        redact_secret(&mut self.notion.token);
+        self.slack.webhook = redact_url(&self.slack.webhook);
+    }
+
+    fn clear_secrets(&mut self) {
+        self.notion.token.clear();
+        self.slack.webhook.clear();
+    }
+
+    fn restore_secrets(&mut self, local: &Self) {
+        restore_secret(&mut self.notion.token, &local.notion.token);
+        restore_secret(&mut self.slack.webhook, &local.slack.webhook);
+    }
+}
+
+/// Notion database receiving a page per finished meeting; off until both
+/// `token` and `database_id` are set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotionConfig {
+    /// Internal integration secret; the database must be shared with it.
+    pub token: String,
+    pub database_id: String,
+    /// Database property names; an empty name leaves that property unset.
+    pub title_property: String,
+    pub date_property: String,
+    pub participants_property: String,
+    pub tags_property: String,
+}
+
+impl Default for NotionConfig {
+    fn default() -> Self {
+        Self {
+            token: String::new(),
+            database_id: String::new(),
+            title_property: "Name".to_string(),
+            date_property: "Date".to_string(),
+            participants_property: "Participants".to_string(),
+            tags_property: "Tags".to_string(),
+        }
+    }
+}
+
+impl NotionConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.token.trim().is_empty() && !self.database_id.trim().is_empty()
+    }
+}
+
+/// Slack incoming webhook receiving the summary and action items, from the
+/// post-meeting palette or automatically once the end-of-meeting pass is done.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlackConfig {
+    /// Incoming webhook URL; empty disables posting.
+    pub webhook: String,
+    /// Channel override such as `#team-minutes`; empty uses the webhook's own.
+    pub channel: String,
+    pub post_on_end: bool,
+}
+
+impl SlackConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.webhook.trim().is_empty()
+    }
+}
+
+/// Recipients for the post-meeting `email minutes` draft, on top of
+/// participants that are email addresses.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmailConfig {
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
+}
+
+/// Background backup of finalized session directories to S3-compatible or
+/// WebDAV storage; `koe sync status` lists what is still to upload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncConfig {
+    /// `s3` or `webdav`; empty disables sync.
+    pub backend: String,
+    /// Upload recorded audio too; off keeps only transcripts, notes, and exports.
+    pub include_audio: bool,
+    /// Remote folder (S3 key prefix or WebDAV path) holding one folder per session.
+    pub prefix: String,
+    pub interval_secs: u32,
+    pub s3: S3Config,
+    pub webdav: WebDavConfig,
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            backend: String::new(),
+            include_audio: true,
+            prefix: "koe".to_string(),
+            interval_secs: 300,
+            s3: S3Config::default(),
+            webdav: WebDavConfig::default(),
+        }
+    }
+}
+
+impl SyncConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.backend.trim().is_empty()
+    }
+
+    fn redact(&mut self) {
+        redact_secret(&mut self.s3.secret_access_key);
+        redact_secret(&mut self.webdav.password);
+    }
+
+    fn clear_secrets(&mut self) {
+        self.s3.secret_access_key.clear();
+        self.webdav.password.clear();
+    }
+
+    fn restore_secrets(&mut self, local: &Self) {
+        restore_secret(&mut self.s3.secret_access_key, &local.s3.secret_access_key);
+        restore_secret(&mut self.webdav.password, &local.webdav.password);
+    }
+}
+
+fn redact_secret(secret: &mut String) {
+    if !secret.trim().is_empty() {
+        *secret = "<redacted>".to_string();
+    }
+}
+
+fn restore_secret(secret: &mut String, local: &str) {
+    if secret.trim().is_empty() {
+        *secret = local.to_string();
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct S3Config {
+    /// Service URL such as `https://s3.us-east-1.amazonaws.com`; buckets are
+    /// addressed path-style so MinIO, R2, and B2 endpoints work too.
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+impl Default for S3Config {
+    fn default() -> Self {
+        Self {
+            endpoint: String::new(),
+            bucket: String::new(),
+            region: "us-east-1".to_string(),
+            access_key_id: String::new(),
+            secret_access_key: String::new(),
         }
     }
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebDavConfig {
+    /// Collection URL uploads go under, e.g. a Nextcloud files URL.
+    pub url: String,
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalendarConfig {
+    /// Event title patterns checked in order; the first match picks the meeting
+    /// profile for a session launched with `--event`.
+    pub templates: Vec<CalendarTemplate>,
+    /// ICS file path or http(s)/webcal URL read at launch and meeting start to
+    /// fill the title, context, and participants; empty disables the lookup.
+    pub ics: String,
+    /// Minutes before an event starts that it already counts as current.
+    pub early_minutes: u32,
+}
+
+impl Default for CalendarConfig {
+    fn default() -> Self {
+        Self {
+            templates: Vec::new(),
+            ics: String::new(),
+            early_minutes: 10,
+        }
+    }
+}
+
+/// Watcher offering (or starting) a meeting when a call app becomes active.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DetectConfig {
+    pub enabled: bool,
+    /// `prompt` asks in the TUI; `start` starts the meeting right away.
+    pub action: String,
+    /// `audio` waits until a meeting app plays or records audio; `running` only
+    /// needs it open.
+    pub trigger: String,
+    /// Process names of meeting apps.
+    pub apps: Vec<String>,
+    /// Browser process names; these only count while producing audio (e.g. Meet).
+    pub browsers: Vec<String>,
+    pub interval_secs: u32,
+}
+
+impl Default for DetectConfig {
+    fn default() -> Self {
+        let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        Self {
+            enabled: false,
+            action: "prompt".to_string(),
+            trigger: "audio".to_string(),
+            apps: names(&["zoom.us", "Microsoft Teams", "MSTeams", "Webex", "Slack"]),
+            browsers: names(&[
+                "Google Chrome",
+                "Safari",
+                "Arc",
+                "Firefox",
+                "Microsoft Edge",
+                "Brave Browser",
+            ]),
+            interval_secs: 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalendarTemplate {
+    /// Case-insensitive; matches anywhere in the title, `*` matches any run of characters.
+    pub pattern: String,
+    /// Name of an entry in `profiles`.
+    pub profile: String,
+}
+
+impl CalendarConfig {
+    pub fn profile_for_event(&self, title: &str) -> Option<&str> {
+        self.templates
+            .iter()
+            .find(|template| title_matches(&template.pattern, title))
+            .map(|template| template.profile.as_str())
+    }
+}
+
+fn title_matches(pattern: &str, title: &str) -> bool {
+    let title = title.to_lowercase();
+    let mut rest = title.as_str();
+    let mut parts = pattern
+        .trim()
+        .split('*')
+        .map(str::to_lowercase)
+        .filter(|part| !part.is_empty())
+        .peekable();
+    if parts.peek().is_none() {
+        return false;
+    }
+    for part in parts {
+        match rest.find(&part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkspaceConfig {
+    /// Sessions directory, relative to `~/.koe`; defaults to `workspaces/<name>/sessions`.
+    pub sessions_dir: String,
+    /// Export directory; defaults to `<session.export_dir>/<name>` when that is set.
+    pub export_dir: String,
+    pub context: String,
+    pub participants: Vec<String>,
+    /// Replace the global `webhooks`, `integrations`, and `sync` sections for
+    /// this workspace's meetings; unset sections inherit the global ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhooks: Option<WebhooksConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<IntegrationsConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync: Option<SyncConfig>,
+}
+
+/// Meeting-type preset; empty fields inherit from the workspace and session defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MeetingProfileConfig {
+    pub context: String,
+    pub participants: Vec<String>,
+    /// Overrides `summarize.prompt_profile`.
+    pub prompt_profile: String,
+    /// Transcribe mode (local or cloud); applied at launch with `--profile`.
+    pub transcribe: String,
+    /// Summarize mode (local or cloud); applied at launch with `--profile`.
+    pub summarize: String,
+}
+
 impl Config {
+    /// This config with `workspace`'s destination overrides applied, so a
+    /// meeting only reaches that workspace's webhooks, integrations, and sync.
+    pub fn for_workspace(&self, workspace: Option<&str>) -> Self {
+        let mut config = self.clone();
+        let Some(workspace) = workspace.and_then(|name| self.workspaces.get(name)) else {
+            return config;
+        };
+        if let Some(webhooks) = &workspace.webhooks {
+            config.webhooks = webhooks.clone();
+        }
+        if let Some(integrations) = &workspace.integrations {
+            config.integrations = integrations.clone();
+        }
+        if let Some(sync) = &workspace.sync {
+            config.sync = sync.clone();
+        }
+        config
+    }
+
     pub fn load_or_create(paths: &ConfigPaths) -> Result<Self, ConfigError> {
         ensure_dirs(paths)?;
         if paths.config_path.exists() {
@@ -275,7 +1036,7 @@ impl Config {
             .unwrap_or(0) as u32;
 
         let mut config: Config = toml::from_str(&content)?;
-        let mut migrated = false;
+        let mut migrated = migrate_review_slack_webhook(&raw, &mut config);
 
         if file_version < CONFIG_VERSION {
             config.version = CONFIG_VERSION;
@@ -302,15 +1063,105 @@ impl Config {
         Ok(())
     }
 
+    /// Re-read the file, apply `change`, and write it back, so settings saved
+    /// from a running session skip its env and CLI overrides.
+    pub fn update(
+        paths: &ConfigPaths,
+        change: impl FnOnce(&mut Config),
+    ) -> Result<(), ConfigError> {
+        let mut config = Self::load(paths)?;
+        change(&mut config);
+        Self::write(paths, &config)
+    }
+
     pub fn redacted(&self) -> Self {
         let mut redacted = self.clone();
         redact_provider(&mut redacted.transcribe.local);
         redact_provider(&mut redacted.transcribe.cloud);
         redact_provider(&mut redacted.summarize.local);
         redact_provider(&mut redacted.summarize.cloud);
+        redacted.webhooks.redact();
+        redacted.integrations.redact();
+        redacted.sync.redact();
+        for workspace in redacted.workspaces.values_mut() {
+            if let Some(webhooks) = &mut workspace.webhooks {
+                webhooks.redact();
+            }
+            if let Some(integrations) = &mut workspace.integrations {
+                integrations.redact();
+            }
+            if let Some(sync) = &mut workspace.sync {
+                sync.redact();
+            }
+        }
         redacted
     }
 
+    /// Copy with API keys and webhook URLs cleared, for sharing outside this machine.
+    pub fn without_secrets(&self) -> Self {
+        let mut shared = self.clone();
+        for profile in shared.provider_profiles_mut() {
+            profile.api_key.clear();
+        }
+        shared.webhooks = WebhooksConfig::default();
+        shared.integrations.clear_secrets();
+        shared.sync.clear_secrets();
+        for workspace in shared.workspaces.values_mut() {
+            if workspace.webhooks.is_some() {
+                workspace.webhooks = Some(WebhooksConfig::default());
+            }
+            if let Some(integrations) = &mut workspace.integrations {
+                integrations.clear_secrets();
+            }
+            if let Some(sync) = &mut workspace.sync {
+                sync.clear_secrets();
+            }
+        }
+        shared
+    }
+
+    /// Keep this machine's API keys and webhooks where `other` has none (e.g. after importing a bundle).
+    pub fn restore_secrets(&mut self, other: &Config) {
+        self.webhooks.restore_secrets(&other.webhooks);
+        self.integrations.restore_secrets(&other.integrations);
+        self.sync.restore_secrets(&other.sync);
+        for (name, workspace) in &mut self.workspaces {
+            let Some(local) = other.workspaces.get(name) else {
+                continue;
+            };
+            if let (Some(webhooks), Some(local)) = (&mut workspace.webhooks, &local.webhooks) {
+                webhooks.restore_secrets(local);
+            }
+            if let (Some(integrations), Some(local)) =
+                (&mut workspace.integrations, &local.integrations)
+            {
+                integrations.restore_secrets(local);
+            }
+            if let (Some(sync), Some(local)) = (&mut workspace.sync, &local.sync) {
+                sync.restore_secrets(local);
+            }
+        }
+        let mut existing = other.clone();
+        for (profile, local) in self
+            .provider_profiles_mut()
+            .into_iter()
+            .zip(existing.provider_profiles_mut())
+        {
+            if profile.api_key.trim().is_empty() {
+                profile.api_key = std::mem::take(&mut local.api_key);
+            }
+        }
+    }
+
+    fn provider_profiles_mut(&mut self) -> [&mut ProviderConfig; 4] {
+        [
+            &mut self.transcribe.local,
+            &mut self.transcribe.cloud,
+            &mut self.summarize.local,
+            &mut self.summarize.cloud,
+        ]
+    }
+
     pub fn validate(&self) -> Result<(), ConfigError> {
         validate_active("transcribe.active", self.transcribe.active.as_str())?;
         validate_active("summarize.active", self.summarize.active.as_str())?;
@@ -360,6 +1211,64 @@ impl Config {
                 }
             }
         }
+        if self.metrics.share && self.metrics.share_url.trim().is_empty() {
+            return Err(ConfigError::Validation(
+                "metrics.share requires metrics.share_url".into(),
+            ));
+        }
+        let process = &self.process;
+        match process.vad.as_str() {
+            "silero" | "energy" => {}
+            "webrtc" => {
+                return Err(ConfigError::Validation(
+                    "process.vad = \"webrtc\" is not available in this build; use silero or energy"
+                        .into(),
+                ));
+            }
+            _ => {
+                return Err(ConfigError::Validation(
+                    "process.vad must be silero or energy".into(),
+                ));
+            }
+        }
+        if !(process.vad_threshold > 0.0 && process.vad_threshold < 1.0) {
+            return Err(ConfigError::Validation(
+                "process.vad_threshold must be between 0 and 1".into(),
+            ));
+        }
+        if process.chunk_min_ms == 0
+            || process.chunk_min_ms > process.chunk_target_ms
+            || process.chunk_target_ms > process.chunk_max_ms
+        {
+            return Err(ConfigError::Validation(
+                "process chunk lengths must satisfy 0 < chunk_min_ms <= chunk_target_ms <= chunk_max_ms".into(),
+            ));
+        }
+        if process.chunk_max_ms > MAX_CHUNK_MS {
+            return Err(ConfigError::Validation(format!(
+                "process.chunk_max_ms must be at most {MAX_CHUNK_MS} (whisper's 30 s window)"
+            )));
+        }
+        if process.chunk_overlap_ms >= process.chunk_min_ms {
+            return Err(ConfigError::Validation(
+                "process.chunk_overlap_ms must be shorter than process.chunk_min_ms".into(),
+            ));
+        }
+        if !matches!(self.session.fsync.as_str(), "never" | "interval" | "always") {
+            return Err(ConfigError::Validation(
+                "session.fsync must be never, interval, or always".into(),
+            ));
+        }
+        if !matches!(self.session.export_layout.as_str(), "session" | "day") {
+            return Err(ConfigError::Validation(
+                "session.export_layout must be session or day".into(),
+            ));
+        }
+        if !matches!(self.audio.archive_format.as_str(), "wav" | "flac" | "opus") {
+            return Err(ConfigError::Validation(
+                "audio.archive_format must be wav, flac, or opus".into(),
+            ));
+        }
         let agc = &self.audio.mixdown.agc;
         if agc.target_rms_dbfs > 0.0 {
             return Err(ConfigError::Validation(
@@ -420,9 +1329,52 @@ impl Config {
             ));
         }
 
-        if self.summarize.prompt_profile.trim().is_empty() {
+        validate_prompt_profile("summarize.prompt_profile", &self.summarize.prompt_profile)?;
+        if !matches!(self.summarize.schedule.as_str(), "adaptive" | "interval") {
+            return Err(ConfigError::Validation(
+                "summarize.schedule must be adaptive or interval".into(),
+            ));
+        }
+        for (field, value) in [
+            ("summarize.interval_secs", self.summarize.interval_secs),
+            (
+                "summarize.trigger_segments",
+                self.summarize.trigger_segments,
+            ),
+            ("summarize.pause_ms", self.summarize.pause_ms),
+            ("summarize.window_segments", self.summarize.window_segments),
+            ("summarize.max_ops", self.summarize.max_ops),
+        ] {
+            if value == 0 {
+                return Err(ConfigError::Validation(format!(
+                    "{field} must be greater than 0"
+                )));
+            }
+        }
+        if self.limits.max_rss_mb > 0 && self.limits.degraded_model.trim().is_empty() {
             return Err(ConfigError::Validation(
-                "summarize.prompt_profile must not be empty".into(),
+                "limits.degraded_model must not be empty when limits.max_rss_mb is set".into(),
+            ));
+        }
+        for (field, value) in [
+            ("ui.pause_key", &self.ui.pause_key),
+            ("ui.hold_to_pause_key", &self.ui.hold_to_pause_key),
+        ] {
+            if !value.trim().is_empty() && crate::tui::KeyBinding::parse(value).is_none() {
+                return Err(ConfigError::Validation(format!(
+                    "{field} {value:?} is not a key like space, ctrl+space, p, or f5"
+                )));
+            }
+        }
+        if crate::tui::PaneView::parse(&self.ui.layout).is_none() {
+            return Err(ConfigError::Validation(format!(
+                "ui.layout {:?} must be split, notes, or transcript",
+                self.ui.layout
+            )));
+        }
+        if !crate::tui::SPLIT_PERCENT_RANGE.contains(&self.ui.split_percent) {
+            return Err(ConfigError::Validation(
+                "ui.split_percent must be between 20 and 80".into(),
             ));
         }
         if self.ui.color_theme.trim().is_empty() {
@@ -430,6 +1382,13 @@ impl Config {
                 "ui.color_theme must not be empty".into(),
             ));
         }
+        crate::tui::check_color_theme(&self.ui)
+            .map_err(|message| ConfigError::Validation(format!("ui.color_theme: {message}")))?;
+        if !(0.0..=1.0).contains(&self.session.low_confidence_threshold) {
+            return Err(ConfigError::Validation(
+                "session.low_confidence_threshold must be between 0 and 1".into(),
+            ));
+        }
         for participant in &self.session.participants {
             if participant.trim().is_empty() {
                 return Err(ConfigError::Validation(
@@ -437,11 +1396,115 @@ impl Config {
                 ));
             }
         }
+        validate_destinations("", &self.webhooks, &self.integrations, &self.sync)?;
+        for (name, workspace) in &self.workspaces {
+            if workspace.webhooks.is_some()
+                || workspace.integrations.is_some()
+                || workspace.sync.is_some()
+            {
+                let scoped = self.for_workspace(Some(name));
+                validate_destinations(
+                    &format!("workspaces.{name}."),
+                    &scoped.webhooks,
+                    &scoped.integrations,
+                    &scoped.sync,
+                )?;
+            }
+        }
+        let osc = self.outputs.osc.trim();
+        if !osc.is_empty() && osc.parse::<std::net::SocketAddr>().is_err() {
+            return Err(ConfigError::Validation(format!(
+                "outputs.osc must be host:port with an IP address (got {osc})"
+            )));
+        }
+        if self.http.enabled && self.http.port == 0 {
+            return Err(ConfigError::Validation(
+                "http.port must be > 0 when http.enabled is true".into(),
+            ));
+        }
+        if self.translate.enabled && self.translate.target_language.trim().is_empty() {
+            return Err(ConfigError::Validation(
+                "translate.target_language must be set when translate.enabled is true".into(),
+            ));
+        }
+        if !matches!(self.detect.action.as_str(), "prompt" | "start") {
+            return Err(ConfigError::Validation(
+                "detect.action must be prompt or start".into(),
+            ));
+        }
+        if !matches!(self.detect.trigger.as_str(), "audio" | "running") {
+            return Err(ConfigError::Validation(
+                "detect.trigger must be audio or running".into(),
+            ));
+        }
+        if self.detect.interval_secs == 0 {
+            return Err(ConfigError::Validation(
+                "detect.interval_secs must be greater than 0".into(),
+            ));
+        }
+        for (index, template) in self.calendar.templates.iter().enumerate() {
+            if template.pattern.trim().trim_matches('*').is_empty() {
+                return Err(ConfigError::Validation(format!(
+                    "calendar.templates[{index}].pattern must not be empty"
+                )));
+            }
+            if !self.profiles.contains_key(&template.profile) {
+                return Err(ConfigError::Validation(format!(
+                    "calendar.templates[{index}].profile {:?} is not a configured profile",
+                    template.profile
+                )));
+            }
+        }
+        for (name, workspace) in &self.workspaces {
+            if !is_valid_workspace_name(name) {
+                return Err(ConfigError::Validation(format!(
+                    "workspace name {name:?} must use only letters, digits, '-' or '_'"
+                )));
+            }
+            if workspace.participants.iter().any(|p| p.trim().is_empty()) {
+                return Err(ConfigError::Validation(format!(
+                    "workspaces.{name}.participants entries must not be empty"
+                )));
+            }
+        }
+        for (name, profile) in &self.profiles {
+            if !is_valid_workspace_name(name) {
+                return Err(ConfigError::Validation(format!(
+                    "profile name {name:?} must use only letters, digits, '-' or '_'"
+                )));
+            }
+            if profile.participants.iter().any(|p| p.trim().is_empty()) {
+                return Err(ConfigError::Validation(format!(
+                    "profiles.{name}.participants entries must not be empty"
+                )));
+            }
+            if !profile.prompt_profile.is_empty() {
+                validate_prompt_profile(
+                    &format!("profiles.{name}.prompt_profile"),
+                    &profile.prompt_profile,
+                )?;
+            }
+            for (field, value) in [
+                ("transcribe", &profile.transcribe),
+                ("summarize", &profile.summarize),
+            ] {
+                if !value.is_empty() {
+                    validate_active(&format!("profiles.{name}.{field}"), value)?;
+                }
+            }
+        }
 
         Ok(())
     }
 }
 
+pub fn is_valid_workspace_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+}
+
 fn ensure_dirs(paths: &ConfigPaths) -> Result<(), ConfigError> {
     fs::create_dir_all(&paths.base_dir)?;
     fs::create_dir_all(&paths.models_dir)?;
@@ -469,6 +1532,22 @@ fn set_strict_permissions(path: &Path) -> Result<(), ConfigError> {
     Ok(())
 }
 
+/// Move a `review.slack_webhook` from older files into `integrations.slack.webhook`,
+/// which now backs both the review export target and `post minutes to slack`.
+fn migrate_review_slack_webhook(raw: &toml::Value, config: &mut Config) -> bool {
+    let legacy = raw
+        .get("review")
+        .and_then(|review| review.get("slack_webhook"))
+        .and_then(|value| value.as_str());
+    let Some(legacy) = legacy else {
+        return false;
+    };
+    if config.integrations.slack.webhook.trim().is_empty() {
+        config.integrations.slack.webhook = legacy.trim().to_string();
+    }
+    true
+}
+
 fn warn_if_loose_permissions(path: &Path) -> Result<(), ConfigError> {
     #[cfg(unix)]
     {
@@ -497,19 +1576,121 @@ fn validate_active(field: &str, value: &str) -> Result<(), ConfigError> {
     }
 }
 
-fn validate_transcribe_profile(
-    label: &str,
-    profile: &ProviderConfig,
-    is_active: bool,
+/// Checks for the meeting destinations, globally (`prefix` empty) or as a
+/// workspace override (`prefix` `workspaces.<name>.`).
+fn validate_destinations(
+    prefix: &str,
+    webhooks: &WebhooksConfig,
+    integrations: &IntegrationsConfig,
+    sync: &SyncConfig,
 ) -> Result<(), ConfigError> {
-    match profile.provider.as_str() {
-        "whisper" | "groq" => {}
+    for (event, urls) in [
+        ("meeting_start", &webhooks.meeting_start),
+        ("notes_patch", &webhooks.notes_patch),
+        ("meeting_end", &webhooks.meeting_end),
+    ] {
+        if let Some(url) = urls
+            .iter()
+            .find(|url| !url.starts_with("http://") && !url.starts_with("https://"))
+        {
+            return Err(ConfigError::Validation(format!(
+                "{prefix}webhooks.{event} entries must be http(s) URLs (got {url})"
+            )));
+        }
+    }
+    let notion = &integrations.notion;
+    if notion.token.trim().is_empty() != notion.database_id.trim().is_empty() {
+        return Err(ConfigError::Validation(format!(
+            "{prefix}integrations.notion needs both token and database_id"
+        )));
+    }
+    if notion.is_enabled() && notion.title_property.trim().is_empty() {
+        return Err(ConfigError::Validation(format!(
+            "{prefix}integrations.notion.title_property must not be empty"
+        )));
+    }
+    let slack = &integrations.slack;
+    if slack.is_enabled() && !slack.webhook.trim().starts_with("https://") {
+        return Err(ConfigError::Validation(format!(
+            "{prefix}integrations.slack.webhook must be an https URL"
+        )));
+    }
+    if slack.post_on_end && !slack.is_enabled() {
+        return Err(ConfigError::Validation(format!(
+            "{prefix}integrations.slack.post_on_end requires integrations.slack.webhook"
+        )));
+    }
+    match sync.backend.trim() {
+        "" => {}
+        "s3" => {
+            let s3 = &sync.s3;
+            if !s3.endpoint.trim().starts_with("http://")
+                && !s3.endpoint.trim().starts_with("https://")
+            {
+                return Err(ConfigError::Validation(format!(
+                    "{prefix}sync.s3.endpoint must be an http(s) URL"
+                )));
+            }
+            if [
+                &s3.bucket,
+                &s3.region,
+                &s3.access_key_id,
+                &s3.secret_access_key,
+            ]
+            .iter()
+            .any(|value| value.trim().is_empty())
+            {
+                return Err(ConfigError::Validation(format!(
+                    "{prefix}sync.s3 needs bucket, region, access_key_id, and secret_access_key"
+                )));
+            }
+        }
+        "webdav" => {
+            let url = sync.webdav.url.trim();
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err(ConfigError::Validation(format!(
+                    "{prefix}sync.webdav.url must be an http(s) URL"
+                )));
+            }
+        }
         other => {
             return Err(ConfigError::Validation(format!(
-                "{label}.provider must be whisper or groq (got {other})"
+                "{prefix}sync.backend must be s3 or webdav (got {other})"
             )));
         }
     }
+    if sync.interval_secs == 0 {
+        return Err(ConfigError::Validation(format!(
+            "{prefix}sync.interval_secs must be > 0"
+        )));
+    }
+    Ok(())
+}
+
+/// Built-in profiles, or the name of a template in `~/.koe/prompts/`.
+fn validate_prompt_profile(field: &str, value: &str) -> Result<(), ConfigError> {
+    match value {
+        "minimal" | "podcast" | "lecture" => Ok(()),
+        other if is_valid_workspace_name(other) => Ok(()),
+        other => Err(ConfigError::Validation(format!(
+            "{field} must be minimal, podcast, lecture, or a prompts/<name>.txt template name (got {other:?})"
+        ))),
+    }
+}
+
+fn validate_transcribe_profile(
+    label: &str,
+    profile: &ProviderConfig,
+    is_active: bool,
+) -> Result<(), ConfigError> {
+    let names = transcribe_provider_names();
+    if !names.contains(&profile.provider) {
+        return Err(ConfigError::Validation(format!(
+            "{label}.provider must be one of {} (got {})",
+            names.join(", "),
+            profile.provider
+        )));
+    }
 
     if profile.model.trim().is_empty() {
         return Err(ConfigError::Validation(format!(
@@ -530,6 +1711,15 @@ fn validate_transcribe_profile(
             "{label}.api_key required when {label}.provider=groq"
         )));
     }
+    if is_active
+        && profile.provider == "assemblyai"
+        && profile.api_key.trim().is_empty()
+        && std::env::var_os("ASSEMBLYAI_API_KEY").is_none()
+    {
+        return Err(ConfigError::Validation(format!(
+            "{label}.api_key or ASSEMBLYAI_API_KEY required when {label}.provider=assemblyai"
+        )));
+    }
     Ok(())
 }
 
@@ -538,13 +1728,13 @@ fn validate_summarize_profile(
     profile: &ProviderConfig,
     is_active: bool,
 ) -> Result<(), ConfigError> {
-    match profile.provider.as_str() {
-        "ollama" | "openrouter" => {}
-        other => {
-            return Err(ConfigError::Validation(format!(
-                "{label}.provider must be ollama or openrouter (got {other})"
-            )));
-        }
+    let names = summarize_provider_names();
+    if !names.contains(&profile.provider) {
+        return Err(ConfigError::Validation(format!(
+            "{label}.provider must be one of {} (got {})",
+            names.join(", "),
+            profile.provider
+        )));
     }
 
     if profile.model.trim().is_empty() {
@@ -557,6 +1747,15 @@ fn validate_summarize_profile(
             "{label}.api_key required when {label}.provider=openrouter"
         )));
     }
+    if is_active
+        && profile.provider == "gemini"
+        && profile.api_key.trim().is_empty()
+        && std::env::var_os("GEMINI_API_KEY").is_none()
+    {
+        return Err(ConfigError::Validation(format!(
+            "{label}.api_key or GEMINI_API_KEY required when {label}.provider=gemini"
+        )));
+    }
     Ok(())
 }
 
@@ -566,10 +1765,23 @@ fn redact_provider(profile: &mut ProviderConfig) {
     }
 }
 
+/// Keep scheme and host; webhook paths often embed tokens.
+fn redact_url(url: &str) -> String {
+    let host_start = url.find("://").map_or(0, |idx| idx + 3);
+    match url[host_start..].find('/') {
+        Some(idx) => format!("{}/<redacted>", &url[..host_start + idx]),
+        None => url.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{CONFIG_VERSION, Config, ConfigPaths};
+    use super::{
+        CONFIG_VERSION, CalendarTemplate, Config, ConfigPaths, IntegrationsConfig,
+        MeetingProfileConfig, SyncConfig, WebhooksConfig, WorkspaceConfig,
+    };
     use std::fs;
+    use std::path::PathBuf;
 
     #[test]
     fn load_or_create_writes_defaults_and_dirs() {
@@ -625,6 +1837,28 @@ api_key = ""
         assert!(updated.contains("[summarize.local]"));
     }
 
+    #[test]
+    fn load_moves_review_slack_webhook_into_integrations() {
+        let temp = tempfile::tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().join("koe"));
+        fs::create_dir_all(&paths.base_dir).unwrap();
+        let content = r#"version = 5
+
+[review]
+slack_webhook = "https://hooks.slack.com/services/T0/B0/xyz"
+"#;
+        fs::write(&paths.config_path, content).unwrap();
+
+        let config = Config::load(&paths).unwrap();
+        assert_eq!(
+            config.integrations.slack.webhook,
+            "https://hooks.slack.com/services/T0/B0/xyz"
+        );
+        let updated = fs::read_to_string(&paths.config_path).unwrap();
+        assert!(!updated.contains("slack_webhook"));
+        assert!(updated.contains("[integrations.slack]"));
+    }
+
     #[test]
     fn redacted_hides_api_keys() {
         let mut config = Config::default();
@@ -635,10 +1869,191 @@ api_key = ""
         assert_eq!(redacted.summarize.cloud.api_key, "<redacted>");
     }
 
+    #[test]
+    fn workspace_paths_and_names() {
+        let paths = ConfigPaths::from_base(PathBuf::from("/tmp/koe"));
+        let workspace = WorkspaceConfig::default();
+        assert_eq!(
+            paths.for_workspace("acme", &workspace).sessions_dir,
+            PathBuf::from("/tmp/koe/workspaces/acme/sessions")
+        );
+
+        let mut config = Config::default();
+        config
+            .workspaces
+            .insert("acme corp".to_string(), WorkspaceConfig::default());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn without_secrets_clears_keys_and_restore_keeps_local_ones() {
+        let mut local = Config::default();
+        local.summarize.cloud.api_key = "secret".to_string();
+        let shared = local.without_secrets();
+        assert!(shared.summarize.cloud.api_key.is_empty());
+
+        let mut imported = shared.clone();
+        imported.session.context = "team standard".to_string();
+        imported.restore_secrets(&local);
+        assert_eq!(imported.summarize.cloud.api_key, "secret");
+        assert_eq!(imported.session.context, "team standard");
+    }
+
+    #[test]
+    fn workspace_secrets_are_redacted_exported_and_restored() {
+        let mut local = Config::default();
+        let mut integrations = IntegrationsConfig::default();
+        integrations.notion.token = "ntn_secret".to_string();
+        integrations.slack.webhook = "https://hooks.slack.com/services/T0/B0/xyz".to_string();
+        let mut sync = SyncConfig::default();
+        sync.s3.secret_access_key = "s3-secret".to_string();
+        sync.webdav.password = "dav-secret".to_string();
+        local.workspaces.insert(
+            "acme".to_string(),
+            WorkspaceConfig {
+                webhooks: Some(WebhooksConfig {
+                    meeting_end: vec!["https://example.com/hook?key=abc".to_string()],
+                    ..WebhooksConfig::default()
+                }),
+                integrations: Some(integrations),
+                sync: Some(sync),
+                ..WorkspaceConfig::default()
+            },
+        );
+
+        let printed = toml::to_string(&local.redacted()).unwrap();
+        let shared = toml::to_string(&local.without_secrets()).unwrap();
+        for secret in ["ntn_secret", "xyz", "s3-secret", "dav-secret", "key=abc"] {
+            assert!(!printed.contains(secret), "{secret} printed");
+            assert!(!shared.contains(secret), "{secret} exported");
+        }
+
+        let mut imported: Config = toml::from_str(&shared).unwrap();
+        imported.restore_secrets(&local);
+        let workspace = &imported.workspaces["acme"];
+        let integrations = workspace.integrations.as_ref().unwrap();
+        assert_eq!(integrations.notion.token, "ntn_secret");
+        assert_eq!(
+            integrations.slack.webhook,
+            "https://hooks.slack.com/services/T0/B0/xyz"
+        );
+        let sync = workspace.sync.as_ref().unwrap();
+        assert_eq!(sync.s3.secret_access_key, "s3-secret");
+        assert_eq!(sync.webdav.password, "dav-secret");
+        assert_eq!(
+            workspace.webhooks.as_ref().unwrap().meeting_end,
+            ["https://example.com/hook?key=abc"]
+        );
+    }
+
+    #[test]
+    fn calendar_templates_pick_profile_by_event_title() {
+        let mut config = Config::default();
+        config
+            .profiles
+            .insert("one-on-one".to_string(), MeetingProfileConfig::default());
+        config
+            .profiles
+            .insert("standup".to_string(), MeetingProfileConfig::default());
+        config.calendar.templates = vec![
+            CalendarTemplate {
+                pattern: "1:1".to_string(),
+                profile: "one-on-one".to_string(),
+            },
+            CalendarTemplate {
+                pattern: "daily*standup".to_string(),
+                profile: "standup".to_string(),
+            },
+        ];
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.calendar.profile_for_event("Alice / Bob 1:1"),
+            Some("one-on-one")
+        );
+        assert_eq!(
+            config.calendar.profile_for_event("Daily Eng Standup"),
+            Some("standup")
+        );
+        assert_eq!(config.calendar.profile_for_event("Standup daily"), None);
+
+        config.calendar.templates[1].profile = "missing".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn workspace_destinations_replace_the_global_ones() {
+        let config: Config = toml::from_str(
+            r#"
+[integrations.slack]
+webhook = "https://hooks.slack.com/services/global"
+
+[workspaces.acme]
+[workspaces.acme.integrations.slack]
+webhook = "https://hooks.slack.com/services/acme"
+[workspaces.acme.sync]
+backend = "webdav"
+[workspaces.acme.sync.webdav]
+url = "https://dav.acme.example/koe"
+
+[workspaces.personal]
+"#,
+        )
+        .unwrap();
+        config.validate().unwrap();
+
+        let acme = config.for_workspace(Some("acme"));
+        assert_eq!(
+            acme.integrations.slack.webhook,
+            "https://hooks.slack.com/services/acme"
+        );
+        assert_eq!(acme.sync.webdav.url, "https://dav.acme.example/koe");
+        let personal = config.for_workspace(Some("personal"));
+        assert_eq!(
+            personal.integrations.slack.webhook,
+            "https://hooks.slack.com/services/global"
+        );
+        assert!(!personal.sync.is_enabled());
+        assert_eq!(
+            config.for_workspace(None).integrations.slack.webhook,
+            "https://hooks.slack.com/services/global"
+        );
+
+        let mut invalid = config.clone();
+        invalid
+            .workspaces
+            .get_mut("acme")
+            .unwrap()
+            .integrations
+            .as_mut()
+            .unwrap()
+            .slack
+            .webhook = "http://insecure".to_string();
+        let err = invalid.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("workspaces.acme.integrations.slack.webhook"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn validate_rejects_bad_provider() {
         let mut config = Config::default();
         config.transcribe.local.provider = "bad".to_string();
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn validate_accepts_registered_summarize_provider() {
+        let mut config = Config::default();
+        config.summarize.cloud.provider = "house-llm".to_string();
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("must be one of gemini, ollama, openrouter"));
+        assert!(err.ends_with("(got house-llm)"));
+        koe_core::summarize::register_summarize_provider("house-llm", |_, _| {
+            Err(koe_core::SummarizeError::Failed(
+                "not reachable in tests".into(),
+            ))
+        });
+        config.validate().unwrap();
+    }
 }
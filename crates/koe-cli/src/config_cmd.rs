@@ -1,6 +1,9 @@
-use crate::config::{Config, ConfigError, ConfigPaths};
-use clap::Args;
+use crate::audit::AuditLog;
+use crate::bundle;
+use crate::config::{Config, ConfigError, ConfigPaths, is_valid_workspace_name};
+use clap::{Args, Subcommand};
 use koe_core::capture::list_audio_inputs as list_input_devices;
+use std::path::PathBuf;
 use std::process::Command;
 
 #[derive(Args, Debug, Clone)]
@@ -20,6 +23,29 @@ pub struct ConfigArgs {
     /// Set a config value (dotted key=value)
     #[arg(long, value_name = "key=value")]
     pub set: Vec<String>,
+
+    #[command(subcommand)]
+    pub action: Option<ConfigAction>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigAction {
+    /// Print config without API keys, or package it with shared resources
+    Export {
+        /// Write config, prompts, templates, and glossary to a .tar.gz bundle
+        #[arg(
+            long,
+            value_name = "path",
+            num_args = 0..=1,
+            default_missing_value = "koe-bundle.tar.gz"
+        )]
+        bundle: Option<PathBuf>,
+    },
+    /// Import a bundle from `koe config export --bundle`, keeping local API keys
+    Import {
+        /// Bundle archive to import
+        path: PathBuf,
+    },
 }
 
 pub fn run(args: &ConfigArgs, paths: &ConfigPaths) -> Result<(), ConfigError> {
@@ -28,6 +54,30 @@ pub fn run(args: &ConfigArgs, paths: &ConfigPaths) -> Result<(), ConfigError> {
         return Ok(());
     }
 
+    match &args.action {
+        Some(ConfigAction::Export { bundle: Some(dest) }) => {
+            let config = Config::load_or_create(paths)?;
+            let entries = bundle::export_bundle(paths, &config, dest)?;
+            println!("wrote {} ({})", dest.display(), entries.join(", "));
+            return Ok(());
+        }
+        Some(ConfigAction::Export { bundle: None }) => {
+            let config = Config::load_or_create(paths)?;
+            let output = toml::to_string_pretty(&config.without_secrets())?;
+            println!("{output}");
+            return Ok(());
+        }
+        Some(ConfigAction::Import { path }) => {
+            let before = Config::load_or_create(paths)?;
+            let entries = bundle::import_bundle(paths, path)?;
+            let after = Config::load(paths)?;
+            AuditLog::new(paths).record_config_change("config_import", &before, &after)?;
+            println!("imported {} ({})", path.display(), entries.join(", "));
+            return Ok(());
+        }
+        None => {}
+    }
+
     if args.edit && (!args.set.is_empty() || args.print) {
         return Err(ConfigError::Validation(
             "--edit cannot be combined with --set or --print".into(),
@@ -36,19 +86,24 @@ pub fn run(args: &ConfigArgs, paths: &ConfigPaths) -> Result<(), ConfigError> {
 
     let mut config = Config::load_or_create(paths)?;
 
+    let audit = AuditLog::new(paths);
     if args.edit {
+        let before = config;
         edit_config(paths)?;
         config = Config::load(paths)?;
+        audit.record_config_change("config_edit", &before, &config)?;
         config.validate()?;
         return Ok(());
     }
 
     if !args.set.is_empty() {
+        let before = config.clone();
         for assignment in &args.set {
             apply_set(&mut config, assignment)?;
         }
         config.validate()?;
         Config::write(paths, &config)?;
+        audit.record_config_change("config_set", &before, &config)?;
     }
 
     if args.print || (args.set.is_empty() && !args.edit) {
@@ -175,6 +230,15 @@ fn apply_set(config: &mut Config, assignment: &str) -> Result<(), ConfigError> {
         "audio.microphone_device_id" => {
             config.audio.microphone_device_id = value.to_string();
         }
+        "audio.multitrack" => {
+            config.audio.multitrack = parse_bool(value, key)?;
+        }
+        "audio.health_check" => {
+            config.audio.health_check = parse_bool(value, key)?;
+        }
+        "audio.archive_format" => {
+            config.audio.archive_format = value.to_string();
+        }
         "audio.mixdown.agc.enabled" => {
             config.audio.mixdown.agc.enabled = parse_bool(value, key)?;
         }
@@ -238,6 +302,15 @@ fn apply_set(config: &mut Config, assignment: &str) -> Result<(), ConfigError> {
         "transcribe.cloud.api_key" => {
             config.transcribe.cloud.api_key = value.to_string();
         }
+        "transcribe.streaming" => {
+            config.transcribe.streaming = parse_bool(value, key)?;
+        }
+        "transcribe.failover" => {
+            config.transcribe.failover = parse_bool(value, key)?;
+        }
+        "transcribe.offline_queue_chunks" => {
+            config.transcribe.offline_queue_chunks = parse_u32(value, key)?;
+        }
         "transcribe.provider" => {
             set_active_provider(
                 "transcribe.provider",
@@ -316,6 +389,36 @@ fn apply_set(config: &mut Config, assignment: &str) -> Result<(), ConfigError> {
         "summarize.prompt_profile" => {
             config.summarize.prompt_profile = value.to_string();
         }
+        "summarize.flashcards" => {
+            config.summarize.flashcards = parse_bool(value, key)?;
+        }
+        "summarize.chapters" => {
+            config.summarize.chapters = parse_bool(value, key)?;
+        }
+        "summarize.auto_title" => {
+            config.summarize.auto_title = parse_bool(value, key)?;
+        }
+        "summarize.schedule" => {
+            config.summarize.schedule = value.to_string();
+        }
+        "summarize.trigger_segments" => {
+            config.summarize.trigger_segments = parse_u32(value, key)?;
+        }
+        "summarize.pause_ms" => {
+            config.summarize.pause_ms = parse_u32(value, key)?;
+        }
+        "summarize.interval_secs" => {
+            config.summarize.interval_secs = parse_u32(value, key)?;
+        }
+        "summarize.window_segments" => {
+            config.summarize.window_segments = parse_u32(value, key)?;
+        }
+        "summarize.max_ops" => {
+            config.summarize.max_ops = parse_u32(value, key)?;
+        }
+        "summarize.consolidate_secs" => {
+            config.summarize.consolidate_secs = parse_u32(value, key)?;
+        }
         "session.context" => {
             config.session.context = value.to_string();
         }
@@ -325,9 +428,298 @@ fn apply_set(config: &mut Config, assignment: &str) -> Result<(), ConfigError> {
         "session.export_dir" => {
             config.session.export_dir = value.to_string();
         }
+        "session.low_confidence_threshold" => {
+            config.session.low_confidence_threshold = parse_f32(value, key)?;
+        }
+        "process.vad" => {
+            config.process.vad = value.to_string();
+        }
+        "process.vad_threshold" => {
+            config.process.vad_threshold = parse_f32(value, key)?;
+        }
+        "process.vad_min_speech_ms" => {
+            config.process.vad_min_speech_ms = parse_u32(value, key)?;
+        }
+        "process.vad_hangover_ms" => {
+            config.process.vad_hangover_ms = parse_u32(value, key)?;
+        }
+        "process.chunk_min_ms" => {
+            config.process.chunk_min_ms = parse_u32(value, key)?;
+        }
+        "process.chunk_target_ms" => {
+            config.process.chunk_target_ms = parse_u32(value, key)?;
+        }
+        "process.chunk_max_ms" => {
+            config.process.chunk_max_ms = parse_u32(value, key)?;
+        }
+        "process.chunk_overlap_ms" => {
+            config.process.chunk_overlap_ms = parse_u32(value, key)?;
+        }
+        "process.split_turns" => {
+            config.process.split_turns = parse_bool(value, key)?;
+        }
+        "process.calibrate" => {
+            config.process.calibrate = parse_bool(value, key)?;
+        }
+        "session.fsync" => {
+            config.session.fsync = value.to_string();
+        }
+        "session.export_layout" => {
+            config.session.export_layout = value.to_string();
+        }
+        "session.export_flatten" => {
+            config.session.export_flatten = parse_bool(value, key)?;
+        }
+        "session.tag_prompt" => {
+            config.session.tag_prompt = parse_bool(value, key)?;
+        }
+        "session.auto_start" => {
+            config.session.auto_start = parse_bool(value, key)?;
+        }
+        "session.autosave_export_minutes" => {
+            config.session.autosave_export_minutes = parse_u32(value, key)?;
+        }
+        "calendar.ics" => {
+            config.calendar.ics = value.to_string();
+        }
+        "calendar.early_minutes" => {
+            config.calendar.early_minutes = parse_u32(value, key)?;
+        }
+        "detect.enabled" => {
+            config.detect.enabled = parse_bool(value, key)?;
+        }
+        "detect.action" => {
+            config.detect.action = value.to_string();
+        }
+        "detect.trigger" => {
+            config.detect.trigger = value.to_string();
+        }
+        "detect.apps" => {
+            config.detect.apps = parse_list(value);
+        }
+        "detect.browsers" => {
+            config.detect.browsers = parse_list(value);
+        }
+        "detect.interval_secs" => {
+            config.detect.interval_secs = parse_u32(value, key)?;
+        }
         "ui.color_theme" => {
             config.ui.color_theme = value.to_string();
         }
+        "ui.pause_key" => {
+            config.ui.pause_key = value.to_string();
+        }
+        "ui.hold_to_pause_key" => {
+            config.ui.hold_to_pause_key = value.to_string();
+        }
+        "ui.layout" => {
+            config.ui.layout = value.to_string();
+        }
+        "ui.split_percent" => {
+            config.ui.split_percent = parse_u16(value, key)?;
+        }
+        "ui.check_updates" => {
+            config.ui.check_updates = parse_bool(value, key)?;
+        }
+        "limits.max_rss_mb" => {
+            config.limits.max_rss_mb = parse_u32(value, key)?;
+        }
+        "limits.degraded_model" => {
+            config.limits.degraded_model = value.to_string();
+        }
+        "webhooks.meeting_start" => {
+            config.webhooks.meeting_start = parse_list(value);
+        }
+        "webhooks.notes_patch" => {
+            config.webhooks.notes_patch = parse_list(value);
+        }
+        "webhooks.meeting_end" => {
+            config.webhooks.meeting_end = parse_list(value);
+        }
+        "outputs.pipe" => {
+            config.outputs.pipe = value.to_string();
+        }
+        "outputs.osc" => {
+            config.outputs.osc = value.to_string();
+        }
+        "http.enabled" => {
+            config.http.enabled = parse_bool(value, key)?;
+        }
+        "http.port" => {
+            config.http.port = parse_u16(value, key)?;
+        }
+        "metrics.enabled" => {
+            config.metrics.enabled = parse_bool(value, key)?;
+        }
+        "metrics.share" => {
+            config.metrics.share = parse_bool(value, key)?;
+        }
+        "metrics.share_url" => {
+            config.metrics.share_url = value.to_string();
+        }
+        "redact.enabled" => {
+            config.redact.enabled = parse_bool(value, key)?;
+        }
+        "redact.emails" => {
+            config.redact.emails = parse_bool(value, key)?;
+        }
+        "redact.phones" => {
+            config.redact.phones = parse_bool(value, key)?;
+        }
+        "redact.cards" => {
+            config.redact.cards = parse_bool(value, key)?;
+        }
+        "redact.keywords" => {
+            config.redact.keywords = parse_list(value);
+        }
+        "translate.enabled" => {
+            config.translate.enabled = parse_bool(value, key)?;
+        }
+        "translate.target_language" => {
+            config.translate.target_language = value.to_string();
+        }
+        "review.enabled" => {
+            config.review.enabled = parse_bool(value, key)?;
+        }
+        "review.obsidian_vault" => {
+            config.review.obsidian_vault = value.to_string();
+        }
+        "integrations.notion.token" => {
+            config.integrations.notion.token = value.to_string();
+        }
+        "integrations.notion.database_id" => {
+            config.integrations.notion.database_id = value.to_string();
+        }
+        "integrations.notion.title_property" => {
+            config.integrations.notion.title_property = value.to_string();
+        }
+        "integrations.notion.date_property" => {
+            config.integrations.notion.date_property = value.to_string();
+        }
+        "integrations.notion.participants_property" => {
+            config.integrations.notion.participants_property = value.to_string();
+        }
+        "integrations.notion.tags_property" => {
+            config.integrations.notion.tags_property = value.to_string();
+        }
+        "integrations.slack.webhook" | "review.slack_webhook" => {
+            config.integrations.slack.webhook = value.to_string();
+        }
+        "integrations.slack.channel" => {
+            config.integrations.slack.channel = value.to_string();
+        }
+        "integrations.slack.post_on_end" => {
+            config.integrations.slack.post_on_end = parse_bool(value, key)?;
+        }
+        "integrations.email.to" => {
+            config.integrations.email.to = parse_list(value);
+        }
+        "integrations.email.cc" => {
+            config.integrations.email.cc = parse_list(value);
+        }
+        "sync.backend" => {
+            config.sync.backend = value.to_string();
+        }
+        "sync.include_audio" => {
+            config.sync.include_audio = parse_bool(value, key)?;
+        }
+        "sync.prefix" => {
+            config.sync.prefix = value.to_string();
+        }
+        "sync.interval_secs" => {
+            config.sync.interval_secs = parse_u32(value, key)?;
+        }
+        "sync.s3.endpoint" => {
+            config.sync.s3.endpoint = value.to_string();
+        }
+        "sync.s3.bucket" => {
+            config.sync.s3.bucket = value.to_string();
+        }
+        "sync.s3.region" => {
+            config.sync.s3.region = value.to_string();
+        }
+        "sync.s3.access_key_id" => {
+            config.sync.s3.access_key_id = value.to_string();
+        }
+        "sync.s3.secret_access_key" => {
+            config.sync.s3.secret_access_key = value.to_string();
+        }
+        "sync.webdav.url" => {
+            config.sync.webdav.url = value.to_string();
+        }
+        "sync.webdav.username" => {
+            config.sync.webdav.username = value.to_string();
+        }
+        "sync.webdav.password" => {
+            config.sync.webdav.password = value.to_string();
+        }
+        _ => {
+            if let Some(rest) = key.strip_prefix("workspaces.") {
+                return apply_workspace_set(config, key, rest, value);
+            }
+            if let Some(rest) = key.strip_prefix("profiles.") {
+                return apply_profile_set(config, key, rest, value);
+            }
+            return Err(ConfigError::Validation(format!(
+                "unknown config key: {key}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Set `workspaces.<name>.<field>`, creating the workspace if needed.
+fn apply_workspace_set(
+    config: &mut Config,
+    key: &str,
+    rest: &str,
+    value: &str,
+) -> Result<(), ConfigError> {
+    let (name, field) = rest
+        .rsplit_once('.')
+        .ok_or_else(|| ConfigError::Validation(format!("unknown config key: {key}")))?;
+    if !is_valid_workspace_name(name) {
+        return Err(ConfigError::Validation(format!(
+            "workspace name {name:?} must use only letters, digits, '-' or '_'"
+        )));
+    }
+    let workspace = config.workspaces.entry(name.to_string()).or_default();
+    match field {
+        "sessions_dir" => workspace.sessions_dir = value.to_string(),
+        "export_dir" => workspace.export_dir = value.to_string(),
+        "context" => workspace.context = value.to_string(),
+        "participants" => workspace.participants = parse_participants(value)?,
+        _ => {
+            return Err(ConfigError::Validation(format!(
+                "unknown config key: {key}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Set `profiles.<name>.<field>`, creating the profile if needed.
+fn apply_profile_set(
+    config: &mut Config,
+    key: &str,
+    rest: &str,
+    value: &str,
+) -> Result<(), ConfigError> {
+    let (name, field) = rest
+        .rsplit_once('.')
+        .ok_or_else(|| ConfigError::Validation(format!("unknown config key: {key}")))?;
+    if !is_valid_workspace_name(name) {
+        return Err(ConfigError::Validation(format!(
+            "profile name {name:?} must use only letters, digits, '-' or '_'"
+        )));
+    }
+    let profile = config.profiles.entry(name.to_string()).or_default();
+    match field {
+        "context" => profile.context = value.to_string(),
+        "participants" => profile.participants = parse_participants(value)?,
+        "prompt_profile" => profile.prompt_profile = value.to_string(),
+        "transcribe" => profile.transcribe = value.to_string(),
+        "summarize" => profile.summarize = value.to_string(),
         _ => {
             return Err(ConfigError::Validation(format!(
                 "unknown config key: {key}"
@@ -438,6 +830,15 @@ fn parse_participants(value: &str) -> Result<Vec<String>, ConfigError> {
     Ok(participants)
 }
 
+/// Comma-separated list; empty clears it.
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::split_editor_command;
@@ -0,0 +1,144 @@
+use crate::config::DetectConfig;
+use crate::tui::UiEvent;
+use std::collections::HashSet;
+use std::process::Command;
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::Duration;
+
+/// Watches for meeting apps and reports each newly detected call once.
+pub fn spawn_watcher(config: DetectConfig, ui_tx: Sender<UiEvent>) {
+    let interval = Duration::from_secs(u64::from(config.interval_secs.max(1)));
+    let spawned = thread::Builder::new()
+        .name("koe-meeting-watch".into())
+        .spawn(move || {
+            let mut previous: Option<String> = None;
+            loop {
+                let processes = running_processes();
+                let audio_pids = if config.trigger == "audio" || !config.browsers.is_empty() {
+                    audio_pids()
+                } else {
+                    HashSet::new()
+                };
+                let current = detect_meeting(&processes, &audio_pids, &config);
+                if let Some(app) = current.as_ref()
+                    && previous.is_none()
+                    && ui_tx
+                        .send(UiEvent::MeetingDetected { app: app.clone() })
+                        .is_err()
+                {
+                    return;
+                }
+                previous = current;
+                thread::sleep(interval);
+            }
+        });
+    if let Err(e) = spawned {
+        eprintln!("meeting watcher thread spawn failed: {e}");
+    }
+}
+
+/// `(pid, executable name)` for every process, from `ps`.
+fn running_processes() -> Vec<(u32, String)> {
+    let Ok(output) = Command::new("ps").args(["-axo", "pid=,comm="]).output() else {
+        return Vec::new();
+    };
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (pid, command) = line.trim_start().split_once(char::is_whitespace)?;
+            let name = command.trim().rsplit('/').next()?;
+            Some((pid.parse().ok()?, name.to_string()))
+        })
+        .collect()
+}
+
+/// Processes CoreAudio keeps the machine awake for, i.e. those playing or recording audio.
+fn audio_pids() -> HashSet<u32> {
+    let Ok(output) = Command::new("pmset").args(["-g", "assertions"]).output() else {
+        return HashSet::new();
+    };
+    parse_audio_assertions(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// `Created for PID: N.` lines following a `com.apple.audio` assertion.
+fn parse_audio_assertions(text: &str) -> HashSet<u32> {
+    let mut pids = HashSet::new();
+    let mut in_audio = false;
+    for line in text.lines() {
+        if line.contains("named:") {
+            in_audio = line.contains("com.apple.audio");
+        } else if in_audio && let Some(rest) = line.trim().strip_prefix("Created for PID:") {
+            if let Ok(pid) = rest.trim().trim_end_matches('.').parse() {
+                pids.insert(pid);
+            }
+            in_audio = false;
+        }
+    }
+    pids
+}
+
+/// The configured app in a call, if any. Meeting apps count while running, or only
+/// while producing audio with `trigger = "audio"`; browsers (Meet) only while producing audio.
+fn detect_meeting(
+    processes: &[(u32, String)],
+    audio_pids: &HashSet<u32>,
+    config: &DetectConfig,
+) -> Option<String> {
+    let require_audio = config.trigger == "audio";
+    let find = |names: &[String], audio_only: bool| {
+        processes.iter().find_map(|(pid, process)| {
+            let app = names.iter().find(|name| matches_app(process, name))?;
+            (!audio_only || audio_pids.contains(pid)).then(|| app.clone())
+        })
+    };
+    find(&config.apps, require_audio).or_else(|| find(&config.browsers, true))
+}
+
+/// Case-insensitive; `Google Chrome` also matches its `Google Chrome Helper` processes.
+fn matches_app(process: &str, app: &str) -> bool {
+    let process = process.to_lowercase();
+    let app = app.to_lowercase();
+    !app.is_empty()
+        && (process == app
+            || process
+                .strip_prefix(&app)
+                .is_some_and(|rest| rest.starts_with(' ')))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_meeting, parse_audio_assertions};
+    use crate::config::DetectConfig;
+
+    #[test]
+    fn detects_meeting_apps_and_browser_audio() {
+        let assertions = "\
+Listed by owning process:
+   pid 389(coreaudiod): [0x0000f9e900019c4f] 00:00:12 PreventUserIdleSleep named: \"com.apple.audio.BuiltInMicrophoneDevice.context.preventuseridlesleep\"
+\tCreated for PID: 812.
+   pid 120(powerd): [0x0000f9e900019c50] 00:10:00 PreventUserIdleSleep named: \"Powerd - Prevent sleep while display is on\"
+\tCreated for PID: 700.
+";
+        let audio = parse_audio_assertions(assertions);
+        assert_eq!(audio.iter().copied().collect::<Vec<_>>(), [812]);
+
+        let processes = vec![
+            (700, "zoom.us".to_string()),
+            (812, "Google Chrome Helper (Renderer)".to_string()),
+        ];
+        let mut config = DetectConfig::default();
+        // Zoom is running but silent, so only the browser tab in a call counts.
+        assert_eq!(
+            detect_meeting(&processes, &audio, &config).as_deref(),
+            Some("Google Chrome")
+        );
+        config.trigger = "running".into();
+        assert_eq!(
+            detect_meeting(&processes, &audio, &config).as_deref(),
+            Some("zoom.us")
+        );
+        config.trigger = "audio".into();
+        assert_eq!(detect_meeting(&processes[..1], &audio, &config), None);
+    }
+}
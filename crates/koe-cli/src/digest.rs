@@ -0,0 +1,229 @@
+use crate::RunArgs;
+use crate::audit::AuditLog;
+use crate::config::{Config, ConfigPaths};
+use crate::session::{SessionError, StoredSession, list_sessions, note_markdown};
+use clap::Args;
+use koe_core::summarize::digest::{DigestMeeting, generate_digest};
+use koe_core::types::NoteKind;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+use time::format_description::well_known::Rfc3339;
+use time::{Duration, OffsetDateTime};
+
+#[derive(Args, Debug, Clone)]
+pub struct DigestArgs {
+    /// Window of meetings to cover, e.g. `24h`, `7d`, or `2w`
+    #[arg(long, default_value = "7d", value_name = "window")]
+    since: String,
+
+    /// Only sessions carrying this tag
+    #[arg(long, value_name = "tag")]
+    tag: Option<String>,
+
+    /// Write here instead of `digests/digest-<date>.md` under the export dir
+    #[arg(long, short, value_name = "path")]
+    output: Option<PathBuf>,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Debug, Error)]
+pub enum DigestError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("time error: {0}")]
+    Time(#[from] time::error::Format),
+    #[error("{0}")]
+    Message(String),
+}
+
+/// Roll the notes of every session started within `--since` into one
+/// markdown digest: a provider-written overview, the open action items, and
+/// each meeting's notes.
+pub fn run(args: &DigestArgs, paths: &ConfigPaths, config: &Config) -> Result<(), DigestError> {
+    let window = parse_window(&args.since).ok_or_else(|| {
+        DigestError::Message(format!(
+            "invalid --since {}: expected e.g. 24h, 7d, or 2w",
+            args.since
+        ))
+    })?;
+    let run = args
+        .run
+        .clone()
+        .resolve(config)
+        .map_err(DigestError::Message)?;
+    let (session_paths, export_dir) =
+        crate::session_storage(paths, config, run.workspace.as_deref());
+    let now = OffsetDateTime::now_utc();
+    let from = now - window;
+    // Start times are UTC RFC 3339, so they compare as strings.
+    let cutoff = from.format(&Rfc3339)?;
+    let mut sessions: Vec<StoredSession> = list_sessions(&session_paths.sessions_dir)?
+        .into_iter()
+        .filter(|session| session.metadata.start_time >= cutoff)
+        .filter(|session| {
+            args.tag
+                .as_deref()
+                .is_none_or(|tag| session.metadata.has_tag(tag))
+        })
+        .collect();
+    // Oldest first so the digest reads in meeting order.
+    sessions.reverse();
+
+    let mut meetings = Vec::new();
+    for session in &sessions {
+        let notes = session.notes()?;
+        if !notes.bullets.is_empty() {
+            meetings.push(DigestMeeting {
+                name: session.metadata.display_name(),
+                notes,
+            });
+        }
+    }
+    if meetings.is_empty() {
+        return Err(DigestError::Message(format!(
+            "no meeting notes in the last {}",
+            args.since
+        )));
+    }
+
+    let profile = run.summarize_profiles.active_profile();
+    let mut provider = crate::create_summarize_for_mode(
+        &run.summarize_profiles,
+        run.summarize_profiles.active.as_str(),
+    )
+    .map_err(|e| DigestError::Message(format!("summarize init failed: {e}")))?;
+    AuditLog::new(paths).record(
+        "digest",
+        &format!(
+            "summarize {}/{} over {} sessions",
+            profile.provider,
+            profile.model,
+            meetings.len()
+        ),
+        None,
+    )?;
+    let period = format!("in the last {}", args.since);
+    let rollup = generate_digest(provider.as_mut(), &meetings, &period)
+        .map_err(|e| DigestError::Message(format!("digest failed: {e}")))?;
+
+    let (from_date, to_date) = (date(from)?, date(now)?);
+    let markdown = render_digest(&from_date, &to_date, &rollup, &meetings);
+    let path = match &args.output {
+        Some(path) => path.clone(),
+        None => export_dir
+            .map(|export_dir| export_dir.root)
+            .unwrap_or_else(|| session_paths.base_dir.clone())
+            .join("digests")
+            .join(format!("digest-{to_date}.md")),
+    };
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, markdown)?;
+    println!("{}", path.display());
+    Ok(())
+}
+
+/// `24h`, `7d`, or `2w` as a duration; `None` for anything else or zero.
+fn parse_window(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let unit = value.chars().last()?;
+    let count: i64 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    if count <= 0 {
+        return None;
+    }
+    match unit {
+        'h' => Some(Duration::hours(count)),
+        'd' => Some(Duration::days(count)),
+        'w' => Some(Duration::weeks(count)),
+        _ => None,
+    }
+}
+
+fn date(time: OffsetDateTime) -> Result<String, DigestError> {
+    let formatted = time.format(&Rfc3339)?;
+    Ok(formatted.get(..10).unwrap_or(&formatted).to_string())
+}
+
+fn render_digest(from: &str, to: &str, rollup: &str, meetings: &[DigestMeeting]) -> String {
+    let mut output = format!("# Digest {from} to {to}\n\n## Overview\n\n");
+    if rollup.is_empty() {
+        output.push_str("(none)\n");
+    } else {
+        output.push_str(&format!("{rollup}\n"));
+    }
+
+    output.push_str("\n## Open Action Items\n\n");
+    let mut any_open = false;
+    for meeting in meetings {
+        for bullet in &meeting.notes.bullets {
+            if bullet.kind == NoteKind::Action && !bullet.done {
+                any_open = true;
+                output.push_str(&format!(
+                    "- [ ] {} ({})\n",
+                    note_markdown(bullet),
+                    meeting.name
+                ));
+            }
+        }
+    }
+    if !any_open {
+        output.push_str("- (none)\n");
+    }
+
+    output.push_str("\n## Meetings\n");
+    for meeting in meetings {
+        output.push_str(&format!("\n### {}\n\n", meeting.name));
+        for bullet in &meeting.notes.bullets {
+            output.push_str(&format!("- {}\n", note_markdown(bullet)));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_window, render_digest};
+    use crate::test_support::bullet;
+    use koe_core::summarize::digest::DigestMeeting;
+    use koe_core::types::{MeetingNotes, NoteBullet, NoteKind};
+    use time::Duration;
+
+    #[test]
+    fn digest_lists_open_actions_and_meeting_notes() {
+        assert_eq!(parse_window("7d"), Some(Duration::days(7)));
+        assert_eq!(parse_window("2w"), Some(Duration::weeks(2)));
+        assert_eq!(parse_window("0d"), None);
+        assert_eq!(parse_window("7"), None);
+
+        let meetings = vec![DigestMeeting {
+            name: "2026-10-14 launch sync".to_string(),
+            notes: MeetingNotes {
+                bullets: vec![
+                    bullet("n1", "Ship on Friday", NoteKind::Decision),
+                    bullet("n2", "Send checklist", NoteKind::Action),
+                    NoteBullet {
+                        done: true,
+                        ..bullet("n3", "Book venue", NoteKind::Action)
+                    },
+                ],
+            },
+        }];
+        let markdown = render_digest("2026-10-11", "2026-10-18", "- Launch on track", &meetings);
+        assert!(markdown.starts_with(
+            "# Digest 2026-10-11 to 2026-10-18\n\n## Overview\n\n- Launch on track\n"
+        ));
+        assert!(markdown.contains(
+            "## Open Action Items\n\n- [ ] Send checklist (2026-10-14 launch sync)\n\n## Meetings"
+        ));
+        assert!(markdown.contains(
+            "### 2026-10-14 launch sync\n\n- Ship on Friday\n- Send checklist\n- [x] Book venue\n"
+        ));
+    }
+}
@@ -0,0 +1,369 @@
+use crate::RunArgs;
+use crate::config::{Config, ConfigPaths, ProviderConfig};
+use clap::Args;
+use koe_core::capture::{CaptureConfig, create_capture, list_audio_inputs, probe_screen_recording};
+use koe_core::types::CaptureStats;
+use serde::Deserialize;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+const PING_TIMEOUT: Duration = Duration::from_secs(5);
+const MIC_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+const GEMINI_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta";
+const ASSEMBLYAI_BASE_URL: &str = "https://api.assemblyai.com/v2";
+const GROQ_BASE_URL: &str = "https://api.groq.com/openai/v1";
+const OLLAMA_BASE_URL: &str = "http://localhost:11434";
+const OPENROUTER_BASE_URL: &str = "https://openrouter.ai/api/v1";
+
+#[derive(Args, Debug, Clone)]
+pub struct DoctorArgs {
+    /// Skip provider network checks
+    #[arg(long)]
+    pub offline: bool,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Debug, Error)]
+pub enum DoctorError {
+    #[error("{0}")]
+    Message(String),
+    #[error("{0} check(s) failed")]
+    Failed(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Check {
+    name: String,
+    status: CheckStatus,
+    detail: String,
+    /// Extra lines printed under the check, e.g. device names.
+    notes: Vec<String>,
+}
+
+impl Check {
+    fn new(name: impl Into<String>, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            detail: detail.into(),
+            notes: Vec::new(),
+        }
+    }
+
+    fn pass(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Pass, detail)
+    }
+
+    fn warn(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Warn, detail)
+    }
+
+    fn fail(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Fail, detail)
+    }
+}
+
+pub fn run(args: &DoctorArgs, paths: &ConfigPaths, config: &Config) -> Result<(), DoctorError> {
+    let run = args
+        .run
+        .clone()
+        .resolve(config)
+        .map_err(DoctorError::Message)?;
+
+    let mut checks = vec![check_config(paths, config)];
+    checks.push(check_screen_recording());
+    checks.push(check_audio_inputs());
+    checks.push(check_microphone(&config.audio.microphone_device_id));
+
+    let transcribe = run.transcribe_profiles.active_profile();
+    let summarize = run.summarize_profiles.active_profile();
+    if transcribe.provider == "whisper" {
+        checks.push(check_whisper_model(
+            "whisper model",
+            &transcribe.model,
+            &paths.models_dir,
+        ));
+    }
+    if config.limits.max_rss_mb > 0 {
+        checks.push(check_whisper_model(
+            "degraded model",
+            &config.limits.degraded_model,
+            &paths.models_dir,
+        ));
+    }
+    if args.offline {
+        checks.push(Check::warn("providers", "skipped (--offline)"));
+    } else {
+        checks.push(check_provider("transcribe", transcribe));
+        checks.push(check_provider("summarize", summarize));
+    }
+
+    print!("{}", render_report(&checks));
+    let failed = checks
+        .iter()
+        .filter(|check| check.status == CheckStatus::Fail)
+        .count();
+    if failed > 0 {
+        return Err(DoctorError::Failed(failed));
+    }
+    Ok(())
+}
+
+fn check_config(paths: &ConfigPaths, config: &Config) -> Check {
+    match config.validate() {
+        Ok(()) => Check::pass("config", paths.config_path.display().to_string()),
+        Err(e) => Check::fail("config", e.to_string()),
+    }
+}
+
+fn check_screen_recording() -> Check {
+    match probe_screen_recording() {
+        Ok(()) => Check::pass("screen recording", "granted"),
+        Err(e) => Check::fail(
+            "screen recording",
+            format!("{e}; allow koe in System Settings → Privacy & Security → Screen Recording"),
+        ),
+    }
+}
+
+fn check_audio_inputs() -> Check {
+    let inputs = list_audio_inputs();
+    if inputs.is_empty() {
+        return Check::fail("audio inputs", "no input devices found");
+    }
+    let mut check = Check::pass("audio inputs", format!("{} device(s)", inputs.len()));
+    check.notes = inputs
+        .iter()
+        .map(|device| {
+            let marker = if device.is_default { " (default)" } else { "" };
+            format!("{} [{}]{marker}", device.name, device.id)
+        })
+        .collect();
+    check
+}
+
+/// Start a mic-only capture and wait briefly for a frame; nothing arriving
+/// usually means the Microphone permission is missing.
+fn check_microphone(device_id: &str) -> Check {
+    let capture_config = CaptureConfig {
+        capture_system: false,
+        capture_microphone: true,
+        microphone_device_id: crate::resolve_microphone_device_id(true, device_id),
+    };
+    let mut capture = match create_capture(CaptureStats::new(), capture_config) {
+        Ok(capture) => capture,
+        Err(e) => return Check::fail("microphone", e.to_string()),
+    };
+    if let Err(e) = capture.start() {
+        return Check::fail("microphone", e.to_string());
+    }
+    let deadline = Instant::now() + MIC_PROBE_TIMEOUT;
+    let mut received = false;
+    while Instant::now() < deadline {
+        if capture.try_recv_mic().is_some() {
+            received = true;
+            break;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    capture.stop();
+    if received {
+        Check::pass("microphone", "receiving audio")
+    } else {
+        Check::fail(
+            "microphone",
+            format!(
+                "no audio within {}s; allow koe in System Settings → Privacy & Security → Microphone",
+                MIC_PROBE_TIMEOUT.as_secs()
+            ),
+        )
+    }
+}
+
+fn check_whisper_model(name: &str, model: &str, models_dir: &Path) -> Check {
+    match crate::whisper_model_path(model, models_dir) {
+        Some(path) => Check::pass(name, path.display().to_string()),
+        None => Check::fail(name, format!("{} not found; run `koe init`", model.trim())),
+    }
+}
+
+fn check_provider(stage: &str, profile: &ProviderConfig) -> Check {
+    let name = format!("{stage} provider");
+    let env_key = match profile.provider.as_str() {
+        "gemini" => std::env::var("GEMINI_API_KEY").unwrap_or_default(),
+        "assemblyai" => std::env::var("ASSEMBLYAI_API_KEY").unwrap_or_default(),
+        _ => String::new(),
+    };
+    let key = match profile.api_key.trim() {
+        "" => env_key.trim(),
+        key => key,
+    };
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(PING_TIMEOUT))
+        .build()
+        .into();
+    match profile.provider.as_str() {
+        "none" => Check::warn(name, "disabled"),
+        "whisper" => Check::pass(name, "whisper (local)"),
+        "groq" | "assemblyai" | "openrouter" | "gemini" if key.is_empty() => {
+            Check::fail(name, format!("{}: api key not set", profile.provider))
+        }
+        "groq" => {
+            let base = std::env::var("GROQ_BASE_URL").unwrap_or_else(|_| GROQ_BASE_URL.into());
+            match agent
+                .get(format!("{base}/models"))
+                .header("Authorization", &format!("Bearer {key}"))
+                .call()
+            {
+                Ok(_) => Check::pass(name, "groq reachable, key accepted"),
+                Err(e) => Check::fail(name, format!("groq: {e}")),
+            }
+        }
+        "openrouter" => {
+            let base =
+                std::env::var("OPENROUTER_BASE_URL").unwrap_or_else(|_| OPENROUTER_BASE_URL.into());
+            match agent
+                .get(format!("{base}/key"))
+                .header("Authorization", &format!("Bearer {key}"))
+                .call()
+            {
+                Ok(_) => Check::pass(name, "openrouter reachable, key accepted"),
+                Err(e) => Check::fail(name, format!("openrouter: {e}")),
+            }
+        }
+        "assemblyai" => {
+            let base =
+                std::env::var("ASSEMBLYAI_BASE_URL").unwrap_or_else(|_| ASSEMBLYAI_BASE_URL.into());
+            match agent
+                .get(format!("{base}/transcript?limit=1"))
+                .header("authorization", key)
+                .call()
+            {
+                Ok(_) => Check::pass(name, "assemblyai reachable, key accepted"),
+                Err(e) => Check::fail(name, format!("assemblyai: {e}")),
+            }
+        }
+        "gemini" => {
+            let base = std::env::var("GEMINI_BASE_URL").unwrap_or_else(|_| GEMINI_BASE_URL.into());
+            match agent
+                .get(format!("{base}/models?pageSize=1"))
+                .header("x-goog-api-key", key)
+                .call()
+            {
+                Ok(_) => Check::pass(name, "gemini reachable, key accepted"),
+                Err(e) => Check::fail(name, format!("gemini: {e}")),
+            }
+        }
+        "ollama" => {
+            let base = std::env::var("OLLAMA_BASE_URL").unwrap_or_else(|_| OLLAMA_BASE_URL.into());
+            let tags = agent
+                .get(format!("{base}/api/tags"))
+                .call()
+                .and_then(|response| response.into_body().read_json::<OllamaTags>());
+            match tags {
+                Ok(tags) if ollama_has_model(&tags, &profile.model) => {
+                    Check::pass(name, format!("ollama serving {}", profile.model))
+                }
+                Ok(_) => Check::fail(
+                    name,
+                    format!("ollama running but {} not pulled", profile.model),
+                ),
+                Err(e) => Check::fail(name, format!("ollama at {base}: {e}")),
+            }
+        }
+        other => Check::warn(name, format!("{other}: no check available")),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaTags {
+    #[serde(default)]
+    models: Vec<OllamaModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaModel {
+    name: String,
+}
+
+/// Ollama lists untagged models as `<name>:latest`.
+fn ollama_has_model(tags: &OllamaTags, model: &str) -> bool {
+    let model = model.trim();
+    tags.models
+        .iter()
+        .any(|entry| entry.name == model || entry.name == format!("{model}:latest"))
+}
+
+fn render_report(checks: &[Check]) -> String {
+    let width = checks
+        .iter()
+        .map(|check| check.name.len())
+        .max()
+        .unwrap_or(0);
+    let mut out = String::from("koe doctor\n");
+    for check in checks {
+        out.push_str(&format!(
+            "  {}  {:<width$}  {}\n",
+            check.status.label(),
+            check.name,
+            check.detail
+        ));
+        for note in &check.notes {
+            out.push_str(&format!("        {:<width$}  - {note}\n", ""));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Check, OllamaModel, OllamaTags, check_whisper_model, ollama_has_model, render_report,
+    };
+    use std::fs;
+
+    #[test]
+    fn report_lists_checks_and_finds_models() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::write(temp.path().join("ggml-base.en.bin"), b"").unwrap();
+        let found = check_whisper_model("whisper model", "base.en", temp.path());
+        let missing = check_whisper_model("whisper model", "large-v3", temp.path());
+
+        let report = render_report(&[found, missing, Check::warn("providers", "skipped")]);
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines[0], "koe doctor");
+        assert!(lines[1].starts_with("  pass  whisper model"));
+        assert!(lines[2].starts_with("  fail  whisper model"));
+        assert!(lines[2].ends_with("large-v3 not found; run `koe init`"));
+        assert!(lines[3].starts_with("  warn  providers    "));
+
+        let tags = OllamaTags {
+            models: vec![OllamaModel {
+                name: "qwen3:latest".to_string(),
+            }],
+        };
+        assert!(ollama_has_model(&tags, "qwen3"));
+        assert!(!ollama_has_model(&tags, "llama3"));
+    }
+}
@@ -0,0 +1,508 @@
+/// A4 in PDF points, with the same margin on every side.
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const PAGE_MARGIN: f32 = 56.0;
+/// Average Helvetica glyph width as a fraction of the font size, used to wrap
+/// lines without font metrics.
+const GLYPH_WIDTH: f32 = 0.52;
+const TITLE_SIZE: f32 = 18.0;
+const HEADING_SIZE: f32 = 13.0;
+const BODY_SIZE: f32 = 10.0;
+const TABLE_PADDING: f32 = 6.0;
+
+const CONTENT_TYPES_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/></Types>"#;
+const RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+/// Output format for [`Document`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    Docx,
+    Pdf,
+}
+
+impl DocumentFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "docx" => Some(Self::Docx),
+            "pdf" => Some(Self::Pdf),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Docx => "docx",
+            Self::Pdf => "pdf",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Heading(String),
+    Paragraph(String),
+    Bullet(String),
+    Table {
+        header: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+}
+
+/// A plain document (title, headings, paragraphs, bullets, tables) written as
+/// DOCX or PDF without external tools; both writers use built-in fonts only.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    pub title: String,
+    pub blocks: Vec<Block>,
+}
+
+impl Document {
+    pub fn render(&self, format: DocumentFormat) -> Vec<u8> {
+        match format {
+            DocumentFormat::Docx => self.to_docx(),
+            DocumentFormat::Pdf => self.to_pdf(),
+        }
+    }
+
+    /// Minimal WordprocessingML package: content types, package rels, and the
+    /// body, stored uncompressed in a zip.
+    pub fn to_docx(&self) -> Vec<u8> {
+        let mut body = docx_paragraph(&self.title, Some(TITLE_SIZE), None);
+        for block in &self.blocks {
+            match block {
+                Block::Heading(text) => {
+                    body.push_str(&docx_paragraph(text, Some(HEADING_SIZE), None));
+                }
+                Block::Paragraph(text) => body.push_str(&docx_paragraph(text, None, None)),
+                Block::Bullet(text) => body.push_str(&docx_paragraph(
+                    &format!("\u{2022}\t{text}"),
+                    None,
+                    Some(r#"<w:ind w:left="360" w:hanging="240"/>"#),
+                )),
+                Block::Table { header, rows } => {
+                    body.push_str(
+                        r#"<w:tbl><w:tblPr><w:tblW w:w="5000" w:type="pct"/><w:tblBorders>"#,
+                    );
+                    for edge in ["top", "left", "bottom", "right", "insideH", "insideV"] {
+                        body.push_str(&format!(
+                            r#"<w:{edge} w:val="single" w:sz="4" w:space="0" w:color="999999"/>"#
+                        ));
+                    }
+                    body.push_str("</w:tblBorders></w:tblPr>");
+                    body.push_str(&docx_row(header, true));
+                    for row in rows {
+                        body.push_str(&docx_row(row, false));
+                    }
+                    // Word expects a paragraph between a table and what follows.
+                    body.push_str("</w:tbl><w:p/>");
+                }
+            }
+        }
+        let document = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{body}<w:sectPr><w:pgSz w:w="11906" w:h="16838"/><w:pgMar w:top="1134" w:right="1134" w:bottom="1134" w:left="1134" w:header="708" w:footer="708" w:gutter="0"/></w:sectPr></w:body></w:document>"#
+        );
+        stored_zip(&[
+            ("[Content_Types].xml", CONTENT_TYPES_XML.as_bytes()),
+            ("_rels/.rels", RELS_XML.as_bytes()),
+            ("word/document.xml", document.as_bytes()),
+        ])
+    }
+
+    /// PDF 1.4 in Helvetica with WinAnsi encoding; characters outside it are
+    /// replaced.
+    pub fn to_pdf(&self) -> Vec<u8> {
+        let mut pages = PdfPages::new();
+        pages.wrapped(&self.title, true, TITLE_SIZE, PAGE_MARGIN, 0.0);
+        for block in &self.blocks {
+            match block {
+                Block::Heading(text) => {
+                    pages.gap(HEADING_SIZE * 0.8);
+                    pages.wrapped(text, true, HEADING_SIZE, PAGE_MARGIN, 0.0);
+                }
+                Block::Paragraph(text) => {
+                    pages.wrapped(text, false, BODY_SIZE, PAGE_MARGIN, 0.0);
+                }
+                Block::Bullet(text) => {
+                    pages.wrapped(&format!("- {text}"), false, BODY_SIZE, PAGE_MARGIN, 8.0);
+                }
+                Block::Table { header, rows } => pages.table(header, rows),
+            }
+        }
+        pages.finish()
+    }
+}
+
+fn docx_paragraph(text: &str, size: Option<f32>, indent: Option<&str>) -> String {
+    let props = match size {
+        Some(size) => format!(
+            r#"<w:rPr><w:b/><w:sz w:val="{}"/></w:rPr>"#,
+            size as u32 * 2
+        ),
+        None => String::new(),
+    };
+    let paragraph_props = indent
+        .map(|indent| format!("<w:pPr>{indent}</w:pPr>"))
+        .unwrap_or_default();
+    let runs: Vec<String> = text
+        .split('\t')
+        .map(|part| format!(r#"<w:t xml:space="preserve">{}</w:t>"#, escape_xml(part)))
+        .collect();
+    format!(
+        "<w:p>{paragraph_props}<w:r>{props}{}</w:r></w:p>",
+        runs.join("<w:tab/>")
+    )
+}
+
+fn docx_row(cells: &[String], header: bool) -> String {
+    let props = if header { "<w:rPr><w:b/></w:rPr>" } else { "" };
+    let cells: String = cells
+        .iter()
+        .map(|cell| {
+            format!(
+                r#"<w:tc><w:p><w:r>{props}<w:t xml:space="preserve">{}</w:t></w:r></w:p></w:tc>"#,
+                escape_xml(cell)
+            )
+        })
+        .collect();
+    format!("<w:tr>{cells}</w:tr>")
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_control() || *c == '\t')
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Zip archive with every entry stored (method 0), which DOCX readers accept.
+fn stored_zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    // 1980-01-01 00:00 in DOS date/time fields.
+    const DOS_DATE: u16 = 0x21;
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    for (name, data) in entries {
+        let offset = out.len() as u32;
+        let crc = crc32(data);
+        let size = data.len() as u32;
+        out.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        for value in [20u16, 0, 0, 0, DOS_DATE] {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        for value in [crc, size, size] {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(data);
+
+        central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        for value in [20u16, 20, 0, 0, 0, DOS_DATE] {
+            central.extend_from_slice(&value.to_le_bytes());
+        }
+        for value in [crc, size, size] {
+            central.extend_from_slice(&value.to_le_bytes());
+        }
+        for value in [name.len() as u16, 0, 0, 0, 0] {
+            central.extend_from_slice(&value.to_le_bytes());
+        }
+        central.extend_from_slice(&0u32.to_le_bytes());
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+    let central_offset = out.len() as u32;
+    out.extend_from_slice(&central);
+    out.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    let count = entries.len() as u16;
+    for value in [0u16, 0, count, count] {
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+    out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    out.extend_from_slice(&central_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Lays text out top to bottom, starting a new page when the current one is full.
+struct PdfPages {
+    pages: Vec<String>,
+    y: f32,
+}
+
+impl PdfPages {
+    fn new() -> Self {
+        Self {
+            pages: vec![String::new()],
+            y: PAGE_HEIGHT - PAGE_MARGIN,
+        }
+    }
+
+    fn page(&mut self) -> &mut String {
+        self.pages.last_mut().expect("at least one page")
+    }
+
+    /// Room for `height` more points, breaking the page when there is not.
+    fn reserve(&mut self, height: f32) {
+        if self.y - height < PAGE_MARGIN {
+            self.pages.push(String::new());
+            self.y = PAGE_HEIGHT - PAGE_MARGIN;
+        }
+    }
+
+    fn gap(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    fn text(&mut self, x: f32, y: f32, bold: bool, size: f32, text: &str) {
+        let font = if bold { "F2" } else { "F1" };
+        let encoded = pdf_string(text);
+        self.page().push_str(&format!(
+            "BT /{font} {size} Tf {x:.1} {y:.1} Td ({encoded}) Tj ET\n"
+        ));
+    }
+
+    /// `text` wrapped to the page width; lines after the first are indented by
+    /// `hang` so bullets line up.
+    fn wrapped(&mut self, text: &str, bold: bool, size: f32, x: f32, hang: f32) {
+        let leading = size * 1.4;
+        let width = PAGE_WIDTH - PAGE_MARGIN - x;
+        for (index, line) in wrap(text, chars_for(width - hang, size))
+            .into_iter()
+            .enumerate()
+        {
+            self.reserve(leading);
+            self.y -= leading;
+            let indent = if index == 0 { 0.0 } else { hang };
+            let y = self.y;
+            self.text(x + indent, y, bold, size, &line);
+        }
+        self.y -= size * 0.4;
+    }
+
+    /// Columns sized by their longest cell, with a rule under the header row.
+    fn table(&mut self, header: &[String], rows: &[Vec<String>]) {
+        let usable = PAGE_WIDTH - 2.0 * PAGE_MARGIN;
+        let longest: Vec<f32> = (0..header.len())
+            .map(|column| {
+                std::iter::once(header)
+                    .chain(rows.iter().map(Vec::as_slice))
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| cell.chars().count().clamp(4, 60) as f32)
+                    .fold(0.0, f32::max)
+            })
+            .collect();
+        let total: f32 = longest.iter().sum::<f32>().max(1.0);
+        let widths: Vec<f32> = longest.iter().map(|len| usable * len / total).collect();
+        let leading = BODY_SIZE * 1.4;
+
+        self.gap(BODY_SIZE * 0.4);
+        for (index, row) in std::iter::once(header)
+            .chain(rows.iter().map(Vec::as_slice))
+            .enumerate()
+        {
+            let cells: Vec<Vec<String>> = widths
+                .iter()
+                .enumerate()
+                .map(|(column, width)| {
+                    let cell = row.get(column).map(String::as_str).unwrap_or_default();
+                    wrap(cell, chars_for(width - TABLE_PADDING, BODY_SIZE))
+                })
+                .collect();
+            let lines = cells.iter().map(Vec::len).max().unwrap_or(1);
+            self.reserve(leading * lines as f32);
+            let top = self.y;
+            let mut x = PAGE_MARGIN;
+            for (column, lines) in cells.iter().enumerate() {
+                for (line_index, line) in lines.iter().enumerate() {
+                    let y = top - leading * (line_index + 1) as f32;
+                    self.text(x, y, index == 0, BODY_SIZE, line);
+                }
+                x += widths[column];
+            }
+            self.y = top - leading * lines as f32;
+            if index == 0 {
+                let y = self.y - 2.0;
+                let right = PAGE_WIDTH - PAGE_MARGIN;
+                self.page().push_str(&format!(
+                    "0.5 w {PAGE_MARGIN:.1} {y:.1} m {right:.1} {y:.1} l S\n"
+                ));
+                self.y -= 4.0;
+            }
+        }
+        self.gap(BODY_SIZE * 0.6);
+    }
+
+    fn finish(self) -> Vec<u8> {
+        let page_count = self.pages.len();
+        // Objects: catalog, page tree, two fonts, then a page and its content per page.
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {page_count} >>",
+                (0..page_count)
+                    .map(|index| format!("{} 0 R", 5 + index * 2))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+        ];
+        for (index, content) in self.pages.iter().enumerate() {
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                6 + index * 2
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{content}endstream",
+                content.len()
+            ));
+        }
+
+        let mut out = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n{object}\nendobj\n", index + 1).as_bytes());
+        }
+        let xref = out.len();
+        out.extend_from_slice(
+            format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+        );
+        for offset in offsets {
+            out.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+        }
+        out.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+                objects.len() + 1
+            )
+            .as_bytes(),
+        );
+        out
+    }
+}
+
+fn chars_for(width: f32, size: f32) -> usize {
+    ((width / (size * GLYPH_WIDTH)) as usize).max(1)
+}
+
+/// Greedy word wrap to `max` characters, splitting words longer than a line.
+fn wrap(text: &str, max: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > max {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..max).collect());
+        }
+        let word: String = word.into_iter().collect();
+        let len = line.chars().count();
+        if len > 0 && len + 1 + word.chars().count() > max {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Body of a PDF literal string in WinAnsi: Latin-1 as octal escapes, common
+/// typographic punctuation folded to ASCII, anything else as `?`.
+fn pdf_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '(' | ')' => {
+                out.push('\\');
+                out.push(c);
+            }
+            ' '..='~' => out.push(c),
+            '\u{2018}' | '\u{2019}' => out.push('\''),
+            '\u{201c}' | '\u{201d}' => out.push('"'),
+            '\u{2013}' | '\u{2014}' | '\u{2022}' => out.push('-'),
+            '\u{2026}' => out.push_str("..."),
+            '\u{a0}'..='\u{ff}' => out.push_str(&format!("\\{:03o}", c as u32)),
+            _ => out.push('?'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Block, Document, crc32, wrap};
+
+    #[test]
+    fn docx_and_pdf_are_well_formed() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(
+            wrap("one two three fourteen", 9),
+            ["one two", "three", "fourteen"]
+        );
+
+        let document = Document {
+            title: "Sync & plan".to_string(),
+            blocks: vec![
+                Block::Heading("Notes".to_string()),
+                Block::Bullet("Ship (v2) café".to_string()),
+                Block::Table {
+                    header: vec!["Action".to_string(), "Owner".to_string()],
+                    rows: vec![vec!["Write <docs>".to_string(), "Sam".to_string()]],
+                },
+            ],
+        };
+
+        let docx = document.to_docx();
+        assert!(docx.starts_with(b"PK\x03\x04"));
+        let end = &docx[docx.len() - 22..];
+        assert_eq!(&end[..4], b"PK\x05\x06");
+        assert_eq!(u16::from_le_bytes([end[10], end[11]]), 3);
+        let text = String::from_utf8_lossy(&docx);
+        assert!(text.contains("Sync &amp; plan"));
+        assert!(text.contains("<w:t xml:space=\"preserve\">Write &lt;docs&gt;</w:t>"));
+
+        let pdf = document.to_pdf();
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.starts_with("%PDF-1.4"));
+        assert!(text.contains("(- Ship \\(v2\\) caf\\351) Tj"));
+        let startxref: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .unwrap();
+        assert!(pdf[startxref..].starts_with(b"xref\n0 7\n"));
+    }
+}
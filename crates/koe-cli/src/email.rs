@@ -0,0 +1,199 @@
+use crate::config::EmailConfig;
+use crate::session::{SessionError, StoredSession};
+use koe_core::types::{MeetingNotes, MeetingSummary, NoteKind};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum EmailError {
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("opening the mail draft failed")]
+    Open,
+}
+
+/// Open a draft in the default mail app with the session's minutes, addressed
+/// to participants that are email addresses plus `config.to`.
+pub fn compose_minutes(config: &EmailConfig, session_dir: &Path) -> Result<(), EmailError> {
+    let session = StoredSession::load(session_dir)?;
+    let notes = session.notes()?;
+    let summary: Option<MeetingSummary> = match fs::read_to_string(session_dir.join("summary.json"))
+    {
+        Ok(contents) => Some(serde_json::from_str(&contents)?),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+    let title = session.metadata.title.clone().unwrap_or_else(|| {
+        format!(
+            "Meeting {}",
+            session.metadata.start_time.get(..10).unwrap_or_default()
+        )
+    });
+    let mut to: Vec<String> = Vec::new();
+    for address in session.metadata.participants.iter().chain(&config.to) {
+        let address = address.trim().to_string();
+        if address.contains('@') && !to.contains(&address) {
+            to.push(address);
+        }
+    }
+    let body = minutes_body(summary.as_ref(), &notes);
+    let url = mailto_url(&to, &config.cc, &format!("Minutes: {title}"), &body);
+    let status = Command::new("open").arg(url).status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(EmailError::Open)
+    }
+}
+
+/// Plain-text minutes: summary overview, decisions, and action items; the
+/// summary's lists stand in for notes that have none.
+fn minutes_body(summary: Option<&MeetingSummary>, notes: &MeetingNotes) -> String {
+    let mut body = String::new();
+    if let Some(overview) = summary
+        .map(|summary| summary.overview.trim())
+        .filter(|overview| !overview.is_empty())
+    {
+        body.push_str(&format!("Summary\n\n{overview}\n\n"));
+    }
+
+    let mut decisions: Vec<String> = notes
+        .bullets
+        .iter()
+        .filter(|bullet| bullet.kind == NoteKind::Decision)
+        .map(|bullet| bullet.text.trim().to_string())
+        .collect();
+    if decisions.is_empty()
+        && let Some(summary) = summary
+    {
+        decisions = summary.decisions.clone();
+    }
+    push_list(&mut body, "Decisions", &decisions);
+
+    let mut actions: Vec<String> = crate::reminders::action_items(notes)
+        .map(|bullet| {
+            let mut line = bullet.text.trim().to_string();
+            match (bullet.owner.as_deref(), bullet.due.as_deref()) {
+                (Some(owner), Some(due)) => line.push_str(&format!(" ({owner}, due {due})")),
+                (Some(owner), None) => line.push_str(&format!(" ({owner})")),
+                (None, Some(due)) => line.push_str(&format!(" (due {due})")),
+                (None, None) => {}
+            }
+            if bullet.done {
+                line.push_str(" [done]");
+            }
+            line
+        })
+        .collect();
+    if actions.is_empty()
+        && let Some(summary) = summary
+    {
+        actions = summary
+            .action_items
+            .iter()
+            .map(|item| match item.owner.as_deref() {
+                Some(owner) => format!("{} ({owner})", item.text.trim()),
+                None => item.text.trim().to_string(),
+            })
+            .collect();
+    }
+    push_list(&mut body, "Action items", &actions);
+    body.trim_end().to_string()
+}
+
+fn push_list(body: &mut String, heading: &str, items: &[String]) {
+    body.push_str(&format!("{heading}\n\n"));
+    if items.is_empty() {
+        body.push_str("- (none)\n");
+    }
+    for item in items {
+        body.push_str(&format!("- {item}\n"));
+    }
+    body.push('\n');
+}
+
+/// RFC 6068 `mailto:` URL; line breaks become CRLF as mail clients expect.
+fn mailto_url(to: &[String], cc: &[String], subject: &str, body: &str) -> String {
+    let encode_list = |addresses: &[String]| -> String {
+        addresses
+            .iter()
+            .map(|address| percent_encode(address.trim()))
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    let mut url = format!(
+        "mailto:{}?subject={}",
+        encode_list(to),
+        percent_encode(subject)
+    );
+    if !cc.is_empty() {
+        url.push_str(&format!("&cc={}", encode_list(cc)));
+    }
+    url.push_str(&format!(
+        "&body={}",
+        percent_encode(&body.replace('\n', "\r\n"))
+    ));
+    url
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'@' => {
+                out.push(byte as char);
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mailto_url, minutes_body};
+    use crate::test_support::bullet;
+    use koe_core::types::{MeetingNotes, MeetingSummary, NoteBullet, NoteKind};
+
+    #[test]
+    fn minutes_draft_lists_decisions_and_actions_in_a_mailto_url() {
+        let summary = MeetingSummary {
+            overview: "Ship Friday.".to_string(),
+            decisions: vec!["From the summary".to_string()],
+            action_items: Vec::new(),
+            open_questions: Vec::new(),
+        };
+        let notes = MeetingNotes {
+            bullets: vec![
+                bullet("n1", "Go with plan B", NoteKind::Decision),
+                NoteBullet {
+                    owner: Some("Ana".to_string()),
+                    ..bullet("n2", "Send the deck", NoteKind::Action)
+                },
+            ],
+        };
+        let body = minutes_body(Some(&summary), &notes);
+        assert_eq!(
+            body,
+            "Summary\n\nShip Friday.\n\nDecisions\n\n- Go with plan B\n\nAction items\n\n- Send the deck (Ana)"
+        );
+
+        let url = mailto_url(
+            &["ana@example.com".to_string(), "sam@example.com".to_string()],
+            &[],
+            "Minutes: Q&A sync",
+            "a b\nc",
+        );
+        assert_eq!(
+            url,
+            "mailto:ana@example.com,sam@example.com?subject=Minutes%3A%20Q%26A%20sync&body=a%20b%0D%0Ac"
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use crate::config::{Config, ConfigPaths};
+use crate::document::{Block, Document, DocumentFormat};
+use crate::session::{ExportDir, SessionError, SessionMetadata, StoredSession, list_sessions};
+use clap::Args;
+use koe_core::types::{MeetingNotes, MeetingSummary, NoteKind, TranscriptSegment};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Args, Debug, Clone)]
+pub struct ExportArgs {
+    /// Session id or directory (defaults to the newest session)
+    pub session: Option<String>,
+
+    /// Export every session carrying this tag instead of a single session
+    #[arg(long, value_name = "tag", conflicts_with_all = ["session", "output"])]
+    tag: Option<String>,
+
+    /// Document format: docx or pdf
+    #[arg(long, value_parser = ["docx", "pdf"])]
+    format: String,
+
+    /// Append the full transcript after the notes
+    #[arg(long)]
+    transcript: bool,
+
+    /// Workspace whose sessions dir holds the session
+    #[arg(long, value_name = "name")]
+    workspace: Option<String>,
+
+    /// Write here instead of `minutes.<format>` next to the session's exports
+    #[arg(long, short, value_name = "path")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Error)]
+pub enum ExportError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{0}")]
+    Message(String),
+}
+
+/// Write a stored session's minutes (details, summary, notes, action items,
+/// and optionally the transcript) as a DOCX or PDF document.
+pub fn run(args: &ExportArgs, paths: &ConfigPaths, config: &Config) -> Result<(), ExportError> {
+    let format = DocumentFormat::parse(&args.format)
+        .ok_or_else(|| ExportError::Message(format!("unknown format: {}", args.format)))?;
+    let (session_paths, export_dir) =
+        crate::session_storage(paths, config, args.workspace.as_deref());
+    let sessions = match &args.tag {
+        Some(tag) => {
+            let tagged: Vec<StoredSession> = list_sessions(&session_paths.sessions_dir)?
+                .into_iter()
+                .filter(|session| session.metadata.has_tag(tag))
+                .collect();
+            if tagged.is_empty() {
+                return Err(ExportError::Message(format!("no sessions tagged {tag}")));
+            }
+            tagged
+        }
+        None => vec![
+            crate::session::find_session(&session_paths.sessions_dir, args.session.as_deref())?
+                .ok_or_else(|| {
+                    ExportError::Message(match &args.session {
+                        Some(id) => format!("session not found: {id}"),
+                        None => "no sessions recorded yet".to_string(),
+                    })
+                })?,
+        ],
+    };
+    for stored in sessions {
+        let path = export_session(
+            stored,
+            format,
+            args,
+            &session_paths,
+            export_dir.clone(),
+            config,
+        )?;
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn export_session(
+    stored: StoredSession,
+    format: DocumentFormat,
+    args: &ExportArgs,
+    session_paths: &ConfigPaths,
+    export_dir: Option<ExportDir>,
+    config: &Config,
+) -> Result<PathBuf, ExportError> {
+    let segments = stored.transcript()?;
+    let notes = stored.notes()?;
+    let summary: Option<MeetingSummary> = match fs::read_to_string(stored.dir.join("summary.json"))
+    {
+        Ok(contents) => Some(serde_json::from_str(&contents)?),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+
+    let document = minutes_document(
+        &stored.metadata,
+        summary.as_ref(),
+        &notes,
+        &segments,
+        args.transcript,
+    );
+    let contents = document.render(format);
+    Ok(match &args.output {
+        Some(path) => {
+            fs::write(path, contents)?;
+            path.clone()
+        }
+        None => stored
+            .reopen(
+                session_paths,
+                export_dir,
+                config.session.low_confidence_threshold,
+            )?
+            .export_file(&format!("minutes.{}", format.extension()), &contents)?,
+    })
+}
+
+fn minutes_document(
+    metadata: &SessionMetadata,
+    summary: Option<&MeetingSummary>,
+    notes: &MeetingNotes,
+    segments: &[TranscriptSegment],
+    include_transcript: bool,
+) -> Document {
+    let title = metadata.title.clone().unwrap_or_else(|| {
+        format!(
+            "Meeting {}",
+            metadata.start_time.get(..10).unwrap_or_default()
+        )
+    });
+    let mut blocks = vec![Block::Heading("Details".to_string())];
+    let date = match (
+        metadata.start_time.get(..10),
+        metadata.start_time.get(11..16),
+    ) {
+        (Some(day), Some(time)) => format!("{day} {time} UTC"),
+        _ => metadata.start_time.clone(),
+    };
+    let mut details = vec![format!("Date: {date}")];
+    if let Some(end_ms) = segments.iter().map(|segment| segment.end_ms).max() {
+        details.push(format!("Duration: {}", format_duration(end_ms)));
+    }
+    if !metadata.participants.is_empty() {
+        details.push(format!(
+            "Participants: {}",
+            metadata.participants.join(", ")
+        ));
+    }
+    if !metadata.tags.is_empty() {
+        details.push(format!("Tags: {}", metadata.tags.join(", ")));
+    }
+    if let Some(context) = metadata.context.as_deref().filter(|c| !c.trim().is_empty()) {
+        details.push(format!("Context: {}", context.trim()));
+    }
+    blocks.extend(details.into_iter().map(Block::Paragraph));
+
+    if let Some(summary) = summary.filter(|summary| !summary.overview.trim().is_empty()) {
+        blocks.push(Block::Heading("Summary".to_string()));
+        blocks.push(Block::Paragraph(summary.overview.trim().to_string()));
+    }
+
+    blocks.push(Block::Heading("Notes".to_string()));
+    let mut any_notes = false;
+    for bullet in notes
+        .bullets
+        .iter()
+        .filter(|bullet| bullet.kind != NoteKind::Action)
+    {
+        let label = match bullet.kind {
+            NoteKind::Decision => "Decision: ",
+            NoteKind::Question => "Question: ",
+            NoteKind::Point | NoteKind::Action => "",
+        };
+        blocks.push(Block::Bullet(format!("{label}{}", bullet.text.trim())));
+        any_notes = true;
+    }
+    if !any_notes {
+        blocks.push(Block::Paragraph("(none)".to_string()));
+    }
+
+    blocks.push(Block::Heading("Action items".to_string()));
+    let rows: Vec<Vec<String>> = crate::reminders::action_items(notes)
+        .map(|bullet| {
+            vec![
+                bullet.text.trim().to_string(),
+                bullet.owner.clone().unwrap_or_default(),
+                bullet.due.clone().unwrap_or_default(),
+                if bullet.done { "done" } else { "open" }.to_string(),
+            ]
+        })
+        .collect();
+    if rows.is_empty() {
+        blocks.push(Block::Paragraph("(none)".to_string()));
+    } else {
+        blocks.push(Block::Table {
+            header: ["Action", "Owner", "Due", "Status"]
+                .map(String::from)
+                .to_vec(),
+            rows,
+        });
+    }
+
+    if include_transcript {
+        blocks.push(Block::Heading("Appendix: transcript".to_string()));
+        blocks.extend(segments.iter().map(|segment| {
+            Block::Paragraph(format!(
+                "[{}] {}: {}",
+                format_duration(segment.start_ms),
+                segment.speaker.as_deref().unwrap_or("Unknown"),
+                segment.text.trim()
+            ))
+        }));
+    }
+    Document { title, blocks }
+}
+
+fn format_duration(ms: i64) -> String {
+    let total_seconds = ms.max(0) / 1000;
+    let (hours, minutes, seconds) = (
+        total_seconds / 3600,
+        total_seconds / 60 % 60,
+        total_seconds % 60,
+    );
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::minutes_document;
+    use crate::document::Block;
+    use crate::session::{SessionMetadata, SessionMetadataInput};
+    use crate::test_support::{bullet, session_input};
+    use koe_core::types::{MeetingNotes, NoteBullet, NoteKind, TranscriptSegment};
+
+    #[test]
+    fn minutes_list_notes_then_an_action_table_and_optional_transcript() {
+        let mut metadata = SessionMetadata::new(SessionMetadataInput {
+            participants: vec!["Sam".to_string(), "Ana".to_string()],
+            ..session_input()
+        })
+        .unwrap();
+        metadata.title = Some("Launch sync".to_string());
+        let note = |id: &str, text: &str, kind: NoteKind, owner: Option<&str>| NoteBullet {
+            evidence: vec![1],
+            owner: owner.map(str::to_string),
+            ..bullet(id, text, kind)
+        };
+        let notes = MeetingNotes {
+            bullets: vec![
+                note("n1", "Ship Friday", NoteKind::Decision, None),
+                note("n2", "Write release notes", NoteKind::Action, Some("Sam")),
+            ],
+        };
+        let segments = vec![TranscriptSegment {
+            id: 1,
+            start_ms: 61_000,
+            end_ms: 65_000,
+            speaker: Some("Sam".to_string()),
+            text: "Let's ship Friday.".to_string(),
+            finalized: true,
+            confidence: None,
+        }];
+
+        let document = minutes_document(&metadata, None, &notes, &segments, false);
+        assert_eq!(document.title, "Launch sync");
+        assert!(
+            document
+                .blocks
+                .contains(&Block::Paragraph("Participants: Sam, Ana".to_string()))
+        );
+        assert!(
+            document
+                .blocks
+                .contains(&Block::Paragraph("Duration: 01:05".to_string()))
+        );
+        assert!(
+            document
+                .blocks
+                .contains(&Block::Bullet("Decision: Ship Friday".to_string()))
+        );
+        assert!(document.blocks.iter().any(|block| matches!(
+            block,
+            Block::Table { rows, .. } if rows == &[vec![
+                "Write release notes".to_string(),
+                "Sam".to_string(),
+                String::new(),
+                "open".to_string(),
+            ]]
+        )));
+        assert!(!document.blocks.contains(&Block::Paragraph(
+            "[01:01] Sam: Let's ship Friday.".to_string()
+        )));
+
+        let document = minutes_document(&metadata, None, &notes, &segments, true);
+        assert_eq!(
+            document.blocks.last(),
+            Some(&Block::Paragraph(
+                "[01:01] Sam: Let's ship Friday.".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use crate::config::{ReviewConfig, SlackConfig};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ExportTargetError {
+    #[error("export target io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Slack(#[from] crate::slack::SlackError),
+}
+
+/// Destinations offered by the post-meeting review for the reviewed notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportTarget {
+    Obsidian,
+    Slack,
+    Clipboard,
+}
+
+impl ExportTarget {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Obsidian => "obsidian vault",
+            Self::Slack => "slack",
+            Self::Clipboard => "clipboard",
+        }
+    }
+}
+
+/// Targets usable with `config` and `slack`; the clipboard is always available.
+pub fn available_targets(config: &ReviewConfig, slack: &SlackConfig) -> Vec<ExportTarget> {
+    let mut targets = Vec::new();
+    if !config.obsidian_vault.trim().is_empty() {
+        targets.push(ExportTarget::Obsidian);
+    }
+    if !slack.webhook.trim().is_empty() {
+        targets.push(ExportTarget::Slack);
+    }
+    targets.push(ExportTarget::Clipboard);
+    targets
+}
+
+/// Send reviewed notes `markdown` for session `session_id` to `target`.
+pub fn deliver(
+    target: ExportTarget,
+    config: &ReviewConfig,
+    slack: &SlackConfig,
+    session_id: &str,
+    markdown: &str,
+) -> Result<(), ExportTargetError> {
+    match target {
+        ExportTarget::Obsidian => {
+            let path = obsidian_note_path(config, session_id);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, markdown)?;
+            Ok(())
+        }
+        ExportTarget::Slack => Ok(crate::slack::post(
+            &slack.webhook,
+            Some(&slack.channel),
+            markdown,
+        )?),
+        ExportTarget::Clipboard => Ok(crate::clipboard::copy(markdown)?),
+    }
+}
+
+fn obsidian_note_path(config: &ReviewConfig, session_id: &str) -> PathBuf {
+    PathBuf::from(config.obsidian_vault.trim()).join(format!("koe-{session_id}.md"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExportTarget, available_targets, deliver};
+    use crate::config::{ReviewConfig, SlackConfig};
+    use std::fs;
+
+    #[test]
+    fn obsidian_target_writes_note_into_vault() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut config = ReviewConfig::default();
+        let mut slack = SlackConfig::default();
+        assert_eq!(
+            available_targets(&config, &slack),
+            vec![ExportTarget::Clipboard]
+        );
+
+        config.obsidian_vault = temp.path().join("vault").to_string_lossy().to_string();
+        assert_eq!(
+            available_targets(&config, &slack),
+            vec![ExportTarget::Obsidian, ExportTarget::Clipboard]
+        );
+        slack.webhook = "https://hooks.slack.com/services/T0/B0/xyz".to_string();
+        assert_eq!(
+            available_targets(&config, &slack),
+            vec![
+                ExportTarget::Obsidian,
+                ExportTarget::Slack,
+                ExportTarget::Clipboard
+            ]
+        );
+
+        deliver(ExportTarget::Obsidian, &config, &slack, "abc", "# Notes\n").unwrap();
+        let written = fs::read_to_string(temp.path().join("vault/koe-abc.md")).unwrap();
+        assert_eq!(written, "# Notes\n");
+    }
+}
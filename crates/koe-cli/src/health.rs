@@ -0,0 +1,145 @@
+use koe_core::types::{AudioSource, CaptureStats};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+/// How long capture is watched before a meeting starts.
+pub const CHECK_DURATION: Duration = Duration::from_secs(2);
+/// Longest wait for the transcriber to answer the probe chunk.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Peaks below this are digital silence rather than a quiet room.
+const MIN_PEAK: f32 = 1e-4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthStatus {
+    Pending,
+    Passed,
+    /// One actionable line per problem.
+    Failed(Vec<String>),
+}
+
+/// Pre-meeting self-test: frames arrive from every configured source, levels
+/// are not flat zero, and the transcriber answers a short probe chunk.
+pub struct HealthCheck {
+    started: Instant,
+    baseline: Vec<(AudioSource, u64)>,
+    probe: Receiver<Result<(), String>>,
+    probe_result: Option<Result<(), String>>,
+}
+
+impl HealthCheck {
+    /// `probe` receives the transcriber's answer to `TranscribeCommand::Probe`.
+    pub fn start(
+        stats: &CaptureStats,
+        sources: &[AudioSource],
+        probe: Receiver<Result<(), String>>,
+        now: Instant,
+    ) -> Self {
+        stats.reset_source_peaks();
+        Self {
+            started: now,
+            baseline: sources
+                .iter()
+                .map(|source| (*source, stats.source_frames(*source)))
+                .collect(),
+            probe,
+            probe_result: None,
+        }
+    }
+
+    pub fn poll(&mut self, stats: &CaptureStats, now: Instant) -> HealthStatus {
+        if self.probe_result.is_none() {
+            match self.probe.try_recv() {
+                Ok(result) => self.probe_result = Some(result),
+                Err(TryRecvError::Disconnected) => {
+                    self.probe_result = Some(Err("transcribe thread is not running".to_string()));
+                }
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+        let elapsed = now.duration_since(self.started);
+        if elapsed < CHECK_DURATION || (self.probe_result.is_none() && elapsed < PROBE_TIMEOUT) {
+            return HealthStatus::Pending;
+        }
+
+        let mut problems = Vec::new();
+        for (source, baseline) in &self.baseline {
+            if stats.source_frames(*source) <= *baseline {
+                problems.push(no_frames_problem(*source));
+            } else if stats.source_peak(*source) < MIN_PEAK {
+                problems.push(silent_problem(*source));
+            }
+        }
+        match &self.probe_result {
+            Some(Ok(())) => {}
+            Some(Err(err)) => problems.push(format!(
+                "transcriber failed: {err}; check the API key and network, or switch transcribe mode from the palette"
+            )),
+            None => problems.push(format!(
+                "transcriber did not answer within {}s; check the network, or switch transcribe mode from the palette",
+                PROBE_TIMEOUT.as_secs()
+            )),
+        }
+        if problems.is_empty() {
+            HealthStatus::Passed
+        } else {
+            HealthStatus::Failed(problems)
+        }
+    }
+}
+
+fn no_frames_problem(source: AudioSource) -> String {
+    match source {
+        AudioSource::Microphone => "microphone: no audio arriving; grant Microphone access to this terminal in System Settings > Privacy & Security, or check audio.microphone_device_id".to_string(),
+        _ => "system audio: no audio arriving; grant Screen & System Audio Recording to this terminal in System Settings > Privacy & Security".to_string(),
+    }
+}
+
+fn silent_problem(source: AudioSource) -> String {
+    match source {
+        AudioSource::Microphone => {
+            "microphone: input is silent; unmute it or pick another input device".to_string()
+        }
+        _ => "system audio: output is silent; start the call audio, or start anyway if nobody is talking yet".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CHECK_DURATION, HealthCheck, HealthStatus};
+    use koe_core::types::{AudioSource, CaptureStats};
+    use std::sync::mpsc;
+    use std::time::Instant;
+
+    #[test]
+    fn check_waits_then_reports_each_failing_source() {
+        let stats = CaptureStats::new();
+        let (probe_tx, probe_rx) = mpsc::channel();
+        let start = Instant::now();
+        let mut check = HealthCheck::start(
+            &stats,
+            &[AudioSource::System, AudioSource::Microphone],
+            probe_rx,
+            start,
+        );
+
+        stats.record_source_frame(AudioSource::Microphone, &[0.0, 0.2, -0.4]);
+        stats.record_source_frame(AudioSource::System, &[0.0; 4]);
+        probe_tx.send(Ok(())).unwrap();
+        assert_eq!(check.poll(&stats, start), HealthStatus::Pending);
+
+        let HealthStatus::Failed(problems) = check.poll(&stats, start + CHECK_DURATION) else {
+            panic!("silent system audio should fail");
+        };
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("system audio: output is silent"));
+
+        let (probe_tx, probe_rx) = mpsc::channel();
+        let mut check = HealthCheck::start(&stats, &[AudioSource::Microphone], probe_rx, start);
+        stats.record_source_frame(AudioSource::Microphone, &[0.01]);
+        probe_tx.send(Ok(())).unwrap();
+        assert_eq!(
+            check.poll(&stats, start + CHECK_DURATION),
+            HealthStatus::Passed
+        );
+    }
+}
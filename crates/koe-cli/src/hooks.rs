@@ -0,0 +1,135 @@
+use crate::webhook::WebhookEvent;
+use koe_core::types::NotesOp;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+const HOOK_TIMEOUT: Duration = Duration::from_secs(10);
+const HOOK_POLL: Duration = Duration::from_millis(50);
+const HOOK_NAMES: [&str; 3] = ["on-meeting-start", "on-finalize", "on-note-added"];
+
+#[derive(Debug, Error)]
+pub enum HookError {
+    #[error("hook io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("hook payload encode failed: {0}")]
+    Encode(#[from] serde_json::Error),
+    #[error("exited with {0}")]
+    Failed(ExitStatus),
+    #[error("timed out after {}s", HOOK_TIMEOUT.as_secs())]
+    TimedOut,
+}
+
+/// True when any lifecycle hook executable exists in `dir`.
+pub fn any_installed(dir: &Path) -> bool {
+    HOOK_NAMES.iter().any(|name| dir.join(name).is_file())
+}
+
+/// Hook file name and stdin payload for `event`; note hooks only see added bullets.
+pub fn hook_for(event: &WebhookEvent) -> Option<(&'static str, WebhookEvent)> {
+    match event {
+        WebhookEvent::MeetingStart { .. } => Some(("on-meeting-start", event.clone())),
+        WebhookEvent::MeetingEnd { .. } => Some(("on-finalize", event.clone())),
+        WebhookEvent::NotesPatch { session_id, ops } => {
+            let ops: Vec<NotesOp> = ops
+                .iter()
+                .filter(|op| matches!(op, NotesOp::Add { .. }))
+                .cloned()
+                .collect();
+            if ops.is_empty() {
+                return None;
+            }
+            Some((
+                "on-note-added",
+                WebhookEvent::NotesPatch {
+                    session_id: session_id.clone(),
+                    ops,
+                },
+            ))
+        }
+    }
+}
+
+/// Run the hook in `dir` matching `event`, if installed, with the event JSON on
+/// stdin. Returns the hook name that ran.
+pub fn run_hook(dir: &Path, event: &WebhookEvent) -> Result<Option<&'static str>, HookError> {
+    let Some((name, payload)) = hook_for(event) else {
+        return Ok(None);
+    };
+    let path: PathBuf = dir.join(name);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let body = serde_json::to_vec(&payload)?;
+    let mut child = Command::new(&path)
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that ignores stdin may exit before reading it.
+        let _ = stdin.write_all(&body);
+    }
+    let deadline = Instant::now() + HOOK_TIMEOUT;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return if status.success() {
+                Ok(Some(name))
+            } else {
+                Err(HookError::Failed(status))
+            };
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(HookError::TimedOut);
+        }
+        thread::sleep(HOOK_POLL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{any_installed, run_hook};
+    use crate::webhook::WebhookEvent;
+    use koe_core::types::NotesOp;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    #[test]
+    fn note_hook_receives_added_bullets_on_stdin() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        assert!(!any_installed(dir));
+
+        let hook = dir.join("on-note-added");
+        fs::write(&hook, "#!/bin/sh\ncat > payload.json\n").unwrap();
+        fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(any_installed(dir));
+
+        let removal = WebhookEvent::NotesPatch {
+            session_id: "abc".to_string(),
+            ops: Vec::new(),
+        };
+        assert_eq!(run_hook(dir, &removal).unwrap(), None);
+
+        let event = WebhookEvent::NotesPatch {
+            session_id: "abc".to_string(),
+            ops: vec![NotesOp::Add {
+                id: "n1".to_string(),
+                text: "ship it".to_string(),
+                evidence: vec![3],
+                kind: None,
+            }],
+        };
+        assert_eq!(run_hook(dir, &event).unwrap(), Some("on-note-added"));
+        let payload: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(dir.join("payload.json")).unwrap()).unwrap();
+        assert_eq!(payload["event"], "notes_patch");
+        assert_eq!(payload["ops"][0]["Add"]["text"], "ship it");
+    }
+}
@@ -0,0 +1,170 @@
+use koe_core::types::{MeetingNotes, NoteKind, TranscriptSegment};
+use std::collections::HashMap;
+
+/// Speaker colors, assigned in order of first appearance and reused after the last.
+const SPEAKER_COLORS: [&str; 6] = [
+    "#2f6fb0", "#b5562b", "#3d8a4f", "#8a4fa8", "#a8841f", "#2b8a8a",
+];
+
+const STYLE: &str = r#"
+body { margin: 0; font: 15px/1.5 -apple-system, system-ui, sans-serif; color: #222; }
+header { position: sticky; top: 0; background: #fff; padding: 12px 24px; border-bottom: 1px solid #ddd; }
+header h1 { margin: 0 0 8px; font-size: 18px; }
+audio { width: 100%; }
+main { display: flex; gap: 24px; padding: 16px 24px; }
+aside { flex: 0 0 32%; position: sticky; top: 120px; align-self: flex-start; max-height: calc(100vh - 140px); overflow-y: auto; }
+aside li { margin-bottom: 6px; }
+aside li[data-start] { cursor: pointer; }
+.kind { font-size: 11px; text-transform: uppercase; color: #888; margin-right: 4px; }
+.done { text-decoration: line-through; color: #888; }
+ol { list-style: none; padding: 0; margin: 0; }
+#transcript { flex: 1; }
+#transcript li { padding: 4px 8px; border-radius: 4px; cursor: pointer; }
+#transcript li:hover { background: #f4f4f4; }
+#transcript li.active { background: #fff4cc; }
+time { color: #888; font-variant-numeric: tabular-nums; margin-right: 8px; }
+.speaker { font-weight: 600; margin-right: 6px; }
+"#;
+
+const SCRIPT: &str = r##"
+const audio = document.getElementById("audio");
+const lines = Array.from(document.querySelectorAll("#transcript li"));
+document.querySelectorAll("[data-start]").forEach((el) => {
+  el.addEventListener("click", () => {
+    audio.currentTime = Number(el.dataset.start);
+    audio.play();
+  });
+});
+audio.addEventListener("timeupdate", () => {
+  let current = null;
+  for (const line of lines) {
+    if (Number(line.dataset.start) <= audio.currentTime) current = line;
+  }
+  lines.forEach((line) => line.classList.toggle("active", line === current));
+});
+"##;
+
+/// Self-contained page with the audio player, the notes in a sidebar, and the
+/// transcript; clicking a line or a note seeks the audio to it. `audio_file`
+/// is a path relative to the page.
+pub fn render_transcript_html(
+    title: &str,
+    audio_file: &str,
+    segments: &[TranscriptSegment],
+    notes: &MeetingNotes,
+) -> String {
+    let mut colors: HashMap<&str, &str> = HashMap::new();
+    for speaker in segments.iter().filter_map(|seg| seg.speaker.as_deref()) {
+        let next = SPEAKER_COLORS[colors.len() % SPEAKER_COLORS.len()];
+        colors.entry(speaker).or_insert(next);
+    }
+    let starts: HashMap<u64, i64> = segments.iter().map(|seg| (seg.id, seg.start_ms)).collect();
+
+    let mut html = format!(
+        "<!doctype html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n<header>\n<h1>{title}</h1>\n<audio id=\"audio\" controls preload=\"metadata\" src=\"{audio}\"></audio>\n</header>\n<main>\n<aside>\n<h2>Notes</h2>\n<ol>\n",
+        title = escape(title),
+        audio = escape(audio_file),
+    );
+    if notes.bullets.is_empty() {
+        html.push_str("<li>(none)</li>\n");
+    }
+    for bullet in &notes.bullets {
+        let start = bullet
+            .evidence
+            .iter()
+            .filter_map(|id| starts.get(id))
+            .min()
+            .map(|ms| format!(" data-start=\"{}\"", seconds(*ms)))
+            .unwrap_or_default();
+        let kind = match bullet.kind {
+            NoteKind::Point => String::new(),
+            NoteKind::Decision => "<span class=\"kind\">decision</span>".to_string(),
+            NoteKind::Action => "<span class=\"kind\">action</span>".to_string(),
+            NoteKind::Question => "<span class=\"kind\">question</span>".to_string(),
+        };
+        let class = if bullet.done { " class=\"done\"" } else { "" };
+        let mut text = escape(&bullet.text);
+        if let Some(owner) = &bullet.owner {
+            text.push_str(&format!(" (@{})", escape(owner)));
+        }
+        if let Some(due) = &bullet.due {
+            text.push_str(&format!(" (due {})", escape(due)));
+        }
+        html.push_str(&format!("<li{start}{class}>{kind}{text}</li>\n"));
+    }
+    html.push_str("</ol>\n</aside>\n<ol id=\"transcript\">\n");
+    for segment in segments {
+        let speaker = segment.speaker.as_deref().unwrap_or("Unknown");
+        let color = colors.get(speaker).copied().unwrap_or("#666");
+        html.push_str(&format!(
+            "<li id=\"seg-{id}\" data-start=\"{start}\"><time>{stamp}</time><span class=\"speaker\" style=\"color: {color}\">{speaker}</span>{text}</li>\n",
+            id = segment.id,
+            start = seconds(segment.start_ms),
+            stamp = timestamp(segment.start_ms),
+            speaker = escape(speaker),
+            text = escape(segment.text.trim()),
+        ));
+    }
+    html.push_str(&format!(
+        "</ol>\n</main>\n<script>{SCRIPT}</script>\n</body>\n</html>\n"
+    ));
+    html
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn seconds(ms: i64) -> String {
+    format!("{:.3}", ms.max(0) as f64 / 1000.0)
+}
+
+fn timestamp(ms: i64) -> String {
+    let total_seconds = ms.max(0) / 1000;
+    format!("{:02}:{:02}", total_seconds / 60, total_seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_transcript_html;
+    use crate::test_support::bullet;
+    use koe_core::types::{MeetingNotes, NoteBullet, NoteKind, TranscriptSegment};
+
+    fn segment(id: u64, start_ms: i64, speaker: &str, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            id,
+            start_ms,
+            end_ms: start_ms + 1_000,
+            speaker: Some(speaker.to_string()),
+            text: text.to_string(),
+            finalized: true,
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn html_links_lines_and_notes_to_audio_times() {
+        let segments = vec![
+            segment(1, 1_500, "Me", "Ship <v2> on Friday"),
+            segment(2, 65_000, "Them", "Agreed"),
+        ];
+        let notes = MeetingNotes {
+            bullets: vec![NoteBullet {
+                evidence: vec![2, 1],
+                owner: Some("Sam".to_string()),
+                ..bullet("n1", "Ship v2 Friday", NoteKind::Decision)
+            }],
+        };
+        let html = render_transcript_html("Sync & plan", "audio-1.wav", &segments, &notes);
+
+        assert!(html.contains("<title>Sync &amp; plan</title>"));
+        assert!(html.contains("src=\"audio-1.wav\""));
+        assert!(html.contains("<li id=\"seg-1\" data-start=\"1.500\"><time>00:01</time><span class=\"speaker\" style=\"color: #2f6fb0\">Me</span>Ship &lt;v2&gt; on Friday</li>"));
+        assert!(html.contains("data-start=\"65.000\"><time>01:05</time><span class=\"speaker\" style=\"color: #b5562b\">Them"));
+        assert!(html.contains("<li data-start=\"1.500\"><span class=\"kind\">decision</span>Ship v2 Friday (@Sam)</li>"));
+    }
+}
@@ -0,0 +1,575 @@
+use crate::checksum::to_hex;
+use crate::tui::{SummarizeCommand, UiEvent};
+use koe_core::transcript::TranscriptLedger;
+use koe_core::types::{MeetingNotes, TranscriptSegment};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::Deserialize;
+use serde_json::json;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender, channel};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const MAX_BODY_BYTES: usize = 64 * 1024;
+const MAX_HEADER_LINES: usize = 64;
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+const SSE_KEEPALIVE: Duration = Duration::from_secs(15);
+/// Open connections (SSE streams included) before new ones are turned away.
+const MAX_CONNECTIONS: usize = 32;
+
+/// What the server shows: the current (or last) meeting and its SSE subscribers.
+#[derive(Default)]
+struct LiveState {
+    session_id: Option<String>,
+    active: bool,
+    ledger: TranscriptLedger,
+    notes: MeetingNotes,
+    subscribers: Vec<Sender<String>>,
+}
+
+impl LiveState {
+    /// Queue one SSE frame for every open stream, dropping closed ones.
+    fn broadcast(&mut self, event: &str, data: &serde_json::Value) {
+        let frame = format!("event: {event}\ndata: {data}\n\n");
+        self.subscribers
+            .retain(|subscriber| subscriber.send(frame.clone()).is_ok());
+    }
+}
+
+/// Handle the TUI uses to publish meeting state to the local HTTP server;
+/// the disabled handle makes every call a no-op.
+#[derive(Clone, Default)]
+pub struct LiveFeed {
+    state: Option<Arc<Mutex<LiveState>>>,
+}
+
+impl LiveFeed {
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    fn with_state(&self, f: impl FnOnce(&mut LiveState)) {
+        if let Some(state) = &self.state
+            && let Ok(mut state) = state.lock()
+        {
+            f(&mut state);
+        }
+    }
+
+    pub fn meeting_start(&self, session_id: &str) {
+        self.with_state(|state| {
+            state.session_id = Some(session_id.to_string());
+            state.active = true;
+            state.ledger = TranscriptLedger::new();
+            state.notes = MeetingNotes::default();
+            state.broadcast("meeting_start", &json!({ "session_id": session_id }));
+        });
+    }
+
+    /// Record finalized segments and stream them to SSE clients.
+    pub fn transcript(&self, segments: &[TranscriptSegment]) {
+        self.with_state(|state| {
+            state.ledger.append(segments.to_vec());
+            state.broadcast("segments", &json!(segments));
+        });
+    }
+
+    pub fn notes(&self, notes: &MeetingNotes) {
+        self.with_state(|state| {
+            state.notes = notes.clone();
+            state.broadcast("notes", &json!(notes));
+        });
+    }
+
+    pub fn meeting_end(&self) {
+        self.with_state(|state| {
+            state.active = false;
+            let session_id = state.session_id.clone();
+            state.broadcast("meeting_end", &json!({ "session_id": session_id }));
+        });
+    }
+}
+
+/// Channels a `POST /context` is forwarded on.
+#[derive(Clone)]
+struct ContextSink {
+    ui_tx: Sender<UiEvent>,
+    summarize_cmd_tx: Sender<SummarizeCommand>,
+}
+
+/// Who may talk to the server: the per-launch bearer token, and the port
+/// a legitimate `Host` header names.
+struct Access {
+    token: String,
+    port: u16,
+}
+
+impl Access {
+    /// `None` when the request may proceed. The Host check stops DNS
+    /// rebinding; the token stops any other local page or process.
+    fn check(&self, request: &Request) -> Option<Response> {
+        let host = request.host.to_ascii_lowercase();
+        let port = self.port.to_string();
+        let host_ok = matches!(
+            host.rsplit_once(':'),
+            Some(("127.0.0.1" | "localhost", value)) if value == port
+        );
+        if !host_ok {
+            return Some(Response::error("403 Forbidden", "unexpected host"));
+        }
+        // `EventSource` cannot set headers, so the read-only stream also
+        // takes the token as `?token=`; everything else needs the header.
+        let token = request.authorization.strip_prefix("Bearer ").or_else(|| {
+            (request.method == "GET" && request.path == "/events")
+                .then(|| query_param(&request.query, "token"))
+                .flatten()
+        });
+        if !token.is_some_and(|token| constant_time_eq(token.as_bytes(), self.token.as_bytes())) {
+            return Some(Response::error(
+                "401 Unauthorized",
+                "missing or wrong token",
+            ));
+        }
+        None
+    }
+}
+
+fn query_param<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find_map(|(key, value)| (key == name).then_some(value))
+}
+
+/// Equality whose running time does not depend on where the inputs differ.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// One of `MAX_CONNECTIONS` slots, released when the connection ends.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn acquire(open: &Arc<AtomicUsize>) -> Option<Self> {
+        open.fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+            (count < MAX_CONNECTIONS).then_some(count + 1)
+        })
+        .ok()
+        .map(|_| Self(Arc::clone(open)))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Bind `127.0.0.1:port` and serve the live meeting from a background thread.
+/// A fresh bearer token is written to `token_path` (mode 0600) first.
+pub fn spawn_http_server(
+    port: u16,
+    token_path: &Path,
+    ui_tx: Sender<UiEvent>,
+    summarize_cmd_tx: Sender<SummarizeCommand>,
+) -> io::Result<LiveFeed> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+    let access = Arc::new(Access {
+        token: write_token(token_path)?,
+        port,
+    });
+    let state = Arc::new(Mutex::new(LiveState::default()));
+    let sink = ContextSink {
+        ui_tx,
+        summarize_cmd_tx,
+    };
+    let accept_state = Arc::clone(&state);
+    thread::Builder::new()
+        .name("koe-http".into())
+        .spawn(move || {
+            let open = Arc::new(AtomicUsize::new(0));
+            for mut stream in listener.incoming().flatten() {
+                let Some(slot) = ConnectionSlot::acquire(&open) else {
+                    let _ = write_response(
+                        &mut stream,
+                        &Response::error("503 Service Unavailable", "too many connections"),
+                    );
+                    continue;
+                };
+                let state = Arc::clone(&accept_state);
+                let access = Arc::clone(&access);
+                let sink = sink.clone();
+                let _ = thread::Builder::new()
+                    .name("koe-http-conn".into())
+                    .spawn(move || {
+                        let _slot = slot;
+                        let _ = handle_connection(stream, &access, &state, &sink);
+                    });
+            }
+        })?;
+    Ok(LiveFeed { state: Some(state) })
+}
+
+/// Replace the token file with 32 random bytes as hex, readable only by the user.
+fn write_token(path: &Path) -> io::Result<String> {
+    let mut bytes = [0u8; 32];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| io::Error::other("random token generation failed"))?;
+    let token = to_hex(&bytes);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let _ = fs::remove_file(path);
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(token.as_bytes())?;
+    Ok(token)
+}
+
+/// The token a running instance wrote, for clients such as `koe attach`.
+pub fn read_token(path: &Path) -> io::Result<String> {
+    Ok(fs::read_to_string(path)?.trim().to_string())
+}
+
+#[derive(Debug)]
+pub(crate) struct Request {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) query: String,
+    pub(crate) content_type: String,
+    pub(crate) host: String,
+    pub(crate) authorization: String,
+    pub(crate) body: Vec<u8>,
+}
+
+/// Parse one request, rejecting bodies over `max_body` bytes.
+pub(crate) fn read_request(reader: &mut impl BufRead, max_body: usize) -> io::Result<Request> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default();
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let (path, query) = (path.to_string(), query.to_string());
+
+    let mut content_length = 0usize;
+    let mut content_type = String::new();
+    let mut host = String::new();
+    let mut authorization = String::new();
+    for _ in 0..MAX_HEADER_LINES {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-length" => {
+                content_length = value.parse().map_err(|_| {
+                    io::Error::new(io::ErrorKind::InvalidData, "bad content-length")
+                })?;
+            }
+            "content-type" => content_type = value.to_ascii_lowercase(),
+            "host" => host = value.to_string(),
+            "authorization" => authorization = value.to_string(),
+            _ => {}
+        }
+    }
+    if content_length > max_body {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "request body too large",
+        ));
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(Request {
+        method,
+        path,
+        query,
+        content_type,
+        host,
+        authorization,
+        body,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct ContextBody {
+    context: String,
+}
+
+struct Response {
+    status: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn json(value: serde_json::Value) -> Self {
+        Self {
+            status: "200 OK",
+            body: value.to_string(),
+        }
+    }
+
+    fn error(status: &'static str, message: &str) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message }).to_string(),
+        }
+    }
+}
+
+fn handle_connection(
+    stream: TcpStream,
+    access: &Access,
+    state: &Mutex<LiveState>,
+    sink: &ContextSink,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut stream = stream;
+    let request = match read_request(&mut reader, MAX_BODY_BYTES) {
+        Ok(request) => request,
+        Err(e) => {
+            return write_response(
+                &mut stream,
+                &Response::error("400 Bad Request", &e.to_string()),
+            );
+        }
+    };
+    if let Some(denied) = access.check(&request) {
+        return write_response(&mut stream, &denied);
+    }
+    if request.method == "GET" && request.path == "/events" {
+        let (tx, rx) = channel();
+        if let Ok(mut state) = state.lock() {
+            state.subscribers.push(tx);
+        }
+        return stream_events(&mut stream, &rx);
+    }
+    let response = route(&request, state, sink);
+    write_response(&mut stream, &response)
+}
+
+fn route(request: &Request, state: &Mutex<LiveState>, sink: &ContextSink) -> Response {
+    let Ok(state) = state.lock() else {
+        return Response::error("500 Internal Server Error", "state unavailable");
+    };
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/transcript") => Response::json(json!({
+            "session_id": state.session_id,
+            "active": state.active,
+            "segments": state.ledger.segments(),
+        })),
+        ("GET", "/notes") => Response::json(json!({
+            "session_id": state.session_id,
+            "active": state.active,
+            "notes": state.notes,
+        })),
+        // JSON only: browsers must preflight it, and no CORS headers are
+        // sent, so arbitrary web pages cannot inject context.
+        ("POST", "/context") if !request.content_type.starts_with("application/json") => {
+            Response::error("415 Unsupported Media Type", "expected application/json")
+        }
+        ("POST", "/context") => match serde_json::from_slice::<ContextBody>(&request.body) {
+            Ok(body) if state.active => {
+                let _ = sink
+                    .summarize_cmd_tx
+                    .send(SummarizeCommand::UpdateContext(body.context.clone()));
+                let _ = sink.ui_tx.send(UiEvent::ContextUpdate(body.context));
+                Response::json(json!({ "ok": true }))
+            }
+            Ok(_) => Response::error("409 Conflict", "no meeting in progress"),
+            Err(e) => Response::error("400 Bad Request", &e.to_string()),
+        },
+        (_, "/transcript" | "/notes" | "/context" | "/events") => {
+            Response::error("405 Method Not Allowed", "method not allowed")
+        }
+        _ => Response::error("404 Not Found", "not found"),
+    }
+}
+
+fn write_response(stream: &mut impl Write, response: &Response) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+/// Hold the connection open and forward queued frames until the client leaves.
+fn stream_events(stream: &mut impl Write, rx: &Receiver<String>) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n"
+    )?;
+    stream.flush()?;
+    loop {
+        match rx.recv_timeout(SSE_KEEPALIVE) {
+            Ok(frame) => stream.write_all(frame.as_bytes())?,
+            Err(RecvTimeoutError::Timeout) => stream.write_all(b": keepalive\n\n")?,
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+        stream.flush()?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Access, ContextSink, LiveFeed, LiveState, MAX_BODY_BYTES, Request, read_request, route,
+    };
+    use crate::test_support::bullet;
+    use crate::tui::{SummarizeCommand, UiEvent};
+    use koe_core::types::{MeetingNotes, NoteBullet, NoteKind, TranscriptSegment};
+    use std::io::Cursor;
+    use std::sync::mpsc::channel;
+    use std::sync::{Arc, Mutex};
+
+    fn post_context(body: &str, content_type: &str) -> Request {
+        let raw = format!(
+            "POST /context HTTP/1.1\r\nHost: 127.0.0.1\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+        read_request(&mut Cursor::new(raw.into_bytes()), MAX_BODY_BYTES).unwrap()
+    }
+
+    #[test]
+    fn routes_serve_live_state_and_forward_context() {
+        let state = Arc::new(Mutex::new(LiveState::default()));
+        let feed = LiveFeed {
+            state: Some(Arc::clone(&state)),
+        };
+        let (sse_tx, sse_rx) = channel();
+        state.lock().unwrap().subscribers.push(sse_tx);
+        let (ui_tx, ui_rx) = channel();
+        let (summarize_cmd_tx, summarize_rx) = channel();
+        let sink = ContextSink {
+            ui_tx,
+            summarize_cmd_tx,
+        };
+
+        let request = post_context(r#"{"context":"pricing"}"#, "application/json");
+        assert_eq!(route(&request, &state, &sink).status, "409 Conflict");
+
+        feed.meeting_start("abc");
+        feed.transcript(&[TranscriptSegment {
+            id: 1,
+            start_ms: 0,
+            end_ms: 1000,
+            speaker: Some("Me".to_string()),
+            text: "ship friday".to_string(),
+            finalized: true,
+            confidence: None,
+        }]);
+        feed.notes(&MeetingNotes {
+            bullets: vec![NoteBullet {
+                evidence: vec![1],
+                ..bullet("n1", "Ship Friday", NoteKind::Point)
+            }],
+        });
+
+        let get = |path: &str| Request {
+            method: "GET".to_string(),
+            path: path.to_string(),
+            query: String::new(),
+            content_type: String::new(),
+            host: String::new(),
+            authorization: String::new(),
+            body: Vec::new(),
+        };
+        let transcript = route(&get("/transcript"), &state, &sink);
+        assert!(transcript.body.contains("ship friday"));
+        assert!(transcript.body.contains(r#""session_id":"abc""#));
+        assert!(
+            route(&get("/notes"), &state, &sink)
+                .body
+                .contains("Ship Friday")
+        );
+        assert_eq!(route(&get("/nope"), &state, &sink).status, "404 Not Found");
+
+        let plain = post_context(r#"{"context":"pricing"}"#, "text/plain");
+        assert_eq!(
+            route(&plain, &state, &sink).status,
+            "415 Unsupported Media Type"
+        );
+        assert_eq!(route(&request, &state, &sink).status, "200 OK");
+        assert!(matches!(
+            summarize_rx.try_recv(),
+            Ok(SummarizeCommand::UpdateContext(value)) if value == "pricing"
+        ));
+        assert!(matches!(
+            ui_rx.try_recv(),
+            Ok(UiEvent::ContextUpdate(value)) if value == "pricing"
+        ));
+
+        let frames: Vec<String> = sse_rx.try_iter().collect();
+        assert!(frames[0].starts_with("event: meeting_start\n"));
+        assert!(frames[1].starts_with("event: segments\ndata: [{"));
+        assert!(frames[2].starts_with("event: notes\n"));
+    }
+
+    #[test]
+    fn access_requires_local_host_and_bearer_token() {
+        let access = Access {
+            token: "secret".to_string(),
+            port: 7373,
+        };
+        let request = |host: &str, authorization: &str| {
+            let raw = format!(
+                "GET /notes HTTP/1.1\r\nHost: {host}\r\nAuthorization: {authorization}\r\n\r\n"
+            );
+            read_request(&mut Cursor::new(raw.into_bytes()), MAX_BODY_BYTES).unwrap()
+        };
+        let status = |host: &str, authorization: &str| {
+            access
+                .check(&request(host, authorization))
+                .map(|response| response.status)
+        };
+        assert_eq!(status("127.0.0.1:7373", "Bearer secret"), None);
+        assert_eq!(status("localhost:7373", "Bearer secret"), None);
+        assert_eq!(
+            status("evil.example:7373", "Bearer secret"),
+            Some("403 Forbidden")
+        );
+        assert_eq!(
+            status("localhost:8080", "Bearer secret"),
+            Some("403 Forbidden")
+        );
+        assert_eq!(
+            status("localhost:7373", "Bearer wrong"),
+            Some("401 Unauthorized")
+        );
+        assert_eq!(status("localhost:7373", ""), Some("401 Unauthorized"));
+
+        let target = |line: &str| {
+            let raw = format!("{line} HTTP/1.1\r\nHost: localhost:7373\r\n\r\n");
+            let request = read_request(&mut Cursor::new(raw.into_bytes()), MAX_BODY_BYTES).unwrap();
+            access.check(&request).map(|response| response.status)
+        };
+        assert_eq!(target("GET /events?token=secret"), None);
+        assert_eq!(target("GET /events?since=1&token=secret"), None);
+        assert_eq!(target("GET /events?token=secreT"), Some("401 Unauthorized"));
+        assert_eq!(target("GET /notes?token=secret"), Some("401 Unauthorized"));
+        assert_eq!(
+            target("POST /context?token=secret"),
+            Some("401 Unauthorized")
+        );
+    }
+}
@@ -1,6 +1,9 @@
+use crate::audit::AuditLog;
+use crate::checksum::sha256_file;
 use crate::config::{Config, ConfigError, ConfigPaths, ProviderConfig};
 use clap::Args;
-use std::fs::{self, File};
+use serde::Deserialize;
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::thread;
@@ -10,18 +13,23 @@ use thiserror::Error;
 pub const DEFAULT_WHISPER_MODEL: &str = "base.en";
 const DEFAULT_GROQ_MODEL: &str = "whisper-large-v3-turbo";
 const MODEL_BASE_URL: &str = "https://huggingface.co/ggerganov/whisper.cpp/resolve/main";
-const DOWNLOAD_MAX_RETRIES: usize = 2;
+/// File listing with the LFS SHA-256 of every published model.
+const MODEL_TREE_URL: &str = "https://huggingface.co/api/models/ggerganov/whisper.cpp/tree/main";
+/// Sidecar next to each model holding its verified SHA-256.
+pub const CHECKSUM_EXTENSION: &str = "sha256";
+const DOWNLOAD_MAX_RETRIES: usize = 8;
 const DOWNLOAD_RETRY_BASE_MS: u64 = 500;
+const PROGRESS_BAR_WIDTH: usize = 30;
 const DOWNLOAD_TIMEOUT_GLOBAL: Duration = Duration::from_secs(600);
 const DOWNLOAD_TIMEOUT_CONNECT: Duration = Duration::from_secs(10);
 const DOWNLOAD_TIMEOUT_RECV_BODY: Duration = Duration::from_secs(600);
 
-struct ModelOption {
-    name: &'static str,
-    size: &'static str,
+pub struct ModelOption {
+    pub name: &'static str,
+    pub size: &'static str,
 }
 
-const WHISPER_MODELS: &[ModelOption] = &[
+pub const WHISPER_MODELS: &[ModelOption] = &[
     ModelOption {
         name: "base.en",
         size: "~142 MB",
@@ -61,6 +69,7 @@ pub fn run(args: &InitArgs, paths: &ConfigPaths) -> Result<(), InitError> {
     print_permissions();
 
     let mut config = Config::load_or_create(paths)?;
+    let before = config.clone();
 
     let mut changed = Vec::new();
     let mut kept = Vec::new();
@@ -153,6 +162,7 @@ pub fn run(args: &InitArgs, paths: &ConfigPaths) -> Result<(), InitError> {
 
     config.validate()?;
     Config::write(paths, &config)?;
+    AuditLog::new(paths).record_config_change("config_init", &before, &config)?;
 
     print_summary(&changed, &kept);
     println!("next: koe");
@@ -160,26 +170,102 @@ pub fn run(args: &InitArgs, paths: &ConfigPaths) -> Result<(), InitError> {
     Ok(())
 }
 
+/// Download `model` into `models_dir`, verified against the published SHA-256.
 pub fn download_model(model: &str, models_dir: &Path, force: bool) -> Result<PathBuf, InitError> {
     fs::create_dir_all(models_dir)?;
     let model_file = model_filename(model);
-    let dest = models_dir.join(model_file);
+    let dest = models_dir.join(&model_file);
 
     if dest.exists() && !force {
         println!("model already present at {}", dest.display());
         return Ok(dest);
     }
 
-    let url = format!(
-        "{MODEL_BASE_URL}/{}",
-        dest.file_name().unwrap().to_string_lossy()
-    );
+    let expected = published_checksum(&model_file)?;
+    let url = format!("{MODEL_BASE_URL}/{model_file}");
     println!("downloading model from {url}");
-    download_to_path(&url, &dest)?;
-    println!("model saved to {}", dest.display());
+    download_to_path(&url, &dest, &expected)?;
+    fs::write(checksum_path(&dest), format!("{expected}\n"))?;
+    println!("model saved to {} (sha256 {expected})", dest.display());
     Ok(dest)
 }
 
+/// Path of the checksum sidecar for the model at `path`.
+pub fn checksum_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{CHECKSUM_EXTENSION}"));
+    PathBuf::from(name)
+}
+
+#[derive(Debug, Deserialize)]
+struct TreeEntry {
+    path: String,
+    lfs: Option<LfsPointer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LfsPointer {
+    /// SHA-256 of the file contents.
+    oid: String,
+}
+
+/// SHA-256 published for `model_file`; errors when no such model exists.
+fn published_checksum(model_file: &str) -> Result<String, InitError> {
+    let entries: Vec<TreeEntry> = download_agent()
+        .get(MODEL_TREE_URL)
+        .call()
+        .and_then(|response| response.into_body().read_json())
+        .map_err(|e| InitError::Message(format!("model checksum lookup failed: {e}")))?;
+    entries
+        .into_iter()
+        .find(|entry| entry.path == model_file)
+        .and_then(|entry| entry.lfs)
+        .map(|lfs| lfs.oid.to_ascii_lowercase())
+        .ok_or_else(|| InitError::Message(format!("no published model named {model_file}")))
+}
+
+/// What to do when the configured whisper model is not on disk at startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingModelAction {
+    Download,
+    SwitchToCloud,
+    RecordOnly,
+}
+
+/// Ask how to proceed without the local whisper model. Cloud is only offered
+/// when the cloud profile has an API key.
+pub fn prompt_missing_model(
+    model: &str,
+    cloud_ready: bool,
+) -> Result<MissingModelAction, InitError> {
+    println!("whisper model {model} is not installed");
+    let mut options = vec![("download now", MissingModelAction::Download)];
+    if cloud_ready {
+        options.push(("switch to cloud profile", MissingModelAction::SwitchToCloud));
+    }
+    options.push((
+        "record only (audio is saved, no transcript)",
+        MissingModelAction::RecordOnly,
+    ));
+    for (idx, (label, _)) in options.iter().enumerate() {
+        println!("  {}) {}", idx + 1, label);
+    }
+    loop {
+        let selection = prompt_line("select [default 1]: ")?;
+        let trimmed = selection.trim();
+        if trimmed.is_empty() {
+            return Ok(options[0].1);
+        }
+        if let Ok(choice) = trimmed.parse::<usize>()
+            && choice >= 1
+            && choice <= options.len()
+        {
+            return Ok(options[choice - 1].1);
+        }
+        println!("invalid selection, try again");
+    }
+}
+
 fn print_permissions() {
     println!("permissions required:");
     println!("System Settings → Privacy & Security → Screen Recording: allow koe");
@@ -470,7 +556,7 @@ fn current_whisper_model_name(value: &str) -> Option<String> {
     Some(without_suffix.to_string())
 }
 
-fn model_filename(model: &str) -> String {
+pub fn model_filename(model: &str) -> String {
     if model.ends_with(".bin") {
         model.to_string()
     } else {
@@ -478,45 +564,147 @@ fn model_filename(model: &str) -> String {
     }
 }
 
-fn download_to_path(url: &str, dest: &Path) -> Result<(), InitError> {
+/// Why a download attempt stopped; decides whether the next attempt resumes.
+#[derive(Debug)]
+enum FetchError {
+    Request(ureq::Error),
+    /// Connection dropped mid-body; the partial file is kept.
+    Body(io::Error),
+    /// Writing the partial file failed; retrying will not help.
+    Local(io::Error),
+    /// The server rejected the resume range, so the partial file was discarded.
+    Restart,
+}
+
+impl FetchError {
+    fn retryable(&self) -> bool {
+        match self {
+            Self::Request(err) => should_retry_download(err),
+            Self::Body(_) | Self::Restart => true,
+            Self::Local(_) => false,
+        }
+    }
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Request(err) => write!(f, "{err}"),
+            Self::Body(err) => write!(f, "connection lost: {err}"),
+            Self::Local(err) => write!(f, "write failed: {err}"),
+            Self::Restart => write!(f, "server refused resume, restarting"),
+        }
+    }
+}
+
+/// Copy a download body into `file`, starting the count at `offset` bytes already
+/// on disk, and print a single updating progress bar.
+fn copy_with_progress(
+    reader: &mut impl io::Read,
+    file: &mut File,
+    offset: u64,
+    total: Option<u64>,
+) -> Result<(), FetchError> {
+    let mut buf = vec![0u8; 64 * 1024];
+    let mut written = offset;
+    let mut last_reported = u64::MAX;
+    loop {
+        let read = reader.read(&mut buf).map_err(FetchError::Body)?;
+        if read == 0 {
+            break;
+        }
+        file.write_all(&buf[..read]).map_err(FetchError::Local)?;
+        written += read as u64;
+        let mb = written / (1024 * 1024);
+        if mb != last_reported {
+            last_reported = mb;
+            print!("\r{}", progress_line(written, total));
+            let _ = io::stdout().flush();
+        }
+    }
+    println!();
+    Ok(())
+}
+
+fn progress_line(written: u64, total: Option<u64>) -> String {
+    let mb = written / (1024 * 1024);
+    match total {
+        Some(total) if total > 0 => {
+            let percent = (written.min(total) * 100) / total;
+            let filled = (percent as usize * PROGRESS_BAR_WIDTH) / 100;
+            format!(
+                "[{}{}] {mb} / {} MB ({percent}%)",
+                "#".repeat(filled),
+                " ".repeat(PROGRESS_BAR_WIDTH - filled),
+                total / (1024 * 1024)
+            )
+        }
+        _ => format!("downloaded {mb} MB"),
+    }
+}
+
+/// Download `url` to `dest` through a `.download` partial file that survives
+/// dropped connections and restarts: each attempt asks for the remaining bytes
+/// with a range request, and the finished file must match `expected_sha256`.
+fn download_to_path(url: &str, dest: &Path, expected_sha256: &str) -> Result<(), InitError> {
     let agent = download_agent();
-    let mut last_error: Option<ureq::Error> = None;
-
-    for attempt in 0..=DOWNLOAD_MAX_RETRIES {
-        let response = agent.get(url).call();
-        match response {
-            Ok(resp) => {
-                let tmp_path = dest.with_extension("download");
-                if tmp_path.exists() {
-                    let _ = fs::remove_file(&tmp_path);
-                }
-                let mut reader = resp.into_body().into_reader();
-                let mut file = File::create(&tmp_path)?;
-                io::copy(&mut reader, &mut file)?;
-                file.sync_all()?;
-                fs::rename(tmp_path, dest)?;
-                return Ok(());
-            }
-            Err(err) => {
-                let retry = should_retry_download(&err);
-                last_error = Some(err);
-                if retry && attempt < DOWNLOAD_MAX_RETRIES {
-                    thread::sleep(download_retry_delay(attempt));
-                    continue;
-                }
-                return Err(InitError::Message(format!(
-                    "model download failed: {}",
-                    last_error.unwrap()
-                )));
-            }
+    let partial = dest.with_extension("download");
+    let mut attempt = 0;
+    while let Err(err) = fetch_into(&agent, url, &partial) {
+        if !err.retryable() || attempt >= DOWNLOAD_MAX_RETRIES {
+            return Err(InitError::Message(format!("model download failed: {err}")));
         }
+        let delay = download_retry_delay(attempt);
+        println!(
+            "download interrupted ({err}); resuming in {} ms",
+            delay.as_millis()
+        );
+        thread::sleep(delay);
+        attempt += 1;
+    }
+
+    println!("verifying checksum");
+    let actual = sha256_file(&partial)?;
+    if actual != expected_sha256 {
+        let _ = fs::remove_file(&partial);
+        return Err(InitError::Message(format!(
+            "model checksum mismatch: expected {expected_sha256}, got {actual}"
+        )));
     }
+    fs::rename(partial, dest)?;
+    Ok(())
+}
 
-    Err(InitError::Message(
-        last_error
-            .map(|err| format!("model download failed: {err}"))
-            .unwrap_or_else(|| "model download failed".into()),
-    ))
+/// One download attempt, appending to `partial` when the server honours the range.
+fn fetch_into(agent: &ureq::Agent, url: &str, partial: &Path) -> Result<(), FetchError> {
+    let offset = fs::metadata(partial).map(|meta| meta.len()).unwrap_or(0);
+    let mut request = agent.get(url);
+    if offset > 0 {
+        request = request.header("Range", format!("bytes={offset}-"));
+    }
+    let response = match request.call() {
+        Ok(response) => response,
+        Err(ureq::Error::StatusCode(416)) if offset > 0 => {
+            fs::remove_file(partial).map_err(FetchError::Local)?;
+            return Err(FetchError::Restart);
+        }
+        Err(err) => return Err(FetchError::Request(err)),
+    };
+    let resumed = offset > 0 && response.status().as_u16() == 206;
+    let start = if resumed { offset } else { 0 };
+    if resumed {
+        println!("resuming at {} MB", offset / (1024 * 1024));
+    }
+    let total = response.body().content_length().map(|len| len + start);
+    let mut file = if resumed {
+        OpenOptions::new().append(true).open(partial)
+    } else {
+        File::create(partial)
+    }
+    .map_err(FetchError::Local)?;
+    let mut reader = response.into_body().into_reader();
+    copy_with_progress(&mut reader, &mut file, start, total)?;
+    file.sync_all().map_err(FetchError::Local)
 }
 
 fn download_agent() -> ureq::Agent {
@@ -546,3 +734,18 @@ fn download_retry_delay(attempt: usize) -> Duration {
     let delay = DOWNLOAD_RETRY_BASE_MS.saturating_mul(1_u64 << shift);
     Duration::from_millis(delay)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::progress_line;
+
+    #[test]
+    fn progress_bar_counts_resumed_bytes() {
+        let mb = 1024 * 1024;
+        assert_eq!(
+            progress_line(50 * mb, Some(100 * mb)),
+            format!("[{}{}] 50 / 100 MB (50%)", "#".repeat(15), " ".repeat(15))
+        );
+        assert_eq!(progress_line(3 * mb, None), "downloaded 3 MB");
+    }
+}
@@ -1,24 +1,81 @@
+mod actions;
+mod attach;
+mod audit;
+mod bundle;
+mod calendar;
+mod checksum;
+mod chunk_queue;
+mod clipboard;
 mod config;
 mod config_cmd;
+mod detect;
+mod digest;
+mod doctor;
+mod document;
+mod email;
+mod export;
+mod export_targets;
+mod health;
+mod hooks;
+mod html;
+mod http;
 mod init;
+mod mcp;
+mod mock;
+mod models;
+mod monitor;
+mod notion;
+mod outputs;
+mod prompts;
 mod raw_audio;
+mod reminders;
+mod resummarize;
+mod retranscribe;
 mod session;
+mod sessions;
+mod slack;
+mod stats;
+mod sync;
+#[cfg(test)]
+mod test_support;
+mod transcribe_file;
+mod translate;
 mod tui;
+mod update;
+mod vocabulary;
+mod webhook;
 
+use chunk_queue::{ChunkQueue, QueuedChunk};
 use clap::{Parser, Subcommand};
-use config::{Config, ConfigPaths, ProviderConfig};
-use koe_core::capture::{CaptureConfig, create_capture, list_audio_inputs};
-use koe_core::process::ChunkRecvTimeoutError;
+use config::{Config, ConfigPaths, MeetingProfileConfig, ProviderConfig, SummarizeConfig};
+use koe_core::capture::{
+    CaptureConfig, create_capture, list_audio_inputs, select_default_microphone,
+};
+use koe_core::process::{ChunkRecvTimeoutError, PartialFeed};
+use koe_core::summarize::ask::answer_question;
+use koe_core::summarize::catch_up::{catch_up, segments_since_last_note};
+use koe_core::summarize::chapters::generate_chapters;
+use koe_core::summarize::consolidate::consolidate_notes;
 use koe_core::summarize::create_summarize_provider;
-use koe_core::summarize::filter::{build_participant_tokens, normalize_text, should_keep_segment};
+use koe_core::summarize::filter::{build_participant_tokens, should_keep_segment};
+use koe_core::summarize::lecture::generate_lecture_notes;
+use koe_core::summarize::show_notes::generate_show_notes;
+use koe_core::summarize::summary::generate_summary;
+use koe_core::summarize::template::summarize_with_template;
+use koe_core::summarize::title::generate_title;
+use koe_core::transcribe::failover::FailoverTranscribeProvider;
 use koe_core::transcribe::{TranscribeProvider, create_transcribe_provider};
 use koe_core::transcript::TranscriptLedger;
 use koe_core::types::{
-    AudioSource, CaptureStats, MeetingNotes, NoteBullet, NotesOp, NotesPatch, SummarizeEvent,
+    AudioSource, CaptureStats, MeetingNotes, NotesPatch, SummarizeEvent, TranscriptSegment,
+};
+use koe_core::usage::UsageMeter;
+use raw_audio::{ArchiveFormat, RawAudioMessage, SharedRawAudioWriter, spawn_raw_audio_writer};
+use session::{
+    ExportDir, ExportLayout, FsyncPolicy, SessionFactory, SessionHandle, TranscriptWriter,
 };
-use raw_audio::{RawAudioMessage, SharedRawAudioWriter, spawn_raw_audio_writer};
-use session::SessionFactory;
-use std::collections::HashSet;
+use stats::MeetingCounters;
+use std::io::IsTerminal;
 use std::path::PathBuf;
 use std::sync::mpsc;
 use std::thread;
@@ -37,8 +94,32 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Command {
+    /// Start a meeting showing only the latest transcript lines as captions
+    Captions(RunArgs),
     Init(init::InitArgs),
     Config(config_cmd::ConfigArgs),
+    TranscribeFile(transcribe_file::TranscribeFileArgs),
+    Doctor(doctor::DoctorArgs),
+    Serve(mcp::ServeArgs),
+    Update(update::UpdateArgs),
+    Attach(attach::AttachArgs),
+    #[command(visible_alias = "summarize")]
+    Resummarize(resummarize::ResummarizeArgs),
+    Retranscribe(retranscribe::RetranscribeArgs),
+    /// Write a session's minutes as a DOCX or PDF document
+    Export(export::ExportArgs),
+    Stats(stats::StatsArgs),
+    Models(models::ModelsArgs),
+    Sessions(sessions::SessionsArgs),
+    /// Track action items across sessions: list open ones, mark them done
+    Actions(actions::ActionsArgs),
+    /// Roll recent meetings' notes and open action items into a digest markdown
+    Digest(digest::DigestArgs),
+    /// Back up finalized sessions to S3 or WebDAV storage
+    Sync(sync::SyncArgs),
+    /// Serve canned Groq, OpenRouter and Ollama responses for offline testing
+    #[command(hide = true)]
+    MockProviders(mock::MockProvidersArgs),
 }
 
 #[derive(Parser, Debug, Clone)]
@@ -66,6 +147,22 @@ struct RunArgs {
     /// Preferred participant names (comma-separated)
     #[arg(long, value_delimiter = ',', value_name = "name,...")]
     participants: Option<Vec<String>>,
+
+    /// Workspace from config selecting sessions/export dirs and defaults
+    #[arg(long, value_name = "name")]
+    workspace: Option<String>,
+
+    /// Meeting profile from config bundling context, participants, and modes
+    #[arg(long, value_name = "name")]
+    profile: Option<String>,
+
+    /// Calendar event title; picks the profile from calendar.templates unless --profile is set
+    #[arg(long, value_name = "title")]
+    event: Option<String>,
+
+    /// Start a meeting immediately on launch instead of waiting on the idle screen
+    #[arg(long)]
+    start: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -74,6 +171,10 @@ struct ResolvedRunArgs {
     summarize_profiles: RuntimeProfiles,
     context: Option<String>,
     participants: Vec<String>,
+    workspace: Option<String>,
+    profile: Option<String>,
+    prompt_profile: String,
+    auto_start: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -84,6 +185,8 @@ struct RuntimeProfiles {
 }
 
 const RAW_AUDIO_QUEUE_CAP: usize = 16;
+/// One second of silence at the transcribers' input rate for the pre-meeting probe.
+const PROBE_SAMPLE_RATE_HZ: u32 = 16_000;
 
 impl RuntimeProfiles {
     fn from_config(active: &str, local: &ProviderConfig, cloud: &ProviderConfig) -> Self {
@@ -131,14 +234,40 @@ impl RunArgs {
 
         apply_env_overrides(&mut transcribe_profiles, &mut summarize_profiles);
 
+        let profile_name = self.profile.clone().or_else(|| {
+            self.event
+                .as_deref()
+                .and_then(|title| config.calendar.profile_for_event(title))
+                .map(str::to_string)
+        });
+        let profile = match profile_name.as_deref() {
+            Some(name) => Some(
+                config
+                    .profiles
+                    .get(name)
+                    .ok_or_else(|| format!("unknown profile: {name}"))?,
+            ),
+            None => None,
+        };
+        let profile_value = |value: fn(&MeetingProfileConfig) -> &String| {
+            profile
+                .map(value)
+                .map(String::as_str)
+                .filter(|value| !value.is_empty())
+        };
+
         let transcribe_mode = select_mode(
             transcribe_profiles.active.as_str(),
-            self.transcribe.as_deref(),
+            self.transcribe
+                .as_deref()
+                .or_else(|| profile_value(|profile| &profile.transcribe)),
             "transcribe",
         )?;
         let summarize_mode = select_mode(
             summarize_profiles.active.as_str(),
-            self.summarize.as_deref(),
+            self.summarize
+                .as_deref()
+                .or_else(|| profile_value(|profile| &profile.summarize)),
             "summarize",
         )?;
         transcribe_profiles.active = transcribe_mode;
@@ -151,12 +280,38 @@ impl RunArgs {
             summarize_profiles.active_profile_mut().model = model;
         }
 
+        let workspace = match self.workspace.as_deref() {
+            Some(name) => Some(
+                config
+                    .workspaces
+                    .get(name)
+                    .ok_or_else(|| format!("unknown workspace: {name}"))?,
+            ),
+            None => None,
+        };
         let context = self.context.or_else(|| {
-            let value = config.session.context.clone();
+            let value = profile_value(|profile| &profile.context)
+                .map(str::to_string)
+                .or_else(|| {
+                    workspace
+                        .map(|workspace| workspace.context.clone())
+                        .filter(|value| !value.is_empty())
+                })
+                .unwrap_or_else(|| config.session.context.clone());
             if value.is_empty() { None } else { Some(value) }
         });
         let participants = self
             .participants
+            .or_else(|| {
+                profile
+                    .map(|profile| profile.participants.clone())
+                    .filter(|participants| !participants.is_empty())
+            })
+            .or_else(|| {
+                workspace
+                    .map(|workspace| workspace.participants.clone())
+                    .filter(|participants| !participants.is_empty())
+            })
             .unwrap_or_else(|| config.session.participants.clone())
             .into_iter()
             .map(|value| value.trim().to_string())
@@ -168,6 +323,12 @@ impl RunArgs {
             summarize_profiles,
             context,
             participants,
+            workspace: self.workspace,
+            prompt_profile: profile_value(|profile| &profile.prompt_profile)
+                .unwrap_or(config.summarize.prompt_profile.as_str())
+                .to_string(),
+            profile: profile_name,
+            auto_start: self.start || config.session.auto_start,
         })
     }
 }
@@ -223,15 +384,29 @@ fn apply_env_overrides(transcribe: &mut RuntimeProfiles, summarize: &mut Runtime
     {
         summarize.cloud.api_key = value;
     }
-    if transcribe.cloud.api_key.trim().is_empty()
-        && let Some(value) = env_override("GROQ_API_KEY")
-    {
-        transcribe.cloud.api_key = value;
+    for profile in [&mut transcribe.local, &mut transcribe.cloud] {
+        let key = match profile.provider.as_str() {
+            "assemblyai" => "ASSEMBLYAI_API_KEY",
+            _ => "GROQ_API_KEY",
+        };
+        if profile.api_key.trim().is_empty()
+            && profile.provider != "whisper"
+            && let Some(value) = env_override(key)
+        {
+            profile.api_key = value;
+        }
     }
-    if summarize.cloud.api_key.trim().is_empty()
-        && let Some(value) = env_override("OPENROUTER_API_KEY")
-    {
-        summarize.cloud.api_key = value;
+    for profile in [&mut summarize.local, &mut summarize.cloud] {
+        let key = match profile.provider.as_str() {
+            "gemini" => "GEMINI_API_KEY",
+            _ => "OPENROUTER_API_KEY",
+        };
+        if profile.api_key.trim().is_empty()
+            && profile.provider != "ollama"
+            && let Some(value) = env_override(key)
+        {
+            profile.api_key = value;
+        }
     }
 }
 
@@ -282,8 +457,15 @@ fn main() {
         }
     };
 
+    let mut run_args = cli.run;
+    let mut captions = false;
     if let Some(command) = cli.command {
         match command {
+            Command::Captions(args) => {
+                run_args = args;
+                run_args.start = true;
+                captions = true;
+            }
             Command::Init(args) => {
                 if let Err(e) = init::run(&args, &paths) {
                     eprintln!("init failed: {e}");
@@ -298,20 +480,140 @@ fn main() {
                 }
                 return;
             }
+            Command::TranscribeFile(args) => {
+                if let Err(e) = transcribe_file::run(&args, &paths, &config) {
+                    eprintln!("transcribe-file failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Doctor(args) => {
+                if let Err(e) = doctor::run(&args, &paths, &config) {
+                    eprintln!("doctor failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Serve(args) => {
+                if let Err(e) = mcp::run(&args, &paths, &config) {
+                    eprintln!("serve failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Update(args) => {
+                if let Err(e) = update::run(&args) {
+                    eprintln!("update failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Attach(args) => {
+                if let Err(e) = attach::run(&args, &paths, &config) {
+                    eprintln!("attach failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Resummarize(args) => {
+                if let Err(e) = resummarize::run(&args, &paths, &config) {
+                    eprintln!("resummarize failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Retranscribe(args) => {
+                if let Err(e) = retranscribe::run(&args, &paths, &config) {
+                    eprintln!("retranscribe failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Export(args) => {
+                if let Err(e) = export::run(&args, &paths, &config) {
+                    eprintln!("export failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Stats(args) => {
+                if let Err(e) = stats::run(&args, &paths, &config) {
+                    eprintln!("stats failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Models(args) => {
+                if let Err(e) = models::run(&args, &paths, &config) {
+                    eprintln!("models failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Sessions(args) => {
+                if let Err(e) = sessions::run(&args, &paths, &config) {
+                    eprintln!("sessions failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Actions(args) => {
+                if let Err(e) = actions::run(&args, &paths, &config) {
+                    eprintln!("actions failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Digest(args) => {
+                if let Err(e) = digest::run(&args, &paths, &config) {
+                    eprintln!("digest failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::Sync(args) => {
+                if let Err(e) = sync::run(&args, &paths, &config) {
+                    eprintln!("sync failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Command::MockProviders(args) => {
+                if let Err(e) = mock::run(&args, &paths, &config) {
+                    eprintln!("mock providers failed: {e}");
+                    std::process::exit(1);
+                }
+                return;
+            }
         }
     }
 
-    let mut run = match cli.run.resolve(&config) {
+    if run_args.event.is_none() && run_args.profile.is_none() {
+        match calendar::current_event(&config.calendar) {
+            Ok(event) => run_args.event = event.map(|event| event.title),
+            Err(e) => eprintln!("calendar lookup failed: {e}"),
+        }
+    }
+    let mut run = match run_args.resolve(&config) {
         Ok(run) => run,
         Err(err) => {
             eprintln!("run args error: {err}");
             std::process::exit(1);
         }
     };
+    let config = config.for_workspace(run.workspace.as_deref());
     let stats = CaptureStats::new();
     let stats_display = stats.clone();
+    let usage = UsageMeter::new();
     let models_dir = paths.models_dir.clone();
 
+    if run.transcribe_profiles.active_profile().provider == "whisper"
+        && whisper_model_path(&run.transcribe_profiles.active_profile().model, &models_dir)
+            .is_none()
+        && std::io::stdin().is_terminal()
+    {
+        resolve_missing_model(&mut run.transcribe_profiles);
+    }
     if run.transcribe_profiles.active_profile().provider == "whisper" {
         let profile = run.transcribe_profiles.active_profile_mut();
         if let Err(e) = ensure_whisper_model(&mut profile.model, &models_dir) {
@@ -324,7 +626,7 @@ fn main() {
     let summarize_profiles_ui = to_ui_profiles(&run.summarize_profiles);
 
     let active_transcribe = run.transcribe_profiles.active_profile();
-    let mut transcribe = match create_transcribe_provider(
+    let mut primary = match create_transcribe_provider(
         active_transcribe.provider.as_str(),
         Some(active_transcribe.model.as_str()),
         non_empty_str(active_transcribe.api_key.as_str()),
@@ -335,6 +637,23 @@ fn main() {
             std::process::exit(1);
         }
     };
+    primary.set_usage_meter(usage.clone());
+    let mut providers = vec![primary];
+    if config.transcribe.failover {
+        match create_fallback_transcribe(&run.transcribe_profiles, &models_dir) {
+            Ok(fallback) => providers.push(fallback),
+            Err(e) => eprintln!("transcribe failover disabled: {e}"),
+        }
+    }
+    // Always a chain, even of one, so segment ids stay monotonic when a
+    // provider is swapped mid-meeting.
+    let mut transcribe = match FailoverTranscribeProvider::new(providers) {
+        Ok(chain) => chain,
+        Err(e) => {
+            eprintln!("transcribe init failed: {e}");
+            std::process::exit(1);
+        }
+    };
 
     let shared_writer = SharedRawAudioWriter::new(None);
     let (raw_tx, raw_rx) = mpsc::sync_channel(RAW_AUDIO_QUEUE_CAP);
@@ -348,13 +667,26 @@ fn main() {
 
     let capture_config =
         capture_config_from_sources(&config.audio.sources, &config.audio.microphone_device_id);
-    let capture = match create_capture(stats.clone(), capture_config) {
+    let capture_sources: Vec<AudioSource> = [
+        (capture_config.capture_system, AudioSource::System),
+        (capture_config.capture_microphone, AudioSource::Microphone),
+    ]
+    .into_iter()
+    .filter_map(|(enabled, source)| enabled.then_some(source))
+    .collect();
+    let (ui_tx, ui_rx) = mpsc::channel();
+    let _ = ui_tx.send(UiEvent::NotesPatch(NotesPatch { ops: Vec::new() }));
+    let mut capture = match create_capture(stats.clone(), capture_config) {
         Ok(c) => c,
         Err(e) => {
             eprintln!("capture init failed: {e}");
             std::process::exit(1);
         }
     };
+    let ui_tx_device = ui_tx.clone();
+    capture.set_device_listener(Box::new(move |change| {
+        let _ = ui_tx_device.send(UiEvent::MicrophoneChanged(change.clone()));
+    }));
 
     let raw_sink: Option<koe_core::process::RawAudioSink> = {
         let raw_tx = raw_tx.clone();
@@ -370,17 +702,21 @@ fn main() {
         }))
     };
 
-    let (processor, chunk_rx) =
-        match koe_core::process::AudioProcessor::start(capture, stats, raw_sink) {
-            Ok(p) => p,
-            Err(e) => {
-                eprintln!("processor start failed: {e}");
-                std::process::exit(1);
-            }
-        };
+    let partial_feed = config.transcribe.streaming.then(PartialFeed::new);
+    let (processor, chunk_rx) = match koe_core::process::AudioProcessor::start(
+        capture,
+        stats,
+        raw_sink,
+        partial_feed.clone(),
+        config.process.to_core(),
+    ) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("processor start failed: {e}");
+            std::process::exit(1);
+        }
+    };
 
-    let (ui_tx, ui_rx) = mpsc::channel();
-    let _ = ui_tx.send(UiEvent::NotesPatch(NotesPatch { ops: Vec::new() }));
     let (transcribe_cmd_tx, transcribe_cmd_rx) = mpsc::channel();
     let (summarize_cmd_tx, summarize_cmd_rx) = mpsc::channel();
     let (summarize_tx_transcribe, summarize_rx) = mpsc::sync_channel(1);
@@ -388,36 +724,90 @@ fn main() {
     let ui_tx_summarize_error = ui_tx.clone();
     let ui_tx_transcribe = ui_tx.clone();
     let transcribe_profiles_runtime = run.transcribe_profiles.clone();
+    let offline_queue_chunks = config.transcribe.offline_queue_chunks as usize;
+    let fsync_policy = FsyncPolicy::parse(&config.session.fsync).unwrap_or(FsyncPolicy::Interval);
     let summarize_profiles_runtime = run.summarize_profiles.clone();
     let summarize_context = run.context.clone().unwrap_or_default();
     let summarize_participants = run.participants.clone();
+    let mut summarize_config = config.summarize.clone();
+    summarize_config.prompt_profile = run.prompt_profile.clone();
+    let prompts_dir = paths.prompts_dir.clone();
+    let summarize_usage = usage.clone();
+    let meeting_counters = MeetingCounters::new();
+    let summarize_counters = meeting_counters.clone();
+    let metrics_path = config.metrics.enabled.then(|| paths.metrics_path.clone());
+    let slack_on_end = config
+        .integrations
+        .slack
+        .post_on_end
+        .then(|| config.integrations.slack.clone());
 
     let summarize_thread =
         match thread::Builder::new()
             .name("koe-summarize".into())
             .spawn(move || {
-                const SUMMARIZE_INTERVAL: Duration = Duration::from_secs(4);
+                const DEGRADED_INTERVAL_FACTOR: u32 = 3;
                 const STABLE_WINDOW_MS: i64 = 4_000;
                 const TAIL_WINDOW_MS: i64 = 15_000;
                 const MAX_NOTES_FOR_PROMPT: usize = 50;
+                // Older bullets only matter for dedupe; the UI archives them to disk.
+                const MAX_TRACKED_NOTES: usize = 200;
                 const MIN_NEW_WORDS: usize = 4;
+                // Transcript span sent with an "ask" question.
+                const ASK_WINDOW_MS: i64 = 10 * 60 * 1_000;
+                // Longest span a "catch me up" digest covers.
+                const CATCH_UP_WINDOW_MS: i64 = 30 * 60 * 1_000;
+                // New transcript needed before the live chapter outline is redrawn.
+                const CHAPTER_INTERVAL_MS: i64 = 10 * 60 * 1_000;
+                // Fewer live notes than this are not worth a consolidation pass.
+                const MIN_NOTES_TO_CONSOLIDATE: usize = 8;
 
                 let current_mode = summarize_profiles_runtime.active.clone();
                 let mut context = summarize_context;
-                let participants = summarize_participants;
-                let participant_tokens = build_participant_tokens(&participants);
+                let mut summarize_config = summarize_config;
+                let mut participants = summarize_participants;
+                let mut participant_tokens = build_participant_tokens(&participants);
                 let mut ledger = TranscriptLedger::new();
                 let mut meeting_notes = MeetingNotes::default();
-                let mut last_summary_at = Instant::now() - SUMMARIZE_INTERVAL;
+                let mut base_interval =
+                    Duration::from_secs(u64::from(summarize_config.interval_secs));
+                let mut degraded = false;
+                let mut summarize_interval = base_interval;
+                let window_segments = summarize_config.window_segments as usize;
+                let adaptive = summarize_config.schedule == "adaptive";
+                let trigger_segments = summarize_config.trigger_segments as usize;
+                let pause = Duration::from_millis(u64::from(summarize_config.pause_ms));
+                let mut last_transcript_at = Instant::now();
+                let max_ops = summarize_config.max_ops as usize;
+                let mut last_summary_at = Instant::now() - summarize_interval;
                 let mut last_summarized_id: u64 = 0;
+                let mut chapters_through_ms: i64 = 0;
+                let consolidate_interval =
+                    Duration::from_secs(u64::from(summarize_config.consolidate_secs));
+                let mut last_consolidated_at = Instant::now();
+                let mut notes_since_consolidation = false;
+                let mut disconnected = false;
 
                 let send_status = |mode: String, provider: String| {
                     let _ = ui_tx_summarize.send(UiEvent::SummarizeStatus { mode, provider });
                 };
+                let load_template =
+                    |profile: &str| match prompts::resolve_template(&prompts_dir, profile) {
+                        Ok(template) => template,
+                        Err(e) => {
+                            let _ = ui_tx_summarize.send(UiEvent::Error {
+                                message: format!("{e}; using the built-in prompt"),
+                            });
+                            None
+                        }
+                    };
+                let mut prompt_template = load_template(&summarize_config.prompt_profile);
 
                 let mut summarize =
                     match create_summarize_for_mode(&summarize_profiles_runtime, &current_mode) {
-                        Ok(provider) => {
+                        Ok(mut provider) => {
+                            provider.set_usage_meter(summarize_usage.clone());
+                            provider.set_max_ops(max_ops);
                             let profile = summarize_profiles_runtime.active_profile();
                             send_status(current_mode.clone(), profile.provider.clone());
                             Some(provider)
@@ -439,30 +829,257 @@ fn main() {
                                 ledger = TranscriptLedger::new();
                                 meeting_notes = MeetingNotes::default();
                                 last_summarized_id = 0;
-                                last_summary_at = Instant::now() - SUMMARIZE_INTERVAL;
+                                chapters_through_ms = 0;
+                                last_summary_at = Instant::now() - summarize_interval;
+                                last_consolidated_at = Instant::now();
+                                notes_since_consolidation = false;
+                                summarize_usage.reset();
+                            }
+                            SummarizeCommand::Degrade => {
+                                degraded = true;
+                                summarize_interval = base_interval * DEGRADED_INTERVAL_FACTOR;
+                            }
+                            SummarizeCommand::SetInterval(secs) => {
+                                base_interval = Duration::from_secs(u64::from(secs));
+                                summarize_interval = if degraded {
+                                    base_interval * DEGRADED_INTERVAL_FACTOR
+                                } else {
+                                    base_interval
+                                };
                             }
                             SummarizeCommand::UpdateContext(value) => {
                                 context = value;
                             }
+                            SummarizeCommand::UpdateParticipants(value) => {
+                                participant_tokens = build_participant_tokens(&value);
+                                participants = value;
+                            }
+                            SummarizeCommand::SetPromptProfile(value) => {
+                                prompt_template = load_template(&value);
+                                summarize_config.prompt_profile = value;
+                            }
+                            SummarizeCommand::Ask(question) => {
+                                let error = match summarize.as_mut() {
+                                    Some(provider) => {
+                                        let since = ledger.highest_end_ms() - ASK_WINDOW_MS;
+                                        let window: Vec<TranscriptSegment> = ledger
+                                            .segments()
+                                            .iter()
+                                            .filter(|seg| seg.end_ms >= since)
+                                            .cloned()
+                                            .collect();
+                                        answer_question(
+                                            provider.as_mut(),
+                                            &question,
+                                            &window,
+                                            non_empty_str(&context),
+                                            &participants,
+                                            &mut |token| {
+                                                let _ = ui_tx_summarize
+                                                    .send(UiEvent::AskToken(token.to_string()));
+                                            },
+                                        )
+                                        .err()
+                                        .map(|e| e.to_string())
+                                    }
+                                    None => Some("summarize provider unavailable".to_string()),
+                                };
+                                let _ = ui_tx_summarize.send(UiEvent::AskDone { error });
+                            }
+                            SummarizeCommand::CatchUp => {
+                                let since = ledger.highest_end_ms() - CATCH_UP_WINDOW_MS;
+                                let window: Vec<TranscriptSegment> =
+                                    segments_since_last_note(ledger.segments(), &meeting_notes)
+                                        .iter()
+                                        .filter(|seg| seg.end_ms >= since)
+                                        .cloned()
+                                        .collect();
+                                // Nothing new: the overlay says so without a provider call.
+                                let error = match summarize.as_mut() {
+                                    _ if window.is_empty() => None,
+                                    Some(provider) => catch_up(
+                                        provider.as_mut(),
+                                        &window,
+                                        non_empty_str(&context),
+                                        &participants,
+                                        &mut |token| {
+                                            let _ = ui_tx_summarize
+                                                .send(UiEvent::CatchUpToken(token.to_string()));
+                                        },
+                                    )
+                                    .err()
+                                    .map(|e| e.to_string()),
+                                    None => Some("summarize provider unavailable".to_string()),
+                                };
+                                let _ = ui_tx_summarize.send(UiEvent::CatchUpDone { error });
+                            }
+                            SummarizeCommand::Finalize {
+                                mut session,
+                                segments,
+                            } => {
+                                if summarize_config.auto_title
+                                    && let Some(provider) = summarize.as_mut()
+                                {
+                                    let event = match auto_title(
+                                        provider.as_mut(),
+                                        &mut session,
+                                        &segments,
+                                        non_empty_str(&context),
+                                        &participants,
+                                    ) {
+                                        Ok(Some(title)) => Some(UiEvent::SessionTitled {
+                                            session_id: session.metadata().id.clone(),
+                                            title,
+                                        }),
+                                        Ok(None) => None,
+                                        Err(e) => Some(UiEvent::Error {
+                                            message: format!("auto title failed: {e}"),
+                                        }),
+                                    };
+                                    if let Some(event) = event {
+                                        let _ = ui_tx_summarize.send(event);
+                                    }
+                                }
+                                if let Some(provider) = summarize.as_mut()
+                                    && let Err(e) = run_finalize_passes(
+                                        provider.as_mut(),
+                                        &summarize_config,
+                                        &session,
+                                        &segments,
+                                        non_empty_str(&context),
+                                        &participants,
+                                    )
+                                {
+                                    let _ = ui_tx_summarize.send(UiEvent::Error {
+                                        message: format!("summarize finalize failed: {e}"),
+                                    });
+                                }
+                                if let Some(slack) = &slack_on_end
+                                    && let Err(e) =
+                                        slack::post_minutes(slack, session.session_dir())
+                                {
+                                    let _ = ui_tx_summarize.send(UiEvent::Error {
+                                        message: format!("{e}"),
+                                    });
+                                }
+                                let report = summarize_usage.report();
+                                if !report.providers.is_empty()
+                                    && let Err(e) = session.write_usage(&report)
+                                {
+                                    let _ = ui_tx_summarize.send(UiEvent::Error {
+                                        message: format!("usage write failed: {e}"),
+                                    });
+                                }
+                                let metrics = summarize_counters.finish(&session, &segments);
+                                if let Some(path) = &metrics_path
+                                    && let Err(e) = stats::append(path, &metrics)
+                                {
+                                    let _ = ui_tx_summarize.send(UiEvent::Error {
+                                        message: format!("metrics write failed: {e}"),
+                                    });
+                                }
+                            }
                         }
                     }
 
+                    if disconnected {
+                        break;
+                    }
+
                     match summarize_rx.recv_timeout(Duration::from_millis(200)) {
                         Ok(segments) => {
                             ledger.append(segments);
+                            last_transcript_at = Instant::now();
                         }
                         Err(mpsc::RecvTimeoutError::Timeout) => {}
-                        Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                        Err(mpsc::RecvTimeoutError::Disconnected) => {
+                            // Handle a Finalize queued on quit before shutting down.
+                            disconnected = true;
+                            continue;
+                        }
                     }
 
-                    let due = Instant::now().duration_since(last_summary_at) >= SUMMARIZE_INTERVAL;
+                    // Transcript only arrives while the VAD hears speech, so a
+                    // quiet channel is a pause between turns.
+                    let paused = adaptive && last_transcript_at.elapsed() >= pause;
+                    let (mut new_segments, mut new_finalized) = (0usize, 0usize);
+                    for seg in ledger.segments() {
+                        if seg.id > last_summarized_id {
+                            new_segments += 1;
+                            new_finalized += usize::from(seg.finalized);
+                        }
+                    }
+                    let due = summarize_due(
+                        adaptive,
+                        last_summary_at.elapsed(),
+                        summarize_interval,
+                        new_finalized >= trigger_segments,
+                        paused && new_segments > 0,
+                    );
 
                     if !due {
                         continue;
                     }
 
                     let highest_end_ms = ledger.highest_end_ms();
-                    let stable_cutoff = highest_end_ms - STABLE_WINDOW_MS;
+                    if summarize_config.chapters
+                        && highest_end_ms - chapters_through_ms >= CHAPTER_INTERVAL_MS
+                        && let Some(provider) = summarize.as_mut()
+                    {
+                        chapters_through_ms = highest_end_ms;
+                        match generate_chapters(
+                            provider.as_mut(),
+                            ledger.segments(),
+                            non_empty_str(&context),
+                            &participants,
+                        ) {
+                            Ok(chapters) => {
+                                let _ = ui_tx_summarize.send(UiEvent::Chapters(chapters));
+                            }
+                            Err(e) => {
+                                let _ = ui_tx_summarize.send(UiEvent::Error {
+                                    message: format!("chapters error: {e}"),
+                                });
+                            }
+                        }
+                    }
+                    if !consolidate_interval.is_zero()
+                        && notes_since_consolidation
+                        && meeting_notes.bullets.len() >= MIN_NOTES_TO_CONSOLIDATE
+                        && last_consolidated_at.elapsed() >= consolidate_interval
+                        && let Some(provider) = summarize.as_mut()
+                    {
+                        last_consolidated_at = Instant::now();
+                        notes_since_consolidation = false;
+                        // The same recent window the live prompt sees.
+                        let start = meeting_notes
+                            .bullets
+                            .len()
+                            .saturating_sub(MAX_NOTES_FOR_PROMPT);
+                        let recent = MeetingNotes {
+                            bullets: meeting_notes.bullets[start..].to_vec(),
+                        };
+                        match consolidate_notes(provider.as_mut(), &recent, non_empty_str(&context))
+                        {
+                            Ok(patch) if !patch.ops.is_empty() => {
+                                meeting_notes.apply_patch(patch.clone());
+                                let _ = ui_tx_summarize.send(UiEvent::NotesPatch(patch));
+                            }
+                            Ok(_) => {}
+                            Err(e) => {
+                                let _ = ui_tx_summarize.send(UiEvent::Error {
+                                    message: format!("notes consolidation error: {e}"),
+                                });
+                            }
+                        }
+                    }
+
+                    // Nothing will revise the latest segments during a pause.
+                    let stable_cutoff = if paused {
+                        highest_end_ms
+                    } else {
+                        highest_end_ms - STABLE_WINDOW_MS
+                    };
                     let tail_cutoff = highest_end_ms - TAIL_WINDOW_MS;
                     let mut new_word_count = 0usize;
                     let mut max_new_id = last_summarized_id;
@@ -481,6 +1098,10 @@ fn main() {
                         if !should_keep_segment(&seg.text, &participant_tokens) {
                             continue;
                         }
+                        // Later segments stay new and go out with the next update.
+                        if segments_for_prompt.len() >= window_segments {
+                            break;
+                        }
                         if is_new {
                             has_new_segment = true;
                             max_new_id = max_new_id.max(seg.id);
@@ -517,24 +1138,47 @@ fn main() {
                         meeting_notes.clone()
                     };
 
-                    let result = provider.summarize(
-                        &segments_for_prompt,
-                        &notes_for_prompt,
-                        context_ref,
-                        &participants,
-                        &mut |event| match event {
-                            SummarizeEvent::DraftToken(_) => {}
-                            SummarizeEvent::PatchReady(patch) => {
-                                patch_ready = Some(patch);
-                            }
-                        },
-                    );
+                    let mut on_event = |event| match event {
+                        SummarizeEvent::DraftToken(token) => {
+                            let _ = ui_tx_summarize.send(UiEvent::SummarizeDraftToken(token));
+                        }
+                        SummarizeEvent::PatchReady(patch) => {
+                            patch_ready = Some(patch);
+                        }
+                    };
+                    let result = match prompt_template.as_deref() {
+                        Some(template) => summarize_with_template(
+                            provider.as_mut(),
+                            template,
+                            &segments_for_prompt,
+                            &notes_for_prompt,
+                            context_ref,
+                            &participants,
+                            &mut on_event,
+                        ),
+                        None => provider.summarize(
+                            &segments_for_prompt,
+                            &notes_for_prompt,
+                            context_ref,
+                            &participants,
+                            &mut on_event,
+                        ),
+                    };
 
+                    summarize_counters.record_summarize(result.is_ok());
+                    let _ = ui_tx_summarize.send(UiEvent::SummarizeDraftDone);
                     match result {
                         Ok(()) => {
                             last_summary_at = Instant::now();
-                            if let Some(patch) = patch_ready {
-                                apply_notes_patch_state(&mut meeting_notes, patch.clone());
+                            if let Some(mut patch) = patch_ready {
+                                patch.ops.truncate(max_ops);
+                                notes_since_consolidation |=
+                                    meeting_notes.apply_patch(patch.clone());
+                                let excess = meeting_notes
+                                    .bullets
+                                    .len()
+                                    .saturating_sub(MAX_TRACKED_NOTES);
+                                meeting_notes.bullets.drain(..excess);
                                 let _ = ui_tx_summarize.send(UiEvent::NotesPatch(patch));
                             }
                             last_summarized_id = max_new_id;
@@ -557,120 +1201,460 @@ fn main() {
             }
         };
 
-    let transcribe_thread =
-        match thread::Builder::new()
-            .name("koe-transcribe".into())
-            .spawn(move || {
-                let current_mode = transcribe_profiles_runtime.active.clone();
-
-                let send_status = |mode: String, provider: String, connected: bool| {
-                    let _ = ui_tx_transcribe.send(UiEvent::TranscribeStatus {
-                        mode,
-                        provider,
-                        connected,
-                    });
-                };
+    let translate_tx = if config.translate.enabled {
+        match translate::spawn_translator(
+            &run.summarize_profiles,
+            config.translate.target_language.clone(),
+            run.context.clone(),
+            usage.clone(),
+            ui_tx.clone(),
+        ) {
+            Ok(tx) => Some(tx),
+            Err(e) => {
+                eprintln!("translate thread spawn failed: {e}");
+                None
+            }
+        }
+    } else {
+        None
+    };
 
-                let active_profile = transcribe_profiles_runtime.active_profile();
-                send_status(current_mode.clone(), active_profile.provider.clone(), true);
-                let mut latency_ms: Option<u128> = None;
-                let mut drain_ack: Option<mpsc::Sender<()>> = None;
+    let learned_vocabulary = match vocabulary::load(&paths.vocabulary_path) {
+        Ok(learned) => learned,
+        Err(e) => {
+            eprintln!("vocabulary load failed: {e}");
+            koe_core::vocabulary::Vocabulary::default()
+        }
+    };
+    let mut transcribe_vocabulary = learned_vocabulary.clone();
+    let transcribe_redactor = config.redact.redactor();
+
+    let transcribe_thread = match thread::Builder::new().name("koe-transcribe".into()).spawn(
+        move || {
+            let current_mode = transcribe_profiles_runtime.active.clone();
+
+            let send_status = |mode: String, provider: String, connected: bool| {
+                let _ = ui_tx_transcribe.send(UiEvent::TranscribeStatus {
+                    mode,
+                    provider,
+                    connected,
+                    ready: true,
+                });
+            };
 
-                loop {
-                    while let Ok(cmd) = transcribe_cmd_rx.try_recv() {
-                        match cmd {
-                            TranscribeCommand::Drain(ack) => {
-                                drain_ack = Some(ack);
+            let active_profile = transcribe_profiles_runtime.active_profile();
+            warm_up_transcribe(
+                &mut transcribe,
+                &current_mode,
+                &active_profile.provider,
+                &ui_tx_transcribe,
+            );
+            let mut latency_ms: Option<u128> = None;
+            let mut drain_ack: Option<mpsc::Sender<()>> = None;
+            let mut active_provider = transcribe.name();
+            let mut chunk_queue: Option<ChunkQueue> = None;
+            let mut connected = true;
+            let mut transcript_writer: Option<TranscriptWriter> = None;
+
+            loop {
+                while let Ok(cmd) = transcribe_cmd_rx.try_recv() {
+                    match cmd {
+                        TranscribeCommand::Drain(ack) => {
+                            drain_ack = Some(ack);
+                        }
+                        TranscribeCommand::Probe(reply) => {
+                            let chunk = koe_core::types::AudioChunk {
+                                source: AudioSource::Mixed,
+                                start_pts_ns: 0,
+                                sample_rate_hz: PROBE_SAMPLE_RATE_HZ,
+                                pcm_mono_f32: vec![0.0; PROBE_SAMPLE_RATE_HZ as usize],
+                            };
+                            let result = transcribe
+                                .transcribe(&chunk)
+                                .map(|_| ())
+                                .map_err(|e| e.to_string());
+                            let _ = reply.send(result);
+                        }
+                        TranscribeCommand::Degrade(model) => {
+                            if transcribe.name() != "whisper" {
+                                continue;
+                            }
+                            let model = model.to_string_lossy();
+                            match create_transcribe_provider("whisper", Some(&model), None) {
+                                Ok(mut provider) => {
+                                    warm_up_transcribe(
+                                        provider.as_mut(),
+                                        mode_for_provider(&transcribe_profiles_runtime, "whisper"),
+                                        "whisper",
+                                        &ui_tx_transcribe,
+                                    );
+                                    transcribe.replace_active(provider);
+                                }
+                                Err(e) => {
+                                    let _ = ui_tx_transcribe.send(UiEvent::Error {
+                                        message: format!("degraded model load failed: {e}"),
+                                    });
+                                }
                             }
                         }
+                        TranscribeCommand::UpdateVocabulary(value) => {
+                            transcribe_vocabulary = value;
+                        }
+                        TranscribeCommand::SessionStarted {
+                            dir,
+                            transcript_path,
+                        } => {
+                            // Chunks still pending from an earlier meeting stay in its dir.
+                            chunk_queue = (offline_queue_chunks > 0).then(|| {
+                                ChunkQueue::new(dir.join("pending"), offline_queue_chunks)
+                            });
+                            transcript_writer =
+                                match TranscriptWriter::open(&transcript_path, fsync_policy) {
+                                    Ok(writer) => Some(writer),
+                                    Err(e) => {
+                                        let _ = ui_tx_transcribe.send(UiEvent::Error {
+                                            message: format!("session transcript open failed: {e}"),
+                                        });
+                                        None
+                                    }
+                                };
+                        }
                     }
+                }
 
-                    let chunk = match chunk_rx.recv_timeout(Duration::from_millis(50)) {
-                        Ok(chunk) => chunk,
-                        Err(ChunkRecvTimeoutError::Timeout) => {
-                            if let Some(ack) = drain_ack.take() {
-                                let _ = ack.send(());
+                let mut queued: Option<PathBuf> = None;
+                let chunk = match chunk_rx.recv_timeout(Duration::from_millis(50)) {
+                    Ok(chunk) => chunk,
+                    Err(ChunkRecvTimeoutError::Timeout) => {
+                        if let Some(ack) = drain_ack.take() {
+                            // Drain precedes finalize: the meeting's transcript is complete.
+                            if let Some(mut writer) = transcript_writer.take()
+                                && let Err(e) = writer.sync(Instant::now())
+                            {
+                                let _ = ui_tx_transcribe.send(UiEvent::Error {
+                                    message: format!("session transcript sync failed: {e}"),
+                                });
                             }
-                            continue;
+                            let _ = ack.send(());
+                        }
+                        if let Some(writer) = transcript_writer.as_mut()
+                            && let Err(e) = writer.tick(Instant::now())
+                        {
+                            let _ = ui_tx_transcribe.send(UiEvent::Error {
+                                message: format!("session transcript sync failed: {e}"),
+                            });
                         }
-                        Err(ChunkRecvTimeoutError::Disconnected) => {
-                            if let Some(ack) = drain_ack.take() {
-                                let _ = ack.send(());
+                        if let Some(retry) = next_queued_chunk(&mut chunk_queue, &ui_tx_transcribe)
+                        {
+                            let QueuedChunk { path, chunk } = retry;
+                            queued = Some(path);
+                            chunk
+                        } else {
+                            // Partials only run while no final chunk is waiting.
+                            for partial in partial_feed.iter().flat_map(PartialFeed::take) {
+                                let mut segments = match transcribe.transcribe_partial(&partial) {
+                                    Ok(segments) => segments,
+                                    Err(e) => {
+                                        let _ = ui_tx_transcribe.send(UiEvent::Error {
+                                            message: format!("transcribe partial error: {e}"),
+                                        });
+                                        continue;
+                                    }
+                                };
+                                if segments.is_empty() {
+                                    continue;
+                                }
+                                if let Some(speaker) = default_speaker(partial.source) {
+                                    for seg in &mut segments {
+                                        seg.speaker.get_or_insert_with(|| speaker.to_string());
+                                    }
+                                }
+                                if let Some(redactor) = &transcribe_redactor {
+                                    for seg in &mut segments {
+                                        redactor.apply(seg);
+                                    }
+                                }
+                                let _ = ui_tx_transcribe.send(UiEvent::TranscriptPartial(segments));
                             }
-                            break;
+                            continue;
                         }
-                    };
+                    }
+                    Err(ChunkRecvTimeoutError::Disconnected) => {
+                        if let Some(ack) = drain_ack.take() {
+                            let _ = ack.send(());
+                        }
+                        break;
+                    }
+                };
 
-                    let (mut segments, elapsed) =
-                        match transcribe_with_latency(transcribe.as_mut(), &chunk) {
-                            Ok(result) => result,
-                            Err(e) => {
-                                let _ = ui_tx_transcribe.send(UiEvent::Error {
-                                    message: format!("transcribe error: {e}"),
-                                });
-                                continue;
+                let result = transcribe_with_latency(&mut transcribe, &chunk);
+                meeting_counters.record_transcribe(result.is_ok());
+                if transcribe.name() != active_provider {
+                    active_provider = transcribe.name();
+                    send_status(
+                        mode_for_provider(&transcribe_profiles_runtime, active_provider)
+                            .to_string(),
+                        active_provider.to_string(),
+                        true,
+                    );
+                }
+                let mode = mode_for_provider(&transcribe_profiles_runtime, active_provider);
+                let (mut segments, elapsed) = match result {
+                    Ok(result) => result,
+                    Err(e) => {
+                        let offline = matches!(e, koe_core::TranscribeError::Network(_));
+                        if offline && connected {
+                            connected = false;
+                            send_status(mode.to_string(), active_provider.to_string(), false);
+                        }
+                        let message = match (chunk_queue.as_mut(), queued.as_ref()) {
+                            (Some(queue), Some(_)) if offline => {
+                                queue.record_failure(Instant::now());
+                                None
+                            }
+                            (Some(queue), Some(retry)) => Some(match queue.remove(retry) {
+                                Ok(()) => format!("queued chunk dropped: {e}"),
+                                Err(err) => format!("queued chunk dropped: {e}; {err}"),
+                            }),
+                            (Some(queue), None) if offline => {
+                                Some(match queue.push(&chunk, Instant::now()) {
+                                    Ok(()) => format!(
+                                        "transcribe offline; chunk queued for retry ({} pending)",
+                                        queue.len()
+                                    ),
+                                    Err(err) => format!("transcribe error: {e}; {err}"),
+                                })
                             }
+                            _ => Some(format!("transcribe error: {e}")),
                         };
-
-                    let smoothed = match latency_ms {
-                        Some(prev) => (prev * 9 + elapsed) / 10,
-                        None => elapsed,
-                    };
-                    latency_ms = Some(smoothed);
-                    let _ = ui_tx_transcribe.send(UiEvent::TranscribeLag { last_ms: smoothed });
-
-                    if segments.is_empty() {
+                        if let Some(message) = message {
+                            let _ = ui_tx_transcribe.send(UiEvent::Error { message });
+                        }
                         continue;
                     }
+                };
+                if !connected {
+                    connected = true;
+                    send_status(mode.to_string(), active_provider.to_string(), true);
+                }
+                if let Some(queue) = chunk_queue.as_mut() {
+                    queue.record_success(Instant::now());
+                    if let Some(retry) = &queued
+                        && let Err(e) = queue.remove(retry)
+                    {
+                        let _ = ui_tx_transcribe.send(UiEvent::Error {
+                            message: format!("offline queue cleanup failed: {e}"),
+                        });
+                    }
+                }
 
-                    if let Some(speaker) = default_speaker(chunk.source) {
-                        for seg in &mut segments {
-                            if seg.speaker.is_none() {
-                                seg.speaker = Some(speaker.to_string());
-                            }
+                let smoothed = match latency_ms {
+                    Some(prev) => (prev * 9 + elapsed) / 10,
+                    None => elapsed,
+                };
+                latency_ms = Some(smoothed);
+                let _ = ui_tx_transcribe.send(UiEvent::TranscribeLag { last_ms: smoothed });
+
+                if segments.is_empty() {
+                    continue;
+                }
+
+                if let Some(speaker) = default_speaker(chunk.source) {
+                    for seg in &mut segments {
+                        if seg.speaker.is_none() {
+                            seg.speaker = Some(speaker.to_string());
                         }
                     }
+                }
+                for seg in &mut segments {
+                    transcribe_vocabulary.apply(seg);
+                    if let Some(redactor) = &transcribe_redactor {
+                        redactor.apply(seg);
+                    }
+                }
 
-                    let _ = summarize_tx_transcribe.try_send(segments.clone());
+                if let Some(writer) = transcript_writer.as_mut()
+                    && let Err(e) = writer.append(&segments, Instant::now())
+                {
+                    let _ = ui_tx_transcribe.send(UiEvent::Error {
+                        message: format!("session transcript write failed: {e}"),
+                    });
+                }
 
-                    if ui_tx_transcribe
-                        .send(UiEvent::Transcript(segments))
-                        .is_err()
-                    {
-                        break;
-                    }
+                let _ = summarize_tx_transcribe.try_send(segments.clone());
+                if let Some(tx) = &translate_tx {
+                    let _ = tx.send(segments.clone());
                 }
-            }) {
-            Ok(handle) => Some(handle),
-            Err(e) => {
-                eprintln!("transcribe thread spawn failed: {e}");
-                std::process::exit(1);
+
+                if ui_tx_transcribe
+                    .send(UiEvent::Transcript(segments))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+            if let Some(writer) = transcript_writer.as_mut() {
+                let _ = writer.sync(Instant::now());
             }
+        },
+    ) {
+        Ok(handle) => Some(handle),
+        Err(e) => {
+            eprintln!("transcribe thread spawn failed: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    if config.limits.max_rss_mb > 0 {
+        let input = monitor::MonitorInput {
+            max_rss_mb: config.limits.max_rss_mb,
+            degraded_model: whisper_model_path(&config.limits.degraded_model, &models_dir),
+            ui_tx: ui_tx.clone(),
+            transcribe_cmd_tx: transcribe_cmd_tx.clone(),
+            summarize_cmd_tx: summarize_cmd_tx.clone(),
         };
+        if let Err(e) = monitor::spawn_monitor(input) {
+            eprintln!("monitor thread spawn failed: {e}");
+        }
+    }
+
+    if config.ui.check_updates {
+        update::spawn_check(ui_tx.clone());
+    }
+    if config.detect.enabled {
+        detect::spawn_watcher(config.detect.clone(), ui_tx.clone());
+    }
 
-    let export_dir = export_dir_from_config(&paths, &config.session.export_dir);
+    let (session_paths, export_dir) = session_storage(&paths, &config, run.workspace.as_deref());
+    if let Err(e) = std::fs::create_dir_all(&session_paths.sessions_dir) {
+        eprintln!("sessions dir create failed: {e}");
+        std::process::exit(1);
+    }
+    if config.sync.is_enabled() {
+        sync::spawn_sync(
+            config.sync.clone(),
+            session_paths.sessions_dir.clone(),
+            ui_tx.clone(),
+        );
+    }
     let session_factory = SessionFactory::new(
-        paths.clone(),
+        session_paths,
         export_dir,
         config.audio.sample_rate,
         config.audio.channels,
         config.audio.sources.clone(),
+        config.session.low_confidence_threshold,
     );
+    let hooks_dir = hooks::any_installed(&paths.hooks_dir).then(|| paths.hooks_dir.clone());
+    let event_outputs = match outputs::EventOutputs::from_config(&config.outputs) {
+        Ok(outputs) => outputs,
+        Err(e) => {
+            eprintln!("event outputs disabled: {e}");
+            None
+        }
+    };
+    let notion = config
+        .integrations
+        .notion
+        .is_enabled()
+        .then(|| config.integrations.notion.clone());
+    let (webhooks, webhook_handle) = if config.webhooks.is_empty()
+        && hooks_dir.is_none()
+        && event_outputs.is_none()
+        && notion.is_none()
+    {
+        (webhook::WebhookSender::disabled(), None)
+    } else {
+        match webhook::spawn_webhooks(
+            config.webhooks.clone(),
+            hooks_dir,
+            event_outputs,
+            notion,
+            ui_tx.clone(),
+        ) {
+            Ok((sender, handle)) => (sender, Some(handle)),
+            Err(e) => {
+                eprintln!("webhook thread spawn failed: {e}");
+                (webhook::WebhookSender::disabled(), None)
+            }
+        }
+    };
+    let live = if config.http.enabled {
+        match http::spawn_http_server(
+            config.http.port,
+            &paths.http_token_path,
+            ui_tx.clone(),
+            summarize_cmd_tx.clone(),
+        ) {
+            Ok(live) => live,
+            Err(e) => {
+                eprintln!("http server on 127.0.0.1:{} failed: {e}", config.http.port);
+                http::LiveFeed::disabled()
+            }
+        }
+    } else {
+        http::LiveFeed::disabled()
+    };
     let ctx = tui::TuiContext {
         processor,
         ui_rx,
+        ui_tx: ui_tx.clone(),
         stats: stats_display,
+        usage,
+        health_check: config.audio.health_check,
+        calibrate: config.process.calibrate,
+        capture_sources,
         transcribe_cmd_tx,
         summarize_cmd_tx,
         ui_config: config.ui.clone(),
+        config_paths: paths.clone(),
         audio_sample_rate_hz: config.audio.sample_rate,
         audio_mixdown: config.audio.mixdown.clone(),
+        audio_multitrack: config.audio.multitrack,
+        audio_archive_format: ArchiveFormat::parse(&config.audio.archive_format)
+            .unwrap_or(ArchiveFormat::Wav),
         session_factory,
         shared_writer,
         initial_context: run.context.clone().unwrap_or_default(),
         participants: run.participants.clone(),
         transcribe_profiles: transcribe_profiles_ui,
         summarize_profiles: summarize_profiles_ui,
+        max_rss_mb: config.limits.max_rss_mb,
+        workspace: run.workspace.clone(),
+        meeting_profiles: config
+            .profiles
+            .iter()
+            .map(|(name, profile)| tui::MeetingProfile {
+                name: name.clone(),
+                context: non_empty_str(&profile.context).map(str::to_string),
+                participants: profile.participants.clone(),
+                prompt_profile: non_empty_str(&profile.prompt_profile).map(str::to_string),
+            })
+            .collect(),
+        active_profile: run.profile.clone(),
+        vocabulary: learned_vocabulary,
+        vocabulary_path: paths.vocabulary_path.clone(),
+        actions_path: paths.actions_path.clone(),
+        actions_sessions_dirs: actions::sessions_dirs(&paths, &config),
+        prompt_profiles: prompts::profile_names(&paths.prompts_dir),
+        prompt_profile: run.prompt_profile.clone(),
+        // Captions mode has no room for the tour; keep it for the next full launch.
+        show_tour: !captions && first_tui_launch(&paths),
+        captions,
+        auto_start: run.auto_start,
+        pause_key: tui::KeyBinding::parse(&config.ui.pause_key),
+        hold_to_pause_key: tui::KeyBinding::parse(&config.ui.hold_to_pause_key),
+        autosave_export: (config.session.autosave_export_minutes > 0)
+            .then(|| Duration::from_secs(u64::from(config.session.autosave_export_minutes) * 60)),
+        calendar: config.calendar.clone(),
+        detect_auto_start: config.detect.action == "start",
+        webhooks,
+        live,
+        review: config.review.clone(),
+        slack: config.integrations.slack.clone(),
+        email: config.integrations.email.clone(),
+        tag_prompt: config.session.tag_prompt,
+        summarize_interval_secs: config.summarize.interval_secs,
     };
 
     if let Err(e) = tui::run(ctx) {
@@ -687,6 +1671,43 @@ fn main() {
     if let Some(handle) = raw_writer_handle {
         let _ = handle.join();
     }
+    if let Some(handle) = webhook_handle {
+        let _ = handle.join();
+    }
+}
+
+/// Build the provider for the inactive transcribe profile, used as the failover target.
+fn create_fallback_transcribe(
+    profiles: &RuntimeProfiles,
+    models_dir: &std::path::Path,
+) -> Result<Box<dyn TranscribeProvider>, String> {
+    let mode = if profiles.active == "cloud" {
+        "local"
+    } else {
+        "cloud"
+    };
+    let mut profile = profiles.profile_for_mode(mode).clone();
+    if profile.provider == "whisper" {
+        ensure_whisper_model(&mut profile.model, models_dir)?;
+    }
+    create_transcribe_provider(
+        profile.provider.as_str(),
+        Some(profile.model.as_str()),
+        non_empty_str(profile.api_key.as_str()),
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Mode whose profile uses `provider`, preferring the configured active mode.
+fn mode_for_provider<'a>(profiles: &'a RuntimeProfiles, provider: &str) -> &'a str {
+    if profiles.active_profile().provider == provider {
+        return profiles.active.as_str();
+    }
+    if profiles.cloud.provider == provider {
+        "cloud"
+    } else {
+        "local"
+    }
 }
 
 fn default_speaker(source: AudioSource) -> Option<&'static str> {
@@ -719,6 +1740,74 @@ fn ensure_whisper_model(model: &mut String, models_dir: &std::path::Path) -> Res
     Ok(())
 }
 
+/// Resolve a whisper model name or path to a local file, if it is installed.
+fn whisper_model_path(model: &str, models_dir: &std::path::Path) -> Option<PathBuf> {
+    let model = match model.trim() {
+        "" => init::DEFAULT_WHISPER_MODEL,
+        model => model,
+    };
+    let path = if looks_like_path(model) {
+        PathBuf::from(model)
+    } else {
+        models_dir.join(init::model_filename(model))
+    };
+    path.exists().then_some(path)
+}
+
+/// Ask how to start when the local whisper model is missing: download it
+/// (handled by `ensure_whisper_model`), switch to cloud, or record only.
+fn resolve_missing_model(profiles: &mut RuntimeProfiles) {
+    let model = profiles.active_profile().model.trim().to_string();
+    let model = if model.is_empty() {
+        init::DEFAULT_WHISPER_MODEL.to_string()
+    } else {
+        model
+    };
+    let cloud_ready = profiles.active != "cloud" && !profiles.cloud.api_key.trim().is_empty();
+    match init::prompt_missing_model(&model, cloud_ready) {
+        Ok(init::MissingModelAction::Download) => {}
+        Ok(init::MissingModelAction::SwitchToCloud) => profiles.active = "cloud".to_string(),
+        Ok(init::MissingModelAction::RecordOnly) => {
+            profiles.active_profile_mut().provider = "none".to_string();
+        }
+        Err(e) => {
+            eprintln!("init failed: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// True the first time the TUI starts; leaves a marker so the tour is offered once.
+fn first_tui_launch(paths: &ConfigPaths) -> bool {
+    let marker = paths.base_dir.join(".tour-shown");
+    if marker.exists() {
+        return false;
+    }
+    let _ = std::fs::write(&marker, "");
+    true
+}
+
+/// Session paths and export dir, scoped to `workspace` when one is selected.
+fn session_storage(
+    paths: &ConfigPaths,
+    config: &Config,
+    workspace: Option<&str>,
+) -> (ConfigPaths, Option<ExportDir>) {
+    let export_dir = |root: PathBuf| ExportDir {
+        root,
+        layout: ExportLayout::parse(&config.session.export_layout).unwrap_or(ExportLayout::Session),
+        flatten: config.session.export_flatten,
+    };
+    let global_export = export_dir_from_config(paths, &config.session.export_dir);
+    let Some((name, workspace)) = workspace.and_then(|name| config.workspaces.get_key_value(name))
+    else {
+        return (paths.clone(), global_export.map(export_dir));
+    };
+    let root = export_dir_from_config(paths, &workspace.export_dir)
+        .or_else(|| global_export.map(|dir| dir.join(name)));
+    (paths.for_workspace(name, workspace), root.map(export_dir))
+}
+
 fn export_dir_from_config(paths: &ConfigPaths, value: &str) -> Option<PathBuf> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
@@ -759,24 +1848,45 @@ fn resolve_microphone_device_id(capture_microphone: bool, mic_device_id: &str) -
     select_default_microphone(&inputs)
 }
 
-fn select_default_microphone(inputs: &[koe_core::capture::AudioInputDeviceInfo]) -> Option<String> {
-    if inputs.is_empty() {
-        return None;
+/// Next offline-queued chunk due for a retry; a queue that cannot be read is turned off.
+fn next_queued_chunk(
+    chunk_queue: &mut Option<ChunkQueue>,
+    ui_tx: &mpsc::Sender<UiEvent>,
+) -> Option<QueuedChunk> {
+    match chunk_queue.as_ref()?.next_due(Instant::now()) {
+        Ok(retry) => retry,
+        Err(e) => {
+            let _ = ui_tx.send(UiEvent::Error {
+                message: format!("offline queue disabled: {e}"),
+            });
+            *chunk_queue = None;
+            None
+        }
     }
-    let built_in = inputs.iter().find(|device| {
-        let name = device.name.to_lowercase();
-        device.id == "BuiltInMicrophoneDevice"
-            || name.contains("built-in")
-            || name.contains("built in")
-            || name.contains("macbook")
-    });
-    if let Some(device) = built_in {
-        return Some(device.id.clone());
-    }
-    inputs
-        .iter()
-        .find(|device| device.is_default)
-        .map(|device| device.id.clone())
+}
+
+/// Warm `transcribe` up, reporting it as not ready until the first inference is paid for.
+fn warm_up_transcribe(
+    transcribe: &mut dyn TranscribeProvider,
+    mode: &str,
+    provider: &str,
+    ui_tx: &mpsc::Sender<UiEvent>,
+) {
+    let send_status = |ready: bool| {
+        let _ = ui_tx.send(UiEvent::TranscribeStatus {
+            mode: mode.to_string(),
+            provider: provider.to_string(),
+            connected: true,
+            ready,
+        });
+    };
+    send_status(false);
+    if let Err(e) = transcribe.warm_up() {
+        let _ = ui_tx.send(UiEvent::Error {
+            message: format!("transcribe warm-up failed: {e}"),
+        });
+    }
+    send_status(true);
 }
 
 fn transcribe_with_latency(
@@ -800,109 +1910,111 @@ fn create_summarize_for_mode(
     )
 }
 
-fn apply_notes_patch_state(notes: &mut MeetingNotes, patch: NotesPatch) -> bool {
-    let mut changed = false;
-    let mut existing_ids: HashSet<String> = notes
-        .bullets
-        .iter()
-        .map(|bullet| bullet.id.clone())
-        .collect();
-    let mut existing_normalized: HashSet<String> = notes
-        .bullets
-        .iter()
-        .map(|bullet| normalize_text(&bullet.text))
-        .collect();
-
-    for op in patch.ops {
-        match op {
-            NotesOp::Add { id, text, evidence } => {
-                let cleaned_text = strip_note_prefixes(&text);
-                let normalized_text = normalize_text(&cleaned_text);
-                if normalized_text.is_empty()
-                    || existing_ids.contains(&id)
-                    || existing_normalized.contains(&normalized_text)
-                    || is_near_duplicate(&normalized_text, &existing_normalized)
-                    || !has_min_content_words(&normalized_text)
-                {
-                    continue;
-                }
-                notes.bullets.push(NoteBullet {
-                    id: id.clone(),
-                    text: cleaned_text,
-                    evidence,
-                });
-                existing_ids.insert(id);
-                existing_normalized.insert(normalized_text);
-                changed = true;
-            }
-        }
+/// Name an untitled session from its transcript, moving its exports to the
+/// new title's location.
+fn auto_title(
+    provider: &mut dyn koe_core::summarize::SummarizeProvider,
+    session: &mut SessionHandle,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> Result<Option<String>, String> {
+    // The TUI may have titled the meeting after handing this copy over.
+    session
+        .reload_labels()
+        .map_err(|e| format!("title load: {e}"))?;
+    if session.metadata().title.is_some()
+        || segments
+            .iter()
+            .all(|segment| segment.text.trim().is_empty())
+    {
+        return Ok(None);
     }
-
-    changed
+    let title = generate_title(provider, segments, context, participants)
+        .map_err(|e| format!("title: {e}"))?;
+    if title.is_empty() {
+        return Ok(None);
+    }
+    session
+        .update_title(&title)
+        .map_err(|e| format!("title save: {e}"))?;
+    Ok(Some(title))
 }
 
-fn is_near_duplicate(candidate: &str, existing: &HashSet<String>) -> bool {
-    let candidate_tokens = content_tokens(candidate);
-    if candidate_tokens.is_empty() {
-        return true;
+/// Run the end-of-meeting pass selected by `summarize.prompt_profile` over the full transcript,
+/// after the chapter outline when `summarize.chapters` is on.
+fn run_finalize_passes(
+    provider: &mut dyn koe_core::summarize::SummarizeProvider,
+    config: &SummarizeConfig,
+    session: &SessionHandle,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> Result<(), String> {
+    if segments
+        .iter()
+        .all(|segment| segment.text.trim().is_empty())
+    {
+        return Ok(());
+    }
+    if config.chapters {
+        let chapters = generate_chapters(provider, segments, context, participants)
+            .map_err(|e| format!("chapters: {e}"))?;
+        session
+            .write_chapters(&chapters)
+            .and_then(|_| session.export_transcript_markdown(segments))
+            .map_err(|e| format!("chapters export: {e}"))?;
     }
-    let candidate_set: HashSet<&str> = candidate_tokens.iter().map(|t| t.as_str()).collect();
-    for existing_text in existing {
-        let existing_tokens = content_tokens(existing_text);
-        if existing_tokens.is_empty() {
-            continue;
+    match config.prompt_profile.as_str() {
+        "podcast" => {
+            let notes = generate_show_notes(provider, segments, context, participants)
+                .map_err(|e| format!("show notes: {e}"))?;
+            session
+                .export_show_notes(&notes)
+                .map_err(|e| format!("show notes export: {e}"))?;
         }
-        let existing_set: HashSet<&str> = existing_tokens.iter().map(|t| t.as_str()).collect();
-        let overlap = candidate_set.intersection(&existing_set).count();
-        let union = candidate_set.len() + existing_set.len() - overlap;
-        if union == 0 {
-            continue;
+        "lecture" => {
+            let notes = generate_lecture_notes(
+                provider,
+                segments,
+                context,
+                participants,
+                config.flashcards,
+            )
+            .map_err(|e| format!("lecture notes: {e}"))?;
+            session
+                .export_lecture_notes(&notes)
+                .map_err(|e| format!("lecture notes export: {e}"))?;
         }
-        let jaccard = overlap as f32 / union as f32;
-        if jaccard >= 0.75 || overlap >= 5 {
-            return true;
+        _ => {
+            let summary = generate_summary(provider, segments, context, participants)
+                .map_err(|e| format!("summary: {e}"))?;
+            session
+                .export_summary(&summary)
+                .map_err(|e| format!("summary export: {e}"))?;
         }
     }
-    false
-}
-
-fn has_min_content_words(normalized: &str) -> bool {
-    content_tokens(normalized).len() >= 5
-}
-
-fn content_tokens(normalized: &str) -> Vec<String> {
-    normalized
-        .split_whitespace()
-        .filter(|token| !is_stopword(token))
-        .map(|token| token.to_string())
-        .collect()
-}
-
-fn is_stopword(token: &str) -> bool {
-    const STOPWORDS: [&str; 28] = [
-        "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "has", "have", "if",
-        "in", "is", "it", "its", "of", "on", "or", "that", "the", "to", "was", "were", "with",
-        "will",
-    ];
-
-    STOPWORDS.contains(&token)
+    Ok(())
 }
 
-fn strip_note_prefixes(text: &str) -> String {
-    let trimmed = text.trim_start();
-    if let Some(rest) = trimmed.strip_prefix("Me:") {
-        return rest.trim_start().to_string();
-    }
-    if let Some(rest) = trimmed.strip_prefix("Them:") {
-        return rest.trim_start().to_string();
-    }
-    trimmed.to_string()
+/// Whether a live notes update is due. Both schedules wait out `interval`;
+/// `adaptive` then also waits for enough new segments or a pause, so silence
+/// costs no provider calls and a burst of talk is picked up at the next turn.
+fn summarize_due(
+    adaptive: bool,
+    since_update: Duration,
+    interval: Duration,
+    enough_segments: bool,
+    paused: bool,
+) -> bool {
+    since_update >= interval && (!adaptive || enough_segments || paused)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{default_speaker, select_default_microphone, transcribe_with_latency};
-    use koe_core::capture::AudioInputDeviceInfo;
+    use super::{RunArgs, default_speaker, summarize_due, transcribe_with_latency};
+    use crate::config::{Config, MeetingProfileConfig};
+    use clap::Parser;
     use koe_core::transcribe::TranscribeProvider;
     use koe_core::types::{AudioChunk, AudioSource, TranscriptSegment};
     use std::time::Duration;
@@ -935,10 +2047,28 @@ mod tests {
                 speaker: None,
                 text: "ok".to_string(),
                 finalized: false,
+                confidence: None,
             }])
         }
     }
 
+    #[test]
+    fn adaptive_schedule_waits_for_segments_or_a_pause() {
+        let interval = Duration::from_secs(4);
+        let later = Duration::from_secs(10);
+        assert!(summarize_due(false, later, interval, false, false));
+        assert!(!summarize_due(true, later, interval, false, false));
+        assert!(summarize_due(true, later, interval, true, false));
+        assert!(summarize_due(true, later, interval, false, true));
+        assert!(!summarize_due(
+            true,
+            Duration::from_secs(1),
+            interval,
+            true,
+            true
+        ));
+    }
+
     #[test]
     fn transcribe_latency_under_budget() {
         let mut transcribe = DummyTranscribe {
@@ -955,41 +2085,50 @@ mod tests {
     }
 
     #[test]
-    fn select_default_microphone_prefers_built_in() {
-        let inputs = vec![
-            AudioInputDeviceInfo {
-                id: "BT-MIC".to_string(),
-                name: "WH-1000XM5".to_string(),
-                is_default: true,
-            },
-            AudioInputDeviceInfo {
-                id: "BuiltInMicrophoneDevice".to_string(),
-                name: "MacBook Pro Microphone".to_string(),
-                is_default: false,
+    fn profile_fills_defaults_and_cli_flags_win() {
+        let mut config = Config::default();
+        config.session.context = "default".to_string();
+        config.profiles.insert(
+            "standup".to_string(),
+            MeetingProfileConfig {
+                context: "daily standup".to_string(),
+                participants: vec!["Ana".to_string()],
+                prompt_profile: "lecture".to_string(),
+                summarize: "cloud".to_string(),
+                ..MeetingProfileConfig::default()
             },
-        ];
-        assert_eq!(
-            select_default_microphone(&inputs).as_deref(),
-            Some("BuiltInMicrophoneDevice")
         );
-    }
 
-    #[test]
-    fn select_default_microphone_falls_back_to_default() {
-        let inputs = vec![AudioInputDeviceInfo {
-            id: "USB-MIC".to_string(),
-            name: "USB Microphone".to_string(),
-            is_default: true,
-        }];
-        assert_eq!(
-            select_default_microphone(&inputs).as_deref(),
-            Some("USB-MIC")
+        let run = RunArgs::parse_from(["koe", "--profile", "standup"])
+            .resolve(&config)
+            .unwrap();
+        assert_eq!(run.context.as_deref(), Some("daily standup"));
+        assert_eq!(run.participants, vec!["Ana".to_string()]);
+        assert_eq!(run.prompt_profile, "lecture");
+        assert_eq!(run.summarize_profiles.active, "cloud");
+        assert!(!run.auto_start);
+
+        let run = RunArgs::parse_from(["koe", "--profile", "standup", "--context", "retro"])
+            .resolve(&config)
+            .unwrap();
+        assert_eq!(run.context.as_deref(), Some("retro"));
+
+        let run = RunArgs::parse_from(["koe", "--start"])
+            .resolve(&config)
+            .unwrap();
+        assert!(run.auto_start);
+        config.session.auto_start = true;
+        assert!(
+            RunArgs::parse_from(["koe"])
+                .resolve(&config)
+                .unwrap()
+                .auto_start
         );
-    }
 
-    #[test]
-    fn select_default_microphone_handles_empty_list() {
-        let inputs = Vec::new();
-        assert_eq!(select_default_microphone(&inputs), None);
+        assert!(
+            RunArgs::parse_from(["koe", "--profile", "missing"])
+                .resolve(&config)
+                .is_err()
+        );
     }
 }
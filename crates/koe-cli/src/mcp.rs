@@ -0,0 +1,423 @@
+use crate::config::{Config, ConfigPaths};
+use crate::session::{SessionError, StoredSession, list_sessions};
+use clap::Args;
+use koe_core::types::{MeetingNotes, TranscriptSegment};
+use serde_json::{Value, json};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use thiserror::Error;
+
+const PROTOCOL_VERSION: &str = "2024-11-05";
+const SESSIONS_URI: &str = "koe://sessions";
+const LIVE_TRANSCRIPT_URI: &str = "koe://live/transcript";
+const LIVE_NOTES_URI: &str = "koe://live/notes";
+const SESSION_URI_PREFIX: &str = "koe://sessions/";
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+#[derive(Args, Debug, Clone)]
+pub struct ServeArgs {
+    /// Serve Model Context Protocol on stdin/stdout
+    #[arg(long)]
+    pub mcp: bool,
+
+    /// Workspace whose sessions are exposed
+    #[arg(long, value_name = "name")]
+    pub workspace: Option<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum McpError {
+    #[error("mcp io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("mcp json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{0}")]
+    Message(String),
+}
+
+pub fn run(args: &ServeArgs, paths: &ConfigPaths, config: &Config) -> Result<(), McpError> {
+    if !args.mcp {
+        return Err(McpError::Message("choose a server mode: --mcp".into()));
+    }
+    if let Some(name) = args.workspace.as_deref()
+        && !config.workspaces.contains_key(name)
+    {
+        return Err(McpError::Message(format!("unknown workspace: {name}")));
+    }
+    let (session_paths, _) = crate::session_storage(paths, config, args.workspace.as_deref());
+    serve_stdio(&session_paths.sessions_dir)
+}
+
+/// Serve Model Context Protocol over stdio (newline-delimited JSON-RPC).
+///
+/// State is read from session files on each request, so a meeting running in
+/// the TUI shows up live through its rolling checkpoints.
+pub fn serve_stdio(sessions_dir: &Path) -> Result<(), McpError> {
+    let stdin = io::stdin();
+    let mut stdout = io::stdout().lock();
+    for line in stdin.lock().lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Value>(&line) {
+            Ok(request) => handle_request(sessions_dir, &request),
+            Err(e) => Some(error_response(Value::Null, PARSE_ERROR, &e.to_string())),
+        };
+        if let Some(response) = response {
+            serde_json::to_writer(&mut stdout, &response)?;
+            stdout.write_all(b"\n")?;
+            stdout.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Response for one JSON-RPC message; notifications get none.
+fn handle_request(sessions_dir: &Path, request: &Value) -> Option<Value> {
+    let id = request.get("id")?.clone();
+    let method = request.get("method").and_then(Value::as_str).unwrap_or("");
+    let params = request.get("params").cloned().unwrap_or(Value::Null);
+    let result = match method {
+        "initialize" => Ok(json!({
+            "protocolVersion": PROTOCOL_VERSION,
+            "capabilities": { "resources": {}, "tools": {} },
+            "serverInfo": { "name": "koe", "version": env!("CARGO_PKG_VERSION") },
+        })),
+        "ping" => Ok(json!({})),
+        "resources/list" => Ok(json!({ "resources": resource_list() })),
+        "resources/templates/list" => Ok(json!({ "resourceTemplates": resource_templates() })),
+        "resources/read" => read_resource(sessions_dir, &params),
+        "tools/list" => Ok(json!({ "tools": tool_list() })),
+        "tools/call" => call_tool(sessions_dir, &params),
+        other => Err((METHOD_NOT_FOUND, format!("unknown method: {other}"))),
+    };
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err((code, message)) => error_response(id, code, &message),
+    })
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+fn resource_list() -> Value {
+    json!([
+        {
+            "uri": SESSIONS_URI,
+            "name": "sessions",
+            "description": "Recorded sessions, newest first",
+            "mimeType": "application/json",
+        },
+        {
+            "uri": LIVE_TRANSCRIPT_URI,
+            "name": "live transcript",
+            "description": "Transcript of the running (or latest) meeting",
+            "mimeType": "text/plain",
+        },
+        {
+            "uri": LIVE_NOTES_URI,
+            "name": "live notes",
+            "description": "Notes of the running (or latest) meeting",
+            "mimeType": "text/plain",
+        },
+    ])
+}
+
+fn resource_templates() -> Value {
+    json!([
+        {
+            "uriTemplate": "koe://sessions/{id}/transcript",
+            "name": "session transcript",
+            "mimeType": "text/plain",
+        },
+        {
+            "uriTemplate": "koe://sessions/{id}/notes",
+            "name": "session notes",
+            "mimeType": "text/plain",
+        },
+    ])
+}
+
+fn tool_list() -> Value {
+    let session_id = json!({
+        "type": "string",
+        "description": "Session id; defaults to the running (or latest) meeting",
+    });
+    json!([
+        {
+            "name": "list_sessions",
+            "description": "List recorded meetings with id, start time, context, tags, and status",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "tag": { "type": "string", "description": "Only meetings carrying this tag" },
+                },
+            },
+        },
+        {
+            "name": "get_transcript",
+            "description": "Transcript of a meeting, optionally only the last N segments",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "session_id": session_id,
+                    "last_n": { "type": "integer", "minimum": 1 },
+                },
+            },
+        },
+        {
+            "name": "get_notes",
+            "description": "Notes (decisions, action items) captured so far in a meeting",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "session_id": session_id },
+            },
+        },
+    ])
+}
+
+type RpcResult = Result<Value, (i64, String)>;
+
+fn read_resource(sessions_dir: &Path, params: &Value) -> RpcResult {
+    let uri = params
+        .get("uri")
+        .and_then(Value::as_str)
+        .ok_or((INVALID_PARAMS, "missing uri".to_string()))?;
+    let (mime, text) = match uri {
+        SESSIONS_URI => (
+            "application/json",
+            sessions_json(sessions_dir, None)?.to_string(),
+        ),
+        LIVE_TRANSCRIPT_URI => ("text/plain", transcript_text(sessions_dir, None, None)?),
+        LIVE_NOTES_URI => ("text/plain", notes_text(sessions_dir, None)?),
+        other => match other
+            .strip_prefix(SESSION_URI_PREFIX)
+            .and_then(|rest| rest.split_once('/'))
+        {
+            Some((id, "transcript")) => {
+                ("text/plain", transcript_text(sessions_dir, Some(id), None)?)
+            }
+            Some((id, "notes")) => ("text/plain", notes_text(sessions_dir, Some(id))?),
+            _ => return Err((INVALID_PARAMS, format!("unknown resource: {other}"))),
+        },
+    };
+    Ok(json!({ "contents": [{ "uri": uri, "mimeType": mime, "text": text }] }))
+}
+
+fn call_tool(sessions_dir: &Path, params: &Value) -> RpcResult {
+    let name = params
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or((INVALID_PARAMS, "missing tool name".to_string()))?;
+    let arguments = params.get("arguments").cloned().unwrap_or(Value::Null);
+    let session_id = arguments.get("session_id").and_then(Value::as_str);
+    let text = match name {
+        "list_sessions" => {
+            sessions_json(sessions_dir, arguments.get("tag").and_then(Value::as_str)).map(
+                |sessions| {
+                    serde_json::to_string_pretty(&sessions).unwrap_or_else(|_| sessions.to_string())
+                },
+            )
+        }
+        "get_transcript" => {
+            let last_n = arguments
+                .get("last_n")
+                .and_then(Value::as_u64)
+                .map(|n| n as usize);
+            transcript_text(sessions_dir, session_id, last_n)
+        }
+        "get_notes" => notes_text(sessions_dir, session_id),
+        other => return Err((INVALID_PARAMS, format!("unknown tool: {other}"))),
+    };
+    // Lookup failures are reported to the model as tool errors; storage
+    // failures stay protocol errors.
+    Ok(match text {
+        Ok(text) => json!({ "content": [{ "type": "text", "text": text }], "isError": false }),
+        Err(err @ (INTERNAL_ERROR, _)) => return Err(err),
+        Err((_, message)) => {
+            json!({ "content": [{ "type": "text", "text": message }], "isError": true })
+        }
+    })
+}
+
+fn sessions_json(sessions_dir: &Path, tag: Option<&str>) -> Result<Value, (i64, String)> {
+    let sessions = list_sessions(sessions_dir).map_err(internal)?;
+    Ok(Value::Array(
+        sessions
+            .iter()
+            .filter(|session| tag.is_none_or(|tag| session.metadata.has_tag(tag)))
+            .map(|session| {
+                let metadata = &session.metadata;
+                json!({
+                    "id": metadata.id,
+                    "name": metadata.display_name(),
+                    "title": metadata.title,
+                    "start_time": metadata.start_time,
+                    "end_time": metadata.end_time,
+                    "finalized": metadata.finalized,
+                    "context": metadata.context,
+                    "participants": metadata.participants,
+                    "tags": metadata.tags,
+                })
+            })
+            .collect(),
+    ))
+}
+
+/// The requested session, or the newest unfinalized one, falling back to the newest.
+fn find_session(sessions_dir: &Path, id: Option<&str>) -> Result<StoredSession, (i64, String)> {
+    let sessions = list_sessions(sessions_dir).map_err(internal)?;
+    let found = match id {
+        Some(id) => sessions
+            .into_iter()
+            .find(|session| session.metadata.id == id),
+        None => {
+            let live = sessions
+                .iter()
+                .position(|session| !session.metadata.finalized)
+                .unwrap_or(0);
+            sessions.into_iter().nth(live)
+        }
+    };
+    found.ok_or_else(|| {
+        (
+            INVALID_PARAMS,
+            match id {
+                Some(id) => format!("session not found: {id}"),
+                None => "no sessions recorded yet".to_string(),
+            },
+        )
+    })
+}
+
+fn transcript_text(
+    sessions_dir: &Path,
+    id: Option<&str>,
+    last_n: Option<usize>,
+) -> Result<String, (i64, String)> {
+    let session = find_session(sessions_dir, id)?;
+    let segments = session.transcript().map_err(internal)?;
+    let skip = last_n.map_or(0, |n| segments.len().saturating_sub(n));
+    Ok(render_transcript(&segments[skip..]))
+}
+
+fn notes_text(sessions_dir: &Path, id: Option<&str>) -> Result<String, (i64, String)> {
+    let session = find_session(sessions_dir, id)?;
+    let notes = session.notes().map_err(internal)?;
+    Ok(render_notes(&notes))
+}
+
+fn render_transcript(segments: &[TranscriptSegment]) -> String {
+    segments
+        .iter()
+        .map(|segment| {
+            let secs = segment.start_ms.max(0) / 1000;
+            let speaker = segment.speaker.as_deref().unwrap_or("Unknown");
+            format!(
+                "[{:02}:{:02}] {speaker}: {}\n",
+                secs / 60,
+                secs % 60,
+                segment.text.trim()
+            )
+        })
+        .collect()
+}
+
+fn render_notes(notes: &MeetingNotes) -> String {
+    notes
+        .bullets
+        .iter()
+        .map(|bullet| {
+            let mark = if bullet.done { "[x] " } else { "" };
+            format!("- {mark}{}\n", bullet.text)
+        })
+        .collect()
+}
+
+/// Storage failures are the server's fault, not the caller's.
+fn internal(err: SessionError) -> (i64, String) {
+    (INTERNAL_ERROR, err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle_request;
+    use crate::config::ConfigPaths;
+    use crate::session::{SessionHandle, SessionMetadata, SessionMetadataInput};
+    use crate::test_support::{bullet, session_input};
+    use koe_core::types::{MeetingNotes, NoteBullet, NoteKind, TranscriptSegment};
+    use serde_json::json;
+
+    #[test]
+    fn tools_read_live_session_from_disk() {
+        let temp = tempfile::tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().join("koe"));
+        let metadata = SessionMetadata::new(SessionMetadataInput {
+            context: Some("planning".to_string()),
+            ..session_input()
+        })
+        .unwrap();
+        let mut session = SessionHandle::start(&paths, metadata, None).unwrap();
+        let segment = |text: &str, finalized| TranscriptSegment {
+            id: 1,
+            start_ms: 61_000,
+            end_ms: 63_000,
+            speaker: Some("Them".to_string()),
+            text: text.to_string(),
+            finalized,
+            confidence: None,
+        };
+        session
+            .append_transcript(&[segment("deadline is friday", false)])
+            .unwrap();
+        session
+            .append_transcript(&[segment("The deadline is Friday.", true)])
+            .unwrap();
+        session
+            .write_notes(&MeetingNotes {
+                bullets: vec![NoteBullet {
+                    evidence: vec![1],
+                    ..bullet("n1", "Ship on Friday", NoteKind::Point)
+                }],
+            })
+            .unwrap();
+
+        let call = |name: &str| {
+            handle_request(
+                &paths.sessions_dir,
+                &json!({"jsonrpc": "2.0", "id": 1, "method": "tools/call",
+                    "params": {"name": name, "arguments": {}}}),
+            )
+            .unwrap()
+        };
+        let transcript = call("get_transcript");
+        assert_eq!(
+            transcript["result"]["content"][0]["text"],
+            "[01:01] Them: The deadline is Friday.\n"
+        );
+        let notes = call("get_notes");
+        assert_eq!(notes["result"]["content"][0]["text"], "- Ship on Friday\n");
+
+        let notification = json!({"jsonrpc": "2.0", "method": "notifications/initialized"});
+        assert!(handle_request(&paths.sessions_dir, &notification).is_none());
+        let unknown = handle_request(
+            &paths.sessions_dir,
+            &json!({"jsonrpc": "2.0", "id": 2, "method": "nope"}),
+        )
+        .unwrap();
+        assert_eq!(unknown["error"]["code"], -32601);
+
+        std::fs::write(
+            session.session_dir().join(&session.metadata().notes_file),
+            "not json",
+        )
+        .unwrap();
+        assert_eq!(call("get_notes")["error"]["code"], -32603);
+    }
+}
@@ -0,0 +1,370 @@
+use crate::config::{Config, ConfigPaths};
+use crate::http::{Request, read_request};
+use clap::Args;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::io::{self, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use thiserror::Error;
+
+const DEFAULT_PORT: u16 = 4010;
+/// Transcription uploads carry a whole WAV chunk.
+const MAX_BODY_BYTES: usize = 32 * 1024 * 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+const MOCK_API_KEY: &str = "mock";
+const GROQ_PATH: &str = "/openai/v1";
+const OPENROUTER_PATH: &str = "/api/v1";
+const DEFAULT_TRANSCRIPT: &str = "This is a mock transcript.";
+/// Bytes per second of 16 kHz 16-bit mono WAV, to size the canned segment.
+const WAV_BYTES_PER_SECOND: f64 = 32_000.0;
+
+#[derive(Args, Debug, Clone)]
+pub struct MockProvidersArgs {
+    /// Port to listen on (127.0.0.1 only)
+    #[arg(long, default_value_t = DEFAULT_PORT)]
+    pub port: u16,
+    /// JSON file of replies per provider, served in order before the canned defaults
+    #[arg(long, value_name = "path")]
+    pub script: Option<PathBuf>,
+    /// Print shell exports pointing koe at the mock and exit
+    #[arg(long)]
+    pub print_env: bool,
+}
+
+#[derive(Debug, Error)]
+pub enum MockError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("script error: {0}")]
+    Script(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Provider {
+    Groq,
+    Openrouter,
+    Ollama,
+}
+
+/// One scripted reply. Non-200 statuses answer with an error body, so retry
+/// and failover paths can be exercised.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+struct Reply {
+    status: u16,
+    /// Transcript text for groq, model output for openrouter and ollama.
+    content: Option<String>,
+    /// Wait before answering, to simulate slow providers and timeouts.
+    delay_ms: u64,
+}
+
+impl Default for Reply {
+    fn default() -> Self {
+        Self {
+            status: 200,
+            content: None,
+            delay_ms: 0,
+        }
+    }
+}
+
+/// Queued replies per provider, falling back to canned defaults once drained.
+#[derive(Default)]
+struct Script {
+    queues: Mutex<HashMap<Provider, VecDeque<Reply>>>,
+    served: AtomicU64,
+}
+
+impl Script {
+    fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            queues: Mutex::new(serde_json::from_str(json)?),
+            served: AtomicU64::new(0),
+        })
+    }
+
+    fn next(&self, provider: Provider) -> Reply {
+        self.served.fetch_add(1, Ordering::Relaxed);
+        self.queues
+            .lock()
+            .ok()
+            .and_then(|mut queues| queues.get_mut(&provider)?.pop_front())
+            .unwrap_or_default()
+    }
+
+    /// A notes patch with a fresh id, so repeated summarize passes add notes.
+    fn canned_patch(&self) -> String {
+        let n = self.served.load(Ordering::Relaxed);
+        json!({
+            "ops": [{
+                "op": "add",
+                "id": format!("mock-{n}"),
+                "text": format!("Mock note {n}"),
+                "evidence": [],
+            }]
+        })
+        .to_string()
+    }
+}
+
+#[derive(Debug)]
+struct MockResponse {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+    delay: Duration,
+}
+
+impl MockResponse {
+    fn json(value: serde_json::Value) -> Self {
+        Self::new(200, "application/json", value.to_string())
+    }
+
+    fn new(status: u16, content_type: &'static str, body: String) -> Self {
+        Self {
+            status,
+            content_type,
+            body,
+            delay: Duration::ZERO,
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::new(
+            status,
+            "application/json",
+            json!({ "error": { "message": message } }).to_string(),
+        )
+    }
+}
+
+pub fn run(
+    args: &MockProvidersArgs,
+    _paths: &ConfigPaths,
+    config: &Config,
+) -> Result<(), MockError> {
+    let exports = env_exports(args.port);
+    if args.print_env {
+        println!("{exports}");
+        return Ok(());
+    }
+    let script = match &args.script {
+        Some(path) => Script::parse(&fs::read_to_string(path)?)?,
+        None => Script::default(),
+    };
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, args.port))?;
+    eprintln!("mock providers on http://127.0.0.1:{}", args.port);
+    eprintln!("point koe at it with:\n{exports}");
+
+    let script = Arc::new(script);
+    let local_model = Arc::new(config.summarize.local.model.clone());
+    for stream in listener.incoming().flatten() {
+        let script = Arc::clone(&script);
+        let local_model = Arc::clone(&local_model);
+        let _ = thread::Builder::new()
+            .name("koe-mock-conn".into())
+            .spawn(move || {
+                let _ = handle_connection(stream, &script, &local_model);
+            });
+    }
+    Ok(())
+}
+
+fn env_exports(port: u16) -> String {
+    let base = format!("http://127.0.0.1:{port}");
+    [
+        format!("export GROQ_BASE_URL={base}{GROQ_PATH}"),
+        format!("export OPENROUTER_BASE_URL={base}{OPENROUTER_PATH}"),
+        format!("export OLLAMA_BASE_URL={base}"),
+        format!("export KOE_TRANSCRIBE_CLOUD_API_KEY={MOCK_API_KEY}"),
+        format!("export KOE_SUMMARIZE_CLOUD_API_KEY={MOCK_API_KEY}"),
+    ]
+    .join("\n")
+}
+
+fn handle_connection(stream: TcpStream, script: &Script, local_model: &str) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut stream = stream;
+    let response = match read_request(&mut reader, MAX_BODY_BYTES) {
+        Ok(request) => respond(&request, script, local_model),
+        Err(e) => MockResponse::error(400, &e.to_string()),
+    };
+    thread::sleep(response.delay);
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.content_type,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+fn respond(request: &Request, script: &Script, local_model: &str) -> MockResponse {
+    let path = request.path.as_str();
+    let provider = match (request.method.as_str(), path) {
+        ("POST", _) if path == format!("{GROQ_PATH}/audio/transcriptions") => Provider::Groq,
+        ("POST", _) if path == format!("{OPENROUTER_PATH}/chat/completions") => {
+            Provider::Openrouter
+        }
+        ("POST", "/api/generate") => Provider::Ollama,
+        // Reachability probes used by `koe doctor`.
+        ("GET", _) if path == format!("{GROQ_PATH}/models") => {
+            return MockResponse::json(json!({ "data": [{ "id": "whisper-large-v3-turbo" }] }));
+        }
+        ("GET", _) if path == format!("{OPENROUTER_PATH}/key") => {
+            return MockResponse::json(json!({ "data": { "label": MOCK_API_KEY } }));
+        }
+        ("GET", "/api/tags") => {
+            return MockResponse::json(json!({ "models": [{ "name": local_model }] }));
+        }
+        _ => return MockResponse::error(404, "not found"),
+    };
+
+    let reply = script.next(provider);
+    let mut response = if reply.status != 200 {
+        let message = reply
+            .content
+            .unwrap_or_else(|| format!("mock {}", reply.status));
+        MockResponse::error(reply.status, &message)
+    } else {
+        let content = reply.content.unwrap_or_else(|| match provider {
+            Provider::Groq => DEFAULT_TRANSCRIPT.to_string(),
+            Provider::Openrouter | Provider::Ollama => script.canned_patch(),
+        });
+        match provider {
+            Provider::Groq => groq_response(&content, request.body.len()),
+            Provider::Openrouter => openrouter_response(&content, &request.body),
+            Provider::Ollama => ollama_response(&content),
+        }
+    };
+    response.delay = Duration::from_millis(reply.delay_ms);
+    response
+}
+
+/// Verbose JSON with one segment spanning the uploaded audio.
+fn groq_response(text: &str, upload_bytes: usize) -> MockResponse {
+    let seconds = (upload_bytes as f64 / WAV_BYTES_PER_SECOND).max(0.5);
+    MockResponse::json(json!({
+        "text": text,
+        "segments": [{ "start": 0.0, "end": seconds, "text": text, "avg_logprob": -0.1 }],
+    }))
+}
+
+/// A chat completion, as server-sent events when the request asks to stream.
+fn openrouter_response(content: &str, request_body: &[u8]) -> MockResponse {
+    let usage = json!({
+        "prompt_tokens": request_body.len() / 4,
+        "completion_tokens": content.len() / 4,
+        "cost": 0.0,
+    });
+    let stream = serde_json::from_slice::<serde_json::Value>(request_body)
+        .map(|body| body["stream"] == json!(true))
+        .unwrap_or(false);
+    if !stream {
+        return MockResponse::json(json!({
+            "choices": [{ "message": { "role": "assistant", "content": content } }],
+            "usage": usage,
+        }));
+    }
+    let mut body = String::new();
+    for token in content.split_inclusive(' ') {
+        let chunk = json!({ "choices": [{ "delta": { "content": token } }] });
+        body.push_str(&format!("data: {chunk}\n\n"));
+    }
+    let last = json!({ "choices": [{ "delta": {} }], "usage": usage });
+    body.push_str(&format!("data: {last}\n\ndata: [DONE]\n\n"));
+    MockResponse::new(200, "text/event-stream", body)
+}
+
+/// Newline-delimited generate chunks, one per word, then the done marker.
+fn ollama_response(content: &str) -> MockResponse {
+    let mut body = String::new();
+    for token in content.split_inclusive(' ') {
+        body.push_str(&json!({ "response": token, "done": false }).to_string());
+        body.push('\n');
+    }
+    body.push_str(&json!({ "response": "", "done": true }).to_string());
+    body.push('\n');
+    MockResponse::new(200, "application/x-ndjson", body)
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        408 => "Request Timeout",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Mock",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Script, respond};
+    use crate::http::Request;
+
+    fn post(path: &str, body: &str) -> Request {
+        Request {
+            method: "POST".into(),
+            path: path.into(),
+            query: String::new(),
+            content_type: "application/json".into(),
+            host: String::new(),
+            authorization: String::new(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn scripted_replies_run_before_canned_defaults() {
+        let script = Script::parse(
+            r#"{"groq": [{"status": 429}, {"content": "hello there", "delay_ms": 5}],
+                "openrouter": [{"content": "{\"ops\": []}"}]}"#,
+        )
+        .expect("script");
+        let transcribe = post("/openai/v1/audio/transcriptions", "");
+
+        let limited = respond(&transcribe, &script, "qwen");
+        assert_eq!(limited.status, 429);
+        let scripted = respond(&transcribe, &script, "qwen");
+        assert!(scripted.body.contains("hello there"));
+        assert_eq!(scripted.delay.as_millis(), 5);
+        let canned = respond(&transcribe, &script, "qwen");
+        assert!(canned.body.contains("mock transcript"));
+
+        let streamed = respond(
+            &post("/api/v1/chat/completions", r#"{"stream": true}"#),
+            &script,
+            "qwen",
+        );
+        assert_eq!(streamed.content_type, "text/event-stream");
+        assert!(streamed.body.contains(r#"\"ops\":"#));
+        assert!(streamed.body.ends_with("data: [DONE]\n\n"));
+
+        let generated = respond(&post("/api/generate", "{}"), &script, "qwen");
+        let last = generated.body.lines().last().expect("done line");
+        assert!(last.contains(r#""done":true"#));
+        assert!(generated.body.contains("mock-"));
+
+        let missing = respond(&post("/v1/unknown", ""), &script, "qwen");
+        assert_eq!(missing.status, 404);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::checksum::sha256_file;
+use crate::config::{Config, ConfigPaths};
+use crate::init::{self, InitError, WHISPER_MODELS};
+use clap::{Args, Subcommand};
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const MODEL_PREFIX: &str = "ggml-";
+const MODEL_SUFFIX: &str = ".bin";
+
+#[derive(Args, Debug, Clone)]
+pub struct ModelsArgs {
+    #[command(subcommand)]
+    pub action: ModelsAction,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ModelsAction {
+    /// Show whisper models on disk with sizes and SHA-256 checksums
+    List {
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Download a whisper model, verified against its published SHA-256
+    Download {
+        /// Model name, e.g. base.en, small, large-v3-turbo
+        model: String,
+        /// Quantized variant
+        #[arg(long, value_parser = ["q5_0", "q8_0"])]
+        quant: Option<String>,
+        /// Download again even if the model is on disk
+        #[arg(long)]
+        force: bool,
+    },
+    /// Delete a downloaded whisper model
+    Remove {
+        model: String,
+        #[arg(long, value_parser = ["q5_0", "q8_0"])]
+        quant: Option<String>,
+    },
+    /// Print the models directory, or the path of one model
+    Path {
+        model: Option<String>,
+        #[arg(long, value_parser = ["q5_0", "q8_0"])]
+        quant: Option<String>,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum ModelsError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Init(#[from] InitError),
+    #[error("{0}")]
+    Message(String),
+}
+
+/// A model file in the models directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InstalledModel {
+    pub name: String,
+    pub path: PathBuf,
+    pub bytes: u64,
+    pub sha256: String,
+    /// The checksum was recorded by a verified download rather than computed now.
+    pub verified: bool,
+    /// Used by the `transcribe.local` profile.
+    pub configured: bool,
+}
+
+pub fn run(args: &ModelsArgs, paths: &ConfigPaths, config: &Config) -> Result<(), ModelsError> {
+    let models_dir = &paths.models_dir;
+    let configured = configured_model(config, models_dir);
+    match &args.action {
+        ModelsAction::List { json } => {
+            let models = installed_models(models_dir, configured.as_deref())?;
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&models)?);
+            } else {
+                print_models(&models);
+            }
+        }
+        ModelsAction::Download {
+            model,
+            quant,
+            force,
+        } => {
+            init::download_model(&variant(model, quant.as_deref()), models_dir, *force)?;
+        }
+        ModelsAction::Remove { model, quant } => {
+            let path = models_dir.join(init::model_filename(&variant(model, quant.as_deref())));
+            if !path.exists() {
+                return Err(ModelsError::Message(format!(
+                    "{} is not downloaded",
+                    path.display()
+                )));
+            }
+            if configured.as_deref() == Some(path.as_path()) {
+                return Err(ModelsError::Message(format!(
+                    "{} is the transcribe.local model; point transcribe.local.model elsewhere first",
+                    path.display()
+                )));
+            }
+            fs::remove_file(&path)?;
+            let sidecar = init::checksum_path(&path);
+            if sidecar.exists() {
+                fs::remove_file(sidecar)?;
+            }
+            println!("removed {}", path.display());
+        }
+        ModelsAction::Path { model: None, .. } => println!("{}", models_dir.display()),
+        ModelsAction::Path {
+            model: Some(model),
+            quant,
+        } => {
+            let path = models_dir.join(init::model_filename(&variant(model, quant.as_deref())));
+            println!("{}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Model name with an optional quantization suffix, e.g. `small-q5_0`.
+fn variant(model: &str, quant: Option<&str>) -> String {
+    match quant {
+        Some(quant) => format!("{model}-{quant}"),
+        None => model.to_string(),
+    }
+}
+
+fn configured_model(config: &Config, models_dir: &Path) -> Option<PathBuf> {
+    let local = &config.transcribe.local;
+    if local.provider != "whisper" {
+        return None;
+    }
+    crate::whisper_model_path(&local.model, models_dir)
+}
+
+/// Model files in `models_dir`, sorted by name. Checksums come from the sidecar
+/// written by a verified download, else are computed from the file.
+pub fn installed_models(
+    models_dir: &Path,
+    configured: Option<&Path>,
+) -> Result<Vec<InstalledModel>, ModelsError> {
+    let entries = match fs::read_dir(models_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut models = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let Some(name) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(MODEL_PREFIX))
+            .and_then(|name| name.strip_suffix(MODEL_SUFFIX))
+            .map(str::to_string)
+        else {
+            continue;
+        };
+        let recorded = fs::read_to_string(init::checksum_path(&path))
+            .ok()
+            .map(|value| value.trim().to_ascii_lowercase())
+            .filter(|value| !value.is_empty());
+        let verified = recorded.is_some();
+        let sha256 = match recorded {
+            Some(value) => value,
+            None => sha256_file(&path)?,
+        };
+        models.push(InstalledModel {
+            name,
+            bytes: fs::metadata(&path)?.len(),
+            sha256,
+            verified,
+            configured: configured == Some(path.as_path()),
+            path,
+        });
+    }
+    models.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(models)
+}
+
+fn print_models(models: &[InstalledModel]) {
+    if models.is_empty() {
+        println!("no models downloaded");
+    }
+    for model in models {
+        let marker = if model.configured {
+            " (configured)"
+        } else {
+            ""
+        };
+        let verified = if model.verified { "" } else { " unverified" };
+        println!(
+            "{:<24} {:>6} MB  sha256 {}{verified}{marker}",
+            model.name,
+            model.bytes / (1024 * 1024),
+            model.sha256
+        );
+    }
+    let available: Vec<String> = WHISPER_MODELS
+        .iter()
+        .filter(|option| !models.iter().any(|model| model.name == option.name))
+        .map(|option| format!("{} ({})", option.name, option.size))
+        .collect();
+    if !available.is_empty() {
+        println!("available: {}", available.join(", "));
+    }
+    println!("quantized variants: koe models download <model> --quant q5_0|q8_0");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{installed_models, variant};
+    use crate::checksum::sha256_hex;
+    use std::fs;
+
+    #[test]
+    fn lists_models_with_recorded_or_computed_checksums() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("ggml-small-q5_0.bin"), b"quantized").expect("write");
+        fs::write(dir.path().join("ggml-small-q5_0.bin.sha256"), "ABC123\n").expect("write");
+        fs::write(dir.path().join("ggml-base.en.bin"), b"weights").expect("write");
+        fs::write(dir.path().join("ggml-base.en.download"), b"partial").expect("write");
+        let configured = dir.path().join("ggml-base.en.bin");
+
+        let models = installed_models(dir.path(), Some(&configured)).expect("list");
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].name, "base.en");
+        assert_eq!(models[0].bytes, 7);
+        assert_eq!(models[0].sha256, sha256_hex(b"weights"));
+        assert!(!models[0].verified && models[0].configured);
+        assert_eq!(models[1].name, variant("small", Some("q5_0")));
+        assert_eq!(models[1].sha256, "abc123");
+        assert!(models[1].verified && !models[1].configured);
+    }
+}
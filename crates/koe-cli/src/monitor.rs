@@ -0,0 +1,104 @@
+use crate::tui::{SummarizeCommand, TranscribeCommand, UiEvent};
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::mpsc::Sender;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// CPU and resident memory of the koe process.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourceUsage {
+    pub cpu_percent: f32,
+    pub rss_mb: u64,
+}
+
+pub struct MonitorInput {
+    pub max_rss_mb: u32,
+    /// Smaller whisper model to switch to on the limit; `None` when unavailable.
+    pub degraded_model: Option<PathBuf>,
+    pub ui_tx: Sender<UiEvent>,
+    pub transcribe_cmd_tx: Sender<TranscribeCommand>,
+    pub summarize_cmd_tx: Sender<SummarizeCommand>,
+}
+
+/// Sample own CPU/RSS periodically for the diagnostics overlay and degrade
+/// transcribe/summarize once `max_rss_mb` is exceeded. Exits when the UI goes away.
+pub fn spawn_monitor(input: MonitorInput) -> io::Result<JoinHandle<()>> {
+    thread::Builder::new()
+        .name("koe-monitor".into())
+        .spawn(move || {
+            let pid = std::process::id();
+            let mut degraded = false;
+            loop {
+                thread::sleep(SAMPLE_INTERVAL);
+                let Some(usage) = sample_usage(pid) else {
+                    continue;
+                };
+                if input.ui_tx.send(UiEvent::ResourceUsage(usage)).is_err() {
+                    break;
+                }
+                if degraded || input.max_rss_mb == 0 || usage.rss_mb <= input.max_rss_mb as u64 {
+                    continue;
+                }
+                degraded = true;
+                let mut actions = vec!["slower summarize"];
+                if let Some(model) = input.degraded_model.clone() {
+                    let _ = input
+                        .transcribe_cmd_tx
+                        .send(TranscribeCommand::Degrade(model));
+                    actions.insert(0, "smaller model");
+                }
+                let _ = input.summarize_cmd_tx.send(SummarizeCommand::Degrade);
+                let _ = input.ui_tx.send(UiEvent::Error {
+                    message: format!(
+                        "memory limit reached ({} MB > {} MB); degraded: {}",
+                        usage.rss_mb,
+                        input.max_rss_mb,
+                        actions.join(", ")
+                    ),
+                });
+            }
+        })
+}
+
+fn sample_usage(pid: u32) -> Option<ResourceUsage> {
+    let output = Command::new("ps")
+        .args(["-o", "%cpu=,rss=", "-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_ps_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `ps -o %cpu=,rss=` output (RSS in KiB).
+fn parse_ps_output(output: &str) -> Option<ResourceUsage> {
+    let mut fields = output.split_whitespace();
+    let cpu_percent = fields.next()?.replace(',', ".").parse().ok()?;
+    let rss_kb: u64 = fields.next()?.parse().ok()?;
+    Some(ResourceUsage {
+        cpu_percent,
+        rss_mb: rss_kb / 1024,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ResourceUsage, parse_ps_output};
+
+    #[test]
+    fn parse_ps_output_reads_cpu_and_rss() {
+        assert_eq!(
+            parse_ps_output(" 12.5 204800\n"),
+            Some(ResourceUsage {
+                cpu_percent: 12.5,
+                rss_mb: 200,
+            })
+        );
+        assert_eq!(parse_ps_output(""), None);
+    }
+}
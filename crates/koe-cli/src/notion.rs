@@ -0,0 +1,257 @@
+use crate::config::NotionConfig;
+use crate::session::{SessionError, StoredSession};
+use koe_core::types::{MeetingNotes, NoteKind};
+use serde_json::{Value, json};
+use std::path::Path;
+use std::time::Duration;
+use thiserror::Error;
+
+const DEFAULT_BASE_URL: &str = "https://api.notion.com/v1";
+const NOTION_VERSION: &str = "2022-06-28";
+const NOTION_TIMEOUT: Duration = Duration::from_secs(15);
+/// Notion accepts at most this many child blocks per request.
+const MAX_CHILDREN: usize = 100;
+/// Notion rejects rich text content longer than this.
+const MAX_TEXT: usize = 2000;
+
+#[derive(Debug, Error)]
+pub enum NotionError {
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("notion request failed: {0}")]
+    Request(String),
+}
+
+/// Create a page for a finished meeting in the configured database: title,
+/// date, participants, and tags as properties; notes, action items as to-dos,
+/// and the transcript path in the body. Returns the page URL.
+pub fn push_meeting(
+    config: &NotionConfig,
+    session_dir: &Path,
+    transcript: Option<&Path>,
+) -> Result<String, NotionError> {
+    let session = StoredSession::load(session_dir)?;
+    let notes = session.notes()?;
+    let (page, rest) = page_request(config, &session, &notes, transcript);
+
+    let base_url = std::env::var("NOTION_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(NOTION_TIMEOUT))
+        .build()
+        .into();
+    let auth = format!("Bearer {}", config.token.trim());
+    let created: Value = agent
+        .post(format!("{base_url}/pages"))
+        .header("Authorization", &auth)
+        .header("Notion-Version", NOTION_VERSION)
+        .send_json(&page)
+        .and_then(|response| response.into_body().read_json())
+        .map_err(|e| NotionError::Request(e.to_string()))?;
+    let page_id = created["id"].as_str().unwrap_or_default().to_string();
+    for batch in rest.chunks(MAX_CHILDREN) {
+        agent
+            .patch(format!("{base_url}/blocks/{page_id}/children"))
+            .header("Authorization", &auth)
+            .header("Notion-Version", NOTION_VERSION)
+            .send_json(json!({ "children": batch }))
+            .map_err(|e| NotionError::Request(e.to_string()))?;
+    }
+    Ok(created["url"].as_str().unwrap_or(&page_id).to_string())
+}
+
+/// The create-page body (with up to [`MAX_CHILDREN`] blocks) and the blocks
+/// left to append afterwards.
+fn page_request(
+    config: &NotionConfig,
+    session: &StoredSession,
+    notes: &MeetingNotes,
+    transcript: Option<&Path>,
+) -> (Value, Vec<Value>) {
+    let metadata = &session.metadata;
+    let title = metadata.title.clone().unwrap_or_else(|| {
+        format!(
+            "Meeting {}",
+            metadata.start_time.get(..10).unwrap_or_default()
+        )
+    });
+    let mut properties = serde_json::Map::new();
+    properties.insert(
+        config.title_property.trim().to_string(),
+        json!({ "title": rich_text(&title) }),
+    );
+    if !config.date_property.trim().is_empty() {
+        properties.insert(
+            config.date_property.trim().to_string(),
+            json!({ "date": { "start": metadata.start_time, "end": metadata.end_time } }),
+        );
+    }
+    for (property, values) in [
+        (&config.participants_property, &metadata.participants),
+        (&config.tags_property, &metadata.tags),
+    ] {
+        if !property.trim().is_empty() {
+            properties.insert(
+                property.trim().to_string(),
+                json!({ "multi_select": multi_select(values) }),
+            );
+        }
+    }
+
+    let mut children = vec![heading("Notes")];
+    let points: Vec<Value> = notes
+        .bullets
+        .iter()
+        .filter(|bullet| bullet.kind != NoteKind::Action)
+        .map(|bullet| {
+            let label = match bullet.kind {
+                NoteKind::Decision => "Decision: ",
+                NoteKind::Question => "Question: ",
+                NoteKind::Point | NoteKind::Action => "",
+            };
+            block(
+                "bulleted_list_item",
+                json!({ "rich_text": rich_text(&format!("{label}{}", bullet.text.trim())) }),
+            )
+        })
+        .collect();
+    if points.is_empty() {
+        children.push(paragraph("(none)"));
+    }
+    children.extend(points);
+
+    children.push(heading("Action items"));
+    let actions: Vec<Value> = crate::reminders::action_items(notes)
+        .map(|bullet| {
+            let mut text = bullet.text.trim().to_string();
+            match (bullet.owner.as_deref(), bullet.due.as_deref()) {
+                (Some(owner), Some(due)) => text.push_str(&format!(" ({owner}, due {due})")),
+                (Some(owner), None) => text.push_str(&format!(" ({owner})")),
+                (None, Some(due)) => text.push_str(&format!(" (due {due})")),
+                (None, None) => {}
+            }
+            block(
+                "to_do",
+                json!({ "rich_text": rich_text(&text), "checked": bullet.done }),
+            )
+        })
+        .collect();
+    if actions.is_empty() {
+        children.push(paragraph("(none)"));
+    }
+    children.extend(actions);
+
+    if let Some(transcript) = transcript {
+        children.push(paragraph(&format!(
+            "Transcript: file://{}",
+            transcript.display()
+        )));
+    }
+
+    let rest = children.split_off(children.len().min(MAX_CHILDREN));
+    let page = json!({
+        "parent": { "database_id": config.database_id.trim() },
+        "properties": properties,
+        "children": children,
+    });
+    (page, rest)
+}
+
+fn block(kind: &str, content: Value) -> Value {
+    json!({ "object": "block", "type": kind, kind: content })
+}
+
+fn heading(text: &str) -> Value {
+    block("heading_2", json!({ "rich_text": rich_text(text) }))
+}
+
+fn paragraph(text: &str) -> Value {
+    block("paragraph", json!({ "rich_text": rich_text(text) }))
+}
+
+fn rich_text(text: &str) -> Value {
+    let content: String = text.chars().take(MAX_TEXT).collect();
+    json!([{ "type": "text", "text": { "content": content } }])
+}
+
+/// Multi-select options; Notion does not allow commas in option names.
+fn multi_select(values: &[String]) -> Value {
+    values
+        .iter()
+        .map(|value| value.replace(',', " ").trim().to_string())
+        .filter(|value| !value.is_empty())
+        .map(|name| json!({ "name": name }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::page_request;
+    use crate::config::NotionConfig;
+    use crate::session::{SessionMetadata, SessionMetadataInput, StoredSession};
+    use crate::test_support::{bullet, session_input};
+    use koe_core::types::{MeetingNotes, NoteBullet, NoteKind};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn page_carries_properties_notes_and_action_todos() {
+        let mut metadata = SessionMetadata::new(SessionMetadataInput {
+            participants: vec!["Sam".to_string(), "Ana, PM".to_string()],
+            ..session_input()
+        })
+        .unwrap();
+        metadata.title = Some("Launch sync".to_string());
+        metadata.tags = vec!["launch".to_string()];
+        let session = StoredSession {
+            dir: PathBuf::from("/tmp/session"),
+            metadata,
+        };
+        let note = |text: &str, kind: NoteKind, done: bool| NoteBullet {
+            done,
+            ..bullet(text, text, kind)
+        };
+        let mut bullets = vec![
+            note("Ship Friday", NoteKind::Decision, false),
+            note("Write notes", NoteKind::Action, true),
+        ];
+        bullets.extend((0..120).map(|_| note("filler", NoteKind::Point, false)));
+        let notes = MeetingNotes { bullets };
+        let config = NotionConfig {
+            token: "secret".to_string(),
+            database_id: "db1".to_string(),
+            tags_property: String::new(),
+            ..NotionConfig::default()
+        };
+
+        let (page, rest) = page_request(
+            &config,
+            &session,
+            &notes,
+            Some(Path::new("/exports/transcript.md")),
+        );
+        assert_eq!(page["parent"]["database_id"], "db1");
+        assert_eq!(
+            page["properties"]["Name"]["title"][0]["text"]["content"],
+            "Launch sync"
+        );
+        assert_eq!(
+            page["properties"]["Participants"]["multi_select"][1]["name"],
+            "Ana  PM"
+        );
+        assert!(page["properties"].get("Tags").is_none());
+        let children = page["children"].as_array().unwrap();
+        assert_eq!(children.len(), 100);
+        assert_eq!(
+            children[1]["bulleted_list_item"]["rich_text"][0]["text"]["content"],
+            "Decision: Ship Friday"
+        );
+        let todo = rest
+            .iter()
+            .find(|block| block["type"] == "to_do")
+            .expect("action item appended after the first batch");
+        assert_eq!(todo["to_do"]["checked"], true);
+        assert_eq!(
+            rest.last().unwrap()["paragraph"]["rich_text"][0]["text"]["content"],
+            "Transcript: file:///exports/transcript.md"
+        );
+    }
+}
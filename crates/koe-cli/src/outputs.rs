@@ -0,0 +1,203 @@
+use crate::config::OutputsConfig;
+use crate::webhook::WebhookEvent;
+use koe_core::types::NotesOp;
+use serde_json::json;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::net::{SocketAddr, UdpSocket};
+use std::os::unix::fs::{FileTypeExt, OpenOptionsExt};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Meeting state and note signals for control surfaces (Stream Deck,
+/// Companion, OSC apps), written to a named pipe and/or sent as OSC over UDP.
+#[derive(Debug)]
+pub struct EventOutputs {
+    pipe: Option<PathBuf>,
+    osc: Option<(UdpSocket, SocketAddr)>,
+}
+
+/// One output signal; rendered as a JSON line for the pipe and an OSC message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Signal {
+    Phase(&'static str),
+    Note(String),
+}
+
+impl EventOutputs {
+    /// Build from config, creating the named pipe if needed; `None` when nothing is configured.
+    pub fn from_config(config: &OutputsConfig) -> io::Result<Option<Self>> {
+        if config.is_empty() {
+            return Ok(None);
+        }
+        let pipe = match config.pipe.trim() {
+            "" => None,
+            path => {
+                let path = PathBuf::from(path);
+                ensure_fifo(&path)?;
+                Some(path)
+            }
+        };
+        let osc = match config.osc.trim() {
+            "" => None,
+            target => {
+                let addr: SocketAddr = target
+                    .parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("{e}")))?;
+                let bind = if addr.is_ipv4() {
+                    "0.0.0.0:0"
+                } else {
+                    "[::]:0"
+                };
+                Some((UdpSocket::bind(bind)?, addr))
+            }
+        };
+        Ok(Some(Self { pipe, osc }))
+    }
+
+    /// Send every signal derived from `event`. A pipe without a reader is not an error.
+    pub fn emit(&self, event: &WebhookEvent) -> io::Result<()> {
+        for signal in signals(event) {
+            if let Some(path) = &self.pipe {
+                // Non-blocking, so a pipe nobody is reading fails fast instead of blocking.
+                match OpenOptions::new()
+                    .write(true)
+                    .custom_flags(libc::O_NONBLOCK)
+                    .open(path)
+                {
+                    Ok(mut pipe) => {
+                        let mut line = pipe_line(&signal);
+                        line.push('\n');
+                        pipe.write_all(line.as_bytes())?;
+                    }
+                    // ENXIO: no process has the pipe open for reading.
+                    Err(e) if e.raw_os_error() == Some(libc::ENXIO) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            if let Some((socket, addr)) = &self.osc {
+                for message in osc_messages(&signal) {
+                    socket.send_to(&message, addr)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn ensure_fifo(path: &Path) -> io::Result<()> {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.file_type().is_fifo() => Ok(()),
+        Ok(_) => Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} exists and is not a named pipe", path.display()),
+        )),
+        Err(_) => {
+            let status = Command::new("mkfifo").arg(path).status()?;
+            if status.success() {
+                Ok(())
+            } else {
+                Err(io::Error::other(format!(
+                    "mkfifo {} failed: {status}",
+                    path.display()
+                )))
+            }
+        }
+    }
+}
+
+fn signals(event: &WebhookEvent) -> Vec<Signal> {
+    match event {
+        WebhookEvent::MeetingStart { .. } => vec![Signal::Phase("active")],
+        WebhookEvent::MeetingEnd { .. } => vec![Signal::Phase("post_meeting")],
+        WebhookEvent::NotesPatch { ops, .. } => ops
+            .iter()
+            .filter_map(|op| match op {
+                NotesOp::Add { text, .. } => Some(Signal::Note(text.clone())),
+                NotesOp::Remove { .. } | NotesOp::Merge { .. } | NotesOp::Reorder { .. } => None,
+            })
+            .collect(),
+    }
+}
+
+fn pipe_line(signal: &Signal) -> String {
+    match signal {
+        Signal::Phase(phase) => json!({ "event": "phase", "phase": phase }).to_string(),
+        Signal::Note(text) => json!({ "event": "note", "text": text }).to_string(),
+    }
+}
+
+/// `/koe/phase` + `/koe/active` (1/0) for phase changes, `/koe/note` for new notes.
+fn osc_messages(signal: &Signal) -> Vec<Vec<u8>> {
+    match signal {
+        Signal::Phase(phase) => vec![
+            osc_message("/koe/phase", &OscArg::Str(phase)),
+            osc_message("/koe/active", &OscArg::Int(i32::from(*phase == "active"))),
+        ],
+        Signal::Note(text) => vec![osc_message("/koe/note", &OscArg::Str(text))],
+    }
+}
+
+enum OscArg<'a> {
+    Str(&'a str),
+    Int(i32),
+}
+
+/// Encode a single-argument OSC 1.0 message.
+fn osc_message(address: &str, arg: &OscArg<'_>) -> Vec<u8> {
+    let mut out = Vec::new();
+    push_osc_string(&mut out, address);
+    match arg {
+        OscArg::Str(value) => {
+            push_osc_string(&mut out, ",s");
+            push_osc_string(&mut out, value);
+        }
+        OscArg::Int(value) => {
+            push_osc_string(&mut out, ",i");
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+    out
+}
+
+/// OSC strings are NUL-terminated and padded to a multiple of four bytes.
+fn push_osc_string(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(value.as_bytes());
+    let padding = 4 - value.len() % 4;
+    out.extend(std::iter::repeat_n(0, padding));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OscArg, Signal, osc_message, pipe_line, signals};
+    use crate::webhook::WebhookEvent;
+    use koe_core::types::NotesOp;
+
+    #[test]
+    fn events_map_to_pipe_lines_and_padded_osc() {
+        let event = WebhookEvent::NotesPatch {
+            session_id: "abc".to_string(),
+            ops: vec![NotesOp::Add {
+                id: "n1".to_string(),
+                text: "Send deck".to_string(),
+                evidence: vec![1],
+                kind: None,
+            }],
+        };
+        let signals = signals(&event);
+        assert_eq!(signals, vec![Signal::Note("Send deck".to_string())]);
+        assert_eq!(
+            pipe_line(&signals[0]),
+            r#"{"event":"note","text":"Send deck"}"#
+        );
+
+        assert_eq!(
+            osc_message("/koe/note", &OscArg::Str("ab")),
+            b"/koe/note\0\0\0,s\0\0ab\0\0".to_vec()
+        );
+        assert_eq!(
+            osc_message("/koe/active", &OscArg::Int(1)),
+            b"/koe/active\0,i\0\0\0\0\0\x01".to_vec()
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Profiles handled by the built-in prompts and finalize passes.
+pub const BUILTIN_PROFILES: [&str; 3] = ["minimal", "podcast", "lecture"];
+
+/// Live-notes template for `profile`: `None` for built-in profiles, the text
+/// of `<prompts_dir>/<profile>.txt` otherwise.
+pub fn resolve_template(prompts_dir: &Path, profile: &str) -> Result<Option<String>, String> {
+    if BUILTIN_PROFILES.contains(&profile) {
+        return Ok(None);
+    }
+    let path = prompts_dir.join(format!("{profile}.txt"));
+    match fs::read_to_string(&path) {
+        Ok(text) if text.trim().is_empty() => Err(format!("{} is empty", path.display())),
+        Ok(text) => Ok(Some(text)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            Err(format!("prompt template not found: {}", path.display()))
+        }
+        Err(err) => Err(format!("prompt template {}: {err}", path.display())),
+    }
+}
+
+/// Built-in profiles followed by the templates in `prompts_dir`, sorted by name.
+pub fn profile_names(prompts_dir: &Path) -> Vec<String> {
+    let mut custom: Vec<String> = fs::read_dir(prompts_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+                .filter(|name| !BUILTIN_PROFILES.contains(&name.as_str()))
+                .collect()
+        })
+        .unwrap_or_default();
+    custom.sort();
+    BUILTIN_PROFILES
+        .iter()
+        .map(|name| (*name).to_string())
+        .chain(custom)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{profile_names, resolve_template};
+    use std::fs;
+
+    #[test]
+    fn templates_resolve_from_prompts_dir() {
+        let dir = std::env::temp_dir().join(format!("koe-prompts-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("standup.txt"), "notes for {{transcript}}").unwrap();
+        fs::write(dir.join("readme.md"), "ignored").unwrap();
+
+        assert_eq!(resolve_template(&dir, "minimal"), Ok(None));
+        assert_eq!(
+            resolve_template(&dir, "standup"),
+            Ok(Some("notes for {{transcript}}".to_string()))
+        );
+        assert!(resolve_template(&dir, "missing").is_err());
+        assert_eq!(
+            profile_names(&dir),
+            vec!["minimal", "podcast", "lecture", "standup"]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
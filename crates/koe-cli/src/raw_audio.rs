@@ -2,7 +2,9 @@ use crate::config::{AgcConfig, DenoiseConfig, MixdownConfig};
 use koe_core::types::AudioSource;
 use std::collections::VecDeque;
 use std::f32::consts::PI;
-use std::io::{BufWriter, Write};
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, Command, Stdio};
 use std::sync::mpsc::Receiver;
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
@@ -11,8 +13,16 @@ use std::time::{Duration, Instant};
 const AGC_RMS_WINDOW_MS: f32 = 50.0;
 const AGC_PEAK_RELEASE_MS: f32 = 50.0;
 const DENOISE_RMS_WINDOW_MS: f32 = 30.0;
+/// Denoise gate placed this far above a calibrated noise floor.
+const DENOISE_CALIBRATION_MARGIN_DB: f32 = 6.0;
 const EPSILON: f32 = 1.0e-6;
 
+/// Denoise threshold for a measured noise floor: just above it, so background
+/// hum is reduced while quiet speech passes.
+pub fn calibrated_denoise_threshold(noise_floor_dbfs: f32) -> f32 {
+    (noise_floor_dbfs + DENOISE_CALIBRATION_MARGIN_DB).min(0.0)
+}
+
 fn db_to_gain(db: f32) -> f32 {
     10_f32.powf(db / 20.0)
 }
@@ -183,6 +193,10 @@ impl NoiseReducer {
         self.gain += (target_gain - self.gain) * coeff;
         sample * self.gain
     }
+
+    fn set_threshold(&mut self, threshold_dbfs: f32) {
+        self.threshold = db_to_gain(threshold_dbfs).max(EPSILON);
+    }
 }
 
 struct HighPassFilter {
@@ -215,8 +229,450 @@ impl HighPassFilter {
     }
 }
 
+/// Unmixed per-source recordings kept alongside the mixdown for multitrack export.
+struct SourceTracks {
+    system: BufWriter<std::fs::File>,
+    mic: BufWriter<std::fs::File>,
+}
+
+impl SourceTracks {
+    fn write(&mut self, source: AudioSource, samples: &[f32]) -> std::io::Result<()> {
+        let writer = match source {
+            AudioSource::System => &mut self.system,
+            AudioSource::Microphone => &mut self.mic,
+            AudioSource::Mixed => return Ok(()),
+        };
+        for sample in samples {
+            writer.write_all(&sample.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.system.flush()?;
+        self.mic.flush()
+    }
+}
+
+/// Slow down (`speed` < 1) or speed up `samples` by linear-interpolation
+/// resampling; pitch shifts with the rate.
+pub fn time_stretch(samples: &[f32], speed: f32) -> Vec<f32> {
+    if samples.is_empty() || speed <= 0.0 {
+        return Vec::new();
+    }
+    let out_len = (samples.len() as f32 / speed).ceil() as usize;
+    let last = samples.len() - 1;
+    (0..out_len)
+        .map(|i| {
+            let position = i as f32 * speed;
+            let index = (position.floor() as usize).min(last);
+            let next = (index + 1).min(last);
+            let frac = position - index as f32;
+            samples[index] + (samples[next] - samples[index]) * frac
+        })
+        .collect()
+}
+
+/// On-disk format of the session mixdown (`audio.archive_format`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// Raw f32 samples, wrapped into a WAV on export.
+    Wav,
+    /// Lossless 16-bit FLAC encoded in-process.
+    Flac,
+    /// Opus in Ogg, encoded by an `ffmpeg` child process.
+    Opus,
+}
+
+impl ArchiveFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "wav" => Some(Self::Wav),
+            "flac" => Some(Self::Flac),
+            "opus" => Some(Self::Opus),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Wav => "raw",
+            Self::Flac => "flac",
+            Self::Opus => "ogg",
+        }
+    }
+}
+
+const FLAC_BLOCK_SIZE: usize = 4096;
+const FLAC_MAX_FIXED_ORDER: usize = 4;
+const FLAC_MAX_RICE_PARAM: u32 = 14;
+const FLAC_STREAMINFO_OFFSET: u64 = 8;
+const OPUS_BITRATE: &str = "32k";
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u64,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, bits: u32) {
+        if bits == 0 {
+            return;
+        }
+        self.acc = (self.acc << bits) | (value & ((1u64 << bits) - 1));
+        self.bits += bits;
+        while self.bits >= 8 {
+            self.bits -= 8;
+            self.bytes.push((self.acc >> self.bits) as u8);
+        }
+    }
+
+    fn write_signed(&mut self, value: i32, bits: u32) {
+        self.write(u64::from(value as u32), bits);
+    }
+
+    fn write_unary(&mut self, zeros: u32) {
+        let mut remaining = zeros;
+        while remaining >= 32 {
+            self.write(0, 32);
+            remaining -= 32;
+        }
+        self.write(1, remaining + 1);
+    }
+
+    fn align(&mut self) {
+        if self.bits > 0 {
+            self.write(0, 8 - self.bits);
+        }
+    }
+
+    fn into_bytes(mut self) -> Vec<u8> {
+        self.align();
+        self.bytes
+    }
+}
+
+fn crc8(bytes: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for byte in bytes {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+fn crc16(bytes: &[u8]) -> u16 {
+    let mut crc = 0u16;
+    for byte in bytes {
+        crc ^= u16::from(*byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Streaming mono FLAC encoder using fixed predictors and Rice coding.
+/// STREAMINFO is rewritten with the final sample count on `finish`.
+struct FlacEncoder<W: Write + Seek> {
+    out: W,
+    sample_rate_hz: u32,
+    block: Vec<i32>,
+    frame_number: u64,
+    total_samples: u64,
+    min_frame_bytes: u32,
+    max_frame_bytes: u32,
+}
+
+impl<W: Write + Seek> FlacEncoder<W> {
+    fn new(out: W, sample_rate_hz: u32) -> std::io::Result<Self> {
+        let mut encoder = Self {
+            out,
+            sample_rate_hz,
+            block: Vec::with_capacity(FLAC_BLOCK_SIZE),
+            frame_number: 0,
+            total_samples: 0,
+            min_frame_bytes: 0,
+            max_frame_bytes: 0,
+        };
+        encoder.out.write_all(b"fLaC")?;
+        // Last metadata block, type STREAMINFO, 34 bytes.
+        encoder.out.write_all(&[0x80, 0, 0, 34])?;
+        let streaminfo = encoder.streaminfo();
+        encoder.out.write_all(&streaminfo)?;
+        Ok(encoder)
+    }
+
+    fn write_sample(&mut self, sample: f32) -> std::io::Result<()> {
+        let scaled = (sample.clamp(-1.0, 1.0) * f32::from(i16::MAX)).round() as i32;
+        self.block.push(scaled);
+        if self.block.len() == FLAC_BLOCK_SIZE {
+            self.write_frame()?;
+        }
+        Ok(())
+    }
+
+    /// Flush whole frames only; a short block is legal solely as the last frame.
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.out.flush()
+    }
+
+    fn finish(&mut self) -> std::io::Result<()> {
+        if !self.block.is_empty() {
+            self.write_frame()?;
+        }
+        let end = self.out.stream_position()?;
+        self.out.seek(SeekFrom::Start(FLAC_STREAMINFO_OFFSET))?;
+        let streaminfo = self.streaminfo();
+        self.out.write_all(&streaminfo)?;
+        self.out.seek(SeekFrom::Start(end))?;
+        self.out.flush()
+    }
+
+    fn streaminfo(&self) -> Vec<u8> {
+        let mut bits = BitWriter::default();
+        bits.write(FLAC_BLOCK_SIZE as u64, 16);
+        bits.write(FLAC_BLOCK_SIZE as u64, 16);
+        bits.write(u64::from(self.min_frame_bytes), 24);
+        bits.write(u64::from(self.max_frame_bytes), 24);
+        bits.write(u64::from(self.sample_rate_hz), 20);
+        bits.write(0, 3); // one channel
+        bits.write(15, 5); // 16 bits per sample
+        bits.write(self.total_samples, 36);
+        // An all-zero MD5 signature means "not computed".
+        for _ in 0..4 {
+            bits.write(0, 32);
+        }
+        bits.into_bytes()
+    }
+
+    fn write_frame(&mut self) -> std::io::Result<()> {
+        let mut bits = BitWriter::default();
+        bits.write(0b11_1111_1111_1110, 14);
+        bits.write(0, 1);
+        bits.write(0, 1); // fixed block size
+        bits.write(0b0111, 4); // block size - 1 follows as 16 bits
+        bits.write(0b0000, 4); // sample rate from STREAMINFO
+        bits.write(0b0000, 4); // mono
+        bits.write(0b100, 3); // 16 bits per sample
+        bits.write(0, 1);
+        write_utf8_number(&mut bits, self.frame_number);
+        bits.write((self.block.len() - 1) as u64, 16);
+        // The header is byte aligned here, so `bytes` holds all of it.
+        let crc = crc8(&bits.bytes);
+        bits.write(u64::from(crc), 8);
+        write_subframe(&mut bits, &self.block);
+        bits.align();
+        let crc = crc16(&bits.bytes);
+        bits.write(u64::from(crc), 16);
+        let frame = bits.into_bytes();
+
+        self.out.write_all(&frame)?;
+        let frame_bytes = frame.len() as u32;
+        self.min_frame_bytes = if self.frame_number == 0 {
+            frame_bytes
+        } else {
+            self.min_frame_bytes.min(frame_bytes)
+        };
+        self.max_frame_bytes = self.max_frame_bytes.max(frame_bytes);
+        self.total_samples += self.block.len() as u64;
+        self.frame_number += 1;
+        self.block.clear();
+        Ok(())
+    }
+}
+
+/// Pick the fixed predictor order with the smallest residual, then code it
+/// with a single Rice partition, falling back to verbatim when that is smaller.
+fn write_subframe(bits: &mut BitWriter, block: &[i32]) {
+    let order = (0..=FLAC_MAX_FIXED_ORDER.min(block.len()))
+        .min_by_key(|order| {
+            fixed_residual(block, *order)
+                .map(|r| u64::from(r.unsigned_abs()))
+                .sum::<u64>()
+        })
+        .unwrap_or(0);
+    let folded: Vec<u32> = fixed_residual(block, order).map(zigzag).collect();
+    let (param, rice_bits) = (0..=FLAC_MAX_RICE_PARAM)
+        .map(|param| {
+            let bits = folded
+                .iter()
+                .map(|value| u64::from(value >> param) + 1 + u64::from(param))
+                .sum::<u64>();
+            (param, bits)
+        })
+        .min_by_key(|(_, bits)| *bits)
+        .unwrap_or((0, 0));
+    let verbatim_bits = block.len() as u64 * 16;
+    let fixed_bits = order as u64 * 16 + 2 + 4 + 4 + rice_bits;
+    if fixed_bits >= verbatim_bits {
+        bits.write(0b0000_0010, 8);
+        for sample in block {
+            bits.write_signed(*sample, 16);
+        }
+        return;
+    }
+    bits.write(0b0001_0000 | ((order as u64) << 1), 8);
+    for sample in &block[..order] {
+        bits.write_signed(*sample, 16);
+    }
+    bits.write(0b00, 2); // Rice coding with 4-bit parameters
+    bits.write(0, 4); // partition order 0
+    bits.write(u64::from(param), 4);
+    for value in folded {
+        bits.write_unary(value >> param);
+        bits.write(u64::from(value), param);
+    }
+}
+
+fn fixed_residual(block: &[i32], order: usize) -> impl Iterator<Item = i32> + '_ {
+    (order..block.len()).map(move |i| {
+        let s = |back: usize| block[i - back];
+        match order {
+            0 => s(0),
+            1 => s(0) - s(1),
+            2 => s(0) - 2 * s(1) + s(2),
+            3 => s(0) - 3 * s(1) + 3 * s(2) - s(3),
+            _ => s(0) - 4 * s(1) + 6 * s(2) - 4 * s(3) + s(4),
+        }
+    })
+}
+
+fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+fn write_utf8_number(bits: &mut BitWriter, value: u64) {
+    if value < 0x80 {
+        bits.write(value, 8);
+        return;
+    }
+    let mut continuation = 1;
+    while value >= 1u64 << (5 * continuation + 6) {
+        continuation += 1;
+    }
+    let prefix = (0xFFu64 << (7 - continuation)) & 0xFF;
+    bits.write(prefix | (value >> (6 * continuation)), 8);
+    for index in (0..continuation).rev() {
+        bits.write(0x80 | ((value >> (6 * index)) & 0x3F), 8);
+    }
+}
+
+/// Pipes f32 samples into `ffmpeg`, which writes the Opus file itself.
+struct OpusEncoder {
+    child: Child,
+    stdin: Option<BufWriter<ChildStdin>>,
+}
+
+impl OpusEncoder {
+    fn spawn(path: &Path, sample_rate_hz: u32) -> std::io::Result<Self> {
+        let mut child = Command::new("ffmpeg")
+            .args(["-loglevel", "error", "-y", "-f", "f32le", "-ar"])
+            .arg(sample_rate_hz.to_string())
+            .args([
+                "-ac",
+                "1",
+                "-i",
+                "pipe:0",
+                "-c:a",
+                "libopus",
+                "-b:a",
+                OPUS_BITRATE,
+            ])
+            .arg(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| std::io::Error::other(format!("ffmpeg unavailable for opus: {e}")))?;
+        let stdin = child.stdin.take().map(BufWriter::new);
+        Ok(Self { child, stdin })
+    }
+
+    fn write_sample(&mut self, sample: f32) -> std::io::Result<()> {
+        match self.stdin.as_mut() {
+            Some(stdin) => stdin.write_all(&sample.to_le_bytes()),
+            None => Err(std::io::Error::other("opus encoder already finished")),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self.stdin.as_mut() {
+            Some(stdin) => stdin.flush(),
+            None => Ok(()),
+        }
+    }
+
+    fn finish(&mut self) -> std::io::Result<()> {
+        let Some(mut stdin) = self.stdin.take() else {
+            return Ok(());
+        };
+        stdin.flush()?;
+        drop(stdin);
+        let status = self.child.wait()?;
+        if !status.success() {
+            return Err(std::io::Error::other(format!(
+                "ffmpeg exited with {status}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for OpusEncoder {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+/// Final stage of the writer: where processed mixdown samples end up.
+enum ArchiveSink {
+    Raw(BufWriter<std::fs::File>),
+    Flac(Box<FlacEncoder<BufWriter<std::fs::File>>>),
+    Opus(OpusEncoder),
+}
+
+impl ArchiveSink {
+    fn write_sample(&mut self, sample: f32) -> std::io::Result<()> {
+        match self {
+            Self::Raw(file) => file.write_all(&sample.to_le_bytes()),
+            Self::Flac(encoder) => encoder.write_sample(sample),
+            Self::Opus(encoder) => encoder.write_sample(sample),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Raw(file) => file.flush(),
+            Self::Flac(encoder) => encoder.flush(),
+            Self::Opus(encoder) => encoder.flush(),
+        }
+    }
+
+    fn finish(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Raw(file) => file.flush(),
+            Self::Flac(encoder) => encoder.finish(),
+            Self::Opus(encoder) => encoder.finish(),
+        }
+    }
+}
+
 pub struct RawAudioWriter {
-    file: BufWriter<std::fs::File>,
+    sink: ArchiveSink,
+    tracks: Option<SourceTracks>,
     system: VecDeque<f32>,
     mic: VecDeque<f32>,
     pending_flush_samples: usize,
@@ -229,19 +685,57 @@ impl RawAudioWriter {
     const FLUSH_SAMPLES: usize = 48_000;
     const MISSING_SOURCE_TIMEOUT: Duration = Duration::from_millis(500);
 
-    pub fn new(file: std::fs::File, sample_rate_hz: u32, mixdown: MixdownConfig) -> Self {
-        Self {
-            file: BufWriter::new(file),
+    /// Write the mixdown to `path` as `format`; `Wav` appends raw f32 samples.
+    pub fn create(
+        format: ArchiveFormat,
+        path: &Path,
+        sample_rate_hz: u32,
+        mixdown: MixdownConfig,
+    ) -> std::io::Result<Self> {
+        let sink = match format {
+            ArchiveFormat::Wav => ArchiveSink::Raw(BufWriter::new(
+                std::fs::OpenOptions::new().append(true).open(path)?,
+            )),
+            ArchiveFormat::Flac => ArchiveSink::Flac(Box::new(FlacEncoder::new(
+                BufWriter::new(std::fs::File::create(path)?),
+                sample_rate_hz,
+            )?)),
+            ArchiveFormat::Opus => ArchiveSink::Opus(OpusEncoder::spawn(path, sample_rate_hz)?),
+        };
+        Ok(Self {
+            sink,
+            tracks: None,
             system: VecDeque::new(),
             mic: VecDeque::new(),
             pending_flush_samples: 0,
             last_system_at: None,
             last_mic_at: None,
             mixdown: MixdownProcessor::new(sample_rate_hz, &mixdown),
-        }
+        })
+    }
+
+    /// Move the denoise gate, if denoise is enabled; returns whether it was.
+    pub fn set_denoise_threshold(&mut self, threshold_dbfs: f32) -> bool {
+        let Some(denoise) = self.mixdown.denoise.as_mut() else {
+            return false;
+        };
+        denoise.set_threshold(threshold_dbfs);
+        true
+    }
+
+    /// Also record each source unmixed into its own file.
+    pub fn with_tracks(mut self, system: std::fs::File, mic: std::fs::File) -> Self {
+        self.tracks = Some(SourceTracks {
+            system: BufWriter::new(system),
+            mic: BufWriter::new(mic),
+        });
+        self
     }
 
     pub fn write_samples(&mut self, source: AudioSource, samples: &[f32]) -> std::io::Result<()> {
+        if let Some(tracks) = self.tracks.as_mut() {
+            tracks.write(source, samples)?;
+        }
         match source {
             AudioSource::System => {
                 self.system.extend(samples.iter().copied());
@@ -321,17 +815,26 @@ impl RawAudioWriter {
         self.mix_available()?;
         self.drain_remaining_source(AudioSource::System)?;
         self.drain_remaining_source(AudioSource::Microphone)?;
-        self.file.flush()?;
+        self.sink.flush()?;
+        if let Some(tracks) = self.tracks.as_mut() {
+            tracks.flush()?;
+        }
         self.pending_flush_samples = 0;
         Ok(())
     }
 
+    /// Flush and close the archive, completing any encoder trailer.
+    pub fn finish(mut self) -> std::io::Result<()> {
+        self.flush()?;
+        self.sink.finish()
+    }
+
     fn write_sample(&mut self, sample: f32) -> std::io::Result<()> {
         let processed = self.mixdown.process(sample);
-        self.file.write_all(&processed.to_le_bytes())?;
+        self.sink.write_sample(processed)?;
         self.pending_flush_samples += 1;
         if self.pending_flush_samples >= Self::FLUSH_SAMPLES {
-            self.file.flush()?;
+            self.sink.flush()?;
             self.pending_flush_samples = 0;
         }
         Ok(())
@@ -352,13 +855,22 @@ impl SharedRawAudioWriter {
 
     pub fn set(&self, writer: Option<RawAudioWriter>) {
         if let Ok(mut guard) = self.inner.lock() {
-            if let Some(existing) = guard.as_mut() {
-                let _ = existing.flush();
+            if let Some(existing) = guard.take() {
+                let _ = existing.finish();
             }
             *guard = writer;
         }
     }
 
+    /// Retune the active writer's denoise gate; false when there is no writer or denoise is off.
+    pub fn set_denoise_threshold(&self, threshold_dbfs: f32) -> bool {
+        self.inner.lock().is_ok_and(|mut guard| {
+            guard
+                .as_mut()
+                .is_some_and(|writer| writer.set_denoise_threshold(threshold_dbfs))
+        })
+    }
+
     pub fn write_samples(&self, source: AudioSource, samples: &[f32]) -> std::io::Result<()> {
         let mut guard = self
             .inner
@@ -391,8 +903,12 @@ pub fn spawn_raw_audio_writer(
 
 #[cfg(test)]
 mod tests {
-    use super::{MixdownProcessor, db_to_gain};
+    use super::{
+        ArchiveFormat, FlacEncoder, MixdownProcessor, RawAudioWriter, db_to_gain, time_stretch,
+    };
     use crate::config::MixdownConfig;
+    use koe_core::types::AudioSource;
+    use tempfile::tempdir;
 
     fn rms(samples: &[f32]) -> f32 {
         if samples.is_empty() {
@@ -501,4 +1017,68 @@ mod tests {
             .fold(0.0_f32, |acc, sample| acc.max(sample.abs()));
         assert!(max < 0.01);
     }
+
+    #[test]
+    fn time_stretch_interpolates_at_half_speed() {
+        let slowed = time_stretch(&[0.0, 1.0, 0.0], 0.5);
+        assert_eq!(slowed, vec![0.0, 0.5, 1.0, 0.5, 0.0, 0.0]);
+        assert_eq!(time_stretch(&[0.5; 300], 0.75).len(), 400);
+    }
+
+    #[test]
+    fn tracks_record_sources_unmixed() {
+        let temp = tempdir().unwrap();
+        let mix_path = temp.path().join("mix.raw");
+        let system_path = temp.path().join("system.raw");
+        let mic_path = temp.path().join("mic.raw");
+        std::fs::File::create(&mix_path).unwrap();
+        let mut writer = RawAudioWriter::create(
+            ArchiveFormat::Wav,
+            &mix_path,
+            48_000,
+            MixdownConfig::default(),
+        )
+        .unwrap()
+        .with_tracks(
+            std::fs::File::create(&system_path).unwrap(),
+            std::fs::File::create(&mic_path).unwrap(),
+        );
+
+        writer
+            .write_samples(AudioSource::System, &[0.5, 0.5])
+            .unwrap();
+        writer
+            .write_samples(AudioSource::Microphone, &[-0.25])
+            .unwrap();
+        writer.flush().unwrap();
+
+        let system = std::fs::read(&system_path).unwrap();
+        let mic = std::fs::read(&mic_path).unwrap();
+        assert_eq!(system.len(), 8);
+        assert_eq!(mic, (-0.25f32).to_le_bytes().to_vec());
+        assert_eq!(std::fs::read(&mix_path).unwrap().len(), 12);
+    }
+
+    #[test]
+    fn flac_archive_compresses_and_records_sample_count() {
+        let samples: Vec<f32> = (0..10_000)
+            .map(|i| (i as f32 * 440.0 * 2.0 * std::f32::consts::PI / 48_000.0).sin() * 0.5)
+            .collect();
+        let mut encoder = FlacEncoder::new(std::io::Cursor::new(Vec::new()), 48_000).unwrap();
+        for sample in &samples {
+            encoder.write_sample(*sample).unwrap();
+        }
+        encoder.finish().unwrap();
+        let bytes = encoder.out.into_inner();
+
+        assert_eq!(&bytes[..4], b"fLaC");
+        // Sample rate (20 bits), channels, bits per sample, then 36-bit total.
+        let packed = u64::from_be_bytes(bytes[18..26].try_into().unwrap());
+        assert_eq!(packed >> 44, 48_000);
+        assert_eq!((packed >> 36) & 0x1F, 15);
+        assert_eq!(packed & 0xF_FFFF_FFFF, samples.len() as u64);
+        assert_eq!(&bytes[42..44], &[0xFF, 0xF8]);
+        // Well under the 2 bytes per sample of 16-bit PCM.
+        assert!(bytes.len() < samples.len());
+    }
 }
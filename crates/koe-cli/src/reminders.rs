@@ -0,0 +1,262 @@
+use koe_core::types::{MeetingNotes, NoteBullet, NoteKind};
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, Weekday};
+
+/// Local calendar day of an RFC 3339 UTC timestamp such as a session's `start_time`.
+pub fn meeting_date(start_time: &str) -> Option<Date> {
+    let year: i32 = start_time.get(0..4)?.parse().ok()?;
+    let month: u8 = start_time.get(5..7)?.parse().ok()?;
+    let day: u8 = start_time.get(8..10)?.parse().ok()?;
+    let hour: u8 = start_time.get(11..13)?.parse().ok()?;
+    let minute: u8 = start_time.get(14..16)?.parse().ok()?;
+    let date = Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()?;
+    let utc = PrimitiveDateTime::new(date, Time::from_hms(hour, minute, 0).ok()?).assume_utc();
+    Some(utc.to_offset(crate::calendar::local_offset()).date())
+}
+
+/// Rewrite every due value that `parse_due` understands as an ISO date;
+/// anything else is left as written.
+pub fn normalize_dues(notes: &mut MeetingNotes, meeting: Date) {
+    for bullet in &mut notes.bullets {
+        if let Some(date) = bullet
+            .due
+            .as_deref()
+            .and_then(|due| parse_due(due, meeting))
+        {
+            bullet.due = Some(date.to_string());
+        }
+    }
+}
+
+/// Date for free-text due values like `Friday`, `next week`, `in 3 days`, or
+/// `2026-11-02`, relative to the meeting day. Weeks start on Monday.
+pub fn parse_due(value: &str, meeting: Date) -> Option<Date> {
+    let value = value
+        .trim()
+        .trim_end_matches(['.', '!'])
+        .to_lowercase()
+        .replace("end of", "eo");
+    let value = ["by ", "on ", "due ", "before "]
+        .iter()
+        .find_map(|prefix| value.strip_prefix(prefix))
+        .unwrap_or(&value)
+        .trim();
+    if let Some(date) = iso_date(value) {
+        return Some(date);
+    }
+    let monday = meeting - Duration::days(i64::from(meeting.weekday().number_days_from_monday()));
+    match value {
+        "today" | "eod" | "eo day" | "eo today" => return Some(meeting),
+        "tomorrow" => return Some(meeting + Duration::days(1)),
+        "this week" | "eow" | "eo week" | "eo the week" => {
+            return Some((monday + Duration::days(4)).max(meeting));
+        }
+        "next week" => return Some(monday + Duration::weeks(1)),
+        "eom" | "eo month" | "eo the month" => return last_of_month(meeting),
+        "next month" => return last_of_month(meeting).map(|last| last + Duration::days(1)),
+        _ => {}
+    }
+    if let Some(rest) = value.strip_prefix("in ") {
+        let (count, unit) = rest.split_once(' ')?;
+        let count: i64 = match count {
+            "a" | "one" => 1,
+            "two" => 2,
+            "three" => 3,
+            count => count.parse().ok()?,
+        };
+        return match unit.trim_end_matches('s') {
+            "day" => Some(meeting + Duration::days(count)),
+            "week" => Some(meeting + Duration::weeks(count)),
+            _ => None,
+        };
+    }
+    if let Some(day) = value.strip_prefix("next ").and_then(weekday) {
+        let offset = 7 + i64::from(day.number_days_from_monday());
+        return Some(monday + Duration::days(offset));
+    }
+    let day = weekday(value.strip_prefix("this ").unwrap_or(value))?;
+    let ahead = (7 + i64::from(day.number_days_from_monday())
+        - i64::from(meeting.weekday().number_days_from_monday()))
+        % 7;
+    Some(meeting + Duration::days(if ahead == 0 { 7 } else { ahead }))
+}
+
+fn iso_date(value: &str) -> Option<Date> {
+    let mut parts = value.splitn(3, '-');
+    let year: i32 = parts.next()?.parse().ok()?;
+    let month: u8 = parts.next()?.parse().ok()?;
+    let day: u8 = parts.next()?.parse().ok()?;
+    Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()
+}
+
+/// Full or abbreviated (at least three letters) weekday name.
+fn weekday(value: &str) -> Option<Weekday> {
+    const DAYS: [(&str, Weekday); 7] = [
+        ("monday", Weekday::Monday),
+        ("tuesday", Weekday::Tuesday),
+        ("wednesday", Weekday::Wednesday),
+        ("thursday", Weekday::Thursday),
+        ("friday", Weekday::Friday),
+        ("saturday", Weekday::Saturday),
+        ("sunday", Weekday::Sunday),
+    ];
+    if value.len() < 3 {
+        return None;
+    }
+    DAYS.iter()
+        .find(|(name, _)| name.starts_with(value))
+        .map(|(_, day)| *day)
+}
+
+fn last_of_month(date: Date) -> Option<Date> {
+    date.replace_day(date.month().length(date.year())).ok()
+}
+
+/// Action items as iCalendar `VTODO`s for Reminders, Calendar, and other task apps.
+pub fn action_items_ics(notes: &MeetingNotes, session_id: &str, stamp: &str) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//koe//action items//EN".to_string(),
+    ];
+    for bullet in action_items(notes) {
+        lines.push("BEGIN:VTODO".to_string());
+        lines.push(format!("UID:{session_id}-{}@koe", bullet.id));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        lines.push(format!("SUMMARY:{}", ics_text(&bullet.text)));
+        if let Some(date) = bullet.due.as_deref().and_then(iso_date) {
+            lines.push(format!(
+                "DUE;VALUE=DATE:{:04}{:02}{:02}",
+                date.year(),
+                u8::from(date.month()),
+                date.day()
+            ));
+        }
+        if let Some(owner) = &bullet.owner {
+            lines.push(format!(
+                "DESCRIPTION:{}",
+                ics_text(&format!("Owner: {owner}"))
+            ));
+        }
+        let status = if bullet.done {
+            "COMPLETED"
+        } else {
+            "NEEDS-ACTION"
+        };
+        lines.push(format!("STATUS:{status}"));
+        lines.push("END:VTODO".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+    let mut output = lines.join("\r\n");
+    output.push_str("\r\n");
+    output
+}
+
+/// Action items as a TaskPaper project, which Things, OmniFocus, and Reminders
+/// accept when pasted or imported.
+pub fn action_items_taskpaper(notes: &MeetingNotes, project: &str) -> String {
+    let mut output = format!("{}:\n", project.replace(':', " -"));
+    for bullet in action_items(notes) {
+        output.push_str(&format!("\t- {}", bullet.text.replace('\n', " ").trim()));
+        if let Some(owner) = &bullet.owner {
+            output.push_str(&format!(" @owner({})", taskpaper_value(owner)));
+        }
+        if let Some(due) = &bullet.due {
+            output.push_str(&format!(" @due({})", taskpaper_value(due)));
+        }
+        if bullet.done {
+            output.push_str(" @done");
+        }
+        output.push('\n');
+    }
+    output
+}
+
+pub fn action_items(notes: &MeetingNotes) -> impl Iterator<Item = &NoteBullet> {
+    notes
+        .bullets
+        .iter()
+        .filter(|bullet| bullet.kind == NoteKind::Action)
+}
+
+/// UTC `DTSTAMP` value, e.g. `20261014T100000Z`.
+pub fn ics_stamp(now: OffsetDateTime) -> String {
+    let now = now.to_offset(time::UtcOffset::UTC);
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        now.year(),
+        u8::from(now.month()),
+        now.day(),
+        now.hour(),
+        now.minute(),
+        now.second()
+    )
+}
+
+fn ics_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn taskpaper_value(value: &str) -> String {
+    value.replace(['(', ')'], "").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action_items_ics, action_items_taskpaper, normalize_dues, parse_due};
+    use crate::test_support::bullet;
+    use koe_core::types::{MeetingNotes, NoteBullet, NoteKind};
+    use time::{Date, Month};
+
+    fn date(month: Month, day: u8) -> Date {
+        Date::from_calendar_date(2026, month, day).unwrap()
+    }
+
+    #[test]
+    fn due_values_normalize_relative_to_the_meeting_and_export() {
+        // Wednesday.
+        let meeting = date(Month::October, 14);
+        for (value, expected) in [
+            ("Friday", date(Month::October, 16)),
+            ("by wed", date(Month::October, 21)),
+            ("next Monday", date(Month::October, 19)),
+            ("next week", date(Month::October, 19)),
+            ("tomorrow", date(Month::October, 15)),
+            ("end of month", date(Month::October, 31)),
+            ("in 2 weeks", date(Month::October, 28)),
+            ("2026-11-02", date(Month::November, 2)),
+        ] {
+            assert_eq!(parse_due(value, meeting), Some(expected), "{value}");
+        }
+        assert_eq!(parse_due("after the launch", meeting), None);
+        assert_eq!(parse_due("sunset review", meeting), None);
+
+        let note = |id: &str, kind: NoteKind, due: &str| NoteBullet {
+            owner: Some("Sam".to_string()),
+            due: Some(due.to_string()),
+            ..bullet(id, &format!("send deck, v{id}"), kind)
+        };
+        let mut notes = MeetingNotes {
+            bullets: vec![
+                note("1", NoteKind::Action, "Friday"),
+                note("2", NoteKind::Decision, "Friday"),
+                note("3", NoteKind::Action, "after the launch"),
+            ],
+        };
+        normalize_dues(&mut notes, meeting);
+        assert_eq!(notes.bullets[0].due.as_deref(), Some("2026-10-16"));
+        assert_eq!(notes.bullets[2].due.as_deref(), Some("after the launch"));
+
+        let ics = action_items_ics(&notes, "s1", "20261014T100000Z");
+        assert_eq!(ics.matches("BEGIN:VTODO").count(), 2);
+        assert!(ics.contains("UID:s1-1@koe\r\n"));
+        assert!(ics.contains("SUMMARY:send deck\\, v1\r\nDUE;VALUE=DATE:20261016\r\n"));
+        assert_eq!(
+            action_items_taskpaper(&notes, "Sync"),
+            "Sync:\n\t- send deck, v1 @owner(Sam) @due(2026-10-16)\n\t- send deck, v3 @owner(Sam) @due(after the launch)\n"
+        );
+    }
+}
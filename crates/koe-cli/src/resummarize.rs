@@ -0,0 +1,293 @@
+use crate::RunArgs;
+use crate::audit::AuditLog;
+use crate::config::{Config, ConfigPaths};
+use crate::session::{SessionError, StoredSession};
+use clap::Args;
+use koe_core::summarize::diff::{NotesDiff, diff_notes};
+use koe_core::summarize::summary::generate_summary;
+use koe_core::types::{MeetingNotes, NoteBullet, TranscriptSegment};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Args, Debug, Clone)]
+pub struct ResummarizeArgs {
+    /// Session id or directory (defaults to the newest session)
+    pub session: Option<String>,
+
+    /// Notes JSON to compare against instead of the session's own notes,
+    /// e.g. an earlier `notes-<provider>-<model>.json`
+    #[arg(long, value_name = "path")]
+    pub baseline: Option<PathBuf>,
+
+    /// Also regenerate the end-of-meeting summary as `summary-<provider>-<model>.md`
+    #[arg(long)]
+    pub summary: bool,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Debug, Error)]
+pub enum ResummarizeError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{0}")]
+    Message(String),
+}
+
+/// Summarize a stored transcript again with the selected model and write the
+/// new notes plus a diff against the baseline into the session dir. Earlier
+/// runs are kept: a repeat with the same model gets a `-v2`, `-v3`, ... label.
+pub fn run(
+    args: &ResummarizeArgs,
+    paths: &ConfigPaths,
+    config: &Config,
+) -> Result<(), ResummarizeError> {
+    let run = args
+        .run
+        .clone()
+        .resolve(config)
+        .map_err(ResummarizeError::Message)?;
+    let (session_paths, _) = crate::session_storage(paths, config, run.workspace.as_deref());
+    let session = find_session(&session_paths.sessions_dir, args.session.as_deref())?;
+    let segments = session.transcript()?;
+    if segments.is_empty() {
+        return Err(ResummarizeError::Message(format!(
+            "session {} has no transcript",
+            session.metadata.id
+        )));
+    }
+    let before: MeetingNotes = match &args.baseline {
+        Some(path) => serde_json::from_str(&fs::read_to_string(path)?)?,
+        None => session.notes()?,
+    };
+
+    let context = run
+        .context
+        .clone()
+        .or_else(|| session.metadata.context.clone());
+    let participants = if run.participants.is_empty() {
+        session.metadata.participants.clone()
+    } else {
+        run.participants.clone()
+    };
+    let profile = run.summarize_profiles.active_profile();
+    let label = versioned_label(
+        &session.dir,
+        &file_label(&format!("{}-{}", profile.provider, profile.model)),
+    );
+    let mut provider = crate::create_summarize_for_mode(
+        &run.summarize_profiles,
+        run.summarize_profiles.active.as_str(),
+    )
+    .map_err(|e| ResummarizeError::Message(format!("summarize init failed: {e}")))?;
+    AuditLog::new(paths).record(
+        "resummarize",
+        &format!("summarize {}/{}", profile.provider, profile.model),
+        Some(&session.metadata.id),
+    )?;
+
+    let after = crate::transcribe_file::summarize_transcript(
+        provider.as_mut(),
+        &segments,
+        context.as_deref(),
+        &participants,
+        &mut |_| Ok(()),
+    )?;
+    let diff = diff_notes(&before, &after);
+    let summary = if args.summary {
+        let summary = generate_summary(
+            provider.as_mut(),
+            &segments,
+            context.as_deref(),
+            &participants,
+        )
+        .map_err(|e| ResummarizeError::Message(format!("summary failed: {e}")))?;
+        session.write_artifact(
+            &format!("summary-{label}.json"),
+            serde_json::to_string_pretty(&summary)?.as_bytes(),
+        )?;
+        Some(session.write_artifact(
+            &format!("summary-{label}.md"),
+            crate::session::render_summary(&summary).as_bytes(),
+        )?)
+    } else {
+        None
+    };
+
+    let notes_path = session.write_artifact(
+        &format!("notes-{label}.json"),
+        serde_json::to_string_pretty(&after)?.as_bytes(),
+    )?;
+    session.write_artifact(
+        &format!("notes-diff-{label}.json"),
+        serde_json::to_string_pretty(&diff)?.as_bytes(),
+    )?;
+    let diff_path = session.write_artifact(
+        &format!("notes-diff-{label}.md"),
+        diff_markdown(&diff, &segments).as_bytes(),
+    )?;
+
+    eprintln!(
+        "{} added, {} removed, {} changed, {} unchanged",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len(),
+        diff.unchanged.len()
+    );
+    println!("{}", notes_path.display());
+    println!("{}", diff_path.display());
+    if let Some(path) = summary {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+/// Session by id or directory, or the newest one when none is given.
+fn find_session(sessions_dir: &Path, id: Option<&str>) -> Result<StoredSession, ResummarizeError> {
+    crate::session::find_session(sessions_dir, id)?.ok_or_else(|| {
+        ResummarizeError::Message(match id {
+            Some(id) => format!("session not found: {id}"),
+            None => "no sessions recorded yet".to_string(),
+        })
+    })
+}
+
+/// `provider-model` reduced to characters safe in a file name.
+fn file_label(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '.' {
+                ch
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// `label`, or `label-vN` for the first N from 2 without notes in `dir` yet.
+fn versioned_label(dir: &Path, label: &str) -> String {
+    let taken = |label: &str| dir.join(format!("notes-{label}.json")).exists();
+    if !taken(label) {
+        return label.to_string();
+    }
+    (2..)
+        .map(|version| format!("{label}-v{version}"))
+        .find(|label| !taken(label))
+        .unwrap_or_else(|| label.to_string())
+}
+
+/// Added, removed, and changed bullets, each followed by its evidence segments.
+fn diff_markdown(diff: &NotesDiff, segments: &[TranscriptSegment]) -> String {
+    let by_id: HashMap<u64, &TranscriptSegment> =
+        segments.iter().map(|seg| (seg.id, seg)).collect();
+    let mut output = String::from("# Notes diff\n\n");
+    output.push_str(&format!(
+        "{} added, {} removed, {} changed, {} unchanged\n",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len(),
+        diff.unchanged.len()
+    ));
+
+    output.push_str("\n## Added\n\n");
+    push_bullets(&mut output, &diff.added, &by_id);
+    output.push_str("\n## Removed\n\n");
+    push_bullets(&mut output, &diff.removed, &by_id);
+    output.push_str("\n## Changed\n\n");
+    if diff.changed.is_empty() {
+        output.push_str("- (none)\n");
+    }
+    for change in &diff.changed {
+        output.push_str(&format!("- before: {}\n", change.before.text));
+        output.push_str(&format!("  after: {}\n", change.after.text));
+        push_evidence(&mut output, &change.after.evidence, &by_id);
+    }
+    output
+}
+
+fn push_bullets(
+    output: &mut String,
+    bullets: &[NoteBullet],
+    by_id: &HashMap<u64, &TranscriptSegment>,
+) {
+    if bullets.is_empty() {
+        output.push_str("- (none)\n");
+    }
+    for bullet in bullets {
+        output.push_str(&format!("- {}\n", bullet.text));
+        push_evidence(output, &bullet.evidence, by_id);
+    }
+}
+
+fn push_evidence(output: &mut String, evidence: &[u64], by_id: &HashMap<u64, &TranscriptSegment>) {
+    for id in evidence {
+        match by_id.get(id) {
+            Some(seg) => output.push_str(&format!(
+                "  > [{id}] {}: {}\n",
+                seg.speaker.as_deref().unwrap_or("Unknown"),
+                seg.text
+            )),
+            None => output.push_str(&format!("  > [{id}]\n")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff_markdown, file_label, versioned_label};
+    use crate::test_support::bullet;
+    use koe_core::summarize::diff::diff_notes;
+    use koe_core::types::{MeetingNotes, NoteBullet, NoteKind, TranscriptSegment};
+
+    #[test]
+    fn diff_markdown_quotes_evidence_segments() {
+        let segments = vec![TranscriptSegment {
+            id: 7,
+            start_ms: 0,
+            end_ms: 1_000,
+            speaker: Some("Them".to_string()),
+            text: "we hire a designer in May".to_string(),
+            finalized: true,
+            confidence: None,
+        }];
+        let before = MeetingNotes::default();
+        let after = MeetingNotes {
+            bullets: vec![NoteBullet {
+                evidence: vec![7],
+                ..bullet("n7", "Hire a designer in May", NoteKind::Point)
+            }],
+        };
+        let markdown = diff_markdown(&diff_notes(&before, &after), &segments);
+
+        assert!(markdown.contains("1 added, 0 removed, 0 changed, 0 unchanged"));
+        assert!(
+            markdown
+                .contains("- Hire a designer in May\n  > [7] Them: we hire a designer in May\n")
+        );
+        assert!(markdown.contains("## Removed\n\n- (none)\n"));
+        assert_eq!(
+            file_label("openrouter-google/gemini-2.5"),
+            "openrouter-google-gemini-2.5"
+        );
+    }
+
+    #[test]
+    fn repeat_runs_get_versioned_labels() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(versioned_label(dir.path(), "ollama-qwen"), "ollama-qwen");
+        std::fs::write(dir.path().join("notes-ollama-qwen.json"), "{}").unwrap();
+        assert_eq!(versioned_label(dir.path(), "ollama-qwen"), "ollama-qwen-v2");
+        std::fs::write(dir.path().join("notes-ollama-qwen-v2.json"), "{}").unwrap();
+        assert_eq!(versioned_label(dir.path(), "ollama-qwen"), "ollama-qwen-v3");
+    }
+}
@@ -0,0 +1,238 @@
+use crate::RunArgs;
+use crate::audit::AuditLog;
+use crate::config::{Config, ConfigPaths};
+use crate::session::{SessionError, StoredSession};
+use crate::transcribe_file::{
+    PIPELINE_SAMPLE_RATE, TranscribeFileError, decode_audio_file, resample_linear,
+    transcribe_samples,
+};
+use clap::Args;
+use koe_core::TranscribeError;
+use koe_core::transcribe::create_transcribe_provider;
+use koe_core::transcript::TranscriptLedger;
+use koe_core::types::AudioSource;
+use std::fs;
+use std::io;
+use thiserror::Error;
+
+#[derive(Args, Debug, Clone)]
+pub struct RetranscribeArgs {
+    /// Session id or directory (defaults to the newest session)
+    pub session: Option<String>,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Debug, Error)]
+pub enum RetranscribeError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("transcribe error: {0}")]
+    Transcribe(#[from] TranscribeError),
+    #[error("{0}")]
+    File(#[from] TranscribeFileError),
+    #[error("{0}")]
+    Message(String),
+}
+
+/// Transcribe a stored session's audio again with the selected model, store
+/// the result as the next `transcript-vN` file, and refresh `transcript.md`.
+pub fn run(
+    args: &RetranscribeArgs,
+    paths: &ConfigPaths,
+    config: &Config,
+) -> Result<(), RetranscribeError> {
+    let mut run = args
+        .run
+        .clone()
+        .resolve(config)
+        .map_err(RetranscribeError::Message)?;
+    if run.transcribe_profiles.active_profile().provider == "whisper" {
+        let profile = run.transcribe_profiles.active_profile_mut();
+        crate::ensure_whisper_model(&mut profile.model, &paths.models_dir)
+            .map_err(RetranscribeError::Message)?;
+    }
+
+    let (session_paths, export_dir) =
+        crate::session_storage(paths, config, run.workspace.as_deref());
+    let stored =
+        crate::session::find_session(&session_paths.sessions_dir, args.session.as_deref())?
+            .ok_or_else(|| {
+                RetranscribeError::Message(match &args.session {
+                    Some(id) => format!("session not found: {id}"),
+                    None => "no sessions recorded yet".to_string(),
+                })
+            })?;
+    let tracks = session_tracks(&stored)?;
+    if tracks.iter().all(|(_, samples)| samples.is_empty()) {
+        return Err(RetranscribeError::Message(format!(
+            "session {} has no recorded audio",
+            stored.metadata.id
+        )));
+    }
+
+    let profile = run.transcribe_profiles.active_profile().clone();
+    let mut transcribe = create_transcribe_provider(
+        profile.provider.as_str(),
+        Some(profile.model.as_str()),
+        crate::non_empty_str(profile.api_key.as_str()),
+    )?;
+    AuditLog::new(paths).record(
+        "retranscribe",
+        &format!("transcribe {}/{}", profile.provider, profile.model),
+        Some(&stored.metadata.id),
+    )?;
+
+    let mut ledger = TranscriptLedger::new();
+    for (source, samples) in &tracks {
+        ledger.append(transcribe_samples(
+            transcribe.as_mut(),
+            samples,
+            *source,
+            config,
+            paths,
+            &mut |_| Ok(()),
+        )?);
+    }
+    let segments = ledger.segments().to_vec();
+    if segments.is_empty() {
+        return Err(RetranscribeError::Message(
+            "no speech transcribed; the current transcript is unchanged".to_string(),
+        ));
+    }
+
+    let mut session = stored.reopen(
+        &session_paths,
+        export_dir,
+        config.session.low_confidence_threshold,
+    )?;
+    let path = session.replace_transcript(&segments, profile.provider, profile.model)?;
+    session.export_transcript_markdown(&segments)?;
+    eprintln!(
+        "notes still cite the previous transcript; run `koe summarize {}` to rebuild them",
+        session.metadata().id
+    );
+    println!("{}", path.display());
+    Ok(())
+}
+
+/// Recorded audio as 48 kHz mono per capture source: the per-source tracks when
+/// the session kept them, so segments keep their Me/Them labels, else the mixdown.
+fn session_tracks(
+    session: &StoredSession,
+) -> Result<Vec<(AudioSource, Vec<f32>)>, RetranscribeError> {
+    let tracks = session.audio_tracks();
+    if tracks.is_empty() {
+        return Ok(vec![(AudioSource::Mixed, session_samples(session)?)]);
+    }
+    tracks
+        .into_iter()
+        .map(|(source, path)| {
+            let bytes = fs::read(path)?;
+            Ok((
+                source,
+                raw_samples(&bytes, 1, session.metadata.audio_sample_rate_hz),
+            ))
+        })
+        .collect()
+}
+
+/// The recorded mixdown as 48 kHz mono: the compressed archive when there is
+/// one, else `audio.raw` (interleaved f32 at the session's rate).
+fn session_samples(session: &StoredSession) -> Result<Vec<f32>, RetranscribeError> {
+    if let Some(file) = &session.metadata.audio_archive_file {
+        return Ok(decode_audio_file(&session.dir.join(file), &session.dir)?);
+    }
+    let bytes = fs::read(session.dir.join(&session.metadata.audio_raw_file))?;
+    Ok(raw_samples(
+        &bytes,
+        session.metadata.audio_channels,
+        session.metadata.audio_sample_rate_hz,
+    ))
+}
+
+/// Interleaved little-endian f32 at `sample_rate` downmixed and resampled to
+/// the pipeline rate.
+fn raw_samples(bytes: &[u8], channels: u16, sample_rate: u32) -> Vec<f32> {
+    let channels = usize::from(channels.max(1));
+    let mono: Vec<f32> = bytes
+        .chunks_exact(4 * channels)
+        .map(|frame| {
+            frame
+                .chunks_exact(4)
+                .map(|sample| f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]))
+                .sum::<f32>()
+                / channels as f32
+        })
+        .collect();
+    resample_linear(&mono, sample_rate, PIPELINE_SAMPLE_RATE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{session_samples, session_tracks};
+    use crate::session::{SessionMetadata, SessionMetadataInput, StoredSession};
+    use crate::test_support::session_input;
+    use koe_core::types::AudioSource;
+
+    #[test]
+    fn raw_audio_is_downmixed_and_resampled_to_the_pipeline_rate() {
+        let dir = tempfile::tempdir().unwrap();
+        let metadata = SessionMetadata::new(SessionMetadataInput {
+            audio_sample_rate_hz: 24_000,
+            audio_channels: 2,
+            audio_sources: vec!["system".to_string(), "microphone".to_string()],
+            ..session_input()
+        })
+        .unwrap();
+        let frames: Vec<u8> = (0..240)
+            .flat_map(|_| [0.5f32, -0.1f32])
+            .flat_map(f32::to_le_bytes)
+            .collect();
+        std::fs::write(dir.path().join(&metadata.audio_raw_file), frames).unwrap();
+
+        let samples = session_samples(&StoredSession {
+            dir: dir.path().to_path_buf(),
+            metadata,
+        })
+        .unwrap();
+        assert_eq!(samples.len(), 480);
+        assert!(samples.iter().all(|sample| (sample - 0.2).abs() < 1e-6));
+    }
+
+    #[test]
+    fn per_source_tracks_keep_their_capture_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut metadata = SessionMetadata::new(SessionMetadataInput {
+            audio_sources: vec!["system".to_string(), "microphone".to_string()],
+            ..session_input()
+        })
+        .unwrap();
+        metadata.audio_track_files = vec![
+            "audio-system-abc.raw".to_string(),
+            "audio-microphone-abc.raw".to_string(),
+        ];
+        for (file, level) in metadata.audio_track_files.iter().zip([0.25f32, -0.5]) {
+            let frames: Vec<u8> = (0..96).flat_map(|_| level.to_le_bytes()).collect();
+            std::fs::write(dir.path().join(file), frames).unwrap();
+        }
+
+        let tracks = session_tracks(&StoredSession {
+            dir: dir.path().to_path_buf(),
+            metadata,
+        })
+        .unwrap();
+        let sources: Vec<AudioSource> = tracks.iter().map(|(source, _)| *source).collect();
+        assert_eq!(sources, vec![AudioSource::System, AudioSource::Microphone]);
+        assert!(
+            tracks[0]
+                .1
+                .iter()
+                .all(|sample| (sample - 0.25).abs() < 1e-6)
+        );
+        assert!(tracks[1].1.iter().all(|sample| (sample + 0.5).abs() < 1e-6));
+    }
+}
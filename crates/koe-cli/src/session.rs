@@ -1,10 +1,20 @@
+use crate::audit::AuditLog;
 use crate::config::ConfigPaths;
-use koe_core::types::{MeetingNotes, TranscriptSegment};
+use crate::raw_audio::{ArchiveFormat, time_stretch};
+use koe_core::transcript::TranscriptLedger;
+use koe_core::types::{
+    AudioSource, Chapter, Flashcard, LectureNotes, MeetingNotes, MeetingSummary, NoteBullet,
+    OutlineItem, ShowNotes, TranscriptSegment,
+};
+use koe_core::usage::UsageReport;
 use serde::{Deserialize, Serialize};
-use std::fs::{self, OpenOptions};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File, OpenOptions};
 use std::io;
-use std::io::Write;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
@@ -17,6 +27,12 @@ const CONTEXT_PREFIX: &str = "context";
 const AUDIO_PREFIX: &str = "audio";
 const TRANSCRIPT_PREFIX: &str = "transcript";
 const NOTES_PREFIX: &str = "notes";
+const NOTES_ARCHIVE_PREFIX: &str = "notes-archive";
+const TRACK_SYSTEM_PREFIX: &str = "audio-system";
+const TRACK_MIC_PREFIX: &str = "audio-microphone";
+const CLIP_MARGIN_MS: i64 = 250;
+/// How often `FsyncPolicy::Interval` syncs the transcript to disk.
+const FSYNC_INTERVAL: Duration = Duration::from_secs(5);
 
 #[derive(Debug, Error)]
 pub enum SessionError {
@@ -28,6 +44,8 @@ pub enum SessionError {
     Json(#[from] serde_json::Error),
     #[error("session time error: {0}")]
     Time(#[from] time::error::Format),
+    #[error("session metadata parse error: {0}")]
+    Parse(#[from] toml::de::Error),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +66,27 @@ pub struct SessionMetadata {
     pub context_file: String,
     pub audio_raw_file: String,
     pub audio_wav_file: String,
+    /// Compressed mixdown (FLAC or Opus) written instead of `audio_raw_file`.
+    #[serde(default)]
+    pub audio_archive_file: Option<String>,
+    #[serde(default)]
+    pub audio_track_files: Vec<String>,
+    /// Capture pauses; transcript times exclude the paused spans.
+    #[serde(default)]
+    pub pauses: Vec<PauseEvent>,
+    /// Provider/model switches during the session, e.g. failover.
+    #[serde(default)]
+    pub changes: Vec<ChangeEvent>,
+    /// Noise-floor calibration taken at meeting start, when enabled.
+    #[serde(default)]
+    pub calibration: Option<Calibration>,
+    /// Latest `koe sessions score` result against a corrected reference.
+    #[serde(default)]
+    pub score: Option<TranscriptScore>,
+    /// Names given to speaker labels such as `Them` or `Speaker 2`; the stored
+    /// transcript keeps the labels and exports show the names.
+    #[serde(default)]
+    pub speaker_names: BTreeMap<String, String>,
     pub transcript_file: String,
     pub notes_file: String,
     pub transcribe_provider: String,
@@ -56,6 +95,47 @@ pub struct SessionMetadata {
     pub summarize_model: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeEvent {
+    pub at: String,
+    /// `transcribe` or `summarize`.
+    pub stage: String,
+    /// Previous and new `provider/model`.
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Calibration {
+    pub noise_floor_dbfs: f32,
+    pub vad_threshold: f32,
+    /// Mixdown denoise gate; absent when denoise is disabled.
+    pub denoise_threshold_dbfs: Option<f32>,
+}
+
+/// Error rates of the stored transcript against a corrected reference.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptScore {
+    pub reference_file: String,
+    pub scored_at: String,
+    /// Word error rate: (substitutions + deletions + insertions) / reference words.
+    pub wer: f64,
+    /// Character error rate over the same normalized text.
+    pub cer: f64,
+    pub reference_words: usize,
+    pub substitutions: usize,
+    pub deletions: usize,
+    pub insertions: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PauseEvent {
+    pub paused_at: String,
+    pub resumed_at: Option<String>,
+    /// Meeting-relative time of the pause.
+    pub meeting_ms: i64,
+}
+
 #[derive(Debug, Clone)]
 pub struct SessionMetadataInput {
     pub context: Option<String>,
@@ -72,19 +152,21 @@ pub struct SessionMetadataInput {
 #[derive(Debug, Clone)]
 pub struct SessionFactory {
     paths: ConfigPaths,
-    export_dir: Option<PathBuf>,
+    export_dir: Option<ExportDir>,
     audio_sample_rate_hz: u32,
     audio_channels: u16,
     audio_sources: Vec<String>,
+    low_confidence_threshold: f32,
 }
 
 impl SessionFactory {
     pub fn new(
         paths: ConfigPaths,
-        export_dir: Option<PathBuf>,
+        export_dir: Option<ExportDir>,
         audio_sample_rate_hz: u32,
         audio_channels: u16,
         audio_sources: Vec<String>,
+        low_confidence_threshold: f32,
     ) -> Self {
         Self {
             paths,
@@ -92,6 +174,7 @@ impl SessionFactory {
             audio_sample_rate_hz,
             audio_channels,
             audio_sources,
+            low_confidence_threshold,
         }
     }
 
@@ -115,15 +198,39 @@ impl SessionFactory {
             summarize_provider,
             summarize_model,
         })?;
-        SessionHandle::start(&self.paths, metadata, self.export_dir.clone())
+        let mut session = SessionHandle::start(&self.paths, metadata, self.export_dir.clone())?;
+        session.low_confidence_threshold = self.low_confidence_threshold;
+        Ok(session)
     }
 
     pub fn sessions_dir(&self) -> &Path {
         &self.paths.sessions_dir
     }
+
+    /// Folder of date-and-title named exports, when an export dir is set.
+    pub fn export_root(&self) -> Option<&Path> {
+        self.export_dir.as_ref().map(|dir| dir.root.as_path())
+    }
+
+    pub fn low_confidence_threshold(&self) -> f32 {
+        self.low_confidence_threshold
+    }
 }
 
 impl SessionMetadata {
+    /// `YYYY-MM-DD title` for listings; untitled sessions read `YYYY-MM-DD untitled`.
+    pub fn display_name(&self) -> String {
+        let date = self.start_time.get(..10).unwrap_or(&self.start_time);
+        format!("{date} {}", self.title.as_deref().unwrap_or("untitled"))
+    }
+
+    /// Whether the session carries `tag`, compared after [`parse_tags`] normalization.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        parse_tags(tag)
+            .first()
+            .is_some_and(|tag| self.tags.iter().any(|t| t == tag))
+    }
+
     pub fn new(input: SessionMetadataInput) -> Result<Self, SessionError> {
         let id = Uuid::now_v7().to_string();
         let start_time = OffsetDateTime::now_utc().format(&Rfc3339)?;
@@ -150,6 +257,13 @@ impl SessionMetadata {
             context_file,
             audio_raw_file,
             audio_wav_file,
+            audio_archive_file: None,
+            audio_track_files: Vec::new(),
+            pauses: Vec::new(),
+            changes: Vec::new(),
+            calibration: None,
+            score: None,
+            speaker_names: BTreeMap::new(),
             transcript_file,
             notes_file,
             transcribe_provider: input.transcribe_provider,
@@ -163,16 +277,19 @@ impl SessionMetadata {
 #[derive(Debug, Clone)]
 pub struct SessionHandle {
     dir: PathBuf,
-    export_dir: Option<PathBuf>,
+    export_dir: Option<ExportDir>,
     metadata_path: PathBuf,
     metadata: SessionMetadata,
+    notes_archived: usize,
+    low_confidence_threshold: f32,
+    audit: AuditLog,
 }
 
 impl SessionHandle {
     pub fn start(
         paths: &ConfigPaths,
         metadata: SessionMetadata,
-        export_dir: Option<PathBuf>,
+        export_dir: Option<ExportDir>,
     ) -> Result<Self, SessionError> {
         fs::create_dir_all(&paths.sessions_dir)?;
         let dir = paths.sessions_dir.join(&metadata.id);
@@ -193,15 +310,32 @@ impl SessionHandle {
         let notes_snapshot = NotesSnapshot {
             updated_at: OffsetDateTime::now_utc().format(&Rfc3339)?,
             state: MeetingNotes::default(),
+            archived: 0,
         };
         let notes_payload = serde_json::to_string_pretty(&notes_snapshot)?;
         write_atomic(&notes_path, notes_payload.as_bytes())?;
 
+        let audit = AuditLog::new(paths);
+        audit.record(
+            "session_start",
+            &format!(
+                "transcribe {}/{}, summarize {}/{}",
+                metadata.transcribe_provider,
+                metadata.transcribe_model,
+                metadata.summarize_provider,
+                metadata.summarize_model
+            ),
+            Some(&metadata.id),
+        )?;
+
         Ok(Self {
             dir,
             export_dir,
             metadata_path,
             metadata,
+            notes_archived: 0,
+            low_confidence_threshold: 0.0,
+            audit,
         })
     }
 
@@ -209,18 +343,97 @@ impl SessionHandle {
         &self.dir
     }
 
+    pub fn metadata(&self) -> &SessionMetadata {
+        &self.metadata
+    }
+
     pub fn audio_raw_path(&self) -> PathBuf {
         self.dir.join(&self.metadata.audio_raw_file)
     }
 
+    /// File holding the recorded mixdown: the compressed archive if any, else audio.raw.
+    pub fn audio_archive_path(&self) -> PathBuf {
+        match &self.metadata.audio_archive_file {
+            Some(file) => self.dir.join(file),
+            None => self.audio_raw_path(),
+        }
+    }
+
     pub fn export_transcript_path(&self) -> Result<PathBuf, SessionError> {
-        let root = self.export_root()?;
-        Ok(root.join("transcript.md"))
+        Ok(self.export_root()?.path("transcript.md"))
+    }
+
+    /// Link to `segments[index]` in the exported markdown transcript.
+    pub fn transcript_link(
+        &self,
+        segments: &[TranscriptSegment],
+        index: usize,
+    ) -> Result<Option<String>, SessionError> {
+        let path = self.export_transcript_path()?;
+        Ok(segment_anchors(segments)
+            .into_iter()
+            .nth(index)
+            .map(|anchor| format!("file://{}#{anchor}", path.display())))
     }
 
     pub fn export_notes_path(&self) -> Result<PathBuf, SessionError> {
-        let root = self.export_root()?;
-        Ok(root.join("notes.md"))
+        Ok(self.export_root()?.path("notes.md"))
+    }
+
+    /// Replace the meeting context mid-session, e.g. from the local HTTP API.
+    pub fn update_context(&mut self, context: &str) -> Result<(), SessionError> {
+        let context_path = self.dir.join(&self.metadata.context_file);
+        write_atomic(&context_path, context.as_bytes())?;
+        self.metadata.context = crate::non_empty_str(context).map(str::to_string);
+        self.touch_metadata()
+    }
+
+    /// Set the meeting title, e.g. from the calendar event at meeting start;
+    /// exports already written move to the new title's location.
+    pub fn update_title(&mut self, title: &str) -> Result<(), SessionError> {
+        self.reload_labels()?;
+        let previous = match &self.export_dir {
+            Some(export_dir) => export_dir.find(&self.metadata)?,
+            None => None,
+        };
+        self.metadata.title = crate::non_empty_str(title).map(str::to_string);
+        self.touch_metadata()?;
+        if let (Some(export_dir), Some(previous)) = (&self.export_dir, previous) {
+            export_dir.relocate(&self.metadata, &previous)?;
+        }
+        Ok(())
+    }
+
+    /// Replace the session's tags, keeping a title another handle wrote meanwhile.
+    pub fn update_tags(&mut self, tags: Vec<String>) -> Result<(), SessionError> {
+        self.reload_labels()?;
+        self.metadata.tags = tags;
+        self.touch_metadata()
+    }
+
+    /// Re-read the title and tags from disk, where another handle (the TUI or
+    /// the auto-title pass at finalize) may have changed them.
+    pub fn reload_labels(&mut self) -> Result<(), SessionError> {
+        let stored = StoredSession::load(&self.dir)?;
+        self.metadata.title = stored.metadata.title;
+        self.metadata.tags = stored.metadata.tags;
+        Ok(())
+    }
+
+    /// Show `label` as `name` in exports and add `name` to the participants;
+    /// naming a label after itself drops the mapping.
+    pub fn rename_speaker(&mut self, label: &str, name: &str) -> Result<(), SessionError> {
+        if label == name {
+            self.metadata.speaker_names.remove(label);
+        } else {
+            self.metadata
+                .speaker_names
+                .insert(label.to_string(), name.to_string());
+            if !self.metadata.participants.iter().any(|p| p == name) {
+                self.metadata.participants.push(name.to_string());
+            }
+        }
+        self.touch_metadata()
     }
 
     pub fn update_transcribe(
@@ -228,8 +441,17 @@ impl SessionHandle {
         provider: String,
         model: String,
     ) -> Result<(), SessionError> {
+        let from = format!(
+            "{}/{}",
+            self.metadata.transcribe_provider, self.metadata.transcribe_model
+        );
         self.metadata.transcribe_provider = provider;
         self.metadata.transcribe_model = model;
+        let to = format!(
+            "{}/{}",
+            self.metadata.transcribe_provider, self.metadata.transcribe_model
+        );
+        self.record_change("transcribe", from, to)?;
         self.touch_metadata()
     }
 
@@ -238,11 +460,39 @@ impl SessionHandle {
         provider: String,
         model: String,
     ) -> Result<(), SessionError> {
+        let from = format!(
+            "{}/{}",
+            self.metadata.summarize_provider, self.metadata.summarize_model
+        );
         self.metadata.summarize_provider = provider;
         self.metadata.summarize_model = model;
+        let to = format!(
+            "{}/{}",
+            self.metadata.summarize_provider, self.metadata.summarize_model
+        );
+        self.record_change("summarize", from, to)?;
         self.touch_metadata()
     }
 
+    /// Note a provider switch in metadata and the audit log; no-op when unchanged.
+    fn record_change(&mut self, stage: &str, from: String, to: String) -> Result<(), SessionError> {
+        if from == to {
+            return Ok(());
+        }
+        self.audit.record(
+            "provider_change",
+            &format!("{stage} {from} -> {to}"),
+            Some(&self.metadata.id),
+        )?;
+        self.metadata.changes.push(ChangeEvent {
+            at: OffsetDateTime::now_utc().format(&Rfc3339)?,
+            stage: stage.to_string(),
+            from,
+            to,
+        });
+        Ok(())
+    }
+
     pub fn is_finalized(&self) -> bool {
         self.metadata.finalized
     }
@@ -254,10 +504,105 @@ impl SessionHandle {
             .open(self.audio_raw_path())?)
     }
 
-    pub fn append_transcript(
+    /// Create per-source raw files (system, microphone) for multitrack recording.
+    /// Prepare the mixdown file for `format` and return its path. Compressed
+    /// formats replace the empty audio.raw created at session start.
+    pub fn open_audio_archive(&mut self, format: ArchiveFormat) -> Result<PathBuf, SessionError> {
+        if format == ArchiveFormat::Wav {
+            warn_if_loose_permissions(&self.audio_raw_path())?;
+            return Ok(self.audio_raw_path());
+        }
+        let file = file_name(AUDIO_PREFIX, format.extension(), &self.metadata.id);
+        let path = self.dir.join(&file);
+        fs::write(&path, [])?;
+        set_strict_permissions(&path)?;
+        let raw_path = self.audio_raw_path();
+        if fs::metadata(&raw_path).is_ok_and(|m| m.len() == 0) {
+            fs::remove_file(raw_path)?;
+        }
+        self.metadata.audio_archive_file = Some(file);
+        self.touch_metadata()?;
+        Ok(path)
+    }
+
+    pub fn open_audio_tracks(&mut self) -> Result<(fs::File, fs::File), SessionError> {
+        let system_file = file_name(TRACK_SYSTEM_PREFIX, "raw", &self.metadata.id);
+        let mic_file = file_name(TRACK_MIC_PREFIX, "raw", &self.metadata.id);
+        let mut files = Vec::with_capacity(2);
+        for name in [&system_file, &mic_file] {
+            let path = self.dir.join(name);
+            let file = OpenOptions::new().create(true).append(true).open(&path)?;
+            set_strict_permissions(&path)?;
+            files.push(file);
+        }
+        self.metadata.audio_track_files = vec![system_file, mic_file];
+        self.touch_metadata()?;
+        let mic = files
+            .pop()
+            .ok_or_else(|| io::Error::other("missing mic track"))?;
+        let system = files
+            .pop()
+            .ok_or_else(|| io::Error::other("missing system track"))?;
+        Ok((system, mic))
+    }
+
+    pub fn record_pause(&mut self, meeting_ms: i64) -> Result<(), SessionError> {
+        self.metadata.pauses.push(PauseEvent {
+            paused_at: OffsetDateTime::now_utc().format(&Rfc3339)?,
+            resumed_at: None,
+            meeting_ms,
+        });
+        self.touch_metadata()
+    }
+
+    pub fn record_calibration(&mut self, calibration: Calibration) -> Result<(), SessionError> {
+        self.metadata.calibration = Some(calibration);
+        self.touch_metadata()
+    }
+
+    pub fn record_resume(&mut self) -> Result<(), SessionError> {
+        let Some(pause) = self
+            .metadata
+            .pauses
+            .last_mut()
+            .filter(|pause| pause.resumed_at.is_none())
+        else {
+            return Ok(());
+        };
+        pause.resumed_at = Some(OffsetDateTime::now_utc().format(&Rfc3339)?);
+        self.touch_metadata()
+    }
+
+    /// Append segments outside the live pipeline (edits, file transcription);
+    /// live segments go through a `TranscriptWriter` on the transcribe thread.
+    /// Store a re-transcription as the next `transcript-vN` file and make it the
+    /// session transcript; earlier versions stay on disk.
+    pub fn replace_transcript(
         &mut self,
         segments: &[TranscriptSegment],
-    ) -> Result<(), SessionError> {
+        provider: String,
+        model: String,
+    ) -> Result<PathBuf, SessionError> {
+        let file = (2..)
+            .map(|version| {
+                file_name(
+                    &format!("{TRANSCRIPT_PREFIX}-v{version}"),
+                    "jsonl",
+                    &self.metadata.id,
+                )
+            })
+            .find(|file| !self.dir.join(file).exists())
+            .unwrap_or_default();
+        let path = self.dir.join(&file);
+        let mut contents = Vec::new();
+        write_transcript_lines(&mut contents, segments)?;
+        write_atomic(&path, &contents)?;
+        self.metadata.transcript_file = file;
+        self.update_transcribe(provider, model)?;
+        Ok(path)
+    }
+
+    pub fn append_transcript(&self, segments: &[TranscriptSegment]) -> Result<(), SessionError> {
         if segments.is_empty() {
             return Ok(());
         }
@@ -265,20 +610,14 @@ impl SessionHandle {
             .append(true)
             .open(self.transcript_path())?;
         warn_if_loose_permissions(&self.transcript_path())?;
-        for segment in segments {
-            let record = TranscriptRecord::from_segment(segment);
-            let line = serde_json::to_string(&record)?;
-            file.write_all(line.as_bytes())?;
-            file.write_all(b"\n")?;
-        }
-        self.touch_metadata()?;
-        Ok(())
+        write_transcript_lines(&mut file, segments)
     }
 
     pub fn write_notes(&mut self, state: &MeetingNotes) -> Result<(), SessionError> {
         let snapshot = NotesSnapshot {
             updated_at: OffsetDateTime::now_utc().format(&Rfc3339)?,
             state: state.clone(),
+            archived: self.notes_archived,
         };
         let payload = serde_json::to_string_pretty(&snapshot)?;
         write_atomic(&self.notes_path(), payload.as_bytes())?;
@@ -286,55 +625,358 @@ impl SessionHandle {
         Ok(())
     }
 
+    /// Number of bullets moved out of the live notes into the archive file.
+    pub fn notes_archived(&self) -> usize {
+        self.notes_archived
+    }
+
+    /// Append old bullets to the notes archive so the live notes stay small.
+    pub fn archive_notes(&mut self, bullets: &[NoteBullet]) -> Result<(), SessionError> {
+        let path = self.notes_archive_path();
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        set_strict_permissions(&path)?;
+        for bullet in bullets {
+            let line = serde_json::to_string(bullet)?;
+            file.write_all(line.as_bytes())?;
+            file.write_all(b"\n")?;
+        }
+        self.notes_archived += bullets.len();
+        Ok(())
+    }
+
+    /// Load archived bullets from disk, oldest first.
+    pub fn load_archived_notes(&self) -> Result<Vec<NoteBullet>, SessionError> {
+        read_jsonl(&self.notes_archive_path())
+    }
+
+    /// Archived bullets followed by the live ones.
+    pub fn full_notes(&self, live: &MeetingNotes) -> Result<MeetingNotes, SessionError> {
+        if self.notes_archived == 0 {
+            return Ok(live.clone());
+        }
+        let mut bullets = self.load_archived_notes()?;
+        bullets.extend(live.bullets.iter().cloned());
+        Ok(MeetingNotes { bullets })
+    }
+
     pub fn export_transcript_markdown(
         &self,
         segments: &[TranscriptSegment],
     ) -> Result<(), SessionError> {
         let export_root = self.export_root()?;
-        let path = export_root.join("transcript.md");
+        let path = export_root.path("transcript.md");
+        let segments = name_speakers(segments, &self.metadata.speaker_names);
+        let chapters = self.load_chapters()?;
         let mut output = String::from("# Transcript\n");
         if segments.is_empty() {
             output.push_str("- (empty)\n");
         } else {
-            for segment in segments {
+            let mut heading = None;
+            for (segment, anchor) in segments.iter().zip(segment_anchors(&segments)) {
+                if chapters.is_empty() {
+                    let block = segment.start_ms.max(0) / 60_000;
+                    if heading != Some(block as usize) {
+                        heading = Some(block as usize);
+                        output.push_str(&format!("\n## {block:02}:00\n\n"));
+                    }
+                } else {
+                    let index = chapters
+                        .partition_point(|chapter| chapter.start_ms <= segment.start_ms)
+                        .saturating_sub(1);
+                    if heading != Some(index) {
+                        heading = Some(index);
+                        let chapter = &chapters[index];
+                        output.push_str(&format!(
+                            "\n## {} ({}-{})\n\n",
+                            chapter.title,
+                            format_timestamp(chapter.start_ms),
+                            format_timestamp(chapter.end_ms)
+                        ));
+                    }
+                }
                 let start = format_timestamp(segment.start_ms);
                 let end = format_timestamp(segment.end_ms);
                 let speaker = segment.speaker.as_deref().unwrap_or("Unknown");
                 let text = segment.text.replace('\n', " ").trim().to_string();
-                output.push_str(&format!("- [{start}-{end}] {speaker}: {text}\n"));
+                let marker = if self.is_low_confidence(segment) {
+                    " (?)"
+                } else {
+                    ""
+                };
+                output.push_str(&format!(
+                    "- <a id=\"{anchor}\"></a>[{start}-{end}] {speaker}: {text}{marker}\n"
+                ));
             }
         }
         write_atomic(&path, output.as_bytes())?;
         Ok(())
     }
 
-    pub fn export_audio_wav(&self) -> Result<(), SessionError> {
-        let export_root = self.export_root()?;
-        let wav_path = export_root.join(&self.metadata.audio_wav_file);
+    /// Write `contents` as `name` next to the other exports, e.g. `minutes.pdf`.
+    pub fn export_file(&self, name: &str, contents: &[u8]) -> Result<PathBuf, SessionError> {
+        let path = self.export_root()?.path(name);
+        write_atomic(&path, contents)?;
+        Ok(path)
+    }
+
+    /// Write `transcript.html`: the transcript and `notes` around a player for
+    /// the exported audio, which is written first if missing.
+    pub fn export_html(
+        &self,
+        segments: &[TranscriptSegment],
+        notes: &MeetingNotes,
+    ) -> Result<PathBuf, SessionError> {
+        let audio_path = self.export_audio_path()?;
+        if !audio_path.exists() {
+            self.export_audio()?;
+        }
+        let audio_file = audio_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let title = self.metadata.title.clone().unwrap_or_else(|| {
+            format!(
+                "Meeting {}",
+                self.metadata.start_time.get(..10).unwrap_or_default()
+            )
+        });
+        let segments = name_speakers(segments, &self.metadata.speaker_names);
+        let path = self.export_root()?.path("transcript.html");
+        let html = crate::html::render_transcript_html(&title, &audio_file, &segments, notes);
+        write_atomic(&path, html.as_bytes())?;
+        Ok(path)
+    }
+
+    pub fn export_transcript_srt(
+        &self,
+        segments: &[TranscriptSegment],
+    ) -> Result<PathBuf, SessionError> {
+        let path = self.export_root()?.path("transcript.srt");
+        let segments = name_speakers(segments, &self.metadata.speaker_names);
+        write_atomic(&path, subtitles(&segments, SubtitleFormat::Srt).as_bytes())?;
+        Ok(path)
+    }
+
+    pub fn export_transcript_vtt(
+        &self,
+        segments: &[TranscriptSegment],
+    ) -> Result<PathBuf, SessionError> {
+        let path = self.export_root()?.path("transcript.vtt");
+        let segments = name_speakers(segments, &self.metadata.speaker_names);
+        write_atomic(&path, subtitles(&segments, SubtitleFormat::Vtt).as_bytes())?;
+        Ok(path)
+    }
+
+    /// Exported audio: the compressed archive when recorded, else a WAV.
+    pub fn export_audio_path(&self) -> Result<PathBuf, SessionError> {
+        let file = self
+            .metadata
+            .audio_archive_file
+            .as_ref()
+            .unwrap_or(&self.metadata.audio_wav_file);
+        Ok(self.export_root()?.path(file))
+    }
+
+    pub fn export_audio(&self) -> Result<(), SessionError> {
+        let export_path = self.export_audio_path()?;
+        if self.metadata.audio_archive_file.is_some() {
+            let archive_path = self.audio_archive_path();
+            if archive_path != export_path {
+                write_replacing(&export_path, |tmp_path| {
+                    fs::copy(&archive_path, tmp_path)?;
+                    set_strict_permissions(tmp_path)
+                })?;
+            }
+            return Ok(());
+        }
         let raw_path = self.audio_raw_path();
-        write_wav_from_raw(
-            &raw_path,
-            &wav_path,
+        write_replacing(&export_path, |tmp_path| {
+            write_wav_from_raw(
+                &raw_path,
+                tmp_path,
+                self.metadata.audio_sample_rate_hz,
+                self.metadata.audio_channels,
+            )
+        })
+    }
+
+    /// Write the mixdown audio under `segment` (plus a short margin), slowed to
+    /// `speed`, to `clip.wav` in the session dir for review playback.
+    pub fn segment_clip(
+        &self,
+        segment: &TranscriptSegment,
+        speed: f32,
+    ) -> Result<PathBuf, SessionError> {
+        if self.metadata.audio_archive_file.is_some() {
+            return Err(io::Error::other(
+                "segment playback needs raw audio; set audio.archive_format = wav",
+            )
+            .into());
+        }
+        let rate = i64::from(self.metadata.audio_sample_rate_hz);
+        let channels = usize::from(self.metadata.audio_channels.max(1));
+        let frame_bytes = 4 * channels as u64;
+        let start = (segment.start_ms - CLIP_MARGIN_MS).max(0) * rate / 1000;
+        let end = (segment.end_ms + CLIP_MARGIN_MS) * rate / 1000;
+        let mut reader = fs::File::open(self.audio_raw_path())?;
+        let available = reader.metadata()?.len() / frame_bytes;
+        let start = (start as u64).min(available);
+        let end = (end.max(0) as u64).clamp(start, available);
+        reader.seek(SeekFrom::Start(start * frame_bytes))?;
+        let mut bytes = vec![0u8; ((end - start) * frame_bytes) as usize];
+        reader.read_exact(&mut bytes)?;
+        let samples: Vec<f32> = bytes
+            .chunks_exact(4 * channels)
+            .map(|frame| {
+                frame
+                    .chunks_exact(4)
+                    .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                    .sum::<f32>()
+                    / channels as f32
+            })
+            .collect();
+        let stretched = time_stretch(&samples, speed);
+
+        let path = self.dir.join("clip.wav");
+        let mut writer = io::BufWriter::new(fs::File::create(&path)?);
+        write_wav_header(
+            &mut writer,
             self.metadata.audio_sample_rate_hz,
-            self.metadata.audio_channels,
-        )
+            1,
+            stretched.len() as u64,
+        )?;
+        for sample in stretched {
+            writer.write_all(&sample.to_le_bytes())?;
+        }
+        writer.flush()?;
+        set_strict_permissions(&path)?;
+        Ok(path)
+    }
+
+    /// Export per-source WAV stems, a stereo mix (mic left, system right), and
+    /// Audacity and Reaper label tracks for DAW editing.
+    pub fn export_multitrack(
+        &self,
+        segments: &[TranscriptSegment],
+    ) -> Result<PathBuf, SessionError> {
+        if self.metadata.audio_track_files.is_empty() {
+            return Err(io::Error::other(
+                "no per-source tracks recorded; set audio.multitrack = true",
+            )
+            .into());
+        }
+        let export_root = self.export_root()?;
+        for track in &self.metadata.audio_track_files {
+            let raw_path = self.dir.join(track);
+            let wav_path = export_root.path(Path::new(track).with_extension("wav"));
+            write_replacing(&wav_path, |tmp_path| {
+                write_wav_from_raw(&raw_path, tmp_path, self.metadata.audio_sample_rate_hz, 1)
+            })?;
+        }
+        if let [system, mic] = self.metadata.audio_track_files.as_slice() {
+            write_replacing(&export_root.path("stereo.wav"), |tmp_path| {
+                write_stereo_wav_from_tracks(
+                    &self.dir.join(mic),
+                    &self.dir.join(system),
+                    tmp_path,
+                    self.metadata.audio_sample_rate_hz,
+                )
+            })?;
+        }
+        let segments = name_speakers(segments, &self.metadata.speaker_names);
+        write_atomic(
+            &export_root.path("labels.txt"),
+            audacity_labels(&segments).as_bytes(),
+        )?;
+        write_atomic(
+            &export_root.path("regions.csv"),
+            reaper_regions(&segments).as_bytes(),
+        )?;
+        Ok(export_root.dir)
     }
 
     pub fn export_notes_markdown(&self, state: &MeetingNotes) -> Result<(), SessionError> {
+        let output = self.notes_markdown(state)?;
         let export_root = self.export_root()?;
-        let path = export_root.join("notes.md");
-        let mut output = String::from("# Notes\n\n");
+        let path = export_root.path("notes.md");
+        write_atomic(&path, output.as_bytes())?;
+        Ok(())
+    }
 
-        if state.bullets.is_empty() {
-            output.push_str("- (none)\n");
-        } else {
-            for item in &state.bullets {
-                output.push_str(&format!("- {}\n", item.text.trim()));
+    /// Markdown for archived plus live notes, as written to `notes.md`, one
+    /// section per note kind.
+    pub fn notes_markdown(&self, state: &MeetingNotes) -> Result<String, SessionError> {
+        let state = self.full_notes(state)?;
+        let mut output = String::from("# Notes\n");
+
+        let sections = state.sections();
+        if sections.is_empty() {
+            output.push_str("\n- (none)\n");
+        }
+        for (kind, bullets) in sections {
+            output.push_str(&format!("\n## {}\n\n", kind.section_title()));
+            for item in bullets {
+                output.push_str(&format!("- {}\n", note_markdown(item)));
             }
         }
+        Ok(output)
+    }
 
-        write_atomic(&path, output.as_bytes())?;
-        Ok(())
+    /// Write the end-of-meeting summary as `summary.md` plus `summary.json` in the session dir.
+    pub fn export_summary(&self, summary: &MeetingSummary) -> Result<PathBuf, SessionError> {
+        let payload = serde_json::to_string_pretty(summary)?;
+        write_atomic(&self.dir.join("summary.json"), payload.as_bytes())?;
+        let path = self.export_root()?.path("summary.md");
+        write_atomic(&path, render_summary(summary).as_bytes())?;
+        Ok(path)
+    }
+
+    /// Write lecture study notes as `lecture.md`, plus `flashcards.csv` for Anki when cards exist.
+    pub fn export_lecture_notes(&self, notes: &LectureNotes) -> Result<PathBuf, SessionError> {
+        let payload = serde_json::to_string_pretty(notes)?;
+        write_atomic(&self.dir.join("lecture.json"), payload.as_bytes())?;
+        let export_root = self.export_root()?;
+        if !notes.flashcards.is_empty() {
+            write_atomic(
+                &export_root.path("flashcards.csv"),
+                anki_csv(&notes.flashcards).as_bytes(),
+            )?;
+        }
+        let path = export_root.path("lecture.md");
+        write_atomic(&path, render_lecture_notes(notes).as_bytes())?;
+        Ok(path)
+    }
+
+    /// Write podcast show notes as `show_notes.md` for publishing and `show_notes.json` for reuse.
+    pub fn export_show_notes(&self, notes: &ShowNotes) -> Result<PathBuf, SessionError> {
+        let payload = serde_json::to_string_pretty(notes)?;
+        write_atomic(&self.dir.join("show_notes.json"), payload.as_bytes())?;
+        let path = self.export_root()?.path("show_notes.md");
+        write_atomic(&path, render_show_notes(notes).as_bytes())?;
+        Ok(path)
+    }
+
+    /// Write the topic outline as `chapters.json`; the transcript export uses it for headings.
+    pub fn write_chapters(&self, chapters: &[Chapter]) -> Result<PathBuf, SessionError> {
+        let path = self.dir.join("chapters.json");
+        write_atomic(&path, serde_json::to_string_pretty(chapters)?.as_bytes())?;
+        Ok(path)
+    }
+
+    /// Chapters from `chapters.json`; a missing file reads as none.
+    pub fn load_chapters(&self) -> Result<Vec<Chapter>, SessionError> {
+        match fs::read_to_string(self.dir.join("chapters.json")) {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Write cloud provider usage and the estimated cost as `usage.json` in the session dir.
+    pub fn write_usage(&self, report: &UsageReport) -> Result<PathBuf, SessionError> {
+        let path = self.dir.join("usage.json");
+        write_atomic(&path, serde_json::to_string_pretty(report)?.as_bytes())?;
+        Ok(path)
     }
 
     pub fn export_on_exit(
@@ -342,13 +984,64 @@ impl SessionHandle {
         segments: &[TranscriptSegment],
         state: &MeetingNotes,
     ) -> Result<(), SessionError> {
-        self.write_notes(state)?;
-        self.export_audio_wav()?;
-        self.export_transcript_markdown(segments)?;
-        self.export_notes_markdown(state)?;
+        let mut state = state.clone();
+        self.normalize_dues(&mut state);
+        self.write_notes(&state)?;
+        self.export_audio()?;
+        self.export_markdown(segments, &state)?;
+        self.export_action_items(&state)?;
         self.finalize()
     }
 
+    /// Rewrite free-text due values ("Friday", "next week") as ISO dates
+    /// relative to the day the meeting started.
+    pub fn normalize_dues(&self, state: &mut MeetingNotes) {
+        if let Some(date) = crate::reminders::meeting_date(&self.metadata.start_time) {
+            crate::reminders::normalize_dues(state, date);
+        }
+    }
+
+    /// Write action items as `actions.ics` and `actions.taskpaper`; `None`
+    /// when the notes have no action items.
+    pub fn export_action_items(
+        &self,
+        state: &MeetingNotes,
+    ) -> Result<Option<PathBuf>, SessionError> {
+        let mut state = self.full_notes(state)?;
+        self.normalize_dues(&mut state);
+        if crate::reminders::action_items(&state).next().is_none() {
+            return Ok(None);
+        }
+        let export_root = self.export_root()?;
+        let stamp = crate::reminders::ics_stamp(OffsetDateTime::now_utc());
+        let project = self.metadata.title.clone().unwrap_or_else(|| {
+            format!(
+                "Meeting {}",
+                self.metadata.start_time.get(..10).unwrap_or_default()
+            )
+        });
+        write_atomic(
+            &export_root.path("actions.taskpaper"),
+            crate::reminders::action_items_taskpaper(&state, &project).as_bytes(),
+        )?;
+        let path = export_root.path("actions.ics");
+        write_atomic(
+            &path,
+            crate::reminders::action_items_ics(&state, &self.metadata.id, &stamp).as_bytes(),
+        )?;
+        Ok(Some(path))
+    }
+
+    /// Refresh `transcript.md` and `notes.md`; also used for periodic autosave mid-meeting.
+    pub fn export_markdown(
+        &self,
+        segments: &[TranscriptSegment],
+        state: &MeetingNotes,
+    ) -> Result<(), SessionError> {
+        self.export_transcript_markdown(segments)?;
+        self.export_notes_markdown(state)
+    }
+
     pub fn finalize(&mut self) -> Result<(), SessionError> {
         let end_time = OffsetDateTime::now_utc().format(&Rfc3339)?;
         self.metadata.end_time = Some(end_time.clone());
@@ -358,7 +1051,13 @@ impl SessionHandle {
         Ok(())
     }
 
-    fn transcript_path(&self) -> PathBuf {
+    fn is_low_confidence(&self, segment: &TranscriptSegment) -> bool {
+        segment
+            .confidence
+            .is_some_and(|confidence| confidence < self.low_confidence_threshold)
+    }
+
+    pub fn transcript_path(&self) -> PathBuf {
         self.dir.join(&self.metadata.transcript_file)
     }
 
@@ -366,13 +1065,21 @@ impl SessionHandle {
         self.dir.join(&self.metadata.notes_file)
     }
 
-    fn export_root(&self) -> Result<PathBuf, SessionError> {
-        let root = match &self.export_dir {
-            Some(base) => base.join(&self.metadata.id),
-            None => self.dir.clone(),
-        };
-        fs::create_dir_all(&root)?;
-        Ok(root)
+    fn notes_archive_path(&self) -> PathBuf {
+        self.dir
+            .join(file_name(NOTES_ARCHIVE_PREFIX, "jsonl", &self.metadata.id))
+    }
+
+    /// Where exports go: the session dir without an export dir, else the
+    /// date-and-title location claimed for this session.
+    fn export_root(&self) -> Result<ExportLocation, SessionError> {
+        match &self.export_dir {
+            Some(export_dir) => export_dir.claim(&self.metadata),
+            None => Ok(ExportLocation {
+                dir: self.dir.clone(),
+                prefix: None,
+            }),
+        }
     }
 
     fn touch_metadata(&mut self) -> Result<(), SessionError> {
@@ -382,89 +1089,569 @@ impl SessionHandle {
     }
 }
 
-fn file_name(prefix: &str, ext: &str, id: &str) -> String {
-    format!("{prefix}-{id}.{ext}")
-}
+/// Hidden file naming the session that owns an export location, so two
+/// meetings with the same date and title never write into each other.
+const EXPORT_MARKER: &str = ".koe-session";
 
-#[derive(Serialize)]
-struct TranscriptRecord {
-    id: u64,
-    start_ms: i64,
-    end_ms: i64,
-    speaker: Option<String>,
-    text: String,
-    finalized: bool,
-    source: String,
+/// How exports are arranged under the export dir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportLayout {
+    /// `<export_dir>/<date> <title>/`
+    Session,
+    /// `<export_dir>/<date>/<title>/`
+    Day,
 }
 
-impl TranscriptRecord {
-    fn from_segment(segment: &TranscriptSegment) -> Self {
-        let source = match segment.speaker.as_deref() {
-            Some("Me") => "microphone",
-            Some("Them") => "system",
-            _ => "unknown",
-        };
-        Self {
-            id: segment.id,
-            start_ms: segment.start_ms,
-            end_ms: segment.end_ms,
-            speaker: segment.speaker.clone(),
-            text: segment.text.clone(),
-            finalized: segment.finalized,
-            source: source.to_string(),
+impl ExportLayout {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "session" => Some(Self::Session),
+            "day" => Some(Self::Day),
+            _ => None,
         }
     }
 }
 
-#[derive(Serialize)]
-struct NotesSnapshot {
-    updated_at: String,
-    state: MeetingNotes,
+/// `session.export_dir` with its layout; `flatten` drops the per-meeting
+/// folder and prefixes file names with the meeting name instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportDir {
+    pub root: PathBuf,
+    pub layout: ExportLayout,
+    pub flatten: bool,
 }
 
-fn write_metadata(path: &Path, metadata: &SessionMetadata) -> Result<(), SessionError> {
-    let contents = toml::to_string_pretty(metadata)?;
-    write_atomic(path, contents.as_bytes())
+/// A folder claimed by one session, with the file name prefix when flattened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportLocation {
+    pub dir: PathBuf,
+    pub prefix: Option<String>,
 }
 
-fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), SessionError> {
-    let parent = path
-        .parent()
-        .ok_or_else(|| io::Error::other("session path missing parent directory"))?;
-    let tmp_path = parent.join(".tmp-write");
-    fs::write(&tmp_path, contents)?;
-    set_strict_permissions(&tmp_path)?;
-    fs::rename(tmp_path, path)?;
-    Ok(())
-}
+impl ExportLocation {
+    pub fn path(&self, name: impl AsRef<Path>) -> PathBuf {
+        match &self.prefix {
+            Some(prefix) => self
+                .dir
+                .join(format!("{prefix} {}", name.as_ref().display())),
+            None => self.dir.join(name),
+        }
+    }
 
-fn set_strict_permissions(path: &Path) -> Result<(), SessionError> {
-    #[cfg(unix)]
-    {
-        let perm = fs::Permissions::from_mode(0o600);
-        fs::set_permissions(path, perm)?;
+    fn marker(&self) -> PathBuf {
+        match &self.prefix {
+            Some(prefix) => self.dir.join(format!(".{prefix}{EXPORT_MARKER}")),
+            None => self.dir.join(EXPORT_MARKER),
+        }
     }
-    Ok(())
 }
 
-fn warn_if_loose_permissions(path: &Path) -> Result<(), SessionError> {
-    #[cfg(unix)]
-    {
-        let metadata = fs::metadata(path)?;
-        let mode = metadata.permissions().mode() & 0o777;
-        if mode & 0o077 != 0 {
-            eprintln!(
-                "session file {} is group/world readable; set permissions to 0600",
-                path.display()
-            );
+impl ExportDir {
+    /// The `attempt`-th candidate for the session's date and title; later
+    /// attempts get a `-2`, `-3`, ... suffix.
+    fn candidate(&self, metadata: &SessionMetadata, attempt: usize) -> ExportLocation {
+        let date = metadata.start_time.get(..10).unwrap_or("undated");
+        let slug = slugify(metadata.title.as_deref().unwrap_or_default());
+        let (dir, name) = match self.layout {
+            ExportLayout::Session => (self.root.clone(), format!("{date} {slug}")),
+            ExportLayout::Day => (self.root.join(date), slug),
+        };
+        let name = if attempt > 1 {
+            format!("{name}-{attempt}")
+        } else {
+            name
+        };
+        if self.flatten {
+            ExportLocation {
+                dir,
+                prefix: Some(name),
+            }
+        } else {
+            ExportLocation {
+                dir: dir.join(name),
+                prefix: None,
+            }
         }
     }
-    Ok(())
-}
 
-fn write_wav_from_raw(
-    raw_path: &Path,
-    wav_path: &Path,
+    /// The first free candidate (or the one this session already holds),
+    /// claimed by creating its marker exclusively.
+    pub fn claim(&self, metadata: &SessionMetadata) -> Result<ExportLocation, SessionError> {
+        let mut attempt = 1;
+        loop {
+            let location = self.candidate(metadata, attempt);
+            fs::create_dir_all(&location.dir)?;
+            let marker = location.marker();
+            match OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&marker)
+            {
+                Ok(mut file) => {
+                    file.write_all(metadata.id.as_bytes())?;
+                    return Ok(location);
+                }
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    if fs::read_to_string(&marker)?.trim() == metadata.id {
+                        return Ok(location);
+                    }
+                }
+                Err(err) => return Err(err.into()),
+            }
+            attempt += 1;
+        }
+    }
+
+    /// The location this session already holds for its current title.
+    pub fn find(&self, metadata: &SessionMetadata) -> Result<Option<ExportLocation>, SessionError> {
+        let mut attempt = 1;
+        loop {
+            let location = self.candidate(metadata, attempt);
+            match fs::read_to_string(location.marker()) {
+                Ok(id) if id.trim() == metadata.id => return Ok(Some(location)),
+                Ok(_) => attempt += 1,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    /// Move exports written under `previous` to the location for the
+    /// session's current title.
+    fn relocate(
+        &self,
+        metadata: &SessionMetadata,
+        previous: &ExportLocation,
+    ) -> Result<(), SessionError> {
+        let next = self.claim(metadata)?;
+        if &next == previous {
+            return Ok(());
+        }
+        let old_marker = previous.marker();
+        let entries = fs::read_dir(&previous.dir)?.collect::<Result<Vec<_>, _>>()?;
+        for entry in entries {
+            if entry.path() == old_marker {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let target = match &previous.prefix {
+                Some(prefix) => match name.strip_prefix(&format!("{prefix} ")) {
+                    Some(rest) => next.path(rest),
+                    None => continue,
+                },
+                None => next.path(&name),
+            };
+            fs::rename(entry.path(), target)?;
+        }
+        fs::remove_file(old_marker)?;
+        if previous.prefix.is_none() {
+            let _ = fs::remove_dir(&previous.dir);
+        }
+        Ok(())
+    }
+}
+
+/// Lower-case words (letters and digits in any script) joined by `-`, with
+/// punctuation and path separators dropped so names survive every sync
+/// service; `meeting` when nothing is left.
+fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    let mut len = 0;
+    for c in title.chars() {
+        if c.is_alphanumeric() {
+            for lower in c.to_lowercase() {
+                slug.push(lower);
+                len += 1;
+            }
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+            len += 1;
+        }
+        if len >= 60 {
+            break;
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "meeting".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// When the transcript file is fsynced after segments are appended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FsyncPolicy {
+    /// Leave flushing to the OS; fastest, may lose recent lines on power loss.
+    Never,
+    /// Sync at most every few seconds while segments keep arriving.
+    Interval,
+    /// Sync after every batch.
+    Always,
+}
+
+impl FsyncPolicy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "never" => Some(Self::Never),
+            "interval" => Some(Self::Interval),
+            "always" => Some(Self::Always),
+            _ => None,
+        }
+    }
+}
+
+/// Live transcript appender owned by the transcribe thread, so segments reach
+/// disk even when the UI loop stalls. Each batch is written straight to the
+/// file; `policy` decides when it is also synced.
+pub struct TranscriptWriter {
+    file: File,
+    policy: FsyncPolicy,
+    last_sync: Instant,
+    unsynced: bool,
+}
+
+impl TranscriptWriter {
+    pub fn open(path: &Path, policy: FsyncPolicy) -> Result<Self, SessionError> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        set_strict_permissions(path)?;
+        Ok(Self {
+            file,
+            policy,
+            last_sync: Instant::now(),
+            unsynced: false,
+        })
+    }
+
+    pub fn append(
+        &mut self,
+        segments: &[TranscriptSegment],
+        now: Instant,
+    ) -> Result<(), SessionError> {
+        if segments.is_empty() {
+            return Ok(());
+        }
+        write_transcript_lines(&mut self.file, segments)?;
+        self.unsynced = true;
+        match self.policy {
+            FsyncPolicy::Always => self.sync(now),
+            FsyncPolicy::Interval => self.tick(now),
+            FsyncPolicy::Never => Ok(()),
+        }
+    }
+
+    /// Called periodically; syncs lines older than the interval.
+    pub fn tick(&mut self, now: Instant) -> Result<(), SessionError> {
+        if self.policy == FsyncPolicy::Interval
+            && self.unsynced
+            && now.duration_since(self.last_sync) >= FSYNC_INTERVAL
+        {
+            self.sync(now)?;
+        }
+        Ok(())
+    }
+
+    /// Sync everything written so far, e.g. before the meeting is finalized.
+    pub fn sync(&mut self, now: Instant) -> Result<(), SessionError> {
+        if self.unsynced && self.policy != FsyncPolicy::Never {
+            self.file.sync_data()?;
+        }
+        self.unsynced = false;
+        self.last_sync = now;
+        Ok(())
+    }
+}
+
+/// A session on disk, read without taking it over (e.g. while the TUI still writes it).
+#[derive(Debug, Clone)]
+pub struct StoredSession {
+    pub dir: PathBuf,
+    pub metadata: SessionMetadata,
+}
+
+impl StoredSession {
+    pub fn load(dir: &Path) -> Result<Self, SessionError> {
+        let contents = fs::read_to_string(dir.join("metadata.toml"))?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            metadata: toml::from_str(&contents)?,
+        })
+    }
+
+    /// Per-source raw tracks (mono f32 at the session rate) with the source each
+    /// captured; empty unless the session was recorded with `audio.multitrack`.
+    pub fn audio_tracks(&self) -> Vec<(AudioSource, PathBuf)> {
+        self.metadata
+            .audio_track_files
+            .iter()
+            .map(|file| {
+                let source = if file.starts_with(TRACK_MIC_PREFIX) {
+                    AudioSource::Microphone
+                } else {
+                    AudioSource::System
+                };
+                (source, self.dir.join(file))
+            })
+            .collect()
+    }
+
+    /// Transcript replayed through a ledger so revised segments appear once,
+    /// with renamed speakers shown by name.
+    pub fn transcript(&self) -> Result<Vec<TranscriptSegment>, SessionError> {
+        let segments = read_jsonl(&self.dir.join(&self.metadata.transcript_file))?;
+        let mut ledger = TranscriptLedger::new();
+        ledger.append(segments);
+        Ok(name_speakers(ledger.segments(), &self.metadata.speaker_names).into_owned())
+    }
+
+    /// Archived bullets followed by the latest notes snapshot.
+    pub fn notes(&self) -> Result<MeetingNotes, SessionError> {
+        let archive = self
+            .dir
+            .join(file_name(NOTES_ARCHIVE_PREFIX, "jsonl", &self.metadata.id));
+        let mut bullets: Vec<NoteBullet> = read_jsonl(&archive)?;
+        let snapshot: NotesSnapshot =
+            match fs::read_to_string(self.dir.join(&self.metadata.notes_file)) {
+                Ok(contents) => serde_json::from_str(&contents)?,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    return Ok(MeetingNotes { bullets });
+                }
+                Err(err) => return Err(err.into()),
+            };
+        bullets.extend(snapshot.state.bullets);
+        Ok(MeetingNotes { bullets })
+    }
+
+    pub fn record_score(&mut self, score: TranscriptScore) -> Result<(), SessionError> {
+        self.metadata.score = Some(score);
+        write_metadata(&self.dir.join("metadata.toml"), &self.metadata)
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<String>) -> Result<(), SessionError> {
+        self.metadata.tags = tags;
+        write_metadata(&self.dir.join("metadata.toml"), &self.metadata)
+    }
+
+    /// Handle for rewriting a stored session's transcript and exports.
+    pub fn reopen(
+        self,
+        paths: &ConfigPaths,
+        export_dir: Option<ExportDir>,
+        low_confidence_threshold: f32,
+    ) -> Result<SessionHandle, SessionError> {
+        let archived: Vec<NoteBullet> = read_jsonl(&self.dir.join(file_name(
+            NOTES_ARCHIVE_PREFIX,
+            "jsonl",
+            &self.metadata.id,
+        )))?;
+        Ok(SessionHandle {
+            metadata_path: self.dir.join("metadata.toml"),
+            dir: self.dir,
+            export_dir,
+            metadata: self.metadata,
+            notes_archived: archived.len(),
+            low_confidence_threshold,
+            audit: AuditLog::new(paths),
+        })
+    }
+
+    /// Write an extra file (e.g. a re-summarization) into the session dir.
+    pub fn write_artifact(&self, name: &str, contents: &[u8]) -> Result<PathBuf, SessionError> {
+        let path = self.dir.join(name);
+        write_atomic(&path, contents)?;
+        Ok(path)
+    }
+}
+
+/// Session by id or directory, or the newest one when none is given.
+pub fn find_session(
+    sessions_dir: &Path,
+    id: Option<&str>,
+) -> Result<Option<StoredSession>, SessionError> {
+    if let Some(id) = id {
+        let path = Path::new(id);
+        if path.is_dir() {
+            return StoredSession::load(path).map(Some);
+        }
+    }
+    let sessions = list_sessions(sessions_dir)?;
+    Ok(match id {
+        Some(id) => sessions
+            .into_iter()
+            .find(|session| session.metadata.id == id),
+        None => sessions.into_iter().next(),
+    })
+}
+
+/// Sessions under `sessions_dir`, newest first; unreadable entries are skipped.
+pub fn list_sessions(sessions_dir: &Path) -> Result<Vec<StoredSession>, SessionError> {
+    let entries = match fs::read_dir(sessions_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut sessions: Vec<StoredSession> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| StoredSession::load(&entry.path()).ok())
+        .collect();
+    sessions.sort_by(|a, b| b.metadata.start_time.cmp(&a.metadata.start_time));
+    Ok(sessions)
+}
+
+/// Tags from `input` split on commas and whitespace: lower-cased, without a
+/// leading `#`, deduplicated in first-seen order.
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split(|c: char| c == ',' || c.is_whitespace()) {
+        let tag = tag.trim_start_matches('#').to_lowercase();
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+/// One JSON value per non-empty line; a missing file reads as empty.
+fn read_jsonl<T: serde::de::DeserializeOwned>(path: &Path) -> Result<Vec<T>, SessionError> {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut values = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        values.push(serde_json::from_str(&line)?);
+    }
+    Ok(values)
+}
+
+fn file_name(prefix: &str, ext: &str, id: &str) -> String {
+    format!("{prefix}-{id}.{ext}")
+}
+
+#[derive(Serialize)]
+struct TranscriptRecord {
+    id: u64,
+    start_ms: i64,
+    end_ms: i64,
+    speaker: Option<String>,
+    text: String,
+    finalized: bool,
+    source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confidence: Option<f32>,
+}
+
+impl TranscriptRecord {
+    fn from_segment(segment: &TranscriptSegment) -> Self {
+        // Diarized labels such as `Speaker A` only come from system audio.
+        let source = match segment.speaker.as_deref() {
+            Some("Me") => "microphone",
+            Some("Them") => "system",
+            Some(label) if label.starts_with("Speaker ") => "system",
+            _ => "unknown",
+        };
+        Self {
+            id: segment.id,
+            start_ms: segment.start_ms,
+            end_ms: segment.end_ms,
+            speaker: segment.speaker.clone(),
+            text: segment.text.clone(),
+            finalized: segment.finalized,
+            source: source.to_string(),
+            confidence: segment.confidence,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct NotesSnapshot {
+    updated_at: String,
+    state: MeetingNotes,
+    /// Bullets older than `state`, stored in the notes archive file.
+    archived: usize,
+}
+
+/// One JSON line per segment, each written in a single call so concurrent
+/// appenders never interleave partial lines.
+fn write_transcript_lines(
+    file: &mut impl Write,
+    segments: &[TranscriptSegment],
+) -> Result<(), SessionError> {
+    for segment in segments {
+        let mut line = serde_json::to_string(&TranscriptRecord::from_segment(segment))?;
+        line.push('\n');
+        file.write_all(line.as_bytes())?;
+    }
+    Ok(())
+}
+
+fn write_metadata(path: &Path, metadata: &SessionMetadata) -> Result<(), SessionError> {
+    let contents = toml::to_string_pretty(metadata)?;
+    write_atomic(path, contents.as_bytes())
+}
+
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), SessionError> {
+    write_replacing(path, |tmp_path| {
+        fs::write(tmp_path, contents)?;
+        set_strict_permissions(tmp_path)
+    })
+}
+
+/// Run `write` against a hidden temp file next to `path`, then rename it into
+/// place, so readers and file-sync daemons never see a partial file. Each call
+/// gets its own temp name, so concurrent writers in one dir do not collide.
+fn write_replacing(
+    path: &Path,
+    write: impl FnOnce(&Path) -> Result<(), SessionError>,
+) -> Result<(), SessionError> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| io::Error::other("session path missing parent directory"))?;
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp_path = parent.join(format!(".{name}.{}.tmp", Uuid::now_v7().simple()));
+    if let Err(err) = write(&tmp_path) {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(err);
+    }
+    fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+fn set_strict_permissions(path: &Path) -> Result<(), SessionError> {
+    #[cfg(unix)]
+    {
+        let perm = fs::Permissions::from_mode(0o600);
+        fs::set_permissions(path, perm)?;
+    }
+    Ok(())
+}
+
+fn warn_if_loose_permissions(path: &Path) -> Result<(), SessionError> {
+    #[cfg(unix)]
+    {
+        let metadata = fs::metadata(path)?;
+        let mode = metadata.permissions().mode() & 0o777;
+        if mode & 0o077 != 0 {
+            eprintln!(
+                "session file {} is group/world readable; set permissions to 0600",
+                path.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn write_wav_from_raw(
+    raw_path: &Path,
+    wav_path: &Path,
     sample_rate: u32,
     channels: u16,
 ) -> Result<(), SessionError> {
@@ -488,6 +1675,40 @@ fn write_wav_from_raw(
     Ok(())
 }
 
+/// Interleave two mono raw tracks into one stereo WAV, padding the shorter
+/// track with silence.
+fn write_stereo_wav_from_tracks(
+    left_path: &Path,
+    right_path: &Path,
+    wav_path: &Path,
+    sample_rate: u32,
+) -> Result<(), SessionError> {
+    let left_len = fs::metadata(left_path)?.len() / 4;
+    let right_len = fs::metadata(right_path)?.len() / 4;
+    let frames = left_len.max(right_len);
+
+    let tmp_path = wav_path.with_extension("tmp");
+    let mut left = io::BufReader::new(fs::File::open(left_path)?);
+    let mut right = io::BufReader::new(fs::File::open(right_path)?);
+    let mut writer = io::BufWriter::new(fs::File::create(&tmp_path)?);
+    write_wav_header(&mut writer, sample_rate, 2, frames)?;
+    let mut sample = [0u8; 4];
+    for frame in 0..frames {
+        for (reader, len) in [(&mut left, left_len), (&mut right, right_len)] {
+            if frame < len {
+                reader.read_exact(&mut sample)?;
+            } else {
+                sample = [0; 4];
+            }
+            writer.write_all(&sample)?;
+        }
+    }
+    writer.flush()?;
+    set_strict_permissions(&tmp_path)?;
+    fs::rename(tmp_path, wav_path)?;
+    Ok(())
+}
+
 fn write_wav_header(
     writer: &mut impl Write,
     sample_rate: u32,
@@ -534,6 +1755,290 @@ fn write_wav_header(
     Ok(())
 }
 
+/// `segments` with mapped speaker labels replaced by their names.
+fn name_speakers<'a>(
+    segments: &'a [TranscriptSegment],
+    names: &BTreeMap<String, String>,
+) -> Cow<'a, [TranscriptSegment]> {
+    let mapped = |segment: &TranscriptSegment| {
+        segment
+            .speaker
+            .as_ref()
+            .is_some_and(|speaker| names.contains_key(speaker))
+    };
+    if !segments.iter().any(mapped) {
+        return Cow::Borrowed(segments);
+    }
+    Cow::Owned(
+        segments
+            .iter()
+            .map(|segment| TranscriptSegment {
+                speaker: segment
+                    .speaker
+                    .as_ref()
+                    .map(|speaker| names.get(speaker).unwrap_or(speaker).clone()),
+                ..segment.clone()
+            })
+            .collect(),
+    )
+}
+
+fn segment_label(segment: &TranscriptSegment) -> String {
+    let speaker = segment.speaker.as_deref().unwrap_or("Unknown");
+    let text = segment.text.replace(['\n', '\t'], " ");
+    format!("{speaker}: {}", text.trim())
+}
+
+fn seconds(ms: i64) -> f64 {
+    ms.max(0) as f64 / 1000.0
+}
+
+/// Audacity label track: tab-separated start, end (seconds), label.
+fn audacity_labels(segments: &[TranscriptSegment]) -> String {
+    let mut output = String::new();
+    for segment in segments {
+        output.push_str(&format!(
+            "{:.6}\t{:.6}\t{}\n",
+            seconds(segment.start_ms),
+            seconds(segment.end_ms),
+            segment_label(segment)
+        ));
+    }
+    output
+}
+
+/// Reaper region list as exported/imported by the Region/Marker Manager.
+fn reaper_regions(segments: &[TranscriptSegment]) -> String {
+    let mut output = String::from("#,Name,Start,End,Length\n");
+    for (index, segment) in segments.iter().enumerate() {
+        let start = seconds(segment.start_ms);
+        let end = seconds(segment.end_ms.max(segment.start_ms));
+        let name = segment_label(segment).replace('"', "\"\"");
+        output.push_str(&format!(
+            "R{},\"{name}\",{start:.3},{end:.3},{:.3}\n",
+            index + 1,
+            end - start
+        ));
+    }
+    output
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SubtitleFormat {
+    Srt,
+    Vtt,
+}
+
+fn subtitles(segments: &[TranscriptSegment], format: SubtitleFormat) -> String {
+    let mut output = match format {
+        SubtitleFormat::Srt => String::new(),
+        SubtitleFormat::Vtt => String::from("WEBVTT\n\n"),
+    };
+    let mut index = 0;
+    for (segment, anchor) in segments.iter().zip(segment_anchors(segments)) {
+        let text = segment.text.replace('\n', " ").trim().to_string();
+        if text.is_empty() {
+            continue;
+        }
+        index += 1;
+        let start = format_cue_timestamp(segment.start_ms, format);
+        let end = format_cue_timestamp(segment.end_ms.max(segment.start_ms), format);
+        let cue = match (format, segment.speaker.as_deref()) {
+            (SubtitleFormat::Vtt, Some(speaker)) => format!("<v {speaker}>{text}"),
+            (SubtitleFormat::Srt, Some(speaker)) => format!("{speaker}: {text}"),
+            (_, None) => text,
+        };
+        match format {
+            SubtitleFormat::Srt => output.push_str(&format!("{index}\n")),
+            SubtitleFormat::Vtt => output.push_str(&format!("{anchor}\n")),
+        }
+        output.push_str(&format!("{start} --> {end}\n{cue}\n\n"));
+    }
+    output
+}
+
+/// `HH:MM:SS,mmm` for SRT, `HH:MM:SS.mmm` for WebVTT.
+fn format_cue_timestamp(ms: i64, format: SubtitleFormat) -> String {
+    let ms = ms.max(0);
+    let hours = ms / 3_600_000;
+    let minutes = (ms / 60_000) % 60;
+    let seconds = (ms / 1000) % 60;
+    let millis = ms % 1000;
+    let separator = match format {
+        SubtitleFormat::Srt => ',',
+        SubtitleFormat::Vtt => '.',
+    };
+    format!("{hours:02}:{minutes:02}:{seconds:02}{separator}{millis:03}")
+}
+
+/// Bullet text with its done checkbox and owner, as edited in the notes pane.
+pub fn note_markdown(bullet: &NoteBullet) -> String {
+    let mut line = String::new();
+    if bullet.done {
+        line.push_str("[x] ");
+    }
+    line.push_str(bullet.text.trim());
+    match (bullet.owner.as_deref(), bullet.due.as_deref()) {
+        (Some(owner), Some(due)) => line.push_str(&format!(" ({owner}, due {due})")),
+        (Some(owner), None) => line.push_str(&format!(" ({owner})")),
+        (None, Some(due)) => line.push_str(&format!(" (due {due})")),
+        (None, None) => {}
+    }
+    line
+}
+
+pub fn render_summary(summary: &MeetingSummary) -> String {
+    let mut output = String::from("# Summary\n\n## Overview\n\n");
+    if summary.overview.is_empty() {
+        output.push_str("(none)\n");
+    } else {
+        output.push_str(&format!("{}\n", summary.overview));
+    }
+
+    push_list_section(&mut output, "Decisions", &summary.decisions);
+
+    output.push_str("\n## Action Items\n\n");
+    if summary.action_items.is_empty() {
+        output.push_str("- (none)\n");
+    }
+    for item in &summary.action_items {
+        match item.owner.as_deref() {
+            Some(owner) => output.push_str(&format!("- [ ] {} ({owner})\n", item.text)),
+            None => output.push_str(&format!("- [ ] {}\n", item.text)),
+        }
+    }
+
+    push_list_section(&mut output, "Open Questions", &summary.open_questions);
+    output
+}
+
+fn push_list_section(output: &mut String, heading: &str, items: &[String]) {
+    output.push_str(&format!("\n## {heading}\n\n"));
+    if items.is_empty() {
+        output.push_str("- (none)\n");
+    }
+    for item in items {
+        output.push_str(&format!("- {item}\n"));
+    }
+}
+
+fn render_lecture_notes(notes: &LectureNotes) -> String {
+    let mut output = String::from("# Lecture Notes\n\n## Outline\n\n");
+    if notes.outline.is_empty() {
+        output.push_str("- (none)\n");
+    }
+    push_outline(&mut output, &notes.outline, 0);
+
+    output.push_str("\n## Key Terms\n\n");
+    if notes.terms.is_empty() {
+        output.push_str("- (none)\n");
+    }
+    for term in &notes.terms {
+        output.push_str(&format!("- **{}**: {}\n", term.term, term.definition));
+    }
+    output
+}
+
+fn push_outline(output: &mut String, items: &[OutlineItem], depth: usize) {
+    for item in items {
+        output.push_str(&format!("{}- {}\n", "  ".repeat(depth), item.text));
+        push_outline(output, &item.children, depth + 1);
+    }
+}
+
+/// Anki text import: header directives, then one `question,answer` row per card.
+fn anki_csv(cards: &[Flashcard]) -> String {
+    let mut output = String::from("#separator:comma\n#html:false\n#columns:Front,Back\n");
+    for card in cards {
+        output.push_str(&format!(
+            "{},{}\n",
+            csv_field(&card.question),
+            csv_field(&card.answer)
+        ));
+    }
+    output
+}
+
+fn csv_field(value: &str) -> String {
+    let value = value.replace('\n', " ");
+    if value.contains([',', '"']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn render_show_notes(notes: &ShowNotes) -> String {
+    let mut output = String::from("# Show Notes\n\n## Episode Summary\n\n");
+    if notes.summary.is_empty() {
+        output.push_str("(none)\n");
+    } else {
+        output.push_str(&format!("{}\n", notes.summary));
+    }
+
+    output.push_str("\n## Chapters\n\n");
+    if notes.chapters.is_empty() {
+        output.push_str("- (none)\n");
+    }
+    for chapter in &notes.chapters {
+        let start = format_timestamp(chapter.start_ms);
+        output.push_str(&format!("- {start} {}\n", chapter.title));
+    }
+
+    output.push_str("\n## Pull Quotes\n\n");
+    if notes.quotes.is_empty() {
+        output.push_str("- (none)\n");
+    }
+    for quote in &notes.quotes {
+        let start = format_timestamp(quote.start_ms);
+        let text = quote.text.replace('\n', " ");
+        match quote.speaker.as_deref() {
+            Some(speaker) => output.push_str(&format!("> \"{text}\" — {speaker} ({start})\n\n")),
+            None => output.push_str(&format!("> \"{text}\" ({start})\n\n")),
+        }
+    }
+    if !notes.quotes.is_empty() {
+        output.pop();
+    }
+
+    output.push_str("\n## Links Mentioned\n\n");
+    if notes.links.is_empty() {
+        output.push_str("- (none)\n");
+    }
+    for link in &notes.links {
+        if link.url.is_empty() {
+            output.push_str(&format!("- {}\n", link.label));
+        } else {
+            output.push_str(&format!("- [{}]({})\n", link.label, link.url));
+        }
+    }
+    output
+}
+
+/// Stable anchor for the transcript moment at `ms`, e.g. `t-14m32s`.
+fn segment_anchor(ms: i64) -> String {
+    let total_seconds = ms.max(0) / 1000;
+    format!("t-{}m{:02}s", total_seconds / 60, total_seconds % 60)
+}
+
+/// Anchor per segment, in order: the markdown HTML id and WebVTT cue id. Segments
+/// starting in the same second get `-2`, `-3`, ... so each anchor is unique.
+fn segment_anchors(segments: &[TranscriptSegment]) -> Vec<String> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    segments
+        .iter()
+        .map(|segment| {
+            let anchor = segment_anchor(segment.start_ms);
+            let count = seen.entry(anchor.clone()).or_insert(0);
+            *count += 1;
+            match *count {
+                1 => anchor,
+                n => format!("{anchor}-{n}"),
+            }
+        })
+        .collect()
+}
+
 fn format_timestamp(ms: i64) -> String {
     let total_seconds = ms.max(0) / 1000;
     let minutes = total_seconds / 60;
@@ -543,27 +2048,27 @@ fn format_timestamp(ms: i64) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{SessionHandle, SessionMetadata, SessionMetadataInput};
+    use super::{
+        ExportDir, ExportLayout, FsyncPolicy, SessionHandle, SessionMetadata, SessionMetadataInput,
+        StoredSession, SubtitleFormat, TranscriptWriter, anki_csv, audacity_labels, reaper_regions,
+        render_lecture_notes, render_show_notes, render_summary, slugify, subtitles,
+        write_stereo_wav_from_tracks,
+    };
     use crate::config::ConfigPaths;
-    use koe_core::types::{MeetingNotes, TranscriptSegment};
+    use crate::test_support::{bullet, session_input, session_metadata};
+    use koe_core::types::{
+        Chapter, Flashcard, KeyTerm, LectureNotes, MeetingNotes, MeetingSummary, NoteBullet,
+        NoteKind, OutlineItem, ShowNotes, ShowNotesChapter, ShowNotesLink, ShowNotesQuote,
+        SummaryActionItem, TranscriptSegment,
+    };
+    use std::time::{Duration, Instant};
     use tempfile::tempdir;
 
     #[test]
     fn export_on_exit_writes_transcript_and_notes() {
         let temp = tempdir().unwrap();
         let paths = ConfigPaths::from_base(temp.path().join("koe"));
-        let metadata = SessionMetadata::new(SessionMetadataInput {
-            context: None,
-            participants: Vec::new(),
-            audio_sample_rate_hz: 48_000,
-            audio_channels: 1,
-            audio_sources: vec!["system".to_string()],
-            transcribe_provider: "whisper".to_string(),
-            transcribe_model: "base.en".to_string(),
-            summarize_provider: "ollama".to_string(),
-            summarize_model: "qwen3:30b-a3b".to_string(),
-        })
-        .unwrap();
+        let metadata = session_metadata();
         let session_id = metadata.id.clone();
         let notes_file = metadata.notes_file.clone();
         let audio_wav_file = metadata.audio_wav_file.clone();
@@ -577,12 +2082,12 @@ mod tests {
             speaker: Some("Me".to_string()),
             text: "hello".to_string(),
             finalized: true,
+            confidence: None,
         }];
         let mut state = MeetingNotes::default();
-        state.bullets.push(koe_core::types::NoteBullet {
-            id: "n1".to_string(),
-            text: "first point".to_string(),
+        state.bullets.push(NoteBullet {
             evidence: vec![1],
+            ..bullet("n1", "first point", NoteKind::Point)
         });
 
         session.export_on_exit(&segments, &state).unwrap();
@@ -599,5 +2104,504 @@ mod tests {
 
         let wav_path = session_dir.join(audio_wav_file);
         assert!(wav_path.exists());
+
+        let mut stored = super::find_session(&paths.sessions_dir, None)
+            .unwrap()
+            .unwrap();
+        stored
+            .record_score(super::TranscriptScore {
+                reference_file: "ref.txt".to_string(),
+                scored_at: "2026-10-17T10:00:00Z".to_string(),
+                wer: 0.25,
+                cer: 0.1,
+                reference_words: 4,
+                substitutions: 1,
+                deletions: 0,
+                insertions: 0,
+            })
+            .unwrap();
+        let reloaded = super::StoredSession::load(&session_dir).unwrap();
+        assert_eq!(reloaded.metadata.score, stored.metadata.score);
+    }
+
+    #[test]
+    fn segment_clip_reads_whole_frames_of_interleaved_audio() {
+        let temp = tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().join("koe"));
+        let metadata = SessionMetadata::new(SessionMetadataInput {
+            audio_sample_rate_hz: 1_000,
+            audio_channels: 2,
+            audio_sources: vec!["system".to_string(), "microphone".to_string()],
+            ..session_input()
+        })
+        .unwrap();
+        let session = SessionHandle::start(&paths, metadata, None).unwrap();
+        let frames: Vec<u8> = (0..2_000)
+            .flat_map(|_| [0.5f32, -0.1f32])
+            .flat_map(f32::to_le_bytes)
+            .collect();
+        std::fs::write(session.audio_raw_path(), frames).unwrap();
+        let segment = TranscriptSegment {
+            id: 1,
+            start_ms: 500,
+            end_ms: 1_000,
+            speaker: None,
+            text: "clip".to_string(),
+            finalized: true,
+            confidence: None,
+        };
+
+        let clip = std::fs::read(session.segment_clip(&segment, 1.0).unwrap()).unwrap();
+        let data = clip.windows(4).position(|tag| tag == b"data").unwrap() + 8;
+        let samples: Vec<f32> = clip[data..]
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        assert_eq!(samples.len(), 1_000);
+        assert!(samples.iter().all(|sample| (sample - 0.2).abs() < 1e-6));
+    }
+
+    #[test]
+    fn tags_normalize_and_keep_a_title_written_elsewhere() {
+        let temp = tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().join("koe"));
+        let metadata = session_metadata();
+        let mut session = SessionHandle::start(&paths, metadata, None).unwrap();
+        let mut background = session.clone();
+        background.update_title("Launch review").unwrap();
+        session.update_tags(vec!["retro".to_string()]).unwrap();
+        background.update_title("Launch retro").unwrap();
+        assert_eq!(background.metadata().tags, vec!["retro"]);
+
+        assert_eq!(
+            super::parse_tags("#Planning, q4 planning,,"),
+            vec!["planning", "q4"]
+        );
+        session
+            .update_tags(super::parse_tags("planning q4"))
+            .unwrap();
+        let stored = StoredSession::load(session.session_dir()).unwrap();
+        assert_eq!(stored.metadata.title.as_deref(), Some("Launch retro"));
+        assert!(stored.metadata.has_tag("#Q4"));
+        assert!(!stored.metadata.has_tag("retro"));
+    }
+
+    #[test]
+    fn exports_get_collision_safe_date_and_title_names() {
+        let temp = tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().join("koe"));
+        let root = temp.path().join("Meeting Notes");
+        let metadata = || {
+            let mut metadata = session_metadata();
+            metadata.start_time = "2026-10-18T09:30:00Z".to_string();
+            metadata.title = Some("Launch sync!".to_string());
+            metadata
+        };
+        let export_dir = |layout, flatten| ExportDir {
+            root: root.clone(),
+            layout,
+            flatten,
+        };
+
+        let first = SessionHandle::start(
+            &paths,
+            metadata(),
+            Some(export_dir(ExportLayout::Session, false)),
+        )
+        .unwrap();
+        let mut second = SessionHandle::start(
+            &paths,
+            metadata(),
+            Some(export_dir(ExportLayout::Session, false)),
+        )
+        .unwrap();
+        assert_eq!(
+            first.export_notes_path().unwrap(),
+            root.join("2026-10-18 launch-sync/notes.md")
+        );
+        second.export_file("minutes.pdf", b"pdf").unwrap();
+        assert!(root.join("2026-10-18 launch-sync-2/minutes.pdf").exists());
+        second.update_title("Retro").unwrap();
+        assert!(root.join("2026-10-18 retro/minutes.pdf").exists());
+        assert!(!root.join("2026-10-18 launch-sync-2").exists());
+
+        let mut flat = SessionHandle::start(
+            &paths,
+            metadata(),
+            Some(export_dir(ExportLayout::Day, true)),
+        )
+        .unwrap();
+        flat.export_file("minutes.pdf", b"pdf").unwrap();
+        assert!(root.join("2026-10-18/launch-sync minutes.pdf").exists());
+        flat.update_title("").unwrap();
+        assert!(root.join("2026-10-18/meeting minutes.pdf").exists());
+        assert!(!root.join("2026-10-18/launch-sync minutes.pdf").exists());
+    }
+
+    #[test]
+    fn slugs_keep_non_ascii_words() {
+        assert_eq!(slugify("Planning: Q3 / Roadmap"), "planning-q3-roadmap");
+        assert_eq!(slugify("Équipe Réunion"), "équipe-réunion");
+        assert_eq!(slugify("週次 定例"), "週次-定例");
+        assert_eq!(slugify("Встреча 2"), "встреча-2");
+        assert_eq!(slugify("!!! ???"), "meeting");
+        assert_eq!(slugify(&"日".repeat(80)).chars().count(), 60);
+    }
+
+    #[test]
+    fn notes_export_includes_archive_and_edits() {
+        let temp = tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().join("koe"));
+        let metadata = session_metadata();
+        let mut session = SessionHandle::start(&paths, metadata, None).unwrap();
+        let point = |id: &str, text: &str| bullet(id, text, NoteKind::Point);
+
+        session
+            .archive_notes(&[point("n1", "archived point")])
+            .unwrap();
+        assert_eq!(session.notes_archived(), 1);
+        assert_eq!(session.load_archived_notes().unwrap()[0].id, "n1");
+
+        let mut live = MeetingNotes {
+            bullets: vec![point("n2", "live point"), point("n3", "ship Friday")],
+        };
+        live.bullets[0].done = true;
+        live.bullets[0].owner = Some("Sam".to_string());
+        live.bullets[1].kind = NoteKind::Decision;
+        session.export_notes_markdown(&live).unwrap();
+        let notes_md = std::fs::read_to_string(session.export_notes_path().unwrap()).unwrap();
+        assert_eq!(
+            notes_md,
+            "# Notes\n\n## Key Points\n\n- archived point\n- [x] live point (Sam)\n\n## Decisions\n\n- ship Friday\n"
+        );
+    }
+
+    #[test]
+    fn transcript_exports_keep_confidence_and_flag_low_confidence_lines() {
+        let temp = tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().join("koe"));
+        let metadata = session_metadata();
+        let mut session = SessionHandle::start(&paths, metadata, None).unwrap();
+        session.low_confidence_threshold = 0.6;
+        let segment = |id: u64, text: &str, confidence: Option<f32>| TranscriptSegment {
+            id,
+            start_ms: 0,
+            end_ms: 1_000,
+            speaker: Some("Them".to_string()),
+            text: text.to_string(),
+            finalized: true,
+            confidence,
+        };
+        let segments = vec![
+            segment(1, "clear", Some(0.9)),
+            segment(2, "mumbled", Some(0.3)),
+            segment(3, "unscored", None),
+        ];
+
+        session.append_transcript(&segments).unwrap();
+        let jsonl = std::fs::read_to_string(session.transcript_path()).unwrap();
+        let records: Vec<serde_json::Value> = jsonl
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!((records[1]["confidence"].as_f64().unwrap() - 0.3).abs() < 1e-6);
+        assert!(records[2].get("confidence").is_none());
+
+        session.export_transcript_markdown(&segments).unwrap();
+        let markdown = std::fs::read_to_string(session.export_transcript_path().unwrap()).unwrap();
+        assert!(
+            markdown.contains("\n## 00:00\n\n- <a id=\"t-0m00s\"></a>[00:00-00:01] Them: clear\n")
+        );
+        assert!(markdown.contains("<a id=\"t-0m00s-2\"></a>[00:00-00:01] Them: mumbled (?)\n"));
+        assert_eq!(
+            session.transcript_link(&segments, 2).unwrap().as_deref(),
+            Some(
+                format!(
+                    "file://{}#t-0m00s-3",
+                    session.export_transcript_path().unwrap().display()
+                )
+                .as_str()
+            )
+        );
+        assert!(markdown.contains("Them: unscored\n"));
+    }
+
+    #[test]
+    fn renamed_speakers_show_in_exports_but_not_the_stored_labels() {
+        let temp = tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().join("koe"));
+        let metadata = SessionMetadata::new(SessionMetadataInput {
+            participants: vec!["Ana".to_string()],
+            ..session_input()
+        })
+        .unwrap();
+        let mut session = SessionHandle::start(&paths, metadata, None).unwrap();
+        let segments = vec![TranscriptSegment {
+            id: 1,
+            start_ms: 0,
+            end_ms: 1_000,
+            speaker: Some("Them".to_string()),
+            text: "ship it".to_string(),
+            finalized: true,
+            confidence: None,
+        }];
+        session.append_transcript(&segments).unwrap();
+        session.rename_speaker("Them", "Dana").unwrap();
+
+        session.export_transcript_markdown(&segments).unwrap();
+        let markdown = std::fs::read_to_string(session.export_transcript_path().unwrap()).unwrap();
+        assert!(markdown.contains("] Dana: ship it\n"));
+        let jsonl = std::fs::read_to_string(session.transcript_path()).unwrap();
+        assert!(jsonl.contains("\"speaker\":\"Them\""));
+
+        let stored = StoredSession::load(session.session_dir()).unwrap();
+        assert_eq!(stored.metadata.participants, ["Ana", "Dana"]);
+        assert_eq!(
+            stored.transcript().unwrap()[0].speaker.as_deref(),
+            Some("Dana")
+        );
+
+        session.rename_speaker("Them", "Them").unwrap();
+        assert!(session.metadata().speaker_names.is_empty());
+    }
+
+    #[test]
+    fn chapters_replace_minute_headings_in_the_transcript_export() {
+        let temp = tempdir().unwrap();
+        let paths = ConfigPaths::from_base(temp.path().join("koe"));
+        let metadata = session_metadata();
+        let session = SessionHandle::start(&paths, metadata, None).unwrap();
+        let segment = |id: u64, start_ms: i64, text: &str| TranscriptSegment {
+            id,
+            start_ms,
+            end_ms: start_ms + 5_000,
+            speaker: Some("Them".to_string()),
+            text: text.to_string(),
+            finalized: true,
+            confidence: None,
+        };
+        let segments = vec![
+            segment(1, 0, "hello"),
+            segment(2, 30_000, "agenda"),
+            segment(3, 90_000, "budget"),
+        ];
+        assert!(session.load_chapters().unwrap().is_empty());
+        let chapters = vec![
+            Chapter {
+                title: "Intro".to_string(),
+                start_ms: 0,
+                end_ms: 90_000,
+            },
+            Chapter {
+                title: "Budget".to_string(),
+                start_ms: 90_000,
+                end_ms: 95_000,
+            },
+        ];
+        session.write_chapters(&chapters).unwrap();
+        assert_eq!(session.load_chapters().unwrap(), chapters);
+
+        session.export_transcript_markdown(&segments).unwrap();
+        let markdown = std::fs::read_to_string(session.export_transcript_path().unwrap()).unwrap();
+        assert!(markdown.contains("\n## Intro (00:00-01:30)\n\n- <a id=\"t-0m00s\"></a>"));
+        assert!(markdown.contains("Them: agenda\n\n## Budget (01:30-01:35)\n\n"));
+        assert!(!markdown.contains("## 00:00"));
+    }
+
+    #[test]
+    fn transcript_writer_appends_lines_and_parses_policies() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("transcript.jsonl");
+        std::fs::write(&path, []).unwrap();
+        let segment = |id: u64, text: &str| TranscriptSegment {
+            id,
+            start_ms: 0,
+            end_ms: 1_000,
+            speaker: Some("Me".to_string()),
+            text: text.to_string(),
+            finalized: true,
+            confidence: None,
+        };
+
+        let now = Instant::now();
+        let mut writer = TranscriptWriter::open(&path, FsyncPolicy::Interval).unwrap();
+        writer.append(&[segment(1, "first")], now).unwrap();
+        writer
+            .append(&[segment(2, "second")], now + Duration::from_secs(6))
+            .unwrap();
+        writer.sync(now + Duration::from_secs(7)).unwrap();
+
+        let lines: Vec<serde_json::Value> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["text"], "second");
+        assert_eq!(FsyncPolicy::parse("always"), Some(FsyncPolicy::Always));
+        assert_eq!(FsyncPolicy::parse("sometimes"), None);
+    }
+
+    #[test]
+    fn stereo_export_puts_mic_left_and_pads_shorter_track() {
+        let temp = tempdir().unwrap();
+        let mic = temp.path().join("mic.raw");
+        let system = temp.path().join("system.raw");
+        let wav = temp.path().join("stereo.wav");
+        let raw = |samples: &[f32]| {
+            samples
+                .iter()
+                .flat_map(|s| s.to_le_bytes())
+                .collect::<Vec<_>>()
+        };
+        std::fs::write(&mic, raw(&[0.25, 0.5])).unwrap();
+        std::fs::write(&system, raw(&[-0.75])).unwrap();
+
+        write_stereo_wav_from_tracks(&mic, &system, &wav, 48_000).unwrap();
+
+        let bytes = std::fs::read(&wav).unwrap();
+        assert_eq!(u16::from_le_bytes([bytes[22], bytes[23]]), 2);
+        let data = &bytes[bytes.len() - 16..];
+        assert_eq!(data, raw(&[0.25, -0.75, 0.5, 0.0]).as_slice());
+    }
+
+    #[test]
+    fn label_tracks_use_segment_times_and_speakers() {
+        let segments = vec![TranscriptSegment {
+            id: 1,
+            start_ms: 1_500,
+            end_ms: 4_250,
+            speaker: Some("Them".to_string()),
+            text: "say \"hi\"".to_string(),
+            finalized: true,
+            confidence: None,
+        }];
+
+        assert_eq!(
+            audacity_labels(&segments),
+            "1.500000\t4.250000\tThem: say \"hi\"\n"
+        );
+        let csv = reaper_regions(&segments);
+        assert!(csv.starts_with("#,Name,Start,End,Length\n"));
+        assert!(csv.contains("R1,\"Them: say \"\"hi\"\"\",1.500,4.250,2.750"));
+    }
+
+    #[test]
+    fn subtitles_render_srt_and_vtt_cues() {
+        let segments = vec![
+            TranscriptSegment {
+                id: 1,
+                start_ms: 3_723_004,
+                end_ms: 3_725_500,
+                speaker: Some("Me".to_string()),
+                text: "hello there".to_string(),
+                finalized: true,
+                confidence: None,
+            },
+            TranscriptSegment {
+                id: 2,
+                start_ms: 3_726_000,
+                end_ms: 3_727_000,
+                speaker: None,
+                text: "  ".to_string(),
+                finalized: true,
+                confidence: None,
+            },
+        ];
+
+        assert_eq!(
+            subtitles(&segments, SubtitleFormat::Srt),
+            "1\n01:02:03,004 --> 01:02:05,500\nMe: hello there\n\n"
+        );
+        assert_eq!(
+            subtitles(&segments, SubtitleFormat::Vtt),
+            "WEBVTT\n\nt-62m03s\n01:02:03.004 --> 01:02:05.500\n<v Me>hello there\n\n"
+        );
+    }
+
+    #[test]
+    fn show_notes_render_chapters_quotes_and_links() {
+        let notes = ShowNotes {
+            summary: "We talk about shipping.".to_string(),
+            chapters: vec![ShowNotesChapter {
+                start_ms: 65_000,
+                title: "Release Day".to_string(),
+            }],
+            quotes: vec![ShowNotesQuote {
+                start_ms: 70_000,
+                speaker: Some("Them".to_string()),
+                text: "ship it".to_string(),
+            }],
+            links: vec![
+                ShowNotesLink {
+                    label: "Rust".to_string(),
+                    url: "https://rust-lang.org".to_string(),
+                },
+                ShowNotesLink {
+                    label: "The Pragmatic Programmer".to_string(),
+                    url: String::new(),
+                },
+            ],
+        };
+
+        let output = render_show_notes(&notes);
+        assert!(output.contains("## Episode Summary\n\nWe talk about shipping.\n"));
+        assert!(output.contains("- 01:05 Release Day\n"));
+        assert!(output.contains("> \"ship it\" — Them (01:10)\n"));
+        assert!(output.contains("- [Rust](https://rust-lang.org)\n"));
+        assert!(output.contains("- The Pragmatic Programmer\n"));
+    }
+
+    #[test]
+    fn summary_renders_sections_and_checkboxes() {
+        let summary = MeetingSummary {
+            overview: "Planned the launch.".to_string(),
+            decisions: vec!["Ship Friday".to_string()],
+            action_items: vec![
+                SummaryActionItem {
+                    text: "Write changelog".to_string(),
+                    owner: Some("Sam".to_string()),
+                },
+                SummaryActionItem {
+                    text: "Book room".to_string(),
+                    owner: None,
+                },
+            ],
+            open_questions: Vec::new(),
+        };
+
+        assert_eq!(
+            render_summary(&summary),
+            "# Summary\n\n## Overview\n\nPlanned the launch.\n\n## Decisions\n\n- Ship Friday\n\n## Action Items\n\n- [ ] Write changelog (Sam)\n- [ ] Book room\n\n## Open Questions\n\n- (none)\n"
+        );
+    }
+
+    #[test]
+    fn lecture_notes_render_nested_outline_and_anki_csv() {
+        let notes = LectureNotes {
+            outline: vec![OutlineItem {
+                text: "Entropy".to_string(),
+                children: vec![OutlineItem {
+                    text: "Definition".to_string(),
+                    children: Vec::new(),
+                }],
+            }],
+            terms: vec![KeyTerm {
+                term: "Entropy".to_string(),
+                definition: "A measure of disorder".to_string(),
+            }],
+            flashcards: vec![Flashcard {
+                question: "What is \"entropy\", briefly?".to_string(),
+                answer: "Disorder".to_string(),
+            }],
+        };
+
+        let output = render_lecture_notes(&notes);
+        assert!(output.contains("- Entropy\n  - Definition\n"));
+        assert!(output.contains("- **Entropy**: A measure of disorder\n"));
+        assert_eq!(
+            anki_csv(&notes.flashcards),
+            "#separator:comma\n#html:false\n#columns:Front,Back\n\"What is \"\"entropy\"\", briefly?\",Disorder\n"
+        );
     }
 }
@@ -0,0 +1,359 @@
+use crate::config::{Config, ConfigPaths};
+use crate::session::{SessionError, TranscriptScore, find_session, list_sessions, parse_tags};
+use clap::{Args, Subcommand};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+
+#[derive(Args, Debug, Clone)]
+pub struct SessionsArgs {
+    #[command(subcommand)]
+    pub action: SessionsAction,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SessionsAction {
+    /// List sessions newest first as `YYYY-MM-DD title`
+    List {
+        /// Workspace whose sessions dir to list
+        #[arg(long, value_name = "name")]
+        workspace: Option<String>,
+        /// Only sessions carrying this tag
+        #[arg(long, value_name = "tag")]
+        tag: Option<String>,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Add tags to a session, or remove them with --remove
+    Tag {
+        /// Session id or directory
+        session: String,
+        /// Tags separated by commas or spaces, e.g. `planning,q4`
+        tags: String,
+        /// Remove the given tags instead of adding them
+        #[arg(long)]
+        remove: bool,
+        /// Workspace whose sessions dir holds the session
+        #[arg(long, value_name = "name")]
+        workspace: Option<String>,
+    },
+    /// Score the stored transcript against a corrected reference (WER/CER)
+    Score {
+        /// Session id or directory
+        session: String,
+        /// Corrected transcript as plain text
+        #[arg(long, value_name = "path")]
+        reference: PathBuf,
+        /// Workspace whose sessions dir holds the session
+        #[arg(long, value_name = "name")]
+        workspace: Option<String>,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Export an HTML page with an audio player, clickable transcript, and notes
+    Html {
+        /// Session id or directory (defaults to the newest session)
+        session: Option<String>,
+        /// Workspace whose sessions dir holds the session
+        #[arg(long, value_name = "name")]
+        workspace: Option<String>,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum SessionsError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("time error: {0}")]
+    Time(#[from] time::error::Format),
+    #[error("{0}")]
+    Message(String),
+}
+
+/// Substitutions, deletions, and insertions turning the reference into the hypothesis.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct EditCounts {
+    substitutions: usize,
+    deletions: usize,
+    insertions: usize,
+}
+
+impl EditCounts {
+    fn total(self) -> usize {
+        self.substitutions + self.deletions + self.insertions
+    }
+}
+
+pub fn run(args: &SessionsArgs, paths: &ConfigPaths, config: &Config) -> Result<(), SessionsError> {
+    match &args.action {
+        SessionsAction::Score {
+            session,
+            reference,
+            workspace,
+            json,
+        } => {
+            let (session_paths, _) = crate::session_storage(paths, config, workspace.as_deref());
+            let mut stored = find_session(&session_paths.sessions_dir, Some(session))?
+                .ok_or_else(|| SessionsError::Message(format!("session not found: {session}")))?;
+            let hypothesis: Vec<String> = stored
+                .transcript()?
+                .into_iter()
+                .map(|segment| segment.text)
+                .collect();
+            let reference_text = fs::read_to_string(reference)?;
+            let reference_path = reference
+                .canonicalize()
+                .unwrap_or_else(|_| reference.clone());
+            let score = score_transcript(
+                &reference_text,
+                &hypothesis.join(" "),
+                reference_path.display().to_string(),
+                OffsetDateTime::now_utc().format(&Rfc3339)?,
+            )
+            .ok_or_else(|| SessionsError::Message("reference has no words".into()))?;
+            let previous = stored.metadata.score.clone();
+            stored.record_score(score.clone())?;
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&score)?);
+            } else {
+                print_score(&score, previous.as_ref());
+            }
+        }
+        SessionsAction::List {
+            workspace,
+            tag,
+            json,
+        } => {
+            let (session_paths, _) = crate::session_storage(paths, config, workspace.as_deref());
+            let mut sessions = list_sessions(&session_paths.sessions_dir)?;
+            if let Some(tag) = tag {
+                sessions.retain(|session| session.metadata.has_tag(tag));
+            }
+            if *json {
+                let listing: Vec<serde_json::Value> = sessions
+                    .iter()
+                    .map(|session| {
+                        serde_json::json!({
+                            "id": session.metadata.id,
+                            "name": session.metadata.display_name(),
+                            "title": session.metadata.title,
+                            "start_time": session.metadata.start_time,
+                            "finalized": session.metadata.finalized,
+                            "tags": session.metadata.tags,
+                            "dir": session.dir,
+                        })
+                    })
+                    .collect();
+                println!("{}", serde_json::to_string_pretty(&listing)?);
+            } else {
+                for session in &sessions {
+                    let tags: String = session
+                        .metadata
+                        .tags
+                        .iter()
+                        .map(|tag| format!("  #{tag}"))
+                        .collect();
+                    println!(
+                        "{}  {}{tags}{}",
+                        session.metadata.display_name(),
+                        session.metadata.id,
+                        if session.metadata.finalized {
+                            ""
+                        } else {
+                            "  (in progress)"
+                        }
+                    );
+                }
+            }
+        }
+        SessionsAction::Tag {
+            session,
+            tags,
+            remove,
+            workspace,
+        } => {
+            let (session_paths, _) = crate::session_storage(paths, config, workspace.as_deref());
+            let mut stored = find_session(&session_paths.sessions_dir, Some(session))?
+                .ok_or_else(|| SessionsError::Message(format!("session not found: {session}")))?;
+            let given = parse_tags(tags);
+            if given.is_empty() {
+                return Err(SessionsError::Message("no tags given".into()));
+            }
+            let mut updated = stored.metadata.tags.clone();
+            if *remove {
+                updated.retain(|tag| !given.contains(tag));
+            } else {
+                for tag in given {
+                    if !updated.contains(&tag) {
+                        updated.push(tag);
+                    }
+                }
+            }
+            stored.set_tags(updated)?;
+            println!(
+                "{}  {}",
+                stored.metadata.display_name(),
+                stored
+                    .metadata
+                    .tags
+                    .iter()
+                    .map(|tag| format!("#{tag}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            );
+        }
+        SessionsAction::Html { session, workspace } => {
+            let (session_paths, export_dir) =
+                crate::session_storage(paths, config, workspace.as_deref());
+            let stored = find_session(&session_paths.sessions_dir, session.as_deref())?
+                .ok_or_else(|| {
+                    SessionsError::Message(match session {
+                        Some(id) => format!("session not found: {id}"),
+                        None => "no sessions recorded yet".to_string(),
+                    })
+                })?;
+            let segments = stored.transcript()?;
+            let notes = stored.notes()?;
+            let handle = stored.reopen(
+                &session_paths,
+                export_dir,
+                config.session.low_confidence_threshold,
+            )?;
+            println!("{}", handle.export_html(&segments, &notes)?.display());
+        }
+    }
+    Ok(())
+}
+
+fn print_score(score: &TranscriptScore, previous: Option<&TranscriptScore>) {
+    println!(
+        "WER {:.1}%  ({} substitutions, {} deletions, {} insertions over {} words)",
+        score.wer * 100.0,
+        score.substitutions,
+        score.deletions,
+        score.insertions,
+        score.reference_words
+    );
+    println!("CER {:.1}%", score.cer * 100.0);
+    if let Some(previous) = previous {
+        println!(
+            "previous WER {:.1}%, CER {:.1}% ({})",
+            previous.wer * 100.0,
+            previous.cer * 100.0,
+            previous.scored_at
+        );
+    }
+}
+
+/// WER and CER of `hypothesis` against `reference`, after lower-casing and
+/// dropping punctuation. `None` when the reference has no words.
+fn score_transcript(
+    reference: &str,
+    hypothesis: &str,
+    reference_file: String,
+    scored_at: String,
+) -> Option<TranscriptScore> {
+    let reference_words = words(reference);
+    if reference_words.is_empty() {
+        return None;
+    }
+    let hypothesis_words = words(hypothesis);
+    let word_edits = edit_counts(&reference_words, &hypothesis_words);
+    let reference_chars: Vec<char> = reference_words.join(" ").chars().collect();
+    let hypothesis_chars: Vec<char> = hypothesis_words.join(" ").chars().collect();
+    let char_edits = edit_counts(&reference_chars, &hypothesis_chars);
+    Some(TranscriptScore {
+        reference_file,
+        scored_at,
+        wer: word_edits.total() as f64 / reference_words.len() as f64,
+        cer: char_edits.total() as f64 / reference_chars.len() as f64,
+        reference_words: reference_words.len(),
+        substitutions: word_edits.substitutions,
+        deletions: word_edits.deletions,
+        insertions: word_edits.insertions,
+    })
+}
+
+/// Lower-cased words; apostrophes inside words are kept ("don't").
+fn words(text: &str) -> Vec<String> {
+    text.split(|ch: char| !(ch.is_alphanumeric() || ch == '\'' || ch == '’'))
+        .map(|word| {
+            word.trim_matches(['\'', '’'])
+                .replace('’', "'")
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Levenshtein alignment keeping only two rows, so hour-long transcripts fit in memory.
+fn edit_counts<T: PartialEq>(reference: &[T], hypothesis: &[T]) -> EditCounts {
+    let mut previous: Vec<EditCounts> = (0..=hypothesis.len())
+        .map(|insertions| EditCounts {
+            insertions,
+            ..EditCounts::default()
+        })
+        .collect();
+    let mut current = previous.clone();
+    for (row, expected) in reference.iter().enumerate() {
+        current[0] = EditCounts {
+            deletions: row + 1,
+            ..EditCounts::default()
+        };
+        for (col, actual) in hypothesis.iter().enumerate() {
+            let mut diagonal = previous[col];
+            if expected != actual {
+                diagonal.substitutions += 1;
+            }
+            let mut deletion = previous[col + 1];
+            deletion.deletions += 1;
+            let mut insertion = current[col];
+            insertion.insertions += 1;
+            current[col + 1] = [diagonal, deletion, insertion]
+                .into_iter()
+                .min_by_key(|counts| counts.total())
+                .unwrap_or(diagonal);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[hypothesis.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::score_transcript;
+
+    #[test]
+    fn scores_word_and_character_errors() {
+        let score = score_transcript(
+            "The cat sat on the mat. Don't move!",
+            "the cat sit on mat don’t move today",
+            "ref.txt".into(),
+            "2026-10-17T10:00:00Z".into(),
+        )
+        .expect("score");
+        assert_eq!(score.reference_words, 8);
+        assert_eq!(
+            (score.substitutions, score.deletions, score.insertions),
+            (1, 1, 1)
+        );
+        assert!((score.wer - 3.0 / 8.0).abs() < 1e-9);
+        // "sat"->"sit" is one character, "the " four, " today" six.
+        let reference_chars = "the cat sat on the mat don't move".len() as f64;
+        assert!((score.cer - 11.0 / reference_chars).abs() < 1e-9);
+
+        let perfect = score_transcript("Hello, world", "hello world", String::new(), String::new())
+            .expect("score");
+        assert_eq!((perfect.wer, perfect.cer), (0.0, 0.0));
+        assert!(score_transcript("...", "words", String::new(), String::new()).is_none());
+    }
+}
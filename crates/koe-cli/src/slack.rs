@@ -0,0 +1,155 @@
+use crate::config::SlackConfig;
+use crate::session::{SessionError, StoredSession};
+use koe_core::types::{MeetingNotes, MeetingSummary};
+use serde_json::json;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+use thiserror::Error;
+
+const SLACK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Error)]
+pub enum SlackError {
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("slack post failed: {0}")]
+    Post(String),
+}
+
+/// Post `text` to a Slack incoming webhook; `channel` overrides the webhook's
+/// default where the webhook allows it.
+pub fn post(webhook: &str, channel: Option<&str>, text: &str) -> Result<(), SlackError> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(SLACK_TIMEOUT))
+        .build()
+        .into();
+    let mut body = json!({ "text": text });
+    if let Some(channel) = channel.map(str::trim).filter(|channel| !channel.is_empty()) {
+        body["channel"] = json!(channel);
+    }
+    agent
+        .post(webhook.trim())
+        .send_json(&body)
+        .map_err(|e| SlackError::Post(e.to_string()))?;
+    Ok(())
+}
+
+/// Post a finished session's summary overview and action items to the
+/// configured channel.
+pub fn post_minutes(config: &SlackConfig, session_dir: &Path) -> Result<(), SlackError> {
+    let session = StoredSession::load(session_dir)?;
+    let notes = session.notes()?;
+    let summary: Option<MeetingSummary> = match fs::read_to_string(session_dir.join("summary.json"))
+    {
+        Ok(contents) => Some(serde_json::from_str(&contents)?),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+    let title = session.metadata.title.clone().unwrap_or_else(|| {
+        format!(
+            "Meeting {}",
+            session.metadata.start_time.get(..10).unwrap_or_default()
+        )
+    });
+    post(
+        &config.webhook,
+        Some(&config.channel),
+        &minutes_message(&title, summary.as_ref(), &notes),
+    )
+}
+
+/// Slack mrkdwn: bold title, the summary overview, then action items with
+/// owners and due dates; the notes' actions stand in when there is no summary.
+fn minutes_message(title: &str, summary: Option<&MeetingSummary>, notes: &MeetingNotes) -> String {
+    let mut message = format!("*{}*\n", title.trim());
+    if let Some(overview) = summary
+        .map(|summary| summary.overview.trim())
+        .filter(|overview| !overview.is_empty())
+    {
+        message.push_str(&format!("\n{overview}\n"));
+    }
+
+    let mut actions: Vec<String> = crate::reminders::action_items(notes)
+        .map(|bullet| {
+            let mut line = format!("• {}", bullet.text.trim());
+            match (bullet.owner.as_deref(), bullet.due.as_deref()) {
+                (Some(owner), Some(due)) => line.push_str(&format!(" ({owner}, due {due})")),
+                (Some(owner), None) => line.push_str(&format!(" ({owner})")),
+                (None, Some(due)) => line.push_str(&format!(" (due {due})")),
+                (None, None) => {}
+            }
+            if bullet.done {
+                line.push_str(" ✓");
+            }
+            line
+        })
+        .collect();
+    if actions.is_empty()
+        && let Some(summary) = summary
+    {
+        actions = summary
+            .action_items
+            .iter()
+            .map(|item| match item.owner.as_deref() {
+                Some(owner) => format!("• {} ({owner})", item.text.trim()),
+                None => format!("• {}", item.text.trim()),
+            })
+            .collect();
+    }
+    message.push_str("\n*Action items*\n");
+    if actions.is_empty() {
+        message.push_str("(none)\n");
+    } else {
+        message.push_str(&actions.join("\n"));
+        message.push('\n');
+    }
+    // Slack reads `<...>` as links and mentions.
+    message
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::minutes_message;
+    use crate::test_support::bullet;
+    use koe_core::types::{MeetingNotes, MeetingSummary, NoteBullet, NoteKind, SummaryActionItem};
+
+    #[test]
+    fn minutes_list_overview_and_action_items() {
+        let summary = MeetingSummary {
+            overview: "Agreed to ship on Friday.".to_string(),
+            decisions: Vec::new(),
+            action_items: vec![SummaryActionItem {
+                text: "Draft the <launch> announcement".to_string(),
+                owner: Some("Ana".to_string()),
+            }],
+            open_questions: Vec::new(),
+        };
+        let empty = MeetingNotes::default();
+        assert_eq!(
+            minutes_message("Launch sync", Some(&summary), &empty),
+            "*Launch sync*\n\nAgreed to ship on Friday.\n\n*Action items*\n• Draft the &lt;launch&gt; announcement (Ana)\n"
+        );
+
+        let notes = MeetingNotes {
+            bullets: vec![NoteBullet {
+                done: true,
+                owner: Some("Sam".to_string()),
+                due: Some("2026-10-23".to_string()),
+                ..bullet("n1", "Write release notes", NoteKind::Action)
+            }],
+        };
+        assert_eq!(
+            minutes_message("Launch sync", None, &notes),
+            "*Launch sync*\n\n*Action items*\n• Write release notes (Sam, due 2026-10-23) ✓\n"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use crate::audit::AuditLog;
+use crate::config::{Config, ConfigPaths};
+use crate::session::SessionHandle;
+use clap::Args;
+use koe_core::types::TranscriptSegment;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+const SHARE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Weeks listed in `koe stats --usage`, newest last.
+const WEEKS_SHOWN: usize = 8;
+
+#[derive(Args, Debug, Clone)]
+pub struct StatsArgs {
+    /// Local usage metrics: meetings per week, minutes transcribed, provider mix, error rates
+    #[arg(long)]
+    pub usage: bool,
+
+    /// Send the aggregate (no transcripts, names, or context) to metrics.share_url;
+    /// requires metrics.share = true
+    #[arg(long, requires = "usage")]
+    pub share: bool,
+
+    /// Print JSON instead of text
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Error)]
+pub enum StatsError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{0}")]
+    Message(String),
+}
+
+/// Request outcomes for the current meeting, shared by the worker threads.
+#[derive(Debug, Clone, Default)]
+pub struct MeetingCounters {
+    transcribe_requests: Arc<AtomicU64>,
+    transcribe_failures: Arc<AtomicU64>,
+    summarize_requests: Arc<AtomicU64>,
+    summarize_failures: Arc<AtomicU64>,
+}
+
+impl MeetingCounters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_transcribe(&self, ok: bool) {
+        record(&self.transcribe_requests, &self.transcribe_failures, ok);
+    }
+
+    pub fn record_summarize(&self, ok: bool) {
+        record(&self.summarize_requests, &self.summarize_failures, ok);
+    }
+
+    /// Meeting totals for `session`, resetting the counters for the next meeting.
+    pub fn finish(
+        &self,
+        session: &SessionHandle,
+        segments: &[TranscriptSegment],
+    ) -> MeetingMetrics {
+        let (year, week, _) = OffsetDateTime::now_utc().to_iso_week_date();
+        let end_ms = segments.iter().map(|segment| segment.end_ms).max();
+        let metadata = session.metadata();
+        MeetingMetrics {
+            week: format!("{year}-W{week:02}"),
+            minutes: end_ms.unwrap_or(0).max(0) as f64 / 60_000.0,
+            transcribe_provider: metadata.transcribe_provider.clone(),
+            summarize_provider: metadata.summarize_provider.clone(),
+            transcribe_requests: self.transcribe_requests.swap(0, Ordering::Relaxed),
+            transcribe_failures: self.transcribe_failures.swap(0, Ordering::Relaxed),
+            summarize_requests: self.summarize_requests.swap(0, Ordering::Relaxed),
+            summarize_failures: self.summarize_failures.swap(0, Ordering::Relaxed),
+        }
+    }
+}
+
+fn record(requests: &AtomicU64, failures: &AtomicU64, ok: bool) {
+    requests.fetch_add(1, Ordering::Relaxed);
+    if !ok {
+        failures.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// One finished meeting, a line of `~/.koe/metrics.jsonl`. Holds no transcript,
+/// names, context, or session id.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeetingMetrics {
+    /// ISO week the meeting ended in, e.g. `2026-W42`.
+    pub week: String,
+    pub minutes: f64,
+    pub transcribe_provider: String,
+    pub summarize_provider: String,
+    pub transcribe_requests: u64,
+    pub transcribe_failures: u64,
+    pub summarize_requests: u64,
+    pub summarize_failures: u64,
+}
+
+pub fn append(path: &Path, metrics: &MeetingMetrics) -> io::Result<()> {
+    let mut line = serde_json::to_string(metrics)?;
+    line.push('\n');
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(line.as_bytes())
+}
+
+fn load(path: &Path) -> io::Result<Vec<MeetingMetrics>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct WeekUsage {
+    pub week: String,
+    pub meetings: usize,
+    pub minutes: f64,
+}
+
+/// Aggregate over every recorded meeting; this is all `--share` ever sends.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UsageSummary {
+    pub meetings: usize,
+    pub minutes: f64,
+    pub weeks: Vec<WeekUsage>,
+    /// Meetings per `transcribe:<provider>` and `summarize:<provider>`.
+    pub providers: BTreeMap<String, usize>,
+    pub transcribe_error_rate: f64,
+    pub summarize_error_rate: f64,
+}
+
+impl UsageSummary {
+    pub fn from_meetings(meetings: &[MeetingMetrics]) -> Self {
+        let mut weeks: BTreeMap<&str, WeekUsage> = BTreeMap::new();
+        let mut providers = BTreeMap::new();
+        let (mut transcribe, mut transcribe_failed) = (0, 0);
+        let (mut summarize, mut summarize_failed) = (0, 0);
+        for meeting in meetings {
+            let week = weeks
+                .entry(meeting.week.as_str())
+                .or_insert_with(|| WeekUsage {
+                    week: meeting.week.clone(),
+                    ..WeekUsage::default()
+                });
+            week.meetings += 1;
+            week.minutes += meeting.minutes;
+            *providers
+                .entry(format!("transcribe:{}", meeting.transcribe_provider))
+                .or_insert(0) += 1;
+            *providers
+                .entry(format!("summarize:{}", meeting.summarize_provider))
+                .or_insert(0) += 1;
+            transcribe += meeting.transcribe_requests;
+            transcribe_failed += meeting.transcribe_failures;
+            summarize += meeting.summarize_requests;
+            summarize_failed += meeting.summarize_failures;
+        }
+        let rate = |failed: u64, total: u64| {
+            if total == 0 {
+                0.0
+            } else {
+                failed as f64 / total as f64
+            }
+        };
+        let weeks: Vec<WeekUsage> = weeks.into_values().collect();
+        let skip = weeks.len().saturating_sub(WEEKS_SHOWN);
+        Self {
+            meetings: meetings.len(),
+            minutes: meetings.iter().map(|meeting| meeting.minutes).sum(),
+            weeks: weeks.into_iter().skip(skip).collect(),
+            providers,
+            transcribe_error_rate: rate(transcribe_failed, transcribe),
+            summarize_error_rate: rate(summarize_failed, summarize),
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut out = format!(
+            "meetings: {}  minutes transcribed: {:.0}\n",
+            self.meetings, self.minutes
+        );
+        out.push_str("per week:\n");
+        for week in &self.weeks {
+            out.push_str(&format!(
+                "  {}  {} meeting(s)  {:.0} min\n",
+                week.week, week.meetings, week.minutes
+            ));
+        }
+        out.push_str("providers:\n");
+        for (provider, meetings) in &self.providers {
+            out.push_str(&format!("  {provider}  {meetings}\n"));
+        }
+        out.push_str(&format!(
+            "error rate: transcribe {:.1}%  summarize {:.1}%\n",
+            self.transcribe_error_rate * 100.0,
+            self.summarize_error_rate * 100.0
+        ));
+        out
+    }
+}
+
+pub fn run(args: &StatsArgs, paths: &ConfigPaths, config: &Config) -> Result<(), StatsError> {
+    if !args.usage {
+        return Err(StatsError::Message(
+            "nothing selected; pass --usage".to_string(),
+        ));
+    }
+    let summary = UsageSummary::from_meetings(&load(&paths.metrics_path)?);
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else if summary.meetings == 0 {
+        println!(
+            "no meetings recorded yet{}",
+            if config.metrics.enabled {
+                ""
+            } else {
+                " (metrics.enabled = false)"
+            }
+        );
+    } else {
+        print!("{}", summary.render());
+    }
+    if args.share {
+        share(&summary, paths, config)?;
+    }
+    Ok(())
+}
+
+fn share(summary: &UsageSummary, paths: &ConfigPaths, config: &Config) -> Result<(), StatsError> {
+    let url = config.metrics.share_url.trim();
+    if !config.metrics.share || url.is_empty() {
+        return Err(StatsError::Message(
+            "sharing is off; set metrics.share = true and metrics.share_url to opt in".to_string(),
+        ));
+    }
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(SHARE_TIMEOUT))
+        .build()
+        .into();
+    agent
+        .post(url)
+        .send_json(summary)
+        .map_err(|e| StatsError::Message(format!("share failed: {e}")))?;
+    AuditLog::new(paths).record("metrics_share", url, None)?;
+    eprintln!("shared usage aggregate with {url}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MeetingMetrics, UsageSummary};
+
+    fn meeting(week: &str, minutes: f64, transcribe: &str, failures: u64) -> MeetingMetrics {
+        MeetingMetrics {
+            week: week.to_string(),
+            minutes,
+            transcribe_provider: transcribe.to_string(),
+            summarize_provider: "openrouter".to_string(),
+            transcribe_requests: 10,
+            transcribe_failures: failures,
+            summarize_requests: 4,
+            summarize_failures: 0,
+        }
+    }
+
+    #[test]
+    fn summary_groups_weeks_providers_and_error_rates() {
+        let summary = UsageSummary::from_meetings(&[
+            meeting("2026-W41", 30.0, "groq", 1),
+            meeting("2026-W42", 15.0, "whisper", 0),
+            meeting("2026-W42", 45.0, "groq", 3),
+        ]);
+        assert_eq!(summary.meetings, 3);
+        assert_eq!(summary.minutes, 90.0);
+        assert_eq!(summary.weeks.len(), 2);
+        assert_eq!(summary.weeks[1].meetings, 2);
+        assert_eq!(summary.weeks[1].minutes, 60.0);
+        assert_eq!(summary.providers["transcribe:groq"], 2);
+        assert_eq!(summary.providers["summarize:openrouter"], 3);
+        assert!((summary.transcribe_error_rate - 4.0 / 30.0).abs() < 1e-9);
+        assert_eq!(summary.summarize_error_rate, 0.0);
+    }
+}
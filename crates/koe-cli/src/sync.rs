@@ -0,0 +1,568 @@
+use crate::checksum::{hmac_sha256, sha256_hex, to_hex};
+use crate::config::{Config, ConfigPaths, SyncConfig};
+use crate::session::{SessionError, list_sessions};
+use crate::tui::UiEvent;
+use clap::{Args, Subcommand};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::{Duration, UNIX_EPOCH};
+use thiserror::Error;
+use time::OffsetDateTime;
+
+/// Per-session record of what has been uploaded, kept in the session dir.
+const MANIFEST_FILE: &str = ".sync.json";
+const SYNC_TIMEOUT: Duration = Duration::from_secs(300);
+const AUDIO_EXTENSIONS: &[&str] = &["raw", "wav", "flac", "opus", "ogg", "m4a", "mp3"];
+
+#[derive(Args, Debug, Clone)]
+pub struct SyncArgs {
+    #[command(subcommand)]
+    pub action: SyncAction,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SyncAction {
+    /// List finalized sessions with files not yet uploaded
+    Status {
+        /// Workspace whose sessions dir to check
+        #[arg(long, value_name = "name")]
+        workspace: Option<String>,
+    },
+    /// Upload pending files now instead of waiting for the background task
+    Push {
+        /// Workspace whose sessions dir to upload
+        #[arg(long, value_name = "name")]
+        workspace: Option<String>,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum SyncError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("upload of {key} failed: {message}")]
+    Upload { key: String, message: String },
+    #[error("{0}")]
+    Message(String),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    /// Remote the files went to; a different one makes everything pending again.
+    remote: String,
+    /// Relative path to the size and mtime it had when uploaded.
+    files: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingFile {
+    /// Path inside the session dir, `/`-separated.
+    pub relative: String,
+    pub size: u64,
+    fingerprint: String,
+}
+
+pub fn run(args: &SyncArgs, paths: &ConfigPaths, config: &Config) -> Result<(), SyncError> {
+    let (SyncAction::Status { workspace } | SyncAction::Push { workspace }) = &args.action;
+    let scoped = config.for_workspace(workspace.as_deref());
+    let config = &scoped;
+    let sync = &config.sync;
+    if !sync.is_enabled() {
+        return Err(SyncError::Message(
+            "sync is disabled (set sync.backend to s3 or webdav)".to_string(),
+        ));
+    }
+    match &args.action {
+        SyncAction::Status { workspace } => {
+            let (session_paths, _) = crate::session_storage(paths, config, workspace.as_deref());
+            println!("remote: {}", remote_label(sync));
+            let (mut files, mut bytes, mut sessions) = (0, 0, 0);
+            for session in list_sessions(&session_paths.sessions_dir)? {
+                if !session.metadata.finalized {
+                    continue;
+                }
+                let pending = pending_files(sync, &session.dir)?;
+                if pending.is_empty() {
+                    continue;
+                }
+                let size: u64 = pending.iter().map(|file| file.size).sum();
+                println!(
+                    "{}  {} file(s)  {}",
+                    session.metadata.id,
+                    pending.len(),
+                    format_bytes(size)
+                );
+                sessions += 1;
+                files += pending.len();
+                bytes += size;
+            }
+            if sessions == 0 {
+                println!("all finalized sessions are uploaded");
+            } else {
+                println!(
+                    "pending: {files} file(s) in {sessions} session(s), {}",
+                    format_bytes(bytes)
+                );
+            }
+        }
+        SyncAction::Push { workspace } => {
+            let (session_paths, _) = crate::session_storage(paths, config, workspace.as_deref());
+            let uploaded = sync_all(sync, &session_paths.sessions_dir)?;
+            println!("uploaded {uploaded} file(s) to {}", remote_label(sync));
+        }
+    }
+    Ok(())
+}
+
+/// Upload pending files of finalized sessions every `interval_secs` until the
+/// process exits; failures show up as UI errors, once per distinct message.
+pub fn spawn_sync(config: SyncConfig, sessions_dir: PathBuf, ui_tx: Sender<UiEvent>) {
+    let interval = Duration::from_secs(u64::from(config.interval_secs.max(1)));
+    let spawned = thread::Builder::new()
+        .name("koe-sync".into())
+        .spawn(move || {
+            let mut last_error: Option<String> = None;
+            loop {
+                match sync_all(&config, &sessions_dir) {
+                    Ok(_) => last_error = None,
+                    Err(e) => {
+                        let message = format!("sync failed: {e}");
+                        if last_error.as_ref() != Some(&message)
+                            && ui_tx
+                                .send(UiEvent::Error {
+                                    message: message.clone(),
+                                })
+                                .is_err()
+                        {
+                            return;
+                        }
+                        last_error = Some(message);
+                    }
+                }
+                thread::sleep(interval);
+            }
+        });
+    if let Err(e) = spawned {
+        eprintln!("sync thread spawn failed: {e}");
+    }
+}
+
+/// Upload every finalized session's pending files; a failing session does not
+/// hold back the rest, its first error is returned afterwards.
+pub fn sync_all(config: &SyncConfig, sessions_dir: &Path) -> Result<usize, SyncError> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(SYNC_TIMEOUT))
+        .build()
+        .into();
+    let mut uploaded = 0;
+    let mut first_error = None;
+    for session in list_sessions(sessions_dir)? {
+        if !session.metadata.finalized {
+            continue;
+        }
+        match sync_session(config, &agent, &session.dir) {
+            Ok(count) => uploaded += count,
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    match first_error {
+        Some(e) => Err(e),
+        None => Ok(uploaded),
+    }
+}
+
+fn sync_session(
+    config: &SyncConfig,
+    agent: &ureq::Agent,
+    session_dir: &Path,
+) -> Result<usize, SyncError> {
+    let pending = pending_files(config, session_dir)?;
+    if pending.is_empty() {
+        return Ok(0);
+    }
+    let mut manifest = read_manifest(config, session_dir)?;
+    let session_name = session_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut created = Vec::new();
+    for file in &pending {
+        let key = remote_key(config, &session_name, &file.relative);
+        let contents = fs::read(session_dir.join(&file.relative))?;
+        match config.backend.trim() {
+            "s3" => put_s3(config, agent, &key, &contents)?,
+            _ => put_webdav(config, agent, &key, &contents, &mut created)?,
+        }
+        manifest
+            .files
+            .insert(file.relative.clone(), file.fingerprint.clone());
+        fs::write(
+            session_dir.join(MANIFEST_FILE),
+            serde_json::to_vec_pretty(&manifest)?,
+        )?;
+    }
+    Ok(pending.len())
+}
+
+/// Files changed or added since the last upload to the configured remote,
+/// leaving out audio unless `include_audio` is set.
+pub fn pending_files(
+    config: &SyncConfig,
+    session_dir: &Path,
+) -> Result<Vec<PendingFile>, SyncError> {
+    let manifest = read_manifest(config, session_dir)?;
+    let mut files = Vec::new();
+    collect_files(session_dir, "", &mut files)?;
+    Ok(files
+        .into_iter()
+        .filter(|file| file.relative != MANIFEST_FILE)
+        .filter(|file| config.include_audio || !is_audio(&file.relative))
+        .filter(|file| manifest.files.get(&file.relative) != Some(&file.fingerprint))
+        .collect())
+}
+
+fn collect_files(dir: &Path, prefix: &str, files: &mut Vec<PendingFile>) -> io::Result<()> {
+    let mut entries: Vec<_> = fs::read_dir(dir)?.filter_map(Result::ok).collect();
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let relative = format!("{prefix}{}", entry.file_name().to_string_lossy());
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            collect_files(&entry.path(), &format!("{relative}/"), files)?;
+        } else if metadata.is_file() && !relative.ends_with(".tmp") {
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|since| since.as_nanos())
+                .unwrap_or_default();
+            files.push(PendingFile {
+                relative,
+                size: metadata.len(),
+                fingerprint: format!("{}:{modified}", metadata.len()),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn read_manifest(config: &SyncConfig, session_dir: &Path) -> Result<Manifest, SyncError> {
+    let manifest: Manifest = match fs::read(session_dir.join(MANIFEST_FILE)) {
+        Ok(contents) => serde_json::from_slice(&contents)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Manifest::default(),
+        Err(err) => return Err(err.into()),
+    };
+    let remote = remote_label(config);
+    Ok(if manifest.remote == remote {
+        manifest
+    } else {
+        Manifest {
+            remote,
+            files: BTreeMap::new(),
+        }
+    })
+}
+
+fn is_audio(relative: &str) -> bool {
+    Path::new(relative)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            AUDIO_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str())
+        })
+}
+
+fn remote_label(config: &SyncConfig) -> String {
+    let prefix = config.prefix.trim().trim_matches('/');
+    match config.backend.trim() {
+        "s3" => format!("s3://{}/{prefix}", config.s3.bucket.trim()),
+        _ => format!(
+            "{}/{prefix}",
+            config.webdav.url.trim().trim_end_matches('/')
+        ),
+    }
+}
+
+/// `prefix/session/relative`, each segment percent-encoded.
+fn remote_key(config: &SyncConfig, session_name: &str, relative: &str) -> String {
+    config
+        .prefix
+        .split('/')
+        .chain([session_name])
+        .chain(relative.split('/'))
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .map(uri_encode)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn put_s3(
+    config: &SyncConfig,
+    agent: &ureq::Agent,
+    key: &str,
+    contents: &[u8],
+) -> Result<(), SyncError> {
+    let endpoint = config.s3.endpoint.trim().trim_end_matches('/');
+    let path = format!("/{}/{key}", uri_encode(config.s3.bucket.trim()));
+    let headers = s3_headers(config, endpoint, &path, contents, OffsetDateTime::now_utc());
+    let mut request = agent.put(format!("{endpoint}{path}"));
+    for (name, value) in &headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+    request.send(contents).map_err(|e| SyncError::Upload {
+        key: key.to_string(),
+        message: e.to_string(),
+    })?;
+    Ok(())
+}
+
+/// AWS Signature Version 4 headers for a path-style `PUT`.
+fn s3_headers(
+    config: &SyncConfig,
+    endpoint: &str,
+    path: &str,
+    contents: &[u8],
+    now: OffsetDateTime,
+) -> Vec<(String, String)> {
+    let s3 = &config.s3;
+    let host = endpoint
+        .split_once("://")
+        .map_or(endpoint, |(_, rest)| rest)
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    let date = format!(
+        "{:04}{:02}{:02}",
+        now.year(),
+        u8::from(now.month()),
+        now.day()
+    );
+    let timestamp = format!(
+        "{date}T{:02}{:02}{:02}Z",
+        now.hour(),
+        now.minute(),
+        now.second()
+    );
+    let payload_hash = sha256_hex(contents);
+    let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+    let canonical_request = format!(
+        "PUT\n{path}\n\nhost:{host}\nx-amz-content-sha256:{payload_hash}\nx-amz-date:{timestamp}\n\n{signed_headers}\n{payload_hash}"
+    );
+    let region = s3.region.trim();
+    let scope = format!("{date}/{region}/s3/aws4_request");
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{timestamp}\n{scope}\n{}",
+        sha256_hex(canonical_request.as_bytes())
+    );
+    let mut key = hmac_sha256(
+        format!("AWS4{}", s3.secret_access_key.trim()).as_bytes(),
+        date.as_bytes(),
+    );
+    for part in [region, "s3", "aws4_request"] {
+        key = hmac_sha256(&key, part.as_bytes());
+    }
+    let signature = to_hex(&hmac_sha256(&key, string_to_sign.as_bytes()));
+    vec![
+        (
+            "Authorization".to_string(),
+            format!(
+                "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+                s3.access_key_id.trim()
+            ),
+        ),
+        ("x-amz-content-sha256".to_string(), payload_hash),
+        ("x-amz-date".to_string(), timestamp),
+    ]
+}
+
+/// `PUT` under the collection URL, creating missing folders with `MKCOL`
+/// first; `created` remembers folders made during this pass.
+fn put_webdav(
+    config: &SyncConfig,
+    agent: &ureq::Agent,
+    key: &str,
+    contents: &[u8],
+    created: &mut Vec<String>,
+) -> Result<(), SyncError> {
+    let webdav = &config.webdav;
+    let base = webdav.url.trim().trim_end_matches('/');
+    let auth = (!webdav.username.trim().is_empty()).then(|| {
+        format!(
+            "Basic {}",
+            base64(format!("{}:{}", webdav.username.trim(), webdav.password).as_bytes())
+        )
+    });
+    let upload_error = |message: String| SyncError::Upload {
+        key: key.to_string(),
+        message,
+    };
+    let segments: Vec<&str> = key.split('/').collect();
+    for depth in 1..segments.len() {
+        let folder = segments[..depth].join("/");
+        if created.contains(&folder) {
+            continue;
+        }
+        let mut request = ureq::http::Request::builder()
+            .method("MKCOL")
+            .uri(format!("{base}/{folder}/"));
+        if let Some(auth) = &auth {
+            request = request.header("Authorization", auth);
+        }
+        let request = request.body(()).map_err(|e| upload_error(e.to_string()))?;
+        match agent.run(request) {
+            // 405: the folder already exists.
+            Ok(_) | Err(ureq::Error::StatusCode(405)) => created.push(folder),
+            Err(e) => return Err(upload_error(e.to_string())),
+        }
+    }
+    let mut request = agent.put(format!("{base}/{key}"));
+    if let Some(auth) = &auth {
+        request = request.header("Authorization", auth);
+    }
+    request
+        .send(contents)
+        .map_err(|e| upload_error(e.to_string()))?;
+    Ok(())
+}
+
+fn uri_encode(segment: &str) -> String {
+    let mut out = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char);
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let triple = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(triple >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn format_bytes(bytes: u64) -> String {
+    if bytes >= 1 << 20 {
+        format!("{:.1} MB", bytes as f64 / f64::from(1 << 20))
+    } else if bytes >= 1 << 10 {
+        format!("{:.1} KB", bytes as f64 / f64::from(1 << 10))
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        MANIFEST_FILE, Manifest, base64, pending_files, remote_key, remote_label, s3_headers,
+    };
+    use crate::config::SyncConfig;
+    use std::fs;
+
+    #[test]
+    fn pending_files_skip_uploaded_and_optionally_audio() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        fs::write(dir.join("transcript.jsonl"), "{}\n").unwrap();
+        fs::write(dir.join("audio-1.raw"), [0u8; 8]).unwrap();
+        fs::create_dir(dir.join("clips")).unwrap();
+        fs::write(dir.join("clips/intro.wav"), [0u8; 4]).unwrap();
+        let mut config = SyncConfig {
+            backend: "s3".to_string(),
+            ..SyncConfig::default()
+        };
+        config.s3.bucket = "notes".to_string();
+
+        let relative = |config: &SyncConfig| -> Vec<String> {
+            pending_files(config, dir)
+                .unwrap()
+                .into_iter()
+                .map(|file| file.relative)
+                .collect()
+        };
+        assert_eq!(
+            relative(&config),
+            ["audio-1.raw", "clips/intro.wav", "transcript.jsonl"]
+        );
+
+        let uploaded = pending_files(&config, dir).unwrap();
+        let manifest = Manifest {
+            remote: remote_label(&config),
+            files: uploaded
+                .iter()
+                .filter(|file| file.relative == "transcript.jsonl")
+                .map(|file| (file.relative.clone(), file.fingerprint.clone()))
+                .collect(),
+        };
+        fs::write(
+            dir.join(MANIFEST_FILE),
+            serde_json::to_vec(&manifest).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(relative(&config), ["audio-1.raw", "clips/intro.wav"]);
+
+        config.include_audio = false;
+        assert!(relative(&config).is_empty());
+        config.s3.bucket = "elsewhere".to_string();
+        assert_eq!(relative(&config), ["transcript.jsonl"]);
+
+        assert_eq!(
+            remote_key(&config, "2026-10-18 sync", "clips/a b.wav"),
+            "koe/2026-10-18%20sync/clips/a%20b.wav"
+        );
+        assert_eq!(base64(b"user:pa"), "dXNlcjpwYQ==");
+    }
+
+    #[test]
+    fn s3_requests_carry_a_sigv4_signature() {
+        let mut config = SyncConfig {
+            backend: "s3".to_string(),
+            ..SyncConfig::default()
+        };
+        config.s3.access_key_id = "AKID".to_string();
+        config.s3.secret_access_key = "SECRET".to_string();
+        let now = time::OffsetDateTime::from_unix_timestamp(1_792_324_800).unwrap();
+        let headers = s3_headers(
+            &config,
+            "http://localhost:9000",
+            "/notes/koe/s%201/a.txt",
+            b"hello",
+            now,
+        );
+        assert_eq!(
+            headers[0].1,
+            "AWS4-HMAC-SHA256 Credential=AKID/20261018/us-east-1/s3/aws4_request, SignedHeaders=host;x-amz-content-sha256;x-amz-date, Signature=ae061e57d9433da31ad31c277bafa0db39d99e55b13551e4807aefc0f06a2c3a"
+        );
+        assert_eq!(headers[2].1, "20261018T120000Z");
+    }
+}
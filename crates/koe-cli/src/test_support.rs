@@ -0,0 +1,39 @@
+//! Fixtures shared by the unit tests.
+
+use crate::session::{SessionMetadata, SessionMetadataInput};
+use koe_core::types::{NoteBullet, NoteKind};
+
+/// A 48 kHz mono system-audio session transcribed by whisper and summarized
+/// by ollama; override fields with `..session_input()`.
+pub fn session_input() -> SessionMetadataInput {
+    SessionMetadataInput {
+        context: None,
+        participants: Vec::new(),
+        audio_sample_rate_hz: 48_000,
+        audio_channels: 1,
+        audio_sources: vec!["system".to_string()],
+        transcribe_provider: "whisper".to_string(),
+        transcribe_model: "base.en".to_string(),
+        summarize_provider: "ollama".to_string(),
+        summarize_model: "qwen3:4b".to_string(),
+    }
+}
+
+/// Fresh metadata for [`session_input`].
+pub fn session_metadata() -> SessionMetadata {
+    SessionMetadata::new(session_input()).unwrap()
+}
+
+/// An open bullet with no evidence, owner, or due date; override fields with
+/// `..bullet(id, text, kind)`.
+pub fn bullet(id: &str, text: &str, kind: NoteKind) -> NoteBullet {
+    NoteBullet {
+        id: id.to_string(),
+        text: text.to_string(),
+        evidence: Vec::new(),
+        done: false,
+        owner: None,
+        due: None,
+        kind,
+    }
+}
@@ -0,0 +1,556 @@
+use crate::RunArgs;
+use crate::config::{Config, ConfigPaths};
+use crate::session::{SessionError, SessionFactory};
+use clap::Args;
+use koe_core::process::chunk_offline;
+use koe_core::summarize::SummarizeProvider;
+use koe_core::summarize::filter::{build_participant_tokens, should_keep_segment};
+use koe_core::transcribe::{TranscribeProvider, create_transcribe_provider};
+use koe_core::transcript::TranscriptLedger;
+use koe_core::types::{AudioSource, MeetingNotes, SummarizeEvent, TranscriptSegment};
+use koe_core::{ProcessError, TranscribeError};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Sample rate the processing pipeline expects as input.
+pub(crate) const PIPELINE_SAMPLE_RATE: u32 = 48_000;
+/// Transcript span handed to the summarizer per pass.
+const SUMMARY_WINDOW_MS: i64 = 60_000;
+const MAX_NOTES_FOR_PROMPT: usize = 50;
+
+#[derive(Args, Debug, Clone)]
+pub struct TranscribeFileArgs {
+    /// Audio file to transcribe (wav, mp3, m4a)
+    pub path: PathBuf,
+
+    /// Skip the summarize pass and only write the transcript
+    #[arg(long)]
+    pub no_summary: bool,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Debug, Error)]
+pub enum TranscribeFileError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Session(#[from] SessionError),
+    #[error("process error: {0}")]
+    Process(#[from] ProcessError),
+    #[error("transcribe error: {0}")]
+    Transcribe(#[from] TranscribeError),
+    #[error("decode failed: {0}")]
+    Decode(String),
+    #[error("{0}")]
+    Message(String),
+}
+
+pub fn run(
+    args: &TranscribeFileArgs,
+    paths: &ConfigPaths,
+    config: &Config,
+) -> Result<(), TranscribeFileError> {
+    let mut run = args
+        .run
+        .clone()
+        .resolve(config)
+        .map_err(TranscribeFileError::Message)?;
+
+    if run.transcribe_profiles.active_profile().provider == "whisper" {
+        let profile = run.transcribe_profiles.active_profile_mut();
+        crate::ensure_whisper_model(&mut profile.model, &paths.models_dir)
+            .map_err(TranscribeFileError::Message)?;
+    }
+
+    let samples = decode_audio_file(&args.path, &std::env::temp_dir())?;
+    let duration_ms = samples.len() as u64 * 1000 / u64::from(PIPELINE_SAMPLE_RATE);
+    eprintln!(
+        "decoded {} ({})",
+        args.path.display(),
+        format_duration_ms(duration_ms)
+    );
+
+    let transcribe_profile = run.transcribe_profiles.active_profile().clone();
+    let summarize_profile = run.summarize_profiles.active_profile().clone();
+    let mut transcribe = create_transcribe_provider(
+        transcribe_profile.provider.as_str(),
+        Some(transcribe_profile.model.as_str()),
+        crate::non_empty_str(transcribe_profile.api_key.as_str()),
+    )?;
+
+    let (session_paths, export_dir) =
+        crate::session_storage(paths, config, run.workspace.as_deref());
+    let factory = SessionFactory::new(
+        session_paths,
+        export_dir,
+        PIPELINE_SAMPLE_RATE,
+        1,
+        vec!["file".to_string()],
+        config.session.low_confidence_threshold,
+    );
+    let mut session = factory.create(
+        transcribe_profile.provider.clone(),
+        transcribe_profile.model.clone(),
+        summarize_profile.provider.clone(),
+        summarize_profile.model.clone(),
+        run.context.clone(),
+        run.participants.clone(),
+    )?;
+
+    {
+        let mut raw = io::BufWriter::new(session.open_audio_raw()?);
+        for sample in &samples {
+            raw.write_all(&sample.to_le_bytes())?;
+        }
+        raw.flush()?;
+    }
+
+    let segments = transcribe_samples(
+        transcribe.as_mut(),
+        &samples,
+        AudioSource::Mixed,
+        config,
+        paths,
+        &mut |segments| session.append_transcript(segments),
+    )?;
+
+    let mut notes = MeetingNotes::default();
+    if !args.no_summary && !segments.is_empty() {
+        match crate::create_summarize_for_mode(
+            &run.summarize_profiles,
+            run.summarize_profiles.active.as_str(),
+        ) {
+            Ok(mut provider) => {
+                notes = summarize_transcript(
+                    provider.as_mut(),
+                    &segments,
+                    run.context.as_deref(),
+                    &run.participants,
+                    &mut |notes| session.write_notes(notes),
+                )?;
+                if let Err(err) = crate::run_finalize_passes(
+                    provider.as_mut(),
+                    &config.summarize,
+                    &session,
+                    &segments,
+                    run.context.as_deref(),
+                    &run.participants,
+                ) {
+                    eprintln!("summarize finalize failed: {err}");
+                }
+            }
+            Err(err) => eprintln!("summarize init failed: {err}"),
+        }
+    }
+
+    session.export_on_exit(&segments, &notes)?;
+    println!("{}", session.session_dir().display());
+    Ok(())
+}
+
+/// Chunk 48 kHz mono `samples` recorded from `source` and transcribe them chunk
+/// by chunk, applying learned vocabulary and redaction; `on_segments` gets each
+/// chunk's output.
+pub(crate) fn transcribe_samples(
+    transcribe: &mut dyn TranscribeProvider,
+    samples: &[f32],
+    source: AudioSource,
+    config: &Config,
+    paths: &ConfigPaths,
+    on_segments: &mut dyn FnMut(&[TranscriptSegment]) -> Result<(), SessionError>,
+) -> Result<Vec<TranscriptSegment>, TranscribeFileError> {
+    let chunks = chunk_offline(samples, source, &config.process.to_core())?;
+    let speaker = crate::default_speaker(source);
+    let vocabulary = crate::vocabulary::load(&paths.vocabulary_path)?;
+    let redactor = config.redact.redactor();
+    let mut ledger = TranscriptLedger::new();
+    for (index, chunk) in chunks.iter().enumerate() {
+        eprint!("\rtranscribing chunk {}/{}", index + 1, chunks.len());
+        let mut segments = match transcribe.transcribe(chunk) {
+            Ok(segments) => segments,
+            Err(err) => {
+                eprintln!("\ntranscribe error on chunk {}: {err}", index + 1);
+                continue;
+            }
+        };
+        if let Some(speaker) = speaker {
+            for seg in &mut segments {
+                if seg.speaker.is_none() {
+                    seg.speaker = Some(speaker.to_string());
+                }
+            }
+        }
+        for seg in &mut segments {
+            vocabulary.apply(seg);
+            if let Some(redactor) = &redactor {
+                redactor.apply(seg);
+            }
+        }
+        on_segments(&segments)?;
+        ledger.append(segments);
+    }
+    eprintln!();
+    Ok(ledger.segments().to_vec())
+}
+
+/// Build notes the way a live meeting would, one transcript window at a time,
+/// calling `on_update` after every window that changed them.
+pub(crate) fn summarize_transcript(
+    provider: &mut dyn SummarizeProvider,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+    on_update: &mut dyn FnMut(&MeetingNotes) -> Result<(), SessionError>,
+) -> Result<MeetingNotes, SessionError> {
+    let mut notes = MeetingNotes::default();
+    let windows = summary_windows(segments, participants);
+    for (index, window) in windows.iter().enumerate() {
+        eprint!("\rsummarizing {}/{}", index + 1, windows.len());
+        let notes_for_prompt = tail_notes(&notes);
+        let mut patch_ready = None;
+        let result = provider.summarize(
+            window,
+            &notes_for_prompt,
+            context,
+            participants,
+            &mut |event| {
+                if let SummarizeEvent::PatchReady(patch) = event {
+                    patch_ready = Some(patch);
+                }
+            },
+        );
+        match result {
+            Ok(()) => {
+                if let Some(patch) = patch_ready
+                    && notes.apply_patch(patch)
+                {
+                    on_update(&notes)?;
+                }
+            }
+            Err(err) => eprintln!("\nsummarize error: {err}"),
+        }
+    }
+    eprintln!();
+    Ok(notes)
+}
+
+/// Split the transcript into consecutive windows for the summarizer, dropping filler.
+fn summary_windows(
+    segments: &[TranscriptSegment],
+    participants: &[String],
+) -> Vec<Vec<TranscriptSegment>> {
+    let participant_tokens = build_participant_tokens(participants);
+    let mut windows: Vec<Vec<TranscriptSegment>> = Vec::new();
+    let mut window_start_ms: Option<i64> = None;
+    for seg in segments {
+        if !should_keep_segment(&seg.text, &participant_tokens) {
+            continue;
+        }
+        match window_start_ms {
+            Some(start) if seg.start_ms - start < SUMMARY_WINDOW_MS => {}
+            _ => {
+                window_start_ms = Some(seg.start_ms);
+                windows.push(Vec::new());
+            }
+        }
+        if let Some(window) = windows.last_mut() {
+            window.push(seg.clone());
+        }
+    }
+    windows
+}
+
+fn tail_notes(notes: &MeetingNotes) -> MeetingNotes {
+    let start = notes.bullets.len().saturating_sub(MAX_NOTES_FOR_PROMPT);
+    MeetingNotes {
+        bullets: notes.bullets[start..].to_vec(),
+    }
+}
+
+/// Decode an audio file into 48 kHz mono samples.
+///
+/// WAV is parsed directly and Ogg/Opus is decoded by `ffmpeg`; anything else is
+/// converted to WAV with `afconvert` first, through a fresh file in `scratch_dir`.
+pub(crate) fn decode_audio_file(
+    path: &Path,
+    scratch_dir: &Path,
+) -> Result<Vec<f32>, TranscribeFileError> {
+    if !path.exists() {
+        return Err(TranscribeFileError::Message(format!(
+            "audio file not found at {}",
+            path.display()
+        )));
+    }
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let bytes = match extension.as_str() {
+        "wav" => fs::read(path)?,
+        "ogg" | "opus" => return decode_with_ffmpeg(path),
+        _ => convert_with_afconvert(path, scratch_dir)?,
+    };
+    let wav = parse_wav(&bytes)?;
+    Ok(resample_linear(
+        &wav.samples,
+        wav.sample_rate,
+        PIPELINE_SAMPLE_RATE,
+    ))
+}
+
+/// Ogg/Opus (the `opus` archive format) straight to 48 kHz mono f32; `afconvert`
+/// cannot read it.
+fn decode_with_ffmpeg(path: &Path) -> Result<Vec<f32>, TranscribeFileError> {
+    let output = Command::new("ffmpeg")
+        .args(["-nostdin", "-v", "error", "-i"])
+        .arg(path)
+        .args(["-f", "f32le", "-ac", "1", "-ar"])
+        .arg(PIPELINE_SAMPLE_RATE.to_string())
+        .arg("-")
+        .output()
+        .map_err(|e| {
+            TranscribeFileError::Decode(format!(
+                "ffmpeg is required to decode {}: {e}",
+                path.display()
+            ))
+        })?;
+    if !output.status.success() {
+        return Err(TranscribeFileError::Decode(format!(
+            "ffmpeg failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(output
+        .stdout
+        .chunks_exact(4)
+        .map(|sample| f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]))
+        .collect())
+}
+
+fn convert_with_afconvert(path: &Path, scratch_dir: &Path) -> Result<Vec<u8>, TranscribeFileError> {
+    let tmp_path = scratch_dir.join(format!(".koe-decode-{}.wav", Uuid::now_v7().simple()));
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&tmp_path)?;
+    let output = Command::new("afconvert")
+        .arg("-f")
+        .arg("WAVE")
+        .arg("-d")
+        .arg(format!("LEF32@{PIPELINE_SAMPLE_RATE}"))
+        .arg("-c")
+        .arg("1")
+        .arg(path)
+        .arg(&tmp_path)
+        .output();
+    let bytes = match output {
+        Ok(output) if output.status.success() => fs::read(&tmp_path).map_err(Into::into),
+        Ok(output) => Err(TranscribeFileError::Decode(format!(
+            "afconvert failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))),
+        Err(e) => Err(TranscribeFileError::Decode(format!(
+            "afconvert unavailable: {e}"
+        ))),
+    };
+    let _ = fs::remove_file(&tmp_path);
+    bytes
+}
+
+#[derive(Debug)]
+struct DecodedWav {
+    sample_rate: u32,
+    samples: Vec<f32>,
+}
+
+/// Parse PCM (8/16/24/32-bit) or IEEE float WAV data, downmixing to mono.
+fn parse_wav(bytes: &[u8]) -> Result<DecodedWav, TranscribeFileError> {
+    let invalid = |msg: &str| TranscribeFileError::Decode(msg.to_string());
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err(invalid("not a RIFF/WAVE file"));
+    }
+
+    let mut format: Option<(u16, u16, u32, u16)> = None;
+    let mut data: Option<&[u8]> = None;
+    let mut offset = 12;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let size = u32::from_le_bytes([
+            bytes[offset + 4],
+            bytes[offset + 5],
+            bytes[offset + 6],
+            bytes[offset + 7],
+        ]) as usize;
+        let body_start = offset + 8;
+        let body_end = body_start.saturating_add(size).min(bytes.len());
+        let body = &bytes[body_start..body_end];
+        match id {
+            b"fmt " => {
+                if body.len() < 16 {
+                    return Err(invalid("fmt chunk too short"));
+                }
+                let mut tag = u16::from_le_bytes([body[0], body[1]]);
+                let channels = u16::from_le_bytes([body[2], body[3]]);
+                let sample_rate = u32::from_le_bytes([body[4], body[5], body[6], body[7]]);
+                let bits = u16::from_le_bytes([body[14], body[15]]);
+                // WAVE_FORMAT_EXTENSIBLE keeps the real format tag in the sub-format GUID
+                if tag == 0xFFFE && body.len() >= 26 {
+                    tag = u16::from_le_bytes([body[24], body[25]]);
+                }
+                format = Some((tag, channels, sample_rate, bits));
+            }
+            b"data" => data = Some(body),
+            _ => {}
+        }
+        // Chunks are word-aligned
+        offset = body_start.saturating_add(size + (size & 1));
+    }
+
+    let (tag, channels, sample_rate, bits) = format.ok_or_else(|| invalid("missing fmt chunk"))?;
+    let data = data.ok_or_else(|| invalid("missing data chunk"))?;
+    if channels == 0 || sample_rate == 0 {
+        return Err(invalid("invalid channel count or sample rate"));
+    }
+
+    let decode: fn(&[u8]) -> f32 = match (tag, bits) {
+        (1, 8) => |b| (f32::from(b[0]) - 128.0) / 128.0,
+        (1, 16) => |b| f32::from(i16::from_le_bytes([b[0], b[1]])) / 32_768.0,
+        (1, 24) => |b| (i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) as f32 / 8_388_608.0,
+        (1, 32) => |b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2_147_483_648.0,
+        (3, 32) => |b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+        (3, 64) => |b| f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]) as f32,
+        _ => {
+            return Err(TranscribeFileError::Decode(format!(
+                "unsupported wav encoding (format {tag}, {bits}-bit)"
+            )));
+        }
+    };
+
+    let sample_bytes = usize::from(bits / 8);
+    let frame_bytes = sample_bytes * usize::from(channels);
+    let samples = data
+        .chunks_exact(frame_bytes)
+        .map(|frame| {
+            let sum: f32 = frame.chunks_exact(sample_bytes).map(decode).sum();
+            sum / f32::from(channels)
+        })
+        .collect();
+
+    Ok(DecodedWav {
+        sample_rate,
+        samples,
+    })
+}
+
+pub(crate) fn resample_linear(samples: &[f32], from_hz: u32, to_hz: u32) -> Vec<f32> {
+    if from_hz == to_hz || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let ratio = f64::from(from_hz) / f64::from(to_hz);
+    let out_len = (samples.len() as f64 / ratio).floor() as usize;
+    (0..out_len)
+        .map(|i| {
+            let pos = i as f64 * ratio;
+            let index = pos.floor() as usize;
+            let frac = (pos - index as f64) as f32;
+            let a = samples[index.min(samples.len() - 1)];
+            let b = samples[(index + 1).min(samples.len() - 1)];
+            a + (b - a) * frac
+        })
+        .collect()
+}
+
+fn format_duration_ms(ms: u64) -> String {
+    let total_seconds = ms / 1000;
+    format!(
+        "{:02}:{:02}:{:02}",
+        total_seconds / 3600,
+        (total_seconds / 60) % 60,
+        total_seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_wav, resample_linear, summary_windows};
+    use koe_core::types::TranscriptSegment;
+
+    fn wav_bytes(format: u16, channels: u16, rate: u32, bits: u16, data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(b"RIFF");
+        out.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        out.extend_from_slice(b"WAVE");
+        out.extend_from_slice(b"fmt ");
+        out.extend_from_slice(&16u32.to_le_bytes());
+        out.extend_from_slice(&format.to_le_bytes());
+        out.extend_from_slice(&channels.to_le_bytes());
+        out.extend_from_slice(&rate.to_le_bytes());
+        let block_align = channels * bits / 8;
+        out.extend_from_slice(&(rate * u32::from(block_align)).to_le_bytes());
+        out.extend_from_slice(&block_align.to_le_bytes());
+        out.extend_from_slice(&bits.to_le_bytes());
+        out.extend_from_slice(b"data");
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(data);
+        out
+    }
+
+    #[test]
+    fn parse_wav_downmixes_pcm16_stereo() {
+        let mut data = Vec::new();
+        for (left, right) in [(16_384i16, 0i16), (-32_768, -32_768)] {
+            data.extend_from_slice(&left.to_le_bytes());
+            data.extend_from_slice(&right.to_le_bytes());
+        }
+        let wav = parse_wav(&wav_bytes(1, 2, 44_100, 16, &data)).unwrap();
+        assert_eq!(wav.sample_rate, 44_100);
+        assert_eq!(wav.samples, vec![0.25, -1.0]);
+    }
+
+    #[test]
+    fn parse_wav_reads_float32() {
+        let mut data = Vec::new();
+        for value in [0.5f32, -0.25] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        let wav = parse_wav(&wav_bytes(3, 1, 48_000, 32, &data)).unwrap();
+        assert_eq!(wav.samples, vec![0.5, -0.25]);
+    }
+
+    #[test]
+    fn parse_wav_rejects_non_riff() {
+        assert!(parse_wav(b"ID3\x03not a wav file").is_err());
+    }
+
+    #[test]
+    fn resample_linear_scales_length() {
+        let input = vec![0.0f32; 16_000];
+        assert_eq!(resample_linear(&input, 16_000, 48_000).len(), 48_000);
+        assert_eq!(resample_linear(&input, 48_000, 48_000).len(), 16_000);
+    }
+
+    #[test]
+    fn summary_windows_split_by_time() {
+        let seg = |id: u64, start_ms: i64| TranscriptSegment {
+            id,
+            start_ms,
+            end_ms: start_ms + 2_000,
+            speaker: None,
+            text: "we agreed to ship the release on friday".to_string(),
+            finalized: true,
+            confidence: None,
+        };
+        let segments = vec![seg(1, 0), seg(2, 30_000), seg(3, 65_000)];
+        let windows = summary_windows(&segments, &[]);
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].len(), 2);
+        assert_eq!(windows[1][0].id, 3);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::RuntimeProfiles;
+use crate::tui::UiEvent;
+use koe_core::summarize::translate::translate_segments;
+use koe_core::types::TranscriptSegment;
+use koe_core::usage::UsageMeter;
+use std::collections::HashSet;
+use std::io;
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+use std::time::Duration;
+
+/// Wait after the first new segment so a burst goes out as one request.
+const BATCH_DELAY: Duration = Duration::from_millis(1_500);
+const MAX_BATCH_SEGMENTS: usize = 20;
+
+/// Start the translation track: segments sent on the returned channel are
+/// translated with their own summarize provider instance (so notes are never
+/// delayed) and delivered back as `UiEvent::Translations`.
+pub fn spawn_translator(
+    profiles: &RuntimeProfiles,
+    target_language: String,
+    context: Option<String>,
+    usage: UsageMeter,
+    ui_tx: Sender<UiEvent>,
+) -> io::Result<Sender<Vec<TranscriptSegment>>> {
+    let (tx, rx) = mpsc::channel::<Vec<TranscriptSegment>>();
+    let profiles = profiles.clone();
+    thread::Builder::new()
+        .name("koe-translate".into())
+        .spawn(move || {
+            let mut provider =
+                match crate::create_summarize_for_mode(&profiles, profiles.active.as_str()) {
+                    Ok(mut provider) => {
+                        provider.set_usage_meter(usage);
+                        provider
+                    }
+                    Err(e) => {
+                        let _ = ui_tx.send(UiEvent::Error {
+                            message: format!("translate init failed: {e}"),
+                        });
+                        return;
+                    }
+                };
+            while let Ok(first) = rx.recv() {
+                thread::sleep(BATCH_DELAY);
+                let mut pending = first;
+                pending.extend(rx.try_iter().flatten());
+                let batch = latest_revisions(pending);
+                for chunk in batch.chunks(MAX_BATCH_SEGMENTS) {
+                    match translate_segments(
+                        provider.as_mut(),
+                        chunk,
+                        &target_language,
+                        context.as_deref(),
+                    ) {
+                        Ok(translations) if translations.is_empty() => {}
+                        Ok(translations) => {
+                            if ui_tx.send(UiEvent::Translations(translations)).is_err() {
+                                return;
+                            }
+                        }
+                        Err(e) => {
+                            let _ = ui_tx.send(UiEvent::Error {
+                                message: format!("translate error: {e}"),
+                            });
+                        }
+                    }
+                }
+            }
+        })?;
+    Ok(tx)
+}
+
+/// Keep only the newest revision of each segment id, ordered by when it arrived.
+fn latest_revisions(segments: Vec<TranscriptSegment>) -> Vec<TranscriptSegment> {
+    let mut seen = HashSet::new();
+    let mut latest: Vec<TranscriptSegment> = segments
+        .into_iter()
+        .rev()
+        .filter(|seg| !seg.text.trim().is_empty() && seen.insert(seg.id))
+        .collect();
+    latest.reverse();
+    latest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::latest_revisions;
+    use koe_core::types::TranscriptSegment;
+
+    fn seg(id: u64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            id,
+            start_ms: id as i64 * 1_000,
+            end_ms: id as i64 * 1_000 + 900,
+            speaker: None,
+            text: text.to_string(),
+            finalized: true,
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn batch_keeps_newest_revision_per_segment() {
+        let batch = latest_revisions(vec![
+            seg(1, "hola"),
+            seg(2, "que tal"),
+            seg(1, "hola a todos"),
+            seg(3, "  "),
+        ]);
+        let texts: Vec<(u64, &str)> = batch.iter().map(|s| (s.id, s.text.as_str())).collect();
+        assert_eq!(texts, vec![(2, "que tal"), (1, "hola a todos")]);
+    }
+}
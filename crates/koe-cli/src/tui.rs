@@ -1,41 +1,108 @@
-use crate::config::{MixdownConfig, UiConfig};
-use crate::raw_audio::{RawAudioWriter, SharedRawAudioWriter};
-use crate::session::{SessionFactory, SessionHandle};
-use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use crate::actions::{ActionStatus, ActionStore, ActionsError};
+use crate::attach::ObserverEvent;
+use crate::calendar::{self, CalendarError};
+use crate::clipboard;
+use crate::config::{
+    CalendarConfig, Config, ConfigPaths, EmailConfig, MixdownConfig, ReviewConfig, SlackConfig,
+    UiConfig,
+};
+use crate::email;
+use crate::export_targets::{self, ExportTarget};
+use crate::health::{HealthCheck, HealthStatus};
+use crate::http::LiveFeed;
+use crate::monitor::ResourceUsage;
+use crate::raw_audio::{
+    ArchiveFormat, RawAudioWriter, SharedRawAudioWriter, calibrated_denoise_threshold,
+};
+use crate::session::{Calibration, SessionFactory, SessionHandle, parse_tags};
+use crate::slack;
+use crate::webhook::{WebhookEvent, WebhookSender};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, KeyboardEnhancementFlags,
+    PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+};
 use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
-use koe_core::process::AudioProcessor;
-use koe_core::transcript::TranscriptLedger;
+use koe_core::capture::MicrophoneChange;
+use koe_core::clock::SessionClock;
+use koe_core::process::{AudioProcessor, NoiseCalibration};
+use koe_core::summarize::translate::SegmentTranslation;
+use koe_core::transcript::{SegmentReplacement, TranscriptLedger, changed_words};
 use koe_core::types::{
-    CaptureStats, MeetingNotes, NoteBullet, NotesOp, NotesPatch, TranscriptSegment,
+    AudioSource, CaptureStats, Chapter, MeetingNotes, NoteBullet, NoteKind, NotesOp, NotesPatch,
+    TranscriptSegment,
 };
+use koe_core::usage::UsageMeter;
+use koe_core::vocabulary::Vocabulary;
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use std::collections::{BTreeMap, HashMap};
 use std::io;
-use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender, TryRecvError, channel};
 use std::thread;
 use std::time::{Duration, Instant};
 
+/// Live bullets kept in memory before the oldest batch is archived to the session dir.
+const MAX_LIVE_NOTES: usize = 200;
+const NOTES_ARCHIVE_BATCH: usize = 100;
+const REVISION_HIGHLIGHT: Duration = Duration::from_millis(1500);
+const REVISION_FADE: Duration = Duration::from_millis(3000);
+/// Finalized segments shown in focus caption mode.
+const CAPTION_SEGMENTS: usize = 3;
+
 #[derive(Debug, Clone)]
 pub enum TranscribeCommand {
     Drain(Sender<()>),
+    /// Transcribe a short silent chunk and report whether the provider answered.
+    Probe(Sender<Result<(), String>>),
+    /// A meeting started in this session dir; the transcribe thread appends live
+    /// segments to `transcript_path` and queues chunks that fail offline in `dir`.
+    SessionStarted {
+        dir: PathBuf,
+        transcript_path: PathBuf,
+    },
+    /// Swap the local whisper model for a smaller one after a resource limit trips.
+    Degrade(PathBuf),
+    /// Learned transcript corrections changed in the review screen or by an edit.
+    UpdateVocabulary(Vocabulary),
 }
 
 #[derive(Debug, Clone)]
 pub enum SummarizeCommand {
     Reset,
     UpdateContext(String),
+    UpdateParticipants(Vec<String>),
+    /// Switch the end-of-meeting pass, e.g. when a meeting profile is applied.
+    SetPromptProfile(String),
+    /// Answer a question from the recent transcript; replies as `UiEvent::Ask*`.
+    Ask(String),
+    /// Digest the transcript since the last note; replies as `UiEvent::CatchUp*`.
+    CatchUp,
+    /// Run end-of-meeting passes over the full transcript and write their exports.
+    Finalize {
+        session: Box<SessionHandle>,
+        segments: Vec<TranscriptSegment>,
+    },
+    /// Lengthen the summarize interval after a resource limit trips.
+    Degrade,
+    /// Seconds between live notes updates, from the palette.
+    SetInterval(u32),
 }
 
 pub enum UiEvent {
     Transcript(Vec<TranscriptSegment>),
+    /// Interim hypotheses for in-progress speech; replaced by the next final transcript.
+    TranscriptPartial(Vec<TranscriptSegment>),
     NotesPatch(NotesPatch),
+    /// Streamed text of the notes update in progress, shown as a preview.
+    SummarizeDraftToken(String),
+    /// The notes update finished (or failed); the preview goes away.
+    SummarizeDraftDone,
     Error {
         message: String,
     },
@@ -43,6 +110,8 @@ pub enum UiEvent {
         mode: String,
         provider: String,
         connected: bool,
+        /// False while the provider warms up; the first chunk would stall.
+        ready: bool,
     },
     SummarizeStatus {
         mode: String,
@@ -51,6 +120,42 @@ pub enum UiEvent {
     TranscribeLag {
         last_ms: u128,
     },
+    ResourceUsage(ResourceUsage),
+    /// A newer release was found by the opt-in startup check.
+    UpdateAvailable {
+        version: String,
+    },
+    /// Meeting context replaced from outside the TUI (local HTTP API).
+    ContextUpdate(String),
+    /// Streamed text of the answer to the pending "ask" question.
+    AskToken(String),
+    AskDone {
+        error: Option<String>,
+    },
+    /// Streamed text of the pending "catch me up" digest.
+    CatchUpToken(String),
+    CatchUpDone {
+        error: Option<String>,
+    },
+    /// Translated lines for the live translation track, keyed by segment id.
+    Translations(Vec<SegmentTranslation>),
+    /// Topic outline from the chapters pass, shown as transcript section headers.
+    Chapters(Vec<Chapter>),
+    /// Capture moved the mic stream to another input device.
+    MicrophoneChanged(MicrophoneChange),
+    /// A meeting app started a call (see `detect.rs`).
+    MeetingDetected {
+        app: String,
+    },
+    /// The auto-title pass at finalize named a session on disk.
+    SessionTitled {
+        session_id: String,
+        title: String,
+    },
+    /// The `post minutes to slack` command finished (or failed).
+    SlackPosted {
+        error: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -92,21 +197,81 @@ impl ModeProfiles {
     }
 }
 
+/// Meeting-type preset from config; `None` or empty fields keep the current value.
+#[derive(Debug, Clone)]
+pub struct MeetingProfile {
+    pub name: String,
+    pub context: Option<String>,
+    pub participants: Vec<String>,
+    pub prompt_profile: Option<String>,
+}
+
 pub struct TuiContext {
     pub processor: AudioProcessor,
     pub ui_rx: Receiver<UiEvent>,
+    /// Lets background work started from the TUI report back.
+    pub ui_tx: Sender<UiEvent>,
     pub stats: CaptureStats,
+    pub usage: UsageMeter,
+    /// Run the capture/transcriber self-test before starting a meeting.
+    pub health_check: bool,
+    /// Calibrate the mic noise floor at each meeting start.
+    pub calibrate: bool,
+    pub capture_sources: Vec<AudioSource>,
     pub transcribe_cmd_tx: Sender<TranscribeCommand>,
     pub summarize_cmd_tx: Sender<SummarizeCommand>,
     pub ui_config: UiConfig,
+    /// Where layout changes made in the TUI are saved.
+    pub config_paths: ConfigPaths,
     pub audio_sample_rate_hz: u32,
     pub audio_mixdown: MixdownConfig,
+    pub audio_multitrack: bool,
+    pub audio_archive_format: ArchiveFormat,
     pub session_factory: SessionFactory,
     pub shared_writer: SharedRawAudioWriter,
     pub initial_context: String,
     pub participants: Vec<String>,
     pub transcribe_profiles: ModeProfiles,
     pub summarize_profiles: ModeProfiles,
+    pub max_rss_mb: u32,
+    pub workspace: Option<String>,
+    pub meeting_profiles: Vec<MeetingProfile>,
+    pub active_profile: Option<String>,
+    pub vocabulary: Vocabulary,
+    pub vocabulary_path: PathBuf,
+    /// Store behind the `open action items` view, refreshed from these sessions dirs.
+    pub actions_path: PathBuf,
+    pub actions_sessions_dirs: Vec<PathBuf>,
+    /// Built-in prompt profiles followed by templates from `~/.koe/prompts/`.
+    pub prompt_profiles: Vec<String>,
+    pub prompt_profile: String,
+    /// Open the onboarding tour on launch.
+    pub show_tour: bool,
+    /// Open in focus captions mode (`koe captions`).
+    pub captions: bool,
+    /// Start a meeting on launch, skipping the idle screen and health check.
+    pub auto_start: bool,
+    /// Normal-mode key toggling capture pause.
+    pub pause_key: Option<KeyBinding>,
+    /// Key pausing capture while held.
+    pub hold_to_pause_key: Option<KeyBinding>,
+    /// Interval for refreshing the markdown exports during a meeting.
+    pub autosave_export: Option<Duration>,
+    /// Calendar read at each meeting start for the title, context, and participants.
+    pub calendar: CalendarConfig,
+    /// Start a meeting when a call is detected instead of asking.
+    pub detect_auto_start: bool,
+    pub webhooks: WebhookSender,
+    pub live: LiveFeed,
+    pub review: ReviewConfig,
+    /// Webhook behind the post-meeting `post minutes to slack` command.
+    pub slack: SlackConfig,
+    /// Extra recipients for the post-meeting `email minutes` draft.
+    pub email: EmailConfig,
+    /// Ask for tags once a meeting ends.
+    pub tag_prompt: bool,
+    /// Starting seconds between live notes updates (`summarize.interval_secs`).
+    pub summarize_interval_secs: u32,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -127,10 +292,85 @@ struct UiTheme {
     error: Color,
 }
 
+/// Built-in `ui.color_theme` names; `ui.themes` may add more.
+const BUILTIN_THEMES: [&str; 4] = ["minimal", "dark", "light", "high-contrast"];
+
 impl UiTheme {
     fn from_config(config: &UiConfig) -> Self {
-        let _ = config.color_theme.as_str();
-        Self::minimal()
+        Self::resolve(config).unwrap_or_else(|_| Self::minimal())
+    }
+
+    /// Theme named by `color_theme`: a built-in, or a `ui.themes` palette
+    /// layered over its `base` built-in.
+    fn resolve(config: &UiConfig) -> Result<Self, String> {
+        let name = config.color_theme.trim();
+        if let Some(theme) = Self::builtin(name) {
+            return Ok(theme);
+        }
+        let palette = config.themes.get(name).ok_or_else(|| {
+            format!(
+                "unknown theme {name:?}; use {} or a ui.themes entry",
+                BUILTIN_THEMES.join(", ")
+            )
+        })?;
+        let base = match palette.base.trim() {
+            "" => "minimal",
+            base => base,
+        };
+        let mut theme = Self::builtin(base)
+            .ok_or_else(|| format!("theme {name:?} has unknown base {base:?}"))?;
+        for (field, value, slot) in [
+            ("accent", &palette.accent, &mut theme.accent),
+            ("me", &palette.me, &mut theme.me),
+            ("them", &palette.them, &mut theme.them),
+            ("heading", &palette.heading, &mut theme.heading),
+            ("muted", &palette.muted, &mut theme.muted),
+            ("neutral", &palette.neutral, &mut theme.neutral),
+            ("error", &palette.error, &mut theme.error),
+        ] {
+            if value.trim().is_empty() {
+                continue;
+            }
+            *slot = parse_hex_color(value).ok_or_else(|| {
+                format!("theme {name:?} {field} {value:?} is not a #rrggbb color")
+            })?;
+        }
+        Ok(theme)
+    }
+
+    fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "minimal" => Some(Self::minimal()),
+            "dark" => Some(Self {
+                accent: Color::Rgb(97, 175, 239),
+                me: Color::Rgb(220, 220, 220),
+                them: Color::Rgb(152, 195, 121),
+                heading: Color::Rgb(229, 192, 123),
+                muted: Color::Rgb(128, 128, 128),
+                neutral: Color::Rgb(80, 80, 80),
+                error: Color::Rgb(224, 108, 117),
+            }),
+            "light" => Some(Self {
+                accent: Color::Rgb(0, 110, 170),
+                me: Color::Rgb(40, 40, 40),
+                them: Color::Rgb(30, 100, 60),
+                heading: Color::Rgb(90, 60, 140),
+                muted: Color::Rgb(110, 110, 110),
+                neutral: Color::Rgb(170, 170, 170),
+                error: Color::Rgb(190, 30, 30),
+            }),
+            // Named colors so the terminal's own high-contrast palette applies.
+            "high-contrast" => Some(Self {
+                accent: Color::Yellow,
+                me: Color::White,
+                them: Color::LightCyan,
+                heading: Color::White,
+                muted: Color::Gray,
+                neutral: Color::Gray,
+                error: Color::LightRed,
+            }),
+            _ => None,
+        }
     }
 
     fn minimal() -> Self {
@@ -146,6 +386,20 @@ impl UiTheme {
     }
 }
 
+/// Why `config.color_theme` cannot be used, for config validation.
+pub fn check_color_theme(config: &UiConfig) -> Result<(), String> {
+    UiTheme::resolve(config).map(|_| ())
+}
+
+fn parse_hex_color(value: &str) -> Option<Color> {
+    let hex = value.trim().strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |range: std::ops::Range<usize>| u8::from_str_radix(&hex[range], 16).ok();
+    Some(Color::Rgb(channel(0..2)?, channel(2..4)?, channel(4..6)?))
+}
+
 #[derive(Debug, Clone)]
 struct PaletteState {
     filter: String,
@@ -165,6 +419,569 @@ impl PaletteState {
 enum UiMode {
     Normal,
     Palette(PaletteState),
+    Diagnostics,
+    NotesFocus(NotesFocusState),
+    /// Picking a note whose evidence the transcript pane scrolls to; never edits.
+    NotesEvidence(NotesFocusState),
+    /// Typing a transcript search query; the query lives in `TranscriptView`.
+    TranscriptSearch,
+    Review(ReviewState),
+    /// Full-screen captions of the latest transcript lines.
+    Captions,
+    /// Onboarding tour spotlighting one part of the screen per step.
+    Tour(TourStep),
+    /// Question box and streamed answer; the exchange lives in `AskState`.
+    Ask,
+    /// Streamed digest of what was said since the last note.
+    CatchUp,
+    /// Editing one transcript line; the edit teaches the vocabulary.
+    Correct(CorrectState),
+    /// Naming a speaker label, prefilled with its current name.
+    RenameSpeaker(RenameSpeakerState),
+    /// Editing the meeting title, prefilled with the current one.
+    SetTitle(String),
+    /// Editing the meeting's comma-separated tags; an empty line clears them.
+    Tags(String),
+    /// Editing the seconds between live notes updates.
+    SummarizeInterval(String),
+    /// Learned corrections, with removal.
+    Vocabulary(VocabularyReviewState),
+    /// Open action items across sessions, with marking done.
+    Actions(ActionsViewState),
+    /// Pre-meeting self-test in progress; the check lives outside the mode.
+    HealthCheck,
+    /// Self-test problems blocking the meeting start.
+    HealthFailed(Vec<String>),
+    /// Offer to start a meeting for the detected call app.
+    MeetingDetected(String),
+}
+
+/// Onboarding tour steps, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TourStep {
+    Welcome,
+    Palette,
+    Notes,
+    Transcript,
+    Footer,
+    Keys,
+}
+
+/// Screen region a tour step spotlights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TourTarget {
+    Screen,
+    TitleBar,
+    Notes,
+    Transcript,
+    Footer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TourOutcome {
+    Stay,
+    Exit,
+}
+
+impl TourStep {
+    const ALL: [Self; 6] = [
+        Self::Welcome,
+        Self::Palette,
+        Self::Notes,
+        Self::Transcript,
+        Self::Footer,
+        Self::Keys,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|step| *step == self).unwrap_or(0)
+    }
+
+    fn next(self) -> Option<Self> {
+        Self::ALL.get(self.index() + 1).copied()
+    }
+
+    fn prev(self) -> Self {
+        Self::ALL[self.index().saturating_sub(1)]
+    }
+
+    fn target(self) -> TourTarget {
+        match self {
+            Self::Welcome | Self::Keys => TourTarget::Screen,
+            Self::Palette => TourTarget::TitleBar,
+            Self::Notes => TourTarget::Notes,
+            Self::Transcript => TourTarget::Transcript,
+            Self::Footer => TourTarget::Footer,
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Welcome => "Welcome to koe",
+            Self::Palette => "Command palette",
+            Self::Notes => "Notes",
+            Self::Transcript => "Transcript",
+            Self::Footer => "Footer metrics",
+            Self::Keys => "Keys",
+        }
+    }
+
+    fn body(self) -> &'static [&'static str] {
+        match self {
+            Self::Welcome => &[
+                "koe transcribes meetings live and keeps",
+                "running notes. This tour shows where",
+                "everything lives.",
+            ],
+            Self::Palette => &[
+                "ctrl+p opens the command palette. Starting",
+                "and ending meetings, exports, and review",
+                "all live there; type to filter.",
+            ],
+            Self::Notes => &[
+                "Notes fill in on the left as the meeting",
+                "goes on, each bullet tied to what was said.",
+            ],
+            Self::Transcript => &[
+                "The transcript runs on the right. pgup/pgdn",
+                "scroll, g jumps to the top, / searches,",
+                "f toggles follow.",
+            ],
+            Self::Footer => &[
+                "The footer shows the meeting timer, capture",
+                "activity, active providers, transcribe lag,",
+                "and chunk counters.",
+            ],
+            Self::Keys => &[
+                "q quits, esc closes overlays, ctrl+c exits",
+                "anywhere. Reopen this tour from the palette",
+                "with \"help/tour\".",
+            ],
+        }
+    }
+}
+
+/// The latest "ask" exchange; kept after closing so reopening shows it.
+#[derive(Debug, Clone, Default)]
+struct AskState {
+    input: String,
+    question: String,
+    answer: String,
+    pending: bool,
+    error: Option<String>,
+}
+
+/// Latest "catch me up" digest, kept until the next request.
+#[derive(Debug, Default)]
+struct CatchUpState {
+    digest: String,
+    pending: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AskOutcome {
+    Stay,
+    Submit(String),
+    Exit,
+}
+
+/// Transcript line being corrected, prefilled with its current text.
+#[derive(Debug, Clone)]
+struct CorrectState {
+    segment_id: u64,
+    input: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CorrectOutcome {
+    Stay,
+    Submit(String),
+    Exit,
+}
+
+/// Speaker label being named; Tab cycles through the participants.
+#[derive(Debug, Clone)]
+struct RenameSpeakerState {
+    label: String,
+    input: String,
+    suggestion: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct VocabularyReviewState {
+    selected: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VocabularyOutcome {
+    Stay,
+    Remove(usize),
+    Exit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ActionsOutcome {
+    Stay,
+    Done(usize),
+    Exit,
+}
+
+/// Action items open when the view was opened, in due order; ones marked
+/// done stay listed (ticked) until the view closes.
+#[derive(Debug, Clone)]
+struct ActionsViewState {
+    store: ActionStore,
+    ids: Vec<String>,
+    selected: usize,
+}
+
+impl ActionsViewState {
+    fn load(path: &Path, sessions_dirs: &[PathBuf]) -> Result<Self, ActionsError> {
+        let mut store = crate::actions::load(path)?;
+        store.refresh(sessions_dirs)?;
+        crate::actions::save(path, &store)?;
+        let ids = store
+            .sorted()
+            .into_iter()
+            .filter(|action| action.status == ActionStatus::Open)
+            .map(|action| action.id.clone())
+            .collect();
+        Ok(Self {
+            store,
+            ids,
+            selected: 0,
+        })
+    }
+}
+
+/// Selection and in-progress edit while the notes pane has focus.
+#[derive(Debug, Clone, Default)]
+struct NotesFocusState {
+    selected: usize,
+    edit: Option<NoteEdit>,
+}
+
+#[derive(Debug, Clone)]
+struct NoteEdit {
+    field: NoteEditField,
+    buffer: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NoteEditField {
+    Text,
+    Owner,
+    Due,
+}
+
+/// Post-meeting review: vet notes, then pick export targets before finalizing.
+#[derive(Debug, Clone)]
+struct ReviewState {
+    step: ReviewStep,
+    notes: NotesFocusState,
+    /// Verdicts by bullet id; bullets without one are still tentative.
+    verdicts: HashMap<String, NoteVerdict>,
+    targets: Vec<(ExportTarget, bool)>,
+    target_selected: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReviewStep {
+    Notes,
+    Targets,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NoteVerdict {
+    Accepted,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReviewOutcome {
+    Unchanged,
+    Changed,
+    Finalize,
+    Exit,
+}
+
+impl ReviewState {
+    fn new(targets: Vec<ExportTarget>) -> Self {
+        Self {
+            step: ReviewStep::Notes,
+            notes: NotesFocusState::default(),
+            verdicts: HashMap::new(),
+            targets: targets.into_iter().map(|target| (target, false)).collect(),
+            target_selected: 0,
+        }
+    }
+
+    fn verdict(&self, bullet: &NoteBullet) -> Option<NoteVerdict> {
+        self.verdicts.get(&bullet.id).copied()
+    }
+
+    /// Drop rejected bullets from `notes`; returns how many were removed.
+    fn apply(&self, notes: &mut MeetingNotes) -> usize {
+        let before = notes.bullets.len();
+        notes
+            .bullets
+            .retain(|bullet| self.verdict(bullet) != Some(NoteVerdict::Rejected));
+        before - notes.bullets.len()
+    }
+
+    fn selected_targets(&self) -> Vec<ExportTarget> {
+        self.targets
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(target, _)| *target)
+            .collect()
+    }
+}
+
+/// How prominently a revised segment's changed words are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RevisionStage {
+    Fresh,
+    Fading,
+}
+
+#[derive(Debug, Clone)]
+struct SegmentRevision {
+    changed: Vec<bool>,
+    at: Instant,
+    stage: RevisionStage,
+}
+
+/// Live-pane decorations on top of the ledger: words changed by recent segment
+/// replacements (highlighted, then faded), the latest interim segments, and
+/// translation-track lines.
+#[derive(Debug, Clone, Default)]
+struct TranscriptDisplay {
+    revisions: HashMap<u64, SegmentRevision>,
+    partials: Vec<TranscriptSegment>,
+    translations: HashMap<u64, String>,
+    /// Names shown for speaker labels, mirrored into session metadata.
+    speaker_names: BTreeMap<String, String>,
+    chapters: Vec<Chapter>,
+    /// Segments below this confidence render dimmed with a `(?)` marker; 0 disables.
+    low_confidence_threshold: f32,
+}
+
+impl TranscriptDisplay {
+    fn new(low_confidence_threshold: f32) -> Self {
+        Self {
+            low_confidence_threshold,
+            ..Self::default()
+        }
+    }
+
+    /// Clear everything from the previous meeting, keeping settings.
+    fn reset(&mut self) {
+        *self = Self::new(self.low_confidence_threshold);
+    }
+
+    fn is_low_confidence(&self, seg: &TranscriptSegment) -> bool {
+        seg.confidence
+            .is_some_and(|confidence| confidence < self.low_confidence_threshold)
+    }
+
+    fn record(&mut self, replacements: Vec<SegmentReplacement>, ledger: &TranscriptLedger) {
+        let now = Instant::now();
+        for replacement in replacements {
+            let Some(seg) = ledger.segments().iter().find(|s| s.id == replacement.id) else {
+                continue;
+            };
+            let changed = changed_words(&replacement.previous_text, &seg.text);
+            if changed.iter().any(|c| *c) {
+                self.revisions.insert(
+                    replacement.id,
+                    SegmentRevision {
+                        changed,
+                        at: now,
+                        stage: RevisionStage::Fresh,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Advance highlight stages; true when the pane needs re-rendering.
+    fn tick(&mut self, now: Instant) -> bool {
+        let before = self.revisions.len();
+        self.revisions
+            .retain(|_, revision| now.duration_since(revision.at) < REVISION_FADE);
+        let mut changed = self.revisions.len() != before;
+        for revision in self.revisions.values_mut() {
+            if revision.stage == RevisionStage::Fresh
+                && now.duration_since(revision.at) >= REVISION_HIGHLIGHT
+            {
+                revision.stage = RevisionStage::Fading;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+/// Transcript pane scrollback position and search state.
+#[derive(Debug, Clone)]
+struct TranscriptView {
+    follow: bool,
+    /// Exclusive end of the visible segment window while not following.
+    anchor_end: usize,
+    search: Option<TranscriptSearch>,
+    /// Segment ids cited by the note picked in notes evidence mode, highlighted.
+    evidence: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TranscriptSearch {
+    query: String,
+    matches: Vec<usize>,
+    current: usize,
+}
+
+impl TranscriptView {
+    fn new() -> Self {
+        Self {
+            follow: true,
+            anchor_end: 0,
+            search: None,
+            evidence: Vec::new(),
+        }
+    }
+
+    fn end(&self, len: usize) -> usize {
+        if self.follow {
+            len
+        } else {
+            self.anchor_end.min(len)
+        }
+    }
+
+    fn scroll_up(&mut self, len: usize, rows: usize) {
+        self.anchor_end = self.end(len).saturating_sub(rows).max(rows.min(len));
+        self.follow = false;
+    }
+
+    fn scroll_down(&mut self, len: usize, rows: usize) {
+        if self.follow {
+            return;
+        }
+        self.anchor_end += rows;
+        if self.anchor_end >= len {
+            self.follow = true;
+        }
+    }
+
+    fn scroll_top(&mut self, len: usize, rows: usize) {
+        self.anchor_end = rows.min(len);
+        self.follow = false;
+    }
+
+    fn toggle_follow(&mut self, len: usize) {
+        self.anchor_end = len;
+        self.follow = !self.follow;
+    }
+
+    /// Re-run the search over the full transcript and jump to the newest match.
+    fn update_search(&mut self, segments: &[TranscriptSegment], rows: usize) {
+        let Some(search) = self.search.as_mut() else {
+            return;
+        };
+        search.matches = find_matches(segments, &search.query);
+        search.current = search.matches.len().saturating_sub(1);
+        self.jump_to_match(segments.len(), rows);
+    }
+
+    /// Step to an older (`older = true`) or newer match, wrapping around.
+    fn step_match(&mut self, segments: &[TranscriptSegment], rows: usize, older: bool) {
+        let Some(search) = self.search.as_mut() else {
+            return;
+        };
+        search.matches = find_matches(segments, &search.query);
+        let count = search.matches.len();
+        if count == 0 {
+            return;
+        }
+        let current = search.current.min(count - 1);
+        search.current = if older {
+            (current + count - 1) % count
+        } else {
+            (current + 1) % count
+        };
+        self.jump_to_match(segments.len(), rows);
+    }
+
+    /// Segment targeted by review actions: the current search match, else the
+    /// bottom visible segment.
+    fn selected(&self, len: usize) -> Option<usize> {
+        let current = self
+            .search
+            .as_ref()
+            .and_then(|search| search.matches.get(search.current).copied());
+        current.or_else(|| self.end(len).checked_sub(1))
+    }
+
+    /// Highlight `ids` and scroll to the earliest one still in the ledger;
+    /// false when none of them is.
+    fn jump_to_evidence(
+        &mut self,
+        segments: &[TranscriptSegment],
+        ids: &[u64],
+        rows: usize,
+    ) -> bool {
+        let Some(first) = segments.iter().position(|seg| ids.contains(&seg.id)) else {
+            self.evidence.clear();
+            return false;
+        };
+        self.evidence = ids.to_vec();
+        self.search = None;
+        self.follow = false;
+        self.anchor_end = (first + 1 + rows / 2).min(segments.len());
+        true
+    }
+
+    fn jump_to_match(&mut self, len: usize, rows: usize) {
+        let Some(index) = self
+            .search
+            .as_ref()
+            .and_then(|search| search.matches.get(search.current).copied())
+        else {
+            return;
+        };
+        self.follow = false;
+        self.anchor_end = (index + 1 + rows / 2).min(len);
+    }
+}
+
+/// Indices of segments containing `query` (ASCII case-insensitive).
+fn find_matches(segments: &[TranscriptSegment], query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let needle = query.to_ascii_lowercase();
+    segments
+        .iter()
+        .enumerate()
+        .filter(|(_, seg)| seg.text.to_ascii_lowercase().contains(&needle))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NotesEvidenceOutcome {
+    Stay,
+    Jump(usize),
+    Exit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NotesFocusOutcome {
+    Unchanged,
+    Changed,
+    Exit,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -175,9 +992,39 @@ enum PaletteCommandId {
     CopyTranscriptPath,
     CopyNotesPath,
     CopyAudioPath,
+    CopyNotesMarkdown,
+    CopyTranscriptSelection,
     OpenSessionFolder,
     ExportMarkdown,
+    ExportMultitrack,
+    ExportSubtitles,
+    ExportHtml,
+    ExportActionItems,
+    PostToSlack,
+    EmailMinutes,
     StartNewMeeting,
+    ShowDiagnostics,
+    EditNotes,
+    SummarizeInterval,
+    ShowNoteEvidence,
+    ReviewMeeting,
+    PlaySegmentSlow,
+    PlaySegmentSlower,
+    CopySegmentLink,
+    FocusCaptions,
+    CycleLayout,
+    TogglePause,
+    SetTitle,
+    TagMeeting,
+    NextProfile,
+    NextPromptProfile,
+    CorrectTranscript,
+    RenameSpeaker,
+    ReviewVocabulary,
+    OpenActions,
+    ShowTour,
+    Ask,
+    CatchUp,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -214,8 +1061,42 @@ impl Waveform {
     }
 }
 
+/// How long each loop waits for terminal input.
+const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// Redraw rate while the waveform animates (one frame per waveform step).
+const ACTIVE_FRAME_INTERVAL: Duration = Duration::from_millis(120);
+/// Redraw rate when nothing animates; keeps the timer and capture stats fresh.
+const IDLE_FRAME_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Skips frames when nothing changed: events and input mark it dirty, and
+/// otherwise it only lets a frame through once per tick interval.
+#[derive(Debug, Default)]
+struct RedrawGate {
+    dirty: bool,
+    last_draw: Option<Instant>,
+}
+
+impl RedrawGate {
+    fn mark(&mut self) {
+        self.dirty = true;
+    }
+
+    fn due(&self, now: Instant, interval: Duration) -> bool {
+        self.dirty
+            || self
+                .last_draw
+                .is_none_or(|last| now.duration_since(last) >= interval)
+    }
+
+    fn drawn(&mut self, now: Instant) {
+        self.dirty = false;
+        self.last_draw = Some(now);
+    }
+}
+
 struct StartMeetingInput<'a> {
     factory: &'a SessionFactory,
+    title: Option<&'a str>,
     shared_writer: &'a SharedRawAudioWriter,
     transcribe_profiles: &'a ModeProfiles,
     summarize_profiles: &'a ModeProfiles,
@@ -223,6 +1104,8 @@ struct StartMeetingInput<'a> {
     participants: &'a [String],
     audio_sample_rate_hz: u32,
     audio_mixdown: &'a MixdownConfig,
+    audio_multitrack: bool,
+    audio_archive_format: ArchiveFormat,
 }
 
 struct FooterState<'a> {
@@ -235,67 +1118,445 @@ struct FooterState<'a> {
     summarize_mode: &'a str,
     summarize_provider: &'a str,
     transcribe_connected: bool,
+    transcribe_ready: bool,
     transcribe_lag_ms: Option<u128>,
     stats: &'a CaptureStats,
     ledger: &'a TranscriptLedger,
+    notes: &'a MeetingNotes,
+    cost_usd: f64,
 }
 
 struct TerminalGuard;
 
-#[derive(Debug, Clone)]
-struct UiError {
-    message: String,
+/// Pops the key release reporting pushed for hold-to-pause.
+struct KeyboardEnhancementGuard;
+
+impl Drop for KeyboardEnhancementGuard {
+    fn drop(&mut self) {
+        let _ = crossterm::execute!(io::stdout(), PopKeyboardEnhancementFlags);
+    }
 }
 
-fn set_error(error_state: &mut Option<UiError>, message: String) {
-    *error_state = Some(UiError { message });
+/// Without release events, a held key shows up as repeats; the hold ends once
+/// they stop for this long (longer than the usual initial repeat delay).
+const HOLD_RELEASE_TIMEOUT: Duration = Duration::from_millis(750);
+
+/// Key from config such as `space`, `ctrl+space`, `p`, or `f5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
 }
 
-impl Drop for TerminalGuard {
-    fn drop(&mut self) {
-        let _ = terminal::disable_raw_mode();
-        let _ = crossterm::execute!(io::stdout(), LeaveAlternateScreen);
+impl KeyBinding {
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_lowercase();
+        if value.is_empty() {
+            return None;
+        }
+        let mut modifiers = KeyModifiers::NONE;
+        let mut parts: Vec<&str> = value.split('+').map(str::trim).collect();
+        let key = parts.pop()?;
+        for part in parts {
+            modifiers |= match part {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "option" => KeyModifiers::ALT,
+                _ => return None,
+            };
+        }
+        let code = match key {
+            "space" => KeyCode::Char(' '),
+            "tab" => KeyCode::Tab,
+            "enter" => KeyCode::Enter,
+            key if key.len() > 1 && key.starts_with('f') => KeyCode::F(key[1..].parse().ok()?),
+            key => {
+                let mut chars = key.chars();
+                let ch = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                KeyCode::Char(ch)
+            }
+        };
+        Some(Self { code, modifiers })
+    }
+
+    fn matches(&self, key: &KeyEvent) -> bool {
+        let code = match key.code {
+            KeyCode::Char(ch) => KeyCode::Char(ch.to_ascii_lowercase()),
+            other => other,
+        };
+        code == self.code && key.modifiers.difference(KeyModifiers::SHIFT) == self.modifiers
     }
 }
 
-pub fn run(ctx: TuiContext) -> Result<(), Box<dyn std::error::Error>> {
-    terminal::enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    crossterm::execute!(stdout, EnterAlternateScreen)?;
-    let _terminal_guard = TerminalGuard;
+/// Notes pane share of the split view, in percent, that `ui.split_percent` may take.
+pub const SPLIT_PERCENT_RANGE: std::ops::RangeInclusive<u16> = 20..=80;
+const SPLIT_PERCENT_STEP: u16 = 5;
 
-    // Panic hook to restore terminal on panic
-    let original_hook = std::panic::take_hook();
-    std::panic::set_hook(Box::new(move |info| {
-        let _ = terminal::disable_raw_mode();
-        let _ = crossterm::execute!(io::stdout(), LeaveAlternateScreen);
-        original_hook(info);
-    }));
+/// Which panes the main view shows, cycled with `v` and saved as `ui.layout`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaneView {
+    Split,
+    NotesOnly,
+    TranscriptOnly,
+}
 
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+impl PaneView {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "split" => Some(Self::Split),
+            "notes" => Some(Self::NotesOnly),
+            "transcript" => Some(Self::TranscriptOnly),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Split => "split",
+            Self::NotesOnly => "notes",
+            Self::TranscriptOnly => "transcript",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::Split => Self::NotesOnly,
+            Self::NotesOnly => Self::TranscriptOnly,
+            Self::TranscriptOnly => Self::Split,
+        }
+    }
+}
+
+/// Main view pane arrangement; `<`/`>` move the split.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PaneLayout {
+    view: PaneView,
+    notes_percent: u16,
+}
+
+impl PaneLayout {
+    fn from_config(config: &UiConfig) -> Self {
+        Self {
+            view: PaneView::parse(&config.layout).unwrap_or(PaneView::Split),
+            notes_percent: config
+                .split_percent
+                .clamp(*SPLIT_PERCENT_RANGE.start(), *SPLIT_PERCENT_RANGE.end()),
+        }
+    }
+
+    /// Grow or shrink the notes pane by one step; resizing shows both panes.
+    fn resize(&mut self, grow: bool) {
+        self.view = PaneView::Split;
+        self.notes_percent = if grow {
+            self.notes_percent + SPLIT_PERCENT_STEP
+        } else {
+            self.notes_percent.saturating_sub(SPLIT_PERCENT_STEP)
+        }
+        .clamp(*SPLIT_PERCENT_RANGE.start(), *SPLIT_PERCENT_RANGE.end());
+    }
+
+    /// Notes, separator, and transcript areas; hidden panes get zero width.
+    fn areas(self, area: Rect) -> [Rect; 3] {
+        let constraints = match self.view {
+            PaneView::Split => [
+                Constraint::Percentage(self.notes_percent),
+                Constraint::Length(1),
+                Constraint::Percentage(100 - self.notes_percent),
+            ],
+            PaneView::NotesOnly => [
+                Constraint::Percentage(100),
+                Constraint::Length(0),
+                Constraint::Length(0),
+            ],
+            PaneView::TranscriptOnly => [
+                Constraint::Length(0),
+                Constraint::Length(0),
+                Constraint::Percentage(100),
+            ],
+        };
+        Layout::horizontal(constraints).areas(area)
+    }
+
+    /// Write the layout back to `ui.layout`/`ui.split_percent` in the config file.
+    fn save(self, paths: &ConfigPaths, error_state: &mut Option<UiError>) {
+        let saved = Config::update(paths, |config| {
+            config.ui.layout = self.view.as_str().to_string();
+            config.ui.split_percent = self.notes_percent;
+        });
+        if let Err(err) = saved {
+            set_error(error_state, format!("layout save failed: {err}"));
+        }
+    }
+}
+
+/// Capture and meeting-clock state flipped by a pause or resume.
+struct PauseState<'a> {
+    processor: &'a mut AudioProcessor,
+    meeting_clock: &'a mut SessionClock,
+    clock_base: Instant,
+    capture_paused: &'a mut bool,
+    session: &'a mut Option<SessionHandle>,
+    error_state: &'a mut Option<UiError>,
+}
+
+impl PauseState<'_> {
+    fn set_paused(&mut self, paused: bool) {
+        if *self.capture_paused == paused {
+            return;
+        }
+        let now = clock_ns(self.clock_base);
+        let recorded = if paused {
+            self.processor.pause();
+            self.meeting_clock.pause(now);
+            let meeting_ms = self.meeting_clock.meeting_ns(now) / 1_000_000;
+            self.session
+                .as_mut()
+                .map(|s| s.record_pause(meeting_ms as i64))
+        } else {
+            self.processor.resume();
+            self.meeting_clock.resume(now);
+            self.session.as_mut().map(|s| s.record_resume())
+        };
+        *self.capture_paused = paused;
+        if let Some(Err(err)) = recorded {
+            set_error(
+                self.error_state,
+                format!("session pause write failed: {err}"),
+            );
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct UiError {
+    message: String,
+    /// Informational status shown in place of an error, e.g. a mic switch.
+    notice: bool,
+}
+
+fn set_error(error_state: &mut Option<UiError>, message: String) {
+    *error_state = Some(UiError {
+        message,
+        notice: false,
+    });
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+        let _ = crossterm::execute!(io::stdout(), LeaveAlternateScreen);
+    }
+}
+
+/// Switch to the alternate screen in raw mode; the guard restores the terminal on drop.
+fn enter_terminal() -> io::Result<(Terminal<CrosstermBackend<io::Stdout>>, TerminalGuard)> {
+    terminal::enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    crossterm::execute!(stdout, EnterAlternateScreen)?;
+    let guard = TerminalGuard;
+
+    // Panic hook to restore terminal on panic
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = terminal::disable_raw_mode();
+        let _ = crossterm::execute!(io::stdout(), LeaveAlternateScreen);
+        original_hook(info);
+    }));
+
+    let backend = CrosstermBackend::new(stdout);
+    Ok((Terminal::new(backend)?, guard))
+}
+
+/// Read-only mirror of another instance's meeting, fed by `koe attach`.
+pub fn run_observer(
+    rx: Receiver<ObserverEvent>,
+    endpoint: &str,
+    ui_config: &UiConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (mut terminal, _terminal_guard) = enter_terminal()?;
+    let theme = UiTheme::from_config(ui_config);
+    let display = TranscriptDisplay::default();
+    let mut ledger = TranscriptLedger::new();
+    let mut meeting_notes = MeetingNotes::default();
+    let mut transcript_lines = render_transcript_lines(&ledger, &display, &theme);
+    let notes_tab = NotesTab::All;
+    let mut notes_lines = render_notes_lines(&meeting_notes, 0, notes_tab, &theme);
+    let mut status = "connecting".to_string();
+    let mut redraw = RedrawGate::default();
+
+    loop {
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                ObserverEvent::Snapshot {
+                    active,
+                    segments,
+                    notes,
+                } => {
+                    ledger = TranscriptLedger::new();
+                    ledger.append(segments);
+                    meeting_notes = notes;
+                    status = if active { "live" } else { "idle" }.to_string();
+                }
+                ObserverEvent::MeetingStart => {
+                    ledger = TranscriptLedger::new();
+                    meeting_notes = MeetingNotes::default();
+                    status = "live".to_string();
+                }
+                ObserverEvent::Segments(segments) => {
+                    ledger.append(segments);
+                }
+                ObserverEvent::Notes(notes) => meeting_notes = notes,
+                ObserverEvent::MeetingEnd => status = "meeting ended".to_string(),
+                ObserverEvent::Disconnected(message) => {
+                    status = format!("disconnected: {message}; retrying");
+                }
+            }
+            transcript_lines = render_transcript_lines(&ledger, &display, &theme);
+            notes_lines = render_notes_lines(&meeting_notes, 0, notes_tab, &theme);
+            redraw.mark();
+        }
+
+        let now = Instant::now();
+        if redraw.due(now, IDLE_FRAME_INTERVAL) {
+            redraw.drawn(now);
+            terminal.draw(|frame| {
+                let [title_area, content_area, footer_area] = Layout::vertical([
+                    Constraint::Length(1),
+                    Constraint::Min(1),
+                    Constraint::Length(1),
+                ])
+                .areas(frame.area());
+
+                let title = Line::from(vec![
+                    Span::styled("■ ", Style::default().fg(theme.accent)),
+                    Span::styled(
+                        format!("koe v{}", env!("CARGO_PKG_VERSION")),
+                        Style::default().fg(theme.accent),
+                    ),
+                    Span::styled("  read-only", Style::default().fg(theme.heading)),
+                    Span::styled(format!("  {endpoint}"), Style::default().fg(theme.muted)),
+                ]);
+                frame.render_widget(Paragraph::new(title), title_area);
+
+                let [notes_area, separator_area, transcript_area] = Layout::horizontal([
+                    Constraint::Percentage(55),
+                    Constraint::Length(1),
+                    Constraint::Percentage(45),
+                ])
+                .areas(content_area);
+                frame.render_widget(
+                    Paragraph::new(Span::styled("|", Style::default().fg(theme.muted))),
+                    separator_area,
+                );
+                render_scrolled_paragraph(frame, notes_area, &notes_lines);
+                render_scrolled_paragraph(frame, transcript_area, &transcript_lines);
+
+                let footer = Line::from(vec![
+                    Span::styled(
+                        truncate_line(&status, footer_area.width.saturating_sub(8) as usize),
+                        Style::default().fg(theme.muted),
+                    ),
+                    Span::styled("  q quit", Style::default().fg(theme.muted)),
+                ]);
+                frame.render_widget(Paragraph::new(footer), footer_area);
+            })?;
+        }
+
+        let input = if event::poll(INPUT_POLL_INTERVAL)? {
+            redraw.mark();
+            Some(event::read()?)
+        } else {
+            None
+        };
+        if let Some(Event::Key(key)) = input {
+            let ctrl_c =
+                key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+            if ctrl_c || matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+pub fn run(ctx: TuiContext) -> Result<(), Box<dyn std::error::Error>> {
+    let (mut terminal, _terminal_guard) = enter_terminal()?;
 
     let mut processor = ctx.processor;
     let theme = UiTheme::from_config(&ctx.ui_config);
     let mut ledger = TranscriptLedger::new();
     let mut meeting_notes = MeetingNotes::default();
-    let mut transcript_lines = render_transcript_lines(&ledger, &theme);
-    let mut notes_lines = render_notes_lines(&meeting_notes, &theme);
+    let mut transcript_display =
+        TranscriptDisplay::new(ctx.session_factory.low_confidence_threshold());
+    let mut transcript_lines = render_transcript_lines(&ledger, &transcript_display, &theme);
+    let mut notes_tab = NotesTab::default();
+    let mut notes_lines = render_notes_lines(&meeting_notes, 0, notes_tab, &theme);
     let mut transcribe_connected = true;
+    let mut transcribe_ready = false;
     let mut transcribe_lag_ms: Option<u128> = None;
+    let mut resource_usage: Option<ResourceUsage> = None;
+    let mut update_available: Option<String> = None;
+    let mut detected_meeting: Option<String> = None;
+    let mut ask = AskState::default();
+    let mut catch_up = CatchUpState::default();
+    let mut summarize_draft: Option<String> = None;
+    let mut summarize_interval_secs = ctx.summarize_interval_secs;
     let mut error_state: Option<UiError> = None;
     let mut phase = MeetingPhase::Idle;
-    let mut mode = UiMode::Normal;
-    let mut meeting_started_at: Option<Instant> = None;
+    let mut mode = if ctx.captions {
+        UiMode::Captions
+    } else if ctx.show_tour {
+        UiMode::Tour(TourStep::Welcome)
+    } else {
+        UiMode::Normal
+    };
+    let clock_base = Instant::now();
+    let mut meeting_clock = SessionClock::new();
     let mut meeting_elapsed = Duration::ZERO;
     let mut capture_paused = true;
-    let context = ctx.initial_context.clone();
+    let mut context = ctx.initial_context.clone();
+    let mut participants = ctx.participants.clone();
+    let mut calendar_fill = CalendarFill::default();
+    let mut active_profile = ctx.active_profile.clone();
+    let mut prompt_profile = ctx.prompt_profile.clone();
+    let mut vocabulary = ctx.vocabulary.clone();
     let mut transcribe_profiles = ctx.transcribe_profiles.clone();
     let mut summarize_profiles = ctx.summarize_profiles.clone();
     let mut session: Option<SessionHandle> = None;
     let mut session_finalized = false;
     let mut waveform = Waveform::new();
+    let mut transcript_view = TranscriptView::new();
+    let mut transcript_rows: usize = 1;
+    let mut layout = PaneLayout::from_config(&ctx.ui_config);
     let mut exit_requested = false;
+    let mut playback: Option<std::process::Child> = None;
+    let mut health_check: Option<HealthCheck> = None;
+    let mut start_requested = ctx.auto_start;
+    let mut redraw = RedrawGate::default();
+    let mut last_autosave = Instant::now();
+    // Last press of the hold-to-pause key while it holds capture paused.
+    let mut held_pause: Option<Instant> = None;
+    let key_releases = ctx.hold_to_pause_key.is_some()
+        && terminal::supports_keyboard_enhancement().unwrap_or(false)
+        && crossterm::execute!(
+            io::stdout(),
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+        )
+        .is_ok();
+    let _keyboard_guard = key_releases.then_some(KeyboardEnhancementGuard);
+    macro_rules! pause_state {
+        () => {
+            PauseState {
+                processor: &mut processor,
+                meeting_clock: &mut meeting_clock,
+                clock_base,
+                capture_paused: &mut capture_paused,
+                session: &mut session,
+                error_state: &mut error_state,
+            }
+        };
+    }
     processor.pause();
 
     loop {
@@ -304,81 +1565,361 @@ pub fn run(ctx: TuiContext) -> Result<(), Box<dyn std::error::Error>> {
             session: &mut session,
             ledger: &mut ledger,
             meeting_notes: &mut meeting_notes,
+            transcript_display: &mut transcript_display,
             transcript_lines: &mut transcript_lines,
             notes_lines: &mut notes_lines,
+            notes_tab,
             transcribe_profiles: &mut transcribe_profiles,
             summarize_profiles: &mut summarize_profiles,
             transcribe_connected: &mut transcribe_connected,
+            transcribe_ready: &mut transcribe_ready,
             transcribe_lag_ms: &mut transcribe_lag_ms,
+            resource_usage: &mut resource_usage,
             error_state: &mut error_state,
             theme: &theme,
+            webhooks: &ctx.webhooks,
+            live: &ctx.live,
+            context: &mut context,
+            update_available: &mut update_available,
+            detected_meeting: &mut detected_meeting,
+            ask: &mut ask,
+            catch_up: &mut catch_up,
+            summarize_draft: &mut summarize_draft,
         };
-        drain_ui_events(&ctx.ui_rx, &mut event_state);
+        if drain_ui_events(&ctx.ui_rx, &mut event_state) {
+            redraw.mark();
+        }
+        if transcript_display.tick(Instant::now()) {
+            transcript_lines = render_transcript_lines(&ledger, &transcript_display, &theme);
+            redraw.mark();
+        }
+
+        if let Some(app) = detected_meeting.take()
+            && phase == MeetingPhase::Idle
+        {
+            redraw.mark();
+            if ctx.detect_auto_start {
+                start_requested = true;
+            } else if matches!(mode, UiMode::Normal) {
+                mode = UiMode::MeetingDetected(app);
+            }
+        }
+
+        if let Some(check) = health_check.as_mut() {
+            match check.poll(&ctx.stats, Instant::now()) {
+                HealthStatus::Pending => {}
+                HealthStatus::Passed => {
+                    redraw.mark();
+                    health_check = None;
+                    mode = UiMode::Normal;
+                    start_requested = true;
+                }
+                HealthStatus::Failed(problems) => {
+                    redraw.mark();
+                    health_check = None;
+                    mode = UiMode::HealthFailed(problems);
+                }
+            }
+        }
+        if std::mem::take(&mut start_requested) && phase == MeetingPhase::Idle {
+            redraw.mark();
+            let title = match apply_calendar_event(
+                &ctx.calendar,
+                &mut calendar_fill,
+                &mut context,
+                &mut participants,
+            ) {
+                Ok(title) => title,
+                Err(err) => {
+                    set_error(&mut error_state, format!("calendar lookup failed: {err}"));
+                    None
+                }
+            };
+            let start_input = StartMeetingInput {
+                factory: &ctx.session_factory,
+                title: title.as_deref(),
+                shared_writer: &ctx.shared_writer,
+                transcribe_profiles: &transcribe_profiles,
+                summarize_profiles: &summarize_profiles,
+                context: &context,
+                participants: &participants,
+                audio_sample_rate_hz: ctx.audio_sample_rate_hz,
+                audio_mixdown: &ctx.audio_mixdown,
+                audio_multitrack: ctx.audio_multitrack,
+                audio_archive_format: ctx.audio_archive_format,
+            };
+            if let Ok(new_session) = start_meeting(start_input) {
+                ctx.webhooks.send(WebhookEvent::meeting_start(&new_session));
+                ctx.live.meeting_start(&new_session.metadata().id);
+                let _ = ctx
+                    .transcribe_cmd_tx
+                    .send(TranscribeCommand::SessionStarted {
+                        dir: new_session.session_dir().to_path_buf(),
+                        transcript_path: new_session.transcript_path(),
+                    });
+                session = Some(new_session);
+                session_finalized = false;
+                meeting_notes = MeetingNotes::default();
+                ledger = TranscriptLedger::new();
+                transcript_view = TranscriptView::new();
+                transcript_display.reset();
+                transcript_lines = render_transcript_lines(&ledger, &transcript_display, &theme);
+                notes_lines = render_notes_lines(&meeting_notes, 0, notes_tab, &theme);
+                meeting_clock.reset();
+                meeting_clock.start(clock_ns(clock_base));
+                meeting_elapsed = Duration::ZERO;
+                last_autosave = Instant::now();
+                phase = MeetingPhase::MeetingActive;
+                capture_paused = false;
+                processor.reset_clock();
+                processor.resume();
+                if ctx.calibrate {
+                    processor.calibrate();
+                }
+                let _ = ctx.summarize_cmd_tx.send(SummarizeCommand::Reset);
+                let _ = ctx
+                    .summarize_cmd_tx
+                    .send(SummarizeCommand::UpdateContext(context.clone()));
+                let _ = ctx
+                    .summarize_cmd_tx
+                    .send(SummarizeCommand::UpdateParticipants(participants.clone()));
+            }
+        }
+
+        if let Some(result) = processor.take_calibration()
+            && let Some(session) = session.as_mut()
+            && let Err(err) = record_calibration(result, session, &ctx.shared_writer)
+        {
+            set_error(
+                &mut error_state,
+                format!("session calibration write failed: {err}"),
+            );
+            redraw.mark();
+        }
+
+        if phase == MeetingPhase::MeetingActive && meeting_clock.is_started() {
+            meeting_elapsed = meeting_elapsed_at(&meeting_clock, clock_base);
+        }
 
-        if phase == MeetingPhase::MeetingActive
-            && let Some(started) = meeting_started_at
+        if let Some(interval) = ctx.autosave_export
+            && phase == MeetingPhase::MeetingActive
+            && last_autosave.elapsed() >= interval
         {
-            meeting_elapsed = started.elapsed();
+            last_autosave = Instant::now();
+            if let Some(active_session) = session.as_ref()
+                && let Err(err) = active_session.export_markdown(ledger.segments(), &meeting_notes)
+            {
+                set_error(&mut error_state, format!("autosave export failed: {err}"));
+                redraw.mark();
+            }
         }
 
-        if phase == MeetingPhase::MeetingActive && !capture_paused {
+        let animating = phase == MeetingPhase::MeetingActive && !capture_paused;
+        if animating {
             waveform.tick();
         }
 
-        terminal.draw(|frame| {
-            let [title_area, content_area, error_area, footer_area] = Layout::vertical([
-                Constraint::Length(1),
-                Constraint::Min(1),
-                Constraint::Length(1),
-                Constraint::Length(1),
-            ])
-            .areas(frame.area());
+        let interval = if animating {
+            ACTIVE_FRAME_INTERVAL
+        } else {
+            IDLE_FRAME_INTERVAL
+        };
+        let now = Instant::now();
+        if redraw.due(now, interval) {
+            redraw.drawn(now);
+            terminal.draw(|frame| {
+                let [title_area, content_area, error_area, footer_area] = Layout::vertical([
+                    Constraint::Length(1),
+                    Constraint::Min(1),
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                ])
+                .areas(frame.area());
 
-            render_title_bar(frame, title_area, &theme);
+                render_title_bar(
+                    frame,
+                    title_area,
+                    &theme,
+                    ctx.workspace.as_deref(),
+                    active_profile.as_deref(),
+                    &prompt_profile,
+                    update_available.as_deref(),
+                );
 
-            let [notes_area, separator_area, transcript_area] = Layout::horizontal([
-                Constraint::Percentage(55),
-                Constraint::Length(1),
-                Constraint::Percentage(45),
-            ])
-            .areas(content_area);
+                let [notes_area, separator_area, transcript_area] = layout.areas(content_area);
 
-            let separator = Paragraph::new(Text::from(Line::from(Span::styled(
-                "|",
-                Style::default().fg(theme.muted),
-            ))));
-            frame.render_widget(separator, separator_area);
+                let separator = Paragraph::new(Text::from(Line::from(Span::styled(
+                    "|",
+                    Style::default().fg(theme.muted),
+                ))));
+                frame.render_widget(separator, separator_area);
 
-            render_scrolled_paragraph(frame, notes_area, &notes_lines);
-            render_scrolled_paragraph(frame, transcript_area, &transcript_lines);
+                match &mode {
+                    UiMode::NotesFocus(state) => {
+                        let hint = if state.edit.is_some() {
+                            "Notes  enter save · esc cancel"
+                        } else {
+                            "Notes  ↑/↓ select · enter edit · o owner · x done · d delete · esc back"
+                        };
+                        let lines = render_notes_focus_lines(&meeting_notes, state, hint, &theme);
+                        render_focused_paragraph(frame, notes_area, &lines, state.selected + 1);
+                    }
+                    UiMode::NotesEvidence(state) => {
+                        let lines = render_notes_focus_lines(
+                            &meeting_notes,
+                            state,
+                            "Notes  ↑/↓ select · enter show evidence · esc back",
+                            &theme,
+                        );
+                        render_focused_paragraph(frame, notes_area, &lines, state.selected + 1);
+                    }
+                    UiMode::Review(state) => {
+                        let (lines, focus) = render_review_lines(&meeting_notes, state, &theme);
+                        render_focused_paragraph(frame, notes_area, &lines, focus);
+                    }
+                    _ => match summarize_draft.as_deref() {
+                        Some(draft) => {
+                            let mut lines = notes_lines.clone();
+                            lines.push(draft_preview_line(draft, &theme));
+                            render_scrolled_paragraph(frame, notes_area, &lines);
+                        }
+                        None => render_scrolled_paragraph(frame, notes_area, &notes_lines),
+                    },
+                }
+                transcript_rows = (transcript_area.height as usize).saturating_sub(1).max(1);
+                if transcript_view.follow && transcript_view.search.is_none() {
+                    render_scrolled_paragraph(frame, transcript_area, &transcript_lines);
+                } else {
+                    let lines = render_transcript_window(
+                        ledger.segments(),
+                        &transcript_display,
+                        &transcript_view,
+                        matches!(mode, UiMode::TranscriptSearch),
+                        transcript_rows,
+                        &theme,
+                    );
+                    render_scrolled_paragraph(frame, transcript_area, &lines);
+                }
 
-            let footer_state = FooterState {
-                phase,
-                capture_paused,
-                elapsed: meeting_elapsed,
-                waveform: &waveform,
-                transcribe_mode: transcribe_profiles.active.as_str(),
-                transcribe_provider: transcribe_profiles.active_profile().provider.as_str(),
-                summarize_mode: summarize_profiles.active.as_str(),
-                summarize_provider: summarize_profiles.active_profile().provider.as_str(),
-                transcribe_connected,
-                transcribe_lag_ms,
-                stats: &ctx.stats,
-                ledger: &ledger,
-            };
-            render_error_line(frame, error_area, &theme, error_state.as_ref());
-            render_footer(frame, footer_area, &theme, footer_state);
+                let footer_state = FooterState {
+                    phase,
+                    capture_paused,
+                    elapsed: meeting_elapsed,
+                    waveform: &waveform,
+                    transcribe_mode: transcribe_profiles.active.as_str(),
+                    transcribe_provider: transcribe_profiles.active_profile().provider.as_str(),
+                    summarize_mode: summarize_profiles.active.as_str(),
+                    summarize_provider: summarize_profiles.active_profile().provider.as_str(),
+                    transcribe_connected,
+                    transcribe_ready,
+                    transcribe_lag_ms,
+                    stats: &ctx.stats,
+                    ledger: &ledger,
+                    notes: &meeting_notes,
+                    cost_usd: ctx.usage.cost_usd(),
+                };
+                render_error_line(frame, error_area, &theme, error_state.as_ref());
+                render_footer(frame, footer_area, &theme, footer_state);
 
-            match &mode {
-                UiMode::Palette(state) => {
-                    render_palette(frame, state, &theme, phase);
+                match &mode {
+                    UiMode::Palette(state) => {
+                        render_palette(frame, state, &theme, phase);
+                    }
+                    UiMode::NotesFocus(_)
+                    | UiMode::NotesEvidence(_)
+                    | UiMode::TranscriptSearch
+                    | UiMode::Review(_) => {}
+                    UiMode::Captions => {
+                        let lines =
+                            caption_lines(ledger.segments(), &transcript_display.partials, &theme);
+                        render_captions(frame, &theme, &lines);
+                    }
+                    UiMode::Tour(step) => {
+                        let areas = TourAreas {
+                            title: title_area,
+                            notes: notes_area,
+                            transcript: transcript_area,
+                            footer: footer_area,
+                        };
+                        render_tour(frame, &theme, *step, areas);
+                    }
+                    UiMode::Ask => render_ask(frame, &theme, &ask),
+                    UiMode::CatchUp => render_catch_up(frame, &theme, &catch_up),
+                    UiMode::Correct(state) => render_correct(frame, &theme, state),
+                    UiMode::RenameSpeaker(state) => render_rename_speaker(frame, &theme, state),
+                    UiMode::SetTitle(input) => render_line_prompt(
+                        frame,
+                        &theme,
+                        "Meeting title",
+                        input,
+                        "enter save  esc cancel",
+                    ),
+                    UiMode::SummarizeInterval(input) => render_line_prompt(
+                        frame,
+                        &theme,
+                        "Notes update interval (seconds)",
+                        input,
+                        "enter save  esc cancel",
+                    ),
+                    UiMode::Tags(input) => render_line_prompt(
+                        frame,
+                        &theme,
+                        "Meeting tags",
+                        input,
+                        "comma-separated  enter save  esc skip",
+                    ),
+                    UiMode::HealthCheck => render_health_check(frame, &theme, None),
+                    UiMode::HealthFailed(problems) => {
+                        render_health_check(frame, &theme, Some(problems))
+                    }
+                    UiMode::MeetingDetected(app) => render_meeting_detected(frame, &theme, app),
+                    UiMode::Vocabulary(state) => {
+                        render_vocabulary(frame, &theme, &vocabulary, state);
+                    }
+                    UiMode::Actions(state) => render_actions(frame, &theme, state),
+                    UiMode::Diagnostics => {
+                        let diagnostics = DiagnosticsState {
+                            usage: resource_usage,
+                            max_rss_mb: ctx.max_rss_mb,
+                            transcribe_lag_ms,
+                            stats: &ctx.stats,
+                        };
+                        render_diagnostics(frame, &theme, diagnostics);
+                    }
+                    UiMode::Normal => {}
                 }
-                UiMode::Normal => {}
-            }
-        })?;
+            })?;
+        }
 
-        if event::poll(Duration::from_millis(50))?
-            && let Event::Key(key) = event::read()?
+        let mut input = if event::poll(INPUT_POLL_INTERVAL)? {
+            redraw.mark();
+            Some(event::read()?)
+        } else {
+            None
+        };
+        if let Some(Event::Key(key)) = input
+            && ctx.hold_to_pause_key.is_some_and(|hold| hold.matches(&key))
+            && matches!(mode, UiMode::Normal)
+        {
+            input = None;
+            if key.kind == KeyEventKind::Release {
+                if held_pause.take().is_some() {
+                    pause_state!().set_paused(false);
+                }
+            } else if phase == MeetingPhase::MeetingActive
+                && (held_pause.is_some() || !capture_paused)
+            {
+                pause_state!().set_paused(true);
+                held_pause = Some(Instant::now());
+            }
+        }
+        if !key_releases && held_pause.is_some_and(|last| last.elapsed() >= HOLD_RELEASE_TIMEOUT) {
+            held_pause = None;
+            pause_state!().set_paused(false);
+            redraw.mark();
+        }
+        if let Some(Event::Key(key)) = input
+            && key.kind != KeyEventKind::Release
         {
             if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
                 exit_requested = true;
@@ -394,127 +1935,527 @@ pub fn run(ctx: TuiContext) -> Result<(), Box<dyn std::error::Error>> {
                     {
                         mode = UiMode::Palette(PaletteState::new());
                     }
+                    if phase == MeetingPhase::MeetingActive
+                        && ctx.pause_key.is_some_and(|pause| pause.matches(&key))
+                    {
+                        let paused = !capture_paused;
+                        pause_state!().set_paused(paused);
+                        held_pause = None;
+                        continue;
+                    }
+                    let len = ledger.len();
+                    match key.code {
+                        KeyCode::PageUp => transcript_view.scroll_up(len, transcript_rows),
+                        KeyCode::PageDown => transcript_view.scroll_down(len, transcript_rows),
+                        KeyCode::Char('g') => transcript_view.scroll_top(len, transcript_rows),
+                        KeyCode::Char('G') => transcript_view.follow = true,
+                        KeyCode::Char('f') => transcript_view.toggle_follow(len),
+                        KeyCode::Char('/') => {
+                            transcript_view.search = Some(TranscriptSearch::default());
+                            mode = UiMode::TranscriptSearch;
+                        }
+                        KeyCode::Char('n') => {
+                            transcript_view.step_match(ledger.segments(), transcript_rows, true);
+                        }
+                        KeyCode::Char('N') => {
+                            transcript_view.step_match(ledger.segments(), transcript_rows, false);
+                        }
+                        KeyCode::Esc => {
+                            transcript_view.search = None;
+                            transcript_view.evidence.clear();
+                        }
+                        KeyCode::Char(ch @ ('v' | '<' | '>')) => {
+                            match ch {
+                                'v' => layout.view = layout.view.next(),
+                                _ => layout.resize(ch == '>'),
+                            }
+                            layout.save(&ctx.config_paths, &mut error_state);
+                        }
+                        KeyCode::Char(ch) => {
+                            if let Some(tab) = NotesTab::from_key(ch) {
+                                notes_tab = tab;
+                                let archived = session.as_ref().map_or(0, |s| s.notes_archived());
+                                notes_lines =
+                                    render_notes_lines(&meeting_notes, archived, notes_tab, &theme);
+                            }
+                        }
+                        _ => {}
+                    }
                 }
-                UiMode::Palette(state) => {
-                    if key.code == KeyCode::Esc {
+                UiMode::TranscriptSearch => match key.code {
+                    KeyCode::Esc => {
+                        transcript_view.search = None;
+                        transcript_view.follow = true;
                         mode = UiMode::Normal;
-                        continue;
                     }
-                    if key.code == KeyCode::Up && state.selected > 0 {
-                        state.selected -= 1;
+                    KeyCode::Enter => mode = UiMode::Normal,
+                    KeyCode::Up => {
+                        transcript_view.step_match(ledger.segments(), transcript_rows, true);
                     }
-                    if key.code == KeyCode::Down {
-                        state.selected = state.selected.saturating_add(1);
+                    KeyCode::Down => {
+                        transcript_view.step_match(ledger.segments(), transcript_rows, false);
                     }
-                    if key.code == KeyCode::Backspace {
-                        state.filter.pop();
-                        state.selected = 0;
+                    KeyCode::Backspace => {
+                        if let Some(search) = transcript_view.search.as_mut() {
+                            search.query.pop();
+                        }
+                        transcript_view.update_search(ledger.segments(), transcript_rows);
                     }
-                    if let KeyCode::Char(ch) = key.code
-                        && !key.modifiers.contains(KeyModifiers::CONTROL)
-                    {
-                        state.filter.push(ch);
-                        state.selected = 0;
+                    KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        if let Some(search) = transcript_view.search.as_mut() {
+                            search.query.push(ch);
+                        }
+                        transcript_view.update_search(ledger.segments(), transcript_rows);
                     }
-                    if key.code == KeyCode::Enter {
-                        let commands = filtered_commands(phase, &state.filter);
-                        if let Some(command) = commands.get(state.selected) {
-                            match command.id {
-                                PaletteCommandId::StartMeeting => {
-                                    let start_input = StartMeetingInput {
-                                        factory: &ctx.session_factory,
-                                        shared_writer: &ctx.shared_writer,
-                                        transcribe_profiles: &transcribe_profiles,
-                                        summarize_profiles: &summarize_profiles,
-                                        context: &context,
-                                        participants: &ctx.participants,
-                                        audio_sample_rate_hz: ctx.audio_sample_rate_hz,
-                                        audio_mixdown: &ctx.audio_mixdown,
-                                    };
-                                    if let Ok(new_session) = start_meeting(start_input) {
-                                        session = Some(new_session);
-                                        session_finalized = false;
-                                        meeting_notes = MeetingNotes::default();
-                                        ledger = TranscriptLedger::new();
-                                        transcript_lines = render_transcript_lines(&ledger, &theme);
-                                        notes_lines = render_notes_lines(&meeting_notes, &theme);
-                                        meeting_started_at = Some(Instant::now());
-                                        meeting_elapsed = Duration::ZERO;
-                                        phase = MeetingPhase::MeetingActive;
-                                        capture_paused = false;
-                                        processor.resume();
-                                        let _ = ctx.summarize_cmd_tx.send(SummarizeCommand::Reset);
-                                        let _ = ctx
-                                            .summarize_cmd_tx
-                                            .send(SummarizeCommand::UpdateContext(context.clone()));
-                                    }
+                    _ => {}
+                },
+                UiMode::NotesEvidence(state) => {
+                    match handle_notes_evidence_key(state, key, meeting_notes.bullets.len()) {
+                        NotesEvidenceOutcome::Stay => {}
+                        NotesEvidenceOutcome::Jump(index) => {
+                            let evidence = &meeting_notes.bullets[index].evidence;
+                            if evidence.is_empty() {
+                                set_error(&mut error_state, "note cites no transcript".to_string());
+                            } else if !transcript_view.jump_to_evidence(
+                                ledger.segments(),
+                                evidence,
+                                transcript_rows,
+                            ) {
+                                set_error(
+                                    &mut error_state,
+                                    "cited transcript is no longer in view".to_string(),
+                                );
+                            }
+                        }
+                        NotesEvidenceOutcome::Exit => mode = UiMode::Normal,
+                    }
+                }
+                UiMode::NotesFocus(state) => {
+                    match handle_notes_focus_key(state, key, &mut meeting_notes) {
+                        NotesFocusOutcome::Unchanged => {}
+                        NotesFocusOutcome::Changed => {
+                            let archived = session.as_ref().map_or(0, |s| s.notes_archived());
+                            notes_lines =
+                                render_notes_lines(&meeting_notes, archived, notes_tab, &theme);
+                            if let Some(active_session) = session.as_mut() {
+                                if let Err(err) = active_session.write_notes(&meeting_notes) {
+                                    set_error(
+                                        &mut error_state,
+                                        format!("session notes write failed: {err}"),
+                                    );
                                 }
-                                PaletteCommandId::EndMeeting => {
-                                    processor.pause();
-                                    let mut event_state = UiEventState {
-                                        phase,
-                                        session: &mut session,
-                                        ledger: &mut ledger,
-                                        meeting_notes: &mut meeting_notes,
-                                        transcript_lines: &mut transcript_lines,
-                                        notes_lines: &mut notes_lines,
-                                        transcribe_profiles: &mut transcribe_profiles,
-                                        summarize_profiles: &mut summarize_profiles,
-                                        transcribe_connected: &mut transcribe_connected,
-                                        transcribe_lag_ms: &mut transcribe_lag_ms,
-                                        error_state: &mut error_state,
-                                        theme: &theme,
-                                    };
-                                    let drained = drain_transcribe_with_timeout(
-                                        &ctx.ui_rx,
-                                        &ctx.transcribe_cmd_tx,
-                                        &mut event_state,
-                                        Duration::from_secs(2),
+                                if phase == MeetingPhase::PostMeeting
+                                    && let Err(err) =
+                                        active_session.export_notes_markdown(&meeting_notes)
+                                {
+                                    set_error(
+                                        &mut error_state,
+                                        format!("export notes failed: {err}"),
                                     );
-                                    if !drained {
-                                        set_error(
-                                            &mut error_state,
-                                            "transcribe drain timed out".to_string(),
-                                        );
-                                    }
+                                }
+                            }
+                        }
+                        NotesFocusOutcome::Exit => {
+                            mode = UiMode::Normal;
+                        }
+                    }
+                }
+                UiMode::Review(state) => match handle_review_key(state, key, &mut meeting_notes) {
+                    ReviewOutcome::Unchanged => {}
+                    ReviewOutcome::Changed => {
+                        let archived = session.as_ref().map_or(0, |s| s.notes_archived());
+                        notes_lines =
+                            render_notes_lines(&meeting_notes, archived, notes_tab, &theme);
+                    }
+                    ReviewOutcome::Finalize => {
+                        state.apply(&mut meeting_notes);
+                        let targets = state.selected_targets();
+                        mode = UiMode::Normal;
+                        let archived = session.as_ref().map_or(0, |s| s.notes_archived());
+                        notes_lines =
+                            render_notes_lines(&meeting_notes, archived, notes_tab, &theme);
+                        if let Some(active_session) = session.as_mut() {
+                            if let Err(err) = active_session.write_notes(&meeting_notes) {
+                                set_error(
+                                    &mut error_state,
+                                    format!("session notes write failed: {err}"),
+                                );
+                            }
+                            finish_meeting(
+                                &ctx.webhooks,
+                                &ctx.live,
+                                &ctx.summarize_cmd_tx,
+                                active_session,
+                                ledger.segments().to_vec(),
+                                &mut meeting_notes,
+                                &mut error_state,
+                            );
+                            session_finalized = true;
+                            deliver_review_exports(
+                                &ctx.review,
+                                &ctx.slack,
+                                active_session,
+                                &meeting_notes,
+                                &targets,
+                                &mut error_state,
+                            );
+                            if ctx.tag_prompt {
+                                mode = UiMode::Tags(tags_input(active_session));
+                            }
+                        }
+                    }
+                    ReviewOutcome::Exit => mode = UiMode::Normal,
+                },
+                UiMode::Diagnostics | UiMode::Captions => {
+                    if key.code == KeyCode::Esc {
+                        mode = UiMode::Normal;
+                    }
+                }
+                UiMode::Tour(step) => {
+                    if handle_tour_key(step, key) == TourOutcome::Exit {
+                        mode = UiMode::Normal;
+                    }
+                }
+                UiMode::HealthCheck => {
+                    if key.code == KeyCode::Esc {
+                        health_check = None;
+                        mode = UiMode::Normal;
+                    }
+                }
+                UiMode::HealthFailed(_) => match key.code {
+                    KeyCode::Char('r') | KeyCode::Enter => {
+                        health_check = Some(begin_health_check(
+                            &ctx.transcribe_cmd_tx,
+                            &ctx.stats,
+                            &ctx.capture_sources,
+                        ));
+                        mode = UiMode::HealthCheck;
+                    }
+                    KeyCode::Char('s') => {
+                        start_requested = true;
+                        mode = UiMode::Normal;
+                    }
+                    KeyCode::Esc => mode = UiMode::Normal,
+                    _ => {}
+                },
+                UiMode::MeetingDetected(_) => match key.code {
+                    KeyCode::Enter | KeyCode::Char('s') => {
+                        if ctx.health_check {
+                            health_check = Some(begin_health_check(
+                                &ctx.transcribe_cmd_tx,
+                                &ctx.stats,
+                                &ctx.capture_sources,
+                            ));
+                            mode = UiMode::HealthCheck;
+                        } else {
+                            start_requested = true;
+                            mode = UiMode::Normal;
+                        }
+                    }
+                    KeyCode::Esc => mode = UiMode::Normal,
+                    _ => {}
+                },
+                UiMode::CatchUp => {
+                    if matches!(key.code, KeyCode::Esc | KeyCode::Enter) {
+                        mode = UiMode::Normal;
+                    }
+                }
+                UiMode::Ask => match handle_ask_key(&mut ask, key) {
+                    AskOutcome::Stay => {}
+                    AskOutcome::Submit(question) => {
+                        let _ = ctx.summarize_cmd_tx.send(SummarizeCommand::Ask(question));
+                    }
+                    AskOutcome::Exit => mode = UiMode::Normal,
+                },
+                UiMode::Correct(state) => match handle_correct_key(state, key) {
+                    CorrectOutcome::Stay => {}
+                    CorrectOutcome::Submit(text) => {
+                        let segment_id = state.segment_id;
+                        mode = UiMode::Normal;
+                        let Some(segment) = ledger
+                            .segments()
+                            .iter()
+                            .find(|seg| seg.id == segment_id)
+                            .cloned()
+                        else {
+                            continue;
+                        };
+                        if segment.text.trim() == text {
+                            continue;
+                        }
+                        let learned =
+                            vocabulary.learn(segment.speaker.as_deref(), &segment.text, &text);
+                        if !learned.is_empty() {
+                            if let Err(err) =
+                                crate::vocabulary::save(&ctx.vocabulary_path, &vocabulary)
+                            {
+                                set_error(
+                                    &mut error_state,
+                                    format!("vocabulary save failed: {err}"),
+                                );
+                            }
+                            let _ = ctx
+                                .transcribe_cmd_tx
+                                .send(TranscribeCommand::UpdateVocabulary(vocabulary.clone()));
+                        }
+                        let corrected = TranscriptSegment { text, ..segment };
+                        if let Some(active_session) = session.as_mut()
+                            && let Err(err) =
+                                active_session.append_transcript(std::slice::from_ref(&corrected))
+                        {
+                            set_error(
+                                &mut error_state,
+                                format!("session transcript write failed: {err}"),
+                            );
+                        }
+                        ctx.live.transcript(std::slice::from_ref(&corrected));
+                        let replacements = ledger.append(vec![corrected]);
+                        transcript_display.record(replacements, &ledger);
+                        transcript_lines =
+                            render_transcript_lines(&ledger, &transcript_display, &theme);
+                    }
+                    CorrectOutcome::Exit => mode = UiMode::Normal,
+                },
+                UiMode::RenameSpeaker(state) => {
+                    match handle_rename_speaker_key(state, key, &participants) {
+                        CorrectOutcome::Stay => {}
+                        CorrectOutcome::Submit(name) => {
+                            let label = state.label.clone();
+                            mode = UiMode::Normal;
+                            if name == label {
+                                transcript_display.speaker_names.remove(&label);
+                            } else {
+                                transcript_display
+                                    .speaker_names
+                                    .insert(label.clone(), name.clone());
+                                if !participants.contains(&name) {
+                                    participants.push(name.clone());
+                                    let _ = ctx.summarize_cmd_tx.send(
+                                        SummarizeCommand::UpdateParticipants(participants.clone()),
+                                    );
+                                }
+                            }
+                            if let Some(active_session) = session.as_mut()
+                                && let Err(err) = active_session.rename_speaker(&label, &name)
+                            {
+                                set_error(
+                                    &mut error_state,
+                                    format!("session speaker rename failed: {err}"),
+                                );
+                            }
+                            transcript_lines =
+                                render_transcript_lines(&ledger, &transcript_display, &theme);
+                        }
+                        CorrectOutcome::Exit => mode = UiMode::Normal,
+                    }
+                }
+                UiMode::SetTitle(input) => match handle_line_input(input, key) {
+                    CorrectOutcome::Stay => {}
+                    CorrectOutcome::Submit(title) => {
+                        mode = UiMode::Normal;
+                        if let Some(active_session) = session.as_mut()
+                            && let Err(err) = active_session.update_title(&title)
+                        {
+                            set_error(&mut error_state, format!("title save failed: {err}"));
+                        }
+                    }
+                    CorrectOutcome::Exit => mode = UiMode::Normal,
+                },
+                UiMode::SummarizeInterval(input) => match handle_line_input(input, key) {
+                    CorrectOutcome::Stay => {}
+                    CorrectOutcome::Submit(value) => match value.parse::<u32>() {
+                        Ok(secs) if secs > 0 => {
+                            mode = UiMode::Normal;
+                            summarize_interval_secs = secs;
+                            let _ = ctx
+                                .summarize_cmd_tx
+                                .send(SummarizeCommand::SetInterval(secs));
+                            error_state = Some(UiError {
+                                message: format!("notes update every {secs}s"),
+                                notice: true,
+                            });
+                        }
+                        _ => set_error(
+                            &mut error_state,
+                            format!("interval must be a whole number of seconds: {value}"),
+                        ),
+                    },
+                    CorrectOutcome::Exit => mode = UiMode::Normal,
+                },
+                UiMode::Tags(input) => {
+                    // Unlike other prompts, an empty line is submitted: it clears the tags.
+                    let outcome = if key.code == KeyCode::Enter {
+                        CorrectOutcome::Submit(input.clone())
+                    } else {
+                        handle_line_input(input, key)
+                    };
+                    match outcome {
+                        CorrectOutcome::Stay => {}
+                        CorrectOutcome::Submit(tags) => {
+                            mode = UiMode::Normal;
+                            if let Some(active_session) = session.as_mut()
+                                && let Err(err) = active_session.update_tags(parse_tags(&tags))
+                            {
+                                set_error(&mut error_state, format!("tags save failed: {err}"));
+                            }
+                        }
+                        CorrectOutcome::Exit => mode = UiMode::Normal,
+                    }
+                }
+                UiMode::Actions(state) => match handle_actions_key(state, key) {
+                    ActionsOutcome::Stay => {}
+                    ActionsOutcome::Done(index) => {
+                        let result = state
+                            .store
+                            .mark_done(&state.ids[index])
+                            .map(|_| ())
+                            .and_then(|()| {
+                                crate::actions::save(&ctx.actions_path, &state.store)
+                                    .map_err(ActionsError::from)
+                            });
+                        if let Err(err) = result {
+                            set_error(&mut error_state, format!("action update failed: {err}"));
+                        }
+                    }
+                    ActionsOutcome::Exit => mode = UiMode::Normal,
+                },
+                UiMode::Vocabulary(state) => {
+                    match handle_vocabulary_key(state, key, vocabulary.entries.len()) {
+                        VocabularyOutcome::Stay => {}
+                        VocabularyOutcome::Remove(index) => {
+                            vocabulary.entries.remove(index);
+                            state.selected = state
+                                .selected
+                                .min(vocabulary.entries.len().saturating_sub(1));
+                            if let Err(err) =
+                                crate::vocabulary::save(&ctx.vocabulary_path, &vocabulary)
+                            {
+                                set_error(
+                                    &mut error_state,
+                                    format!("vocabulary save failed: {err}"),
+                                );
+                            }
+                            let _ = ctx
+                                .transcribe_cmd_tx
+                                .send(TranscribeCommand::UpdateVocabulary(vocabulary.clone()));
+                        }
+                        VocabularyOutcome::Exit => mode = UiMode::Normal,
+                    }
+                }
+                UiMode::Palette(state) => {
+                    if key.code == KeyCode::Esc {
+                        mode = UiMode::Normal;
+                        continue;
+                    }
+                    if key.code == KeyCode::Up && state.selected > 0 {
+                        state.selected -= 1;
+                    }
+                    if key.code == KeyCode::Down {
+                        state.selected = state.selected.saturating_add(1);
+                    }
+                    if key.code == KeyCode::Backspace {
+                        state.filter.pop();
+                        state.selected = 0;
+                    }
+                    if let KeyCode::Char(ch) = key.code
+                        && !key.modifiers.contains(KeyModifiers::CONTROL)
+                    {
+                        state.filter.push(ch);
+                        state.selected = 0;
+                    }
+                    if key.code == KeyCode::Enter {
+                        let commands = filtered_commands(phase, &state.filter);
+                        if let Some(command) = commands.get(state.selected) {
+                            match command.id {
+                                PaletteCommandId::StartMeeting => {
+                                    if ctx.health_check {
+                                        health_check = Some(begin_health_check(
+                                            &ctx.transcribe_cmd_tx,
+                                            &ctx.stats,
+                                            &ctx.capture_sources,
+                                        ));
+                                        mode = UiMode::HealthCheck;
+                                        continue;
+                                    }
+                                    start_requested = true;
+                                }
+                                PaletteCommandId::EndMeeting => {
+                                    processor.pause();
+                                    let mut event_state = UiEventState {
+                                        phase,
+                                        session: &mut session,
+                                        ledger: &mut ledger,
+                                        meeting_notes: &mut meeting_notes,
+                                        transcript_display: &mut transcript_display,
+                                        transcript_lines: &mut transcript_lines,
+                                        notes_lines: &mut notes_lines,
+                                        notes_tab,
+                                        transcribe_profiles: &mut transcribe_profiles,
+                                        summarize_profiles: &mut summarize_profiles,
+                                        transcribe_connected: &mut transcribe_connected,
+                                        transcribe_ready: &mut transcribe_ready,
+                                        transcribe_lag_ms: &mut transcribe_lag_ms,
+                                        resource_usage: &mut resource_usage,
+                                        error_state: &mut error_state,
+                                        theme: &theme,
+                                        webhooks: &ctx.webhooks,
+                                        live: &ctx.live,
+                                        context: &mut context,
+                                        update_available: &mut update_available,
+                                        detected_meeting: &mut detected_meeting,
+                                        ask: &mut ask,
+                                        catch_up: &mut catch_up,
+                                        summarize_draft: &mut summarize_draft,
+                                    };
+                                    let drained = drain_transcribe_with_timeout(
+                                        &ctx.ui_rx,
+                                        &ctx.transcribe_cmd_tx,
+                                        &mut event_state,
+                                        Duration::from_secs(2),
+                                    );
+                                    if !drained {
+                                        set_error(
+                                            &mut error_state,
+                                            "transcribe drain timed out".to_string(),
+                                        );
+                                    }
                                     ctx.shared_writer.set(None);
+                                    capture_paused = true;
+                                    phase = MeetingPhase::PostMeeting;
+                                    if session.is_some() && ctx.review.enabled {
+                                        mode = UiMode::Review(ReviewState::new(
+                                            export_targets::available_targets(
+                                                &ctx.review,
+                                                &ctx.slack,
+                                            ),
+                                        ));
+                                        continue;
+                                    }
                                     if let Some(active_session) = session.as_mut() {
-                                        let segments = ledger.segments().to_vec();
-                                        let state_snapshot = meeting_notes.clone();
-                                        match export_session_with_timeout(
-                                            active_session.clone(),
-                                            segments,
-                                            state_snapshot,
-                                        ) {
-                                            Ok(ExportOutcome::Completed) => {}
-                                            Ok(ExportOutcome::Pending) => {
-                                                set_error(
-                                                    &mut error_state,
-                                                    "export still running; continuing in background"
-                                                        .to_string(),
-                                                );
-                                            }
-                                            Err(err) => {
-                                                set_error(
-                                                    &mut error_state,
-                                                    format!("export failed: {err}"),
-                                                );
-                                            }
-                                        }
-                                        if let Err(err) = active_session.finalize() {
-                                            set_error(
-                                                &mut error_state,
-                                                format!("session finalize failed: {err}"),
-                                            );
-                                        }
+                                        finish_meeting(
+                                            &ctx.webhooks,
+                                            &ctx.live,
+                                            &ctx.summarize_cmd_tx,
+                                            active_session,
+                                            ledger.segments().to_vec(),
+                                            &mut meeting_notes,
+                                            &mut error_state,
+                                        );
                                         session_finalized = true;
+                                        if ctx.tag_prompt {
+                                            mode = UiMode::Tags(tags_input(active_session));
+                                            continue;
+                                        }
                                     }
-                                    capture_paused = true;
-                                    phase = MeetingPhase::PostMeeting;
                                 }
                                 PaletteCommandId::BrowseSessions => {
-                                    if let Err(err) = open_path(ctx.session_factory.sessions_dir())
-                                    {
+                                    // Exports are named by date and title; session dirs by id.
+                                    let dir = ctx
+                                        .session_factory
+                                        .export_root()
+                                        .filter(|root| root.is_dir())
+                                        .unwrap_or(ctx.session_factory.sessions_dir());
+                                    if let Err(err) = open_path(dir) {
                                         set_error(
                                             &mut error_state,
                                             format!("open sessions failed: {err}"),
@@ -537,14 +2478,81 @@ pub fn run(ctx: TuiContext) -> Result<(), Box<dyn std::error::Error>> {
                                         set_error(&mut error_state, format!("copy failed: {err}"));
                                     }
                                 }
+                                PaletteCommandId::CopySegmentLink => {
+                                    let segments = ledger.segments();
+                                    let link = match (
+                                        session.as_ref(),
+                                        transcript_view.selected(segments.len()),
+                                    ) {
+                                        (Some(active_session), Some(index)) => active_session
+                                            .transcript_link(segments, index)
+                                            .map_err(|err| err.to_string()),
+                                        _ => Ok(None),
+                                    };
+                                    match link {
+                                        Ok(Some(link)) => {
+                                            if let Err(err) = clipboard::copy(&link) {
+                                                set_error(
+                                                    &mut error_state,
+                                                    format!("copy failed: {err}"),
+                                                );
+                                            }
+                                        }
+                                        Ok(None) => set_error(
+                                            &mut error_state,
+                                            "no transcript line to link".to_string(),
+                                        ),
+                                        Err(err) => set_error(
+                                            &mut error_state,
+                                            format!("copy failed: {err}"),
+                                        ),
+                                    }
+                                }
                                 PaletteCommandId::CopyAudioPath => {
                                     if let Some(active_session) = session.as_ref()
                                         && let Err(err) =
-                                            copy_to_clipboard(&active_session.audio_raw_path())
+                                            copy_to_clipboard(&active_session.audio_archive_path())
                                     {
                                         set_error(&mut error_state, format!("copy failed: {err}"));
                                     }
                                 }
+                                PaletteCommandId::CopyNotesMarkdown => {
+                                    if let Some(active_session) = session.as_ref() {
+                                        let copied = active_session
+                                            .notes_markdown(&meeting_notes)
+                                            .map_err(|err| err.to_string())
+                                            .and_then(|markdown| {
+                                                clipboard::copy(&markdown)
+                                                    .map_err(|err| err.to_string())
+                                            });
+                                        if let Err(err) = copied {
+                                            set_error(
+                                                &mut error_state,
+                                                format!("copy failed: {err}"),
+                                            );
+                                        }
+                                    }
+                                }
+                                PaletteCommandId::CopyTranscriptSelection => {
+                                    match transcript_selection_text(
+                                        ledger.segments(),
+                                        &transcript_view,
+                                        &transcript_display.speaker_names,
+                                    ) {
+                                        Some(text) => {
+                                            if let Err(err) = clipboard::copy(&text) {
+                                                set_error(
+                                                    &mut error_state,
+                                                    format!("copy failed: {err}"),
+                                                );
+                                            }
+                                        }
+                                        None => set_error(
+                                            &mut error_state,
+                                            "no transcript line to copy".to_string(),
+                                        ),
+                                    }
+                                }
                                 PaletteCommandId::OpenSessionFolder => {
                                     if let Some(active_session) = session.as_ref()
                                         && let Err(err) = open_path(active_session.session_dir())
@@ -575,6 +2583,103 @@ pub fn run(ctx: TuiContext) -> Result<(), Box<dyn std::error::Error>> {
                                         }
                                     }
                                 }
+                                PaletteCommandId::ExportActionItems => {
+                                    if let Some(active_session) = session.as_ref() {
+                                        match active_session.export_action_items(&meeting_notes) {
+                                            Ok(Some(_)) => {}
+                                            Ok(None) => set_error(
+                                                &mut error_state,
+                                                "no action items to export".to_string(),
+                                            ),
+                                            Err(err) => set_error(
+                                                &mut error_state,
+                                                format!("export action items failed: {err}"),
+                                            ),
+                                        }
+                                    }
+                                }
+                                PaletteCommandId::ExportMultitrack => {
+                                    if let Some(active_session) = session.as_ref()
+                                        && let Err(err) =
+                                            active_session.export_multitrack(ledger.segments())
+                                    {
+                                        set_error(
+                                            &mut error_state,
+                                            format!("export multitrack failed: {err}"),
+                                        );
+                                    }
+                                }
+                                PaletteCommandId::ExportSubtitles => {
+                                    if let Some(active_session) = session.as_ref() {
+                                        if let Err(err) =
+                                            active_session.export_transcript_srt(ledger.segments())
+                                        {
+                                            set_error(
+                                                &mut error_state,
+                                                format!("export srt failed: {err}"),
+                                            );
+                                        }
+                                        if let Err(err) =
+                                            active_session.export_transcript_vtt(ledger.segments())
+                                        {
+                                            set_error(
+                                                &mut error_state,
+                                                format!("export vtt failed: {err}"),
+                                            );
+                                        }
+                                    }
+                                }
+                                PaletteCommandId::ExportHtml => {
+                                    if let Some(active_session) = session.as_ref() {
+                                        let exported = active_session
+                                            .full_notes(&meeting_notes)
+                                            .and_then(|notes| {
+                                                active_session
+                                                    .export_html(ledger.segments(), &notes)
+                                            });
+                                        if let Err(err) = exported {
+                                            set_error(
+                                                &mut error_state,
+                                                format!("export html failed: {err}"),
+                                            );
+                                        }
+                                    }
+                                }
+                                PaletteCommandId::PostToSlack => {
+                                    if let Some(active_session) = session.as_ref() {
+                                        if ctx.slack.is_enabled() {
+                                            if let Err(err) = spawn_slack_post(
+                                                ctx.slack.clone(),
+                                                active_session.session_dir().to_path_buf(),
+                                                ctx.ui_tx.clone(),
+                                            ) {
+                                                set_error(
+                                                    &mut error_state,
+                                                    format!("slack post failed: {err}"),
+                                                );
+                                            }
+                                        } else {
+                                            set_error(
+                                                &mut error_state,
+                                                "slack post failed: set integrations.slack.webhook first"
+                                                    .to_string(),
+                                            );
+                                        }
+                                    }
+                                }
+                                PaletteCommandId::EmailMinutes => {
+                                    if let Some(active_session) = session.as_ref()
+                                        && let Err(err) = email::compose_minutes(
+                                            &ctx.email,
+                                            active_session.session_dir(),
+                                        )
+                                    {
+                                        set_error(
+                                            &mut error_state,
+                                            format!("email draft failed: {err}"),
+                                        );
+                                    }
+                                }
                                 PaletteCommandId::StartNewMeeting => {
                                     processor.pause();
                                     let needs_export = session.as_ref().is_some_and(|active| {
@@ -586,14 +2691,26 @@ pub fn run(ctx: TuiContext) -> Result<(), Box<dyn std::error::Error>> {
                                             session: &mut session,
                                             ledger: &mut ledger,
                                             meeting_notes: &mut meeting_notes,
+                                            transcript_display: &mut transcript_display,
                                             transcript_lines: &mut transcript_lines,
                                             notes_lines: &mut notes_lines,
+                                            notes_tab,
                                             transcribe_profiles: &mut transcribe_profiles,
                                             summarize_profiles: &mut summarize_profiles,
                                             transcribe_connected: &mut transcribe_connected,
+                                            transcribe_ready: &mut transcribe_ready,
                                             transcribe_lag_ms: &mut transcribe_lag_ms,
+                                            resource_usage: &mut resource_usage,
                                             error_state: &mut error_state,
                                             theme: &theme,
+                                            webhooks: &ctx.webhooks,
+                                            live: &ctx.live,
+                                            context: &mut context,
+                                            update_available: &mut update_available,
+                                            detected_meeting: &mut detected_meeting,
+                                            ask: &mut ask,
+                                            catch_up: &mut catch_up,
+                                            summarize_draft: &mut summarize_draft,
                                         };
                                         let drained = drain_transcribe_with_timeout(
                                             &ctx.ui_rx,
@@ -636,70 +2753,383 @@ pub fn run(ctx: TuiContext) -> Result<(), Box<dyn std::error::Error>> {
                                             }
                                         }
                                         let _ = active_session.finalize();
+                                        ctx.webhooks
+                                            .send(WebhookEvent::meeting_end(active_session));
+                                        ctx.live.meeting_end();
+                                        let _ =
+                                            ctx.summarize_cmd_tx.send(SummarizeCommand::Finalize {
+                                                session: Box::new(active_session.clone()),
+                                                segments: ledger.segments().to_vec(),
+                                            });
                                     }
                                     session = None;
                                     session_finalized = false;
                                     meeting_notes = MeetingNotes::default();
                                     ledger = TranscriptLedger::new();
-                                    transcript_lines = render_transcript_lines(&ledger, &theme);
-                                    notes_lines = render_notes_lines(&meeting_notes, &theme);
-                                    meeting_started_at = None;
+                                    transcript_view = TranscriptView::new();
+                                    transcript_display.reset();
+                                    transcript_lines = render_transcript_lines(
+                                        &ledger,
+                                        &transcript_display,
+                                        &theme,
+                                    );
+                                    notes_lines =
+                                        render_notes_lines(&meeting_notes, 0, notes_tab, &theme);
+                                    meeting_clock.reset();
                                     meeting_elapsed = Duration::ZERO;
                                     phase = MeetingPhase::Idle;
                                     capture_paused = true;
 
                                     let _ = ctx.summarize_cmd_tx.send(SummarizeCommand::Reset);
 
+                                    let title = match apply_calendar_event(
+                                        &ctx.calendar,
+                                        &mut calendar_fill,
+                                        &mut context,
+                                        &mut participants,
+                                    ) {
+                                        Ok(title) => title,
+
+                                        Err(err) => {
+                                            set_error(
+                                                &mut error_state,
+                                                format!("calendar lookup failed: {err}"),
+                                            );
+
+                                            None
+                                        }
+                                    };
+
                                     let start_input = StartMeetingInput {
                                         factory: &ctx.session_factory,
+                                        title: title.as_deref(),
                                         shared_writer: &ctx.shared_writer,
                                         transcribe_profiles: &transcribe_profiles,
                                         summarize_profiles: &summarize_profiles,
                                         context: &context,
-                                        participants: &ctx.participants,
+                                        participants: &participants,
                                         audio_sample_rate_hz: ctx.audio_sample_rate_hz,
                                         audio_mixdown: &ctx.audio_mixdown,
+                                        audio_multitrack: ctx.audio_multitrack,
+                                        audio_archive_format: ctx.audio_archive_format,
                                     };
                                     if let Ok(new_session) = start_meeting(start_input) {
+                                        ctx.webhooks
+                                            .send(WebhookEvent::meeting_start(&new_session));
+                                        ctx.live.meeting_start(&new_session.metadata().id);
+                                        let _ = ctx.transcribe_cmd_tx.send(
+                                            TranscribeCommand::SessionStarted {
+                                                dir: new_session.session_dir().to_path_buf(),
+                                                transcript_path: new_session.transcript_path(),
+                                            },
+                                        );
                                         session = Some(new_session);
                                         session_finalized = false;
                                         meeting_notes = MeetingNotes::default();
                                         ledger = TranscriptLedger::new();
-                                        transcript_lines = render_transcript_lines(&ledger, &theme);
-                                        notes_lines = render_notes_lines(&meeting_notes, &theme);
-                                        meeting_started_at = Some(Instant::now());
+                                        transcript_view = TranscriptView::new();
+                                        transcript_display.reset();
+                                        transcript_lines = render_transcript_lines(
+                                            &ledger,
+                                            &transcript_display,
+                                            &theme,
+                                        );
+                                        notes_lines = render_notes_lines(
+                                            &meeting_notes,
+                                            0,
+                                            notes_tab,
+                                            &theme,
+                                        );
+                                        meeting_clock.reset();
+                                        meeting_clock.start(clock_ns(clock_base));
                                         meeting_elapsed = Duration::ZERO;
+                                        last_autosave = Instant::now();
                                         phase = MeetingPhase::MeetingActive;
                                         capture_paused = false;
+                                        processor.reset_clock();
                                         processor.resume();
+                                        if ctx.calibrate {
+                                            processor.calibrate();
+                                        }
                                         let _ = ctx
                                             .summarize_cmd_tx
                                             .send(SummarizeCommand::UpdateContext(context.clone()));
+                                        let _ = ctx.summarize_cmd_tx.send(
+                                            SummarizeCommand::UpdateParticipants(
+                                                participants.clone(),
+                                            ),
+                                        );
                                     }
                                 }
-                            }
-                        }
-                        mode = UiMode::Normal;
-                    }
-                }
-            }
-        }
-
-        if exit_requested {
+                                PaletteCommandId::Ask => {
+                                    mode = UiMode::Ask;
+                                    continue;
+                                }
+                                PaletteCommandId::CatchUp => {
+                                    catch_up = CatchUpState {
+                                        pending: true,
+                                        ..CatchUpState::default()
+                                    };
+                                    let _ = ctx.summarize_cmd_tx.send(SummarizeCommand::CatchUp);
+                                    mode = UiMode::CatchUp;
+                                    continue;
+                                }
+                                PaletteCommandId::CorrectTranscript => {
+                                    let segments = ledger.segments();
+                                    match transcript_view
+                                        .selected(segments.len())
+                                        .and_then(|index| segments.get(index))
+                                    {
+                                        Some(segment) => {
+                                            mode = UiMode::Correct(CorrectState {
+                                                segment_id: segment.id,
+                                                input: segment.text.trim().to_string(),
+                                            });
+                                            continue;
+                                        }
+                                        None => set_error(
+                                            &mut error_state,
+                                            "no transcript line to correct".to_string(),
+                                        ),
+                                    }
+                                }
+                                PaletteCommandId::RenameSpeaker => {
+                                    let segments = ledger.segments();
+                                    // The selected line's speaker, else whoever spoke last.
+                                    let label = transcript_view
+                                        .selected(segments.len())
+                                        .and_then(|index| segments.get(index))
+                                        .into_iter()
+                                        .chain(segments.iter().rev())
+                                        .find_map(|segment| segment.speaker.clone());
+                                    match label {
+                                        Some(label) => {
+                                            let input = transcript_display
+                                                .speaker_names
+                                                .get(&label)
+                                                .unwrap_or(&label)
+                                                .clone();
+                                            mode = UiMode::RenameSpeaker(RenameSpeakerState {
+                                                label,
+                                                input,
+                                                suggestion: 0,
+                                            });
+                                            continue;
+                                        }
+                                        None => set_error(
+                                            &mut error_state,
+                                            "no speaker to rename".to_string(),
+                                        ),
+                                    }
+                                }
+                                PaletteCommandId::SummarizeInterval => {
+                                    mode = UiMode::SummarizeInterval(
+                                        summarize_interval_secs.to_string(),
+                                    );
+                                    continue;
+                                }
+                                PaletteCommandId::SetTitle => {
+                                    if let Some(active_session) = session.as_ref() {
+                                        mode = UiMode::SetTitle(
+                                            active_session
+                                                .metadata()
+                                                .title
+                                                .clone()
+                                                .unwrap_or_default(),
+                                        );
+                                        continue;
+                                    }
+                                }
+                                PaletteCommandId::TagMeeting => {
+                                    if let Some(active_session) = session.as_ref() {
+                                        mode = UiMode::Tags(tags_input(active_session));
+                                        continue;
+                                    }
+                                }
+                                PaletteCommandId::ReviewVocabulary => {
+                                    mode = UiMode::Vocabulary(VocabularyReviewState::default());
+                                    continue;
+                                }
+                                PaletteCommandId::OpenActions => {
+                                    match ActionsViewState::load(
+                                        &ctx.actions_path,
+                                        &ctx.actions_sessions_dirs,
+                                    ) {
+                                        Ok(state) => {
+                                            mode = UiMode::Actions(state);
+                                            continue;
+                                        }
+                                        Err(err) => set_error(
+                                            &mut error_state,
+                                            format!("action items failed: {err}"),
+                                        ),
+                                    }
+                                }
+                                PaletteCommandId::ShowDiagnostics => {
+                                    mode = UiMode::Diagnostics;
+                                    continue;
+                                }
+                                PaletteCommandId::ShowTour => {
+                                    mode = UiMode::Tour(TourStep::Welcome);
+                                    continue;
+                                }
+                                PaletteCommandId::TogglePause => {
+                                    let paused = !capture_paused;
+                                    pause_state!().set_paused(paused);
+                                    held_pause = None;
+                                }
+                                PaletteCommandId::NextPromptProfile => {
+                                    if let Some(next) =
+                                        next_prompt_profile(&ctx.prompt_profiles, &prompt_profile)
+                                    {
+                                        prompt_profile = next.to_string();
+                                        let _ = ctx.summarize_cmd_tx.send(
+                                            SummarizeCommand::SetPromptProfile(
+                                                prompt_profile.clone(),
+                                            ),
+                                        );
+                                    }
+                                }
+                                PaletteCommandId::NextProfile => {
+                                    match next_profile(
+                                        &ctx.meeting_profiles,
+                                        active_profile.as_deref(),
+                                    ) {
+                                        Some(profile) => {
+                                            if let Some(value) = &profile.context {
+                                                context = value.clone();
+                                            }
+                                            if !profile.participants.is_empty() {
+                                                participants = profile.participants.clone();
+                                            }
+                                            let _ = ctx.summarize_cmd_tx.send(
+                                                SummarizeCommand::UpdateContext(context.clone()),
+                                            );
+                                            let _ = ctx.summarize_cmd_tx.send(
+                                                SummarizeCommand::UpdateParticipants(
+                                                    participants.clone(),
+                                                ),
+                                            );
+                                            if let Some(value) = &profile.prompt_profile {
+                                                prompt_profile = value.clone();
+                                                let _ = ctx.summarize_cmd_tx.send(
+                                                    SummarizeCommand::SetPromptProfile(
+                                                        value.clone(),
+                                                    ),
+                                                );
+                                            }
+                                            active_profile = Some(profile.name.clone());
+                                        }
+                                        None => set_error(
+                                            &mut error_state,
+                                            "no meeting profiles in config".to_string(),
+                                        ),
+                                    }
+                                }
+                                PaletteCommandId::FocusCaptions => {
+                                    mode = UiMode::Captions;
+                                    continue;
+                                }
+                                PaletteCommandId::CycleLayout => {
+                                    layout.view = layout.view.next();
+                                    layout.save(&ctx.config_paths, &mut error_state);
+                                }
+                                PaletteCommandId::EditNotes => {
+                                    mode = UiMode::NotesFocus(NotesFocusState::default());
+                                    continue;
+                                }
+                                PaletteCommandId::ShowNoteEvidence => {
+                                    // Start on the newest note, the one most likely being checked.
+                                    mode = UiMode::NotesEvidence(NotesFocusState {
+                                        selected: meeting_notes.bullets.len().saturating_sub(1),
+                                        edit: None,
+                                    });
+                                    continue;
+                                }
+                                PaletteCommandId::PlaySegmentSlow
+                                | PaletteCommandId::PlaySegmentSlower => {
+                                    let speed = match command.id {
+                                        PaletteCommandId::PlaySegmentSlow => 0.75,
+                                        _ => 0.5,
+                                    };
+                                    let segments = ledger.segments();
+                                    let selected = transcript_view
+                                        .selected(segments.len())
+                                        .and_then(|index| segments.get(index));
+                                    if let (Some(active_session), Some(segment)) =
+                                        (session.as_ref(), selected)
+                                    {
+                                        if let Some(mut child) = playback.take() {
+                                            let _ = child.kill();
+                                            let _ = child.wait();
+                                        }
+                                        match active_session
+                                            .segment_clip(segment, speed)
+                                            .map_err(|e| e.to_string())
+                                            .and_then(|path| {
+                                                play_audio(&path).map_err(|e| e.to_string())
+                                            }) {
+                                            Ok(child) => playback = Some(child),
+                                            Err(err) => set_error(
+                                                &mut error_state,
+                                                format!("segment playback failed: {err}"),
+                                            ),
+                                        }
+                                    }
+                                }
+                                PaletteCommandId::ReviewMeeting => {
+                                    if session_finalized
+                                        || session.as_ref().is_none_or(|s| s.is_finalized())
+                                    {
+                                        set_error(
+                                            &mut error_state,
+                                            "meeting already finalized".to_string(),
+                                        );
+                                    } else {
+                                        mode = UiMode::Review(ReviewState::new(
+                                            export_targets::available_targets(
+                                                &ctx.review,
+                                                &ctx.slack,
+                                            ),
+                                        ));
+                                        continue;
+                                    }
+                                }
+                            }
+                        }
+                        mode = UiMode::Normal;
+                    }
+                }
+            }
+        }
+
+        if exit_requested {
             processor.pause();
             let mut event_state = UiEventState {
                 phase,
                 session: &mut session,
                 ledger: &mut ledger,
                 meeting_notes: &mut meeting_notes,
+                transcript_display: &mut transcript_display,
                 transcript_lines: &mut transcript_lines,
                 notes_lines: &mut notes_lines,
+                notes_tab,
                 transcribe_profiles: &mut transcribe_profiles,
                 summarize_profiles: &mut summarize_profiles,
                 transcribe_connected: &mut transcribe_connected,
+                transcribe_ready: &mut transcribe_ready,
                 transcribe_lag_ms: &mut transcribe_lag_ms,
+                resource_usage: &mut resource_usage,
                 error_state: &mut error_state,
                 theme: &theme,
+                webhooks: &ctx.webhooks,
+                live: &ctx.live,
+                context: &mut context,
+                update_available: &mut update_available,
+                detected_meeting: &mut detected_meeting,
+                ask: &mut ask,
+                catch_up: &mut catch_up,
+                summarize_draft: &mut summarize_draft,
             };
             let drained = drain_transcribe_with_timeout(
                 &ctx.ui_rx,
@@ -715,14 +3145,27 @@ pub fn run(ctx: TuiContext) -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    if let Some(mut child) = playback {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
     if let Some(mut active_session) = session
         && !active_session.is_finalized()
         && !session_finalized
     {
         let segments = ledger.segments().to_vec();
         let notes_snapshot = meeting_notes.clone();
-        let _ = export_session_with_timeout(active_session.clone(), segments, notes_snapshot);
+        let _ =
+            export_session_with_timeout(active_session.clone(), segments.clone(), notes_snapshot);
         let _ = active_session.finalize();
+        ctx.webhooks
+            .send(WebhookEvent::meeting_end(&active_session));
+        ctx.live.meeting_end();
+        let _ = ctx.summarize_cmd_tx.send(SummarizeCommand::Finalize {
+            session: Box::new(active_session),
+            segments,
+        });
     }
 
     processor.stop();
@@ -735,19 +3178,53 @@ struct UiEventState<'a> {
     session: &'a mut Option<SessionHandle>,
     ledger: &'a mut TranscriptLedger,
     meeting_notes: &'a mut MeetingNotes,
+    transcript_display: &'a mut TranscriptDisplay,
     transcript_lines: &'a mut Vec<Line<'static>>,
     notes_lines: &'a mut Vec<Line<'static>>,
+    notes_tab: NotesTab,
     transcribe_profiles: &'a mut ModeProfiles,
     summarize_profiles: &'a mut ModeProfiles,
     transcribe_connected: &'a mut bool,
+    transcribe_ready: &'a mut bool,
     transcribe_lag_ms: &'a mut Option<u128>,
+    resource_usage: &'a mut Option<ResourceUsage>,
     error_state: &'a mut Option<UiError>,
     theme: &'a UiTheme,
+    webhooks: &'a WebhookSender,
+    live: &'a LiveFeed,
+    context: &'a mut String,
+    update_available: &'a mut Option<String>,
+    detected_meeting: &'a mut Option<String>,
+    ask: &'a mut AskState,
+    catch_up: &'a mut CatchUpState,
+    summarize_draft: &'a mut Option<String>,
 }
 
 impl<'a> UiEventState<'a> {
     fn push_error(&mut self, message: String) {
-        *self.error_state = Some(UiError { message });
+        set_error(self.error_state, message);
+    }
+
+    /// Move the oldest bullets to the session's notes archive and re-render what remains.
+    fn archive_old_notes(&mut self) {
+        let mut archived = 0;
+        if let Some(active_session) = self.session.as_mut() {
+            let batch: Vec<NoteBullet> = self
+                .meeting_notes
+                .bullets
+                .drain(..NOTES_ARCHIVE_BATCH)
+                .collect();
+            match active_session.archive_notes(&batch) {
+                Ok(()) => archived = active_session.notes_archived(),
+                Err(err) => {
+                    self.meeting_notes.bullets.splice(0..0, batch);
+                    archived = active_session.notes_archived();
+                    self.push_error(format!("session notes archive failed: {err}"));
+                }
+            }
+        }
+        *self.notes_lines =
+            render_notes_lines(self.meeting_notes, archived, self.notes_tab, self.theme);
     }
 
     fn apply_event(&mut self, event: UiEvent) {
@@ -756,25 +3233,75 @@ impl<'a> UiEventState<'a> {
         match event {
             UiEvent::Transcript(segments) => {
                 if accept_updates {
-                    if let Some(active_session) = self.session.as_mut()
-                        && let Err(err) = active_session.append_transcript(&segments)
-                    {
-                        self.push_error(format!("session transcript write failed: {err}"));
-                    }
-                    self.ledger.append(segments);
-                    *self.transcript_lines = render_transcript_lines(self.ledger, self.theme);
+                    self.live.transcript(&segments);
+                    let replacements = self.ledger.append(segments);
+                    self.transcript_display.record(replacements, self.ledger);
+                    self.transcript_display.partials.clear();
+                    *self.transcript_lines =
+                        render_transcript_lines(self.ledger, self.transcript_display, self.theme);
+                }
+            }
+            UiEvent::TranscriptPartial(segments) => {
+                if accept_updates {
+                    self.transcript_display.partials = segments;
+                    *self.transcript_lines =
+                        render_transcript_lines(self.ledger, self.transcript_display, self.theme);
                 }
             }
             UiEvent::NotesPatch(patch) => {
-                if accept_updates && apply_notes_patch(self.meeting_notes, patch) {
+                let before = self.meeting_notes.bullets.len();
+                let ops = patch.ops.clone();
+                let adds_only = ops.iter().all(|op| matches!(op, NotesOp::Add { .. }));
+                if accept_updates && self.meeting_notes.apply_patch(patch) {
+                    if let Some(active_session) = self.session.as_ref() {
+                        self.webhooks
+                            .send(WebhookEvent::notes_patch(active_session, ops));
+                    }
+                    if self.meeting_notes.bullets.len() > MAX_LIVE_NOTES {
+                        self.archive_old_notes();
+                    } else if !adds_only || self.notes_tab == NotesTab::All {
+                        // Removed or merged bullets leave earlier lines stale,
+                        // and new ones may land in any section.
+                        let archived = self
+                            .session
+                            .as_ref()
+                            .map_or(0, |session| session.notes_archived());
+                        *self.notes_lines = render_notes_lines(
+                            self.meeting_notes,
+                            archived,
+                            self.notes_tab,
+                            self.theme,
+                        );
+                    } else {
+                        let was_empty = !self.meeting_notes.bullets[..before]
+                            .iter()
+                            .any(|bullet| self.notes_tab.includes(bullet.kind));
+                        append_note_lines(
+                            self.notes_lines,
+                            &self.meeting_notes.bullets[before..],
+                            was_empty,
+                            self.notes_tab,
+                            self.theme,
+                        );
+                    }
                     if let Some(active_session) = self.session.as_mut()
                         && let Err(err) = active_session.write_notes(self.meeting_notes)
                     {
                         self.push_error(format!("session notes write failed: {err}"));
                     }
-                    *self.notes_lines = render_notes_lines(self.meeting_notes, self.theme);
+                    self.live.notes(self.meeting_notes);
+                }
+            }
+            UiEvent::SummarizeDraftToken(token) => {
+                if self.phase == MeetingPhase::MeetingActive {
+                    self.summarize_draft
+                        .get_or_insert_default()
+                        .push_str(&token);
                 }
             }
+            UiEvent::SummarizeDraftDone => {
+                *self.summarize_draft = None;
+            }
             UiEvent::Error { message } => {
                 self.push_error(message);
             }
@@ -782,10 +3309,12 @@ impl<'a> UiEventState<'a> {
                 mode,
                 provider,
                 connected,
+                ready,
             } => {
                 self.transcribe_profiles.active = mode.clone();
                 self.transcribe_profiles.set_provider(&mode, provider);
                 *self.transcribe_connected = connected;
+                *self.transcribe_ready = ready;
                 if let Some(active_session) = self.session.as_mut() {
                     let profile = self.transcribe_profiles.active_profile();
                     if let Err(err) = active_session
@@ -810,21 +3339,120 @@ impl<'a> UiEventState<'a> {
             UiEvent::TranscribeLag { last_ms } => {
                 *self.transcribe_lag_ms = Some(last_ms);
             }
+            UiEvent::ResourceUsage(usage) => {
+                *self.resource_usage = Some(usage);
+            }
+            UiEvent::UpdateAvailable { version } => {
+                *self.update_available = Some(version);
+            }
+            UiEvent::MicrophoneChanged(change) => {
+                let message = if change.fallback {
+                    format!("microphone disconnected; switched to {}", change.name)
+                } else {
+                    format!("microphone {} reconnected", change.name)
+                };
+                *self.error_state = Some(UiError {
+                    message,
+                    notice: true,
+                });
+            }
+            UiEvent::MeetingDetected { app } => {
+                if self.phase == MeetingPhase::Idle {
+                    *self.detected_meeting = Some(app);
+                }
+            }
+            UiEvent::SessionTitled { session_id, title } => {
+                if let Some(active_session) = self.session.as_mut()
+                    && active_session.metadata().id == session_id
+                {
+                    if let Err(err) = active_session.reload_labels() {
+                        self.push_error(format!("session reload failed: {err}"));
+                    } else {
+                        *self.error_state = Some(UiError {
+                            message: format!("titled: {title}"),
+                            notice: true,
+                        });
+                    }
+                }
+            }
+            UiEvent::SlackPosted { error } => match error {
+                None => {
+                    *self.error_state = Some(UiError {
+                        message: "minutes posted to slack".to_string(),
+                        notice: true,
+                    });
+                }
+                Some(err) => self.push_error(format!("slack post failed: {err}")),
+            },
+            UiEvent::AskToken(token) => {
+                if self.ask.pending {
+                    self.ask.answer.push_str(&token);
+                }
+            }
+            UiEvent::AskDone { error } => {
+                self.ask.pending = false;
+                self.ask.error = error;
+            }
+            UiEvent::CatchUpToken(token) => {
+                if self.catch_up.pending {
+                    self.catch_up.digest.push_str(&token);
+                }
+            }
+            UiEvent::CatchUpDone { error } => {
+                self.catch_up.pending = false;
+                self.catch_up.error = error;
+            }
+            UiEvent::Translations(translations) => {
+                for translation in translations {
+                    self.transcript_display
+                        .translations
+                        .insert(translation.id, translation.text);
+                }
+                *self.transcript_lines =
+                    render_transcript_lines(self.ledger, self.transcript_display, self.theme);
+            }
+            UiEvent::Chapters(chapters) => {
+                if accept_updates {
+                    if let Some(active_session) = self.session.as_ref()
+                        && let Err(err) = active_session.write_chapters(&chapters)
+                    {
+                        self.push_error(format!("chapters write failed: {err}"));
+                    }
+                    self.transcript_display.chapters = chapters;
+                    *self.transcript_lines =
+                        render_transcript_lines(self.ledger, self.transcript_display, self.theme);
+                }
+            }
+            UiEvent::ContextUpdate(value) => {
+                if accept_updates {
+                    if let Some(active_session) = self.session.as_mut()
+                        && let Err(err) = active_session.update_context(&value)
+                    {
+                        self.push_error(format!("session context update failed: {err}"));
+                    }
+                    *self.context = value;
+                }
+            }
         }
     }
 }
 
-fn drain_ui_events(ui_rx: &Receiver<UiEvent>, state: &mut UiEventState<'_>) {
+/// Apply all pending events; true when anything was applied.
+fn drain_ui_events(ui_rx: &Receiver<UiEvent>, state: &mut UiEventState<'_>) -> bool {
+    let mut changed = false;
     loop {
         match ui_rx.try_recv() {
             Ok(event) => state.apply_event(event),
             Err(TryRecvError::Empty) => break,
             Err(TryRecvError::Disconnected) => {
+                changed |= *state.transcribe_connected;
                 *state.transcribe_connected = false;
                 break;
             }
         }
+        changed = true;
     }
+    changed
 }
 
 fn drain_transcribe_with_timeout(
@@ -860,7 +3488,7 @@ fn drain_transcribe_with_timeout(
         }
     }
 
-    drain_ui_events(ui_rx, state);
+    let _ = drain_ui_events(ui_rx, state);
 
     drained
 }
@@ -870,7 +3498,7 @@ fn start_meeting(
 ) -> Result<SessionHandle, crate::session::SessionError> {
     let transcribe_profile = input.transcribe_profiles.active_profile();
     let summarize_profile = input.summarize_profiles.active_profile();
-    let session = input.factory.create(
+    let mut session = input.factory.create(
         transcribe_profile.provider.to_string(),
         transcribe_profile.model.to_string(),
         summarize_profile.provider.to_string(),
@@ -882,26 +3510,116 @@ fn start_meeting(
         },
         input.participants.to_vec(),
     )?;
-    let audio_raw = session.open_audio_raw()?;
-    input.shared_writer.set(Some(RawAudioWriter::new(
-        audio_raw,
+    if let Some(title) = input.title {
+        session.update_title(title)?;
+    }
+    let audio_path = session.open_audio_archive(input.audio_archive_format)?;
+    let mut writer = RawAudioWriter::create(
+        input.audio_archive_format,
+        &audio_path,
         input.audio_sample_rate_hz,
         input.audio_mixdown.clone(),
-    )));
+    )?;
+    if input.audio_multitrack {
+        let (system, mic) = session.open_audio_tracks()?;
+        writer = writer.with_tracks(system, mic);
+    }
+    input.shared_writer.set(Some(writer));
     Ok(session)
 }
 
-fn render_title_bar(frame: &mut ratatui::Frame, area: Rect, theme: &UiTheme) {
+/// Context and participants last taken from the calendar, which the next
+/// meeting's event may replace; anything the user set is kept.
+#[derive(Default)]
+struct CalendarFill {
+    context: Option<String>,
+    participants: Option<Vec<String>>,
+}
+
+/// Fill an empty context and participant list from the calendar event running
+/// now; returns the event title for the session metadata.
+fn apply_calendar_event(
+    calendar: &CalendarConfig,
+    filled: &mut CalendarFill,
+    context: &mut String,
+    participants: &mut Vec<String>,
+) -> Result<Option<String>, CalendarError> {
+    let Some(event) = calendar::current_event(calendar)? else {
+        return Ok(None);
+    };
+    if context.trim().is_empty() || filled.context.as_ref() == Some(context) {
+        *context = event.context();
+        filled.context = Some(context.clone());
+    }
+    if !event.participants.is_empty()
+        && (participants.is_empty() || filled.participants.as_ref() == Some(participants))
+    {
+        *participants = event.participants;
+        filled.participants = Some(participants.clone());
+    }
+    Ok(Some(event.title).filter(|title| !title.is_empty()))
+}
+
+/// Apply a finished noise calibration to the mixdown denoise gate and store it
+/// in the session metadata.
+fn record_calibration(
+    result: NoiseCalibration,
+    session: &mut SessionHandle,
+    shared_writer: &SharedRawAudioWriter,
+) -> Result<(), crate::session::SessionError> {
+    let denoise_threshold = calibrated_denoise_threshold(result.noise_floor_dbfs);
+    let denoise_applied = shared_writer.set_denoise_threshold(denoise_threshold);
+    session.record_calibration(Calibration {
+        noise_floor_dbfs: result.noise_floor_dbfs,
+        vad_threshold: result.vad_threshold,
+        denoise_threshold_dbfs: denoise_applied.then_some(denoise_threshold),
+    })
+}
+
+fn render_title_bar(
+    frame: &mut ratatui::Frame,
+    area: Rect,
+    theme: &UiTheme,
+    workspace: Option<&str>,
+    profile: Option<&str>,
+    prompt_profile: &str,
+    update: Option<&str>,
+) {
     let hint = "ctrl+p command palette";
     let hint_len = hint.len() as u16;
     let [left, right] =
         Layout::horizontal([Constraint::Min(1), Constraint::Length(hint_len + 1)]).areas(area);
 
     let version = env!("CARGO_PKG_VERSION");
-    let left_line = Line::from(vec![
+    let mut left_spans = vec![
         Span::styled("■ ", Style::default().fg(theme.accent)),
         Span::styled(format!("koe v{version}"), Style::default().fg(theme.accent)),
-    ]);
+    ];
+    if let Some(workspace) = workspace {
+        left_spans.push(Span::styled(
+            format!("  {workspace}"),
+            Style::default().fg(theme.heading),
+        ));
+    }
+    if let Some(profile) = profile {
+        left_spans.push(Span::styled(
+            format!("  {profile}"),
+            Style::default().fg(theme.muted),
+        ));
+    }
+    if prompt_profile != "minimal" {
+        left_spans.push(Span::styled(
+            format!("  prompt {prompt_profile}"),
+            Style::default().fg(theme.muted),
+        ));
+    }
+    if let Some(update) = update {
+        left_spans.push(Span::styled(
+            format!("  update {update} available"),
+            Style::default().fg(theme.muted),
+        ));
+    }
+    let left_line = Line::from(left_spans);
     let right_line = Line::from(Span::styled(hint, Style::default().fg(theme.muted)));
 
     frame.render_widget(Paragraph::new(left_line), left);
@@ -928,17 +3646,36 @@ fn render_footer(frame: &mut ratatui::Frame, area: Rect, theme: &UiTheme, state:
         "----------".to_string()
     };
 
-    let transcribe_state = if state.transcribe_connected {
-        "ok"
-    } else {
+    let transcribe_state = if !state.transcribe_connected {
         "disc"
+    } else if !state.transcribe_ready {
+        "warm"
+    } else {
+        "ok"
     };
     let lag = state
         .transcribe_lag_ms
         .map(|ms| format!("{:.1}", ms as f64 / 1000.0))
         .unwrap_or_else(|| "n/a".to_string());
+    // Local-only meetings cost nothing, so the estimate only shows once a cloud call is billed.
+    let cost = if state.cost_usd > 0.0 {
+        format!(" | cost:~${:.3}", state.cost_usd)
+    } else {
+        String::new()
+    };
+    let count = |kind: NoteKind| {
+        state
+            .notes
+            .bullets
+            .iter()
+            .filter(|bullet| bullet.kind == kind)
+            .count()
+    };
     let metrics = format!(
-        "transcribe:{}:{} | summarize:{}:{} | {transcribe_state} | lag:{lag}s | chunks:{}/{} | raw_drop:{} | segs:{}",
+        "D:{} A:{} Q:{} | transcribe:{}:{} | summarize:{}:{} | {transcribe_state} | lag:{lag}s | chunks:{}/{} | raw_drop:{} | segs:{}{cost}",
+        count(NoteKind::Decision),
+        count(NoteKind::Action),
+        count(NoteKind::Question),
         state.transcribe_mode,
         state.transcribe_provider,
         state.summarize_mode,
@@ -961,14 +3698,37 @@ fn render_footer(frame: &mut ratatui::Frame, area: Rect, theme: &UiTheme, state:
         Paragraph::new(wave_text).style(Style::default().fg(theme.muted)),
         middle,
     );
+    let mut metrics_spans = Vec::new();
+    if let Some(missing) = missing_sources_label(state.stats) {
+        metrics_spans.push(Span::styled(
+            format!("{missing} | "),
+            Style::default().fg(theme.error),
+        ));
+    }
+    metrics_spans.push(Span::styled(metrics, Style::default().fg(theme.muted)));
     frame.render_widget(
-        Paragraph::new(metrics)
-            .alignment(Alignment::Right)
-            .style(Style::default().fg(theme.muted)),
+        Paragraph::new(Line::from(metrics_spans)).alignment(Alignment::Right),
         right,
     );
 }
 
+/// Footer marker for requested capture sources that are unavailable and being re-probed.
+fn missing_sources_label(stats: &CaptureStats) -> Option<String> {
+    let missing: Vec<&str> = [
+        (AudioSource::System, "system"),
+        (AudioSource::Microphone, "mic"),
+    ]
+    .into_iter()
+    .filter(|(source, _)| stats.source_missing(*source))
+    .map(|(_, label)| label)
+    .collect();
+    if missing.is_empty() {
+        None
+    } else {
+        Some(format!("no {} (retrying)", missing.join("+")))
+    }
+}
+
 fn render_error_line(
     frame: &mut ratatui::Frame,
     area: Rect,
@@ -976,8 +3736,13 @@ fn render_error_line(
     error_state: Option<&UiError>,
 ) {
     let line = if let Some(error) = error_state {
-        let message = truncate_line(&format!("error: {}", error.message), area.width as usize);
-        Line::from(Span::styled(message, Style::default().fg(theme.error)))
+        let (prefix, color) = if error.notice {
+            ("notice", theme.accent)
+        } else {
+            ("error", theme.error)
+        };
+        let message = truncate_line(&format!("{prefix}: {}", error.message), area.width as usize);
+        Line::from(Span::styled(message, Style::default().fg(color)))
     } else {
         Line::from(Span::styled("", Style::default().fg(theme.muted)))
     };
@@ -1061,65 +3826,822 @@ fn render_palette(
     );
 }
 
-fn render_command_lines(
-    commands: Vec<(PaletteCommand, bool)>,
-    theme: &UiTheme,
-    width: usize,
-) -> Vec<Line<'static>> {
-    let mut lines = Vec::new();
-    for (command, is_selected) in commands.into_iter() {
-        let label = command.label;
-        let category = command.category;
-        let available = width.saturating_sub(1);
-        let gap = available
-            .saturating_sub(label.len())
-            .saturating_sub(category.len())
-            .max(1);
-        let padding = " ".repeat(gap);
-        let spans = if is_selected {
-            let sel = Style::default()
-                .fg(theme.accent)
-                .add_modifier(Modifier::REVERSED);
-            vec![
-                Span::styled(label.to_string(), sel),
-                Span::styled(padding, sel),
-                Span::styled(category.to_string(), sel),
-            ]
-        } else {
-            vec![
-                Span::styled(label.to_string(), Style::default().fg(theme.neutral)),
-                Span::styled(padding, Style::default().fg(theme.neutral)),
-                Span::styled(category.to_string(), Style::default().fg(theme.muted)),
-            ]
-        };
+fn render_ask(frame: &mut ratatui::Frame, theme: &UiTheme, state: &AskState) {
+    let width = 72.min(frame.area().width.saturating_sub(4) as usize) as u16;
+    let height = 18.min(frame.area().height.saturating_sub(2));
+    let area = centered_rect(width, height, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(Block::default().borders(Borders::ALL), area);
 
-        lines.push(Line::from(spans));
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    let [title_area, input_area, body_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new("Ask the Meeting")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.heading)),
+        title_area,
+    );
+    frame.render_widget(Paragraph::new(format!("> {}", state.input)), input_area);
+
+    let mut lines = Vec::new();
+    if !state.question.is_empty() {
+        lines.push(Line::from(Span::styled(
+            state.question.clone(),
+            Style::default().fg(theme.accent),
+        )));
     }
-    lines
-}
+    if !state.answer.is_empty() {
+        lines.push(Line::from(Span::styled(
+            state.answer.trim().to_string(),
+            Style::default().fg(theme.neutral),
+        )));
+    } else if state.pending {
+        lines.push(Line::from(Span::styled(
+            "thinking...",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    if let Some(error) = &state.error {
+        lines.push(Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(theme.error),
+        )));
+    }
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).wrap(Wrap { trim: false }),
+        body_area,
+    );
 
-fn render_scrolled_paragraph(frame: &mut ratatui::Frame, area: Rect, lines: &[Line<'static>]) {
-    let scroll = lines.len().saturating_sub(area.height as usize) as u16;
-    let padded = pad_lines(lines);
-    let paragraph = Paragraph::new(Text::from(padded))
-        .wrap(Wrap { trim: false })
-        .scroll((scroll, 0));
-    frame.render_widget(paragraph, area);
+    let hint = if state.pending {
+        "answering... esc close"
+    } else {
+        "enter ask  esc close"
+    };
+    frame.render_widget(
+        Paragraph::new(hint)
+            .alignment(Alignment::Right)
+            .style(Style::default().fg(theme.muted)),
+        hint_area,
+    );
 }
 
-fn pad_lines(lines: &[Line<'static>]) -> Vec<Line<'static>> {
-    lines
-        .iter()
-        .cloned()
-        .map(|line| {
-            let mut spans = vec![Span::raw(" ")];
-            spans.extend(line.spans);
-            Line::from(spans)
+fn render_catch_up(frame: &mut ratatui::Frame, theme: &UiTheme, state: &CatchUpState) {
+    let width = 72.min(frame.area().width.saturating_sub(4) as usize) as u16;
+    let height = 12.min(frame.area().height.saturating_sub(2));
+    let area = centered_rect(width, height, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(Block::default().borders(Borders::ALL), area);
+
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    let [title_area, body_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new("Catch Up")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.heading)),
+        title_area,
+    );
+
+    let mut lines: Vec<Line> = state
+        .digest
+        .trim()
+        .lines()
+        .map(|line| {
+            Line::from(Span::styled(
+                line.to_string(),
+                Style::default().fg(theme.neutral),
+            ))
+        })
+        .collect();
+    if lines.is_empty() {
+        let placeholder = if state.pending {
+            "reading the transcript since the last note..."
+        } else if state.error.is_none() {
+            "nothing new since the last note"
+        } else {
+            ""
+        };
+        lines.push(Line::from(Span::styled(
+            placeholder,
+            Style::default().fg(theme.muted),
+        )));
+    }
+    if let Some(error) = &state.error {
+        lines.push(Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(theme.error),
+        )));
+    }
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).wrap(Wrap { trim: false }),
+        body_area,
+    );
+    frame.render_widget(
+        Paragraph::new("esc close")
+            .alignment(Alignment::Right)
+            .style(Style::default().fg(theme.muted)),
+        hint_area,
+    );
+}
+
+fn render_correct(frame: &mut ratatui::Frame, theme: &UiTheme, state: &CorrectState) {
+    let width = 72.min(frame.area().width.saturating_sub(4) as usize) as u16;
+    let height = 7.min(frame.area().height.saturating_sub(2));
+    let area = centered_rect(width, height, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(Block::default().borders(Borders::ALL), area);
+
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    let [title_area, input_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new("Correct Transcript Line")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.heading)),
+        title_area,
+    );
+    frame.render_widget(
+        Paragraph::new(format!("> {}▏", state.input)).wrap(Wrap { trim: false }),
+        input_area,
+    );
+    frame.render_widget(
+        Paragraph::new("enter save + learn  esc cancel")
+            .alignment(Alignment::Right)
+            .style(Style::default().fg(theme.muted)),
+        hint_area,
+    );
+}
+
+fn render_rename_speaker(frame: &mut ratatui::Frame, theme: &UiTheme, state: &RenameSpeakerState) {
+    render_line_prompt(
+        frame,
+        theme,
+        &format!("Rename {}", state.label),
+        &state.input,
+        "tab participants  enter save  esc cancel",
+    );
+}
+
+/// Centered one-line input box with a heading and a key hint.
+fn render_line_prompt(
+    frame: &mut ratatui::Frame,
+    theme: &UiTheme,
+    heading: &str,
+    input: &str,
+    hint: &str,
+) {
+    let width = 60.min(frame.area().width.saturating_sub(4) as usize) as u16;
+    let height = 6.min(frame.area().height.saturating_sub(2));
+    let area = centered_rect(width, height, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(Block::default().borders(Borders::ALL), area);
+
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    let [title_area, input_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new(heading.to_string())
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.heading)),
+        title_area,
+    );
+    frame.render_widget(
+        Paragraph::new(format!("> {input}▏")).wrap(Wrap { trim: false }),
+        input_area,
+    );
+    frame.render_widget(
+        Paragraph::new(hint.to_string())
+            .alignment(Alignment::Right)
+            .style(Style::default().fg(theme.muted)),
+        hint_area,
+    );
+}
+
+/// Self-test overlay: a progress line while running, the problems once it fails.
+fn render_health_check(frame: &mut ratatui::Frame, theme: &UiTheme, problems: Option<&[String]>) {
+    let width = 76.min(frame.area().width.saturating_sub(4) as usize) as u16;
+    let wrap_width = width.saturating_sub(2).max(1) as usize;
+    let (lines, rows) = match problems {
+        None => (
+            vec![Line::from(Span::styled(
+                "listening to each source and probing the transcriber...",
+                Style::default().fg(theme.muted),
+            ))],
+            1,
+        ),
+        Some(problems) => (
+            problems
+                .iter()
+                .map(|problem| {
+                    Line::from(Span::styled(
+                        format!("• {problem}"),
+                        Style::default().fg(theme.error),
+                    ))
+                })
+                .collect(),
+            problems
+                .iter()
+                .map(|problem| (problem.chars().count() + 2).div_ceil(wrap_width))
+                .sum::<usize>(),
+        ),
+    };
+    let height = (rows as u16 + 4).min(frame.area().height.saturating_sub(2));
+    let area = centered_rect(width, height, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(Block::default().borders(Borders::ALL), area);
+
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    let [title_area, body_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let (title, hint) = match problems {
+        None => ("Checking Audio", "esc cancel"),
+        Some(_) => ("Meeting Not Started", "r retry  s start anyway  esc cancel"),
+    };
+    frame.render_widget(
+        Paragraph::new(title)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.heading)),
+        title_area,
+    );
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).wrap(Wrap { trim: false }),
+        body_area,
+    );
+    frame.render_widget(
+        Paragraph::new(hint)
+            .alignment(Alignment::Right)
+            .style(Style::default().fg(theme.muted)),
+        hint_area,
+    );
+}
+
+fn render_meeting_detected(frame: &mut ratatui::Frame, theme: &UiTheme, app: &str) {
+    let width = 56.min(frame.area().width.saturating_sub(4) as usize) as u16;
+    let area = centered_rect(width, 5, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(Block::default().borders(Borders::ALL), area);
+
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    let [title_area, body_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+    frame.render_widget(
+        Paragraph::new("Meeting Detected")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.heading)),
+        title_area,
+    );
+    frame.render_widget(
+        Paragraph::new(format!("{app} looks like it is in a call"))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.neutral)),
+        body_area,
+    );
+    frame.render_widget(
+        Paragraph::new("enter start meeting  esc dismiss")
+            .alignment(Alignment::Right)
+            .style(Style::default().fg(theme.muted)),
+        hint_area,
+    );
+}
+
+fn render_vocabulary(
+    frame: &mut ratatui::Frame,
+    theme: &UiTheme,
+    vocabulary: &Vocabulary,
+    state: &VocabularyReviewState,
+) {
+    let width = 72.min(frame.area().width.saturating_sub(4) as usize) as u16;
+    let height = 18.min(frame.area().height.saturating_sub(2));
+    let area = centered_rect(width, height, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(Block::default().borders(Borders::ALL), area);
+
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    let [title_area, body_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new("Learned Vocabulary")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.heading)),
+        title_area,
+    );
+
+    let rows = body_area.height as usize;
+    let start = (state.selected + 1).saturating_sub(rows);
+    let mut lines = Vec::new();
+    if vocabulary.entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "nothing learned yet; fix lines with \"correct transcript line\"",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    for (index, entry) in vocabulary.entries.iter().enumerate().skip(start).take(rows) {
+        let status = if entry.is_learned() {
+            "learned"
+        } else {
+            "pending"
+        };
+        let mut spans = vec![
+            Span::styled(
+                format!("{} → {}", entry.from, entry.to),
+                Style::default().fg(theme.neutral),
+            ),
+            Span::styled(
+                format!(
+                    "  {} ×{} {status}",
+                    entry.speaker.as_deref().unwrap_or("anyone"),
+                    entry.count
+                ),
+                Style::default().fg(theme.muted),
+            ),
+        ];
+        if index == state.selected {
+            for span in &mut spans {
+                span.style = span.style.add_modifier(Modifier::REVERSED);
+            }
+        }
+        lines.push(Line::from(spans));
+    }
+    frame.render_widget(Paragraph::new(Text::from(lines)), body_area);
+
+    frame.render_widget(
+        Paragraph::new("↑↓ select  d remove  esc close")
+            .alignment(Alignment::Right)
+            .style(Style::default().fg(theme.muted)),
+        hint_area,
+    );
+}
+
+fn render_actions(frame: &mut ratatui::Frame, theme: &UiTheme, state: &ActionsViewState) {
+    let width = 88.min(frame.area().width.saturating_sub(4) as usize) as u16;
+    let height = 18.min(frame.area().height.saturating_sub(2));
+    let area = centered_rect(width, height, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(Block::default().borders(Borders::ALL), area);
+
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    let [title_area, body_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new("Open Action Items")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.heading)),
+        title_area,
+    );
+
+    let rows = body_area.height as usize;
+    let start = (state.selected + 1).saturating_sub(rows);
+    let mut lines = Vec::new();
+    if state.ids.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "no open action items",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    for (index, id) in state.ids.iter().enumerate().skip(start).take(rows) {
+        let Some(action) = state.store.actions.iter().find(|action| &action.id == id) else {
+            continue;
+        };
+        let done = action.status == ActionStatus::Done;
+        let mut detail = String::new();
+        if let Some(owner) = &action.owner {
+            detail.push_str(&format!("  {owner}"));
+        }
+        if let Some(due) = &action.due {
+            detail.push_str(&format!("  due {due}"));
+        }
+        detail.push_str(&format!("  {}", action.meeting));
+        let mut spans = vec![
+            Span::styled(
+                format!("[{}] {}", if done { "x" } else { " " }, action.text),
+                Style::default().fg(if done { theme.muted } else { theme.neutral }),
+            ),
+            Span::styled(detail, Style::default().fg(theme.muted)),
+        ];
+        if index == state.selected {
+            for span in &mut spans {
+                span.style = span.style.add_modifier(Modifier::REVERSED);
+            }
+        }
+        lines.push(Line::from(spans));
+    }
+    frame.render_widget(Paragraph::new(Text::from(lines)), body_area);
+
+    frame.render_widget(
+        Paragraph::new("↑↓ select  d/enter done  esc close")
+            .alignment(Alignment::Right)
+            .style(Style::default().fg(theme.muted)),
+        hint_area,
+    );
+}
+
+struct DiagnosticsState<'a> {
+    usage: Option<ResourceUsage>,
+    max_rss_mb: u32,
+    transcribe_lag_ms: Option<u128>,
+    stats: &'a CaptureStats,
+}
+
+/// Latest transcript lines for focus captions: older ones muted, the newest
+/// bold, then any interim text; segments are separated by a blank line.
+fn caption_lines(
+    segments: &[TranscriptSegment],
+    partials: &[TranscriptSegment],
+    theme: &UiTheme,
+) -> Vec<Line<'static>> {
+    let start = segments.len().saturating_sub(CAPTION_SEGMENTS);
+    let recent = &segments[start..];
+    let mut lines = Vec::new();
+    for (index, seg) in recent.iter().enumerate() {
+        let style = if index + 1 == recent.len() {
+            Style::default()
+                .fg(theme.neutral)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.muted)
+        };
+        if !lines.is_empty() {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from(Span::styled(seg.text.trim().to_string(), style)));
+    }
+    let partial_style = Style::default()
+        .fg(theme.muted)
+        .add_modifier(Modifier::ITALIC);
+    for seg in partials {
+        if !lines.is_empty() {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from(Span::styled(
+            seg.text.trim().to_string(),
+            partial_style,
+        )));
+    }
+    lines
+}
+
+/// Regions of the main layout the tour can spotlight.
+#[derive(Debug, Clone, Copy)]
+struct TourAreas {
+    title: Rect,
+    notes: Rect,
+    transcript: Rect,
+    footer: Rect,
+}
+
+/// Dim everything but the step's target and show the step card beside it.
+fn render_tour(frame: &mut ratatui::Frame, theme: &UiTheme, step: TourStep, areas: TourAreas) {
+    let screen = frame.area();
+    let (target, card_space) = match step.target() {
+        TourTarget::Screen => (None, screen),
+        TourTarget::TitleBar => (Some(areas.title), screen),
+        TourTarget::Notes => (Some(areas.notes), areas.transcript),
+        TourTarget::Transcript => (Some(areas.transcript), areas.notes),
+        TourTarget::Footer => (Some(areas.footer), screen),
+    };
+    let buffer = frame.buffer_mut();
+    buffer.set_style(screen, Style::default().add_modifier(Modifier::DIM));
+    if let Some(target) = target {
+        buffer.set_style(
+            target,
+            Style::default()
+                .remove_modifier(Modifier::DIM)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+
+    let body = step.body();
+    let width = 48.min(card_space.width.saturating_sub(2));
+    let height = 2 + 1 + 1 + body.len() as u16 + 1 + 1;
+    let area = centered_rect(width, height, card_space);
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent)),
+        area,
+    );
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            step.title(),
+            Style::default().fg(theme.heading),
+        )),
+        Line::from(""),
+    ];
+    lines.extend(body.iter().map(|line| Line::from(*line)));
+    lines.push(Line::from(""));
+    let hint = if step.next().is_some() {
+        "enter next · ← back · esc skip"
+    } else {
+        "enter done · ← back"
+    };
+    lines.push(Line::from(Span::styled(
+        format!("{}/{} · {hint}", step.index() + 1, TourStep::ALL.len()),
+        Style::default().fg(theme.muted),
+    )));
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Full-screen caption view: text wrapped with wide margins, anchored to the
+/// bottom so the newest line stays in the same place.
+fn render_captions(frame: &mut ratatui::Frame, theme: &UiTheme, lines: &[Line<'static>]) {
+    let area = frame.area();
+    frame.render_widget(Clear, area);
+    let [body_area, hint_area] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(area);
+    let margin = body_area.width / 10;
+    let text_area = Rect {
+        x: body_area.x + margin,
+        y: body_area.y + 1,
+        width: body_area.width.saturating_sub(margin * 2).max(1),
+        height: body_area.height.saturating_sub(2),
+    };
+    let width = text_area.width as usize;
+    let wrapped_rows: usize = lines
+        .iter()
+        .map(|line| line.width().div_ceil(width).max(1))
+        .sum();
+    let scroll = wrapped_rows.saturating_sub(text_area.height as usize) as u16;
+    let top = (text_area.height as usize).saturating_sub(wrapped_rows) as u16;
+    let text_area = Rect {
+        y: text_area.y + top,
+        height: text_area.height - top,
+        ..text_area
+    };
+    let body = if lines.is_empty() {
+        vec![Line::from(Span::styled(
+            "waiting for speech...",
+            Style::default().fg(theme.muted),
+        ))]
+    } else {
+        lines.to_vec()
+    };
+    frame.render_widget(
+        Paragraph::new(Text::from(body))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .scroll((scroll, 0)),
+        text_area,
+    );
+    frame.render_widget(
+        Paragraph::new("focus captions · esc exit")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.muted)),
+        hint_area,
+    );
+}
+
+fn render_diagnostics(frame: &mut ratatui::Frame, theme: &UiTheme, state: DiagnosticsState) {
+    let width = 48.min(frame.area().width.saturating_sub(4) as usize) as u16;
+    let height = 2 + 1 + 7;
+    let area = centered_rect(width, height, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(Block::default().borders(Borders::ALL), area);
+
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    let [title_area, body_area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(inner);
+
+    frame.render_widget(
+        Paragraph::new("Diagnostics")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.heading)),
+        title_area,
+    );
+
+    let (cpu, rss) = match state.usage {
+        Some(usage) => (
+            format!("{:.1}%", usage.cpu_percent),
+            format!("{} MB", usage.rss_mb),
+        ),
+        None => ("n/a".to_string(), "n/a".to_string()),
+    };
+    let limit = if state.max_rss_mb == 0 {
+        "none".to_string()
+    } else {
+        format!("{} MB", state.max_rss_mb)
+    };
+    let over_limit = state.max_rss_mb > 0
+        && state
+            .usage
+            .is_some_and(|usage| usage.rss_mb > state.max_rss_mb as u64);
+    let lag = state
+        .transcribe_lag_ms
+        .map(|ms| format!("{:.1}s", ms as f64 / 1000.0))
+        .unwrap_or_else(|| "n/a".to_string());
+    let rows = [
+        ("cpu", cpu, false),
+        ("rss", rss, over_limit),
+        ("rss limit", limit, false),
+        ("transcribe lag", lag, false),
+        (
+            "chunks emitted/dropped",
+            format!(
+                "{}/{}",
+                state.stats.chunks_emitted(),
+                state.stats.chunks_dropped()
+            ),
+            false,
+        ),
+        (
+            "raw frames dropped",
+            state.stats.raw_frames_dropped().to_string(),
+            false,
+        ),
+        (
+            "capture",
+            missing_sources_label(state.stats).unwrap_or_else(|| "ok".to_string()),
+            missing_sources_label(state.stats).is_some(),
+        ),
+    ];
+    let lines: Vec<Line<'static>> = rows
+        .into_iter()
+        .map(|(label, value, alert)| {
+            let value_style = if alert {
+                Style::default().fg(theme.error)
+            } else {
+                Style::default().fg(theme.neutral)
+            };
+            Line::from(vec![
+                Span::styled(format!("{label:<24}"), Style::default().fg(theme.muted)),
+                Span::styled(value, value_style),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(Text::from(lines)), body_area);
+}
+
+fn render_command_lines(
+    commands: Vec<(PaletteCommand, bool)>,
+    theme: &UiTheme,
+    width: usize,
+) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for (command, is_selected) in commands.into_iter() {
+        let label = command.label;
+        let category = command.category;
+        let available = width.saturating_sub(1);
+        let gap = available
+            .saturating_sub(label.len())
+            .saturating_sub(category.len())
+            .max(1);
+        let padding = " ".repeat(gap);
+        let spans = if is_selected {
+            let sel = Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::REVERSED);
+            vec![
+                Span::styled(label.to_string(), sel),
+                Span::styled(padding, sel),
+                Span::styled(category.to_string(), sel),
+            ]
+        } else {
+            vec![
+                Span::styled(label.to_string(), Style::default().fg(theme.neutral)),
+                Span::styled(padding, Style::default().fg(theme.neutral)),
+                Span::styled(category.to_string(), Style::default().fg(theme.muted)),
+            ]
+        };
+
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+fn render_scrolled_paragraph(frame: &mut ratatui::Frame, area: Rect, lines: &[Line<'static>]) {
+    let scroll = lines.len().saturating_sub(area.height as usize) as u16;
+    let padded = pad_lines(lines);
+    let paragraph = Paragraph::new(Text::from(padded))
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+/// Like `render_scrolled_paragraph`, but scrolls only as far as needed to keep `focus` visible.
+fn render_focused_paragraph(
+    frame: &mut ratatui::Frame,
+    area: Rect,
+    lines: &[Line<'static>],
+    focus: usize,
+) {
+    let scroll = (focus + 1).saturating_sub(area.height as usize) as u16;
+    let padded = pad_lines(lines);
+    let paragraph = Paragraph::new(Text::from(padded))
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+fn pad_lines(lines: &[Line<'static>]) -> Vec<Line<'static>> {
+    lines
+        .iter()
+        .cloned()
+        .map(|line| {
+            let mut spans = vec![Span::raw(" ")];
+            spans.extend(line.spans);
+            Line::from(spans)
         })
         .collect()
 }
 
-fn render_transcript_lines(ledger: &TranscriptLedger, theme: &UiTheme) -> Vec<Line<'static>> {
+fn render_transcript_lines(
+    ledger: &TranscriptLedger,
+    display: &TranscriptDisplay,
+    theme: &UiTheme,
+) -> Vec<Line<'static>> {
     const MAX_SEGMENTS: usize = 200;
     let segments = ledger.segments();
     let start = segments.len().saturating_sub(MAX_SEGMENTS);
@@ -1130,51 +4652,409 @@ fn render_transcript_lines(ledger: &TranscriptLedger, theme: &UiTheme) -> Vec<Li
         Style::default().fg(theme.heading),
     )));
 
+    let names = &display.speaker_names;
+    let mut chapter = None;
     for seg in &segments[start..] {
-        let mut spans = Vec::new();
-        if let Some(speaker) = seg.speaker.as_deref() {
-            let style = speaker_style(theme, speaker);
-            spans.push(Span::styled(format!("{speaker}: "), style));
+        lines.extend(chapter_heading(&display.chapters, seg, &mut chapter, theme));
+        let line = match display.revisions.get(&seg.id) {
+            Some(revision) => revised_transcript_line(seg, revision, names, theme),
+            None => transcript_line(seg, names, None, false, theme),
+        };
+        if display.is_low_confidence(seg) {
+            lines.push(low_confidence_line(line, seg, theme));
+        } else {
+            lines.push(line);
+        }
+        if let Some(translation) = display.translations.get(&seg.id) {
+            lines.push(Line::from(Span::styled(
+                format!("  ↳ {translation}"),
+                Style::default().fg(theme.muted),
+            )));
         }
-        spans.push(Span::styled(
-            seg.text.trim().to_string(),
-            Style::default().fg(theme.neutral),
-        ));
-        lines.push(Line::from(spans));
     }
 
-    if segments.is_empty() {
+    if segments.is_empty() && display.partials.is_empty() {
         lines.push(Line::from(Span::styled(
             "waiting for transcript...",
             Style::default().fg(theme.muted),
         )));
     }
+    append_partial_lines(&mut lines, &display.partials, theme);
 
     lines
 }
 
-fn render_notes_lines(notes: &MeetingNotes, theme: &UiTheme) -> Vec<Line<'static>> {
-    let mut lines = Vec::new();
-    lines.push(Line::from(Span::styled(
-        "Notes",
+/// Transcript line with the words changed by a recent replacement highlighted.
+fn revised_transcript_line(
+    seg: &TranscriptSegment,
+    revision: &SegmentRevision,
+    names: &BTreeMap<String, String>,
+    theme: &UiTheme,
+) -> Line<'static> {
+    let mut spans = Vec::new();
+    if let Some(speaker) = seg.speaker.as_deref() {
+        let style = speaker_style(theme, speaker);
+        spans.push(Span::styled(
+            format!("{}: ", speaker_name(names, speaker)),
+            style,
+        ));
+    }
+    let text_style = Style::default().fg(theme.neutral);
+    let changed_style = match revision.stage {
+        RevisionStage::Fresh => Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD),
+        RevisionStage::Fading => Style::default().fg(theme.accent),
+    };
+    for (index, word) in seg.text.split_whitespace().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(" ", text_style));
+        }
+        let style = if revision.changed.get(index).copied().unwrap_or(false) {
+            changed_style
+        } else {
+            text_style
+        };
+        spans.push(Span::styled(word.to_string(), style));
+    }
+    Line::from(spans)
+}
+
+/// Transcript pane while scrolled back or searching: a window of `rows` segments
+/// ending at the view's anchor, with search status in the heading.
+fn render_transcript_window(
+    segments: &[TranscriptSegment],
+    display: &TranscriptDisplay,
+    view: &TranscriptView,
+    editing_search: bool,
+    rows: usize,
+    theme: &UiTheme,
+) -> Vec<Line<'static>> {
+    let end = view.end(segments.len());
+    let start = end.saturating_sub(rows);
+    let mut heading = vec![Span::styled(
+        "Transcript",
         Style::default().fg(theme.heading),
-    )));
+    )];
+    let status = match &view.search {
+        Some(search) if editing_search => {
+            format!("  /{}▏  {} matches", search.query, search.matches.len())
+        }
+        Some(search) if search.matches.is_empty() => format!("  /{}  no matches", search.query),
+        Some(search) => format!(
+            "  /{}  {}/{}  n/N step · esc clear",
+            search.query,
+            search.current.min(search.matches.len() - 1) + 1,
+            search.matches.len()
+        ),
+        None if !view.evidence.is_empty() => "  note evidence · esc clear · f follow".to_string(),
+        None => "  paused · f follow · G bottom".to_string(),
+    };
+    heading.push(Span::styled(status, Style::default().fg(theme.muted)));
+    let mut lines = vec![Line::from(heading)];
 
-    if notes.bullets.is_empty() {
+    let query = view
+        .search
+        .as_ref()
+        .map(|search| search.query.as_str())
+        .filter(|query| !query.is_empty());
+    let current = view
+        .search
+        .as_ref()
+        .and_then(|search| search.matches.get(search.current).copied());
+    let mut chapter = None;
+    for (index, seg) in segments.iter().enumerate().take(end).skip(start) {
+        lines.extend(chapter_heading(&display.chapters, seg, &mut chapter, theme));
+        let mut line = transcript_line(
+            seg,
+            &display.speaker_names,
+            query,
+            current == Some(index),
+            theme,
+        );
+        if display.is_low_confidence(seg) {
+            line = low_confidence_line(line, seg, theme);
+        }
+        if view.evidence.contains(&seg.id) {
+            lines.push(line.patch_style(Style::default().add_modifier(Modifier::REVERSED)));
+        } else {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+/// Dim the text of an uncertain transcript line and mark it `(?)`, leaving the speaker label.
+fn low_confidence_line(
+    mut line: Line<'static>,
+    seg: &TranscriptSegment,
+    theme: &UiTheme,
+) -> Line<'static> {
+    let skip = usize::from(seg.speaker.is_some());
+    for span in line.spans.iter_mut().skip(skip) {
+        span.style = span.style.fg(theme.muted);
+    }
+    line.spans.push(Span::styled(
+        " (?)",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    ));
+    line
+}
+
+/// Section header for the chapter `seg` falls in, when it differs from `current`.
+fn chapter_heading(
+    chapters: &[Chapter],
+    seg: &TranscriptSegment,
+    current: &mut Option<usize>,
+    theme: &UiTheme,
+) -> Option<Line<'static>> {
+    if chapters.is_empty() {
+        return None;
+    }
+    let index = chapters
+        .partition_point(|chapter| chapter.start_ms <= seg.start_ms)
+        .saturating_sub(1);
+    if *current == Some(index) {
+        return None;
+    }
+    *current = Some(index);
+    let chapter = &chapters[index];
+    let start = Duration::from_millis(chapter.start_ms.max(0) as u64);
+    Some(Line::from(Span::styled(
+        format!("── {} · {}", chapter.title, format_duration(start)),
+        Style::default()
+            .fg(theme.heading)
+            .add_modifier(Modifier::BOLD),
+    )))
+}
+
+/// One transcript line, with `query` occurrences highlighted when searching.
+fn transcript_line(
+    seg: &TranscriptSegment,
+    names: &BTreeMap<String, String>,
+    query: Option<&str>,
+    is_current: bool,
+    theme: &UiTheme,
+) -> Line<'static> {
+    let mut spans = Vec::new();
+    if let Some(speaker) = seg.speaker.as_deref() {
+        let style = speaker_style(theme, speaker);
+        spans.push(Span::styled(
+            format!("{}: ", speaker_name(names, speaker)),
+            style,
+        ));
+    }
+    let text = seg.text.trim();
+    let text_style = Style::default().fg(theme.neutral);
+    let Some(query) = query else {
+        spans.push(Span::styled(text.to_string(), text_style));
+        return Line::from(spans);
+    };
+    let mut match_style = Style::default()
+        .fg(theme.accent)
+        .add_modifier(Modifier::REVERSED);
+    if is_current {
+        match_style = match_style.add_modifier(Modifier::BOLD);
+    }
+    let haystack = text.to_ascii_lowercase();
+    let needle = query.to_ascii_lowercase();
+    let mut cursor = 0;
+    while let Some(found) = haystack[cursor..].find(&needle) {
+        let begin = cursor + found;
+        let end = begin + needle.len();
+        if begin > cursor {
+            spans.push(Span::styled(text[cursor..begin].to_string(), text_style));
+        }
+        spans.push(Span::styled(text[begin..end].to_string(), match_style));
+        cursor = end;
+    }
+    if cursor < text.len() {
+        spans.push(Span::styled(text[cursor..].to_string(), text_style));
+    }
+    Line::from(spans)
+}
+
+/// Append interim segments after the transcript, dimmed so they read as provisional.
+fn append_partial_lines(
+    lines: &mut Vec<Line<'static>>,
+    partials: &[TranscriptSegment],
+    theme: &UiTheme,
+) {
+    let style = Style::default()
+        .fg(theme.muted)
+        .add_modifier(Modifier::ITALIC);
+    for seg in partials {
+        let mut spans = Vec::new();
+        if let Some(speaker) = seg.speaker.as_deref() {
+            spans.push(Span::styled(format!("{speaker}: "), style));
+        }
+        spans.push(Span::styled(seg.text.trim().to_string(), style));
+        lines.push(Line::from(spans));
+    }
+}
+
+/// Characters of the streaming notes draft shown after "thinking…".
+const DRAFT_PREVIEW_CHARS: usize = 48;
+
+/// Dimmed "thinking…" line with the tail of the notes draft, so the pane shows
+/// the summarizer working instead of sitting still.
+fn draft_preview_line(draft: &str, theme: &UiTheme) -> Line<'static> {
+    let style = Style::default()
+        .fg(theme.muted)
+        .add_modifier(Modifier::ITALIC);
+    let flat = draft.split_whitespace().collect::<Vec<_>>().join(" ");
+    let count = flat.chars().count();
+    let tail: String = if count > DRAFT_PREVIEW_CHARS {
+        let skipped: String = flat.chars().skip(count - DRAFT_PREVIEW_CHARS).collect();
+        format!("…{skipped}")
+    } else {
+        flat
+    };
+    let mut spans = vec![Span::styled("thinking…".to_string(), style)];
+    if !tail.is_empty() {
+        spans.push(Span::styled(format!(" {tail}"), style));
+    }
+    Line::from(spans)
+}
+
+fn render_notes_lines(
+    notes: &MeetingNotes,
+    archived: usize,
+    tab: NotesTab,
+    theme: &UiTheme,
+) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut header = vec![Span::styled("Notes", Style::default().fg(theme.heading))];
+    for (index, candidate) in NotesTab::ALL.into_iter().enumerate() {
+        let color = if candidate == tab {
+            theme.accent
+        } else {
+            theme.muted
+        };
+        header.push(Span::styled(
+            format!("  {} {}", index + 1, candidate.label()),
+            Style::default().fg(color),
+        ));
+    }
+    lines.push(Line::from(header));
+
+    if archived > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("{archived} earlier notes archived to session"),
+            Style::default().fg(theme.muted),
+        )));
+    }
+
+    let mut shown = notes
+        .bullets
+        .iter()
+        .filter(|bullet| tab.includes(bullet.kind))
+        .peekable();
+    if shown.peek().is_none() {
         lines.push(Line::from(Span::styled(
-            "waiting for notes...",
+            tab.placeholder(),
             Style::default().fg(theme.muted),
         )));
         return lines;
     }
 
-    for bullet in &notes.bullets {
-        lines.push(note_line(bullet.text.clone(), theme));
+    if tab != NotesTab::All {
+        for bullet in shown {
+            lines.push(note_line(bullet, theme));
+        }
+        return lines;
     }
 
+    for (kind, bullets) in notes.sections() {
+        lines.push(Line::from(Span::styled(
+            kind.section_title(),
+            Style::default()
+                .fg(theme.muted)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for bullet in bullets {
+            lines.push(note_line(bullet, theme));
+        }
+    }
     lines
 }
 
+/// Append lines for newly added bullets instead of re-rendering the whole
+/// notes pane; only for single-kind tabs, since `All` groups by section.
+fn append_note_lines(
+    lines: &mut Vec<Line<'static>>,
+    added: &[NoteBullet],
+    was_empty: bool,
+    tab: NotesTab,
+    theme: &UiTheme,
+) {
+    let mut shown = added
+        .iter()
+        .filter(|bullet| tab.includes(bullet.kind))
+        .peekable();
+    if shown.peek().is_none() {
+        return;
+    }
+    if was_empty {
+        // Drop the "waiting for notes..." placeholder.
+        lines.pop();
+    }
+    for bullet in shown {
+        lines.push(note_line(bullet, theme));
+    }
+}
+
+/// Notes pane filter, switched with 1-4 in normal mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum NotesTab {
+    #[default]
+    All,
+    Decisions,
+    Actions,
+    Questions,
+}
+
+impl NotesTab {
+    const ALL: [NotesTab; 4] = [
+        NotesTab::All,
+        NotesTab::Decisions,
+        NotesTab::Actions,
+        NotesTab::Questions,
+    ];
+
+    fn from_key(ch: char) -> Option<Self> {
+        let index = ch.to_digit(10)?.checked_sub(1)?;
+        Self::ALL.get(index as usize).copied()
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            NotesTab::All => "all",
+            NotesTab::Decisions => "decisions",
+            NotesTab::Actions => "actions",
+            NotesTab::Questions => "questions",
+        }
+    }
+
+    fn includes(self, kind: NoteKind) -> bool {
+        match self {
+            NotesTab::All => true,
+            NotesTab::Decisions => kind == NoteKind::Decision,
+            NotesTab::Actions => kind == NoteKind::Action,
+            NotesTab::Questions => kind == NoteKind::Question,
+        }
+    }
+
+    fn placeholder(self) -> String {
+        match self {
+            NotesTab::All => "waiting for notes...".to_string(),
+            tab => format!("no {} yet", tab.label()),
+        }
+    }
+}
+
 fn filtered_commands(phase: MeetingPhase, filter: &str) -> Vec<PaletteCommand> {
     let commands = commands_for_phase(phase);
     if filter.trim().is_empty() {
@@ -1186,25 +5066,142 @@ fn filtered_commands(phase: MeetingPhase, filter: &str) -> Vec<PaletteCommand> {
         .collect()
 }
 
+/// Prompt profile after `current`, wrapping around; built-ins come first.
+fn next_prompt_profile<'a>(profiles: &'a [String], current: &str) -> Option<&'a str> {
+    let next = profiles
+        .iter()
+        .position(|name| name == current)
+        .map_or(0, |index| index + 1);
+    profiles
+        .get(next % profiles.len().max(1))
+        .map(String::as_str)
+}
+
+/// Profile after `active` in config order, wrapping around.
+fn next_profile<'a>(
+    profiles: &'a [MeetingProfile],
+    active: Option<&str>,
+) -> Option<&'a MeetingProfile> {
+    let next = active
+        .and_then(|name| profiles.iter().position(|profile| profile.name == name))
+        .map_or(0, |index| index + 1);
+    profiles.get(next % profiles.len().max(1))
+}
+
 fn commands_for_phase(phase: MeetingPhase) -> Vec<PaletteCommand> {
     match phase {
         MeetingPhase::Idle => vec![
             PaletteCommand {
-                id: PaletteCommandId::StartMeeting,
-                label: "start meeting",
-                category: "meeting",
+                id: PaletteCommandId::StartMeeting,
+                label: "start meeting",
+                category: "meeting",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::NextProfile,
+                label: "switch meeting profile",
+                category: "meeting",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::NextPromptProfile,
+                label: "switch prompt template",
+                category: "notes",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ReviewVocabulary,
+                label: "review learned vocabulary",
+                category: "transcript",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::OpenActions,
+                label: "open action items",
+                category: "review",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::BrowseSessions,
+                label: "browse sessions",
+                category: "view",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ShowDiagnostics,
+                label: "show diagnostics",
+                category: "view",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ShowTour,
+                label: "help/tour",
+                category: "help",
+            },
+        ],
+        MeetingPhase::MeetingActive => vec![
+            PaletteCommand {
+                id: PaletteCommandId::EndMeeting,
+                label: "end meeting",
+                category: "meeting",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::TogglePause,
+                label: "pause/resume capture",
+                category: "meeting",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::SetTitle,
+                label: "set meeting title",
+                category: "meeting",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::TagMeeting,
+                label: "tag meeting",
+                category: "meeting",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::EditNotes,
+                label: "edit notes",
+                category: "notes",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::SummarizeInterval,
+                label: "notes update interval",
+                category: "notes",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ShowNoteEvidence,
+                label: "show note evidence",
+                category: "notes",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::Ask,
+                label: "ask the meeting",
+                category: "notes",
             },
             PaletteCommand {
-                id: PaletteCommandId::BrowseSessions,
-                label: "browse sessions",
-                category: "view",
+                id: PaletteCommandId::CatchUp,
+                label: "catch me up",
+                category: "notes",
             },
-        ],
-        MeetingPhase::MeetingActive => vec![
             PaletteCommand {
-                id: PaletteCommandId::EndMeeting,
-                label: "end meeting",
-                category: "meeting",
+                id: PaletteCommandId::CorrectTranscript,
+                label: "correct transcript line",
+                category: "transcript",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::RenameSpeaker,
+                label: "rename speaker",
+                category: "transcript",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ReviewVocabulary,
+                label: "review learned vocabulary",
+                category: "transcript",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::OpenActions,
+                label: "open action items",
+                category: "review",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::NextPromptProfile,
+                label: "switch prompt template",
+                category: "notes",
             },
             PaletteCommand {
                 id: PaletteCommandId::CopyTranscriptPath,
@@ -1221,13 +5218,93 @@ fn commands_for_phase(phase: MeetingPhase) -> Vec<PaletteCommand> {
                 label: "copy audio path",
                 category: "export",
             },
+            PaletteCommand {
+                id: PaletteCommandId::CopyNotesMarkdown,
+                label: "copy notes as markdown",
+                category: "export",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::CopyTranscriptSelection,
+                label: "copy transcript selection",
+                category: "export",
+            },
             PaletteCommand {
                 id: PaletteCommandId::OpenSessionFolder,
                 label: "open session folder",
                 category: "export",
             },
+            PaletteCommand {
+                id: PaletteCommandId::FocusCaptions,
+                label: "focus captions",
+                category: "view",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::CycleLayout,
+                label: "switch layout",
+                category: "view",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ShowDiagnostics,
+                label: "show diagnostics",
+                category: "view",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ShowTour,
+                label: "help/tour",
+                category: "help",
+            },
         ],
         MeetingPhase::PostMeeting => vec![
+            PaletteCommand {
+                id: PaletteCommandId::ReviewMeeting,
+                label: "review meeting",
+                category: "meeting",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::SetTitle,
+                label: "set meeting title",
+                category: "meeting",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::TagMeeting,
+                label: "tag meeting",
+                category: "meeting",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::EditNotes,
+                label: "edit notes",
+                category: "notes",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ShowNoteEvidence,
+                label: "show note evidence",
+                category: "notes",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::Ask,
+                label: "ask the meeting",
+                category: "notes",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::CorrectTranscript,
+                label: "correct transcript line",
+                category: "transcript",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::RenameSpeaker,
+                label: "rename speaker",
+                category: "transcript",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ReviewVocabulary,
+                label: "review learned vocabulary",
+                category: "transcript",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::OpenActions,
+                label: "open action items",
+                category: "review",
+            },
             PaletteCommand {
                 id: PaletteCommandId::CopyTranscriptPath,
                 label: "copy transcript path",
@@ -1243,16 +5320,71 @@ fn commands_for_phase(phase: MeetingPhase) -> Vec<PaletteCommand> {
                 label: "copy audio path",
                 category: "export",
             },
+            PaletteCommand {
+                id: PaletteCommandId::CopyNotesMarkdown,
+                label: "copy notes as markdown",
+                category: "export",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::CopyTranscriptSelection,
+                label: "copy transcript selection",
+                category: "export",
+            },
             PaletteCommand {
                 id: PaletteCommandId::OpenSessionFolder,
                 label: "open session folder",
                 category: "export",
             },
+            PaletteCommand {
+                id: PaletteCommandId::PlaySegmentSlow,
+                label: "play segment at 0.75x",
+                category: "review",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::PlaySegmentSlower,
+                label: "play segment at 0.5x",
+                category: "review",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::CopySegmentLink,
+                label: "copy link to this moment",
+                category: "review",
+            },
             PaletteCommand {
                 id: PaletteCommandId::ExportMarkdown,
                 label: "export markdown",
                 category: "export",
             },
+            PaletteCommand {
+                id: PaletteCommandId::ExportSubtitles,
+                label: "export subtitles",
+                category: "export",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ExportHtml,
+                label: "export html",
+                category: "export",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::PostToSlack,
+                label: "post minutes to slack",
+                category: "export",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::EmailMinutes,
+                label: "email minutes",
+                category: "export",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ExportActionItems,
+                label: "export action items",
+                category: "export",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ExportMultitrack,
+                label: "export multitrack",
+                category: "export",
+            },
             PaletteCommand {
                 id: PaletteCommandId::StartNewMeeting,
                 label: "start new meeting",
@@ -1263,124 +5395,760 @@ fn commands_for_phase(phase: MeetingPhase) -> Vec<PaletteCommand> {
                 label: "browse sessions",
                 category: "view",
             },
+            PaletteCommand {
+                id: PaletteCommandId::FocusCaptions,
+                label: "focus captions",
+                category: "view",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::CycleLayout,
+                label: "switch layout",
+                category: "view",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ShowDiagnostics,
+                label: "show diagnostics",
+                category: "view",
+            },
+            PaletteCommand {
+                id: PaletteCommandId::ShowTour,
+                label: "help/tour",
+                category: "help",
+            },
         ],
     }
 }
 
-fn limit_commands(
-    commands: &[PaletteCommand],
-    selected: usize,
-    max_rows: usize,
-) -> Vec<(PaletteCommand, bool)> {
-    if commands.is_empty() {
-        return Vec::new();
-    }
-    let mut selected = selected.min(commands.len().saturating_sub(1));
-    let start = if selected >= max_rows {
-        selected + 1 - max_rows
-    } else {
-        0
+fn limit_commands(
+    commands: &[PaletteCommand],
+    selected: usize,
+    max_rows: usize,
+) -> Vec<(PaletteCommand, bool)> {
+    if commands.is_empty() {
+        return Vec::new();
+    }
+    let mut selected = selected.min(commands.len().saturating_sub(1));
+    let start = if selected >= max_rows {
+        selected + 1 - max_rows
+    } else {
+        0
+    };
+    let end = (start + max_rows).min(commands.len());
+    selected -= start;
+
+    commands[start..end]
+        .iter()
+        .enumerate()
+        .map(|(idx, command)| (*command, idx == selected))
+        .collect()
+}
+
+fn fuzzy_match(needle: &str, haystack: &str) -> bool {
+    let needle = needle.to_lowercase();
+    let haystack = haystack.to_lowercase();
+    let mut chars = needle.chars();
+    let mut current = chars.next();
+
+    for ch in haystack.chars() {
+        if let Some(target) = current {
+            if ch == target {
+                current = chars.next();
+            }
+        } else {
+            return true;
+        }
+    }
+
+    current.is_none()
+}
+
+/// Monotonic nanoseconds since `base`, the time base of the UI meeting clock.
+fn clock_ns(base: Instant) -> i128 {
+    base.elapsed().as_nanos() as i128
+}
+
+fn meeting_elapsed_at(clock: &SessionClock, base: Instant) -> Duration {
+    Duration::from_nanos(clock.meeting_ns(clock_ns(base)) as u64)
+}
+
+fn format_duration(duration: Duration) -> String {
+    let total_secs = duration.as_secs();
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+enum ExportOutcome {
+    Completed,
+    Pending,
+}
+
+fn export_session_with_timeout(
+    mut session: SessionHandle,
+    segments: Vec<TranscriptSegment>,
+    notes: MeetingNotes,
+) -> Result<ExportOutcome, Box<dyn std::error::Error>> {
+    let (tx, rx) = channel();
+    thread::spawn(move || {
+        let result = session.export_on_exit(&segments, &notes);
+        let _ = tx.send(result);
+    });
+
+    let timeout = Duration::from_secs(10);
+    match rx.recv_timeout(timeout) {
+        Ok(Ok(())) => Ok(ExportOutcome::Completed),
+        Ok(Err(err)) => Err(Box::new(err)),
+        Err(RecvTimeoutError::Timeout) => Ok(ExportOutcome::Pending),
+        Err(RecvTimeoutError::Disconnected) => Err("export thread disconnected".into()),
+    }
+}
+
+/// Export, finalize, and hand the session to the end-of-meeting summarize passes.
+fn finish_meeting(
+    webhooks: &WebhookSender,
+    live: &LiveFeed,
+    summarize_cmd_tx: &Sender<SummarizeCommand>,
+    active_session: &mut SessionHandle,
+    segments: Vec<TranscriptSegment>,
+    notes: &mut MeetingNotes,
+    error_state: &mut Option<UiError>,
+) {
+    // Keep later reviews and writes on the same ISO dates the export uses.
+    active_session.normalize_dues(notes);
+    match export_session_with_timeout(active_session.clone(), segments.clone(), notes.clone()) {
+        Ok(ExportOutcome::Completed) => {}
+        Ok(ExportOutcome::Pending) => {
+            set_error(
+                error_state,
+                "export still running; continuing in background".to_string(),
+            );
+        }
+        Err(err) => {
+            set_error(error_state, format!("export failed: {err}"));
+        }
+    }
+    if let Err(err) = active_session.finalize() {
+        set_error(error_state, format!("session finalize failed: {err}"));
+    }
+    webhooks.send(WebhookEvent::meeting_end(active_session));
+    live.meeting_end();
+    let _ = summarize_cmd_tx.send(SummarizeCommand::Finalize {
+        session: Box::new(active_session.clone()),
+        segments,
+    });
+}
+
+/// Post the session's minutes off the UI thread; the result comes back as
+/// [`UiEvent::SlackPosted`].
+fn spawn_slack_post(
+    config: SlackConfig,
+    session_dir: PathBuf,
+    ui_tx: Sender<UiEvent>,
+) -> io::Result<()> {
+    thread::Builder::new()
+        .name("koe-slack".into())
+        .spawn(move || {
+            let error = slack::post_minutes(&config, &session_dir)
+                .err()
+                .map(|err| err.to_string());
+            let _ = ui_tx.send(UiEvent::SlackPosted { error });
+        })
+        .map(|_| ())
+}
+
+/// Send reviewed notes to the export targets picked in the review.
+fn deliver_review_exports(
+    config: &ReviewConfig,
+    slack: &SlackConfig,
+    active_session: &SessionHandle,
+    notes: &MeetingNotes,
+    targets: &[ExportTarget],
+    error_state: &mut Option<UiError>,
+) {
+    if targets.is_empty() {
+        return;
+    }
+    let markdown = match active_session.notes_markdown(notes) {
+        Ok(markdown) => markdown,
+        Err(err) => {
+            set_error(error_state, format!("review export failed: {err}"));
+            return;
+        }
+    };
+    let session_id = active_session.metadata().id.as_str();
+    for target in targets {
+        if let Err(err) = export_targets::deliver(*target, config, slack, session_id, &markdown) {
+            set_error(
+                error_state,
+                format!("export to {} failed: {err}", target.label()),
+            );
+        }
+    }
+}
+
+fn note_line(bullet: &NoteBullet, theme: &UiTheme) -> Line<'static> {
+    let (marker, style) = if bullet.done {
+        ("✓", Style::default().fg(theme.muted))
+    } else {
+        ("·", Style::default().fg(theme.neutral))
+    };
+    let mut spans = vec![Span::styled(format!("{marker} {}", bullet.text), style)];
+    if let Some(detail) = note_detail(bullet) {
+        spans.push(Span::styled(
+            format!(" ({detail})"),
+            Style::default().fg(theme.accent),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Owner and due date suffix, e.g. `Sam, due Friday`.
+fn note_detail(bullet: &NoteBullet) -> Option<String> {
+    match (bullet.owner.as_deref(), bullet.due.as_deref()) {
+        (Some(owner), Some(due)) => Some(format!("{owner}, due {due}")),
+        (Some(owner), None) => Some(owner.to_string()),
+        (None, Some(due)) => Some(format!("due {due}")),
+        (None, None) => None,
+    }
+}
+
+/// Selected bullet with an inline edit buffer for `edit.field`.
+fn note_edit_line(
+    marker: &str,
+    bullet: &NoteBullet,
+    edit: &NoteEdit,
+    theme: &UiTheme,
+) -> Line<'static> {
+    let highlight = Style::default()
+        .fg(theme.accent)
+        .add_modifier(Modifier::REVERSED);
+    let label = match edit.field {
+        NoteEditField::Text => {
+            return Line::from(Span::styled(
+                format!("{marker} {}▏", edit.buffer),
+                highlight,
+            ));
+        }
+        NoteEditField::Owner => "owner",
+        NoteEditField::Due => "due",
+    };
+    Line::from(vec![
+        Span::styled(
+            format!("{marker} {} ", bullet.text),
+            Style::default().fg(theme.neutral),
+        ),
+        Span::styled(format!("{label}: {}▏", edit.buffer), highlight),
+    ])
+}
+
+/// Notes pane while focused: hint header, selected bullet highlighted, active edit inline.
+fn render_notes_focus_lines(
+    notes: &MeetingNotes,
+    state: &NotesFocusState,
+    hint: &str,
+    theme: &UiTheme,
+) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(
+        hint.to_string(),
+        Style::default().fg(theme.heading),
+    ))];
+    if notes.bullets.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "no notes yet",
+            Style::default().fg(theme.muted),
+        )));
+        return lines;
+    }
+    let selected = state.selected.min(notes.bullets.len() - 1);
+    for (index, bullet) in notes.bullets.iter().enumerate() {
+        if index != selected {
+            lines.push(note_line(bullet, theme));
+            continue;
+        }
+        let highlight = Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::REVERSED);
+        let line = match &state.edit {
+            Some(edit) => note_edit_line("·", bullet, edit, theme),
+            None => {
+                let spans = note_line(bullet, theme)
+                    .spans
+                    .into_iter()
+                    .map(|span| span.style(highlight))
+                    .collect::<Vec<_>>();
+                Line::from(spans)
+            }
+        };
+        lines.push(line);
+    }
+    lines
+}
+
+/// Move the note selection; Enter asks to jump to the selected note's evidence.
+fn handle_notes_evidence_key(
+    state: &mut NotesFocusState,
+    key: KeyEvent,
+    len: usize,
+) -> NotesEvidenceOutcome {
+    match key.code {
+        KeyCode::Esc => return NotesEvidenceOutcome::Exit,
+        KeyCode::Up => state.selected = state.selected.saturating_sub(1),
+        KeyCode::Down => state.selected += 1,
+        KeyCode::Enter if len > 0 => {
+            state.selected = state.selected.min(len - 1);
+            return NotesEvidenceOutcome::Jump(state.selected);
+        }
+        _ => {}
+    }
+    state.selected = state.selected.min(len.saturating_sub(1));
+    NotesEvidenceOutcome::Stay
+}
+
+/// Apply a key press in notes focus; edits are committed to `notes` on Enter.
+fn handle_notes_focus_key(
+    state: &mut NotesFocusState,
+    key: KeyEvent,
+    notes: &mut MeetingNotes,
+) -> NotesFocusOutcome {
+    if notes.bullets.is_empty() {
+        state.edit = None;
+        return if key.code == KeyCode::Esc {
+            NotesFocusOutcome::Exit
+        } else {
+            NotesFocusOutcome::Unchanged
+        };
+    }
+    state.selected = state.selected.min(notes.bullets.len() - 1);
+
+    if state.edit.is_some() {
+        return if handle_note_edit_key(&mut state.edit, key, &mut notes.bullets[state.selected]) {
+            NotesFocusOutcome::Changed
+        } else {
+            NotesFocusOutcome::Unchanged
+        };
+    }
+
+    let bullet = &mut notes.bullets[state.selected];
+    match key.code {
+        KeyCode::Esc => return NotesFocusOutcome::Exit,
+        KeyCode::Up => state.selected = state.selected.saturating_sub(1),
+        KeyCode::Down => state.selected += 1,
+        KeyCode::Enter | KeyCode::Char('e') => {
+            state.edit = Some(NoteEdit {
+                field: NoteEditField::Text,
+                buffer: bullet.text.clone(),
+            });
+        }
+        KeyCode::Char('o') => {
+            state.edit = Some(NoteEdit {
+                field: NoteEditField::Owner,
+                buffer: bullet.owner.clone().unwrap_or_default(),
+            });
+        }
+        KeyCode::Char('x') | KeyCode::Char(' ') => {
+            bullet.done = !bullet.done;
+            return NotesFocusOutcome::Changed;
+        }
+        KeyCode::Char('d') | KeyCode::Delete => {
+            notes.bullets.remove(state.selected);
+            state.selected = state.selected.min(notes.bullets.len().saturating_sub(1));
+            return NotesFocusOutcome::Changed;
+        }
+        _ => {}
+    }
+    state.selected = state.selected.min(notes.bullets.len() - 1);
+    NotesFocusOutcome::Unchanged
+}
+
+/// Feed a key to the in-progress edit; returns true when Enter changed `bullet`.
+fn handle_note_edit_key(
+    slot: &mut Option<NoteEdit>,
+    key: KeyEvent,
+    bullet: &mut NoteBullet,
+) -> bool {
+    let Some(edit) = slot.as_mut() else {
+        return false;
     };
-    let end = (start + max_rows).min(commands.len());
-    selected -= start;
+    match key.code {
+        KeyCode::Esc => *slot = None,
+        KeyCode::Backspace => {
+            edit.buffer.pop();
+        }
+        KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            edit.buffer.push(ch);
+        }
+        KeyCode::Enter => {
+            let value = edit.buffer.trim().to_string();
+            let field = edit.field;
+            *slot = None;
+            let optional = (!value.is_empty()).then(|| value.clone());
+            match field {
+                NoteEditField::Text if value.is_empty() || value == bullet.text => {}
+                NoteEditField::Text => {
+                    bullet.text = value;
+                    return true;
+                }
+                NoteEditField::Owner if optional != bullet.owner => {
+                    bullet.owner = optional;
+                    return true;
+                }
+                NoteEditField::Due if optional != bullet.due => {
+                    bullet.due = optional;
+                    return true;
+                }
+                NoteEditField::Owner | NoteEditField::Due => {}
+            }
+        }
+        _ => {}
+    }
+    false
+}
 
-    commands[start..end]
-        .iter()
-        .enumerate()
-        .map(|(idx, command)| (*command, idx == selected))
-        .collect()
+/// Edit or submit the question; input is ignored while an answer is streaming.
+fn handle_correct_key(state: &mut CorrectState, key: KeyEvent) -> CorrectOutcome {
+    handle_line_input(&mut state.input, key)
 }
 
-fn fuzzy_match(needle: &str, haystack: &str) -> bool {
-    let needle = needle.to_lowercase();
-    let haystack = haystack.to_lowercase();
-    let mut chars = needle.chars();
-    let mut current = chars.next();
+/// Single-line text entry submitting the trimmed, non-empty input.
+/// Current tags as the tag prompt's starting text.
+fn tags_input(session: &SessionHandle) -> String {
+    session.metadata().tags.join(", ")
+}
 
-    for ch in haystack.chars() {
-        if let Some(target) = current {
-            if ch == target {
-                current = chars.next();
+fn handle_line_input(input: &mut String, key: KeyEvent) -> CorrectOutcome {
+    match key.code {
+        KeyCode::Esc => CorrectOutcome::Exit,
+        KeyCode::Enter => {
+            let text = input.trim().to_string();
+            if text.is_empty() {
+                CorrectOutcome::Stay
+            } else {
+                CorrectOutcome::Submit(text)
             }
-        } else {
-            return true;
         }
+        KeyCode::Backspace => {
+            input.pop();
+            CorrectOutcome::Stay
+        }
+        KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            input.push(ch);
+            CorrectOutcome::Stay
+        }
+        _ => CorrectOutcome::Stay,
     }
-
-    current.is_none()
 }
 
-fn format_duration(duration: Duration) -> String {
-    let total_secs = duration.as_secs();
-    let hours = total_secs / 3600;
-    let minutes = (total_secs % 3600) / 60;
-    let seconds = total_secs % 60;
-    if hours > 0 {
-        format!("{hours}:{minutes:02}:{seconds:02}")
-    } else {
-        format!("{minutes:02}:{seconds:02}")
+fn handle_rename_speaker_key(
+    state: &mut RenameSpeakerState,
+    key: KeyEvent,
+    participants: &[String],
+) -> CorrectOutcome {
+    match key.code {
+        KeyCode::Tab if !participants.is_empty() => {
+            state.input = participants[state.suggestion % participants.len()].clone();
+            state.suggestion += 1;
+            CorrectOutcome::Stay
+        }
+        _ => handle_line_input(&mut state.input, key),
     }
 }
 
-enum ExportOutcome {
-    Completed,
-    Pending,
+/// Ask the transcriber for a probe answer and start watching capture.
+fn begin_health_check(
+    transcribe_cmd_tx: &Sender<TranscribeCommand>,
+    stats: &CaptureStats,
+    sources: &[AudioSource],
+) -> HealthCheck {
+    let (probe_tx, probe_rx) = channel();
+    let _ = transcribe_cmd_tx.send(TranscribeCommand::Probe(probe_tx));
+    HealthCheck::start(stats, sources, probe_rx, Instant::now())
 }
 
-fn export_session_with_timeout(
-    mut session: SessionHandle,
-    segments: Vec<TranscriptSegment>,
-    notes: MeetingNotes,
-) -> Result<ExportOutcome, Box<dyn std::error::Error>> {
-    let (tx, rx) = channel();
-    thread::spawn(move || {
-        let result = session.export_on_exit(&segments, &notes);
-        let _ = tx.send(result);
-    });
+fn handle_actions_key(state: &mut ActionsViewState, key: KeyEvent) -> ActionsOutcome {
+    let len = state.ids.len();
+    match key.code {
+        KeyCode::Esc => ActionsOutcome::Exit,
+        KeyCode::Up => {
+            state.selected = state.selected.saturating_sub(1);
+            ActionsOutcome::Stay
+        }
+        KeyCode::Down => {
+            state.selected = (state.selected + 1).min(len.saturating_sub(1));
+            ActionsOutcome::Stay
+        }
+        KeyCode::Char('d') | KeyCode::Enter if state.selected < len => {
+            ActionsOutcome::Done(state.selected)
+        }
+        _ => ActionsOutcome::Stay,
+    }
+}
 
-    let timeout = Duration::from_secs(10);
-    match rx.recv_timeout(timeout) {
-        Ok(Ok(())) => Ok(ExportOutcome::Completed),
-        Ok(Err(err)) => Err(Box::new(err)),
-        Err(RecvTimeoutError::Timeout) => Ok(ExportOutcome::Pending),
-        Err(RecvTimeoutError::Disconnected) => Err("export thread disconnected".into()),
+fn handle_vocabulary_key(
+    state: &mut VocabularyReviewState,
+    key: KeyEvent,
+    len: usize,
+) -> VocabularyOutcome {
+    match key.code {
+        KeyCode::Esc => VocabularyOutcome::Exit,
+        KeyCode::Up => {
+            state.selected = state.selected.saturating_sub(1);
+            VocabularyOutcome::Stay
+        }
+        KeyCode::Down => {
+            state.selected = (state.selected + 1).min(len.saturating_sub(1));
+            VocabularyOutcome::Stay
+        }
+        KeyCode::Char('d') | KeyCode::Delete if state.selected < len => {
+            VocabularyOutcome::Remove(state.selected)
+        }
+        _ => VocabularyOutcome::Stay,
     }
 }
 
-fn apply_notes_patch(notes: &mut MeetingNotes, patch: NotesPatch) -> bool {
-    let mut changed = false;
+fn handle_ask_key(state: &mut AskState, key: KeyEvent) -> AskOutcome {
+    match key.code {
+        KeyCode::Esc => AskOutcome::Exit,
+        _ if state.pending => AskOutcome::Stay,
+        KeyCode::Enter => {
+            let question = state.input.trim().to_string();
+            if question.is_empty() {
+                return AskOutcome::Stay;
+            }
+            state.input.clear();
+            state.question = question.clone();
+            state.answer.clear();
+            state.error = None;
+            state.pending = true;
+            AskOutcome::Submit(question)
+        }
+        KeyCode::Backspace => {
+            state.input.pop();
+            AskOutcome::Stay
+        }
+        KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.input.push(ch);
+            AskOutcome::Stay
+        }
+        _ => AskOutcome::Stay,
+    }
+}
 
-    for op in patch.ops {
-        match op {
-            NotesOp::Add { id, text, evidence } => {
-                if notes
-                    .bullets
-                    .iter()
-                    .any(|bullet| bullet.id == id || bullet.text == text)
-                {
-                    continue;
+/// Advance, rewind, or dismiss the tour; finishing the last step exits.
+fn handle_tour_key(step: &mut TourStep, key: KeyEvent) -> TourOutcome {
+    match key.code {
+        KeyCode::Enter | KeyCode::Right | KeyCode::Tab | KeyCode::Char(' ') => match step.next() {
+            Some(next) => {
+                *step = next;
+                TourOutcome::Stay
+            }
+            None => TourOutcome::Exit,
+        },
+        KeyCode::Left | KeyCode::BackTab => {
+            *step = step.prev();
+            TourOutcome::Stay
+        }
+        KeyCode::Esc | KeyCode::Char('q') => TourOutcome::Exit,
+        _ => TourOutcome::Stay,
+    }
+}
+
+/// Apply a key press in the post-meeting review.
+fn handle_review_key(
+    state: &mut ReviewState,
+    key: KeyEvent,
+    notes: &mut MeetingNotes,
+) -> ReviewOutcome {
+    if state.step == ReviewStep::Targets {
+        match key.code {
+            KeyCode::Esc => state.step = ReviewStep::Notes,
+            KeyCode::Up => state.target_selected = state.target_selected.saturating_sub(1),
+            KeyCode::Down => {
+                state.target_selected =
+                    (state.target_selected + 1).min(state.targets.len().saturating_sub(1));
+            }
+            KeyCode::Char(' ') | KeyCode::Char('x') => {
+                if let Some((_, enabled)) = state.targets.get_mut(state.target_selected) {
+                    *enabled = !*enabled;
                 }
-                notes.bullets.push(NoteBullet { id, text, evidence });
-                changed = true;
             }
+            KeyCode::Enter => return ReviewOutcome::Finalize,
+            _ => {}
         }
+        return ReviewOutcome::Unchanged;
     }
 
-    changed
+    if notes.bullets.is_empty() {
+        state.notes.edit = None;
+        return match key.code {
+            KeyCode::Esc => ReviewOutcome::Exit,
+            KeyCode::Enter => {
+                state.step = ReviewStep::Targets;
+                ReviewOutcome::Unchanged
+            }
+            _ => ReviewOutcome::Unchanged,
+        };
+    }
+    let selected = state.notes.selected.min(notes.bullets.len() - 1);
+    state.notes.selected = selected;
+
+    if state.notes.edit.is_some() {
+        return if handle_note_edit_key(&mut state.notes.edit, key, &mut notes.bullets[selected]) {
+            ReviewOutcome::Changed
+        } else {
+            ReviewOutcome::Unchanged
+        };
+    }
+
+    let bullet = &notes.bullets[selected];
+    match key.code {
+        KeyCode::Esc => return ReviewOutcome::Exit,
+        KeyCode::Up => state.notes.selected = selected.saturating_sub(1),
+        KeyCode::Down => state.notes.selected = (selected + 1).min(notes.bullets.len() - 1),
+        KeyCode::Char('a') => {
+            state
+                .verdicts
+                .insert(bullet.id.clone(), NoteVerdict::Accepted);
+            state.notes.selected = (selected + 1).min(notes.bullets.len() - 1);
+        }
+        KeyCode::Char('r') => {
+            state
+                .verdicts
+                .insert(bullet.id.clone(), NoteVerdict::Rejected);
+            state.notes.selected = (selected + 1).min(notes.bullets.len() - 1);
+        }
+        KeyCode::Char('A') => {
+            for bullet in &notes.bullets {
+                state
+                    .verdicts
+                    .entry(bullet.id.clone())
+                    .or_insert(NoteVerdict::Accepted);
+            }
+        }
+        KeyCode::Char('o') => {
+            state.notes.edit = Some(NoteEdit {
+                field: NoteEditField::Owner,
+                buffer: bullet.owner.clone().unwrap_or_default(),
+            });
+        }
+        KeyCode::Char('u') => {
+            state.notes.edit = Some(NoteEdit {
+                field: NoteEditField::Due,
+                buffer: bullet.due.clone().unwrap_or_default(),
+            });
+        }
+        KeyCode::Enter => state.step = ReviewStep::Targets,
+        _ => {}
+    }
+    ReviewOutcome::Unchanged
+}
+
+/// Review screen lines for the notes pane plus the line index to keep in view.
+fn render_review_lines(
+    notes: &MeetingNotes,
+    state: &ReviewState,
+    theme: &UiTheme,
+) -> (Vec<Line<'static>>, usize) {
+    let heading = Style::default().fg(theme.heading);
+    let highlight = Style::default()
+        .fg(theme.accent)
+        .add_modifier(Modifier::REVERSED);
+
+    if state.step == ReviewStep::Targets {
+        let rejected = notes
+            .bullets
+            .iter()
+            .filter(|bullet| state.verdict(bullet) == Some(NoteVerdict::Rejected))
+            .count();
+        let mut lines = vec![
+            Line::from(Span::styled(
+                "Export targets  ↑/↓ select · space toggle · enter finalize · esc back",
+                heading,
+            )),
+            Line::from(Span::styled(
+                format!(
+                    "{} notes kept · {rejected} rejected",
+                    notes.bullets.len() - rejected
+                ),
+                Style::default().fg(theme.muted),
+            )),
+        ];
+        for (index, (target, enabled)) in state.targets.iter().enumerate() {
+            let mark = if *enabled { "x" } else { " " };
+            let text = format!("[{mark}] {}", target.label());
+            let style = if index == state.target_selected {
+                highlight
+            } else {
+                Style::default().fg(theme.neutral)
+            };
+            lines.push(Line::from(Span::styled(text, style)));
+        }
+        return (lines, state.target_selected + 2);
+    }
+
+    let hint = if state.notes.edit.is_some() {
+        "Review  enter save · esc cancel"
+    } else {
+        "Review  a accept · r reject · A accept rest · o owner · u due · enter next · esc later"
+    };
+    let mut lines = vec![Line::from(Span::styled(hint, heading))];
+    if notes.bullets.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "no notes to review",
+            Style::default().fg(theme.muted),
+        )));
+        return (lines, 0);
+    }
+    let selected = state.notes.selected.min(notes.bullets.len() - 1);
+    for (index, bullet) in notes.bullets.iter().enumerate() {
+        let (marker, style) = match state.verdict(bullet) {
+            Some(NoteVerdict::Accepted) => ("✓", Style::default().fg(theme.neutral)),
+            Some(NoteVerdict::Rejected) => (
+                "✗",
+                Style::default()
+                    .fg(theme.muted)
+                    .add_modifier(Modifier::CROSSED_OUT),
+            ),
+            None => ("?", Style::default().fg(theme.heading)),
+        };
+        if index == selected
+            && let Some(edit) = &state.notes.edit
+        {
+            lines.push(note_edit_line(marker, bullet, edit, theme));
+            continue;
+        }
+        let mut spans = vec![Span::styled(format!("{marker} {}", bullet.text), style)];
+        if let Some(detail) = note_detail(bullet) {
+            spans.push(Span::styled(
+                format!(" ({detail})"),
+                Style::default().fg(theme.accent),
+            ));
+        }
+        if index == selected {
+            spans = spans
+                .into_iter()
+                .map(|span| span.style(highlight))
+                .collect();
+        }
+        lines.push(Line::from(spans));
+    }
+    (lines, selected + 1)
 }
 
-fn note_line(text: String, theme: &UiTheme) -> Line<'static> {
-    let bullet = "·";
-    Line::from(Span::styled(
-        format!("{bullet} {text}"),
-        Style::default().fg(theme.neutral),
-    ))
+/// Name given to `speaker` with "rename speaker", else the label itself.
+fn speaker_name<'a>(names: &'a BTreeMap<String, String>, speaker: &'a str) -> &'a str {
+    names.get(speaker).map_or(speaker, String::as_str)
 }
 
 fn speaker_style(theme: &UiTheme, speaker: &str) -> Style {
     match speaker {
         "Me" => Style::default().fg(theme.me),
         "Them" => Style::default().fg(theme.them),
+        label if label.starts_with("Speaker ") => Style::default().fg(theme.them),
         _ => Style::default().fg(theme.muted),
     }
 }
@@ -1407,15 +6175,54 @@ fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
 }
 
 fn copy_to_clipboard(path: &Path) -> io::Result<()> {
-    let output = path.to_string_lossy().to_string();
-    let mut child = Command::new("pbcopy")
-        .stdin(std::process::Stdio::piped())
-        .spawn()?;
-    if let Some(stdin) = child.stdin.as_mut() {
-        stdin.write_all(output.as_bytes())?;
-    }
-    let _ = child.wait();
-    Ok(())
+    clipboard::copy(&path.to_string_lossy())
+}
+
+/// `[mm:ss] Speaker: text` lines for the highlighted note evidence, else the
+/// selected transcript line.
+fn transcript_selection_text(
+    segments: &[TranscriptSegment],
+    view: &TranscriptView,
+    names: &BTreeMap<String, String>,
+) -> Option<String> {
+    let selected: Vec<&TranscriptSegment> = if view.evidence.is_empty() {
+        view.selected(segments.len())
+            .and_then(|index| segments.get(index))
+            .into_iter()
+            .collect()
+    } else {
+        segments
+            .iter()
+            .filter(|seg| view.evidence.contains(&seg.id))
+            .collect()
+    };
+    if selected.is_empty() {
+        return None;
+    }
+    let lines: Vec<String> = selected
+        .into_iter()
+        .map(|seg| {
+            let stamp = format_duration(Duration::from_millis(seg.start_ms.max(0) as u64));
+            match seg.speaker.as_deref() {
+                Some(speaker) => format!(
+                    "[{stamp}] {}: {}",
+                    speaker_name(names, speaker),
+                    seg.text.trim()
+                ),
+                None => format!("[{stamp}] {}", seg.text.trim()),
+            }
+        })
+        .collect();
+    Some(lines.join("\n"))
+}
+
+/// Start playing `path` in the background; the caller kills it to stop early.
+fn play_audio(path: &Path) -> io::Result<std::process::Child> {
+    Command::new("afplay")
+        .arg(path)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
 }
 
 fn open_path(path: &Path) -> io::Result<()> {
@@ -1429,52 +6236,522 @@ fn open_path(path: &Path) -> io::Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use super::apply_notes_patch;
-    use koe_core::types::{MeetingNotes, NotesOp, NotesPatch};
+    use super::{
+        AskOutcome, AskState, KeyBinding, NotesEvidenceOutcome, NotesFocusOutcome, NotesFocusState,
+        NotesTab, PaneLayout, PaneView, RedrawGate, ReviewOutcome, ReviewState, TourOutcome,
+        TourStep, TranscriptDisplay, TranscriptSearch, TranscriptView, UiTheme, append_note_lines,
+        caption_lines, draft_preview_line, handle_ask_key, handle_notes_evidence_key,
+        handle_notes_focus_key, handle_review_key, handle_tour_key, render_notes_lines,
+        render_transcript_window, transcript_line, transcript_selection_text,
+    };
+    use crate::config::{ThemePalette, UiConfig};
+    use crate::export_targets::ExportTarget;
+    use crate::test_support::bullet;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use koe_core::types::{MeetingNotes, NoteKind, NotesOp, NotesPatch, TranscriptSegment};
+    use ratatui::layout::Rect;
+    use ratatui::style::{Color, Modifier};
+    use ratatui::text::Line;
+    use std::collections::BTreeMap;
+    use std::time::{Duration, Instant};
 
     #[test]
-    fn apply_notes_patch_appends_bullets() {
-        let mut notes = MeetingNotes::default();
-        let patch = NotesPatch {
-            ops: vec![NotesOp::Add {
-                id: "n1".to_string(),
-                text: "first".to_string(),
-                evidence: vec![1],
-            }],
-        };
+    fn ask_submits_trimmed_question_and_locks_while_pending() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        let mut state = AskState::default();
+        assert_eq!(
+            handle_ask_key(&mut state, key(KeyCode::Enter)),
+            AskOutcome::Stay
+        );
+        for ch in " deadline? ".chars() {
+            handle_ask_key(&mut state, key(KeyCode::Char(ch)));
+        }
+        assert_eq!(
+            handle_ask_key(&mut state, key(KeyCode::Enter)),
+            AskOutcome::Submit("deadline?".to_string())
+        );
+        assert!(state.pending);
+        assert!(state.input.is_empty());
+        assert_eq!(state.question, "deadline?");
 
-        assert!(apply_notes_patch(&mut notes, patch));
-        assert_eq!(notes.bullets.len(), 1);
-        assert_eq!(notes.bullets[0].text, "first");
+        handle_ask_key(&mut state, key(KeyCode::Char('x')));
+        assert!(state.input.is_empty());
+        assert_eq!(
+            handle_ask_key(&mut state, key(KeyCode::Esc)),
+            AskOutcome::Exit
+        );
     }
 
     #[test]
-    fn apply_notes_patch_dedupes_by_id_or_text() {
-        let mut notes = MeetingNotes::default();
-        let patch = NotesPatch {
-            ops: vec![NotesOp::Add {
-                id: "n1".to_string(),
-                text: "first".to_string(),
-                evidence: vec![1],
-            }],
+    fn tour_steps_forward_back_and_exit() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        let mut step = TourStep::Welcome;
+        assert_eq!(
+            handle_tour_key(&mut step, key(KeyCode::Left)),
+            TourOutcome::Stay
+        );
+        assert_eq!(step, TourStep::Welcome);
+
+        for _ in 1..TourStep::ALL.len() {
+            assert_eq!(
+                handle_tour_key(&mut step, key(KeyCode::Enter)),
+                TourOutcome::Stay
+            );
+        }
+        assert_eq!(step, TourStep::Keys);
+        handle_tour_key(&mut step, key(KeyCode::Left));
+        assert_eq!(step, TourStep::Footer);
+        handle_tour_key(&mut step, key(KeyCode::Right));
+        assert_eq!(
+            handle_tour_key(&mut step, key(KeyCode::Enter)),
+            TourOutcome::Exit
+        );
+
+        let mut step = TourStep::Notes;
+        assert_eq!(
+            handle_tour_key(&mut step, key(KeyCode::Esc)),
+            TourOutcome::Exit
+        );
+    }
+
+    #[test]
+    fn draft_preview_shows_the_tail_of_the_streaming_draft() {
+        let theme = UiTheme::minimal();
+        let text = |line: Line<'static>| -> String {
+            line.spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect()
         };
-        assert!(apply_notes_patch(&mut notes, patch));
+        assert_eq!(text(draft_preview_line("", &theme)), "thinking…");
+        assert_eq!(
+            text(draft_preview_line("{\n  \"ops\": [", &theme)),
+            "thinking… { \"ops\": ["
+        );
+        let long = format!("{}{}", "x".repeat(100), " Decided to ship on Friday");
+        let preview = text(draft_preview_line(&long, &theme));
+        assert!(preview.ends_with("x Decided to ship on Friday"));
+        assert_eq!(preview.chars().count(), "thinking… …".chars().count() + 48);
+    }
+
+    #[test]
+    fn append_note_lines_matches_full_render() {
+        let theme = UiTheme::minimal();
+        let mut notes = MeetingNotes::default();
+        let mut lines = render_notes_lines(&notes, 0, NotesTab::Actions, &theme);
+        for (i, text) in [
+            "Sam sends revised pricing sheet before Thursday",
+            "Priya books customer interviews for next sprint",
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let before = notes.bullets.len();
+            let patch = NotesPatch {
+                ops: vec![NotesOp::Add {
+                    id: format!("n{i}"),
+                    text: text.to_string(),
+                    evidence: Vec::new(),
+                    kind: Some(NoteKind::Action),
+                }],
+            };
+            assert!(notes.apply_patch(patch));
+            append_note_lines(
+                &mut lines,
+                &notes.bullets[before..],
+                before == 0,
+                NotesTab::Actions,
+                &theme,
+            );
+        }
+        assert_eq!(
+            lines,
+            render_notes_lines(&notes, 0, NotesTab::Actions, &theme)
+        );
+    }
+
+    #[test]
+    fn key_bindings_parse_and_match_with_modifiers() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        let space = KeyBinding::parse("space").expect("space");
+        assert!(space.matches(&KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE)));
+        assert!(!space.matches(&KeyEvent::new(KeyCode::Char(' '), KeyModifiers::CONTROL)));
+        let ctrl_space = KeyBinding::parse("Ctrl+Space").expect("ctrl+space");
+        assert!(ctrl_space.matches(&KeyEvent::new(KeyCode::Char(' '), KeyModifiers::CONTROL)));
+        let p = KeyBinding::parse("p").expect("p");
+        assert!(p.matches(&KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT)));
+        assert_eq!(
+            KeyBinding::parse("f5").map(|key| key.code),
+            Some(KeyCode::F(5))
+        );
+        for invalid in ["", "hyper+x", "ab", "fx"] {
+            assert!(KeyBinding::parse(invalid).is_none(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn redraw_gate_draws_on_change_or_tick() {
+        let start = Instant::now();
+        let tick = Duration::from_secs(1);
+        let mut gate = RedrawGate::default();
+        assert!(gate.due(start, tick));
+        gate.drawn(start);
+        assert!(!gate.due(start + Duration::from_millis(500), tick));
+        gate.mark();
+        assert!(gate.due(start + Duration::from_millis(500), tick));
+        gate.drawn(start + Duration::from_millis(500));
+        assert!(gate.due(start + Duration::from_millis(1_500), tick));
+    }
 
+    #[test]
+    fn notes_tabs_filter_by_kind() {
+        let theme = UiTheme::minimal();
+        let mut notes = MeetingNotes::default();
         let patch = NotesPatch {
             ops: vec![
                 NotesOp::Add {
                     id: "n1".to_string(),
-                    text: "duplicate-id".to_string(),
-                    evidence: vec![2],
+                    text: "We agreed to ship the release on Friday".to_string(),
+                    evidence: Vec::new(),
+                    kind: None,
                 },
                 NotesOp::Add {
                     id: "n2".to_string(),
-                    text: "first".to_string(),
-                    evidence: vec![3],
+                    text: "Check p95 latency on the staging cluster".to_string(),
+                    evidence: Vec::new(),
+                    kind: Some(NoteKind::Action),
                 },
             ],
         };
-        assert!(!apply_notes_patch(&mut notes, patch));
+        assert!(notes.apply_patch(patch));
+        assert_eq!(notes.bullets[0].kind, NoteKind::Decision);
+        assert_eq!(NotesTab::from_key('3'), Some(NotesTab::Actions));
+        assert_eq!(NotesTab::from_key('5'), None);
+
+        let text = |lines: Vec<Line<'static>>| -> Vec<String> {
+            lines[1..].iter().map(|line| line.to_string()).collect()
+        };
+        let all = render_notes_lines(&notes, 0, NotesTab::All, &theme);
+        assert_eq!(
+            text(all),
+            [
+                "Decisions",
+                "· We agreed to ship the release on Friday",
+                "Action Items",
+                "· Check p95 latency on the staging cluster"
+            ]
+        );
+        let decisions = render_notes_lines(&notes, 0, NotesTab::Decisions, &theme);
+        assert_eq!(
+            text(decisions),
+            ["· We agreed to ship the release on Friday"]
+        );
+        let questions = render_notes_lines(&notes, 0, NotesTab::Questions, &theme);
+        assert_eq!(text(questions), ["no questions yet"]);
+    }
+
+    #[test]
+    fn notes_focus_edits_owner_done_and_delete() {
+        let point = |id: &str| bullet(id, &format!("note {id}"), NoteKind::Point);
+        let mut notes = MeetingNotes {
+            bullets: vec![point("a"), point("b")],
+        };
+        let mut state = NotesFocusState::default();
+        let mut press = |code: KeyCode, notes: &mut MeetingNotes| {
+            handle_notes_focus_key(&mut state, KeyEvent::new(code, KeyModifiers::NONE), notes)
+        };
+
+        press(KeyCode::Down, &mut notes);
+        press(KeyCode::Char('o'), &mut notes);
+        for ch in "Sam".chars() {
+            press(KeyCode::Char(ch), &mut notes);
+        }
+        assert_eq!(
+            press(KeyCode::Enter, &mut notes),
+            NotesFocusOutcome::Changed
+        );
+        assert_eq!(notes.bullets[1].owner.as_deref(), Some("Sam"));
+
+        press(KeyCode::Char('e'), &mut notes);
+        press(KeyCode::Backspace, &mut notes);
+        press(KeyCode::Char('B'), &mut notes);
+        press(KeyCode::Enter, &mut notes);
+        assert_eq!(notes.bullets[1].text, "note B");
+
+        press(KeyCode::Char('x'), &mut notes);
+        assert!(notes.bullets[1].done);
+
+        press(KeyCode::Up, &mut notes);
+        assert_eq!(
+            press(KeyCode::Char('d'), &mut notes),
+            NotesFocusOutcome::Changed
+        );
         assert_eq!(notes.bullets.len(), 1);
+        assert_eq!(notes.bullets[0].id, "b");
+        assert_eq!(press(KeyCode::Esc, &mut notes), NotesFocusOutcome::Exit);
+    }
+
+    #[test]
+    fn review_sets_due_rejects_notes_and_picks_targets() {
+        let point = |id: &str| bullet(id, &format!("note {id}"), NoteKind::Point);
+        let mut notes = MeetingNotes {
+            bullets: vec![point("a"), point("b"), point("c")],
+        };
+        let mut state = ReviewState::new(vec![ExportTarget::Obsidian, ExportTarget::Clipboard]);
+        let mut press = |code: KeyCode, notes: &mut MeetingNotes| {
+            handle_review_key(&mut state, KeyEvent::new(code, KeyModifiers::NONE), notes)
+        };
+
+        press(KeyCode::Char('u'), &mut notes);
+        for ch in "Friday".chars() {
+            press(KeyCode::Char(ch), &mut notes);
+        }
+        assert_eq!(press(KeyCode::Enter, &mut notes), ReviewOutcome::Changed);
+        assert_eq!(notes.bullets[0].due.as_deref(), Some("Friday"));
+
+        press(KeyCode::Char('a'), &mut notes);
+        press(KeyCode::Char('r'), &mut notes);
+        press(KeyCode::Enter, &mut notes);
+        press(KeyCode::Down, &mut notes);
+        press(KeyCode::Char(' '), &mut notes);
+        assert_eq!(press(KeyCode::Enter, &mut notes), ReviewOutcome::Finalize);
+
+        assert_eq!(state.selected_targets(), vec![ExportTarget::Clipboard]);
+        assert_eq!(state.apply(&mut notes), 1);
+        let ids: Vec<_> = notes.bullets.iter().map(|b| b.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "c"]);
+    }
+
+    fn segments(texts: &[&str]) -> Vec<TranscriptSegment> {
+        texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| TranscriptSegment {
+                id: i as u64,
+                start_ms: i as i64 * 1_000,
+                end_ms: i as i64 * 1_000 + 900,
+                speaker: None,
+                text: text.to_string(),
+                finalized: true,
+                confidence: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn transcript_view_scrolls_and_resumes_follow() {
+        let mut view = TranscriptView::new();
+        view.scroll_up(100, 10);
+        assert!(!view.follow);
+        assert_eq!(view.end(100), 90);
+        view.scroll_top(100, 10);
+        assert_eq!(view.end(100), 10);
+        view.anchor_end = 95;
+        view.scroll_down(100, 10);
+        assert!(view.follow);
+    }
+
+    #[test]
+    fn transcript_search_steps_through_matches_and_highlights() {
+        let segments = segments(&["Deadline is Friday", "lunch", "move the deadline", "ok"]);
+        let mut view = TranscriptView::new();
+        view.search = Some(TranscriptSearch {
+            query: "deadline".to_string(),
+            ..TranscriptSearch::default()
+        });
+        view.update_search(&segments, 2);
+        let search = view.search.as_ref().unwrap();
+        assert_eq!(search.matches, vec![0, 2]);
+        assert_eq!(search.current, 1);
+        assert!(!view.follow);
+
+        view.step_match(&segments, 2, true);
+        assert_eq!(view.search.as_ref().unwrap().current, 0);
+        assert_eq!(view.end(segments.len()), 2);
+
+        let line = transcript_line(
+            &segments[0],
+            &BTreeMap::new(),
+            Some("deadline"),
+            true,
+            &UiTheme::minimal(),
+        );
+        let texts: Vec<&str> = line
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(texts, vec!["Deadline", " is Friday"]);
+    }
+
+    #[test]
+    fn note_evidence_scrolls_to_and_highlights_cited_segments() {
+        let segments = segments(&["a", "b", "c", "d", "e", "f", "g", "h"]);
+        let mut state = NotesFocusState {
+            selected: 5,
+            edit: None,
+        };
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(
+            handle_notes_evidence_key(&mut state, enter, 2),
+            NotesEvidenceOutcome::Jump(1)
+        );
+
+        let mut view = TranscriptView::new();
+        let ids = [segments[2].id, segments[3].id];
+        assert!(view.jump_to_evidence(&segments, &ids, 4));
+        assert!(!view.follow);
+        assert_eq!(view.end(segments.len()), 5);
+        let lines = render_transcript_window(
+            &segments,
+            &TranscriptDisplay::default(),
+            &view,
+            false,
+            4,
+            &UiTheme::minimal(),
+        );
+        let highlighted: Vec<String> = lines
+            .iter()
+            .filter(|line| line.style.add_modifier.contains(Modifier::REVERSED))
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(highlighted, vec!["c", "d"]);
+
+        assert!(!view.jump_to_evidence(&segments, &[999], 4));
+        assert!(view.evidence.is_empty());
+    }
+
+    #[test]
+    fn transcript_selection_copies_evidence_or_the_selected_line() {
+        let mut segments = segments(&["a", "b", "c"]);
+        segments[1].speaker = Some("Them".to_string());
+        segments[2].speaker = Some("Me".to_string());
+        let names = BTreeMap::from([("Them".to_string(), "Ana".to_string())]);
+        let mut view = TranscriptView::new();
+        assert_eq!(
+            transcript_selection_text(&segments, &view, &names).as_deref(),
+            Some("[00:02] Me: c")
+        );
+
+        view.evidence = vec![0, 1];
+        assert_eq!(
+            transcript_selection_text(&segments, &view, &names).as_deref(),
+            Some("[00:00] a\n[00:01] Ana: b")
+        );
+        assert_eq!(
+            transcript_selection_text(&[], &TranscriptView::new(), &names),
+            None
+        );
+    }
+
+    #[test]
+    fn low_confidence_segments_render_dimmed_with_a_marker() {
+        let mut segments = segments(&["clear", "mumbled", "unscored"]);
+        segments[0].confidence = Some(0.9);
+        segments[1].confidence = Some(0.3);
+        let theme = UiTheme::minimal();
+        let view = TranscriptView::new();
+        let render = |display: &TranscriptDisplay| -> Vec<String> {
+            render_transcript_window(&segments, display, &view, false, 3, &theme)
+                .iter()
+                .skip(1)
+                .map(|line| line.to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            render(&TranscriptDisplay::new(0.6)),
+            vec!["clear", "mumbled (?)", "unscored"]
+        );
+        assert_eq!(
+            render(&TranscriptDisplay::default()),
+            vec!["clear", "mumbled", "unscored"]
+        );
+        let mut display = TranscriptDisplay::new(0.6);
+        display.chapters.push(koe_core::types::Chapter {
+            title: "Intro".to_string(),
+            start_ms: 0,
+            end_ms: 1,
+        });
+        display.reset();
+        assert!(display.chapters.is_empty());
+        assert!(display.is_low_confidence(&segments[1]));
+    }
+
+    #[test]
+    fn color_themes_resolve_builtins_and_custom_palettes() {
+        let mut config = UiConfig::default();
+        for name in ["minimal", "dark", "light", "high-contrast"] {
+            config.color_theme = name.to_string();
+            assert!(UiTheme::resolve(&config).is_ok(), "{name}");
+        }
+
+        config.color_theme = "solar".to_string();
+        config.themes.insert(
+            "solar".to_string(),
+            ThemePalette {
+                base: "light".to_string(),
+                accent: "#b58900".to_string(),
+                ..ThemePalette::default()
+            },
+        );
+        let theme = UiTheme::resolve(&config).unwrap();
+        assert_eq!(theme.accent, Color::Rgb(0xb5, 0x89, 0x00));
+        assert_eq!(theme.error, UiTheme::builtin("light").unwrap().error);
+
+        config.themes.get_mut("solar").unwrap().me = "b58900".to_string();
+        assert!(UiTheme::resolve(&config).unwrap_err().contains("me"));
+        config.color_theme = "neon".to_string();
+        assert!(UiTheme::resolve(&config).is_err());
+        assert_eq!(
+            UiTheme::from_config(&config).accent,
+            UiTheme::minimal().accent
+        );
+    }
+
+    #[test]
+    fn pane_layout_cycles_views_and_resizes_the_split() {
+        let area = Rect::new(0, 0, 101, 20);
+        let mut layout = PaneLayout::from_config(&UiConfig::default());
+        assert_eq!(layout.view, PaneView::Split);
+        let [notes, separator, transcript] = layout.areas(area);
+        assert_eq!(
+            (notes.width, separator.width, transcript.width),
+            (55, 1, 45)
+        );
+
+        layout.view = layout.view.next();
+        let [notes, _, transcript] = layout.areas(area);
+        assert_eq!((notes.width, transcript.width), (101, 0));
+        layout.view = layout.view.next();
+        let [notes, _, transcript] = layout.areas(area);
+        assert_eq!((notes.width, transcript.width), (0, 101));
+
+        layout.resize(true);
+        assert_eq!((layout.view, layout.notes_percent), (PaneView::Split, 60));
+        for _ in 0..10 {
+            layout.resize(false);
+        }
+        assert_eq!(layout.notes_percent, 20);
+
+        let config = UiConfig {
+            layout: "transcript".to_string(),
+            split_percent: 95,
+            ..UiConfig::default()
+        };
+        let layout = PaneLayout::from_config(&config);
+        assert_eq!(
+            (layout.view, layout.notes_percent),
+            (PaneView::TranscriptOnly, 80)
+        );
+    }
+
+    #[test]
+    fn captions_show_latest_segments_then_partials() {
+        let finalized = segments(&["one", "two", "three", "four"]);
+        let partial = segments(&["fi"]);
+        let lines = caption_lines(&finalized, &partial, &UiTheme::minimal());
+        let texts: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(texts, vec!["two", "", "three", "", "four", "", "fi"]);
     }
 }
@@ -0,0 +1,288 @@
+use crate::checksum::{from_hex, sha256_hex};
+use crate::tui::UiEvent;
+use clap::Args;
+use ring::signature::{ED25519, UnparsedPublicKey};
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::Duration;
+use thiserror::Error;
+
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/u29dc/koe/releases/latest";
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(300);
+const MAX_BINARY_BYTES: u64 = 512 * 1024 * 1024;
+/// Hex Ed25519 key the release checksum files are signed with, baked in by
+/// release builds; without it updates refuse to install unless `--insecure`.
+const RELEASE_PUBLIC_KEY: Option<&str> = option_env!("KOE_RELEASE_PUBLIC_KEY");
+
+#[derive(Args, Debug, Clone)]
+pub struct UpdateArgs {
+    /// Only report whether a newer release exists
+    #[arg(long)]
+    pub check: bool,
+
+    /// Install without verifying the release signature (checksum still checked)
+    #[arg(long)]
+    pub insecure: bool,
+}
+
+#[derive(Debug, Error)]
+pub enum UpdateError {
+    #[error("update io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("release request failed: {0}")]
+    Http(#[from] ureq::Error),
+    #[error("release has no asset {0}")]
+    MissingAsset(String),
+    #[error("checksum mismatch: expected {expected}, got {actual}")]
+    Checksum { expected: String, actual: String },
+    #[error("signature check failed: {0}")]
+    Signature(String),
+}
+
+/// GitHub release, reduced to what the updater reads.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub browser_download_url: String,
+}
+
+impl Release {
+    fn asset(&self, name: &str) -> Result<&ReleaseAsset, UpdateError> {
+        self.assets
+            .iter()
+            .find(|asset| asset.name == name)
+            .ok_or_else(|| UpdateError::MissingAsset(name.to_string()))
+    }
+}
+
+pub fn run(args: &UpdateArgs) -> Result<(), UpdateError> {
+    let current = env!("CARGO_PKG_VERSION");
+    let Some(release) = newer_release(current, CHECK_TIMEOUT)? else {
+        println!("koe v{current} is up to date");
+        return Ok(());
+    };
+    println!("update available: v{current} -> {}", release.tag_name);
+    if args.check {
+        return Ok(());
+    }
+
+    let exe = std::env::current_exe()?.canonicalize()?;
+    if args.insecure {
+        eprintln!("warning: --insecure skips release signature verification");
+    }
+    install(&release, &exe, args.insecure)?;
+    println!("installed {} at {}", release.tag_name, exe.display());
+    Ok(())
+}
+
+/// Opt-in launch check; failures stay silent so offline starts are unaffected.
+pub fn spawn_check(ui_tx: Sender<UiEvent>) {
+    let spawned = thread::Builder::new()
+        .name("koe-update-check".into())
+        .spawn(move || {
+            if let Ok(Some(release)) = newer_release(env!("CARGO_PKG_VERSION"), CHECK_TIMEOUT) {
+                let _ = ui_tx.send(UiEvent::UpdateAvailable {
+                    version: release.tag_name,
+                });
+            }
+        });
+    if let Err(e) = spawned {
+        eprintln!("update check thread spawn failed: {e}");
+    }
+}
+
+/// Latest release when it is newer than `current`.
+pub fn newer_release(current: &str, timeout: Duration) -> Result<Option<Release>, UpdateError> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(timeout))
+        .build()
+        .into();
+    let release: Release = agent
+        .get(LATEST_RELEASE_URL)
+        .header("Accept", "application/vnd.github+json")
+        .header("User-Agent", "koe")
+        .call()?
+        .into_body()
+        .read_json()?;
+    Ok(is_newer(&release.tag_name, current).then_some(release))
+}
+
+/// Release asset name for this platform, e.g. `koe-aarch64-apple-darwin`.
+fn asset_name() -> String {
+    let os = match std::env::consts::OS {
+        "macos" => "apple-darwin",
+        "linux" => "unknown-linux-gnu",
+        other => other,
+    };
+    format!("koe-{}-{os}", std::env::consts::ARCH)
+}
+
+/// Download, verify, and atomically swap the binary at `exe`.
+fn install(release: &Release, exe: &Path, insecure: bool) -> Result<(), UpdateError> {
+    let name = asset_name();
+    let binary = release.asset(&name)?;
+    let checksum = release.asset(&format!("{name}.sha256"))?;
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(DOWNLOAD_TIMEOUT))
+        .build()
+        .into();
+
+    let expected = agent
+        .get(&checksum.browser_download_url)
+        .call()?
+        .into_body()
+        .read_to_string()?;
+    if !insecure {
+        let signature = release.asset(&format!("{name}.sha256.sig"))?;
+        let signature = agent
+            .get(&signature.browser_download_url)
+            .call()?
+            .into_body()
+            .read_to_string()?;
+        verify_signature(RELEASE_PUBLIC_KEY, expected.as_bytes(), &signature)?;
+    }
+    let expected = expected
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let bytes = agent
+        .get(&binary.browser_download_url)
+        .call()?
+        .into_body()
+        .with_config()
+        .limit(MAX_BINARY_BYTES)
+        .read_to_vec()?;
+    let actual = sha256_hex(&bytes);
+    if actual != expected {
+        return Err(UpdateError::Checksum { expected, actual });
+    }
+
+    // Staged next to the binary so the final rename stays on one filesystem.
+    let staged = staging_path(exe);
+    fs::write(&staged, &bytes)?;
+    #[cfg(unix)]
+    fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))?;
+    if let Err(e) = verify_codesign(&staged, insecure) {
+        let _ = fs::remove_file(&staged);
+        return Err(e);
+    }
+    fs::rename(&staged, exe).inspect_err(|_| {
+        let _ = fs::remove_file(&staged);
+    })?;
+    Ok(())
+}
+
+fn staging_path(exe: &Path) -> PathBuf {
+    let name = exe
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "koe".to_string());
+    exe.with_file_name(format!(".{name}.update-{}", std::process::id()))
+}
+
+/// Check the detached hex Ed25519 `signature` over the checksum file
+/// against the pinned release key; a missing key fails closed.
+fn verify_signature(
+    public_key: Option<&str>,
+    checksum: &[u8],
+    signature: &str,
+) -> Result<(), UpdateError> {
+    let public_key = public_key
+        .and_then(|key| from_hex(key.trim()))
+        .ok_or_else(|| {
+            UpdateError::Signature("this build has no release public key pinned".into())
+        })?;
+    let signature = from_hex(signature.trim())
+        .ok_or_else(|| UpdateError::Signature("malformed checksum signature".into()))?;
+    UnparsedPublicKey::new(&ED25519, public_key)
+        .verify(checksum, &signature)
+        .map_err(|_| UpdateError::Signature("checksum file signature does not verify".into()))
+}
+
+/// On macOS the replacement must also pass `codesign --verify`.
+fn verify_codesign(staged: &Path, insecure: bool) -> Result<(), UpdateError> {
+    if insecure || std::env::consts::OS != "macos" || codesign_ok(staged) {
+        return Ok(());
+    }
+    Err(UpdateError::Signature(format!(
+        "{} is not validly signed",
+        staged.display()
+    )))
+}
+
+fn codesign_ok(path: &Path) -> bool {
+    Command::new("codesign")
+        .args(["--verify", "--strict"])
+        .arg(path)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+fn parse_version(value: &str) -> Option<(u64, u64, u64)> {
+    let value = value.trim().trim_start_matches('v');
+    let core = value.split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+    Some((
+        parts.next()??,
+        parts.next()??,
+        parts.next().unwrap_or(Some(0))?,
+    ))
+}
+
+fn is_newer(tag: &str, current: &str) -> bool {
+    match (parse_version(tag), parse_version(current)) {
+        (Some(tag), Some(current)) => tag > current,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UpdateError, is_newer, verify_signature};
+    use crate::checksum::to_hex;
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    #[test]
+    fn versions_compare() {
+        assert!(is_newer("v0.2.0", "0.1.9"));
+        assert!(is_newer("v1.0.0", "0.9.0"));
+        assert!(!is_newer("v0.1.0", "0.1.0"));
+        assert!(!is_newer("v0.1.0-rc.1", "0.1.0"));
+        assert!(!is_newer("nightly", "0.1.0"));
+    }
+
+    #[test]
+    fn checksum_signature_must_match_the_pinned_key() {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let key = to_hex(pair.public_key().as_ref());
+        let checksum = b"abc123  koe-aarch64-apple-darwin\n";
+        let signature = to_hex(pair.sign(checksum).as_ref());
+
+        assert!(verify_signature(Some(&key), checksum, &signature).is_ok());
+        assert!(verify_signature(Some(&key), b"tampered", &signature).is_err());
+        assert!(verify_signature(Some(&key), checksum, "zz").is_err());
+        assert!(matches!(
+            verify_signature(None, checksum, &signature),
+            Err(UpdateError::Signature(_))
+        ));
+    }
+}
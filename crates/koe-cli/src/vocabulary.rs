@@ -0,0 +1,43 @@
+use koe_core::vocabulary::Vocabulary;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Learned corrections from `~/.koe/vocabulary.json`; a missing file is empty.
+pub fn load(path: &Path) -> io::Result<Vocabulary> {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents).map_err(io::Error::other),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vocabulary::default()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Replace the vocabulary file atomically.
+pub fn save(path: &Path, vocabulary: &Vocabulary) -> io::Result<()> {
+    let payload = serde_json::to_string_pretty(vocabulary).map_err(io::Error::other)?;
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, payload)?;
+    fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load, save};
+    use koe_core::vocabulary::Vocabulary;
+    use std::fs;
+
+    #[test]
+    fn vocabulary_round_trips_and_defaults_when_missing() {
+        let dir = std::env::temp_dir().join(format!("koe-vocabulary-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("vocabulary.json");
+        assert_eq!(load(&path).unwrap(), Vocabulary::default());
+
+        let mut vocabulary = Vocabulary::default();
+        vocabulary.learn(Some("Them"), "deploy to cube", "deploy to Kubernetes");
+        save(&path, &vocabulary).unwrap();
+        assert_eq!(load(&path).unwrap(), vocabulary);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,214 @@
+use crate::config::{NotionConfig, WebhooksConfig};
+use crate::hooks;
+use crate::notion;
+use crate::outputs::EventOutputs;
+use crate::session::SessionHandle;
+use crate::tui::UiEvent;
+use koe_core::types::NotesOp;
+use serde::Serialize;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// JSON body posted to webhooks; `event` names the session event.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    MeetingStart {
+        session_id: String,
+        start_time: String,
+        context: Option<String>,
+        participants: Vec<String>,
+    },
+    NotesPatch {
+        session_id: String,
+        ops: Vec<NotesOp>,
+    },
+    MeetingEnd {
+        session_id: String,
+        end_time: Option<String>,
+        session_dir: PathBuf,
+        transcript: Option<PathBuf>,
+        notes: Option<PathBuf>,
+        audio: Option<PathBuf>,
+    },
+}
+
+impl WebhookEvent {
+    pub fn meeting_start(session: &SessionHandle) -> Self {
+        let metadata = session.metadata();
+        Self::MeetingStart {
+            session_id: metadata.id.clone(),
+            start_time: metadata.start_time.clone(),
+            context: metadata.context.clone(),
+            participants: metadata.participants.clone(),
+        }
+    }
+
+    pub fn notes_patch(session: &SessionHandle, ops: Vec<NotesOp>) -> Self {
+        Self::NotesPatch {
+            session_id: session.metadata().id.clone(),
+            ops,
+        }
+    }
+
+    /// End event with export paths; call after the session is finalized.
+    pub fn meeting_end(session: &SessionHandle) -> Self {
+        let metadata = session.metadata();
+        Self::MeetingEnd {
+            session_id: metadata.id.clone(),
+            end_time: metadata.end_time.clone(),
+            session_dir: session.session_dir().to_path_buf(),
+            transcript: session.export_transcript_path().ok(),
+            notes: session.export_notes_path().ok(),
+            audio: session.export_audio_path().ok(),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::MeetingStart { .. } => "meeting_start",
+            Self::NotesPatch { .. } => "notes_patch",
+            Self::MeetingEnd { .. } => "meeting_end",
+        }
+    }
+
+    fn urls<'a>(&self, config: &'a WebhooksConfig) -> &'a [String] {
+        match self {
+            Self::MeetingStart { .. } => &config.meeting_start,
+            Self::NotesPatch { .. } => &config.notes_patch,
+            Self::MeetingEnd { .. } => &config.meeting_end,
+        }
+    }
+}
+
+/// Handle for queueing session events; webhook posts and lifecycle hooks run on
+/// a background thread so slow endpoints never stall the UI.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookSender {
+    tx: Option<Sender<WebhookEvent>>,
+}
+
+impl WebhookSender {
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    pub fn send(&self, event: WebhookEvent) {
+        if let Some(tx) = &self.tx {
+            let _ = tx.send(event);
+        }
+    }
+}
+
+/// Start the webhook thread, also running hooks from `hooks_dir`, signalling
+/// `outputs`, and pushing ended meetings to `notion` when given. It exits once every sender is dropped, after posting
+/// whatever is still queued.
+pub fn spawn_webhooks(
+    config: WebhooksConfig,
+    hooks_dir: Option<PathBuf>,
+    outputs: Option<EventOutputs>,
+    notion: Option<NotionConfig>,
+    ui_tx: Sender<UiEvent>,
+) -> io::Result<(WebhookSender, JoinHandle<()>)> {
+    let (tx, rx) = channel();
+    let handle = thread::Builder::new()
+        .name("koe-webhooks".into())
+        .spawn(move || {
+            post_events(
+                &config,
+                hooks_dir.as_deref(),
+                outputs.as_ref(),
+                notion.as_ref(),
+                &rx,
+                &ui_tx,
+            )
+        })?;
+    Ok((WebhookSender { tx: Some(tx) }, handle))
+}
+
+fn post_events(
+    config: &WebhooksConfig,
+    hooks_dir: Option<&Path>,
+    outputs: Option<&EventOutputs>,
+    notion: Option<&NotionConfig>,
+    rx: &Receiver<WebhookEvent>,
+    ui_tx: &Sender<UiEvent>,
+) {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(WEBHOOK_TIMEOUT))
+        .build()
+        .into();
+    for event in rx {
+        for url in event.urls(config) {
+            if let Err(e) = agent.post(url).send_json(&event) {
+                let _ = ui_tx.send(UiEvent::Error {
+                    message: format!("webhook {} failed: {e}", event.name()),
+                });
+            }
+        }
+        if let Some(dir) = hooks_dir
+            && let Err(e) = hooks::run_hook(dir, &event)
+        {
+            let _ = ui_tx.send(UiEvent::Error {
+                message: format!("hook for {} failed: {e}", event.name()),
+            });
+        }
+        if let Some(outputs) = outputs
+            && let Err(e) = outputs.emit(&event)
+        {
+            let _ = ui_tx.send(UiEvent::Error {
+                message: format!("event output for {} failed: {e}", event.name()),
+            });
+        }
+        if let (
+            Some(notion),
+            WebhookEvent::MeetingEnd {
+                session_dir,
+                transcript,
+                ..
+            },
+        ) = (notion, &event)
+            && let Err(e) = notion::push_meeting(notion, session_dir, transcript.as_deref())
+        {
+            let _ = ui_tx.send(UiEvent::Error {
+                message: format!("notion export failed: {e}"),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebhookEvent;
+    use crate::config::WebhooksConfig;
+    use koe_core::types::NotesOp;
+
+    #[test]
+    fn events_serialize_with_tag_and_route_to_their_urls() {
+        let event = WebhookEvent::NotesPatch {
+            session_id: "abc".to_string(),
+            ops: vec![NotesOp::Add {
+                id: "n1".to_string(),
+                text: "ship it".to_string(),
+                evidence: vec![3],
+                kind: None,
+            }],
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["event"], "notes_patch");
+        assert_eq!(json["session_id"], "abc");
+        assert_eq!(json["ops"][0]["Add"]["text"], "ship it");
+
+        let config = WebhooksConfig {
+            meeting_start: vec!["https://example.com/start".to_string()],
+            notes_patch: vec!["https://example.com/notes".to_string()],
+            meeting_end: Vec::new(),
+        };
+        assert_eq!(event.urls(&config), ["https://example.com/notes"]);
+    }
+}
@@ -0,0 +1,350 @@
+use crate::capture::{
+    AudioCapture, AudioInputDeviceInfo, CaptureConfig, DeviceListener, MicrophoneChange,
+    list_audio_inputs, select_default_microphone,
+};
+use crate::error::CaptureError;
+use crate::types::{AudioFrame, AudioSource, CaptureStats};
+use std::time::{Duration, Instant};
+
+/// How often a degraded capture retries the full source set.
+const REPROBE_INTERVAL: Duration = Duration::from_secs(10);
+/// How often the input device list is checked for a vanished or returned mic.
+const DEVICE_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+pub type CaptureFactory =
+    Box<dyn FnMut(&CaptureConfig) -> Result<Box<dyn AudioCapture>, CaptureError> + Send>;
+
+type InputLister = Box<dyn FnMut() -> Vec<AudioInputDeviceInfo> + Send>;
+
+/// Capture wrapper that falls back to a single source when the other is not
+/// authorized, marks the missing source in `CaptureStats`, and re-probes the
+/// full configuration periodically. It also restarts the mic stream on another
+/// device when the configured one disconnects, and again once it is back.
+pub struct FallbackCapture {
+    requested: CaptureConfig,
+    active: CaptureConfig,
+    inner: Box<dyn AudioCapture>,
+    factory: CaptureFactory,
+    stats: CaptureStats,
+    started: bool,
+    last_probe: Instant,
+    inputs: InputLister,
+    listener: Option<DeviceListener>,
+    last_device_check: Instant,
+}
+
+impl FallbackCapture {
+    pub fn new(
+        requested: CaptureConfig,
+        stats: CaptureStats,
+        mut factory: CaptureFactory,
+    ) -> Result<Self, CaptureError> {
+        let mut first_error = None;
+        for candidate in candidates(&requested) {
+            match factory(&candidate) {
+                Ok(inner) => {
+                    let capture = Self {
+                        requested,
+                        active: candidate,
+                        inner,
+                        factory,
+                        stats,
+                        started: false,
+                        last_probe: Instant::now(),
+                        inputs: Box::new(list_audio_inputs),
+                        listener: None,
+                        last_device_check: Instant::now(),
+                    };
+                    capture.publish_missing();
+                    return Ok(capture);
+                }
+                Err(err) => {
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+        Err(first_error.unwrap_or_else(|| CaptureError::ConfigFailed("no capture sources".into())))
+    }
+
+    fn is_degraded(&self) -> bool {
+        self.active.capture_system != self.requested.capture_system
+            || self.active.capture_microphone != self.requested.capture_microphone
+    }
+
+    fn publish_missing(&self) {
+        self.stats.set_source_missing(
+            AudioSource::System,
+            self.requested.capture_system && !self.active.capture_system,
+        );
+        self.stats.set_source_missing(
+            AudioSource::Microphone,
+            self.requested.capture_microphone && !self.active.capture_microphone,
+        );
+    }
+
+    /// Move the mic stream to the configured device, or to the built-in one while
+    /// the configured device is missing. Failed restarts retry on the next check.
+    fn follow_microphone(&mut self) {
+        if !self.started
+            || !self.active.capture_microphone
+            || self.last_device_check.elapsed() < DEVICE_CHECK_INTERVAL
+        {
+            return;
+        }
+        self.last_device_check = Instant::now();
+        let Some(requested_id) = self.requested.microphone_device_id.clone() else {
+            return;
+        };
+        let inputs = (self.inputs)();
+        let Some(target) = resolve_microphone(&requested_id, &inputs) else {
+            return;
+        };
+        if self.active.microphone_device_id.as_deref() == Some(target.id.as_str()) {
+            return;
+        }
+        let config = CaptureConfig {
+            microphone_device_id: Some(target.id.clone()),
+            ..self.active.clone()
+        };
+        let Ok(capture) = self.open_started(&config) else {
+            return;
+        };
+        self.inner.stop();
+        self.inner = capture;
+        self.active = config;
+        let change = MicrophoneChange {
+            device_id: target.id.clone(),
+            name: target.name.clone(),
+            fallback: target.id != requested_id,
+        };
+        if let Some(listener) = self.listener.as_mut() {
+            listener(&change);
+        }
+    }
+
+    /// Build and start a capture for `config`, returning it only if both succeed.
+    fn open_started(
+        &mut self,
+        config: &CaptureConfig,
+    ) -> Result<Box<dyn AudioCapture>, CaptureError> {
+        let mut capture = (self.factory)(config)?;
+        capture.start()?;
+        Ok(capture)
+    }
+}
+
+impl AudioCapture for FallbackCapture {
+    fn start(&mut self) -> Result<(), CaptureError> {
+        let first_error = match self.inner.start() {
+            Ok(()) => {
+                self.started = true;
+                self.last_probe = Instant::now();
+                return Ok(());
+            }
+            Err(err) => err,
+        };
+        let remaining: Vec<CaptureConfig> = candidates(&self.requested)
+            .into_iter()
+            .filter(|candidate| candidate_rank(candidate) > candidate_rank(&self.active))
+            .collect();
+        for candidate in remaining {
+            if let Ok(capture) = self.open_started(&candidate) {
+                self.inner = capture;
+                self.active = candidate;
+                self.started = true;
+                self.last_probe = Instant::now();
+                self.publish_missing();
+                return Ok(());
+            }
+        }
+        Err(first_error)
+    }
+
+    fn stop(&mut self) {
+        self.inner.stop();
+        self.started = false;
+    }
+
+    fn try_recv_system(&mut self) -> Option<AudioFrame> {
+        self.inner.try_recv_system()
+    }
+
+    fn try_recv_mic(&mut self) -> Option<AudioFrame> {
+        self.inner.try_recv_mic()
+    }
+
+    fn poll(&mut self) {
+        self.follow_microphone();
+        if !self.started || !self.is_degraded() || self.last_probe.elapsed() < REPROBE_INTERVAL {
+            return;
+        }
+        self.last_probe = Instant::now();
+        let requested = self.requested.clone();
+        if let Ok(capture) = self.open_started(&requested) {
+            self.inner.stop();
+            self.inner = capture;
+            self.active = requested;
+            self.publish_missing();
+        }
+    }
+
+    fn set_device_listener(&mut self, listener: DeviceListener) {
+        self.listener = Some(listener);
+    }
+}
+
+/// The configured mic when connected, else the built-in/default stand-in.
+fn resolve_microphone<'a>(
+    requested_id: &str,
+    inputs: &'a [AudioInputDeviceInfo],
+) -> Option<&'a AudioInputDeviceInfo> {
+    if let Some(device) = inputs.iter().find(|device| device.id == requested_id) {
+        return Some(device);
+    }
+    let fallback_id = select_default_microphone(inputs)?;
+    inputs.iter().find(|device| device.id == fallback_id)
+}
+
+/// Configurations to try, in order: the requested set, then each requested source alone.
+fn candidates(requested: &CaptureConfig) -> Vec<CaptureConfig> {
+    let mut candidates = vec![requested.clone()];
+    if requested.capture_system && requested.capture_microphone {
+        candidates.push(CaptureConfig {
+            capture_microphone: false,
+            ..requested.clone()
+        });
+        candidates.push(CaptureConfig {
+            capture_system: false,
+            ..requested.clone()
+        });
+    }
+    candidates
+}
+
+/// Position of a config in the fallback order (requested first, mic-only last).
+fn candidate_rank(config: &CaptureConfig) -> u8 {
+    match (config.capture_system, config.capture_microphone) {
+        (true, true) => 0,
+        (true, false) => 1,
+        _ => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CaptureFactory, FallbackCapture};
+    use crate::capture::{AudioCapture, AudioInputDeviceInfo, CaptureConfig, MicrophoneChange};
+    use crate::error::CaptureError;
+    use crate::types::{AudioFrame, AudioSource, CaptureStats};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    struct FakeCapture;
+
+    impl AudioCapture for FakeCapture {
+        fn start(&mut self) -> Result<(), CaptureError> {
+            Ok(())
+        }
+        fn stop(&mut self) {}
+        fn try_recv_system(&mut self) -> Option<AudioFrame> {
+            None
+        }
+        fn try_recv_mic(&mut self) -> Option<AudioFrame> {
+            None
+        }
+    }
+
+    /// Factory that rejects any config including the mic until `mic_granted` flips.
+    fn factory(mic_granted: Arc<AtomicBool>) -> CaptureFactory {
+        Box::new(move |config: &CaptureConfig| {
+            if config.capture_microphone && !mic_granted.load(Ordering::Relaxed) {
+                return Err(CaptureError::PermissionDenied);
+            }
+            Ok(Box::new(FakeCapture) as Box<dyn AudioCapture>)
+        })
+    }
+
+    #[test]
+    fn falls_back_to_available_source_and_marks_missing() {
+        let stats = CaptureStats::new();
+        let granted = Arc::new(AtomicBool::new(false));
+        let mut capture =
+            FallbackCapture::new(CaptureConfig::default(), stats.clone(), factory(granted))
+                .unwrap();
+        capture.start().unwrap();
+        assert!(!stats.source_missing(AudioSource::System));
+        assert!(stats.source_missing(AudioSource::Microphone));
+    }
+
+    #[test]
+    fn reprobe_restores_full_capture_once_granted() {
+        let stats = CaptureStats::new();
+        let granted = Arc::new(AtomicBool::new(false));
+        let mut capture = FallbackCapture::new(
+            CaptureConfig::default(),
+            stats.clone(),
+            factory(Arc::clone(&granted)),
+        )
+        .unwrap();
+        capture.start().unwrap();
+
+        granted.store(true, Ordering::Relaxed);
+        capture.last_probe -= super::REPROBE_INTERVAL;
+        capture.poll();
+        assert!(!stats.source_missing(AudioSource::Microphone));
+        assert!(capture.active.capture_microphone);
+    }
+    #[test]
+    fn mic_moves_to_built_in_while_configured_device_is_gone() {
+        let device = |id: &str, name: &str| AudioInputDeviceInfo {
+            id: id.to_string(),
+            name: name.to_string(),
+            is_default: false,
+        };
+        let airpods = device("AIRPODS", "AirPods Pro");
+        let built_in = device("BuiltInMicrophoneDevice", "MacBook Pro Microphone");
+        let connected = Arc::new(Mutex::new(vec![airpods.clone(), built_in.clone()]));
+        let changes = Arc::new(Mutex::new(Vec::new()));
+
+        let requested = CaptureConfig {
+            microphone_device_id: Some("AIRPODS".to_string()),
+            ..CaptureConfig::default()
+        };
+        let granted = Arc::new(AtomicBool::new(true));
+        let mut capture =
+            FallbackCapture::new(requested, CaptureStats::new(), factory(granted)).unwrap();
+        let listed = Arc::clone(&connected);
+        capture.inputs = Box::new(move || listed.lock().unwrap().clone());
+        let seen = Arc::clone(&changes);
+        capture.set_device_listener(Box::new(move |change: &MicrophoneChange| {
+            seen.lock().unwrap().push(change.clone());
+        }));
+        capture.start().unwrap();
+
+        let check = |capture: &mut FallbackCapture| {
+            capture.last_device_check -= super::DEVICE_CHECK_INTERVAL;
+            capture.poll();
+            capture.active.microphone_device_id.clone()
+        };
+        assert_eq!(check(&mut capture).as_deref(), Some("AIRPODS"));
+        connected
+            .lock()
+            .unwrap()
+            .retain(|input| input.id != "AIRPODS");
+        assert_eq!(
+            check(&mut capture).as_deref(),
+            Some("BuiltInMicrophoneDevice")
+        );
+        connected.lock().unwrap().push(airpods);
+        assert_eq!(check(&mut capture).as_deref(), Some("AIRPODS"));
+
+        let changes = changes.lock().unwrap();
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| (change.name.as_str(), change.fallback))
+                .collect::<Vec<_>>(),
+            [("MacBook Pro Microphone", true), ("AirPods Pro", false)]
+        );
+    }
+}
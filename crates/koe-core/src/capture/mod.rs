@@ -1,3 +1,4 @@
+mod fallback;
 mod handler;
 mod sck;
 
@@ -11,8 +12,25 @@ pub trait AudioCapture: Send {
     fn stop(&mut self);
     fn try_recv_system(&mut self) -> Option<AudioFrame>;
     fn try_recv_mic(&mut self) -> Option<AudioFrame>;
+
+    /// Periodic housekeeping, called from the processor thread between reads.
+    fn poll(&mut self) {}
+
+    /// Called from the processor thread whenever the mic stream moves to another device.
+    fn set_device_listener(&mut self, _listener: DeviceListener) {}
+}
+
+/// Mic stream moved to another input device, e.g. after AirPods disconnected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MicrophoneChange {
+    pub device_id: String,
+    pub name: String,
+    /// The configured device is unavailable and this is the built-in/default stand-in.
+    pub fallback: bool,
 }
 
+pub type DeviceListener = Box<dyn FnMut(&MicrophoneChange) + Send>;
+
 #[derive(Debug, Clone)]
 pub struct CaptureConfig {
     pub capture_system: bool,
@@ -48,10 +66,93 @@ pub fn list_audio_inputs() -> Vec<AudioInputDeviceInfo> {
         .collect()
 }
 
+/// Built-in mic when present, else the system default.
+pub fn select_default_microphone(inputs: &[AudioInputDeviceInfo]) -> Option<String> {
+    if inputs.is_empty() {
+        return None;
+    }
+    let built_in = inputs.iter().find(|device| {
+        let name = device.name.to_lowercase();
+        device.id == "BuiltInMicrophoneDevice"
+            || name.contains("built-in")
+            || name.contains("built in")
+            || name.contains("macbook")
+    });
+    if let Some(device) = built_in {
+        return Some(device.id.clone());
+    }
+    inputs
+        .iter()
+        .find(|device| device.is_default)
+        .map(|device| device.id.clone())
+}
+
+/// Check screen recording access without starting a stream.
+pub fn probe_screen_recording() -> Result<(), CaptureError> {
+    sck::probe_shareable_content()
+}
+
 /// Create the platform-specific audio capture backend.
+///
+/// If only one of the requested sources is authorized, capture starts degraded
+/// with that source and re-probes the full set in the background. A configured
+/// mic that disappears is swapped for the built-in one until it returns.
 pub fn create_capture(
     stats: CaptureStats,
     config: CaptureConfig,
 ) -> Result<Box<dyn AudioCapture>, CaptureError> {
-    Ok(Box::new(sck::SckCapture::new(stats, config)?))
+    let backend_stats = stats.clone();
+    let factory: fallback::CaptureFactory = Box::new(move |config: &CaptureConfig| {
+        Ok(
+            Box::new(sck::SckCapture::new(backend_stats.clone(), config.clone())?)
+                as Box<dyn AudioCapture>,
+        )
+    });
+    Ok(Box::new(fallback::FallbackCapture::new(
+        config, stats, factory,
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AudioInputDeviceInfo, select_default_microphone};
+
+    #[test]
+    fn select_default_microphone_prefers_built_in() {
+        let inputs = vec![
+            AudioInputDeviceInfo {
+                id: "BT-MIC".to_string(),
+                name: "WH-1000XM5".to_string(),
+                is_default: true,
+            },
+            AudioInputDeviceInfo {
+                id: "BuiltInMicrophoneDevice".to_string(),
+                name: "MacBook Pro Microphone".to_string(),
+                is_default: false,
+            },
+        ];
+        assert_eq!(
+            select_default_microphone(&inputs).as_deref(),
+            Some("BuiltInMicrophoneDevice")
+        );
+    }
+
+    #[test]
+    fn select_default_microphone_falls_back_to_default() {
+        let inputs = vec![AudioInputDeviceInfo {
+            id: "USB-MIC".to_string(),
+            name: "USB Microphone".to_string(),
+            is_default: true,
+        }];
+        assert_eq!(
+            select_default_microphone(&inputs).as_deref(),
+            Some("USB-MIC")
+        );
+    }
+
+    #[test]
+    fn select_default_microphone_handles_empty_list() {
+        let inputs = Vec::new();
+        assert_eq!(select_default_microphone(&inputs), None);
+    }
 }
@@ -22,6 +22,15 @@ pub struct SckCapture {
     capture_microphone: bool,
 }
 
+/// Listing shareable content fails until screen recording is granted.
+pub(super) fn probe_shareable_content() -> Result<(), CaptureError> {
+    let content = SCShareableContent::get().map_err(|_| CaptureError::PermissionDenied)?;
+    if content.displays().is_empty() {
+        return Err(CaptureError::NoDisplay);
+    }
+    Ok(())
+}
+
 impl SckCapture {
     pub fn new(
         stats: CaptureStats,
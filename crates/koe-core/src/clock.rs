@@ -0,0 +1,94 @@
+/// Meeting clock that excludes paused intervals.
+///
+/// Timestamps are nanoseconds on any monotonic base (capture PTS or a UI
+/// `Instant`); `meeting_ns` maps them onto meeting-relative time, so audio,
+/// transcript segments, and the elapsed timer agree across pauses.
+#[derive(Debug, Clone, Default)]
+pub struct SessionClock {
+    origin_ns: Option<i128>,
+    paused_since_ns: Option<i128>,
+    pauses: Vec<(i128, i128)>,
+}
+
+impl SessionClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set time zero at `now_ns` unless the clock is already running.
+    pub fn start(&mut self, now_ns: i128) {
+        if self.origin_ns.is_none() {
+            self.origin_ns = Some(now_ns);
+        }
+    }
+
+    /// Forget the origin and pauses, ready for the next meeting.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.origin_ns.is_some()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_since_ns.is_some()
+    }
+
+    pub fn pause(&mut self, now_ns: i128) {
+        if self.origin_ns.is_some() && self.paused_since_ns.is_none() {
+            self.paused_since_ns = Some(now_ns);
+        }
+    }
+
+    pub fn resume(&mut self, now_ns: i128) {
+        if let Some(since) = self.paused_since_ns.take() {
+            self.pauses.push((since, now_ns.max(since)));
+        }
+    }
+
+    /// Meeting-relative time of `ns`, not counting time spent paused before it.
+    pub fn meeting_ns(&self, ns: i128) -> i128 {
+        let Some(origin) = self.origin_ns else {
+            return 0;
+        };
+        let open = self.paused_since_ns.map(|since| (since, i128::MAX));
+        let paused: i128 = self
+            .pauses
+            .iter()
+            .copied()
+            .chain(open)
+            .map(|(start, end)| (end.min(ns) - start).max(0))
+            .sum();
+        (ns - origin - paused).max(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SessionClock;
+
+    const MS: i128 = 1_000_000;
+
+    #[test]
+    fn paused_intervals_are_excluded() {
+        let mut clock = SessionClock::new();
+        assert_eq!(clock.meeting_ns(500 * MS), 0);
+
+        clock.start(1_000 * MS);
+        assert_eq!(clock.meeting_ns(1_500 * MS), 500 * MS);
+
+        clock.pause(2_000 * MS);
+        assert!(clock.is_paused());
+        // Time stands still while paused.
+        assert_eq!(clock.meeting_ns(5_000 * MS), 1_000 * MS);
+
+        clock.resume(6_000 * MS);
+        assert_eq!(clock.meeting_ns(6_500 * MS), 1_500 * MS);
+        // Timestamps from before the pause are unaffected.
+        assert_eq!(clock.meeting_ns(1_200 * MS), 200 * MS);
+
+        clock.reset();
+        assert!(!clock.is_started());
+    }
+}
@@ -1,11 +1,15 @@
 pub mod capture;
+pub mod clock;
 pub mod error;
 mod http;
 pub mod process;
+pub mod redact;
 pub mod summarize;
 pub mod transcribe;
 pub mod transcript;
 pub mod types;
+pub mod usage;
+pub mod vocabulary;
 
 pub use error::*;
 pub use types::*;
@@ -0,0 +1,81 @@
+/// Microphone audio measured at meeting start, in 512-sample VAD frames (~3 s).
+const CALIBRATION_FRAMES: usize = 94;
+/// Percentile of frame levels taken as the noise floor, low enough to ignore
+/// someone already talking during calibration.
+const FLOOR_PERCENTILE: usize = 20;
+/// Noise floor at which the configured VAD threshold is used unchanged.
+const REFERENCE_FLOOR_DBFS: f32 = -60.0;
+/// VAD threshold change per dB of noise floor above the reference.
+const THRESHOLD_PER_DB: f32 = 0.01;
+const MIN_THRESHOLD: f32 = 0.2;
+const MAX_THRESHOLD: f32 = 0.8;
+
+/// Result of the meeting-start noise measurement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseCalibration {
+    /// Background level of the microphone, in dBFS.
+    pub noise_floor_dbfs: f32,
+    /// VAD threshold the microphone pipeline switched to.
+    pub vad_threshold: f32,
+}
+
+/// Collects frame levels until enough audio has been seen to estimate the floor.
+pub(crate) struct Calibrator {
+    base_threshold: f32,
+    levels: Vec<f32>,
+}
+
+impl Calibrator {
+    pub(crate) fn new(base_threshold: f32) -> Self {
+        Self {
+            base_threshold,
+            levels: Vec::with_capacity(CALIBRATION_FRAMES),
+        }
+    }
+
+    /// Add one 16 kHz frame; returns the calibration once the window is full.
+    pub(crate) fn push(&mut self, frame: &[f32]) -> Option<NoiseCalibration> {
+        let energy = frame.iter().map(|sample| sample * sample).sum::<f32>();
+        let mean = energy / frame.len().max(1) as f32;
+        self.levels.push(10.0 * mean.max(1e-12).log10());
+        if self.levels.len() < CALIBRATION_FRAMES {
+            return None;
+        }
+        self.levels.sort_by(f32::total_cmp);
+        let noise_floor_dbfs = self.levels[self.levels.len() * FLOOR_PERCENTILE / 100];
+        let shift = (noise_floor_dbfs - REFERENCE_FLOOR_DBFS) * THRESHOLD_PER_DB;
+        Some(NoiseCalibration {
+            noise_floor_dbfs,
+            vad_threshold: (self.base_threshold + shift).clamp(MIN_THRESHOLD, MAX_THRESHOLD),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CALIBRATION_FRAMES, Calibrator, NoiseCalibration};
+
+    fn calibrate(noise: f32, speech_frames: usize) -> NoiseCalibration {
+        let mut calibrator = Calibrator::new(0.5);
+        (0..CALIBRATION_FRAMES)
+            .find_map(|index| {
+                let level = if index < speech_frames { 0.3 } else { noise };
+                calibrator.push(&vec![level; 512])
+            })
+            .expect("calibration after full window")
+    }
+
+    #[test]
+    fn threshold_follows_noise_floor_and_ignores_early_speech() {
+        // 0.001 is -60 dBFS, the reference floor.
+        let reference = calibrate(0.001, 30);
+        assert!((reference.noise_floor_dbfs + 60.0).abs() < 0.1);
+        assert!((reference.vad_threshold - 0.5).abs() < 0.01);
+
+        let noisy = calibrate(0.01, 0);
+        assert!((noisy.vad_threshold - 0.7).abs() < 0.01);
+
+        let silent = calibrate(0.0, 0);
+        assert_eq!(silent.vad_threshold, 0.2);
+    }
+}
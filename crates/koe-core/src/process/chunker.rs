@@ -6,21 +6,54 @@ const TARGET_SAMPLES: usize = 64_000; // 4 s
 const MAX_SAMPLES: usize = 96_000; // 6 s
 const OVERLAP_SAMPLES: usize = 16_000; // 1 s
 
+/// Chunk length bounds in 16 kHz samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkLimits {
+    /// Shortest chunk emitted before a flush.
+    pub min_samples: usize,
+    /// Length after which the next speech-to-silence edge emits.
+    pub target_samples: usize,
+    /// Length at which a chunk is emitted even mid-speech.
+    pub max_samples: usize,
+    /// Tail carried into the next chunk so words at the boundary are not cut.
+    pub overlap_samples: usize,
+}
+
+impl Default for ChunkLimits {
+    fn default() -> Self {
+        Self {
+            min_samples: MIN_SAMPLES,
+            target_samples: TARGET_SAMPLES,
+            max_samples: MAX_SAMPLES,
+            overlap_samples: OVERLAP_SAMPLES,
+        }
+    }
+}
+
 /// Accumulates 16 kHz mono samples and emits speech-gated chunks with overlap.
 pub struct Chunker {
+    limits: ChunkLimits,
     buffer: Vec<f32>,
     start_pts_ns: i128,
     was_speech: bool,
     source: AudioSource,
+    /// Buffer offset of audio not yet handed out by `take_partial`.
+    partial_from: usize,
 }
 
 impl Chunker {
     pub fn new(source: AudioSource) -> Self {
+        Self::with_limits(source, ChunkLimits::default())
+    }
+
+    pub fn with_limits(source: AudioSource, limits: ChunkLimits) -> Self {
         Self {
-            buffer: Vec::with_capacity(MAX_SAMPLES),
+            limits,
+            buffer: Vec::with_capacity(limits.max_samples),
             start_pts_ns: 0,
             was_speech: false,
             source,
+            partial_from: 0,
         }
     }
 
@@ -36,10 +69,10 @@ impl Chunker {
         self.was_speech = speech;
 
         // Emit when: buffer >= target AND speech->silence, OR buffer >= max
-        let should_emit = (self.buffer.len() >= TARGET_SAMPLES && speech_to_silence)
-            || self.buffer.len() >= MAX_SAMPLES;
+        let should_emit = (self.buffer.len() >= self.limits.target_samples && speech_to_silence)
+            || self.buffer.len() >= self.limits.max_samples;
 
-        if should_emit && self.buffer.len() >= MIN_SAMPLES {
+        if should_emit && self.buffer.len() >= self.limits.min_samples {
             Some(self.emit())
         } else {
             None
@@ -48,7 +81,7 @@ impl Chunker {
 
     /// Flush remaining samples (e.g. on stop). Emits if buffer >= min.
     pub fn flush(&mut self) -> Option<AudioChunk> {
-        if self.buffer.len() >= MIN_SAMPLES {
+        if self.buffer.len() >= self.limits.min_samples {
             Some(self.emit())
         } else if !self.buffer.is_empty() {
             // Emit even short buffers on flush to avoid data loss
@@ -58,6 +91,24 @@ impl Chunker {
         }
     }
 
+    /// Return audio buffered since the last partial once at least `min_samples`
+    /// have accumulated during speech, for streaming partial transcription.
+    pub fn take_partial(&mut self, min_samples: usize) -> Option<AudioChunk> {
+        let pending = self.buffer.len().saturating_sub(self.partial_from);
+        if !self.was_speech || pending < min_samples.max(1) {
+            return None;
+        }
+        let offset_ns = (self.partial_from as i128 * 1_000_000_000) / SAMPLE_RATE as i128;
+        let chunk = AudioChunk {
+            source: self.source,
+            start_pts_ns: self.start_pts_ns + offset_ns,
+            sample_rate_hz: SAMPLE_RATE,
+            pcm_mono_f32: self.buffer[self.partial_from..].to_vec(),
+        };
+        self.partial_from = self.buffer.len();
+        Some(chunk)
+    }
+
     fn emit(&mut self) -> AudioChunk {
         let chunk = AudioChunk {
             source: self.source,
@@ -67,16 +118,19 @@ impl Chunker {
         };
 
         // Retain overlap
-        if self.buffer.len() > OVERLAP_SAMPLES {
-            let retain_start = self.buffer.len() - OVERLAP_SAMPLES;
+        let overlap = self.limits.overlap_samples;
+        if self.buffer.len() > overlap {
+            let retain_start = self.buffer.len() - overlap;
             self.buffer.drain(..retain_start);
             // Advance PTS past the emitted (non-overlap) portion
-            self.start_pts_ns += ((chunk.pcm_mono_f32.len() as i128 - OVERLAP_SAMPLES as i128)
+            self.start_pts_ns += ((chunk.pcm_mono_f32.len() as i128 - overlap as i128)
                 * 1_000_000_000)
                 / SAMPLE_RATE as i128;
         } else {
             self.buffer.clear();
         }
+        // The retained overlap belongs to the emitted chunk; partials resume after it.
+        self.partial_from = self.buffer.len();
 
         chunk
     }
@@ -124,6 +178,27 @@ mod tests {
         assert_eq!(chunk.unwrap().pcm_mono_f32.len(), MAX_SAMPLES);
     }
 
+    #[test]
+    fn continuous_speech_emits_every_max_length() {
+        let mut chunker = Chunker::new(AudioSource::System);
+        // 500-sample frames divide the limits evenly, so emissions land exactly on max.
+        let frame = vec![0.1f32; 500];
+        let mut emitted = Vec::new();
+        for i in 0..600 {
+            let pts_ns = i as i128 * 31_250_000;
+            emitted.extend(chunker.push(&frame, pts_ns, true));
+        }
+        // 600 frames of speech is ~19 s: never silent, yet chunks keep coming.
+        assert_eq!(emitted.len(), 3);
+        assert!(
+            emitted
+                .iter()
+                .all(|chunk| chunk.pcm_mono_f32.len() == MAX_SAMPLES)
+        );
+        let step_ns = (MAX_SAMPLES - OVERLAP_SAMPLES) as i128 * 1_000_000_000 / 16_000;
+        assert_eq!(emitted[1].start_pts_ns - emitted[0].start_pts_ns, step_ns);
+    }
+
     #[test]
     fn overlap_retained_after_emit() {
         let mut chunker = Chunker::new(AudioSource::System);
@@ -166,4 +241,38 @@ mod tests {
         assert_eq!(chunk.source, AudioSource::Microphone);
         assert_eq!(chunk.sample_rate_hz, 16_000);
     }
+
+    #[test]
+    fn custom_limits_shorten_chunks() {
+        let limits = ChunkLimits {
+            min_samples: 8_000,
+            target_samples: 16_000,
+            max_samples: 24_000,
+            overlap_samples: 4_000,
+        };
+        let mut chunker = Chunker::with_limits(AudioSource::System, limits);
+        assert!(chunker.push(&vec![0.1f32; 16_000], 0, true).is_none());
+        let chunk = chunker.push(&[0.0f32; 512], 1_000_000, false).unwrap();
+        assert_eq!(chunk.pcm_mono_f32.len(), 16_512);
+        assert_eq!(chunker.buffered_samples(), 4_000);
+        let chunk = chunker.push(&vec![0.1f32; 20_000], 0, true).unwrap();
+        assert_eq!(chunk.pcm_mono_f32.len(), 24_000);
+    }
+
+    #[test]
+    fn partials_cover_new_speech_once() {
+        let mut chunker = Chunker::new(AudioSource::System);
+        let samples = vec![0.1f32; 8_000];
+        chunker.push(&samples, 0, true);
+        let partial = chunker.take_partial(8_000).unwrap();
+        assert_eq!(partial.pcm_mono_f32.len(), 8_000);
+        assert!(chunker.take_partial(8_000).is_none());
+
+        chunker.push(&samples, 0, true);
+        let partial = chunker.take_partial(8_000).unwrap();
+        assert_eq!(partial.start_pts_ns, 500_000_000);
+
+        chunker.push(&samples, 0, false);
+        assert!(chunker.take_partial(1).is_none());
+    }
 }
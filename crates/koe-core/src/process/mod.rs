@@ -1,27 +1,105 @@
+mod calibrate;
 pub mod chunker;
+mod partial;
 mod queue;
 pub mod resample;
+mod turns;
 pub mod vad;
 
 use crate::capture::AudioCapture;
+use crate::clock::SessionClock;
 use crate::error::ProcessError;
-use crate::types::{AudioFrame, AudioSource, CaptureStats};
-use chunker::Chunker;
+use crate::types::{AudioChunk, AudioFrame, AudioSource, CaptureStats};
+use calibrate::Calibrator;
+pub use calibrate::NoiseCalibration;
+use chunker::{ChunkLimits, Chunker};
+pub use partial::PartialFeed;
 pub use queue::ChunkRecvTimeoutError;
 use queue::{ChunkReceiver, ChunkSender, SendOutcome, chunk_channel};
 use resample::ResampleConverter;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
-use vad::VadDetector;
+use vad::{VadDetector, VadKind, VadParams};
 
 /// Resampler chunk size at 48 kHz (10 ms).
 const RESAMPLE_CHUNK: usize = 480;
+/// Capture sample rate fed into the pipeline.
+const INPUT_SAMPLE_RATE: i128 = 48_000;
+/// New speech (16 kHz samples) accumulated before handing out a partial, ~500 ms.
+const PARTIAL_SAMPLES: usize = 8_000;
+/// 16 kHz samples per millisecond.
+const SAMPLES_PER_MS: usize = 16;
+/// Duration of one 512-sample VAD frame at 16 kHz.
+const VAD_FRAME_MS: u32 = 32;
+
+/// VAD sensitivity and chunk sizing, in milliseconds. Lower thresholds and
+/// shorter chunks suit rapid back-and-forth; higher thresholds suit noisy rooms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProcessConfig {
+    pub vad: VadKind,
+    /// Speech probability (0..1) a frame must reach to count as speech.
+    pub vad_threshold: f32,
+    /// Continuous speech needed before a segment starts.
+    pub vad_min_speech_ms: u32,
+    /// Silence tolerated inside speech before the segment ends.
+    pub vad_hangover_ms: u32,
+    pub chunk_min_ms: u32,
+    /// A chunk is emitted at the first pause after this length.
+    pub chunk_target_ms: u32,
+    /// A chunk is emitted at this length even mid-speech.
+    pub chunk_max_ms: u32,
+    /// Audio repeated at the start of the next chunk.
+    pub chunk_overlap_ms: u32,
+    /// Split system/mixed chunks at likely speaker turns before transcription.
+    pub split_turns: bool,
+}
+
+impl Default for ProcessConfig {
+    fn default() -> Self {
+        Self {
+            vad: VadKind::Silero,
+            vad_threshold: 0.5,
+            vad_min_speech_ms: 224,
+            vad_hangover_ms: 320,
+            chunk_min_ms: 2_000,
+            chunk_target_ms: 4_000,
+            chunk_max_ms: 6_000,
+            chunk_overlap_ms: 1_000,
+            split_turns: true,
+        }
+    }
+}
+
+impl ProcessConfig {
+    fn vad_params(&self) -> VadParams {
+        let frames = |ms: u32| ms.div_ceil(VAD_FRAME_MS).max(1);
+        VadParams {
+            engine: self.vad,
+            threshold: self.vad_threshold,
+            min_speech_frames: frames(self.vad_min_speech_ms),
+            hangover_frames: frames(self.vad_hangover_ms),
+        }
+    }
+
+    fn chunk_limits(&self) -> ChunkLimits {
+        let samples = |ms: u32| ms as usize * SAMPLES_PER_MS;
+        ChunkLimits {
+            min_samples: samples(self.chunk_min_ms),
+            target_samples: samples(self.chunk_target_ms),
+            max_samples: samples(self.chunk_max_ms),
+            overlap_samples: samples(self.chunk_overlap_ms),
+        }
+    }
+}
 
 /// Audio processor: drains capture ring buffers, resamples, runs VAD, and emits chunks.
 pub struct AudioProcessor {
     running: Arc<AtomicBool>,
     paused: Arc<AtomicBool>,
+    reset_clock: Arc<AtomicBool>,
+    calibrate: Arc<AtomicBool>,
+    calibration: Arc<Mutex<Option<NoiseCalibration>>>,
     thread: Option<JoinHandle<()>>,
 }
 
@@ -33,19 +111,44 @@ struct StreamPipeline {
     chunker: Chunker,
     resample_remainder: Vec<f32>,
     vad_remainder: Vec<f32>,
+    partials: Option<PartialFeed>,
+    /// Cut emitted chunks at speaker turns; only sources that carry several voices.
+    split_turns: bool,
+    calibrator: Option<Calibrator>,
+    calibrated: Option<NoiseCalibration>,
 }
 
 impl StreamPipeline {
-    fn new(source: AudioSource) -> Result<Self, ProcessError> {
+    fn new(source: AudioSource, config: &ProcessConfig) -> Result<Self, ProcessError> {
         Ok(Self {
             resampler: ResampleConverter::new()?,
-            vad: VadDetector::new()?,
-            chunker: Chunker::new(source),
+            vad: VadDetector::with_params(config.vad_params())?,
+            chunker: Chunker::with_limits(source, config.chunk_limits()),
             resample_remainder: Vec::new(),
             vad_remainder: Vec::new(),
+            partials: None,
+            split_turns: config.split_turns && source != AudioSource::Microphone,
+            calibrator: None,
+            calibrated: None,
         })
     }
 
+    /// Measure the noise floor over the next few seconds, then retune the VAD
+    /// threshold relative to `base_threshold`.
+    fn start_calibration(&mut self, base_threshold: f32) {
+        self.calibrator = Some(Calibrator::new(base_threshold));
+        self.calibrated = None;
+    }
+
+    fn take_calibration(&mut self) -> Option<NoiseCalibration> {
+        self.calibrated.take()
+    }
+
+    fn with_partials(mut self, partials: Option<PartialFeed>) -> Self {
+        self.partials = partials;
+        self
+    }
+
     fn process(
         &mut self,
         input_48k: &[f32],
@@ -53,44 +156,9 @@ impl StreamPipeline {
         chunk_tx: &ChunkSender,
         stats: &CaptureStats,
     ) {
-        // Prepend remainder and feed complete chunks to resampler
-        self.resample_remainder.extend_from_slice(input_48k);
-
-        let full_chunks = (self.resample_remainder.len() / RESAMPLE_CHUNK) * RESAMPLE_CHUNK;
-        if full_chunks == 0 {
-            return;
-        }
-
-        let to_resample = &self.resample_remainder[..full_chunks];
-        let resampled = match self.resampler.process(to_resample) {
-            Ok(r) => r,
-            Err(_) => return,
-        };
-        self.resample_remainder.drain(..full_chunks);
-
-        // Feed resampled 16 kHz through VAD in 512-sample frames
-        self.vad_remainder.extend_from_slice(&resampled);
-
-        const VAD_FRAME: usize = 512;
-        let mut offset = 0;
-        while offset + VAD_FRAME <= self.vad_remainder.len() {
-            let frame = &self.vad_remainder[offset..offset + VAD_FRAME];
-            let is_speech = self.vad.process_frame(frame);
-
-            if let Some(chunk) = self.chunker.push(frame, pts_ns, is_speech) {
-                stats.inc_chunks_emitted();
-                match chunk_tx.send_drop_oldest(chunk) {
-                    SendOutcome::Sent => {}
-                    SendOutcome::DroppedOldest => {
-                        stats.inc_chunks_dropped();
-                    }
-                    SendOutcome::Disconnected => return,
-                }
-            }
-
-            offset += VAD_FRAME;
-        }
-        self.vad_remainder.drain(..offset);
+        self.feed(input_48k, pts_ns, None, &mut |chunk| {
+            send_chunk(chunk, chunk_tx, stats)
+        });
     }
 
     #[cfg(test)]
@@ -101,6 +169,20 @@ impl StreamPipeline {
         chunk_tx: &ChunkSender,
         stats: &CaptureStats,
         speech: bool,
+    ) {
+        self.feed(input_48k, pts_ns, Some(speech), &mut |chunk| {
+            send_chunk(chunk, chunk_tx, stats)
+        });
+    }
+
+    /// Resample, run VAD (unless `speech` overrides it), and hand emitted chunks to `emit`.
+    /// Stops early when `emit` returns false.
+    fn feed(
+        &mut self,
+        input_48k: &[f32],
+        pts_ns: i128,
+        speech: Option<bool>,
+        emit: &mut dyn FnMut(AudioChunk) -> bool,
     ) {
         // Prepend remainder and feed complete chunks to resampler
         self.resample_remainder.extend_from_slice(input_48k);
@@ -117,22 +199,38 @@ impl StreamPipeline {
         };
         self.resample_remainder.drain(..full_chunks);
 
+        // Feed resampled 16 kHz through VAD in 512-sample frames
         self.vad_remainder.extend_from_slice(&resampled);
 
         const VAD_FRAME: usize = 512;
         let mut offset = 0;
         while offset + VAD_FRAME <= self.vad_remainder.len() {
             let frame = &self.vad_remainder[offset..offset + VAD_FRAME];
+            if let Some(calibrator) = self.calibrator.as_mut()
+                && let Some(calibration) = calibrator.push(frame)
+            {
+                self.vad.set_threshold(calibration.vad_threshold);
+                self.calibrator = None;
+                self.calibrated = Some(calibration);
+            }
+            let is_speech = match speech {
+                Some(value) => value,
+                None => self.vad.process_frame(frame),
+            };
 
-            if let Some(chunk) = self.chunker.push(frame, pts_ns, speech) {
-                stats.inc_chunks_emitted();
-                match chunk_tx.send_drop_oldest(chunk) {
-                    SendOutcome::Sent => {}
-                    SendOutcome::DroppedOldest => {
-                        stats.inc_chunks_dropped();
+            if let Some(chunk) = self.chunker.push(frame, pts_ns, is_speech) {
+                if let Some(partials) = &self.partials {
+                    partials.discard(chunk.source);
+                }
+                for piece in self.pieces(chunk) {
+                    if !emit(piece) {
+                        return;
                     }
-                    SendOutcome::Disconnected => return,
                 }
+            } else if let Some(partials) = &self.partials
+                && let Some(partial) = self.chunker.take_partial(PARTIAL_SAMPLES)
+            {
+                partials.push(partial);
             }
 
             offset += VAD_FRAME;
@@ -142,20 +240,93 @@ impl StreamPipeline {
 
     fn flush(&mut self, chunk_tx: &ChunkSender, stats: &CaptureStats) {
         if let Some(chunk) = self.chunker.flush() {
-            stats.inc_chunks_emitted();
-            if chunk_tx.send_drop_oldest(chunk) == SendOutcome::DroppedOldest {
-                stats.inc_chunks_dropped();
+            for piece in self.pieces(chunk) {
+                send_chunk(piece, chunk_tx, stats);
             }
         }
     }
+
+    fn pieces(&self, chunk: AudioChunk) -> Vec<AudioChunk> {
+        if self.split_turns {
+            turns::split_at_turns(chunk)
+        } else {
+            vec![chunk]
+        }
+    }
+}
+
+fn send_chunk(chunk: AudioChunk, chunk_tx: &ChunkSender, stats: &CaptureStats) -> bool {
+    stats.inc_chunks_emitted();
+    match chunk_tx.send_drop_oldest(chunk) {
+        SendOutcome::Sent => true,
+        SendOutcome::DroppedOldest => {
+            stats.inc_chunks_dropped();
+            true
+        }
+        SendOutcome::Disconnected => false,
+    }
+}
+
+/// Map a frame's capture PTS onto meeting time, starting the clock on the first
+/// frame and closing any pause that ended with this frame.
+fn meeting_pts(
+    clock: &mut SessionClock,
+    frame: &AudioFrame,
+    last_end_ns: &mut Option<i128>,
+) -> i128 {
+    clock.start(frame.pts_ns);
+    clock.resume(frame.pts_ns);
+    let rate = i128::from(frame.sample_rate_hz.max(1));
+    let end_ns = frame.pts_ns + frame.samples_f32.len() as i128 * 1_000_000_000 / rate;
+    *last_end_ns = Some(last_end_ns.map_or(end_ns, |last| last.max(end_ns)));
+    clock.meeting_ns(frame.pts_ns)
+}
+
+/// Run pre-recorded 48 kHz mono audio through the resample/VAD/chunker pipeline.
+///
+/// Unlike the live processor nothing is dropped: every chunk is returned in order,
+/// with PTS derived from the sample offset.
+pub fn chunk_offline(
+    samples_48k: &[f32],
+    source: AudioSource,
+    config: &ProcessConfig,
+) -> Result<Vec<AudioChunk>, ProcessError> {
+    let mut pipeline = StreamPipeline::new(source, config)?;
+    let mut chunks = Vec::new();
+    for (index, block) in samples_48k.chunks(RESAMPLE_CHUNK).enumerate() {
+        let pts_ns = (index * RESAMPLE_CHUNK) as i128 * 1_000_000_000 / INPUT_SAMPLE_RATE;
+        pipeline.feed(block, pts_ns, None, &mut |chunk| {
+            chunks.push(chunk);
+            true
+        });
+    }
+    // Pad the tail so the resampler sees a full block, then flush the chunker
+    let tail = samples_48k.len() % RESAMPLE_CHUNK;
+    if tail != 0 {
+        let pts_ns = samples_48k.len() as i128 * 1_000_000_000 / INPUT_SAMPLE_RATE;
+        let padding = vec![0.0f32; RESAMPLE_CHUNK - tail];
+        pipeline.feed(&padding, pts_ns, None, &mut |chunk| {
+            chunks.push(chunk);
+            true
+        });
+    }
+    if let Some(chunk) = pipeline.chunker.flush() {
+        chunks.extend(pipeline.pieces(chunk));
+    }
+    Ok(chunks)
 }
 
 impl AudioProcessor {
     /// Start the processor thread. Returns a receiver for audio chunks.
+    ///
+    /// When `partials` is set, in-progress speech is also published there for
+    /// streaming partial transcription.
     pub fn start(
         mut capture: Box<dyn AudioCapture>,
         stats: CaptureStats,
         raw_sink: Option<RawAudioSink>,
+        partials: Option<PartialFeed>,
+        config: ProcessConfig,
     ) -> Result<(Self, ChunkReceiver), ProcessError> {
         capture.start().map_err(ProcessError::Capture)?;
 
@@ -164,24 +335,48 @@ impl AudioProcessor {
         let running_clone = Arc::clone(&running);
         let paused = Arc::new(AtomicBool::new(false));
         let paused_clone = Arc::clone(&paused);
-
-        let mut system_pipeline = StreamPipeline::new(AudioSource::System)?;
-        let mut mic_pipeline = StreamPipeline::new(AudioSource::Microphone)?;
+        let reset_clock = Arc::new(AtomicBool::new(false));
+        let reset_clock_clone = Arc::clone(&reset_clock);
+        let calibrate = Arc::new(AtomicBool::new(false));
+        let calibrate_clone = Arc::clone(&calibrate);
+        let calibration = Arc::new(Mutex::new(None));
+        let calibration_clone = Arc::clone(&calibration);
+
+        let mut system_pipeline =
+            StreamPipeline::new(AudioSource::System, &config)?.with_partials(partials.clone());
+        let mut mic_pipeline =
+            StreamPipeline::new(AudioSource::Microphone, &config)?.with_partials(partials);
 
         let thread = thread::Builder::new()
             .name("koe-audio-processor".into())
             .spawn(move || {
                 let mut raw_sink = raw_sink;
+                let mut clock = SessionClock::new();
+                let mut last_end_ns = None;
                 while running_clone.load(Ordering::Relaxed) {
                     let mut had_data = false;
+                    capture.poll();
+
+                    if reset_clock_clone.swap(false, Ordering::Relaxed) {
+                        clock.reset();
+                        last_end_ns = None;
+                    }
+                    if calibrate_clone.swap(false, Ordering::Relaxed) {
+                        mic_pipeline.start_calibration(config.vad_threshold);
+                    }
 
                     if paused_clone.load(Ordering::Relaxed) {
-                        if capture.try_recv_system().is_some() {
+                        if let Some(end) = last_end_ns {
+                            clock.pause(end);
+                        }
+                        if let Some(frame) = capture.try_recv_system() {
                             stats.inc_frames_captured();
+                            stats.record_source_frame(AudioSource::System, &frame.samples_f32);
                             had_data = true;
                         }
-                        if capture.try_recv_mic().is_some() {
+                        if let Some(frame) = capture.try_recv_mic() {
                             stats.inc_frames_captured();
+                            stats.record_source_frame(AudioSource::Microphone, &frame.samples_f32);
                             had_data = true;
                         }
 
@@ -193,24 +388,28 @@ impl AudioProcessor {
 
                     if let Some(frame) = capture.try_recv_system() {
                         stats.inc_frames_captured();
+                        stats.record_source_frame(AudioSource::System, &frame.samples_f32);
                         if let Some(ref mut sink) = raw_sink {
                             sink(AudioSource::System, &frame);
                         }
-                        system_pipeline.process(
-                            &frame.samples_f32,
-                            frame.pts_ns,
-                            &chunk_tx,
-                            &stats,
-                        );
+                        let pts_ns = meeting_pts(&mut clock, &frame, &mut last_end_ns);
+                        system_pipeline.process(&frame.samples_f32, pts_ns, &chunk_tx, &stats);
                         had_data = true;
                     }
 
                     if let Some(frame) = capture.try_recv_mic() {
                         stats.inc_frames_captured();
+                        stats.record_source_frame(AudioSource::Microphone, &frame.samples_f32);
                         if let Some(ref mut sink) = raw_sink {
                             sink(AudioSource::Microphone, &frame);
                         }
-                        mic_pipeline.process(&frame.samples_f32, frame.pts_ns, &chunk_tx, &stats);
+                        let pts_ns = meeting_pts(&mut clock, &frame, &mut last_end_ns);
+                        mic_pipeline.process(&frame.samples_f32, pts_ns, &chunk_tx, &stats);
+                        if let Some(result) = mic_pipeline.take_calibration()
+                            && let Ok(mut slot) = calibration_clone.lock()
+                        {
+                            *slot = Some(result);
+                        }
                         had_data = true;
                     }
 
@@ -230,6 +429,9 @@ impl AudioProcessor {
             Self {
                 running,
                 paused,
+                reset_clock,
+                calibrate,
+                calibration,
                 thread: Some(thread),
             },
             chunk_rx,
@@ -248,6 +450,22 @@ impl AudioProcessor {
         self.paused.load(Ordering::Relaxed)
     }
 
+    /// Restart meeting time at zero with the next captured frame.
+    pub fn reset_clock(&self) {
+        self.reset_clock.store(true, Ordering::Relaxed);
+    }
+
+    /// Measure the microphone noise floor over the next ~3 s of captured audio
+    /// and retune its VAD threshold; the result is returned by `take_calibration`.
+    pub fn calibrate(&self) {
+        self.calibrate.store(true, Ordering::Relaxed);
+    }
+
+    /// The finished noise calibration, once per `calibrate` call.
+    pub fn take_calibration(&self) -> Option<NoiseCalibration> {
+        self.calibration.lock().ok()?.take()
+    }
+
     /// Signal the processor to stop and wait for the thread to finish.
     pub fn stop(&mut self) {
         self.running.store(false, Ordering::Relaxed);
@@ -265,12 +483,13 @@ impl Drop for AudioProcessor {
 
 #[cfg(test)]
 mod tests {
-    use super::{RESAMPLE_CHUNK, StreamPipeline, chunk_channel};
+    use super::{ProcessConfig, RESAMPLE_CHUNK, StreamPipeline, chunk_channel, chunk_offline};
     use crate::types::{AudioSource, CaptureStats};
 
     #[test]
     fn system_audio_increments_chunk_counters() {
-        let mut pipeline = StreamPipeline::new(AudioSource::System).unwrap();
+        let mut pipeline =
+            StreamPipeline::new(AudioSource::System, &ProcessConfig::default()).unwrap();
         let stats = CaptureStats::new();
         let (chunk_tx, _chunk_rx) = chunk_channel(4);
 
@@ -283,7 +502,8 @@ mod tests {
 
     #[test]
     fn mic_audio_increments_chunk_counters() {
-        let mut pipeline = StreamPipeline::new(AudioSource::Microphone).unwrap();
+        let mut pipeline =
+            StreamPipeline::new(AudioSource::Microphone, &ProcessConfig::default()).unwrap();
         let stats = CaptureStats::new();
         let (chunk_tx, _chunk_rx) = chunk_channel(4);
 
@@ -296,7 +516,8 @@ mod tests {
 
     #[test]
     fn backpressure_increments_drop_counter() {
-        let mut pipeline = StreamPipeline::new(AudioSource::System).unwrap();
+        let mut pipeline =
+            StreamPipeline::new(AudioSource::System, &ProcessConfig::default()).unwrap();
         let stats = CaptureStats::new();
         let (chunk_tx, _chunk_rx) = chunk_channel(1);
 
@@ -307,4 +528,23 @@ mod tests {
         pipeline.process_with_speech(&input, 0, &chunk_tx, &stats, true);
         assert!(stats.chunks_dropped() > 0);
     }
+
+    #[test]
+    fn offline_chunking_keeps_every_chunk_in_order() {
+        let input = vec![0.0f32; 48_000 * 14 + 100];
+        let chunks = chunk_offline(&input, AudioSource::Mixed, &ProcessConfig::default()).unwrap();
+
+        assert!(chunks.len() >= 2);
+        assert!(chunks[0].start_pts_ns < 100_000_000);
+        assert!(
+            chunks
+                .windows(2)
+                .all(|pair| pair[0].start_pts_ns < pair[1].start_pts_ns)
+        );
+        assert!(
+            chunks
+                .iter()
+                .all(|chunk| chunk.source == AudioSource::Mixed)
+        );
+    }
 }
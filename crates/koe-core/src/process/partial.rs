@@ -0,0 +1,72 @@
+use crate::types::{AudioChunk, AudioSource};
+use std::sync::{Arc, Mutex};
+
+/// Side channel carrying in-progress speech audio for streaming partial transcription.
+///
+/// Unlike the chunk queue this never drops audio: contiguous deltas from the same
+/// source are merged, and a source's pending audio is discarded once its final
+/// chunk is emitted, which bounds the backlog to one chunk per source.
+#[derive(Clone, Default)]
+pub struct PartialFeed {
+    pending: Arc<Mutex<Vec<AudioChunk>>>,
+}
+
+impl PartialFeed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn push(&self, chunk: AudioChunk) {
+        let mut pending = self.pending.lock().unwrap();
+        if let Some(last) = pending.iter_mut().rev().find(|c| c.source == chunk.source)
+            && end_pts_ns(last) == chunk.start_pts_ns
+        {
+            last.pcm_mono_f32.extend_from_slice(&chunk.pcm_mono_f32);
+            return;
+        }
+        pending.push(chunk);
+    }
+
+    pub(crate) fn discard(&self, source: AudioSource) {
+        self.pending.lock().unwrap().retain(|c| c.source != source);
+    }
+
+    /// Take all pending partial audio, oldest first.
+    pub fn take(&self) -> Vec<AudioChunk> {
+        std::mem::take(&mut *self.pending.lock().unwrap())
+    }
+}
+
+fn end_pts_ns(chunk: &AudioChunk) -> i128 {
+    chunk.start_pts_ns
+        + (chunk.pcm_mono_f32.len() as i128 * 1_000_000_000) / chunk.sample_rate_hz.max(1) as i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PartialFeed;
+    use crate::types::{AudioChunk, AudioSource};
+
+    fn chunk(source: AudioSource, start_pts_ns: i128, len: usize) -> AudioChunk {
+        AudioChunk {
+            source,
+            start_pts_ns,
+            sample_rate_hz: 16_000,
+            pcm_mono_f32: vec![0.0; len],
+        }
+    }
+
+    #[test]
+    fn merges_contiguous_deltas_and_discards_per_source() {
+        let feed = PartialFeed::new();
+        feed.push(chunk(AudioSource::System, 0, 8_000));
+        feed.push(chunk(AudioSource::Microphone, 0, 8_000));
+        feed.push(chunk(AudioSource::System, 500_000_000, 8_000));
+        feed.discard(AudioSource::Microphone);
+
+        let pending = feed.take();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].pcm_mono_f32.len(), 16_000);
+        assert!(feed.take().is_empty());
+    }
+}
@@ -0,0 +1,184 @@
+use crate::types::AudioChunk;
+
+/// Analysis frame: 32 ms at 16 kHz, matching the VAD frame.
+const FRAME: usize = 512;
+/// Lag range for pitch search, 70..400 Hz at 16 kHz.
+const MIN_LAG: usize = 40;
+const MAX_LAG: usize = 228;
+/// Normalized autocorrelation a frame needs to count as voiced.
+const VOICED_CORRELATION: f32 = 0.5;
+/// A pause is a frame this far below the chunk's speech level.
+const PAUSE_BELOW_SPEECH_DB: f32 = 20.0;
+/// Consecutive quiet frames that make a pause a turn candidate (~96 ms).
+const MIN_PAUSE_FRAMES: usize = 3;
+/// Shortest piece on either side of a cut (~1 s), so whisper keeps enough context.
+const MIN_PIECE_FRAMES: usize = 31;
+/// Voiced frames each side needs for a reliable pitch estimate.
+const MIN_VOICED_FRAMES: usize = 5;
+/// Median pitch difference, in octaves, that marks a different speaker (~4 semitones).
+const TURN_PITCH_OCTAVES: f32 = 0.33;
+
+struct FrameFeatures {
+    energy_db: f32,
+    pitch_hz: Option<f32>,
+}
+
+/// Split a chunk at likely speaker turns: a pause with clearly different median
+/// pitch on either side. Chunks without a confident turn come back whole.
+pub fn split_at_turns(chunk: AudioChunk) -> Vec<AudioChunk> {
+    let frames: Vec<FrameFeatures> = chunk
+        .pcm_mono_f32
+        .chunks_exact(FRAME)
+        .map(|frame| frame_features(frame, chunk.sample_rate_hz))
+        .collect();
+    let cuts = turn_frames(&frames);
+    if cuts.is_empty() {
+        return vec![chunk];
+    }
+
+    let rate = i128::from(chunk.sample_rate_hz.max(1));
+    let mut pieces = Vec::with_capacity(cuts.len() + 1);
+    let mut from = 0;
+    for end in cuts
+        .iter()
+        .map(|cut| cut * FRAME)
+        .chain([chunk.pcm_mono_f32.len()])
+    {
+        pieces.push(AudioChunk {
+            source: chunk.source,
+            start_pts_ns: chunk.start_pts_ns + from as i128 * 1_000_000_000 / rate,
+            sample_rate_hz: chunk.sample_rate_hz,
+            pcm_mono_f32: chunk.pcm_mono_f32[from..end].to_vec(),
+        });
+        from = end;
+    }
+    pieces
+}
+
+/// Frame indexes to cut at, in order.
+fn turn_frames(frames: &[FrameFeatures]) -> Vec<usize> {
+    let mut levels: Vec<f32> = frames.iter().map(|frame| frame.energy_db).collect();
+    if levels.len() < MIN_PIECE_FRAMES * 2 {
+        return Vec::new();
+    }
+    levels.sort_by(f32::total_cmp);
+    let speech_db = levels[levels.len() * 9 / 10];
+    let pause_db = speech_db - PAUSE_BELOW_SPEECH_DB;
+
+    // Middle of each quiet run long enough to be a pause.
+    let mut candidates = Vec::new();
+    let mut run_start = None;
+    for (index, frame) in frames.iter().enumerate() {
+        match (frame.energy_db < pause_db, run_start) {
+            (true, None) => run_start = Some(index),
+            (false, Some(start)) => {
+                if index - start >= MIN_PAUSE_FRAMES {
+                    candidates.push((start + index) / 2);
+                }
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    let mut cuts = Vec::new();
+    let mut piece_start = 0;
+    for (position, &candidate) in candidates.iter().enumerate() {
+        let next = candidates
+            .get(position + 1)
+            .copied()
+            .unwrap_or(frames.len());
+        if candidate - piece_start < MIN_PIECE_FRAMES || frames.len() - candidate < MIN_PIECE_FRAMES
+        {
+            continue;
+        }
+        let (Some(before), Some(after)) = (
+            median_pitch(&frames[piece_start..candidate]),
+            median_pitch(&frames[candidate..next]),
+        ) else {
+            continue;
+        };
+        if (before / after).log2().abs() >= TURN_PITCH_OCTAVES {
+            cuts.push(candidate);
+            piece_start = candidate;
+        }
+    }
+    cuts
+}
+
+fn frame_features(frame: &[f32], sample_rate_hz: u32) -> FrameFeatures {
+    let energy = frame.iter().map(|sample| sample * sample).sum::<f32>();
+    let energy_db = 10.0 * (energy / frame.len() as f32).max(1e-12).log10();
+    FrameFeatures {
+        energy_db,
+        pitch_hz: pitch(frame, energy, sample_rate_hz),
+    }
+}
+
+/// Autocorrelation pitch estimate; `None` for unvoiced or silent frames.
+fn pitch(frame: &[f32], energy: f32, sample_rate_hz: u32) -> Option<f32> {
+    if energy <= f32::EPSILON {
+        return None;
+    }
+    let (lag, correlation) = (MIN_LAG..MAX_LAG.min(frame.len() / 2))
+        .map(|lag| {
+            let sum: f32 = frame.iter().zip(&frame[lag..]).map(|(a, b)| a * b).sum();
+            (lag, sum / energy)
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+    (correlation >= VOICED_CORRELATION).then(|| sample_rate_hz as f32 / lag as f32)
+}
+
+fn median_pitch(frames: &[FrameFeatures]) -> Option<f32> {
+    let mut pitches: Vec<f32> = frames.iter().filter_map(|frame| frame.pitch_hz).collect();
+    if pitches.len() < MIN_VOICED_FRAMES {
+        return None;
+    }
+    pitches.sort_by(f32::total_cmp);
+    Some(pitches[pitches.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_at_turns;
+    use crate::types::{AudioChunk, AudioSource};
+
+    fn tone(hz: f32, seconds: f32) -> Vec<f32> {
+        (0..(16_000.0 * seconds) as usize)
+            .map(|i| 0.3 * (2.0 * std::f32::consts::PI * hz * i as f32 / 16_000.0).sin())
+            .collect()
+    }
+
+    fn chunk(pcm: Vec<f32>) -> AudioChunk {
+        AudioChunk {
+            source: AudioSource::System,
+            start_pts_ns: 2_000_000_000,
+            sample_rate_hz: 16_000,
+            pcm_mono_f32: pcm,
+        }
+    }
+
+    #[test]
+    fn splits_at_pause_between_different_voices_only() {
+        let mut pcm = tone(110.0, 2.0);
+        pcm.extend(vec![0.0; 4_800]);
+        pcm.extend(tone(220.0, 2.0));
+        let pieces = split_at_turns(chunk(pcm.clone()));
+        assert_eq!(pieces.len(), 2);
+        assert_eq!(
+            pieces.iter().map(|p| p.pcm_mono_f32.len()).sum::<usize>(),
+            pcm.len()
+        );
+        let offset_ns = pieces[1].start_pts_ns - pieces[0].start_pts_ns;
+        assert_eq!(
+            offset_ns,
+            pieces[0].pcm_mono_f32.len() as i128 * 1_000_000_000 / 16_000
+        );
+
+        // The same voice pausing mid-sentence stays one chunk.
+        let mut same = tone(110.0, 2.0);
+        same.extend(vec![0.0; 4_800]);
+        same.extend(tone(112.0, 2.0));
+        assert_eq!(split_at_turns(chunk(same)).len(), 1);
+    }
+}
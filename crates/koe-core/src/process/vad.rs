@@ -6,6 +6,116 @@ const CHUNK_SIZE: usize = 512;
 const THRESHOLD: f32 = 0.5;
 const MIN_SPEECH_FRAMES: u32 = 7; // ~224 ms at 512 samples / 16 kHz
 const HANGOVER_FRAMES: u32 = 10; // ~320 ms
+/// Quietest level the energy engine's noise floor can settle at.
+const ENERGY_FLOOR_MIN_DB: f32 = -90.0;
+/// Noise floor before any audio; the first quiet frame pulls it down.
+const ENERGY_FLOOR_START_DB: f32 = 0.0;
+/// Noise floor rise per frame while the level stays above it, so speech does not drag it up.
+const ENERGY_FLOOR_RISE_DB: f32 = 0.05;
+/// Level above the noise floor where the energy engine reports 0.5.
+const ENERGY_MIDPOINT_DB: f32 = 9.0;
+/// Spread around the midpoint mapping SNR onto 0..1.
+const ENERGY_SPAN_DB: f32 = 12.0;
+
+/// Source of per-frame speech probabilities for the VAD state machine.
+pub trait VadEngine: Send {
+    /// Speech probability in `0.0..=1.0` for one 512-sample frame at 16 kHz.
+    fn speech_probability(&mut self, frame_512: &[f32]) -> f32;
+    fn reset(&mut self);
+}
+
+/// Which `VadEngine` the pipeline runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VadKind {
+    /// Silero neural model; robust to noise and music.
+    #[default]
+    Silero,
+    /// Level above an adaptive noise floor; picks up quiet speakers in quiet rooms.
+    Energy,
+}
+
+impl VadKind {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "silero" => Some(Self::Silero),
+            "energy" => Some(Self::Energy),
+            _ => None,
+        }
+    }
+
+    fn engine(self) -> Result<Box<dyn VadEngine>, ProcessError> {
+        match self {
+            Self::Silero => Ok(Box::new(SileroEngine::new()?)),
+            Self::Energy => Ok(Box::new(EnergyEngine::new())),
+        }
+    }
+}
+
+struct SileroEngine {
+    vad: VoiceActivityDetector,
+}
+
+impl SileroEngine {
+    fn new() -> Result<Self, ProcessError> {
+        let vad = VoiceActivityDetector::builder()
+            .sample_rate(SAMPLE_RATE)
+            .chunk_size(CHUNK_SIZE)
+            .build()
+            .map_err(|e| ProcessError::VadInit(e.to_string()))?;
+        Ok(Self { vad })
+    }
+}
+
+impl VadEngine for SileroEngine {
+    fn speech_probability(&mut self, frame_512: &[f32]) -> f32 {
+        self.vad.predict(frame_512.iter().copied())
+    }
+
+    fn reset(&mut self) {
+        self.vad.reset();
+    }
+}
+
+/// Frame level relative to a noise floor that drops immediately and rises slowly.
+pub struct EnergyEngine {
+    noise_floor_db: f32,
+}
+
+impl EnergyEngine {
+    pub fn new() -> Self {
+        Self {
+            noise_floor_db: ENERGY_FLOOR_START_DB,
+        }
+    }
+}
+
+impl Default for EnergyEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VadEngine for EnergyEngine {
+    fn speech_probability(&mut self, frame_512: &[f32]) -> f32 {
+        if frame_512.is_empty() {
+            return 0.0;
+        }
+        let mean_square =
+            frame_512.iter().map(|sample| sample * sample).sum::<f32>() / frame_512.len() as f32;
+        let level_db = (10.0 * mean_square.max(1e-12).log10()).max(ENERGY_FLOOR_MIN_DB);
+        self.noise_floor_db = if level_db < self.noise_floor_db {
+            level_db
+        } else {
+            (self.noise_floor_db + ENERGY_FLOOR_RISE_DB).min(level_db)
+        };
+        let snr_db = level_db - self.noise_floor_db;
+        ((snr_db - ENERGY_MIDPOINT_DB) / ENERGY_SPAN_DB + 0.5).clamp(0.0, 1.0)
+    }
+
+    fn reset(&mut self) {
+        self.noise_floor_db = ENERGY_FLOOR_START_DB;
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum State {
@@ -14,9 +124,34 @@ enum State {
     Hangover,
 }
 
-/// Silero VAD wrapper with a three-state machine: Silence -> Speech -> Hangover -> Silence.
+/// Tuning for the speech state machine, in 512-sample frames (32 ms each).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VadParams {
+    pub engine: VadKind,
+    /// Speech probability a frame must reach to count as speech.
+    pub threshold: f32,
+    /// Consecutive speech frames before speech is confirmed.
+    pub min_speech_frames: u32,
+    /// Non-speech frames tolerated before speech ends.
+    pub hangover_frames: u32,
+}
+
+impl Default for VadParams {
+    fn default() -> Self {
+        Self {
+            engine: VadKind::Silero,
+            threshold: THRESHOLD,
+            min_speech_frames: MIN_SPEECH_FRAMES,
+            hangover_frames: HANGOVER_FRAMES,
+        }
+    }
+}
+
+/// Speech detector: a `VadEngine` behind a three-state machine,
+/// Silence -> Speech -> Hangover -> Silence.
 pub struct VadDetector {
-    vad: VoiceActivityDetector,
+    engine: Box<dyn VadEngine>,
+    params: VadParams,
     state: State,
     speech_count: u32,
     hangover_count: u32,
@@ -24,14 +159,13 @@ pub struct VadDetector {
 
 impl VadDetector {
     pub fn new() -> Result<Self, ProcessError> {
-        let vad = VoiceActivityDetector::builder()
-            .sample_rate(SAMPLE_RATE)
-            .chunk_size(CHUNK_SIZE)
-            .build()
-            .map_err(|e| ProcessError::VadInit(e.to_string()))?;
+        Self::with_params(VadParams::default())
+    }
 
+    pub fn with_params(params: VadParams) -> Result<Self, ProcessError> {
         Ok(Self {
-            vad,
+            engine: params.engine.engine()?,
+            params,
             state: State::Silence,
             speech_count: 0,
             hangover_count: 0,
@@ -40,15 +174,15 @@ impl VadDetector {
 
     /// Process a 512-sample frame at 16 kHz. Returns true when in confirmed speech.
     pub fn process_frame(&mut self, frame_512: &[f32]) -> bool {
-        let prob = self.vad.predict(frame_512.iter().copied());
+        let prob = self.engine.speech_probability(frame_512);
 
-        let is_speech_frame = prob >= THRESHOLD;
+        let is_speech_frame = prob >= self.params.threshold;
 
         match self.state {
             State::Silence => {
                 if is_speech_frame {
                     self.speech_count += 1;
-                    if self.speech_count >= MIN_SPEECH_FRAMES {
+                    if self.speech_count >= self.params.min_speech_frames {
                         self.state = State::Speech;
                         self.speech_count = 0;
                     }
@@ -68,7 +202,7 @@ impl VadDetector {
                     self.hangover_count = 0;
                 } else {
                     self.hangover_count += 1;
-                    if self.hangover_count >= HANGOVER_FRAMES {
+                    if self.hangover_count >= self.params.hangover_frames {
                         self.state = State::Silence;
                         self.hangover_count = 0;
                     }
@@ -79,8 +213,13 @@ impl VadDetector {
         matches!(self.state, State::Speech | State::Hangover)
     }
 
+    /// Change the speech probability threshold, e.g. after noise calibration.
+    pub fn set_threshold(&mut self, threshold: f32) {
+        self.params.threshold = threshold;
+    }
+
     pub fn reset(&mut self) {
-        self.vad.reset();
+        self.engine.reset();
         self.state = State::Silence;
         self.speech_count = 0;
         self.hangover_count = 0;
@@ -165,6 +304,22 @@ mod tests {
         assert_eq!(vad.state, State::Silence);
     }
 
+    #[test]
+    fn energy_engine_follows_noise_floor() {
+        let mut engine = EnergyEngine::new();
+        let hum: Vec<f32> = (0..CHUNK_SIZE)
+            .map(|i| if i % 2 == 0 { 0.001 } else { -0.001 })
+            .collect();
+        for _ in 0..20 {
+            assert!(engine.speech_probability(&hum) < 0.1);
+        }
+        let quiet_voice: Vec<f32> = hum.iter().map(|sample| sample * 12.0).collect();
+        assert!(engine.speech_probability(&quiet_voice) > THRESHOLD);
+        assert_eq!(engine.speech_probability(&silence_frame()), 0.0);
+        assert_eq!(VadKind::parse("energy"), Some(VadKind::Energy));
+        assert_eq!(VadKind::parse("webrtc"), None);
+    }
+
     #[test]
     fn reset_clears_state() {
         let mut vad = VadDetector::new().unwrap();
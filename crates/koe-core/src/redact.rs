@@ -0,0 +1,243 @@
+use crate::types::TranscriptSegment;
+
+const EMAIL_MASK: &str = "[email]";
+const PHONE_MASK: &str = "[phone]";
+const CARD_MASK: &str = "[card]";
+const KEYWORD_MASK: &str = "[redacted]";
+/// Characters allowed between the digits of a phone or card number.
+const NUMBER_SEPARATORS: &[char] = &[' ', '-', '.', '(', ')'];
+
+/// Masks personal data in transcript text before it reaches the ledger, so
+/// the live view, exports, and summaries never see it. Audio is not redacted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Redactor {
+    pub emails: bool,
+    /// 10-15 digits, or 7-9 digits grouped with spaces, dashes, or parentheses.
+    pub phones: bool,
+    /// 13-19 digits that pass the Luhn check.
+    pub cards: bool,
+    /// Words or phrases masked case-insensitively on word boundaries.
+    pub keywords: Vec<String>,
+}
+
+impl Redactor {
+    /// Mask `segment`'s text; true when anything was redacted.
+    pub fn apply(&self, segment: &mut TranscriptSegment) -> bool {
+        let redacted = self.redact(&segment.text);
+        if redacted == segment.text {
+            return false;
+        }
+        segment.text = redacted;
+        true
+    }
+
+    pub fn redact(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        if self.emails {
+            text = mask_emails(&text);
+        }
+        if self.phones || self.cards {
+            text = self.mask_numbers(&text);
+        }
+        for keyword in &self.keywords {
+            text = mask_keyword(&text, keyword.trim());
+        }
+        text
+    }
+
+    fn mask_numbers(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut copied = 0;
+        let mut chars = text.char_indices().peekable();
+        let mut previous: Option<char> = None;
+        while let Some((start, c)) = chars.next() {
+            let opens_number = c.is_ascii_digit()
+                || (matches!(c, '+' | '(')
+                    && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit()));
+            if !opens_number || previous.is_some_and(char::is_alphanumeric) {
+                previous = Some(c);
+                continue;
+            }
+
+            let mut digits: Vec<u32> = c.to_digit(10).into_iter().collect();
+            let mut end = if digits.is_empty() { start } else { start + 1 };
+            // Separators only count once a digit follows them.
+            let mut separators = String::new();
+            let mut pending = String::new();
+            previous = Some(c);
+            while let Some(&(index, next)) = chars.peek() {
+                if let Some(digit) = next.to_digit(10) {
+                    digits.push(digit);
+                    end = index + 1;
+                    separators.push_str(&pending);
+                    pending.clear();
+                } else if NUMBER_SEPARATORS.contains(&next) && pending.len() < 2 {
+                    pending.push(next);
+                } else {
+                    break;
+                }
+                previous = Some(next);
+                chars.next();
+            }
+            if text[end..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphanumeric)
+            {
+                continue;
+            }
+
+            let grouped = separators.contains([' ', '-', '(']);
+            let decimal = separators.matches('.').count() == 1 && !grouped;
+            let mask = if self.cards && (13..=19).contains(&digits.len()) && luhn_valid(&digits) {
+                Some(CARD_MASK)
+            } else if self.phones
+                && !decimal
+                && ((10..=15).contains(&digits.len())
+                    || (grouped && (7..=9).contains(&digits.len())))
+            {
+                Some(PHONE_MASK)
+            } else {
+                None
+            };
+            if let Some(mask) = mask {
+                out.push_str(&text[copied..start]);
+                out.push_str(mask);
+                copied = end;
+            }
+        }
+        out.push_str(&text[copied..]);
+        out
+    }
+}
+
+fn mask_emails(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for piece in text.split_inclusive(char::is_whitespace) {
+        let word = piece.trim_end();
+        let core = word
+            .trim_start_matches(['(', '<', '"', '\''])
+            .trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '>', '"', '\'']);
+        if core.is_empty() || !is_email(core) {
+            out.push_str(piece);
+            continue;
+        }
+        let lead = word.len() - word.trim_start_matches(['(', '<', '"', '\'']).len();
+        out.push_str(&piece[..lead]);
+        out.push_str(EMAIL_MASK);
+        out.push_str(&piece[lead + core.len()..]);
+    }
+    out
+}
+
+fn is_email(word: &str) -> bool {
+    let Some((local, domain)) = word.split_once('@') else {
+        return false;
+    };
+    let local_ok = !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "._%+-".contains(c));
+    let labels: Vec<&str> = domain.split('.').collect();
+    let domain_ok = labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        });
+    local_ok && domain_ok
+}
+
+fn mask_keyword(text: &str, keyword: &str) -> String {
+    if keyword.is_empty() {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut index = 0;
+    while index + keyword.len() <= text.len() {
+        let end = index + keyword.len();
+        let matched = text.is_char_boundary(index)
+            && text.is_char_boundary(end)
+            && text[index..end].eq_ignore_ascii_case(keyword)
+            && !text[..index]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphanumeric)
+            && !text[end..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphanumeric);
+        if matched {
+            out.push_str(&text[copied..index]);
+            out.push_str(KEYWORD_MASK);
+            copied = end;
+            index = end;
+        } else {
+            index += 1;
+        }
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+fn luhn_valid(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(position, &digit)| match position % 2 {
+            0 => digit,
+            _ if digit * 2 > 9 => digit * 2 - 9,
+            _ => digit * 2,
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Redactor;
+    use crate::types::TranscriptSegment;
+
+    #[test]
+    fn masks_emails_numbers_and_keywords() {
+        let redactor = Redactor {
+            emails: true,
+            phones: true,
+            cards: true,
+            keywords: vec!["Project Falcon".to_string()],
+        };
+        assert_eq!(
+            redactor.redact("Mail (jane.doe+koe@example.co.uk), not @here or a@b."),
+            "Mail ([email]), not @here or a@b."
+        );
+        assert_eq!(
+            redactor.redact("Call +1 (555) 123-4567 or 555-1234 today."),
+            "Call [phone] or [phone] today."
+        );
+        assert_eq!(
+            redactor.redact("Card 4111 1111 1111 1111, ref 4111 1111 1111 1112."),
+            "Card [card], ref 4111 1111 1111 1112."
+        );
+        assert_eq!(
+            redactor.redact("Revenue was 1234567 in 2024, pi is 3.14159265."),
+            "Revenue was 1234567 in 2024, pi is 3.14159265."
+        );
+        assert_eq!(
+            redactor.redact("project falcon ships; Project Falconry does not."),
+            "[redacted] ships; Project Falconry does not."
+        );
+
+        let mut segment = TranscriptSegment {
+            id: 1,
+            start_ms: 0,
+            end_ms: 1000,
+            speaker: None,
+            text: "reach me at 555 123 4567".to_string(),
+            finalized: false,
+            confidence: None,
+        };
+        assert!(redactor.apply(&mut segment));
+        assert_eq!(segment.text, "reach me at [phone]");
+        assert!(!Redactor::default().apply(&mut segment));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::SummarizeError;
+use crate::types::TranscriptSegment;
+
+use super::SummarizeProvider;
+use super::patch::{context_block, format_transcript, participants_block};
+
+/// Answer a question about the meeting from a transcript window, streaming
+/// answer text through `on_token` as the provider produces it.
+pub fn answer_question(
+    provider: &mut dyn SummarizeProvider,
+    question: &str,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+    on_token: &mut dyn FnMut(&str),
+) -> Result<String, SummarizeError> {
+    let prompt = build_ask_prompt(question, segments, context, participants);
+    let answer = provider.generate_streaming(&prompt, on_token)?;
+    Ok(answer.trim().to_string())
+}
+
+fn build_ask_prompt(
+    question: &str,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> String {
+    format!(
+        r#"
+<task>
+You are answering a question about a meeting that is in progress, using only its recent transcript.
+</task>
+
+<rules>
+- Answer in plain text, not JSON; 1-3 short sentences
+- Quote names, numbers, and dates exactly as they appear in the transcript
+- If the transcript does not contain the answer, say so briefly
+- Do not invent content that is not in the transcript
+</rules>
+
+<input>
+<input_context>
+{context}
+</input_context>
+
+<input_participants>
+{participants}
+</input_participants>
+
+<input_transcript>
+{transcript}
+</input_transcript>
+
+<input_question>
+{question}
+</input_question>
+</input>
+"#,
+        context = context_block(context),
+        participants = participants_block(participants),
+        transcript = format_transcript(segments),
+        question = question.trim(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::answer_question;
+    use crate::SummarizeError;
+    use crate::summarize::SummarizeProvider;
+    use crate::types::{MeetingNotes, SummarizeEvent, TranscriptSegment};
+
+    struct EchoProvider {
+        prompt: String,
+    }
+
+    impl SummarizeProvider for EchoProvider {
+        fn name(&self) -> &'static str {
+            "echo"
+        }
+
+        fn summarize(
+            &mut self,
+            _recent_segments: &[TranscriptSegment],
+            _notes: &MeetingNotes,
+            _context: Option<&str>,
+            _participants: &[String],
+            _on_event: &mut dyn FnMut(SummarizeEvent),
+        ) -> Result<(), SummarizeError> {
+            Ok(())
+        }
+
+        fn generate(&mut self, prompt: &str) -> Result<String, SummarizeError> {
+            self.prompt = prompt.to_string();
+            Ok(" Friday. ".to_string())
+        }
+    }
+
+    #[test]
+    fn answer_uses_transcript_and_streams_default_generate() {
+        let mut provider = EchoProvider {
+            prompt: String::new(),
+        };
+        let segments = vec![TranscriptSegment {
+            id: 1,
+            start_ms: 0,
+            end_ms: 1_000,
+            speaker: Some("Them".to_string()),
+            text: "the deadline is Friday".to_string(),
+            finalized: true,
+            confidence: None,
+        }];
+        let mut streamed = String::new();
+        let answer = answer_question(
+            &mut provider,
+            "what is the deadline?",
+            &segments,
+            None,
+            &[],
+            &mut |token| streamed.push_str(token),
+        )
+        .unwrap();
+
+        assert_eq!(answer, "Friday.");
+        assert_eq!(streamed, " Friday. ");
+        assert!(provider.prompt.contains("the deadline is Friday"));
+        assert!(
+            provider
+                .prompt
+                .contains("<input_question>\nwhat is the deadline?\n</input_question>")
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::SummarizeError;
+use crate::types::{MeetingNotes, TranscriptSegment};
+
+use super::SummarizeProvider;
+use super::patch::{context_block, format_transcript, participants_block};
+
+/// Segments after the newest evidence of the last note; everything when no
+/// note cites the transcript yet.
+pub fn segments_since_last_note<'a>(
+    segments: &'a [TranscriptSegment],
+    notes: &MeetingNotes,
+) -> &'a [TranscriptSegment] {
+    let Some(last_id) = notes
+        .bullets
+        .iter()
+        .rev()
+        .find_map(|bullet| bullet.evidence.iter().max())
+    else {
+        return segments;
+    };
+    let start = segments.partition_point(|segment| segment.id <= *last_id);
+    &segments[start..]
+}
+
+/// Digest of `segments` for someone who just joined, streaming text through
+/// `on_token` as the provider produces it.
+pub fn catch_up(
+    provider: &mut dyn SummarizeProvider,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+    on_token: &mut dyn FnMut(&str),
+) -> Result<String, SummarizeError> {
+    let prompt = build_catch_up_prompt(segments, context, participants);
+    let digest = provider.generate_streaming(&prompt, on_token)?;
+    Ok(digest.trim().to_string())
+}
+
+fn build_catch_up_prompt(
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> String {
+    format!(
+        r#"
+<task>
+Someone just joined a meeting that is in progress. Catch them up on the transcript below.
+</task>
+
+<rules>
+- Plain text, not JSON; at most 5 lines starting with "- "
+- Lead with the current topic, then decisions, open questions, and anything asked of the listener
+- Quote names, numbers, and dates exactly as they appear in the transcript
+- Do not invent content that is not in the transcript
+</rules>
+
+<input>
+<input_context>
+{context}
+</input_context>
+
+<input_participants>
+{participants}
+</input_participants>
+
+<input_transcript>
+{transcript}
+</input_transcript>
+</input>
+"#,
+        context = context_block(context),
+        participants = participants_block(participants),
+        transcript = format_transcript(segments),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::segments_since_last_note;
+    use crate::types::{MeetingNotes, NoteBullet, NoteKind, TranscriptSegment};
+
+    #[test]
+    fn window_starts_after_last_note_evidence() {
+        let segments: Vec<TranscriptSegment> = (1..=5)
+            .map(|id| TranscriptSegment {
+                id,
+                start_ms: id as i64 * 1_000,
+                end_ms: id as i64 * 1_000 + 900,
+                speaker: None,
+                text: format!("line {id}"),
+                finalized: true,
+                confidence: None,
+            })
+            .collect();
+        let mut notes = MeetingNotes::default();
+        assert_eq!(segments_since_last_note(&segments, &notes).len(), 5);
+
+        notes.bullets.push(NoteBullet {
+            id: "n1".to_string(),
+            text: "first".to_string(),
+            evidence: vec![2, 3],
+            done: false,
+            owner: None,
+            due: None,
+            kind: NoteKind::Point,
+        });
+        let window = segments_since_last_note(&segments, &notes);
+        assert_eq!(window.iter().map(|s| s.id).collect::<Vec<_>>(), [4, 5]);
+    }
+}
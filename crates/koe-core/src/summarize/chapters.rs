@@ -0,0 +1,196 @@
+use crate::SummarizeError;
+use crate::types::{Chapter, TranscriptSegment};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::SummarizeProvider;
+use super::patch::{context_block, format_transcript, parse_json_payload, participants_block};
+use super::schema::generate_structured;
+
+/// Split a transcript into topic chapters covering it end to end.
+pub fn generate_chapters(
+    provider: &mut dyn SummarizeProvider,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> Result<Vec<Chapter>, SummarizeError> {
+    if segments.is_empty() {
+        return Ok(Vec::new());
+    }
+    let prompt = build_chapters_prompt(segments, context, participants);
+    let payload = generate_structured(provider, &prompt, &chapters_schema(), parse_chapters)?;
+    Ok(chapters_from_starts(payload.chapters, segments))
+}
+
+fn chapters_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["chapters"],
+        "properties": {
+            "chapters": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["start_ms", "title"],
+                    "properties": {
+                        "start_ms": {"type": "integer"},
+                        "title": {"type": "string"},
+                    },
+                },
+            },
+        },
+    })
+}
+
+fn parse_chapters(output: &str) -> Result<ChaptersPayload, SummarizeError> {
+    parse_json_payload(output, &chapters_schema())
+}
+
+fn build_chapters_prompt(
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> String {
+    const JSON_SCHEMA_SAMPLE: &str = r#"
+{
+    "chapters": [{"start_ms": 0, "title": "..."}]
+}
+"#;
+    format!(
+        r#"
+<task>
+Segment this meeting transcript into topics, marking where the conversation moves to a new subject.
+</task>
+
+<schema>
+Output JSON matching this schema:
+{JSON_SCHEMA_SAMPLE}
+</schema>
+
+<rules>
+- One chapter per distinct topic, in order; roughly one per 5-15 minutes, never more than 20
+- start_ms must be a start_ms value from the transcript where the topic begins; the first chapter starts at the first segment
+- title: short, specific noun phrase naming the topic, <=60 characters
+- Do not invent content that is not in the transcript
+</rules>
+
+<input>
+<input_context>
+{context}
+</input_context>
+
+<input_participants>
+{participants}
+</input_participants>
+
+<input_transcript>
+{transcript}
+</input_transcript>
+</input>
+"#,
+        context = context_block(context),
+        participants = participants_block(participants),
+        transcript = format_transcript(segments),
+    )
+}
+
+#[derive(Deserialize)]
+struct ChaptersPayload {
+    #[serde(default)]
+    chapters: Vec<ChapterPayload>,
+}
+
+#[derive(Deserialize)]
+struct ChapterPayload {
+    #[serde(default)]
+    start_ms: i64,
+    title: String,
+}
+
+/// Chapters snapped to segment starts, ordered, without duplicates, with the
+/// first one at the transcript start and each ending where the next begins.
+fn chapters_from_starts(
+    mut starts: Vec<ChapterPayload>,
+    segments: &[TranscriptSegment],
+) -> Vec<Chapter> {
+    let (Some(first), Some(last)) = (segments.first(), segments.last()) else {
+        return Vec::new();
+    };
+    starts.retain(|chapter| !chapter.title.trim().is_empty());
+    for chapter in &mut starts {
+        // Models round timestamps; use the latest segment starting at or before it.
+        let index = segments
+            .partition_point(|segment| segment.start_ms <= chapter.start_ms)
+            .saturating_sub(1);
+        chapter.start_ms = segments[index].start_ms;
+    }
+    starts.sort_by_key(|chapter| chapter.start_ms);
+    starts.dedup_by_key(|chapter| chapter.start_ms);
+    if let Some(opening) = starts.first_mut() {
+        opening.start_ms = first.start_ms;
+    }
+    let ends: Vec<i64> = starts
+        .iter()
+        .skip(1)
+        .map(|chapter| chapter.start_ms)
+        .chain([last.end_ms])
+        .collect();
+    starts
+        .into_iter()
+        .zip(ends)
+        .map(|(chapter, end_ms)| Chapter {
+            title: chapter.title.trim().to_string(),
+            start_ms: chapter.start_ms,
+            end_ms,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChapterPayload, chapters_from_starts};
+    use crate::types::{Chapter, TranscriptSegment};
+
+    #[test]
+    fn chapters_snap_to_segments_and_cover_the_transcript() {
+        let segments: Vec<TranscriptSegment> = (0..6)
+            .map(|i| TranscriptSegment {
+                id: i,
+                start_ms: 5_000 + i as i64 * 60_000,
+                end_ms: 5_000 + i as i64 * 60_000 + 50_000,
+                speaker: None,
+                text: format!("line {i}"),
+                finalized: true,
+                confidence: None,
+            })
+            .collect();
+        let payload = |start_ms: i64, title: &str| ChapterPayload {
+            start_ms,
+            title: title.to_string(),
+        };
+        let chapters = chapters_from_starts(
+            vec![
+                payload(190_000, "Budget"),
+                payload(0, " Intro "),
+                payload(185_000, "Budget again"),
+                payload(250_000, " "),
+            ],
+            &segments,
+        );
+        assert_eq!(
+            chapters,
+            vec![
+                Chapter {
+                    title: "Intro".to_string(),
+                    start_ms: 5_000,
+                    end_ms: 185_000,
+                },
+                Chapter {
+                    title: "Budget".to_string(),
+                    start_ms: 185_000,
+                    end_ms: 355_000,
+                },
+            ]
+        );
+    }
+}
@@ -1,10 +1,13 @@
 use crate::SummarizeError;
 use crate::http::{default_agent, retry_delay, should_retry};
 use crate::types::{MeetingNotes, SummarizeEvent, TranscriptSegment};
+use crate::usage::{UsageMeter, UsageRecord};
 use serde::Deserialize;
-use serde_json::json;
+use serde_json::{Value, json};
+use std::io::{BufRead, BufReader};
 use std::thread;
 
+use super::schema::parse_or_repair;
 use super::{SummarizeProvider, patch};
 
 const DEFAULT_BASE_URL: &str = "https://openrouter.ai/api/v1";
@@ -18,6 +21,8 @@ pub struct OpenRouterProvider {
     base_url: String,
     api_key: String,
     agent: ureq::Agent,
+    usage: Option<UsageMeter>,
+    max_ops: usize,
 }
 
 impl OpenRouterProvider {
@@ -34,6 +39,8 @@ impl OpenRouterProvider {
             base_url,
             api_key,
             agent: default_agent(),
+            usage: None,
+            max_ops: patch::DEFAULT_MAX_OPS,
         })
     }
 
@@ -45,10 +52,21 @@ impl OpenRouterProvider {
                 {"role": "user", "content": prompt},
             ],
             "temperature": 0.2,
+            "usage": {"include": true},
         })
     }
 
-    fn parse_response(body: &str) -> Result<String, SummarizeError> {
+    /// Request body asking for structured output matching `schema`.
+    fn build_json_request_body(&self, prompt: &str, schema: &Value) -> serde_json::Value {
+        let mut body = self.build_request_body(prompt);
+        body["response_format"] = json!({
+            "type": "json_schema",
+            "json_schema": {"name": "koe", "strict": false, "schema": schema},
+        });
+        body
+    }
+
+    fn parse_response(body: &str) -> Result<(String, Option<OpenRouterUsage>), SummarizeError> {
         let response: OpenRouterResponse = serde_json::from_str(body)
             .map_err(|e| SummarizeError::InvalidResponse(e.to_string()))?;
         let choice = response
@@ -56,30 +74,28 @@ impl OpenRouterProvider {
             .into_iter()
             .next()
             .ok_or_else(|| SummarizeError::InvalidResponse("no choices".into()))?;
-        Ok(choice.message.content)
+        Ok((choice.message.content, response.usage))
     }
-}
 
-impl SummarizeProvider for OpenRouterProvider {
-    fn name(&self) -> &'static str {
-        "openrouter"
+    fn record_usage(&self, usage: Option<OpenRouterUsage>) {
+        if let (Some(meter), Some(usage)) = (&self.usage, usage) {
+            meter.record(UsageRecord {
+                provider: self.name().to_string(),
+                model: self.model.clone(),
+                prompt_tokens: usage.prompt_tokens,
+                completion_tokens: usage.completion_tokens,
+                cost_usd: usage.cost.unwrap_or_default(),
+                ..UsageRecord::default()
+            });
+        }
     }
 
-    fn summarize(
-        &mut self,
-        recent_segments: &[TranscriptSegment],
-        _notes: &MeetingNotes,
-        context: Option<&str>,
-        participants: &[String],
-        on_event: &mut dyn FnMut(SummarizeEvent),
-    ) -> Result<(), SummarizeError> {
-        let prompt = patch::build_prompt(recent_segments, _notes, context, participants);
+    /// POST to `chat/completions`, retrying transient failures, and return the response body.
+    fn send(&self, body: &serde_json::Value) -> Result<ureq::Body, SummarizeError> {
         let url = format!("{}/chat/completions", self.base_url);
         let mut last_error: Option<ureq::Error> = None;
-        let mut raw_body: Option<String> = None;
 
         for attempt in 0..=MAX_RETRIES {
-            let body = self.build_request_body(&prompt);
             let response = self
                 .agent
                 .post(&url)
@@ -87,14 +103,7 @@ impl SummarizeProvider for OpenRouterProvider {
                 .send_json(body);
 
             match response {
-                Ok(resp) => {
-                    let raw = resp
-                        .into_body()
-                        .read_to_string()
-                        .map_err(|e| SummarizeError::Network(format!("{e}")))?;
-                    raw_body = Some(raw);
-                    break;
-                }
+                Ok(resp) => return Ok(resp.into_body()),
                 Err(err) => {
                     let retry = should_retry(&err);
                     last_error = Some(err);
@@ -102,32 +111,144 @@ impl SummarizeProvider for OpenRouterProvider {
                         thread::sleep(retry_delay(attempt));
                         continue;
                     }
-                    return Err(SummarizeError::Network(format!("{}", last_error.unwrap())));
+                    break;
                 }
             }
         }
 
-        let raw = raw_body.ok_or_else(|| {
-            SummarizeError::Network(
-                last_error
-                    .map(|err| err.to_string())
-                    .unwrap_or_else(|| "openrouter request failed".to_string()),
-            )
-        })?;
+        Err(SummarizeError::Network(
+            last_error
+                .map(|err| err.to_string())
+                .unwrap_or_else(|| "openrouter request failed".to_string()),
+        ))
+    }
+
+    /// Send a request body to `chat/completions` and return the first choice's content.
+    fn request(&self, body: &serde_json::Value) -> Result<String, SummarizeError> {
+        let raw = self
+            .send(body)?
+            .read_to_string()
+            .map_err(|e| SummarizeError::Network(format!("{e}")))?;
+        let (content, usage) = Self::parse_response(raw.trim())?;
+        self.record_usage(usage);
+        Ok(content)
+    }
+
+    /// Stream a prompt's completion over server-sent events, passing each content delta to `on_token`.
+    fn request_stream(
+        &self,
+        prompt: &str,
+        on_token: &mut dyn FnMut(&str),
+    ) -> Result<String, SummarizeError> {
+        let mut body = self.build_request_body(prompt);
+        body["stream"] = json!(true);
+        let reader = BufReader::new(self.send(&body)?.into_reader());
+
+        let mut full_text = String::new();
+        let mut usage = None;
+        for line in reader.lines() {
+            let line = line.map_err(|e| SummarizeError::Network(format!("{e}")))?;
+            // Other SSE lines (comments, blank separators) carry no content.
+            let Some(data) = line.trim().strip_prefix("data:") else {
+                continue;
+            };
+            let data = data.trim();
+            if data == "[DONE]" {
+                break;
+            }
+            let (token, chunk_usage) = Self::parse_stream_chunk(data)?;
+            if let Some(token) = token {
+                on_token(&token);
+                full_text.push_str(&token);
+            }
+            // Only the final chunk carries usage.
+            usage = chunk_usage.or(usage);
+        }
+        self.record_usage(usage);
+        Ok(full_text)
+    }
+
+    fn parse_stream_chunk(
+        data: &str,
+    ) -> Result<(Option<String>, Option<OpenRouterUsage>), SummarizeError> {
+        let chunk: OpenRouterStreamChunk = serde_json::from_str(data)
+            .map_err(|e| SummarizeError::InvalidResponse(e.to_string()))?;
+        let token = chunk
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|choice| choice.delta.content)
+            .filter(|content| !content.is_empty());
+        Ok((token, chunk.usage))
+    }
+}
+
+impl SummarizeProvider for OpenRouterProvider {
+    fn name(&self) -> &'static str {
+        "openrouter"
+    }
 
-        let content = Self::parse_response(raw.trim())?;
+    fn summarize(
+        &mut self,
+        recent_segments: &[TranscriptSegment],
+        _notes: &MeetingNotes,
+        context: Option<&str>,
+        participants: &[String],
+        on_event: &mut dyn FnMut(SummarizeEvent),
+    ) -> Result<(), SummarizeError> {
+        let prompt =
+            patch::build_prompt(recent_segments, _notes, context, participants, self.max_ops);
+        let schema = patch::patch_schema();
+        let content = self.request(&self.build_json_request_body(&prompt, &schema))?;
         if !content.is_empty() {
             on_event(SummarizeEvent::DraftToken(content.clone()));
         }
-        let patch = patch::parse_patch(content.trim())?;
+        let patch = parse_or_repair(self, &prompt, &schema, &content, patch::parse_patch)?;
         on_event(SummarizeEvent::PatchReady(patch));
         Ok(())
     }
+
+    fn generate(&mut self, prompt: &str) -> Result<String, SummarizeError> {
+        self.request(&self.build_request_body(prompt))
+    }
+
+    fn generate_json(&mut self, prompt: &str, schema: &Value) -> Result<String, SummarizeError> {
+        self.request(&self.build_json_request_body(prompt, schema))
+    }
+
+    fn generate_streaming(
+        &mut self,
+        prompt: &str,
+        on_token: &mut dyn FnMut(&str),
+    ) -> Result<String, SummarizeError> {
+        self.request_stream(prompt, on_token)
+    }
+
+    fn set_usage_meter(&mut self, meter: UsageMeter) {
+        self.usage = Some(meter);
+    }
+
+    fn set_max_ops(&mut self, max_ops: usize) {
+        self.max_ops = max_ops;
+    }
 }
 
 #[derive(Deserialize)]
 struct OpenRouterResponse {
     choices: Vec<OpenRouterChoice>,
+    #[serde(default)]
+    usage: Option<OpenRouterUsage>,
+}
+
+/// Token counts and, when requested, the billed cost in USD.
+#[derive(Deserialize)]
+struct OpenRouterUsage {
+    #[serde(default)]
+    prompt_tokens: u64,
+    #[serde(default)]
+    completion_tokens: u64,
+    #[serde(default)]
+    cost: Option<f64>,
 }
 
 #[derive(Deserialize)]
@@ -140,6 +261,24 @@ struct OpenRouterMessage {
     content: String,
 }
 
+#[derive(Deserialize)]
+struct OpenRouterStreamChunk {
+    #[serde(default)]
+    choices: Vec<OpenRouterStreamChoice>,
+    #[serde(default)]
+    usage: Option<OpenRouterUsage>,
+}
+
+#[derive(Deserialize)]
+struct OpenRouterStreamChoice {
+    delta: OpenRouterDelta,
+}
+
+#[derive(Deserialize)]
+struct OpenRouterDelta {
+    content: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::OpenRouterProvider;
@@ -147,9 +286,26 @@ mod tests {
 
     #[test]
     fn parse_response_extracts_content() {
-        let body = r#"{"choices":[{"message":{"content":"{\"ops\": []}"}}]}"#;
-        let content = OpenRouterProvider::parse_response(body).unwrap();
+        let body = r#"{"choices":[{"message":{"content":"{\"ops\": []}"}}],"usage":{"prompt_tokens":120,"completion_tokens":8,"cost":0.0004}}"#;
+        let (content, usage) = OpenRouterProvider::parse_response(body).unwrap();
         assert!(content.contains("ops"));
+        let usage = usage.unwrap();
+        assert_eq!((usage.prompt_tokens, usage.completion_tokens), (120, 8));
+        assert_eq!(usage.cost, Some(0.0004));
+    }
+
+    #[test]
+    fn parse_stream_chunk_reads_delta_content() {
+        let chunk = r#"{"choices":[{"delta":{"content":"Fri"}}]}"#;
+        assert_eq!(
+            OpenRouterProvider::parse_stream_chunk(chunk).unwrap().0,
+            Some("Fri".to_string())
+        );
+        let role_only = r#"{"choices":[{"delta":{"role":"assistant"}}]}"#;
+        assert_eq!(
+            OpenRouterProvider::parse_stream_chunk(role_only).unwrap().0,
+            None
+        );
     }
 
     #[test]
@@ -159,6 +315,8 @@ mod tests {
             base_url: "http://example.com".to_string(),
             api_key: "test-key".to_string(),
             agent: default_agent(),
+            usage: None,
+            max_ops: super::patch::DEFAULT_MAX_OPS,
         };
         let body = provider.build_request_body("prompt");
         let model = body.get("model").and_then(|value| value.as_str());
@@ -0,0 +1,148 @@
+use crate::SummarizeError;
+use crate::types::{MeetingNotes, NotesPatch};
+use serde_json::{Value, json};
+
+use super::SummarizeProvider;
+use super::patch::{context_block, parse_patch_against};
+use super::schema::generate_structured;
+
+/// Cleanup pass over accumulated live notes: merges bullets that say the
+/// same thing, removes empty or superseded ones, and groups related ones,
+/// as remove/merge/reorder ops.
+pub fn consolidate_notes(
+    provider: &mut dyn SummarizeProvider,
+    notes: &MeetingNotes,
+    context: Option<&str>,
+) -> Result<NotesPatch, SummarizeError> {
+    if notes.bullets.len() < 2 {
+        return Ok(NotesPatch { ops: Vec::new() });
+    }
+    let prompt = build_consolidate_prompt(notes, context);
+    generate_structured(
+        provider,
+        &prompt,
+        &consolidate_schema(),
+        parse_consolidation,
+    )
+}
+
+fn consolidate_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["ops"],
+        "properties": {
+            "ops": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["op"],
+                    "properties": {
+                        "op": {"enum": ["remove", "merge", "reorder"]},
+                        "id": {"type": "string"},
+                        "from": {"type": "array", "items": {"type": "string"}},
+                        "ids": {"type": "array", "items": {"type": "string"}},
+                        "text": {"type": "string"},
+                    },
+                },
+            },
+        },
+    })
+}
+
+fn parse_consolidation(output: &str) -> Result<NotesPatch, SummarizeError> {
+    parse_patch_against(output, &consolidate_schema())
+}
+
+fn build_consolidate_prompt(notes: &MeetingNotes, context: Option<&str>) -> String {
+    const JSON_SCHEMA_SAMPLE: &str = r#"
+{
+    "ops": [
+        {"op": "merge", "id": "n_3", "from": ["n_7", "n_9"], "text": "..."},
+        {"op": "remove", "id": "n_4"},
+        {"op": "reorder", "ids": ["n_2", "n_6", "n_5"]}
+    ]
+}
+"#;
+    format!(
+        r#"
+<task>
+These notes were written live, a few at a time, during a meeting that is still going. Clean them up so they read as one concise list.
+</task>
+
+<schema>
+Output JSON matching this schema:
+{JSON_SCHEMA_SAMPLE}
+</schema>
+
+<rules>
+- merge: when notes repeat or continue the same fact, keep the earliest as id, list the others in from, and write one sentence (<=120 characters) with every detail they carry
+- remove: only for notes with no content, or that a later note clearly supersedes or contradicts
+- reorder: list ids of notes on the same topic in reading order so they sit together; they swap among their own places
+- Never merge notes of different kinds, and keep owners, numbers, and dates exactly as written
+- Leave distinct notes alone; return {{"ops": []}} if the list is already clean
+</rules>
+
+<input>
+<input_context>
+{context}
+</input_context>
+
+<input_notes>
+{notes}
+</input_notes>
+</input>
+"#,
+        context = context_block(context),
+        notes = notes_block(notes),
+    )
+}
+
+fn notes_block(notes: &MeetingNotes) -> String {
+    notes
+        .bullets
+        .iter()
+        .map(|bullet| {
+            format!(
+                "- {} [{}]: {}",
+                bullet.id,
+                bullet.kind.as_str(),
+                bullet.text.trim()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_consolidate_prompt, parse_consolidation};
+    use crate::types::{MeetingNotes, NoteBullet, NoteKind, NotesOp};
+
+    #[test]
+    fn consolidation_lists_notes_and_parses_cleanup_ops() {
+        let notes = MeetingNotes {
+            bullets: vec![NoteBullet {
+                id: "n_1".to_string(),
+                text: "Ship on Friday".to_string(),
+                evidence: vec![1],
+                done: false,
+                owner: None,
+                due: None,
+                kind: NoteKind::Decision,
+            }],
+        };
+        let prompt = build_consolidate_prompt(&notes, None);
+        assert!(prompt.contains("- n_1 [decision]: Ship on Friday"));
+
+        let patch = parse_consolidation(
+            r#"{"ops":[{"op":"merge","id":"n_1","from":["n_2"],"text":"Ship Friday"},{"op":"remove","id":"n_3"}]}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            &patch.ops[..],
+            [NotesOp::Merge { id, from, .. }, NotesOp::Remove { .. }]
+                if id == "n_1" && from == &["n_2".to_string()]
+        ));
+        assert!(parse_consolidation(r#"{"ops":[{"op":"add","id":"n_4","text":"x"}]}"#).is_err());
+    }
+}
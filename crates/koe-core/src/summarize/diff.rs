@@ -0,0 +1,141 @@
+use crate::types::{MeetingNotes, NoteBullet};
+use serde::Serialize;
+use std::collections::HashSet;
+
+use super::filter::normalize_text;
+
+/// Word overlap at or above which two bullets count as the same point reworded.
+const CHANGED_SIMILARITY: f32 = 0.5;
+
+/// Bullet-level comparison of two note sets generated from the same transcript.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NotesDiff {
+    pub added: Vec<NoteBullet>,
+    pub removed: Vec<NoteBullet>,
+    pub changed: Vec<ChangedBullet>,
+    pub unchanged: Vec<NoteBullet>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangedBullet {
+    pub before: NoteBullet,
+    pub after: NoteBullet,
+    pub similarity: f32,
+}
+
+impl NotesDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Pair bullets by normalized text first, then by word overlap; whatever is
+/// left over was removed from `before` or added in `after`.
+pub fn diff_notes(before: &MeetingNotes, after: &MeetingNotes) -> NotesDiff {
+    let mut diff = NotesDiff::default();
+    let mut remaining: Vec<Option<&NoteBullet>> = after.bullets.iter().map(Some).collect();
+    let after_norms: Vec<String> = after
+        .bullets
+        .iter()
+        .map(|bullet| normalize_text(&bullet.text))
+        .collect();
+
+    let mut unmatched = Vec::new();
+    for bullet in &before.bullets {
+        let norm = normalize_text(&bullet.text);
+        let exact = remaining
+            .iter()
+            .zip(&after_norms)
+            .position(|(slot, after_norm)| slot.is_some() && *after_norm == norm);
+        match exact.and_then(|index| remaining[index].take()) {
+            Some(_) => diff.unchanged.push(bullet.clone()),
+            None => unmatched.push((bullet, norm)),
+        }
+    }
+
+    for (bullet, norm) in unmatched {
+        let best = remaining
+            .iter()
+            .zip(&after_norms)
+            .enumerate()
+            .filter(|(_, (slot, _))| slot.is_some())
+            .map(|(index, (_, after_norm))| (index, word_similarity(&norm, after_norm)))
+            .filter(|(_, similarity)| *similarity >= CHANGED_SIMILARITY)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        match best.and_then(|(index, similarity)| Some((remaining[index].take()?, similarity))) {
+            Some((after_bullet, similarity)) => diff.changed.push(ChangedBullet {
+                before: bullet.clone(),
+                after: after_bullet.clone(),
+                similarity,
+            }),
+            None => diff.removed.push(bullet.clone()),
+        }
+    }
+
+    diff.added = remaining.into_iter().flatten().cloned().collect();
+    diff
+}
+
+/// Jaccard similarity over the word sets of two normalized strings.
+fn word_similarity(a: &str, b: &str) -> f32 {
+    let a: HashSet<&str> = a.split_whitespace().collect();
+    let b: HashSet<&str> = b.split_whitespace().collect();
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f32 / union as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff_notes;
+    use crate::types::{MeetingNotes, NoteBullet, NoteKind};
+
+    fn notes(texts: &[&str]) -> MeetingNotes {
+        MeetingNotes {
+            bullets: texts
+                .iter()
+                .enumerate()
+                .map(|(index, text)| NoteBullet {
+                    id: format!("n{index}"),
+                    text: (*text).to_string(),
+                    evidence: vec![index as u64],
+                    done: false,
+                    owner: None,
+                    due: None,
+                    kind: NoteKind::Point,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn diff_splits_added_removed_changed_and_unchanged() {
+        let before = notes(&[
+            "Ship the beta on Friday",
+            "Alice owns the pricing page",
+            "Budget review moved to Q3",
+        ]);
+        let after = notes(&[
+            "ship the beta on friday.",
+            "Alice owns the new pricing page copy",
+            "Hire a second designer",
+        ]);
+        let diff = diff_notes(&before, &after);
+
+        assert_eq!(diff.unchanged.len(), 1);
+        assert_eq!(diff.unchanged[0].text, "Ship the beta on Friday");
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].before.text, "Alice owns the pricing page");
+        assert_eq!(
+            diff.changed[0].after.text,
+            "Alice owns the new pricing page copy"
+        );
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].text, "Budget review moved to Q3");
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].text, "Hire a second designer");
+        assert!(!diff.is_empty());
+    }
+}
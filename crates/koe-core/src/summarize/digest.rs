@@ -0,0 +1,99 @@
+use crate::SummarizeError;
+use crate::types::{MeetingNotes, NoteKind};
+
+use super::SummarizeProvider;
+
+/// One meeting's notes as input to a digest.
+#[derive(Debug, Clone)]
+pub struct DigestMeeting {
+    /// `YYYY-MM-DD title`, as in session listings.
+    pub name: String,
+    pub notes: MeetingNotes,
+}
+
+/// Rollup of several meetings' notes, e.g. for a weekly review: themes,
+/// decisions, and open follow-ups, as markdown bullets.
+pub fn generate_digest(
+    provider: &mut dyn SummarizeProvider,
+    meetings: &[DigestMeeting],
+    period: &str,
+) -> Result<String, SummarizeError> {
+    let prompt = build_digest_prompt(meetings, period);
+    let digest = provider.generate(&prompt)?;
+    Ok(digest.trim().to_string())
+}
+
+fn build_digest_prompt(meetings: &[DigestMeeting], period: &str) -> String {
+    format!(
+        r#"
+<task>
+Write a digest of the meetings held {period}, for someone reviewing the period.
+</task>
+
+<rules>
+- Markdown, not JSON; no headings, at most 12 lines starting with "- "
+- Lead with recurring themes across meetings, then key decisions, then open action items and questions
+- Name the meeting a point comes from when it matters, e.g. "(2026-10-14 launch sync)"
+- Keep owners and due dates exactly as given
+- Do not invent content that is not in the notes
+</rules>
+
+<input>
+{meetings}
+</input>
+"#,
+        meetings = meetings_block(meetings),
+    )
+}
+
+fn meetings_block(meetings: &[DigestMeeting]) -> String {
+    let mut out = String::new();
+    for meeting in meetings {
+        out.push_str(&format!("<meeting name=\"{}\">\n", meeting.name));
+        for bullet in &meeting.notes.bullets {
+            let kind = match bullet.kind {
+                NoteKind::Action if bullet.done => "action (done)",
+                kind => kind.as_str(),
+            };
+            out.push_str(&format!("- [{kind}] {}", bullet.text));
+            if let Some(owner) = &bullet.owner {
+                out.push_str(&format!(" (owner: {owner})"));
+            }
+            if let Some(due) = &bullet.due {
+                out.push_str(&format!(" (due: {due})"));
+            }
+            out.push('\n');
+        }
+        out.push_str("</meeting>\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DigestMeeting, build_digest_prompt};
+    use crate::types::{MeetingNotes, NoteBullet, NoteKind};
+
+    #[test]
+    fn digest_prompt_lists_meetings_with_kinds_and_owners() {
+        let meetings = vec![DigestMeeting {
+            name: "2026-10-14 launch sync".to_string(),
+            notes: MeetingNotes {
+                bullets: vec![NoteBullet {
+                    id: "n1".to_string(),
+                    text: "Send the launch checklist".to_string(),
+                    evidence: vec![3],
+                    done: false,
+                    owner: Some("Dana".to_string()),
+                    due: Some("2026-10-20".to_string()),
+                    kind: NoteKind::Action,
+                }],
+            },
+        }];
+        let prompt = build_digest_prompt(&meetings, "in the last 7 days");
+        assert!(prompt.contains("meetings held in the last 7 days"));
+        assert!(prompt.contains(
+            "<meeting name=\"2026-10-14 launch sync\">\n- [action] Send the launch checklist (owner: Dana) (due: 2026-10-20)\n</meeting>"
+        ));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::SummarizeError;
+use crate::http::{default_agent, retry_delay, should_retry};
+use crate::types::{MeetingNotes, SummarizeEvent, TranscriptSegment};
+use crate::usage::{UsageMeter, UsageRecord, gemini_cost};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::io::{BufRead, BufReader};
+use std::thread;
+
+use super::schema::parse_or_repair;
+use super::{SummarizeProvider, patch};
+
+const DEFAULT_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta";
+const DEFAULT_MODEL: &str = "gemini-2.5-flash";
+const SYSTEM_PROMPT: &str =
+    "You are a meeting notes engine. Follow the instructions and output only JSON.";
+const MAX_RETRIES: usize = 2;
+
+/// Google's Generative Language API, called directly rather than through OpenRouter.
+pub struct GeminiProvider {
+    model: String,
+    base_url: String,
+    api_key: String,
+    agent: ureq::Agent,
+    usage: Option<UsageMeter>,
+    max_ops: usize,
+}
+
+impl GeminiProvider {
+    pub fn new(model: Option<&str>, api_key: Option<&str>) -> Result<Self, SummarizeError> {
+        let api_key = api_key
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| SummarizeError::Failed("gemini API key not set".into()))?
+            .to_string();
+        let base_url = std::env::var("GEMINI_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self {
+            model: model
+                .map(|model| model.trim_start_matches("models/"))
+                .unwrap_or(DEFAULT_MODEL)
+                .to_string(),
+            base_url,
+            api_key,
+            agent: default_agent(),
+            usage: None,
+            max_ops: patch::DEFAULT_MAX_OPS,
+        })
+    }
+
+    /// `generateContent` body; a `schema` asks for JSON output matching it.
+    fn build_request_body(&self, prompt: &str, schema: Option<&Value>) -> Value {
+        let mut body = json!({
+            "systemInstruction": {"parts": [{"text": SYSTEM_PROMPT}]},
+            "contents": [{"role": "user", "parts": [{"text": prompt}]}],
+            "generationConfig": {"temperature": 0.2},
+        });
+        if let Some(schema) = schema {
+            body["generationConfig"]["responseMimeType"] = json!("application/json");
+            body["generationConfig"]["responseJsonSchema"] = schema.clone();
+        }
+        body
+    }
+
+    /// Text of the first candidate plus token counts, from a full response or one stream chunk.
+    fn parse_response(body: &str) -> Result<(String, Option<GeminiUsage>), SummarizeError> {
+        let response: GeminiResponse = serde_json::from_str(body)
+            .map_err(|e| SummarizeError::InvalidResponse(e.to_string()))?;
+        if let Some(reason) = response
+            .prompt_feedback
+            .and_then(|feedback| feedback.block_reason)
+        {
+            return Err(SummarizeError::InvalidResponse(format!(
+                "prompt blocked: {reason}"
+            )));
+        }
+        let text = response
+            .candidates
+            .into_iter()
+            .next()
+            .and_then(|candidate| candidate.content)
+            .map(|content| {
+                content
+                    .parts
+                    .into_iter()
+                    .filter_map(|part| part.text)
+                    .collect::<String>()
+            })
+            .unwrap_or_default();
+        Ok((text, response.usage_metadata))
+    }
+
+    fn record_usage(&self, usage: Option<GeminiUsage>) {
+        if let (Some(meter), Some(usage)) = (&self.usage, usage) {
+            meter.record(UsageRecord {
+                provider: self.name().to_string(),
+                model: self.model.clone(),
+                prompt_tokens: usage.prompt_token_count,
+                completion_tokens: usage.candidates_token_count,
+                cost_usd: gemini_cost(
+                    &self.model,
+                    usage.prompt_token_count,
+                    usage.candidates_token_count,
+                ),
+                ..UsageRecord::default()
+            });
+        }
+    }
+
+    /// POST to `models/{model}:{method}`, retrying transient failures, and return the response body.
+    fn send(&self, method: &str, body: &Value) -> Result<ureq::Body, SummarizeError> {
+        let url = format!("{}/models/{}:{method}", self.base_url, self.model);
+        let mut last_error: Option<ureq::Error> = None;
+
+        for attempt in 0..=MAX_RETRIES {
+            let response = self
+                .agent
+                .post(&url)
+                .header("x-goog-api-key", &self.api_key)
+                .send_json(body);
+
+            match response {
+                Ok(resp) => return Ok(resp.into_body()),
+                Err(err) => {
+                    let retry = should_retry(&err);
+                    last_error = Some(err);
+                    if retry && attempt < MAX_RETRIES {
+                        thread::sleep(retry_delay(attempt));
+                        continue;
+                    }
+                    break;
+                }
+            }
+        }
+
+        Err(SummarizeError::Network(
+            last_error
+                .map(|err| err.to_string())
+                .unwrap_or_else(|| "gemini request failed".to_string()),
+        ))
+    }
+
+    fn request(&self, prompt: &str, schema: Option<&Value>) -> Result<String, SummarizeError> {
+        let raw = self
+            .send("generateContent", &self.build_request_body(prompt, schema))?
+            .read_to_string()
+            .map_err(|e| SummarizeError::Network(format!("{e}")))?;
+        let (text, usage) = Self::parse_response(raw.trim())?;
+        self.record_usage(usage);
+        Ok(text)
+    }
+
+    /// Stream a completion over server-sent events, passing each text delta to `on_token`.
+    fn request_stream(
+        &self,
+        prompt: &str,
+        on_token: &mut dyn FnMut(&str),
+    ) -> Result<String, SummarizeError> {
+        let body = self.build_request_body(prompt, None);
+        let reader = BufReader::new(
+            self.send("streamGenerateContent?alt=sse", &body)?
+                .into_reader(),
+        );
+
+        let mut full_text = String::new();
+        let mut usage = None;
+        for line in reader.lines() {
+            let line = line.map_err(|e| SummarizeError::Network(format!("{e}")))?;
+            let Some(data) = line.trim().strip_prefix("data:") else {
+                continue;
+            };
+            let (token, chunk_usage) = Self::parse_response(data.trim())?;
+            if !token.is_empty() {
+                on_token(&token);
+                full_text.push_str(&token);
+            }
+            // Each chunk carries running totals; keep the latest.
+            usage = chunk_usage.or(usage);
+        }
+        self.record_usage(usage);
+        Ok(full_text)
+    }
+}
+
+impl SummarizeProvider for GeminiProvider {
+    fn name(&self) -> &'static str {
+        "gemini"
+    }
+
+    fn summarize(
+        &mut self,
+        recent_segments: &[TranscriptSegment],
+        notes: &MeetingNotes,
+        context: Option<&str>,
+        participants: &[String],
+        on_event: &mut dyn FnMut(SummarizeEvent),
+    ) -> Result<(), SummarizeError> {
+        let prompt =
+            patch::build_prompt(recent_segments, notes, context, participants, self.max_ops);
+        let schema = patch::patch_schema();
+        let content = self.request(&prompt, Some(&schema))?;
+        if !content.is_empty() {
+            on_event(SummarizeEvent::DraftToken(content.clone()));
+        }
+        let patch = parse_or_repair(self, &prompt, &schema, &content, patch::parse_patch)?;
+        on_event(SummarizeEvent::PatchReady(patch));
+        Ok(())
+    }
+
+    fn generate(&mut self, prompt: &str) -> Result<String, SummarizeError> {
+        self.request(prompt, None)
+    }
+
+    fn generate_json(&mut self, prompt: &str, schema: &Value) -> Result<String, SummarizeError> {
+        self.request(prompt, Some(schema))
+    }
+
+    fn generate_streaming(
+        &mut self,
+        prompt: &str,
+        on_token: &mut dyn FnMut(&str),
+    ) -> Result<String, SummarizeError> {
+        self.request_stream(prompt, on_token)
+    }
+
+    fn set_usage_meter(&mut self, meter: UsageMeter) {
+        self.usage = Some(meter);
+    }
+
+    fn set_max_ops(&mut self, max_ops: usize) {
+        self.max_ops = max_ops;
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiResponse {
+    #[serde(default)]
+    candidates: Vec<GeminiCandidate>,
+    #[serde(default)]
+    prompt_feedback: Option<GeminiPromptFeedback>,
+    #[serde(default)]
+    usage_metadata: Option<GeminiUsage>,
+}
+
+#[derive(Deserialize)]
+struct GeminiCandidate {
+    #[serde(default)]
+    content: Option<GeminiContent>,
+}
+
+#[derive(Deserialize)]
+struct GeminiContent {
+    #[serde(default)]
+    parts: Vec<GeminiPart>,
+}
+
+#[derive(Deserialize)]
+struct GeminiPart {
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiPromptFeedback {
+    #[serde(default)]
+    block_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiUsage {
+    #[serde(default)]
+    prompt_token_count: u64,
+    #[serde(default)]
+    candidates_token_count: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeminiProvider;
+    use crate::SummarizeError;
+    use crate::http::default_agent;
+    use serde_json::json;
+
+    #[test]
+    fn request_body_and_response_parsing() {
+        let provider = GeminiProvider {
+            model: "gemini-2.5-flash".to_string(),
+            base_url: "http://example.com".to_string(),
+            api_key: "test-key".to_string(),
+            agent: default_agent(),
+            usage: None,
+            max_ops: super::patch::DEFAULT_MAX_OPS,
+        };
+        let schema = json!({"type": "object"});
+        let body = provider.build_request_body("prompt", Some(&schema));
+        assert_eq!(body["contents"][0]["parts"][0]["text"], "prompt");
+        assert_eq!(
+            body["generationConfig"]["responseMimeType"],
+            "application/json"
+        );
+        assert_eq!(body["generationConfig"]["responseJsonSchema"], schema);
+        assert!(
+            provider.build_request_body("prompt", None)["generationConfig"]
+                .get("responseMimeType")
+                .is_none()
+        );
+
+        let response = r#"{"candidates":[{"content":{"parts":[{"text":"{\"ops\""},{"text":": []}"}],"role":"model"}}],"usageMetadata":{"promptTokenCount":120,"candidatesTokenCount":8}}"#;
+        let (text, usage) = GeminiProvider::parse_response(response).unwrap();
+        assert_eq!(text, r#"{"ops": []}"#);
+        let usage = usage.unwrap();
+        assert_eq!(
+            (usage.prompt_token_count, usage.candidates_token_count),
+            (120, 8)
+        );
+
+        let blocked = r#"{"promptFeedback":{"blockReason":"SAFETY"}}"#;
+        assert!(matches!(
+            GeminiProvider::parse_response(blocked),
+            Err(SummarizeError::InvalidResponse(message)) if message.contains("SAFETY")
+        ));
+    }
+}
@@ -0,0 +1,213 @@
+use crate::SummarizeError;
+use crate::types::{Flashcard, KeyTerm, LectureNotes, OutlineItem, TranscriptSegment};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::SummarizeProvider;
+use super::patch::{context_block, format_transcript, parse_json_payload, participants_block};
+use super::schema::generate_structured;
+
+/// Outline levels kept from model output; deeper nodes are dropped.
+const MAX_OUTLINE_DEPTH: usize = 3;
+
+/// Generate a lecture outline, key terms, and optionally flashcards from the full transcript.
+pub fn generate_lecture_notes(
+    provider: &mut dyn SummarizeProvider,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+    flashcards: bool,
+) -> Result<LectureNotes, SummarizeError> {
+    let prompt = build_lecture_prompt(segments, context, participants, flashcards);
+    let mut notes = generate_structured(provider, &prompt, &lecture_schema(), parse_lecture_notes)?;
+    if !flashcards {
+        notes.flashcards.clear();
+    }
+    Ok(notes)
+}
+
+fn build_lecture_prompt(
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+    flashcards: bool,
+) -> String {
+    const JSON_SCHEMA_SAMPLE: &str = r#"
+{
+    "outline": [{"text": "...", "children": [{"text": "...", "children": []}]}],
+    "terms": [{"term": "...", "definition": "..."}],
+    "flashcards": [{"question": "...", "answer": "..."}]
+}
+"#;
+    let flashcard_rule = if flashcards {
+        "- flashcards: 5-20 question/answer pairs testing the key ideas; answers <=25 words"
+    } else {
+        "- flashcards: always an empty list"
+    };
+    format!(
+        r#"
+<task>
+You are writing study notes for a lecture given mostly by one speaker, from its full transcript.
+</task>
+
+<schema>
+Output JSON matching this schema:
+{JSON_SCHEMA_SAMPLE}
+</schema>
+
+<rules>
+- outline: follow the order of the lecture; top level is major topics, children are supporting points, at most {MAX_OUTLINE_DEPTH} levels
+- outline text: short phrases (<=15 words), not full sentences
+- terms: technical terms or named concepts the lecturer introduced, with the definition as explained in the lecture
+{flashcard_rule}
+- Do not invent content that is not in the transcript
+</rules>
+
+<input>
+<input_context>
+{context}
+</input_context>
+
+<input_participants>
+{participants}
+</input_participants>
+
+<input_transcript>
+{transcript}
+</input_transcript>
+</input>
+"#,
+        context = context_block(context),
+        participants = participants_block(participants),
+        transcript = format_transcript(segments),
+    )
+}
+
+fn lecture_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["outline", "terms", "flashcards"],
+        "properties": {
+            "outline": {"type": "array", "items": {"$ref": "#/$defs/outline_item"}},
+            "terms": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["term", "definition"],
+                    "properties": {
+                        "term": {"type": "string"},
+                        "definition": {"type": "string"},
+                    },
+                },
+            },
+            "flashcards": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["question", "answer"],
+                    "properties": {
+                        "question": {"type": "string"},
+                        "answer": {"type": "string"},
+                    },
+                },
+            },
+        },
+        "$defs": {
+            "outline_item": {
+                "type": "object",
+                "required": ["text"],
+                "properties": {
+                    "text": {"type": "string"},
+                    "children": {"type": "array", "items": {"$ref": "#/$defs/outline_item"}},
+                },
+            },
+        },
+    })
+}
+
+fn parse_lecture_notes(output: &str) -> Result<LectureNotes, SummarizeError> {
+    let payload: LecturePayload = parse_json_payload(output, &lecture_schema())?;
+    Ok(LectureNotes {
+        outline: clean_outline(payload.outline, 1),
+        terms: payload
+            .terms
+            .into_iter()
+            .filter(|term| !term.term.trim().is_empty())
+            .map(|term| KeyTerm {
+                term: term.term.trim().to_string(),
+                definition: term.definition.trim().to_string(),
+            })
+            .collect(),
+        flashcards: payload
+            .flashcards
+            .into_iter()
+            .filter(|card| !card.question.trim().is_empty() && !card.answer.trim().is_empty())
+            .map(|card| Flashcard {
+                question: card.question.trim().to_string(),
+                answer: card.answer.trim().to_string(),
+            })
+            .collect(),
+    })
+}
+
+fn clean_outline(items: Vec<OutlinePayload>, depth: usize) -> Vec<OutlineItem> {
+    if depth > MAX_OUTLINE_DEPTH {
+        return Vec::new();
+    }
+    items
+        .into_iter()
+        .filter(|item| !item.text.trim().is_empty())
+        .map(|item| OutlineItem {
+            text: item.text.trim().to_string(),
+            children: clean_outline(item.children, depth + 1),
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct LecturePayload {
+    #[serde(default)]
+    outline: Vec<OutlinePayload>,
+    #[serde(default)]
+    terms: Vec<TermPayload>,
+    #[serde(default)]
+    flashcards: Vec<FlashcardPayload>,
+}
+
+#[derive(Deserialize)]
+struct OutlinePayload {
+    text: String,
+    #[serde(default)]
+    children: Vec<OutlinePayload>,
+}
+
+#[derive(Deserialize)]
+struct TermPayload {
+    term: String,
+    #[serde(default)]
+    definition: String,
+}
+
+#[derive(Deserialize)]
+struct FlashcardPayload {
+    question: String,
+    answer: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_lecture_notes;
+
+    #[test]
+    fn parse_lecture_notes_keeps_hierarchy_and_caps_depth() {
+        let output = r#"{"outline":[{"text":"Entropy","children":[{"text":"Definition","children":[{"text":"Units","children":[{"text":"too deep"}]}]},{"text":" "}]}],"terms":[{"term":"Entropy","definition":"Disorder measure"}],"flashcards":[{"question":"What is entropy?","answer":"A measure of disorder"},{"question":"","answer":"x"}]}"#;
+        let notes = parse_lecture_notes(output).unwrap();
+        assert_eq!(notes.outline.len(), 1);
+        let definition = &notes.outline[0].children;
+        assert_eq!(definition.len(), 1);
+        assert_eq!(definition[0].children[0].text, "Units");
+        assert!(definition[0].children[0].children.is_empty());
+        assert_eq!(notes.terms[0].term, "Entropy");
+        assert_eq!(notes.flashcards.len(), 1);
+    }
+}
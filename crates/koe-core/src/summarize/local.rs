@@ -2,9 +2,11 @@ use crate::SummarizeError;
 use crate::http::{default_agent, retry_delay, should_retry};
 use crate::types::{MeetingNotes, SummarizeEvent, TranscriptSegment};
 use serde::Deserialize;
-use serde_json::json;
+use serde_json::{Value, json};
+use std::io::{BufRead, BufReader};
 use std::thread;
 
+use super::schema::parse_or_repair;
 use super::{SummarizeProvider, patch};
 
 const DEFAULT_BASE_URL: &str = "http://localhost:11434";
@@ -14,6 +16,7 @@ pub struct OllamaProvider {
     model: String,
     base_url: String,
     agent: ureq::Agent,
+    max_ops: usize,
 }
 
 impl OllamaProvider {
@@ -23,44 +26,37 @@ impl OllamaProvider {
             model: model.to_string(),
             base_url,
             agent: default_agent(),
+            max_ops: patch::DEFAULT_MAX_OPS,
         })
     }
-}
-
-impl SummarizeProvider for OllamaProvider {
-    fn name(&self) -> &'static str {
-        "ollama"
-    }
 
-    fn summarize(
-        &mut self,
-        recent_segments: &[TranscriptSegment],
-        _notes: &MeetingNotes,
-        context: Option<&str>,
-        participants: &[String],
-        on_event: &mut dyn FnMut(SummarizeEvent),
-    ) -> Result<(), SummarizeError> {
-        let prompt = patch::build_prompt(recent_segments, _notes, context, participants);
+    /// Send a prompt to `/api/generate` and collect the streamed response text.
+    /// A `format` schema constrains the output to matching JSON.
+    fn request(
+        &self,
+        prompt: &str,
+        format: Option<&Value>,
+        on_token: &mut dyn FnMut(&str),
+    ) -> Result<String, SummarizeError> {
         let url = format!("{}/api/generate", self.base_url);
         let mut last_error: Option<ureq::Error> = None;
-        let mut raw_body: Option<String> = None;
+        let mut body: Option<ureq::Body> = None;
 
         for attempt in 0..=MAX_RETRIES {
-            let body = json!({
+            let mut request = json!({
                 "model": self.model,
                 "prompt": prompt,
                 "stream": true,
             });
+            if let Some(format) = format {
+                request["format"] = format.clone();
+            }
 
-            let response = self.agent.post(&url).send_json(body);
+            let response = self.agent.post(&url).send_json(request);
 
             match response {
                 Ok(resp) => {
-                    let raw = resp
-                        .into_body()
-                        .read_to_string()
-                        .map_err(|e| SummarizeError::Network(format!("{e}")))?;
-                    raw_body = Some(raw);
+                    body = Some(resp.into_body());
                     break;
                 }
                 Err(err) => {
@@ -75,7 +71,7 @@ impl SummarizeProvider for OllamaProvider {
             }
         }
 
-        let raw = raw_body.ok_or_else(|| {
+        let body = body.ok_or_else(|| {
             SummarizeError::Network(
                 last_error
                     .map(|err| err.to_string())
@@ -83,8 +79,11 @@ impl SummarizeProvider for OllamaProvider {
             )
         })?;
 
+        // Chunks are newline-delimited JSON; read them as they arrive so
+        // callers see tokens before the response completes.
         let mut full_text = String::new();
-        for line in raw.lines() {
+        for line in BufReader::new(body.into_reader()).lines() {
+            let line = line.map_err(|e| SummarizeError::Network(format!("{e}")))?;
             let line = line.trim();
             if line.is_empty() {
                 continue;
@@ -92,7 +91,7 @@ impl SummarizeProvider for OllamaProvider {
             let chunk: OllamaChunk = serde_json::from_str(line)
                 .map_err(|e| SummarizeError::InvalidResponse(e.to_string()))?;
             if let Some(token) = chunk.response {
-                on_event(SummarizeEvent::DraftToken(token.clone()));
+                on_token(&token);
                 full_text.push_str(&token);
             }
             if chunk.done.unwrap_or(false) {
@@ -100,10 +99,54 @@ impl SummarizeProvider for OllamaProvider {
             }
         }
 
-        let patch = patch::parse_patch(full_text.trim())?;
+        Ok(full_text)
+    }
+}
+
+impl SummarizeProvider for OllamaProvider {
+    fn name(&self) -> &'static str {
+        "ollama"
+    }
+
+    fn summarize(
+        &mut self,
+        recent_segments: &[TranscriptSegment],
+        _notes: &MeetingNotes,
+        context: Option<&str>,
+        participants: &[String],
+        on_event: &mut dyn FnMut(SummarizeEvent),
+    ) -> Result<(), SummarizeError> {
+        let prompt =
+            patch::build_prompt(recent_segments, _notes, context, participants, self.max_ops);
+        let schema = patch::patch_schema();
+        let full_text = self.request(&prompt, Some(&schema), &mut |token| {
+            on_event(SummarizeEvent::DraftToken(token.to_string()));
+        })?;
+
+        let patch = parse_or_repair(self, &prompt, &schema, &full_text, patch::parse_patch)?;
         on_event(SummarizeEvent::PatchReady(patch));
         Ok(())
     }
+
+    fn generate(&mut self, prompt: &str) -> Result<String, SummarizeError> {
+        self.request(prompt, None, &mut |_| {})
+    }
+
+    fn generate_json(&mut self, prompt: &str, schema: &Value) -> Result<String, SummarizeError> {
+        self.request(prompt, Some(schema), &mut |_| {})
+    }
+
+    fn generate_streaming(
+        &mut self,
+        prompt: &str,
+        on_token: &mut dyn FnMut(&str),
+    ) -> Result<String, SummarizeError> {
+        self.request(prompt, None, on_token)
+    }
+
+    fn set_max_ops(&mut self, max_ops: usize) {
+        self.max_ops = max_ops;
+    }
 }
 
 #[derive(Deserialize)]
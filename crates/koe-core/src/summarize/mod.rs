@@ -1,10 +1,28 @@
+pub mod ask;
+pub mod catch_up;
+pub mod chapters;
 pub mod cloud;
+pub mod consolidate;
+pub mod diff;
+pub mod digest;
 pub mod filter;
+pub mod gemini;
+pub mod lecture;
 pub mod local;
 mod patch;
+mod schema;
+pub mod show_notes;
+pub mod summary;
+pub mod template;
+pub mod title;
+pub mod translate;
 
 use crate::SummarizeError;
 use crate::types::{MeetingNotes, SummarizeEvent, TranscriptSegment};
+use crate::usage::UsageMeter;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
 
 const DEFAULT_OLLAMA_MODEL: &str = "qwen3:30b-a3b";
 
@@ -18,20 +36,110 @@ pub trait SummarizeProvider: Send {
         participants: &[String],
         on_event: &mut dyn FnMut(SummarizeEvent),
     ) -> Result<(), SummarizeError>;
+
+    /// Run a one-shot prompt and return the raw model output.
+    fn generate(&mut self, prompt: &str) -> Result<String, SummarizeError>;
+
+    /// Like `generate`, but asks the model for JSON matching `schema`.
+    /// Providers without structured output ignore the schema; callers validate either way.
+    fn generate_json(&mut self, prompt: &str, _schema: &Value) -> Result<String, SummarizeError> {
+        self.generate(prompt)
+    }
+
+    /// Like `generate`, but passes output text to `on_token` as it arrives.
+    /// Providers without streaming emit the whole output once.
+    fn generate_streaming(
+        &mut self,
+        prompt: &str,
+        on_token: &mut dyn FnMut(&str),
+    ) -> Result<String, SummarizeError> {
+        let output = self.generate(prompt)?;
+        on_token(&output);
+        Ok(output)
+    }
+
+    /// Record billable usage into `meter`. Local providers ignore it.
+    fn set_usage_meter(&mut self, _meter: UsageMeter) {}
+
+    /// Most ops one live notes patch may carry (`summarize.max_ops`).
+    fn set_max_ops(&mut self, _max_ops: usize) {}
 }
 
+/// Create a summarize provider by name: `"ollama"`, `"openrouter"`,
+/// `"gemini"`, or one added with `register_summarize_provider`.
 pub fn create_summarize_provider(
     provider: &str,
     model: Option<&str>,
     api_key: Option<&str>,
 ) -> Result<Box<dyn SummarizeProvider>, SummarizeError> {
-    match provider {
-        "ollama" => Ok(Box::new(local::OllamaProvider::new(
-            model.unwrap_or(DEFAULT_OLLAMA_MODEL),
-        )?)),
-        "openrouter" => Ok(Box::new(cloud::OpenRouterProvider::new(model, api_key)?)),
-        other => Err(SummarizeError::Failed(format!(
-            "unknown summarize provider: {other}"
-        ))),
-    }
+    let factory = summarize_registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(provider)
+        .cloned()
+        .ok_or_else(|| SummarizeError::Failed(format!("unknown summarize provider: {provider}")))?;
+    factory(model, api_key)
+}
+
+/// Builds a summarize provider from a profile's model and API key.
+pub type SummarizeFactory = dyn Fn(Option<&str>, Option<&str>) -> Result<Box<dyn SummarizeProvider>, SummarizeError>
+    + Send
+    + Sync;
+
+/// Make `name` available to `create_summarize_provider` (and so to config
+/// profiles), replacing any provider already registered under it, built-ins
+/// included.
+pub fn register_summarize_provider<F>(name: &str, factory: F)
+where
+    F: Fn(Option<&str>, Option<&str>) -> Result<Box<dyn SummarizeProvider>, SummarizeError>
+        + Send
+        + Sync
+        + 'static,
+{
+    summarize_registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(name.to_string(), Arc::new(factory));
+}
+
+/// Names `create_summarize_provider` accepts, sorted.
+pub fn summarize_provider_names() -> Vec<String> {
+    let mut names: Vec<String> = summarize_registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .keys()
+        .cloned()
+        .collect();
+    names.sort();
+    names
+}
+
+fn summarize_registry() -> &'static RwLock<HashMap<String, Arc<SummarizeFactory>>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, Arc<SummarizeFactory>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut factories: HashMap<String, Arc<SummarizeFactory>> = HashMap::new();
+        factories.insert(
+            "ollama".into(),
+            Arc::new(|model: Option<&str>, _: Option<&str>| {
+                Ok(Box::new(local::OllamaProvider::new(
+                    model.unwrap_or(DEFAULT_OLLAMA_MODEL),
+                )?) as Box<dyn SummarizeProvider>)
+            }),
+        );
+        factories.insert(
+            "openrouter".into(),
+            Arc::new(|model: Option<&str>, api_key: Option<&str>| {
+                Ok(Box::new(cloud::OpenRouterProvider::new(model, api_key)?)
+                    as Box<dyn SummarizeProvider>)
+            }),
+        );
+        factories.insert(
+            "gemini".into(),
+            Arc::new(|model: Option<&str>, api_key: Option<&str>| {
+                Ok(Box::new(gemini::GeminiProvider::new(model, api_key)?)
+                    as Box<dyn SummarizeProvider>)
+            }),
+        );
+        RwLock::new(factories)
+    })
 }
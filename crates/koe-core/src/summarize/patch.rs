@@ -1,64 +1,58 @@
 use crate::SummarizeError;
-use crate::types::{MeetingNotes, NotesOp, NotesPatch, TranscriptSegment};
+use crate::types::{MeetingNotes, NoteKind, NotesOp, NotesPatch, TranscriptSegment};
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use super::schema;
+
+/// Ops a live notes patch may carry unless `summarize.max_ops` says otherwise.
+pub(crate) const DEFAULT_MAX_OPS: usize = 3;
 
 pub(crate) fn build_prompt(
     recent: &[TranscriptSegment],
     notes: &MeetingNotes,
     context: Option<&str>,
     participants: &[String],
+    max_ops: usize,
 ) -> String {
     const JSON_SCHEMA_SAMPLE: &str = r#"
 {
     "ops": [
-        {"op": "add", "id": "n_1", "text": "...", "evidence": [1,2]}
+        {"op": "add", "id": "n_1", "kind": "decision", "text": "...", "evidence": [1,2]},
+        {"op": "merge", "id": "n_2", "from": ["n_5"], "text": "..."},
+        {"op": "remove", "id": "n_4"},
+        {"op": "reorder", "ids": ["n_3", "n_2"]}
     ]
 }
 "#;
     const EMPTY_OPS: &str = r#"{"ops": []}"#;
-    let transcript = recent
-        .iter()
-        .map(|s| {
-            let text = s.text.trim();
-            match s.speaker.as_deref() {
-                Some(speaker) if !speaker.is_empty() => {
-                    format!("[{}-{}] {speaker}: {text}", s.start_ms, s.end_ms)
-                }
-                _ => format!("[{}-{}] {text}", s.start_ms, s.end_ms),
-            }
-        })
-        .collect::<Vec<_>>()
-        .join("\n");
-    let context_block = context
-        .filter(|value| !value.is_empty())
-        .map(|value| format!("Context:\n{value}\n\n"))
-        .unwrap_or_default();
-    let participants_list = participants
-        .iter()
-        .map(|value| value.trim())
-        .filter(|value| !value.is_empty())
-        .collect::<Vec<_>>();
-    let participants_block = if participants_list.is_empty() {
-        String::new()
-    } else {
-        format!("Participants: {}\n\n", participants_list.join(", "))
-    };
+    let transcript = format_transcript(recent);
+    let context_block = context_block(context);
+    let participants_block = participants_block(participants);
     let notes_block = if notes.bullets.is_empty() {
         String::new()
     } else {
-        let lines = notes
-            .bullets
-            .iter()
-            .map(|bullet| format!("- {}: {}", bullet.id, bullet.text.trim()))
+        let sections = notes
+            .sections()
+            .into_iter()
+            .map(|(kind, bullets)| {
+                let lines = bullets
+                    .iter()
+                    .map(|bullet| format!("- {}: {}", bullet.id, bullet.text.trim()))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!("{}:\n{lines}", kind.section_title())
+            })
             .collect::<Vec<_>>()
             .join("\n");
-        format!("Existing notes (avoid duplicates):\n{lines}\n\n")
+        format!("Existing notes (avoid duplicates):\n{sections}\n\n")
     };
 
     format!(
         r#"
 <task>
-You are processing a live meeting transcript in 4-second increments. Your job: capture anything that might be worth remembering. Err on the side of inclusion -- it's easy to ignore a low-value note later, but impossible to recover a missed one.
+You are processing a live meeting transcript in short increments. Your job: capture anything that might be worth remembering. Err on the side of inclusion -- it's easy to ignore a low-value note later, but impossible to recover a missed one.
 </task>
 
 <schema>
@@ -109,15 +103,27 @@ That's it. Everything else is fair game.
 
 ---
 
+<cleanup>
+FIXING EXISTING NOTES (rare; most responses only add):
+
+- merge: two existing notes state the same fact -- keep the earlier id, list the other in "from", write the combined sentence as "text"
+- remove: an existing note is wrong per the transcript, or is pure filler
+- reorder: related notes are far apart -- list their ids in the order they should read; they swap among their own places
+- Only use ids from the existing notes
+</cleanup>
+
+---
+
 <format>
 FORMAT RULES:
 
-- Max 3 ops per response; 0-2 is normal
+- {max_ops_rule}
 - Each bullet: 1 sentence, <=120 characters
 - Prefer concrete and specific over vague ("ship Friday" not "ship soon")
 - Do not include speaker labels in note text
 - ID format: "n_<number>" -- must not collide with existing note IDs
 - Evidence field: list start_ms values from supporting transcript segments
+- Kind field (always set): the section the note goes under -- "decision" (Decisions: agreed or chosen), "action" (Action Items: someone will do something), "question" (Open Questions: raised, not yet answered), otherwise "point" (Key Points)
 </format>
 
 ---
@@ -142,6 +148,7 @@ FORMAT RULES:
 "#,
         schema = JSON_SCHEMA_SAMPLE,
         empty_ops = EMPTY_OPS,
+        max_ops_rule = max_ops_rule(max_ops),
         context_block = context_block,
         participants_block = participants_block,
         notes_block = notes_block,
@@ -149,18 +156,111 @@ FORMAT RULES:
     )
 }
 
-pub(crate) fn parse_patch(output: &str) -> Result<NotesPatch, SummarizeError> {
-    if let Ok(payload) = serde_json::from_str::<PatchPayload>(output) {
-        return Ok(payload.into_patch());
+fn max_ops_rule(max_ops: usize) -> String {
+    match max_ops {
+        1 => "Max 1 op per response; 0 is normal".to_string(),
+        n => format!(
+            "Max {n} ops per response; 0-{} is normal",
+            n.saturating_sub(1)
+        ),
     }
+}
+
+/// One `[start_ms-end_ms] Speaker: text` line per segment.
+pub(crate) fn format_transcript(segments: &[TranscriptSegment]) -> String {
+    segments
+        .iter()
+        .map(|s| {
+            let text = s.text.trim();
+            match s.speaker.as_deref() {
+                Some(speaker) if !speaker.is_empty() => {
+                    format!("[{}-{}] {speaker}: {text}", s.start_ms, s.end_ms)
+                }
+                _ => format!("[{}-{}] {text}", s.start_ms, s.end_ms),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub(crate) fn context_block(context: Option<&str>) -> String {
+    context
+        .filter(|value| !value.is_empty())
+        .map(|value| format!("Context:\n{value}\n\n"))
+        .unwrap_or_default()
+}
 
-    let json = extract_json_object(output)
-        .ok_or_else(|| SummarizeError::InvalidResponse("no json object found".into()))?;
-    let payload: PatchPayload =
-        serde_json::from_str(json).map_err(|e| SummarizeError::InvalidResponse(e.to_string()))?;
+pub(crate) fn participants_block(participants: &[String]) -> String {
+    let participants_list = participants
+        .iter()
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<_>>();
+    if participants_list.is_empty() {
+        String::new()
+    } else {
+        format!("Participants: {}\n\n", participants_list.join(", "))
+    }
+}
+
+/// Schema for the live notes patch, sent as the provider's structured output format.
+pub(crate) fn patch_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["ops"],
+        "properties": {
+            "ops": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["op"],
+                    "properties": {
+                        "op": {"enum": ["add", "remove", "merge", "reorder"]},
+                        "id": {"type": "string"},
+                        "kind": {"type": "string"},
+                        "text": {"type": "string"},
+                        "evidence": {"type": "array", "items": {"type": "integer"}},
+                        "from": {"type": "array", "items": {"type": "string"}},
+                        "ids": {"type": "array", "items": {"type": "string"}},
+                    },
+                },
+            },
+        },
+    })
+}
+
+pub(crate) fn parse_patch(output: &str) -> Result<NotesPatch, SummarizeError> {
+    parse_patch_against(output, &patch_schema())
+}
+
+/// Parse a notes patch whose ops are constrained by `schema` rather than the live one.
+pub(crate) fn parse_patch_against(
+    output: &str,
+    schema: &Value,
+) -> Result<NotesPatch, SummarizeError> {
+    let payload: PatchPayload = parse_json_payload(output, schema)?;
     Ok(payload.into_patch())
 }
 
+/// Parse model output as JSON, falling back to the first embedded `{...}` object,
+/// and check it against `schema` before deserializing.
+pub(crate) fn parse_json_payload<T: DeserializeOwned>(
+    output: &str,
+    schema: &Value,
+) -> Result<T, SummarizeError> {
+    let value = match serde_json::from_str::<Value>(output) {
+        Ok(value) => value,
+        Err(_) => {
+            let json = extract_json_object(output)
+                .ok_or_else(|| SummarizeError::InvalidResponse("no json object found".into()))?;
+            serde_json::from_str(json)
+                .map_err(|e| SummarizeError::InvalidResponse(e.to_string()))?
+        }
+    };
+    schema::validate(&value, schema).map_err(SummarizeError::InvalidResponse)?;
+    serde_json::from_value(value).map_err(|e| SummarizeError::InvalidResponse(e.to_string()))
+}
+
 #[derive(Deserialize)]
 struct PatchPayload {
     #[serde(default)]
@@ -183,13 +283,41 @@ enum PatchOpPayload {
         text: String,
         #[serde(default)]
         evidence: Vec<u64>,
+        /// Free text so an unknown kind does not reject the whole patch.
+        #[serde(default)]
+        kind: Option<String>,
+    },
+    Remove {
+        id: String,
+    },
+    Merge {
+        id: String,
+        #[serde(default)]
+        from: Vec<String>,
+        text: String,
+    },
+    Reorder {
+        ids: Vec<String>,
     },
 }
 
 impl From<PatchOpPayload> for NotesOp {
     fn from(value: PatchOpPayload) -> Self {
         match value {
-            PatchOpPayload::Add { id, text, evidence } => NotesOp::Add { id, text, evidence },
+            PatchOpPayload::Add {
+                id,
+                text,
+                evidence,
+                kind,
+            } => NotesOp::Add {
+                id,
+                text,
+                evidence,
+                kind: kind.as_deref().and_then(NoteKind::parse),
+            },
+            PatchOpPayload::Remove { id } => NotesOp::Remove { id },
+            PatchOpPayload::Merge { id, from, text } => NotesOp::Merge { id, from, text },
+            PatchOpPayload::Reorder { ids } => NotesOp::Reorder { ids },
         }
     }
 }
@@ -205,8 +333,8 @@ fn extract_json_object(input: &str) -> Option<&str> {
 
 #[cfg(test)]
 mod tests {
-    use super::{build_prompt, extract_json_object, parse_patch};
-    use crate::types::{MeetingNotes, NoteBullet, TranscriptSegment};
+    use super::{DEFAULT_MAX_OPS, build_prompt, extract_json_object, parse_patch};
+    use crate::types::{MeetingNotes, NoteBullet, NoteKind, NotesOp, TranscriptSegment};
 
     fn seg(id: u64, text: &str) -> TranscriptSegment {
         TranscriptSegment {
@@ -216,6 +344,7 @@ mod tests {
             speaker: None,
             text: text.to_string(),
             finalized: true,
+            confidence: None,
         }
     }
 
@@ -239,6 +368,25 @@ mod tests {
         assert_eq!(patch.ops.len(), 1);
     }
 
+    #[test]
+    fn parse_patch_cleanup_ops() {
+        let output = r#"{"ops":[
+            {"op":"merge","id":"n1","from":["n2"],"text":"combined"},
+            {"op":"remove","id":"n3"},
+            {"op":"reorder","ids":["n5","n4"]}
+        ]}"#;
+        let patch = parse_patch(output).unwrap();
+        assert!(matches!(
+            &patch.ops[..],
+            [
+                NotesOp::Merge { .. },
+                NotesOp::Remove { .. },
+                NotesOp::Reorder { ids }
+            ] if ids == &["n5".to_string(), "n4".to_string()]
+        ));
+        assert!(parse_patch(r#"{"ops":[{"op":"remove"}]}"#).is_err());
+    }
+
     #[test]
     fn parse_patch_with_wrapped_json() {
         let output = "text {\"ops\": []} more";
@@ -248,7 +396,13 @@ mod tests {
 
     #[test]
     fn build_prompt_includes_transcript() {
-        let prompt = build_prompt(&[seg(1, "hello")], &MeetingNotes::default(), None, &[]);
+        let prompt = build_prompt(
+            &[seg(1, "hello")],
+            &MeetingNotes::default(),
+            None,
+            &[],
+            DEFAULT_MAX_OPS,
+        );
         assert!(prompt.contains("<input_transcript>"));
     }
 
@@ -259,6 +413,7 @@ mod tests {
             &MeetingNotes::default(),
             Some("team sync"),
             &[],
+            DEFAULT_MAX_OPS,
         );
         assert!(prompt.contains("Context:"));
         assert!(prompt.contains("team sync"));
@@ -271,6 +426,7 @@ mod tests {
             &MeetingNotes::default(),
             None,
             &[],
+            DEFAULT_MAX_OPS,
         );
         assert!(prompt.contains("keep"));
         assert!(prompt.contains("drop"));
@@ -278,17 +434,32 @@ mod tests {
 
     #[test]
     fn build_prompt_is_information_dense() {
-        let prompt = build_prompt(&[seg(1, "alpha")], &MeetingNotes::default(), None, &[]);
+        let prompt = build_prompt(
+            &[seg(1, "alpha")],
+            &MeetingNotes::default(),
+            None,
+            &[],
+            DEFAULT_MAX_OPS,
+        );
         assert!(prompt.contains("WHAT TO CAPTURE"));
         assert!(prompt.contains("<=120"));
+        assert!(prompt.contains("FIXING EXISTING NOTES"));
         assert!(prompt.contains("Max 3 ops per response"));
+        let capped = build_prompt(&[seg(1, "alpha")], &MeetingNotes::default(), None, &[], 1);
+        assert!(capped.contains("Max 1 op per response"));
     }
 
     #[test]
     fn build_prompt_includes_speaker_labels() {
         let mut with_speaker = seg(1, "hello");
         with_speaker.speaker = Some("Me".to_string());
-        let prompt = build_prompt(&[with_speaker], &MeetingNotes::default(), None, &[]);
+        let prompt = build_prompt(
+            &[with_speaker],
+            &MeetingNotes::default(),
+            None,
+            &[],
+            DEFAULT_MAX_OPS,
+        );
         assert!(prompt.contains("Me: hello"));
     }
 
@@ -300,6 +471,7 @@ mod tests {
             &MeetingNotes::default(),
             None,
             &participants,
+            DEFAULT_MAX_OPS,
         );
         assert!(prompt.contains("Participants: Han, Sarah"));
     }
@@ -311,10 +483,14 @@ mod tests {
             id: "n_1".to_string(),
             text: "Decision: ship by Friday".to_string(),
             evidence: vec![1],
+            done: false,
+            owner: None,
+            due: None,
+            kind: NoteKind::Point,
         });
-        let prompt = build_prompt(&[seg(1, "hello")], &notes, None, &[]);
-        assert!(prompt.contains("Existing notes (avoid duplicates):"));
-        assert!(prompt.contains("n_1"));
-        assert!(prompt.contains("Decision: ship by Friday"));
+        let prompt = build_prompt(&[seg(1, "hello")], &notes, None, &[], DEFAULT_MAX_OPS);
+        assert!(prompt.contains(
+            "Existing notes (avoid duplicates):\nKey Points:\n- n_1: Decision: ship by Friday"
+        ));
     }
 }
@@ -0,0 +1,249 @@
+use crate::SummarizeError;
+use serde_json::Value;
+
+use super::SummarizeProvider;
+
+/// Run `prompt` with output constrained to `schema`, then `parse` the reply,
+/// asking the model once to fix a reply that does not parse.
+pub(crate) fn generate_structured<T>(
+    provider: &mut dyn SummarizeProvider,
+    prompt: &str,
+    schema: &Value,
+    parse: fn(&str) -> Result<T, SummarizeError>,
+) -> Result<T, SummarizeError> {
+    let output = provider.generate_json(prompt, schema)?;
+    parse_or_repair(provider, prompt, schema, &output, parse)
+}
+
+/// `parse` an already generated reply; on `InvalidResponse` re-prompt once with
+/// the error and the rejected reply before surfacing the second failure.
+pub(crate) fn parse_or_repair<T>(
+    provider: &mut dyn SummarizeProvider,
+    prompt: &str,
+    schema: &Value,
+    output: &str,
+    parse: fn(&str) -> Result<T, SummarizeError>,
+) -> Result<T, SummarizeError> {
+    match parse(output.trim()) {
+        Err(SummarizeError::InvalidResponse(error)) => {
+            let retry = provider.generate_json(&repair_prompt(prompt, output, &error), schema)?;
+            parse(retry.trim())
+        }
+        result => result,
+    }
+}
+
+fn repair_prompt(prompt: &str, output: &str, error: &str) -> String {
+    format!(
+        r#"{prompt}
+
+<previous_reply>
+{output}
+</previous_reply>
+
+<fix>
+Your previous reply was rejected: {error}.
+Reply again with only the corrected JSON matching the schema, no commentary.
+</fix>
+"#,
+        output = output.trim(),
+    )
+}
+
+/// Check `value` against the subset of JSON Schema the summarize passes use:
+/// `type`, `enum`, `properties`, `required`, `items`, and local `$ref`s.
+pub(crate) fn validate(value: &Value, schema: &Value) -> Result<(), String> {
+    validate_at(value, schema, schema, "$")
+}
+
+fn validate_at(value: &Value, schema: &Value, root: &Value, path: &str) -> Result<(), String> {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let target = reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+            .ok_or_else(|| format!("unresolved schema reference {reference}"))?;
+        return validate_at(value, target, root, path);
+    }
+    if let Some(expected) = schema.get("type") {
+        let matches = match expected {
+            Value::String(name) => type_matches(value, name),
+            Value::Array(names) => names
+                .iter()
+                .filter_map(Value::as_str)
+                .any(|name| type_matches(value, name)),
+            _ => true,
+        };
+        if !matches {
+            return Err(format!("{path} should be of type {expected}"));
+        }
+    }
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array)
+        && !allowed.contains(value)
+    {
+        return Err(format!(
+            "{path} should be one of {}",
+            Value::from(allowed.clone())
+        ));
+    }
+    if let Value::Object(fields) = value {
+        for name in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if !fields.contains_key(name) {
+                return Err(format!("{path} is missing required field `{name}`"));
+            }
+        }
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (name, field) in fields {
+                if let Some(field_schema) = properties.get(name) {
+                    validate_at(field, field_schema, root, &format!("{path}.{name}"))?;
+                }
+            }
+        }
+    }
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (index, item) in items.iter().enumerate() {
+            validate_at(item, item_schema, root, &format!("{path}[{index}]"))?;
+        }
+    }
+    Ok(())
+}
+
+fn type_matches(value: &Value, name: &str) -> bool {
+    match name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_structured, validate};
+    use crate::SummarizeError;
+    use crate::summarize::SummarizeProvider;
+    use crate::summarize::patch::parse_json_payload;
+    use crate::types::{MeetingNotes, SummarizeEvent, TranscriptSegment};
+    use serde::Deserialize;
+    use serde_json::{Value, json};
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "required": ["items"],
+            "properties": {
+                "items": {"type": "array", "items": {"$ref": "#/$defs/item"}},
+            },
+            "$defs": {
+                "item": {
+                    "type": "object",
+                    "required": ["name"],
+                    "properties": {
+                        "name": {"type": "string"},
+                        "kind": {"enum": ["a", "b"]},
+                    },
+                },
+            },
+        })
+    }
+
+    #[derive(Deserialize)]
+    struct Payload {
+        items: Vec<Item>,
+    }
+
+    #[derive(Deserialize)]
+    struct Item {
+        name: String,
+    }
+
+    fn parse(output: &str) -> Result<Payload, SummarizeError> {
+        parse_json_payload(output, &schema())
+    }
+
+    struct ScriptedProvider {
+        replies: Vec<&'static str>,
+        prompts: Vec<String>,
+        schemas: Vec<Value>,
+    }
+
+    impl SummarizeProvider for ScriptedProvider {
+        fn name(&self) -> &'static str {
+            "scripted"
+        }
+
+        fn summarize(
+            &mut self,
+            _recent_segments: &[TranscriptSegment],
+            _notes: &MeetingNotes,
+            _context: Option<&str>,
+            _participants: &[String],
+            _on_event: &mut dyn FnMut(SummarizeEvent),
+        ) -> Result<(), SummarizeError> {
+            Ok(())
+        }
+
+        fn generate(&mut self, _prompt: &str) -> Result<String, SummarizeError> {
+            unreachable!("structured passes use generate_json")
+        }
+
+        fn generate_json(
+            &mut self,
+            prompt: &str,
+            schema: &Value,
+        ) -> Result<String, SummarizeError> {
+            self.prompts.push(prompt.to_string());
+            self.schemas.push(schema.clone());
+            Ok(self.replies.remove(0).to_string())
+        }
+    }
+
+    #[test]
+    fn invalid_replies_get_one_repair_attempt() {
+        assert!(validate(&json!({"items": [{"name": "x", "kind": "a"}]}), &schema()).is_ok());
+        assert_eq!(
+            validate(&json!({"items": [{"kind": "a"}]}), &schema()),
+            Err("$.items[0] is missing required field `name`".to_string())
+        );
+        assert_eq!(
+            validate(&json!({"items": [{"name": 3}]}), &schema()),
+            Err("$.items[0].name should be of type \"string\"".to_string())
+        );
+        assert!(validate(&json!({"items": [{"name": "x", "kind": "c"}]}), &schema()).is_err());
+
+        let mut provider = ScriptedProvider {
+            replies: vec![
+                r#"{"items": [{"title": "x"}]}"#,
+                r#"{"items": [{"name": "x"}]}"#,
+            ],
+            prompts: Vec::new(),
+            schemas: Vec::new(),
+        };
+        let payload = generate_structured(&mut provider, "list things", &schema(), parse).unwrap();
+        assert_eq!(payload.items[0].name, "x");
+        assert_eq!(provider.schemas, [schema(), schema()]);
+        assert!(provider.prompts[1].starts_with("list things"));
+        assert!(provider.prompts[1].contains("missing required field `name`"));
+        assert!(provider.prompts[1].contains(r#"{"items": [{"title": "x"}]}"#));
+
+        let mut provider = ScriptedProvider {
+            replies: vec!["not json", "still not json"],
+            prompts: Vec::new(),
+            schemas: Vec::new(),
+        };
+        assert!(matches!(
+            generate_structured(&mut provider, "list things", &schema(), parse),
+            Err(SummarizeError::InvalidResponse(_))
+        ));
+        assert_eq!(provider.prompts.len(), 2);
+    }
+}
@@ -0,0 +1,232 @@
+use crate::SummarizeError;
+use crate::types::{ShowNotes, ShowNotesChapter, ShowNotesLink, ShowNotesQuote, TranscriptSegment};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::SummarizeProvider;
+use super::patch::{context_block, format_transcript, parse_json_payload, participants_block};
+use super::schema::generate_structured;
+
+/// Generate podcast show notes from a complete transcript in a single pass.
+pub fn generate_show_notes(
+    provider: &mut dyn SummarizeProvider,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> Result<ShowNotes, SummarizeError> {
+    let prompt = build_show_notes_prompt(segments, context, participants);
+    generate_structured(provider, &prompt, &show_notes_schema(), parse_show_notes)
+}
+
+fn build_show_notes_prompt(
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> String {
+    const JSON_SCHEMA_SAMPLE: &str = r#"
+{
+    "summary": "2-4 sentence episode summary",
+    "chapters": [{"start_ms": 0, "title": "..."}],
+    "quotes": [{"start_ms": 0, "speaker": "...", "text": "..."}],
+    "links": [{"label": "...", "url": "..."}]
+}
+"#;
+    format!(
+        r#"
+<task>
+You are writing show notes for a recorded podcast episode from its full transcript.
+</task>
+
+<schema>
+Output JSON matching this schema:
+{JSON_SCHEMA_SAMPLE}
+</schema>
+
+<rules>
+- summary: what the episode covers and why a listener should care, no spoilers of every point
+- chapters: 3-12 chapters in order; start_ms must be a start_ms value from the transcript; the first chapter starts at the first segment
+- chapter titles: short, specific, title case, <=60 characters
+- quotes: 2-5 memorable verbatim quotes, copied exactly from the transcript, with the segment start_ms and speaker
+- links: books, tools, websites, companies, or people explicitly mentioned; leave url empty unless it was spoken or is unambiguous
+- Do not invent content that is not in the transcript
+</rules>
+
+<input>
+<input_context>
+{context}
+</input_context>
+
+<input_participants>
+{participants}
+</input_participants>
+
+<input_transcript>
+{transcript}
+</input_transcript>
+</input>
+"#,
+        context = context_block(context),
+        participants = participants_block(participants),
+        transcript = format_transcript(segments),
+    )
+}
+
+fn show_notes_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["summary", "chapters", "quotes", "links"],
+        "properties": {
+            "summary": {"type": "string"},
+            "chapters": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["start_ms", "title"],
+                    "properties": {
+                        "start_ms": {"type": "integer"},
+                        "title": {"type": "string"},
+                    },
+                },
+            },
+            "quotes": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["start_ms", "text"],
+                    "properties": {
+                        "start_ms": {"type": "integer"},
+                        "speaker": {"type": ["string", "null"]},
+                        "text": {"type": "string"},
+                    },
+                },
+            },
+            "links": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["label"],
+                    "properties": {
+                        "label": {"type": "string"},
+                        "url": {"type": "string"},
+                    },
+                },
+            },
+        },
+    })
+}
+
+fn parse_show_notes(output: &str) -> Result<ShowNotes, SummarizeError> {
+    let payload: ShowNotesPayload = parse_json_payload(output, &show_notes_schema())?;
+    Ok(payload.into())
+}
+
+#[derive(Deserialize)]
+struct ShowNotesPayload {
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    chapters: Vec<ChapterPayload>,
+    #[serde(default)]
+    quotes: Vec<QuotePayload>,
+    #[serde(default)]
+    links: Vec<LinkPayload>,
+}
+
+#[derive(Deserialize)]
+struct ChapterPayload {
+    #[serde(default)]
+    start_ms: i64,
+    title: String,
+}
+
+#[derive(Deserialize)]
+struct QuotePayload {
+    #[serde(default)]
+    start_ms: i64,
+    #[serde(default)]
+    speaker: Option<String>,
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct LinkPayload {
+    label: String,
+    #[serde(default)]
+    url: String,
+}
+
+impl From<ShowNotesPayload> for ShowNotes {
+    fn from(value: ShowNotesPayload) -> Self {
+        let mut chapters: Vec<ShowNotesChapter> = value
+            .chapters
+            .into_iter()
+            .filter(|chapter| !chapter.title.trim().is_empty())
+            .map(|chapter| ShowNotesChapter {
+                start_ms: chapter.start_ms.max(0),
+                title: chapter.title.trim().to_string(),
+            })
+            .collect();
+        chapters.sort_by_key(|chapter| chapter.start_ms);
+        Self {
+            summary: value.summary.trim().to_string(),
+            chapters,
+            quotes: value
+                .quotes
+                .into_iter()
+                .filter(|quote| !quote.text.trim().is_empty())
+                .map(|quote| ShowNotesQuote {
+                    start_ms: quote.start_ms.max(0),
+                    speaker: quote.speaker.filter(|speaker| !speaker.trim().is_empty()),
+                    text: quote.text.trim().to_string(),
+                })
+                .collect(),
+            links: value
+                .links
+                .into_iter()
+                .filter(|link| !link.label.trim().is_empty())
+                .map(|link| ShowNotesLink {
+                    label: link.label.trim().to_string(),
+                    url: link.url.trim().to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_show_notes_prompt, parse_show_notes};
+    use crate::types::TranscriptSegment;
+
+    #[test]
+    fn parse_show_notes_sorts_chapters_and_drops_blanks() {
+        let output = r#"Here you go: {"summary":" An episode. ","chapters":[{"start_ms":9000,"title":"Second"},{"start_ms":0,"title":"Intro"},{"start_ms":5,"title":" "}],"quotes":[{"start_ms":100,"speaker":"","text":"ship it"}],"links":[{"label":"Rust book"}]}"#;
+        let notes = parse_show_notes(output).unwrap();
+        assert_eq!(notes.summary, "An episode.");
+        assert_eq!(notes.chapters.len(), 2);
+        assert_eq!(notes.chapters[0].title, "Intro");
+        assert_eq!(notes.quotes[0].speaker, None);
+        assert_eq!(notes.links[0].url, "");
+    }
+
+    #[test]
+    fn parse_show_notes_rejects_non_json() {
+        assert!(parse_show_notes("no notes today").is_err());
+    }
+
+    #[test]
+    fn show_notes_prompt_includes_transcript_and_schema() {
+        let segments = vec![TranscriptSegment {
+            id: 1,
+            start_ms: 1_000,
+            end_ms: 2_000,
+            speaker: Some("Them".to_string()),
+            text: "welcome to the show".to_string(),
+            finalized: true,
+            confidence: None,
+        }];
+        let prompt = build_show_notes_prompt(&segments, None, &[]);
+        assert!(prompt.contains("[1000-2000] Them: welcome to the show"));
+        assert!(prompt.contains("\"chapters\""));
+    }
+}
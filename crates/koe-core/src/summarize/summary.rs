@@ -0,0 +1,166 @@
+use crate::SummarizeError;
+use crate::types::{MeetingSummary, SummaryActionItem, TranscriptSegment};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::SummarizeProvider;
+use super::patch::{context_block, format_transcript, parse_json_payload, participants_block};
+use super::schema::generate_structured;
+
+/// Generate a structured meeting summary from the complete finalized transcript.
+pub fn generate_summary(
+    provider: &mut dyn SummarizeProvider,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> Result<MeetingSummary, SummarizeError> {
+    let prompt = build_summary_prompt(segments, context, participants);
+    generate_structured(provider, &prompt, &summary_schema(), parse_summary)
+}
+
+fn build_summary_prompt(
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> String {
+    const JSON_SCHEMA_SAMPLE: &str = r#"
+{
+    "overview": "2-4 sentence overview",
+    "decisions": ["..."],
+    "action_items": [{"text": "...", "owner": "..."}],
+    "open_questions": ["..."]
+}
+"#;
+    format!(
+        r#"
+<task>
+You are writing the final summary of a meeting from its complete transcript.
+</task>
+
+<schema>
+Output JSON matching this schema:
+{JSON_SCHEMA_SAMPLE}
+</schema>
+
+<rules>
+- overview: purpose of the meeting and the main outcome, plain prose
+- decisions: only things the group explicitly agreed on
+- action_items: concrete follow-ups starting with a verb; owner is a participant name when one was stated, otherwise null
+- open_questions: unresolved questions or topics deferred to later
+- Each list item is one short sentence (<=20 words); use empty lists when nothing applies
+- Do not invent content that is not in the transcript
+</rules>
+
+<input>
+<input_context>
+{context}
+</input_context>
+
+<input_participants>
+{participants}
+</input_participants>
+
+<input_transcript>
+{transcript}
+</input_transcript>
+</input>
+"#,
+        context = context_block(context),
+        participants = participants_block(participants),
+        transcript = format_transcript(segments),
+    )
+}
+
+fn summary_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["overview", "decisions", "action_items", "open_questions"],
+        "properties": {
+            "overview": {"type": "string"},
+            "decisions": {"type": "array", "items": {"type": "string"}},
+            "action_items": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["text"],
+                    "properties": {
+                        "text": {"type": "string"},
+                        "owner": {"type": ["string", "null"]},
+                    },
+                },
+            },
+            "open_questions": {"type": "array", "items": {"type": "string"}},
+        },
+    })
+}
+
+fn parse_summary(output: &str) -> Result<MeetingSummary, SummarizeError> {
+    let payload: SummaryPayload = parse_json_payload(output, &summary_schema())?;
+    Ok(payload.into())
+}
+
+#[derive(Deserialize)]
+struct SummaryPayload {
+    #[serde(default)]
+    overview: String,
+    #[serde(default)]
+    decisions: Vec<String>,
+    #[serde(default)]
+    action_items: Vec<ActionItemPayload>,
+    #[serde(default)]
+    open_questions: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ActionItemPayload {
+    text: String,
+    #[serde(default)]
+    owner: Option<String>,
+}
+
+impl From<SummaryPayload> for MeetingSummary {
+    fn from(value: SummaryPayload) -> Self {
+        Self {
+            overview: value.overview.trim().to_string(),
+            decisions: clean_items(value.decisions),
+            action_items: value
+                .action_items
+                .into_iter()
+                .filter(|item| !item.text.trim().is_empty())
+                .map(|item| SummaryActionItem {
+                    text: item.text.trim().to_string(),
+                    owner: item
+                        .owner
+                        .map(|owner| owner.trim().to_string())
+                        .filter(|owner| !owner.is_empty()),
+                })
+                .collect(),
+            open_questions: clean_items(value.open_questions),
+        }
+    }
+}
+
+fn clean_items(items: Vec<String>) -> Vec<String> {
+    items
+        .into_iter()
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_summary;
+
+    #[test]
+    fn parse_summary_trims_and_drops_empty_items() {
+        let output = r#"{"overview":" Planned the launch. ","decisions":["Ship Friday"," "],"action_items":[{"text":"Write changelog","owner":"Sam"},{"text":"Book room","owner":" "},{"text":""}],"open_questions":[]}"#;
+        let summary = parse_summary(output).unwrap();
+        assert_eq!(summary.overview, "Planned the launch.");
+        assert_eq!(summary.decisions, vec!["Ship Friday".to_string()]);
+        assert_eq!(summary.action_items.len(), 2);
+        assert_eq!(summary.action_items[0].owner.as_deref(), Some("Sam"));
+        assert_eq!(summary.action_items[1].owner, None);
+        assert!(summary.open_questions.is_empty());
+    }
+}
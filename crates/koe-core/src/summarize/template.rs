@@ -0,0 +1,97 @@
+use crate::SummarizeError;
+use crate::types::{MeetingNotes, SummarizeEvent, TranscriptSegment};
+
+use super::SummarizeProvider;
+use super::patch::{format_transcript, parse_patch, patch_schema};
+use super::schema::parse_or_repair;
+
+/// Render a user prompt template for the live notes pass. Supported
+/// placeholders: `{{transcript}}`, `{{context}}`, `{{participants}}`, and
+/// `{{notes}}`; anything else is left as written.
+pub fn render_template(
+    template: &str,
+    segments: &[TranscriptSegment],
+    notes: &MeetingNotes,
+    context: Option<&str>,
+    participants: &[String],
+) -> String {
+    let notes_list = notes
+        .bullets
+        .iter()
+        .map(|bullet| format!("- {}: {}", bullet.id, bullet.text.trim()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let participants_list = participants
+        .iter()
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ");
+    template
+        .replace("{{transcript}}", &format_transcript(segments))
+        .replace("{{context}}", context.unwrap_or_default().trim())
+        .replace("{{participants}}", &participants_list)
+        .replace("{{notes}}", &notes_list)
+}
+
+/// Live notes pass driven by a user template instead of the built-in prompt.
+/// The template must ask for the same `{"ops": [...]}` JSON as the built-in one.
+pub fn summarize_with_template(
+    provider: &mut dyn SummarizeProvider,
+    template: &str,
+    segments: &[TranscriptSegment],
+    notes: &MeetingNotes,
+    context: Option<&str>,
+    participants: &[String],
+    on_event: &mut dyn FnMut(SummarizeEvent),
+) -> Result<(), SummarizeError> {
+    let prompt = render_template(template, segments, notes, context, participants);
+    let output = provider.generate_streaming(&prompt, &mut |token| {
+        on_event(SummarizeEvent::DraftToken(token.to_string()));
+    })?;
+    let patch = parse_or_repair(provider, &prompt, &patch_schema(), &output, parse_patch)?;
+    on_event(SummarizeEvent::PatchReady(patch));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_template;
+    use crate::types::{MeetingNotes, NoteBullet, NoteKind, TranscriptSegment};
+
+    #[test]
+    fn template_placeholders_are_substituted() {
+        let segments = vec![TranscriptSegment {
+            id: 1,
+            start_ms: 0,
+            end_ms: 900,
+            speaker: Some("Me".to_string()),
+            text: "ship it Friday".to_string(),
+            finalized: true,
+            confidence: None,
+        }];
+        let notes = MeetingNotes {
+            bullets: vec![NoteBullet {
+                id: "n_1".to_string(),
+                text: "Beta planned".to_string(),
+                evidence: vec![1],
+                done: false,
+                owner: None,
+                due: None,
+                kind: NoteKind::Point,
+            }],
+        };
+        let prompt = render_template(
+            "ctx={{context}}\nwho={{participants}}\n{{notes}}\n{{transcript}}\n{{unknown}}",
+            &segments,
+            &notes,
+            Some(" launch sync "),
+            &["Alice".to_string(), " ".to_string(), "Bob".to_string()],
+        );
+
+        assert_eq!(
+            prompt,
+            "ctx=launch sync\nwho=Alice, Bob\n- n_1: Beta planned\n[0-900] Me: ship it Friday\n{{unknown}}"
+        );
+    }
+}
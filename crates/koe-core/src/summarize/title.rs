@@ -0,0 +1,128 @@
+use crate::SummarizeError;
+use crate::types::TranscriptSegment;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::SummarizeProvider;
+use super::patch::{context_block, format_transcript, parse_json_payload, participants_block};
+use super::schema::generate_structured;
+
+/// Longest title kept; longer ones are cut at a word boundary.
+const MAX_TITLE_CHARS: usize = 60;
+
+/// Name a finished meeting from its transcript, e.g. "Q4 launch plan review".
+pub fn generate_title(
+    provider: &mut dyn SummarizeProvider,
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> Result<String, SummarizeError> {
+    let prompt = build_title_prompt(segments, context, participants);
+    generate_structured(provider, &prompt, &title_schema(), parse_title)
+}
+
+fn title_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["title"],
+        "properties": {
+            "title": {"type": "string"},
+        },
+    })
+}
+
+fn parse_title(output: &str) -> Result<String, SummarizeError> {
+    let payload: TitlePayload = parse_json_payload(output, &title_schema())?;
+    Ok(clean_title(&payload.title))
+}
+
+/// Trimmed, unquoted, without a trailing period, at most [`MAX_TITLE_CHARS`].
+fn clean_title(title: &str) -> String {
+    let title = title
+        .trim()
+        .trim_matches(|c| matches!(c, '"' | '\'' | '`' | '“' | '”'))
+        .trim()
+        .trim_end_matches('.');
+    if title.chars().count() <= MAX_TITLE_CHARS {
+        return title.to_string();
+    }
+    let cut: String = title.chars().take(MAX_TITLE_CHARS).collect();
+    match cut.rfind(' ') {
+        Some(space) => cut[..space].trim_end().to_string(),
+        None => cut,
+    }
+}
+
+fn build_title_prompt(
+    segments: &[TranscriptSegment],
+    context: Option<&str>,
+    participants: &[String],
+) -> String {
+    const JSON_SCHEMA_SAMPLE: &str = r#"
+{
+    "title": "..."
+}
+"#;
+    format!(
+        r#"
+<task>
+Name this meeting from its complete transcript, as it would appear in a list of past meetings.
+</task>
+
+<schema>
+Output JSON matching this schema:
+{JSON_SCHEMA_SAMPLE}
+</schema>
+
+<rules>
+- title: short, specific noun phrase naming the main topic, 3-8 words, <=60 characters
+- No dates, no participant names unless the meeting is about them, no quotes or trailing period
+- Do not invent content that is not in the transcript
+</rules>
+
+<input>
+<input_context>
+{context}
+</input_context>
+
+<input_participants>
+{participants}
+</input_participants>
+
+<input_transcript>
+{transcript}
+</input_transcript>
+</input>
+"#,
+        context = context_block(context),
+        participants = participants_block(participants),
+        transcript = format_transcript(segments),
+    )
+}
+
+#[derive(Deserialize)]
+struct TitlePayload {
+    #[serde(default)]
+    title: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_title;
+
+    #[test]
+    fn parse_title_strips_quotes_and_caps_length() {
+        assert_eq!(
+            parse_title(r#"{"title":" \"Q4 launch plan review.\" "}"#).unwrap(),
+            "Q4 launch plan review"
+        );
+        let long = parse_title(
+            r#"{"title":"Quarterly planning for the platform team covering hiring budgets and roadmap"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            long,
+            "Quarterly planning for the platform team covering hiring"
+        );
+    }
+}
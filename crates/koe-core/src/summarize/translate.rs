@@ -0,0 +1,180 @@
+use crate::SummarizeError;
+use crate::types::TranscriptSegment;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::SummarizeProvider;
+use super::patch::{context_block, parse_json_payload};
+use super::schema::generate_structured;
+
+/// Translated text for one transcript segment.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SegmentTranslation {
+    pub id: u64,
+    pub text: String,
+}
+
+/// Translate a batch of transcript segments into `target_language`, one
+/// translation per segment id; ids the model invents are dropped.
+pub fn translate_segments(
+    provider: &mut dyn SummarizeProvider,
+    segments: &[TranscriptSegment],
+    target_language: &str,
+    context: Option<&str>,
+) -> Result<Vec<SegmentTranslation>, SummarizeError> {
+    if segments.is_empty() {
+        return Ok(Vec::new());
+    }
+    let prompt = build_translate_prompt(segments, target_language, context);
+    let payload = generate_structured(provider, &prompt, &translate_schema(), parse_translations)?;
+    Ok(payload
+        .translations
+        .into_iter()
+        .filter(|item| segments.iter().any(|seg| seg.id == item.id))
+        .map(|item| SegmentTranslation {
+            id: item.id,
+            text: item.text.trim().to_string(),
+        })
+        .filter(|item| !item.text.is_empty())
+        .collect())
+}
+
+fn build_translate_prompt(
+    segments: &[TranscriptSegment],
+    target_language: &str,
+    context: Option<&str>,
+) -> String {
+    const JSON_SCHEMA_SAMPLE: &str = r#"
+{
+    "translations": [{"id": 1, "text": "..."}]
+}
+"#;
+    let lines = segments
+        .iter()
+        .map(|seg| format!("[{}] {}", seg.id, seg.text.trim()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        r#"
+<task>
+You are translating a live meeting transcript into {target_language}, line by line.
+</task>
+
+<schema>
+Output JSON matching this schema:
+{JSON_SCHEMA_SAMPLE}
+</schema>
+
+<rules>
+- One entry per input line, using the id in brackets
+- Translate meaning faithfully; keep names, numbers, and dates as spoken
+- If a line is already in {target_language}, copy it unchanged
+- Do not merge, summarize, or add content
+</rules>
+
+<input>
+<input_context>
+{context}
+</input_context>
+
+<input_lines>
+{lines}
+</input_lines>
+</input>
+"#,
+        context = context_block(context),
+    )
+}
+
+fn translate_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["translations"],
+        "properties": {
+            "translations": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["id", "text"],
+                    "properties": {
+                        "id": {"type": "integer"},
+                        "text": {"type": "string"},
+                    },
+                },
+            },
+        },
+    })
+}
+
+fn parse_translations(output: &str) -> Result<TranslatePayload, SummarizeError> {
+    parse_json_payload(output, &translate_schema())
+}
+
+#[derive(Deserialize)]
+struct TranslatePayload {
+    #[serde(default)]
+    translations: Vec<SegmentTranslation>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SegmentTranslation, translate_segments};
+    use crate::SummarizeError;
+    use crate::summarize::SummarizeProvider;
+    use crate::types::{MeetingNotes, SummarizeEvent, TranscriptSegment};
+
+    struct FixedProvider {
+        prompt: String,
+        output: &'static str,
+    }
+
+    impl SummarizeProvider for FixedProvider {
+        fn name(&self) -> &'static str {
+            "fixed"
+        }
+
+        fn summarize(
+            &mut self,
+            _recent_segments: &[TranscriptSegment],
+            _notes: &MeetingNotes,
+            _context: Option<&str>,
+            _participants: &[String],
+            _on_event: &mut dyn FnMut(SummarizeEvent),
+        ) -> Result<(), SummarizeError> {
+            Ok(())
+        }
+
+        fn generate(&mut self, prompt: &str) -> Result<String, SummarizeError> {
+            self.prompt = prompt.to_string();
+            Ok(self.output.to_string())
+        }
+    }
+
+    #[test]
+    fn translations_keep_known_ids_only() {
+        let mut provider = FixedProvider {
+            prompt: String::new(),
+            output: r#"Sure: {"translations":[{"id":4,"text":" Let's start. "},{"id":99,"text":"extra"}]}"#,
+        };
+        let segments = vec![TranscriptSegment {
+            id: 4,
+            start_ms: 0,
+            end_ms: 1_000,
+            speaker: Some("Them".to_string()),
+            text: "Empecemos.".to_string(),
+            finalized: true,
+            confidence: None,
+        }];
+        let translations = translate_segments(&mut provider, &segments, "English", None).unwrap();
+
+        assert_eq!(
+            translations,
+            vec![SegmentTranslation {
+                id: 4,
+                text: "Let's start.".to_string()
+            }]
+        );
+        assert!(provider.prompt.contains("[4] Empecemos."));
+        assert!(provider.prompt.contains("into English"));
+    }
+}
@@ -0,0 +1,353 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::http::{default_agent, retry_delay, should_retry};
+use crate::usage::{UsageMeter, UsageRecord, assemblyai_audio_cost};
+use crate::{AudioChunk, AudioSource, TranscribeError, TranscriptSegment};
+
+use super::{TranscribeProvider, encode_wav};
+
+const DEFAULT_BASE_URL: &str = "https://api.assemblyai.com/v2";
+const DEFAULT_MODEL: &str = "universal";
+const MAX_RETRIES: usize = 4;
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Polling gives up after this many chunk durations (at least
+/// `MIN_POLL_TIMEOUT`), so a slow queue cannot stall the live pipeline for long;
+/// the chunk is retried later.
+const POLL_TIMEOUT_CHUNKS: u32 = 3;
+const MIN_POLL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Cloud transcribe provider using AssemblyAI, with speaker diarization.
+///
+/// Each chunk is uploaded, transcribed with `speaker_labels`, and polled until
+/// done. AssemblyAI assigns speaker letters per request, so they say nothing
+/// across chunks: a system chunk with one voice keeps the default `Them` label,
+/// and one with several is split into `Them A`, `Them B`, ... for that chunk
+/// only. Microphone chunks keep the default `Me` label.
+pub struct AssemblyAiProvider {
+    api_key: String,
+    model: String,
+    base_url: String,
+    segment_id: AtomicU64,
+    agent: ureq::Agent,
+    usage: Option<UsageMeter>,
+}
+
+#[derive(Deserialize)]
+struct UploadResponse {
+    upload_url: String,
+}
+
+#[derive(Deserialize)]
+struct TranscriptResponse {
+    id: String,
+    status: String,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    utterances: Option<Vec<Utterance>>,
+}
+
+#[derive(Deserialize)]
+struct Utterance {
+    #[serde(default)]
+    speaker: Option<String>,
+    start: i64,
+    end: i64,
+    text: String,
+    #[serde(default)]
+    confidence: Option<f32>,
+}
+
+impl AssemblyAiProvider {
+    pub fn new(model: Option<&str>, api_key: Option<&str>) -> Result<Self, TranscribeError> {
+        let api_key = api_key
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| TranscribeError::ModelLoad("assemblyai API key not set".into()))?
+            .to_string();
+        let base_url =
+            std::env::var("ASSEMBLYAI_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self {
+            api_key,
+            model: model.unwrap_or(DEFAULT_MODEL).to_owned(),
+            base_url,
+            segment_id: AtomicU64::new(0),
+            agent: default_agent(),
+            usage: None,
+        })
+    }
+
+    /// Run `call`, retrying transient failures; non-transient ones are not worth queueing.
+    fn with_retries<T>(
+        &self,
+        mut call: impl FnMut() -> Result<T, ureq::Error>,
+    ) -> Result<T, TranscribeError> {
+        let mut attempt = 0;
+        loop {
+            match call() {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    let retry = should_retry(&err);
+                    if retry && attempt < MAX_RETRIES {
+                        thread::sleep(retry_delay(attempt));
+                        attempt += 1;
+                        continue;
+                    }
+                    return Err(if retry {
+                        TranscribeError::Network(err.to_string())
+                    } else {
+                        TranscribeError::TranscribeFailed(err.to_string())
+                    });
+                }
+            }
+        }
+    }
+
+    fn upload(&self, wav_data: &[u8]) -> Result<String, TranscribeError> {
+        let url = format!("{}/upload", self.base_url);
+        let upload: UploadResponse = self.with_retries(|| {
+            self.agent
+                .post(&url)
+                .header("authorization", &self.api_key)
+                .content_type("application/octet-stream")
+                .send(wav_data)?
+                .into_body()
+                .read_json()
+        })?;
+        Ok(upload.upload_url)
+    }
+
+    fn submit(&self, audio_url: &str) -> Result<TranscriptResponse, TranscribeError> {
+        let url = format!("{}/transcript", self.base_url);
+        let body = json!({
+            "audio_url": audio_url,
+            "speech_model": self.model,
+            "speaker_labels": true,
+            "language_code": "en",
+        });
+        self.with_retries(|| {
+            self.agent
+                .post(&url)
+                .header("authorization", &self.api_key)
+                .send_json(&body)?
+                .into_body()
+                .read_json()
+        })
+    }
+
+    /// Poll until the transcript completes or fails, for at most `timeout`.
+    fn wait(
+        &self,
+        mut transcript: TranscriptResponse,
+        timeout: Duration,
+    ) -> Result<TranscriptResponse, TranscribeError> {
+        let url = format!("{}/transcript/{}", self.base_url, transcript.id);
+        let started = Instant::now();
+        loop {
+            match transcript.status.as_str() {
+                "completed" => return Ok(transcript),
+                "error" => {
+                    return Err(TranscribeError::TranscribeFailed(
+                        transcript
+                            .error
+                            .unwrap_or_else(|| "assemblyai transcript failed".to_string()),
+                    ));
+                }
+                _ if started.elapsed() >= timeout => {
+                    return Err(TranscribeError::Network(format!(
+                        "assemblyai transcript {} still {} after {}s",
+                        transcript.id,
+                        transcript.status,
+                        timeout.as_secs()
+                    )));
+                }
+                _ => thread::sleep(POLL_INTERVAL),
+            }
+            transcript = self.with_retries(|| {
+                self.agent
+                    .get(&url)
+                    .header("authorization", &self.api_key)
+                    .call()?
+                    .into_body()
+                    .read_json()
+            })?;
+        }
+    }
+
+    /// Segments from a completed transcript, offset to the chunk's start.
+    fn segments(
+        &self,
+        transcript: TranscriptResponse,
+        chunk: &AudioChunk,
+    ) -> Vec<TranscriptSegment> {
+        let base_ms = (chunk.start_pts_ns / 1_000_000) as i64;
+        let utterances = match transcript.utterances {
+            Some(utterances) if !utterances.is_empty() => utterances,
+            _ => {
+                let text = transcript.text.unwrap_or_default().trim().to_owned();
+                if text.is_empty() {
+                    return Vec::new();
+                }
+                let duration_ms =
+                    chunk.pcm_mono_f32.len() as i64 * 1000 / i64::from(chunk.sample_rate_hz.max(1));
+                vec![Utterance {
+                    speaker: None,
+                    start: 0,
+                    end: duration_ms,
+                    text,
+                    confidence: None,
+                }]
+            }
+        };
+        let mut speakers: Vec<&str> = utterances
+            .iter()
+            .filter_map(|utterance| utterance.speaker.as_deref())
+            .map(str::trim)
+            .filter(|speaker| !speaker.is_empty())
+            .collect();
+        speakers.sort_unstable();
+        speakers.dedup();
+        let split = chunk.source == AudioSource::System && speakers.len() > 1;
+        let label = |speaker: Option<&str>| {
+            let speaker = speaker.map(str::trim).filter(|_| split)?;
+            Some(format!("Them {speaker}"))
+        };
+        utterances
+            .iter()
+            .filter_map(|utterance| {
+                let text = utterance.text.trim().to_owned();
+                if text.is_empty() {
+                    return None;
+                }
+                Some(TranscriptSegment {
+                    id: self.segment_id.fetch_add(1, Ordering::Relaxed),
+                    start_ms: utterance.start + base_ms,
+                    end_ms: utterance.end + base_ms,
+                    speaker: label(utterance.speaker.as_deref()),
+                    text,
+                    finalized: false,
+                    confidence: utterance.confidence.map(|value| value.clamp(0.0, 1.0)),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Polling budget for `chunk`: a few chunk durations, so waiting stays in step
+/// with the chunk cadence.
+fn poll_timeout(chunk: &AudioChunk) -> Duration {
+    let audio = Duration::from_millis(
+        chunk.pcm_mono_f32.len() as u64 * 1000 / u64::from(chunk.sample_rate_hz.max(1)),
+    );
+    (audio * POLL_TIMEOUT_CHUNKS).max(MIN_POLL_TIMEOUT)
+}
+
+impl TranscribeProvider for AssemblyAiProvider {
+    fn name(&self) -> &'static str {
+        "assemblyai"
+    }
+
+    fn transcribe(
+        &mut self,
+        chunk: &AudioChunk,
+    ) -> Result<Vec<TranscriptSegment>, TranscribeError> {
+        let wav_data = encode_wav(&chunk.pcm_mono_f32, chunk.sample_rate_hz);
+        let audio_url = self.upload(&wav_data)?;
+        let transcript = self.wait(self.submit(&audio_url)?, poll_timeout(chunk))?;
+
+        if let Some(usage) = &self.usage {
+            let audio_ms =
+                chunk.pcm_mono_f32.len() as u64 * 1000 / u64::from(chunk.sample_rate_hz.max(1));
+            usage.record(UsageRecord {
+                provider: self.name().to_string(),
+                model: self.model.clone(),
+                audio_ms,
+                cost_usd: assemblyai_audio_cost(&self.model, audio_ms),
+                ..UsageRecord::default()
+            });
+        }
+
+        Ok(self.segments(transcript, chunk))
+    }
+
+    fn set_usage_meter(&mut self, meter: UsageMeter) {
+        self.usage = Some(meter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssemblyAiProvider, MIN_POLL_TIMEOUT, TranscriptResponse, poll_timeout};
+    use crate::{AudioChunk, AudioSource};
+    use std::time::Duration;
+
+    #[test]
+    fn utterances_map_to_labelled_segments_offset_to_the_chunk() {
+        let provider = AssemblyAiProvider::new(None, Some("key")).unwrap();
+        let transcript = || -> TranscriptResponse {
+            serde_json::from_str(
+                r#"{"id":"t1","status":"completed","text":"Hi there. Hello.","utterances":[
+                    {"speaker":"A","start":120,"end":900,"text":" Hi there. ","confidence":0.93},
+                    {"speaker":"B","start":1000,"end":1600,"text":"Hello.","confidence":0.88},
+                    {"speaker":"B","start":1700,"end":1800,"text":" "}
+                ]}"#,
+            )
+            .unwrap()
+        };
+        let mut chunk = AudioChunk {
+            source: AudioSource::System,
+            start_pts_ns: 5_000_000_000,
+            sample_rate_hz: 16_000,
+            pcm_mono_f32: vec![0.0; 32_000],
+        };
+
+        let segments = provider.segments(transcript(), &chunk);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].speaker.as_deref(), Some("Them A"));
+        assert_eq!(segments[0].text, "Hi there.");
+        assert_eq!((segments[0].start_ms, segments[0].end_ms), (5_120, 5_900));
+        assert_eq!(segments[1].speaker.as_deref(), Some("Them B"));
+        assert_ne!(segments[0].id, segments[1].id);
+
+        let single: TranscriptResponse = serde_json::from_str(
+            r#"{"id":"t3","status":"completed","utterances":[
+                {"speaker":"B","start":0,"end":500,"text":"Only me talking."}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(provider.segments(single, &chunk)[0].speaker, None);
+
+        chunk.source = AudioSource::Microphone;
+        assert!(
+            provider
+                .segments(transcript(), &chunk)
+                .iter()
+                .all(|segment| segment.speaker.is_none())
+        );
+
+        let plain: TranscriptResponse =
+            serde_json::from_str(r#"{"id":"t2","status":"completed","text":"just text"}"#).unwrap();
+        let segments = provider.segments(plain, &chunk);
+        assert_eq!(segments[0].text, "just text");
+        assert_eq!((segments[0].start_ms, segments[0].end_ms), (5_000, 7_000));
+    }
+
+    #[test]
+    fn polling_is_bounded_by_the_chunk_length() {
+        let chunk = |seconds: usize| AudioChunk {
+            source: AudioSource::System,
+            start_pts_ns: 0,
+            sample_rate_hz: 16_000,
+            pcm_mono_f32: vec![0.0; 16_000 * seconds],
+        };
+        assert_eq!(poll_timeout(&chunk(2)), MIN_POLL_TIMEOUT);
+        assert_eq!(poll_timeout(&chunk(6)), Duration::from_secs(18));
+    }
+}
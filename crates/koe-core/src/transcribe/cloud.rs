@@ -5,20 +5,24 @@ use serde::Deserialize;
 use ureq::unversioned::multipart::{Form, Part};
 
 use crate::http::{default_agent, retry_delay, should_retry};
+use crate::usage::{UsageMeter, UsageRecord, groq_audio_cost};
 use crate::{AudioChunk, TranscribeError, TranscriptSegment};
 
 use super::{TranscribeProvider, encode_wav};
 
-const GROQ_TRANSCRIPTIONS_URL: &str = "https://api.groq.com/openai/v1/audio/transcriptions";
+const DEFAULT_BASE_URL: &str = "https://api.groq.com/openai/v1";
 const DEFAULT_MODEL: &str = "whisper-large-v3-turbo";
-const MAX_RETRIES: usize = 2;
+/// Backoff runs 0.2, 0.4, 0.8, then 1.6 s before the chunk is reported as a network failure.
+const MAX_RETRIES: usize = 4;
 
 /// Cloud transcribe provider using the Groq Whisper API.
 pub struct GroqProvider {
     api_key: String,
     model: String,
+    transcriptions_url: String,
     segment_id: AtomicU64,
     agent: ureq::Agent,
+    usage: Option<UsageMeter>,
 }
 
 #[derive(Deserialize)]
@@ -32,6 +36,8 @@ struct GroqSegment {
     start: f64,
     end: f64,
     text: String,
+    #[serde(default)]
+    avg_logprob: Option<f64>,
 }
 
 impl GroqProvider {
@@ -41,11 +47,14 @@ impl GroqProvider {
             .filter(|value| !value.is_empty())
             .ok_or_else(|| TranscribeError::ModelLoad("cloud API key not set".into()))?
             .to_string();
+        let base_url = std::env::var("GROQ_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
         Ok(Self {
             api_key,
             model: model.unwrap_or(DEFAULT_MODEL).to_owned(),
+            transcriptions_url: format!("{base_url}/audio/transcriptions"),
             segment_id: AtomicU64::new(0),
             agent: default_agent(),
+            usage: None,
         })
     }
 }
@@ -79,7 +88,7 @@ impl TranscribeProvider for GroqProvider {
 
             let response = self
                 .agent
-                .post(GROQ_TRANSCRIPTIONS_URL)
+                .post(&self.transcriptions_url)
                 .header("Authorization", &format!("Bearer {}", self.api_key))
                 .send(form);
 
@@ -99,7 +108,13 @@ impl TranscribeProvider for GroqProvider {
                         thread::sleep(retry_delay(attempt));
                         continue;
                     }
-                    return Err(TranscribeError::Network(format!("{}", last_error.unwrap())));
+                    let message = format!("{}", last_error.unwrap());
+                    // Only transient failures are worth queueing for later.
+                    return Err(if retry {
+                        TranscribeError::Network(message)
+                    } else {
+                        TranscribeError::TranscribeFailed(message)
+                    });
                 }
             }
         }
@@ -112,6 +127,18 @@ impl TranscribeProvider for GroqProvider {
             )
         })?;
 
+        if let Some(usage) = &self.usage {
+            let audio_ms =
+                chunk.pcm_mono_f32.len() as u64 * 1000 / u64::from(chunk.sample_rate_hz.max(1));
+            usage.record(UsageRecord {
+                provider: self.name().to_string(),
+                model: self.model.clone(),
+                audio_ms,
+                cost_usd: groq_audio_cost(&self.model, audio_ms),
+                ..UsageRecord::default()
+            });
+        }
+
         let base_ms = (chunk.start_pts_ns / 1_000_000) as i64;
 
         let segments = match groq.segments {
@@ -130,6 +157,7 @@ impl TranscribeProvider for GroqProvider {
                         speaker: None,
                         text,
                         finalized: false,
+                        confidence: s.avg_logprob.map(|lp| lp.exp().clamp(0.0, 1.0) as f32),
                     })
                 })
                 .collect(),
@@ -146,6 +174,7 @@ impl TranscribeProvider for GroqProvider {
                         speaker: None,
                         text,
                         finalized: false,
+                        confidence: None,
                     }]
                 }
             }
@@ -153,4 +182,8 @@ impl TranscribeProvider for GroqProvider {
 
         Ok(segments)
     }
+
+    fn set_usage_meter(&mut self, meter: UsageMeter) {
+        self.usage = Some(meter);
+    }
 }
@@ -0,0 +1,279 @@
+use std::time::{Duration, Instant};
+
+use crate::usage::UsageMeter;
+use crate::{AudioChunk, TranscribeError, TranscriptSegment};
+
+use super::TranscribeProvider;
+
+/// Consecutive errors on the active provider before falling back to the next one.
+const MAX_CONSECUTIVE_ERRORS: u32 = 3;
+/// How often a fallen-back chain retries its primary provider.
+const PRIMARY_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Ordered chain of transcribe providers that moves to the next provider after
+/// repeated errors and periodically retries the primary one.
+///
+/// Segment ids are renumbered so they stay monotonic across provider switches.
+pub struct FailoverTranscribeProvider {
+    providers: Vec<Box<dyn TranscribeProvider>>,
+    active: usize,
+    consecutive_errors: u32,
+    last_switch: Instant,
+    next_id: u64,
+}
+
+impl FailoverTranscribeProvider {
+    pub fn new(providers: Vec<Box<dyn TranscribeProvider>>) -> Result<Self, TranscribeError> {
+        if providers.is_empty() {
+            return Err(TranscribeError::ModelLoad(
+                "failover chain requires at least one provider".into(),
+            ));
+        }
+        Ok(Self {
+            providers,
+            active: 0,
+            consecutive_errors: 0,
+            last_switch: Instant::now(),
+            next_id: 0,
+        })
+    }
+
+    /// Index of the provider currently handling chunks.
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    /// Swap the active provider (e.g. for a smaller model under load),
+    /// keeping the rest of the chain and the segment id sequence.
+    pub fn replace_active(&mut self, provider: Box<dyn TranscribeProvider>) {
+        self.providers[self.active] = provider;
+        self.consecutive_errors = 0;
+    }
+
+    fn switch_to(&mut self, index: usize) {
+        self.active = index;
+        self.consecutive_errors = 0;
+        self.last_switch = Instant::now();
+    }
+
+    fn renumber(&mut self, mut segments: Vec<TranscriptSegment>) -> Vec<TranscriptSegment> {
+        for seg in &mut segments {
+            seg.id = self.next_id;
+            self.next_id += 1;
+        }
+        segments
+    }
+}
+
+impl TranscribeProvider for FailoverTranscribeProvider {
+    fn name(&self) -> &'static str {
+        self.providers[self.active].name()
+    }
+
+    fn transcribe(
+        &mut self,
+        chunk: &AudioChunk,
+    ) -> Result<Vec<TranscriptSegment>, TranscribeError> {
+        if self.active > 0 && self.last_switch.elapsed() >= PRIMARY_RETRY_INTERVAL {
+            self.last_switch = Instant::now();
+            if let Ok(segments) = self.providers[0].transcribe(chunk) {
+                self.switch_to(0);
+                return Ok(self.renumber(segments));
+            }
+        }
+
+        loop {
+            match self.providers[self.active].transcribe(chunk) {
+                Ok(segments) => {
+                    self.consecutive_errors = 0;
+                    return Ok(self.renumber(segments));
+                }
+                Err(err) => {
+                    self.consecutive_errors += 1;
+                    let next = self.active + 1;
+                    if self.consecutive_errors < MAX_CONSECUTIVE_ERRORS
+                        || next >= self.providers.len()
+                    {
+                        return Err(err);
+                    }
+                    // Retry the same chunk on the fallback so the outage loses no audio.
+                    self.switch_to(next);
+                }
+            }
+        }
+    }
+
+    fn transcribe_partial(
+        &mut self,
+        chunk: &AudioChunk,
+    ) -> Result<Vec<TranscriptSegment>, TranscribeError> {
+        self.providers[self.active].transcribe_partial(chunk)
+    }
+
+    fn set_usage_meter(&mut self, meter: UsageMeter) {
+        for provider in &mut self.providers {
+            provider.set_usage_meter(meter.clone());
+        }
+    }
+
+    /// Warms every provider so a later fallback does not stall either.
+    fn warm_up(&mut self) -> Result<(), TranscribeError> {
+        let mut first_error = None;
+        for provider in &mut self.providers {
+            if let Err(err) = provider.warm_up() {
+                first_error.get_or_insert(err);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FailoverTranscribeProvider, MAX_CONSECUTIVE_ERRORS};
+    use crate::transcribe::TranscribeProvider;
+    use crate::{AudioChunk, AudioSource, TranscribeError, TranscriptSegment};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct FakeProvider {
+        name: &'static str,
+        failing: Arc<AtomicBool>,
+        warmed: Arc<AtomicBool>,
+    }
+
+    impl TranscribeProvider for FakeProvider {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn transcribe(
+            &mut self,
+            _chunk: &AudioChunk,
+        ) -> Result<Vec<TranscriptSegment>, TranscribeError> {
+            if self.failing.load(Ordering::Relaxed) {
+                return Err(TranscribeError::Network("offline".into()));
+            }
+            Ok(vec![TranscriptSegment {
+                id: 0,
+                start_ms: 0,
+                end_ms: 100,
+                speaker: None,
+                text: self.name.to_string(),
+                finalized: false,
+                confidence: None,
+            }])
+        }
+
+        fn warm_up(&mut self) -> Result<(), TranscribeError> {
+            self.warmed.store(true, Ordering::Relaxed);
+            if self.failing.load(Ordering::Relaxed) {
+                return Err(TranscribeError::Network("offline".into()));
+            }
+            Ok(())
+        }
+    }
+
+    fn chunk() -> AudioChunk {
+        AudioChunk {
+            source: AudioSource::System,
+            start_pts_ns: 0,
+            sample_rate_hz: 16_000,
+            pcm_mono_f32: vec![0.0; 1_600],
+        }
+    }
+
+    #[test]
+    fn falls_back_after_repeated_errors_and_keeps_ids_monotonic() {
+        let outage = Arc::new(AtomicBool::new(false));
+        let mut chain = FailoverTranscribeProvider::new(vec![
+            Box::new(FakeProvider {
+                name: "groq",
+                failing: Arc::clone(&outage),
+                warmed: Arc::default(),
+            }),
+            Box::new(FakeProvider {
+                name: "whisper",
+                failing: Arc::new(AtomicBool::new(false)),
+                warmed: Arc::default(),
+            }),
+        ])
+        .unwrap();
+
+        assert_eq!(chain.transcribe(&chunk()).unwrap()[0].id, 0);
+        outage.store(true, Ordering::Relaxed);
+        for _ in 1..MAX_CONSECUTIVE_ERRORS {
+            assert!(chain.transcribe(&chunk()).is_err());
+            assert_eq!(chain.name(), "groq");
+        }
+
+        let segments = chain.transcribe(&chunk()).unwrap();
+        assert_eq!(chain.name(), "whisper");
+        assert_eq!(segments[0].text, "whisper");
+        assert_eq!(segments[0].id, 1);
+
+        outage.store(false, Ordering::Relaxed);
+        chain.last_switch -= super::PRIMARY_RETRY_INTERVAL;
+        chain.transcribe(&chunk()).unwrap();
+        assert_eq!(chain.active_index(), 0);
+    }
+
+    #[test]
+    fn replacing_the_active_provider_keeps_ids_and_the_chain() {
+        let outage = Arc::new(AtomicBool::new(false));
+        let mut chain = FailoverTranscribeProvider::new(vec![
+            Box::new(FakeProvider {
+                name: "whisper",
+                failing: Arc::new(AtomicBool::new(false)),
+                warmed: Arc::default(),
+            }),
+            Box::new(FakeProvider {
+                name: "groq",
+                failing: Arc::clone(&outage),
+                warmed: Arc::default(),
+            }),
+        ])
+        .unwrap();
+        assert_eq!(chain.transcribe(&chunk()).unwrap()[0].id, 0);
+        assert_eq!(chain.transcribe(&chunk()).unwrap()[0].id, 1);
+
+        // Degraded mid-meeting: the new provider numbers its own segments from 0.
+        let degraded = Arc::new(AtomicBool::new(false));
+        chain.replace_active(Box::new(FakeProvider {
+            name: "whisper",
+            failing: Arc::clone(&degraded),
+            warmed: Arc::default(),
+        }));
+        assert_eq!(chain.transcribe(&chunk()).unwrap()[0].id, 2);
+
+        degraded.store(true, Ordering::Relaxed);
+        for _ in 1..MAX_CONSECUTIVE_ERRORS {
+            assert!(chain.transcribe(&chunk()).is_err());
+        }
+        let segments = chain.transcribe(&chunk()).unwrap();
+        assert_eq!(segments[0].text, "groq");
+        assert_eq!(segments[0].id, 3);
+    }
+
+    #[test]
+    fn warm_up_reaches_every_provider_and_reports_the_first_error() {
+        let warmed = Arc::new(AtomicBool::new(false));
+        let mut chain = FailoverTranscribeProvider::new(vec![
+            Box::new(FakeProvider {
+                name: "groq",
+                failing: Arc::new(AtomicBool::new(true)),
+                warmed: Arc::default(),
+            }),
+            Box::new(FakeProvider {
+                name: "whisper",
+                failing: Arc::new(AtomicBool::new(false)),
+                warmed: Arc::clone(&warmed),
+            }),
+        ])
+        .unwrap();
+
+        assert!(chain.warm_up().is_err());
+        assert!(warmed.load(Ordering::Relaxed));
+        assert_eq!(chain.active_index(), 0);
+    }
+}
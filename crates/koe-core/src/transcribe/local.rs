@@ -1,15 +1,60 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
+use whisper_rs::{
+    FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters, WhisperSegment,
+};
 
-use crate::{AudioChunk, TranscribeError, TranscriptSegment};
+use crate::{AudioChunk, AudioSource, TranscribeError, TranscriptSegment};
 
 use super::TranscribeProvider;
 
+/// Minimum rolling audio (16 kHz samples) before a partial pass runs, ~500 ms.
+const MIN_PARTIAL_SAMPLES: usize = 8_000;
+/// Rolling audio kept for partial passes (16 kHz samples), ~8 s.
+const MAX_ROLLING_SAMPLES: usize = 128_000;
+/// Silence decoded once at startup so Metal kernels and buffers are ready, ~500 ms.
+const WARM_UP_SAMPLES: usize = 8_000;
+
 /// Local transcribe provider using whisper.cpp via whisper-rs with Metal acceleration.
 pub struct WhisperProvider {
     ctx: WhisperContext,
     segment_id: AtomicU64,
+    rolling: Vec<RollingBuffer>,
+}
+
+/// Decoded whisper segment before it gets an id.
+struct RawSegment {
+    start_ms: i64,
+    end_ms: i64,
+    text: String,
+    confidence: Option<f32>,
+}
+
+/// In-progress audio for one source since its last final chunk.
+struct RollingBuffer {
+    source: AudioSource,
+    start_pts_ns: i128,
+    samples: Vec<f32>,
+}
+
+impl RollingBuffer {
+    fn end_pts_ns(&self) -> i128 {
+        self.start_pts_ns + samples_to_ns(self.samples.len())
+    }
+
+    /// Append a delta, restarting the buffer if audio went missing in between.
+    fn append(&mut self, chunk: &AudioChunk) {
+        if chunk.start_pts_ns != self.end_pts_ns() {
+            self.start_pts_ns = chunk.start_pts_ns;
+            self.samples.clear();
+        }
+        self.samples.extend_from_slice(&chunk.pcm_mono_f32);
+        if self.samples.len() > MAX_ROLLING_SAMPLES {
+            let excess = self.samples.len() - MAX_ROLLING_SAMPLES;
+            self.samples.drain(..excess);
+            self.start_pts_ns += samples_to_ns(excess);
+        }
+    }
 }
 
 impl WhisperProvider {
@@ -19,35 +64,41 @@ impl WhisperProvider {
         Ok(Self {
             ctx,
             segment_id: AtomicU64::new(0),
+            rolling: Vec::new(),
         })
     }
-}
-
-impl TranscribeProvider for WhisperProvider {
-    fn name(&self) -> &'static str {
-        "whisper"
-    }
 
-    fn transcribe(
-        &mut self,
-        chunk: &AudioChunk,
-    ) -> Result<Vec<TranscriptSegment>, TranscribeError> {
+    /// Run whisper over `samples` and return one `RawSegment` per non-empty segment.
+    fn run(
+        &self,
+        samples: &[f32],
+        base_ms: i64,
+        partial: bool,
+    ) -> Result<Vec<RawSegment>, TranscribeError> {
         let mut state = self
             .ctx
             .create_state()
             .map_err(|e| TranscribeError::TranscribeFailed(format!("{e}")))?;
 
-        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 5 });
+        let strategy = if partial {
+            SamplingStrategy::Greedy { best_of: 1 }
+        } else {
+            SamplingStrategy::Greedy { best_of: 5 }
+        };
+        let mut params = FullParams::new(strategy);
         params.set_language(Some("en"));
         params.set_n_threads(4);
         params.set_print_progress(false);
         params.set_print_realtime(false);
+        if partial {
+            params.set_single_segment(true);
+            params.set_no_context(true);
+        }
 
         state
-            .full(params, &chunk.pcm_mono_f32)
+            .full(params, samples)
             .map_err(|e| TranscribeError::TranscribeFailed(format!("{e}")))?;
 
-        let base_ms = (chunk.start_pts_ns / 1_000_000) as i64;
         let n_segments = state.full_n_segments();
         let mut segments = Vec::with_capacity(n_segments as usize);
 
@@ -68,17 +119,117 @@ impl TranscribeProvider for WhisperProvider {
             // whisper timestamps are in centiseconds (10ms units)
             let start_ms = base_ms + seg.start_timestamp() * 10;
             let end_ms = base_ms + seg.end_timestamp() * 10;
-            let id = self.segment_id.fetch_add(1, Ordering::Relaxed);
-            segments.push(TranscriptSegment {
-                id,
+            let confidence = mean_token_probability(&seg);
+            segments.push(RawSegment {
                 start_ms,
                 end_ms,
-                speaker: None,
                 text,
-                finalized: false,
+                confidence,
             });
         }
 
         Ok(segments)
     }
 }
+
+impl TranscribeProvider for WhisperProvider {
+    fn name(&self) -> &'static str {
+        "whisper"
+    }
+
+    fn transcribe(
+        &mut self,
+        chunk: &AudioChunk,
+    ) -> Result<Vec<TranscriptSegment>, TranscribeError> {
+        // The final chunk supersedes any partial audio buffered for this source.
+        self.rolling.retain(|buffer| buffer.source != chunk.source);
+
+        let base_ms = (chunk.start_pts_ns / 1_000_000) as i64;
+        let segments = self
+            .run(&chunk.pcm_mono_f32, base_ms, false)?
+            .into_iter()
+            .map(|raw| TranscriptSegment {
+                id: self.segment_id.fetch_add(1, Ordering::Relaxed),
+                start_ms: raw.start_ms,
+                end_ms: raw.end_ms,
+                speaker: None,
+                text: raw.text,
+                finalized: false,
+                confidence: raw.confidence,
+            })
+            .collect();
+
+        Ok(segments)
+    }
+
+    fn warm_up(&mut self) -> Result<(), TranscribeError> {
+        // Decoded directly so hallucinated text never takes a segment id.
+        self.run(&[0.0; WARM_UP_SAMPLES], 0, true).map(|_| ())
+    }
+
+    fn transcribe_partial(
+        &mut self,
+        chunk: &AudioChunk,
+    ) -> Result<Vec<TranscriptSegment>, TranscribeError> {
+        let index = match self
+            .rolling
+            .iter()
+            .position(|buffer| buffer.source == chunk.source)
+        {
+            Some(index) => index,
+            None => {
+                self.rolling.push(RollingBuffer {
+                    source: chunk.source,
+                    start_pts_ns: chunk.start_pts_ns,
+                    samples: Vec::new(),
+                });
+                self.rolling.len() - 1
+            }
+        };
+        self.rolling[index].append(chunk);
+
+        let buffer = &self.rolling[index];
+        if buffer.samples.len() < MIN_PARTIAL_SAMPLES {
+            return Ok(Vec::new());
+        }
+        let base_ms = (buffer.start_pts_ns / 1_000_000) as i64;
+        let segments = self
+            .run(&buffer.samples, base_ms, true)?
+            .into_iter()
+            .map(|raw| TranscriptSegment {
+                id: 0,
+                start_ms: raw.start_ms,
+                end_ms: raw.end_ms,
+                speaker: None,
+                text: raw.text,
+                finalized: false,
+                confidence: raw.confidence,
+            })
+            .collect();
+        Ok(segments)
+    }
+}
+
+/// Mean probability of the segment's text tokens, skipping special tokens like `[_BEG_]`.
+fn mean_token_probability(seg: &WhisperSegment<'_>) -> Option<f32> {
+    let mut sum = 0.0;
+    let mut count = 0;
+    for i in 0..seg.n_tokens() {
+        let Some(token) = seg.get_token(i) else {
+            continue;
+        };
+        if token
+            .to_str()
+            .is_ok_and(|text| text.starts_with("[_") || text.starts_with("<|"))
+        {
+            continue;
+        }
+        sum += token.token_probability();
+        count += 1;
+    }
+    (count > 0).then(|| sum / count as f32)
+}
+
+fn samples_to_ns(samples: usize) -> i128 {
+    (samples as i128 * 1_000_000_000) / 16_000
+}
@@ -1,13 +1,54 @@
+pub mod assemblyai;
 pub mod cloud;
+pub mod failover;
 pub mod local;
 
+use crate::usage::UsageMeter;
 use crate::{AudioChunk, TranscribeError, TranscriptSegment};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
 
 /// Speech-to-text provider abstraction.
 pub trait TranscribeProvider: Send {
     fn name(&self) -> &'static str;
     fn transcribe(&mut self, chunk: &AudioChunk)
     -> Result<Vec<TranscriptSegment>, TranscribeError>;
+
+    /// Transcribe in-progress audio into interim segments for live display.
+    ///
+    /// `chunk` holds only audio new since the previous call for its source.
+    /// Providers without streaming support return nothing.
+    fn transcribe_partial(
+        &mut self,
+        _chunk: &AudioChunk,
+    ) -> Result<Vec<TranscriptSegment>, TranscribeError> {
+        Ok(Vec::new())
+    }
+
+    /// Record billable usage into `meter`. Local providers ignore it.
+    fn set_usage_meter(&mut self, _meter: UsageMeter) {}
+
+    /// Pay one-time costs of the first inference before real audio arrives.
+    /// Providers without such costs (and billed cloud APIs) do nothing.
+    fn warm_up(&mut self) -> Result<(), TranscribeError> {
+        Ok(())
+    }
+}
+
+/// Provider that discards audio, used when recording without any transcription.
+pub struct RecordOnlyProvider;
+
+impl TranscribeProvider for RecordOnlyProvider {
+    fn name(&self) -> &'static str {
+        "none"
+    }
+
+    fn transcribe(
+        &mut self,
+        _chunk: &AudioChunk,
+    ) -> Result<Vec<TranscriptSegment>, TranscribeError> {
+        Ok(Vec::new())
+    }
 }
 
 /// Create a transcribe provider by name.
@@ -15,26 +56,103 @@ pub trait TranscribeProvider: Send {
 /// - `"whisper"` requires `model` pointing to a GGML model file path.
 /// - `"groq"` requires an API key; `model` selects the Groq model name
 ///   (defaults to `whisper-large-v3-turbo`).
+/// - `"assemblyai"` requires an API key; `model` selects the speech model
+///   (defaults to `universal`) and segments carry diarized speaker labels.
+/// - `"none"` records without transcribing.
+///
+/// Any other name must have been added with `register_transcribe_provider`.
 pub fn create_transcribe_provider(
     provider: &str,
     model: Option<&str>,
     api_key: Option<&str>,
 ) -> Result<Box<dyn TranscribeProvider>, TranscribeError> {
-    match provider {
-        "whisper" => {
-            let path = model.ok_or_else(|| {
-                TranscribeError::ModelLoad(
-                    "model path required for whisper provider (--transcribe-model /path/to/ggml-*.bin)"
-                        .into(),
+    // Clone the factory out so a slow model load does not hold the lock.
+    let factory = transcribe_registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(provider)
+        .cloned()
+        .ok_or_else(|| {
+            TranscribeError::ModelLoad(format!("unknown transcribe provider: {provider}"))
+        })?;
+    factory(model, api_key)
+}
+
+/// Builds a transcribe provider from a profile's model and API key.
+pub type TranscribeFactory = dyn Fn(Option<&str>, Option<&str>) -> Result<Box<dyn TranscribeProvider>, TranscribeError>
+    + Send
+    + Sync;
+
+/// Make `name` available to `create_transcribe_provider` (and so to config
+/// profiles), replacing any provider already registered under it, built-ins
+/// included.
+pub fn register_transcribe_provider<F>(name: &str, factory: F)
+where
+    F: Fn(Option<&str>, Option<&str>) -> Result<Box<dyn TranscribeProvider>, TranscribeError>
+        + Send
+        + Sync
+        + 'static,
+{
+    transcribe_registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(name.to_string(), Arc::new(factory));
+}
+
+/// Names `create_transcribe_provider` accepts, sorted.
+pub fn transcribe_provider_names() -> Vec<String> {
+    let mut names: Vec<String> = transcribe_registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .keys()
+        .cloned()
+        .collect();
+    names.sort();
+    names
+}
+
+fn transcribe_registry() -> &'static RwLock<HashMap<String, Arc<TranscribeFactory>>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, Arc<TranscribeFactory>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut factories: HashMap<String, Arc<TranscribeFactory>> = HashMap::new();
+        factories.insert("whisper".into(), Arc::new(whisper_factory));
+        factories.insert(
+            "groq".into(),
+            Arc::new(|model: Option<&str>, api_key: Option<&str>| {
+                Ok(Box::new(cloud::GroqProvider::new(model, api_key)?)
+                    as Box<dyn TranscribeProvider>)
+            }),
+        );
+        factories.insert(
+            "assemblyai".into(),
+            Arc::new(|model: Option<&str>, api_key: Option<&str>| {
+                Ok(
+                    Box::new(assemblyai::AssemblyAiProvider::new(model, api_key)?)
+                        as Box<dyn TranscribeProvider>,
                 )
-            })?;
-            Ok(Box::new(local::WhisperProvider::new(path)?))
-        }
-        "groq" => Ok(Box::new(cloud::GroqProvider::new(model, api_key)?)),
-        other => Err(TranscribeError::ModelLoad(format!(
-            "unknown transcribe provider: {other}"
-        ))),
-    }
+            }),
+        );
+        factories.insert(
+            "none".into(),
+            Arc::new(|_: Option<&str>, _: Option<&str>| {
+                Ok(Box::new(RecordOnlyProvider) as Box<dyn TranscribeProvider>)
+            }),
+        );
+        RwLock::new(factories)
+    })
+}
+
+fn whisper_factory(
+    model: Option<&str>,
+    _api_key: Option<&str>,
+) -> Result<Box<dyn TranscribeProvider>, TranscribeError> {
+    let path = model.ok_or_else(|| {
+        TranscribeError::ModelLoad(
+            "model path required for whisper provider (--transcribe-model /path/to/ggml-*.bin)"
+                .into(),
+        )
+    })?;
+    Ok(Box::new(local::WhisperProvider::new(path)?))
 }
 
 /// Encode f32 PCM samples as a WAV file (RIFF/WAVE, IEEE float32, mono).
@@ -85,10 +203,56 @@ pub fn encode_wav(samples: &[f32], sample_rate: u32) -> Vec<u8> {
     buf
 }
 
+/// Decode a mono IEEE float32 WAV as written by [`encode_wav`]; `None` for any other layout.
+pub fn decode_wav(bytes: &[u8]) -> Option<(Vec<f32>, u32)> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return None;
+    }
+    let mut sample_rate = None;
+    let mut offset = 12;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let size = u32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().ok()?) as usize;
+        let body = bytes.get(offset + 8..offset + 8 + size)?;
+        match id {
+            b"fmt " if body.len() >= 16 => {
+                let format = u16::from_le_bytes([body[0], body[1]]);
+                let channels = u16::from_le_bytes([body[2], body[3]]);
+                let bits = u16::from_le_bytes([body[14], body[15]]);
+                if format != 3 || channels != 1 || bits != 32 {
+                    return None;
+                }
+                sample_rate = Some(u32::from_le_bytes(body[4..8].try_into().ok()?));
+            }
+            b"data" => {
+                let samples = body
+                    .chunks_exact(4)
+                    .map(|raw| f32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]))
+                    .collect();
+                return Some((samples, sample_rate?));
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even length.
+        offset += 8 + size + size % 2;
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn registered_transcribe_providers_are_created_by_name() {
+        assert!(create_transcribe_provider("echo-test", None, None).is_err());
+        register_transcribe_provider("echo-test", |_, _| Ok(Box::new(RecordOnlyProvider)));
+        assert!(transcribe_provider_names().contains(&"echo-test".to_string()));
+        assert!(transcribe_provider_names().contains(&"whisper".to_string()));
+        let provider = create_transcribe_provider("echo-test", None, None).unwrap();
+        assert_eq!(provider.name(), "none");
+    }
+
     #[test]
     fn wav_encoder_produces_valid_header() {
         let samples = vec![0.0f32; 160]; // 10ms at 16kHz
@@ -140,5 +304,7 @@ mod tests {
             ]);
             assert_eq!(value, expected);
         }
+        assert_eq!(decode_wav(&wav), Some((samples, 16000)));
+        assert_eq!(decode_wav(b"RIFF\0\0\0\0WAVE"), None);
     }
 }
@@ -3,6 +3,20 @@ use crate::TranscriptSegment;
 const MUTABLE_WINDOW_MS: i64 = 15_000;
 const SIMILARITY_THRESHOLD: f64 = 0.5;
 const MAX_SEGMENTS: usize = 2_000;
+/// Speaker playback reaches the other source this late at most.
+const ECHO_LAG_MS: i64 = 1_500;
+/// Share of the shorter line's words that must match for a cross-source echo.
+const ECHO_SIMILARITY: f64 = 0.7;
+/// Shorter lines are too likely to be genuine crosstalk ("yeah", "okay").
+const ECHO_MIN_WORDS: usize = 3;
+
+/// An unfinalized segment the ledger swapped for a newer transcription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentReplacement {
+    /// Id of the replacing segment now in the ledger.
+    pub id: u64,
+    pub previous_text: String,
+}
 
 /// Ordered ledger of transcript segments with overlap-aware deduplication.
 ///
@@ -10,6 +24,9 @@ const MAX_SEGMENTS: usize = 2_000;
 /// consecutive transcribe calls may produce segments covering the same audio.
 /// `append` merges incoming segments against existing ones using temporal
 /// overlap and text similarity to decide whether to replace or keep both.
+/// A line heard by both the microphone (`Me`) and system audio (another
+/// speaker) at nearly the same time is an echo and is kept once, under the
+/// source that started it first.
 pub struct TranscriptLedger {
     segments: Vec<TranscriptSegment>,
     highest_end_ms: i64,
@@ -24,15 +41,47 @@ impl TranscriptLedger {
     }
 
     /// Merge new transcription output into the ledger, deduplicating overlaps and
-    /// finalizing old segments that fall outside the overlap window.
-    pub fn append(&mut self, mut incoming: Vec<TranscriptSegment>) {
+    /// finalizing old segments that fall outside the overlap window. Returns the
+    /// replacements whose text changed.
+    pub fn append(&mut self, mut incoming: Vec<TranscriptSegment>) -> Vec<SegmentReplacement> {
         incoming.sort_by_key(|s| s.start_ms);
+        let mut replacements = Vec::new();
 
         for seg in incoming {
             if seg.end_ms > self.highest_end_ms {
                 self.highest_end_ms = seg.end_ms;
             }
 
+            // A known id is an edit of that segment (e.g. a user correction).
+            if let Some(existing) = self.segments.iter_mut().find(|s| s.id == seg.id) {
+                if existing.text.trim() != seg.text.trim() {
+                    replacements.push(SegmentReplacement {
+                        id: seg.id,
+                        previous_text: existing.text.clone(),
+                    });
+                }
+                *existing = seg;
+                continue;
+            }
+
+            if let Some(existing) = self
+                .segments
+                .iter_mut()
+                .find(|existing| is_cross_source_echo(existing, &seg))
+            {
+                // The echo trails the original; keep whichever line started first.
+                if !existing.finalized && seg.start_ms < existing.start_ms {
+                    if existing.text.trim() != seg.text.trim() {
+                        replacements.push(SegmentReplacement {
+                            id: seg.id,
+                            previous_text: existing.text.clone(),
+                        });
+                    }
+                    *existing = seg;
+                }
+                continue;
+            }
+
             if self
                 .segments
                 .iter()
@@ -50,6 +99,12 @@ impl TranscriptLedger {
                     && text_similarity(&existing.text, &seg.text) >= SIMILARITY_THRESHOLD
                 {
                     // Newer segment has more context -- replace the old one.
+                    if existing.text.trim() != seg.text.trim() {
+                        replacements.push(SegmentReplacement {
+                            id: seg.id,
+                            previous_text: existing.text.clone(),
+                        });
+                    }
                     *existing = seg.clone();
                     replaced = true;
                     break;
@@ -74,6 +129,7 @@ impl TranscriptLedger {
         }
 
         self.prune_finalized(MAX_SEGMENTS);
+        replacements
     }
 
     /// Full transcript read.
@@ -145,11 +201,77 @@ impl Default for TranscriptLedger {
     }
 }
 
+/// Flags each whitespace-separated word of `current` that is not part of the
+/// longest common word sequence with `previous` (case and punctuation ignored).
+pub fn changed_words(previous: &str, current: &str) -> Vec<bool> {
+    let normalize = |word: &str| {
+        word.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect::<String>()
+    };
+    let old: Vec<String> = previous.split_whitespace().map(normalize).collect();
+    let new: Vec<String> = current.split_whitespace().map(normalize).collect();
+
+    // lcs[i][j] = common length of old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut changed = vec![true; new.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            changed[j] = false;
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    changed
+}
+
 /// Two segments overlap if their time ranges intersect.
 fn overlaps(a: &TranscriptSegment, b: &TranscriptSegment) -> bool {
     a.start_ms <= b.end_ms && b.start_ms <= a.end_ms
 }
 
+/// The same words from the microphone and another speaker within the playback lag.
+fn is_cross_source_echo(a: &TranscriptSegment, b: &TranscriptSegment) -> bool {
+    let (Some(speaker_a), Some(speaker_b)) = (a.speaker.as_deref(), b.speaker.as_deref()) else {
+        return false;
+    };
+    if (speaker_a == "Me") == (speaker_b == "Me") {
+        return false;
+    }
+    if a.start_ms > b.end_ms + ECHO_LAG_MS || b.start_ms > a.end_ms + ECHO_LAG_MS {
+        return false;
+    }
+    let shorter = a
+        .text
+        .split_whitespace()
+        .count()
+        .min(b.text.split_whitespace().count());
+    if shorter < ECHO_MIN_WORDS {
+        return false;
+    }
+    let common = changed_words(&a.text, &b.text)
+        .iter()
+        .filter(|changed| !**changed)
+        .count();
+    common as f64 / shorter as f64 >= ECHO_SIMILARITY
+}
+
 /// Fast text similarity based on containment and longest common prefix/suffix.
 fn text_similarity(a: &str, b: &str) -> f64 {
     let na = a.to_lowercase();
@@ -190,6 +312,7 @@ mod tests {
             speaker: None,
             text: text.to_string(),
             finalized: false,
+            confidence: None,
         }
     }
 
@@ -229,6 +352,28 @@ mod tests {
         assert_eq!(ledger.segments()[0].text, "the quick brown fox");
     }
 
+    #[test]
+    fn replacement_reports_changed_words() {
+        let mut ledger = TranscriptLedger::new();
+        assert!(
+            ledger
+                .append(vec![seg(1, 0, 100, "we should ship the build on friday")])
+                .is_empty()
+        );
+        let replaced = ledger.append(vec![seg(2, 50, 200, "We should ship the bill on Friday.")]);
+        assert_eq!(
+            replaced,
+            vec![SegmentReplacement {
+                id: 2,
+                previous_text: "we should ship the build on friday".to_string(),
+            }]
+        );
+        assert_eq!(
+            changed_words(&replaced[0].previous_text, &ledger.segments()[0].text),
+            vec![false, false, false, false, true, false, false]
+        );
+    }
+
     #[test]
     fn dissimilar_overlap_kept() {
         let mut ledger = TranscriptLedger::new();
@@ -238,6 +383,41 @@ mod tests {
         assert_eq!(ledger.len(), 2);
     }
 
+    #[test]
+    fn cross_source_echo_kept_once_under_first_speaker() {
+        let speaker = |mut segment: TranscriptSegment, name: &str| {
+            segment.speaker = Some(name.to_string());
+            segment
+        };
+        let mut ledger = TranscriptLedger::new();
+        ledger.append(vec![speaker(
+            seg(1, 1_000, 3_000, "Can everyone see my screen now?"),
+            "Them",
+        )]);
+        // The mic picks up the speakers; it arrives first in the ledger but starts later.
+        ledger.append(vec![speaker(
+            seg(2, 1_400, 3_200, "can everyone see my screen"),
+            "Me",
+        )]);
+        assert_eq!(ledger.len(), 1);
+        assert_eq!(ledger.segments()[0].speaker.as_deref(), Some("Them"));
+
+        let replaced = ledger.append(vec![speaker(
+            seg(3, 800, 2_900, "Can everyone see my screen now"),
+            "Me",
+        )]);
+        assert_eq!(ledger.len(), 1);
+        assert_eq!(ledger.segments()[0].id, 3);
+        assert_eq!(replaced.len(), 1);
+
+        // A reply right after is its own line.
+        ledger.append(vec![speaker(
+            seg(4, 3_300, 4_500, "Yes, I can see your screen"),
+            "Them",
+        )]);
+        assert_eq!(ledger.len(), 2);
+    }
+
     #[test]
     fn finalization_after_window() {
         let mut ledger = TranscriptLedger::new();
@@ -260,6 +440,20 @@ mod tests {
         assert_eq!(ledger.segments()[0].id, 1);
     }
 
+    #[test]
+    fn known_id_edits_finalized_segment() {
+        let mut ledger = TranscriptLedger::new();
+        ledger.append(vec![seg(1, 0, 100, "deploy to cube")]);
+        ledger.append(vec![seg(2, 20_000, 21_000, "later")]);
+        let mut edit = seg(1, 0, 100, "deploy to Kubernetes");
+        edit.finalized = true;
+
+        let replaced = ledger.append(vec![edit]);
+        assert_eq!(replaced.len(), 1);
+        assert_eq!(ledger.len(), 2);
+        assert_eq!(ledger.segments()[0].text, "deploy to Kubernetes");
+    }
+
     #[test]
     fn segments_since_filters() {
         let mut ledger = TranscriptLedger::new();
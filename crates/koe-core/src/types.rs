@@ -1,6 +1,8 @@
+use crate::summarize::filter::normalize_text;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 
 /// A single audio frame from a capture source.
 pub struct AudioFrame {
@@ -35,20 +37,288 @@ pub struct TranscriptSegment {
     pub speaker: Option<String>,
     pub text: String,
     pub finalized: bool,
+    /// Provider confidence in `0.0..=1.0`, when the provider reports one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
 }
 
-/// Rolling meeting notes as a flat bullet stream.
+/// Rolling meeting notes as a bullet stream; each bullet's `kind` puts it in
+/// one of the sections key points, decisions, action items, and open questions.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MeetingNotes {
     pub bullets: Vec<NoteBullet>,
 }
 
+impl MeetingNotes {
+    /// Non-empty sections in `NoteKind::SECTIONS` order, bullets in stream order.
+    pub fn sections(&self) -> Vec<(NoteKind, Vec<&NoteBullet>)> {
+        NoteKind::SECTIONS
+            .into_iter()
+            .map(|kind| {
+                let bullets = self
+                    .bullets
+                    .iter()
+                    .filter(|bullet| bullet.kind == kind)
+                    .collect::<Vec<_>>();
+                (kind, bullets)
+            })
+            .filter(|(_, bullets)| !bullets.is_empty())
+            .collect()
+    }
+
+    /// Apply a live notes patch, returning whether anything changed. Adds
+    /// lose a leading `Me:`/`Them:` and are skipped when their id is taken,
+    /// they say nearly what an existing bullet says, or they carry fewer than
+    /// five content words.
+    pub fn apply_patch(&mut self, patch: NotesPatch) -> bool {
+        let mut changed = false;
+        let mut existing_ids: HashSet<String> = self
+            .bullets
+            .iter()
+            .map(|bullet| bullet.id.clone())
+            .collect();
+        let mut existing_normalized: HashSet<String> = self
+            .bullets
+            .iter()
+            .map(|bullet| normalize_text(&bullet.text))
+            .collect();
+
+        for op in patch.ops {
+            match op {
+                NotesOp::Add {
+                    id,
+                    text,
+                    evidence,
+                    kind,
+                } => {
+                    let cleaned_text = strip_note_prefixes(&text);
+                    let normalized_text = normalize_text(&cleaned_text);
+                    if normalized_text.is_empty()
+                        || existing_ids.contains(&id)
+                        || existing_normalized.contains(&normalized_text)
+                        || is_near_duplicate(&normalized_text, &existing_normalized)
+                        || !has_min_content_words(&normalized_text)
+                    {
+                        continue;
+                    }
+                    let kind = kind.unwrap_or_else(|| NoteKind::infer(&cleaned_text));
+                    self.bullets.push(NoteBullet {
+                        id: id.clone(),
+                        text: cleaned_text,
+                        evidence,
+                        done: false,
+                        owner: None,
+                        due: None,
+                        kind,
+                    });
+                    existing_ids.insert(id);
+                    existing_normalized.insert(normalized_text);
+                    changed = true;
+                }
+                NotesOp::Remove { id } => changed |= self.remove_note(&id),
+                NotesOp::Merge { id, from, text } => {
+                    changed |= self.merge_notes(&id, &from, &strip_note_prefixes(&text));
+                }
+                NotesOp::Reorder { ids } => changed |= self.reorder_notes(&ids),
+            }
+        }
+
+        changed
+    }
+
+    /// Drop the bullet `id`; false when there is none.
+    pub fn remove_note(&mut self, id: &str) -> bool {
+        let before = self.bullets.len();
+        self.bullets.retain(|bullet| bullet.id != id);
+        self.bullets.len() != before
+    }
+
+    /// Fold the `from` bullets into `id`, which keeps its place and takes
+    /// `text`, their evidence, and any owner, due date, or done mark it lacks.
+    ///
+    /// Ids missing from `bullets` belong to notes already archived to disk,
+    /// which stay as written: archived `from` ids are skipped, and when `id`
+    /// itself is archived the first live `from` bullet becomes the target.
+    /// False when no live bullet is named or nothing changes.
+    pub fn merge_notes(&mut self, id: &str, from: &[String], text: &str) -> bool {
+        let live = |id: &str| self.bullets.iter().position(|bullet| bullet.id == id);
+        let Some((index, id)) = std::iter::once(id)
+            .chain(from.iter().map(String::as_str))
+            .find_map(|id| live(id).map(|index| (index, id)))
+        else {
+            return false;
+        };
+        let text = text.trim();
+        let mut changed = false;
+        if !text.is_empty() && self.bullets[index].text != text {
+            self.bullets[index].text = text.to_string();
+            changed = true;
+        }
+        let mut merged = Vec::new();
+        self.bullets.retain(|bullet| {
+            let absorbed = bullet.id != id && from.contains(&bullet.id);
+            if absorbed {
+                merged.push(bullet.clone());
+            }
+            !absorbed
+        });
+        let Some(target) = self.bullets.iter_mut().find(|bullet| bullet.id == id) else {
+            return changed;
+        };
+        for bullet in merged {
+            for evidence in bullet.evidence {
+                if !target.evidence.contains(&evidence) {
+                    target.evidence.push(evidence);
+                }
+            }
+            target.done |= bullet.done;
+            if target.owner.is_none() {
+                target.owner = bullet.owner;
+            }
+            if target.due.is_none() {
+                target.due = bullet.due;
+            }
+            changed = true;
+        }
+        target.evidence.sort_unstable();
+        changed
+    }
+
+    /// Rearrange the bullets named in `ids` into that order, reusing the
+    /// slots they occupy so unnamed bullets stay put. Archived (or unknown)
+    /// ids are dropped first; the live ones keep their relative order.
+    pub fn reorder_notes(&mut self, ids: &[String]) -> bool {
+        let mut slots = Vec::new();
+        let mut ordered = Vec::new();
+        for id in ids {
+            let Some(index) = self.bullets.iter().position(|bullet| &bullet.id == id) else {
+                continue;
+            };
+            if !slots.contains(&index) {
+                slots.push(index);
+                ordered.push(self.bullets[index].clone());
+            }
+        }
+        if slots.len() < 2 {
+            return false;
+        }
+        slots.sort_unstable();
+        let mut changed = false;
+        for (slot, bullet) in slots.into_iter().zip(ordered) {
+            if self.bullets[slot].id != bullet.id {
+                self.bullets[slot] = bullet;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
 /// A single bullet note.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NoteBullet {
     pub id: String,
     pub text: String,
     pub evidence: Vec<u64>,
+    /// Marked complete by the user while reviewing notes.
+    #[serde(default)]
+    pub done: bool,
+    /// Participant the user assigned this item to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Free-form due date set during post-meeting review.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    #[serde(default)]
+    pub kind: NoteKind,
+}
+
+/// What a note records, for grouping notes while the meeting runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteKind {
+    #[default]
+    Point,
+    Decision,
+    Action,
+    Question,
+}
+
+impl NoteKind {
+    /// Order sections appear in when notes are grouped.
+    pub const SECTIONS: [NoteKind; 4] = [
+        NoteKind::Point,
+        NoteKind::Decision,
+        NoteKind::Action,
+        NoteKind::Question,
+    ];
+
+    /// Name used in patches and prompts.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Point => "point",
+            Self::Decision => "decision",
+            Self::Action => "action",
+            Self::Question => "question",
+        }
+    }
+
+    /// Heading for this kind's section.
+    pub fn section_title(self) -> &'static str {
+        match self {
+            Self::Point => "Key Points",
+            Self::Decision => "Decisions",
+            Self::Action => "Action Items",
+            Self::Question => "Open Questions",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "point" | "key_point" | "fact" => Some(Self::Point),
+            "decision" => Some(Self::Decision),
+            "action" | "action_item" | "task" => Some(Self::Action),
+            "question" | "open_question" => Some(Self::Question),
+            _ => None,
+        }
+    }
+
+    /// Kind guessed from the wording, for models that leave `kind` out.
+    pub fn infer(text: &str) -> Self {
+        let text = text.trim().to_lowercase();
+        let has = |words: &[&str]| words.iter().any(|word| text.contains(word));
+        if text.ends_with('?')
+            || text.starts_with("question")
+            || text.starts_with("open question")
+            || has(&["unclear whether", "to be determined", "tbd"])
+        {
+            Self::Question
+        } else if text.starts_with("decision")
+            || has(&[
+                "decided",
+                "agreed",
+                "will go with",
+                "approved",
+                "rejected",
+                "chose ",
+            ])
+        {
+            Self::Decision
+        } else if text.starts_with("action")
+            || text.starts_with("todo")
+            || has(&[
+                " will ",
+                "needs to",
+                "follow up",
+                "follow-up",
+                "assigned to",
+            ])
+        {
+            Self::Action
+        } else {
+            Self::Point
+        }
+    }
 }
 
 /// A patch operation on the meeting notes state.
@@ -58,7 +328,20 @@ pub enum NotesOp {
         id: String,
         text: String,
         evidence: Vec<u64>,
+        /// Kind the model reported; `None` leaves it to `NoteKind::infer`.
+        #[serde(default)]
+        kind: Option<NoteKind>,
+    },
+    /// Drop a bullet that is wrong, empty, or redundant.
+    Remove { id: String },
+    /// Fold the `from` bullets into `id`, which takes `text` and their evidence.
+    Merge {
+        id: String,
+        from: Vec<String>,
+        text: String,
     },
+    /// Put the listed bullets in this order within the places they already hold.
+    Reorder { ids: Vec<String> },
 }
 
 /// A batch of note operations to apply atomically.
@@ -67,6 +350,90 @@ pub struct NotesPatch {
     pub ops: Vec<NotesOp>,
 }
 
+/// Structured end-of-meeting summary generated from the full transcript.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MeetingSummary {
+    pub overview: String,
+    pub decisions: Vec<String>,
+    pub action_items: Vec<SummaryActionItem>,
+    pub open_questions: Vec<String>,
+}
+
+/// A follow-up task, optionally assigned to a participant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryActionItem {
+    pub text: String,
+    pub owner: Option<String>,
+}
+
+/// Podcast-style show notes generated from a full transcript.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShowNotes {
+    pub summary: String,
+    pub chapters: Vec<ShowNotesChapter>,
+    pub quotes: Vec<ShowNotesQuote>,
+    pub links: Vec<ShowNotesLink>,
+}
+
+/// A chapter marker starting at a transcript timestamp.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShowNotesChapter {
+    pub start_ms: i64,
+    pub title: String,
+}
+
+/// A topic section of a meeting transcript, from the chapters pass.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Chapter {
+    pub title: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+/// A verbatim quote worth pulling out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShowNotesQuote {
+    pub start_ms: i64,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+/// A link or resource mentioned in the episode; `url` is empty when only named.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShowNotesLink {
+    pub label: String,
+    pub url: String,
+}
+
+/// Study notes for a single-speaker lecture.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LectureNotes {
+    pub outline: Vec<OutlineItem>,
+    pub terms: Vec<KeyTerm>,
+    pub flashcards: Vec<Flashcard>,
+}
+
+/// A node in a hierarchical lecture outline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutlineItem {
+    pub text: String,
+    pub children: Vec<OutlineItem>,
+}
+
+/// A key term and its definition as given in the lecture.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyTerm {
+    pub term: String,
+    pub definition: String,
+}
+
+/// A question/answer study card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Flashcard {
+    pub question: String,
+    pub answer: String,
+}
+
 /// Events emitted by a summarize provider during streaming.
 pub enum SummarizeEvent {
     DraftToken(String),
@@ -81,6 +448,15 @@ pub struct CaptureStats {
     pub chunks_emitted: Arc<AtomicU64>,
     pub chunks_dropped: Arc<AtomicU64>,
     pub raw_frames_dropped: Arc<AtomicU64>,
+    /// Requested sources that are currently unavailable (e.g. permission not granted).
+    pub system_missing: Arc<AtomicBool>,
+    pub mic_missing: Arc<AtomicBool>,
+    /// Frames received per source, including while capture is paused.
+    pub system_frames: Arc<AtomicU64>,
+    pub mic_frames: Arc<AtomicU64>,
+    /// Peak absolute sample per source since the last `reset_source_peaks`, as f32 bits.
+    pub system_peak: Arc<AtomicU32>,
+    pub mic_peak: Arc<AtomicU32>,
 }
 
 impl CaptureStats {
@@ -91,6 +467,12 @@ impl CaptureStats {
             chunks_emitted: Arc::new(AtomicU64::new(0)),
             chunks_dropped: Arc::new(AtomicU64::new(0)),
             raw_frames_dropped: Arc::new(AtomicU64::new(0)),
+            system_missing: Arc::new(AtomicBool::new(false)),
+            mic_missing: Arc::new(AtomicBool::new(false)),
+            system_frames: Arc::new(AtomicU64::new(0)),
+            mic_frames: Arc::new(AtomicU64::new(0)),
+            system_peak: Arc::new(AtomicU32::new(0)),
+            mic_peak: Arc::new(AtomicU32::new(0)),
         }
     }
 
@@ -133,6 +515,61 @@ impl CaptureStats {
     pub fn raw_frames_dropped(&self) -> u64 {
         self.raw_frames_dropped.load(Ordering::Relaxed)
     }
+
+    pub fn set_source_missing(&self, source: AudioSource, missing: bool) {
+        match source {
+            AudioSource::System => self.system_missing.store(missing, Ordering::Relaxed),
+            AudioSource::Microphone => self.mic_missing.store(missing, Ordering::Relaxed),
+            AudioSource::Mixed => {}
+        }
+    }
+
+    pub fn source_missing(&self, source: AudioSource) -> bool {
+        match source {
+            AudioSource::System => self.system_missing.load(Ordering::Relaxed),
+            AudioSource::Microphone => self.mic_missing.load(Ordering::Relaxed),
+            AudioSource::Mixed => false,
+        }
+    }
+
+    /// Count a frame from `source` and raise its peak level.
+    pub fn record_source_frame(&self, source: AudioSource, samples: &[f32]) {
+        let Some((frames, peak)) = self.source_counters(source) else {
+            return;
+        };
+        frames.fetch_add(1, Ordering::Relaxed);
+        let level = samples
+            .iter()
+            .map(|sample| sample.abs())
+            .filter(|level| level.is_finite())
+            .fold(0.0f32, f32::max);
+        // Non-negative floats order the same as their bit patterns.
+        peak.fetch_max(level.to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn source_frames(&self, source: AudioSource) -> u64 {
+        self.source_counters(source)
+            .map_or(0, |(frames, _)| frames.load(Ordering::Relaxed))
+    }
+
+    pub fn source_peak(&self, source: AudioSource) -> f32 {
+        self.source_counters(source).map_or(0.0, |(_, peak)| {
+            f32::from_bits(peak.load(Ordering::Relaxed))
+        })
+    }
+
+    pub fn reset_source_peaks(&self) {
+        self.system_peak.store(0, Ordering::Relaxed);
+        self.mic_peak.store(0, Ordering::Relaxed);
+    }
+
+    fn source_counters(&self, source: AudioSource) -> Option<(&AtomicU64, &AtomicU32)> {
+        match source {
+            AudioSource::System => Some((&self.system_frames, &self.system_peak)),
+            AudioSource::Microphone => Some((&self.mic_frames, &self.mic_peak)),
+            AudioSource::Mixed => None,
+        }
+    }
 }
 
 impl Default for CaptureStats {
@@ -140,3 +577,204 @@ impl Default for CaptureStats {
         Self::new()
     }
 }
+
+fn is_near_duplicate(candidate: &str, existing: &HashSet<String>) -> bool {
+    let candidate_tokens = content_tokens(candidate);
+    if candidate_tokens.is_empty() {
+        return true;
+    }
+    let candidate_set: HashSet<&str> = candidate_tokens.iter().map(|t| t.as_str()).collect();
+    for existing_text in existing {
+        let existing_tokens = content_tokens(existing_text);
+        if existing_tokens.is_empty() {
+            continue;
+        }
+        let existing_set: HashSet<&str> = existing_tokens.iter().map(|t| t.as_str()).collect();
+        let overlap = candidate_set.intersection(&existing_set).count();
+        let union = candidate_set.len() + existing_set.len() - overlap;
+        if union == 0 {
+            continue;
+        }
+        let jaccard = overlap as f32 / union as f32;
+        if jaccard >= 0.75 || overlap >= 5 {
+            return true;
+        }
+    }
+    false
+}
+
+fn has_min_content_words(normalized: &str) -> bool {
+    content_tokens(normalized).len() >= 5
+}
+
+fn content_tokens(normalized: &str) -> Vec<String> {
+    normalized
+        .split_whitespace()
+        .filter(|token| !is_stopword(token))
+        .map(|token| token.to_string())
+        .collect()
+}
+
+fn is_stopword(token: &str) -> bool {
+    const STOPWORDS: [&str; 28] = [
+        "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "has", "have", "if",
+        "in", "is", "it", "its", "of", "on", "or", "that", "the", "to", "was", "were", "with",
+        "will",
+    ];
+
+    STOPWORDS.contains(&token)
+}
+
+fn strip_note_prefixes(text: &str) -> String {
+    let trimmed = text.trim_start();
+    if let Some(rest) = trimmed.strip_prefix("Me:") {
+        return rest.trim_start().to_string();
+    }
+    if let Some(rest) = trimmed.strip_prefix("Them:") {
+        return rest.trim_start().to_string();
+    }
+    trimmed.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MeetingNotes, NoteKind, NotesOp, NotesPatch};
+
+    fn add(id: &str, text: &str, evidence: u64) -> NotesOp {
+        NotesOp::Add {
+            id: id.to_string(),
+            text: text.to_string(),
+            evidence: vec![evidence],
+            kind: None,
+        }
+    }
+
+    fn ids(notes: &MeetingNotes) -> Vec<&str> {
+        notes
+            .bullets
+            .iter()
+            .map(|bullet| bullet.id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn apply_patch_adds_cleaned_bullets_and_skips_duplicates() {
+        let mut notes = MeetingNotes::default();
+        let patch = NotesPatch {
+            ops: vec![
+                add("n1", "Me: We agreed to ship the release next Friday", 1),
+                add("n1", "Budget review moves to the Monday planning call", 2),
+                add("n2", "we agreed to ship the release next friday!", 3),
+                add("n3", "Agreed: ship the release next Friday afternoon", 4),
+                add("n4", "Pricing is open", 5),
+            ],
+        };
+        assert!(notes.apply_patch(patch));
+        assert_eq!(ids(&notes), ["n1"]);
+        assert_eq!(
+            notes.bullets[0].text,
+            "We agreed to ship the release next Friday"
+        );
+        assert_eq!(notes.bullets[0].kind, NoteKind::Decision);
+
+        let patch = NotesPatch {
+            ops: vec![add(
+                "n1",
+                "Budget review moves to the Monday planning call",
+                6,
+            )],
+        };
+        assert!(!notes.apply_patch(patch));
+    }
+
+    #[test]
+    fn apply_patch_merges_removes_and_reorders_notes() {
+        let mut notes = MeetingNotes::default();
+        let patch = NotesPatch {
+            ops: vec![
+                add("n1", "Release ships Friday after final staging checks", 1),
+                add("n2", "Dana mentioned coffee machine broken again today", 2),
+                add(
+                    "n3",
+                    "Friday shipping confirmed by product and support leads",
+                    3,
+                ),
+                add(
+                    "n4",
+                    "Quarterly budget capped around ten thousand dollars total",
+                    4,
+                ),
+                add("n5", "Launch needs QA sign-off before anything goes out", 5),
+            ],
+        };
+        assert!(notes.apply_patch(patch));
+        notes.bullets[2].owner = Some("Dana".to_string());
+
+        let patch = NotesPatch {
+            ops: vec![
+                NotesOp::Merge {
+                    id: "n1".to_string(),
+                    from: vec!["n3".to_string()],
+                    text: "Them: Ship on Friday, confirmed".to_string(),
+                },
+                NotesOp::Remove {
+                    id: "n2".to_string(),
+                },
+                NotesOp::Remove {
+                    id: "missing".to_string(),
+                },
+            ],
+        };
+        assert!(notes.apply_patch(patch));
+        assert_eq!(ids(&notes), ["n1", "n4", "n5"]);
+        let merged = &notes.bullets[0];
+        assert_eq!(merged.text, "Ship on Friday, confirmed");
+        assert_eq!(merged.evidence, vec![1, 3]);
+        assert_eq!(merged.owner.as_deref(), Some("Dana"));
+
+        let patch = NotesPatch {
+            ops: vec![NotesOp::Reorder {
+                ids: vec!["n5".to_string(), "n4".to_string()],
+            }],
+        };
+        assert!(notes.apply_patch(patch));
+        assert_eq!(ids(&notes), ["n1", "n5", "n4"]);
+    }
+
+    #[test]
+    fn merge_and_reorder_leave_archived_notes_alone() {
+        let mut notes = MeetingNotes::default();
+        let patch = NotesPatch {
+            ops: vec![
+                add("n2", "Release ships Friday after final staging checks", 2),
+                add(
+                    "n3",
+                    "Friday shipping confirmed by product and support leads",
+                    3,
+                ),
+                add(
+                    "n4",
+                    "Quarterly budget capped around ten thousand dollars total",
+                    4,
+                ),
+            ],
+        };
+        assert!(notes.apply_patch(patch));
+
+        // n1 was archived: the first live `from` bullet takes its place as target.
+        assert!(notes.merge_notes(
+            "n1",
+            &["n0".to_string(), "n2".to_string(), "n3".to_string()],
+            "Ship Friday, confirmed"
+        ));
+        assert_eq!(ids(&notes), ["n2", "n4"]);
+        assert_eq!(notes.bullets[0].text, "Ship Friday, confirmed");
+        assert_eq!(notes.bullets[0].evidence, vec![2, 3]);
+        assert!(!notes.merge_notes("n1", &["n0".to_string()], "gone"));
+
+        // Only one live id left to move: nothing to reorder.
+        assert!(!notes.reorder_notes(&["n1".to_string(), "n4".to_string()]));
+        assert!(notes.reorder_notes(&["n4".to_string(), "n1".to_string(), "n2".to_string()]));
+        assert_eq!(ids(&notes), ["n4", "n2"]);
+    }
+}
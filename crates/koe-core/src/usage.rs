@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// Groq bills each transcription request for at least this much audio.
+const GROQ_MIN_BILLED_MS: u64 = 10_000;
+
+/// One metered cloud request.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageRecord {
+    pub provider: String,
+    pub model: String,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub audio_ms: u64,
+    pub cost_usd: f64,
+}
+
+/// Totals for one provider/model pair.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProviderUsage {
+    pub provider: String,
+    pub model: String,
+    pub requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub audio_seconds: f64,
+    pub cost_usd: f64,
+}
+
+/// Usage for a meeting, written to `usage.json` in the session directory.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsageReport {
+    pub providers: Vec<ProviderUsage>,
+    pub total_cost_usd: f64,
+}
+
+/// Shared accumulator for cloud provider usage; clones record into the same totals.
+#[derive(Debug, Clone, Default)]
+pub struct UsageMeter {
+    report: Arc<Mutex<UsageReport>>,
+}
+
+impl UsageMeter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, record: UsageRecord) {
+        let mut report = self.report.lock().unwrap_or_else(|e| e.into_inner());
+        report.total_cost_usd += record.cost_usd;
+        let index = match report
+            .providers
+            .iter()
+            .position(|entry| entry.provider == record.provider && entry.model == record.model)
+        {
+            Some(index) => index,
+            None => {
+                report.providers.push(ProviderUsage {
+                    provider: record.provider,
+                    model: record.model,
+                    ..ProviderUsage::default()
+                });
+                report.providers.len() - 1
+            }
+        };
+        let entry = &mut report.providers[index];
+        entry.requests += 1;
+        entry.prompt_tokens += record.prompt_tokens;
+        entry.completion_tokens += record.completion_tokens;
+        entry.audio_seconds += record.audio_ms as f64 / 1000.0;
+        entry.cost_usd += record.cost_usd;
+    }
+
+    pub fn report(&self) -> UsageReport {
+        self.report
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    pub fn cost_usd(&self) -> f64 {
+        self.report
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .total_cost_usd
+    }
+
+    pub fn reset(&self) {
+        *self.report.lock().unwrap_or_else(|e| e.into_inner()) = UsageReport::default();
+    }
+}
+
+/// Estimated Groq transcription cost from published per-hour prices.
+/// Unknown models are priced as `whisper-large-v3`.
+pub fn groq_audio_cost(model: &str, audio_ms: u64) -> f64 {
+    let usd_per_hour = match model {
+        "whisper-large-v3-turbo" => 0.04,
+        "distil-whisper-large-v3-en" => 0.02,
+        _ => 0.111,
+    };
+    audio_ms.max(GROQ_MIN_BILLED_MS) as f64 / 3_600_000.0 * usd_per_hour
+}
+
+/// Estimated AssemblyAI transcription cost from published per-hour prices,
+/// speaker diarization included. Unknown models are priced as `universal`.
+pub fn assemblyai_audio_cost(model: &str, audio_ms: u64) -> f64 {
+    let usd_per_hour = match model {
+        "nano" => 0.14,
+        "slam-1" => 0.29,
+        _ => 0.17,
+    };
+    audio_ms as f64 / 3_600_000.0 * usd_per_hour
+}
+
+/// Estimated Gemini API cost from published per-million-token prices.
+/// Unknown models are priced as `gemini-2.5-flash`.
+pub fn gemini_cost(model: &str, prompt_tokens: u64, completion_tokens: u64) -> f64 {
+    let (input, output) = match model {
+        "gemini-2.5-pro" => (1.25, 10.0),
+        "gemini-2.5-flash-lite" => (0.10, 0.40),
+        "gemini-2.0-flash" => (0.10, 0.40),
+        "gemini-2.0-flash-lite" => (0.075, 0.30),
+        _ => (0.30, 2.50),
+    };
+    (prompt_tokens as f64 * input + completion_tokens as f64 * output) / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UsageMeter, UsageRecord, gemini_cost, groq_audio_cost};
+
+    #[test]
+    fn meter_totals_per_provider_and_model() {
+        let meter = UsageMeter::new();
+        let shared = meter.clone();
+        shared.record(UsageRecord {
+            provider: "openrouter".to_string(),
+            model: "google/gemini-2.5-flash".to_string(),
+            prompt_tokens: 1_200,
+            completion_tokens: 80,
+            cost_usd: 0.002,
+            ..UsageRecord::default()
+        });
+        shared.record(UsageRecord {
+            provider: "openrouter".to_string(),
+            model: "google/gemini-2.5-flash".to_string(),
+            prompt_tokens: 800,
+            completion_tokens: 20,
+            cost_usd: 0.001,
+            ..UsageRecord::default()
+        });
+        shared.record(UsageRecord {
+            provider: "groq".to_string(),
+            model: "whisper-large-v3-turbo".to_string(),
+            audio_ms: 3_600_000,
+            cost_usd: groq_audio_cost("whisper-large-v3-turbo", 3_600_000),
+            ..UsageRecord::default()
+        });
+
+        let report = meter.report();
+        assert_eq!(report.providers.len(), 2);
+        assert_eq!(report.providers[0].requests, 2);
+        assert_eq!(report.providers[0].prompt_tokens, 2_000);
+        assert_eq!(report.providers[1].audio_seconds, 3_600.0);
+        assert!((meter.cost_usd() - 0.043).abs() < 1e-9);
+        // Short chunks are billed at the provider minimum.
+        assert_eq!(
+            groq_audio_cost("whisper-large-v3-turbo", 2_000),
+            groq_audio_cost("whisper-large-v3-turbo", 10_000)
+        );
+
+        assert!((gemini_cost("gemini-2.5-flash", 1_000_000, 100_000) - 0.55).abs() < 1e-9);
+
+        meter.reset();
+        assert_eq!(shared.cost_usd(), 0.0);
+    }
+}
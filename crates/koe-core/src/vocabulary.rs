@@ -0,0 +1,244 @@
+use crate::types::TranscriptSegment;
+use serde::{Deserialize, Serialize};
+
+/// Times a correction must be made before it is applied to new transcripts.
+pub const LEARN_THRESHOLD: u32 = 2;
+/// Longer rewrites are edits, not vocabulary, and are not learned.
+const MAX_PHRASE_WORDS: usize = 3;
+
+/// Corrections the user made to transcripts, learned per speaker.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vocabulary {
+    pub entries: Vec<VocabularyEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VocabularyEntry {
+    /// Misheard phrase, normalized (lowercase alphanumeric words).
+    pub from: String,
+    /// Replacement as the user typed it.
+    pub to: String,
+    /// Speaker the correction was made for; `None` applies to everyone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    pub count: u32,
+}
+
+impl VocabularyEntry {
+    pub fn is_learned(&self) -> bool {
+        self.count >= LEARN_THRESHOLD
+    }
+
+    fn applies_to(&self, speaker: Option<&str>) -> bool {
+        self.speaker.is_none() || self.speaker.as_deref() == speaker
+    }
+}
+
+impl Vocabulary {
+    /// Record the phrase-level corrections between a segment's text and the
+    /// user's edit of it; returns the `(from, to)` pairs that were counted.
+    pub fn learn(
+        &mut self,
+        speaker: Option<&str>,
+        before: &str,
+        after: &str,
+    ) -> Vec<(String, String)> {
+        let corrections = phrase_corrections(before, after);
+        for (from, to) in &corrections {
+            let existing = self.entries.iter_mut().find(|entry| {
+                entry.from == *from && entry.to == *to && entry.speaker.as_deref() == speaker
+            });
+            match existing {
+                Some(entry) => entry.count += 1,
+                None => self.entries.push(VocabularyEntry {
+                    from: from.clone(),
+                    to: to.clone(),
+                    speaker: speaker.map(str::to_string),
+                    count: 1,
+                }),
+            }
+        }
+        corrections
+    }
+
+    /// Rewrite learned phrases in `segment`; true when the text changed.
+    pub fn apply(&self, segment: &mut TranscriptSegment) -> bool {
+        let mut rules: Vec<&VocabularyEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.is_learned() && entry.applies_to(segment.speaker.as_deref()))
+            .collect();
+        if rules.is_empty() {
+            return false;
+        }
+        // Longest phrases first so "cube control" wins over "cube".
+        rules.sort_by_key(|entry| std::cmp::Reverse(entry.from.split_whitespace().count()));
+
+        let mut words: Vec<String> = segment
+            .text
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        let mut changed = false;
+        for rule in rules {
+            let pattern: Vec<&str> = rule.from.split_whitespace().collect();
+            let mut index = 0;
+            while index + pattern.len() <= words.len() {
+                let window = &words[index..index + pattern.len()];
+                if window
+                    .iter()
+                    .zip(&pattern)
+                    .all(|(word, expected)| normalize_word(word) == *expected)
+                {
+                    let prefix = leading_punctuation(&window[0]).to_string();
+                    let suffix = trailing_punctuation(&window[window.len() - 1]).to_string();
+                    let replacement = format!("{prefix}{}{suffix}", rule.to);
+                    words.splice(index..index + pattern.len(), [replacement]);
+                    changed = true;
+                }
+                index += 1;
+            }
+        }
+        if changed {
+            segment.text = words.join(" ");
+        }
+        changed
+    }
+}
+
+/// Replaced word runs between `before` and `after`, aligned on their longest
+/// common word sequence. Pure insertions/deletions and long rewrites are skipped.
+pub fn phrase_corrections(before: &str, after: &str) -> Vec<(String, String)> {
+    let old: Vec<&str> = before.split_whitespace().collect();
+    let new: Vec<&str> = after.split_whitespace().collect();
+    let old_norm: Vec<String> = old.iter().map(|word| normalize_word(word)).collect();
+    let new_norm: Vec<String> = new.iter().map(|word| normalize_word(word)).collect();
+
+    // lcs[i][j] = common length of old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old_norm[i] == new_norm[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut corrections = Vec::new();
+    let mut from: Vec<&str> = Vec::new();
+    let mut to: Vec<&str> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old_norm[i] == new_norm[j] {
+            flush_correction(&mut from, &mut to, &mut corrections);
+            i += 1;
+            j += 1;
+        } else if j >= new.len() || (i < old.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            from.push(&old_norm[i]);
+            i += 1;
+        } else {
+            to.push(new[j]);
+            j += 1;
+        }
+    }
+    flush_correction(&mut from, &mut to, &mut corrections);
+    corrections
+}
+
+fn flush_correction<'a>(
+    from: &mut Vec<&'a str>,
+    to: &mut Vec<&'a str>,
+    corrections: &mut Vec<(String, String)>,
+) {
+    let from_words: Vec<&str> = from.drain(..).filter(|word| !word.is_empty()).collect();
+    let to_text = to
+        .drain(..)
+        .map(|word| word.trim_matches(|ch: char| !ch.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if from_words.is_empty()
+        || to_text.is_empty()
+        || from_words.len() > MAX_PHRASE_WORDS
+        || to_text.split_whitespace().count() > MAX_PHRASE_WORDS
+    {
+        return;
+    }
+    corrections.push((from_words.join(" "), to_text));
+}
+
+fn normalize_word(word: &str) -> String {
+    word.chars()
+        .filter(|ch| ch.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn leading_punctuation(word: &str) -> &str {
+    let end = word
+        .find(|ch: char| ch.is_alphanumeric())
+        .unwrap_or(word.len());
+    &word[..end]
+}
+
+fn trailing_punctuation(word: &str) -> &str {
+    let start = word
+        .rfind(|ch: char| ch.is_alphanumeric())
+        .map_or(0, |index| {
+            index + word[index..].chars().next().map_or(1, char::len_utf8)
+        });
+    &word[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Vocabulary, phrase_corrections};
+    use crate::types::TranscriptSegment;
+
+    fn segment(speaker: &str, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            id: 1,
+            start_ms: 0,
+            end_ms: 1_000,
+            speaker: Some(speaker.to_string()),
+            text: text.to_string(),
+            finalized: true,
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn repeated_corrections_are_learned_per_speaker() {
+        assert_eq!(
+            phrase_corrections(
+                "we deploy on cube and eighties today",
+                "we deploy on Kubernetes today"
+            ),
+            vec![("cube and eighties".to_string(), "Kubernetes".to_string())]
+        );
+
+        let mut vocabulary = Vocabulary::default();
+        vocabulary.learn(
+            Some("Them"),
+            "cube and eighties is down",
+            "Kubernetes is down",
+        );
+        let mut seg = segment("Them", "is cube and eighties up?");
+        assert!(!vocabulary.apply(&mut seg));
+
+        vocabulary.learn(
+            Some("Them"),
+            "move it to cube and eighties",
+            "move it to Kubernetes",
+        );
+        assert_eq!(vocabulary.entries.len(), 1);
+        assert!(vocabulary.entries[0].is_learned());
+        assert!(vocabulary.apply(&mut seg));
+        assert_eq!(seg.text, "is Kubernetes up?");
+
+        let mut other = segment("Me", "cube and eighties");
+        assert!(!vocabulary.apply(&mut other));
+    }
+}